@@ -9,6 +9,17 @@ fn parse_backend(s: &str) -> Result<Backend, String> {
     Ok(x)
 }
 
+fn parse_case(s: &str) -> Result<Case, String> {
+    Case::from_name(s).ok_or_else(|| format!("Invalid case style: {s}"))
+}
+
+fn parse_rename(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((a, b)) => Ok((a.to_string(), b.to_string())),
+        None => Err(format!("Invalid rename, expected NAME=NEW_NAME: {s}")),
+    }
+}
+
 #[derive(Debug, FromArgs)]
 #[argh(description = "futhark binding generator")]
 struct Main {
@@ -21,6 +32,7 @@ struct Main {
 enum Commands {
     Run(Run),
     Libs(Libs),
+    Diff(Diff),
 }
 
 #[derive(Debug, FromArgs)]
@@ -29,17 +41,30 @@ enum Commands {
     description = "List libraries for the selected backend",
     subcommand
 )]
-
 struct Libs {
     #[argh(
         option,
         default = "Backend::C",
         from_str_fn(parse_backend),
-        description = "futhark backend: c, cuda, opencl, multicore"
+        description = "futhark backend: c, multicore, ispc, cuda, opencl, hip, wasm, wasm-multicore"
     )]
     backend: Backend,
 }
 
+#[derive(Debug, FromArgs)]
+#[argh(
+    name = "diff",
+    description = "Compare two manifests and report breaking changes",
+    subcommand
+)]
+struct Diff {
+    #[argh(positional, description = "old manifest")]
+    old: std::path::PathBuf,
+
+    #[argh(positional, description = "new manifest")]
+    new: std::path::PathBuf,
+}
+
 #[derive(Debug, FromArgs)]
 #[argh(name = "run", description = "generate bindings", subcommand)]
 struct Run {
@@ -53,7 +78,7 @@ struct Run {
         option,
         default = "Backend::C",
         from_str_fn(parse_backend),
-        description = "futhark backend: c, cuda, opencl, multicore"
+        description = "futhark backend: c, multicore, ispc, cuda, opencl, hip, wasm, wasm-multicore"
     )]
     backend: Backend,
 
@@ -67,6 +92,71 @@ struct Run {
         description = "arguments to be passed to the futhark compiler"
     )]
     futhark_args: Vec<String>,
+
+    #[argh(
+        option,
+        default = "String::new()",
+        description = "prefix for generated type names"
+    )]
+    type_prefix: String,
+
+    #[argh(
+        option,
+        default = "String::new()",
+        description = "suffix for generated type names"
+    )]
+    type_suffix: String,
+
+    #[argh(
+        option,
+        default = "Case::Default",
+        from_str_fn(parse_case),
+        description = "case style for generated type names: default, pascal, snake"
+    )]
+    case: Case,
+
+    #[argh(
+        option,
+        from_str_fn(parse_rename),
+        description = "rename a type or entry point: NAME=NEW_NAME"
+    )]
+    rename: Vec<(String, String)>,
+
+    #[argh(
+        switch,
+        description = "generate async entry points and array accessors (rust only)"
+    )]
+    async_api: bool,
+
+    #[argh(
+        switch,
+        description = "generate a handle that runs the context on a worker thread (rust only)"
+    )]
+    context_handle: bool,
+
+    #[argh(
+        switch,
+        description = "return named structs from entry points with multiple outputs (rust and ocaml only)"
+    )]
+    output_structs: bool,
+
+    #[argh(
+        switch,
+        description = "map records with only scalar fields to plain host types (rust and ocaml only)"
+    )]
+    plain_records: bool,
+
+    #[argh(
+        switch,
+        description = "generate host copies of arrays and records using serde (rust only)"
+    )]
+    serde: bool,
+
+    #[argh(
+        switch,
+        description = "generate a parsed profiling report type (rust and ocaml only)"
+    )]
+    parsed_report: bool,
 }
 
 fn main() -> Result<(), Error> {
@@ -80,12 +170,26 @@ fn main() -> Result<(), Error> {
             let out_dir = args.output.parent().unwrap().canonicalize().unwrap();
             let mut compiler = Compiler::new(args.backend, &args.input)
                 .with_extra_args(args.futhark_args)
-                .with_output_dir(out_dir);
+                .with_output_dir(&out_dir);
             if let Some(exe) = args.compiler {
                 compiler = compiler.with_executable_name(exe);
             }
             let pkg = compiler.compile()?;
-            let mut config = Config::new(args.output)?;
+            pkg.check_output(&args.output)?;
+            let naming = Naming {
+                prefix: args.type_prefix,
+                suffix: args.type_suffix,
+                case: args.case,
+                renames: args.rename.into_iter().collect(),
+            };
+            let mut config = Config::new(args.output)?
+                .with_naming(naming)
+                .with_async_api(args.async_api)
+                .with_context_handle(args.context_handle)
+                .with_output_structs(args.output_structs)
+                .with_plain_records(args.plain_records)
+                .with_serde(args.serde)
+                .with_parsed_report(args.parsed_report);
             let mut gen = config.detect().expect("Unable to detect output language");
             gen.generate(&pkg, &mut config)?;
         }
@@ -96,6 +200,17 @@ fn main() -> Result<(), Error> {
                 .for_each(|x| print!("-l{x} "));
             println!();
         }
+        Commands::Diff(args) => {
+            let old = Manifest::parse_file(&args.old)?;
+            let new = Manifest::parse_file(&args.new)?;
+            let changes = old.diff(&new);
+            for change in &changes {
+                println!("{change}");
+            }
+            if changes.iter().any(|c| c.breaking) {
+                std::process::exit(1);
+            }
+        }
     }
 
     Ok(())
@@ -0,0 +1,232 @@
+use crate::*;
+
+/// A single difference between two manifests
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    /// `true` when code written against the old manifest may no longer compile or behave the same
+    pub breaking: bool,
+
+    /// Description of the change
+    pub description: String,
+}
+
+impl Change {
+    fn breaking(description: String) -> Change {
+        Change {
+            breaking: true,
+            description,
+        }
+    }
+
+    fn compatible(description: String) -> Change {
+        Change {
+            breaking: false,
+            description,
+        }
+    }
+}
+
+impl std::fmt::Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let kind = if self.breaking {
+            "breaking"
+        } else {
+            "compatible"
+        };
+        write!(f, "{kind}: {}", self.description)
+    }
+}
+
+fn diff_inputs(
+    name: &str,
+    old: &[manifest::Input],
+    new: &[manifest::Input],
+    changes: &mut Vec<Change>,
+) {
+    if old.len() != new.len() {
+        changes.push(Change::breaking(format!(
+            "entry point `{name}`: number of inputs changed from {} to {}",
+            old.len(),
+            new.len()
+        )));
+    }
+
+    for (i, (a, b)) in old.iter().zip(new.iter()).enumerate() {
+        if a.r#type != b.r#type {
+            changes.push(Change::breaking(format!(
+                "entry point `{name}`: input {i} changed type from `{}` to `{}`",
+                a.r#type, b.r#type
+            )));
+        }
+
+        if a.name != b.name {
+            changes.push(Change::compatible(format!(
+                "entry point `{name}`: input {i} renamed from `{}` to `{}`",
+                a.name, b.name
+            )));
+        }
+
+        // A unique input is consumed by the entry point, so callers can no longer reuse it
+        match (a.unique, b.unique) {
+            (false, true) => changes.push(Change::breaking(format!(
+                "entry point `{name}`: input {i} is now unique (consumed)"
+            ))),
+            (true, false) => changes.push(Change::compatible(format!(
+                "entry point `{name}`: input {i} is no longer unique"
+            ))),
+            _ => (),
+        }
+    }
+}
+
+fn diff_outputs(
+    name: &str,
+    old: &[manifest::Output],
+    new: &[manifest::Output],
+    changes: &mut Vec<Change>,
+) {
+    if old.len() != new.len() {
+        changes.push(Change::breaking(format!(
+            "entry point `{name}`: number of outputs changed from {} to {}",
+            old.len(),
+            new.len()
+        )));
+    }
+
+    for (i, (a, b)) in old.iter().zip(new.iter()).enumerate() {
+        if a.r#type != b.r#type {
+            changes.push(Change::breaking(format!(
+                "entry point `{name}`: output {i} changed type from `{}` to `{}`",
+                a.r#type, b.r#type
+            )));
+        }
+
+        if a.unique != b.unique {
+            changes.push(Change::compatible(format!(
+                "entry point `{name}`: output {i} uniqueness changed from {} to {}",
+                a.unique, b.unique
+            )));
+        }
+    }
+}
+
+fn diff_record(
+    name: &str,
+    old: &manifest::Record,
+    new: &manifest::Record,
+    changes: &mut Vec<Change>,
+) {
+    for field in &old.fields {
+        if !new.fields.iter().any(|f| f.name == field.name) {
+            changes.push(Change::breaking(format!(
+                "record `{name}`: field `{}` removed",
+                field.name
+            )));
+        }
+    }
+
+    for field in &new.fields {
+        match old.fields.iter().find(|f| f.name == field.name) {
+            None => changes.push(Change::breaking(format!(
+                "record `{name}`: field `{}` added",
+                field.name
+            ))),
+            Some(f) if f.r#type != field.r#type => changes.push(Change::breaking(format!(
+                "record `{name}`: field `{}` changed type from `{}` to `{}`",
+                field.name, f.r#type, field.r#type
+            ))),
+            Some(_) => (),
+        }
+    }
+
+    // Record constructors take their fields positionally
+    let old_names: Vec<_> = old.fields.iter().map(|f| &f.name).collect();
+    let new_names: Vec<_> = new.fields.iter().map(|f| &f.name).collect();
+    if old_names.len() == new_names.len()
+        && old_names != new_names
+        && old_names.iter().all(|f| new_names.contains(f))
+    {
+        changes.push(Change::breaking(format!(
+            "record `{name}`: fields reordered"
+        )));
+    }
+}
+
+fn diff_type(name: &str, old: &manifest::Type, new: &manifest::Type, changes: &mut Vec<Change>) {
+    use manifest::Type;
+    match (old, new) {
+        (Type::Array(a), Type::Array(b)) => {
+            if a.elemtype.to_str() != b.elemtype.to_str() || a.rank != b.rank {
+                changes.push(Change::breaking(format!(
+                    "type `{name}`: changed from {}d {} array to {}d {} array",
+                    a.rank,
+                    a.elemtype.to_str(),
+                    b.rank,
+                    b.elemtype.to_str()
+                )));
+            }
+        }
+        (Type::Opaque(a), Type::Opaque(b)) => match (&a.record, &b.record) {
+            (Some(a), Some(b)) => diff_record(name, a, b, changes),
+            (Some(_), None) => changes.push(Change::breaking(format!(
+                "type `{name}`: no longer a record"
+            ))),
+            (None, Some(_)) => changes.push(Change::compatible(format!(
+                "type `{name}`: is now a record"
+            ))),
+            (None, None) => (),
+        },
+        (Type::Array(_), Type::Opaque(_)) => changes.push(Change::breaking(format!(
+            "type `{name}`: changed from array to opaque"
+        ))),
+        (Type::Opaque(_), Type::Array(_)) => changes.push(Change::breaking(format!(
+            "type `{name}`: changed from opaque to array"
+        ))),
+    }
+}
+
+impl Manifest {
+    /// Compare two manifests, returning the changes needed to go from `self` to `new`
+    pub fn diff(&self, new: &Manifest) -> Vec<Change> {
+        let mut changes = Vec::new();
+
+        if self.backend != new.backend {
+            changes.push(Change::compatible(format!(
+                "backend changed from {} to {}",
+                self.backend.to_str(),
+                new.backend.to_str()
+            )));
+        }
+
+        for (name, ty) in &self.types {
+            match new.types.get(name) {
+                Some(t) => diff_type(name, ty, t, &mut changes),
+                None => changes.push(Change::breaking(format!("type `{name}` removed"))),
+            }
+        }
+
+        for name in new.types.keys() {
+            if !self.types.contains_key(name) {
+                changes.push(Change::compatible(format!("type `{name}` added")));
+            }
+        }
+
+        for (name, entry) in &self.entry_points {
+            match new.entry_points.get(name) {
+                Some(e) => {
+                    diff_inputs(name, &entry.inputs, &e.inputs, &mut changes);
+                    diff_outputs(name, &entry.outputs, &e.outputs, &mut changes);
+                }
+                None => changes.push(Change::breaking(format!("entry point `{name}` removed"))),
+            }
+        }
+
+        for name in new.entry_points.keys() {
+            if !self.entry_points.contains_key(name) {
+                changes.push(Change::compatible(format!("entry point `{name}` added")));
+            }
+        }
+
+        changes
+    }
+}
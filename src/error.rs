@@ -9,6 +9,19 @@ pub enum Error {
 
     /// std::io::Error
     Io(std::io::Error),
+
+    /// The manifest contains a type that can't be resolved
+    UnsupportedType(String),
+
+    /// The same name would be generated for multiple types or entry points
+    NameCollision(String),
+
+    /// Code can't be generated for an entry point or backend using the current config
+    Unsupported(String),
+
+    /// The output file would overwrite, or be included in place of, the header generated by
+    /// Futhark
+    HeaderConflict(std::path::PathBuf),
 }
 
 impl From<serde_json::Error> for Error {
@@ -0,0 +1,287 @@
+use crate::generate::{Names, TypeRef, Types};
+use crate::*;
+use std::io::Write;
+
+/// C codegen, generates a header with helper functions for calling entry points using host
+/// buffers, to be used along with the header generated by Futhark
+pub struct C {
+    types: Types,
+    prefix: String,
+    names: Names,
+}
+
+// Functions defined in `templates/c/context.h`, without the prefix
+const C_RESERVED_NAMES: &[&str] = &["error", "error_free", "copy_string", "set_error"];
+
+impl Default for C {
+    fn default() -> Self {
+        C {
+            types: Types::default(),
+            prefix: String::new(),
+            names: Names::new(C_RESERVED_NAMES),
+        }
+    }
+}
+
+/// C type used for a scalar, the Futhark C API uses `uint16_t` for `f16` values
+fn scalar_type(e: manifest::ElemType) -> &'static str {
+    use manifest::ElemType::*;
+    match e {
+        I8 => "int8_t",
+        I16 => "int16_t",
+        I32 => "int32_t",
+        I64 => "int64_t",
+        U8 => "uint8_t",
+        U16 => "uint16_t",
+        U32 => "uint32_t",
+        U64 => "uint64_t",
+        F16 => "uint16_t",
+        F32 => "float",
+        F64 => "double",
+        Bool => "bool",
+    }
+}
+
+/// Convert the output file name into a prefix for the generated functions
+fn prefix_name(path: &std::path::Path) -> String {
+    let stem = path
+        .file_stem()
+        .map(|x| x.to_string_lossy().to_ascii_lowercase())
+        .unwrap_or_default();
+    let mut name: String = stem
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        name.insert_str(0, "futhark_");
+    }
+    name
+}
+
+/// Name of the function used to copy an array to the host, without the prefix
+fn copy_name(cname: &str) -> String {
+    format!("copy_{}", cname.strip_prefix("futhark_").unwrap_or(cname))
+}
+
+/// Get the free function for an array or opaque type
+fn free_fn<'a>(pkg: &'a Package, t: &str) -> &'a str {
+    match &pkg.manifest.types[t] {
+        manifest::Type::Array(a) => &a.ops.free,
+        manifest::Type::Opaque(ty) => &ty.ops.free,
+    }
+}
+
+impl Generate for C {
+    fn bindings(&mut self, pkg: &Package, config: &mut Config) -> Result<(), Error> {
+        // The Futhark header is included by name, so it can't share a name with the output
+        if config.output_path.file_name() == pkg.h_file.file_name() {
+            return Err(Error::HeaderConflict(pkg.h_file.clone()));
+        }
+
+        self.types = Types::new(&pkg.manifest)?;
+        self.prefix = prefix_name(&config.output_path);
+
+        writeln!(config.output_file, "// Generated by futhark-bindgen\n")?;
+
+        let header = pkg
+            .h_file
+            .file_name()
+            .map(|x| x.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/c/context.h"),
+            guard = format!("{}_H", self.prefix.to_ascii_uppercase()),
+            header = header,
+            prefix = self.prefix,
+            prefix_upper = self.prefix.to_ascii_uppercase(),
+        )?;
+
+        Ok(())
+    }
+
+    fn array_type(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        a: &manifest::ArrayType,
+    ) -> Result<(), Error> {
+        let futhark_type = self.types.get(name).cname().unwrap().to_string();
+        let copy_fn = copy_name(&futhark_type);
+        self.names.insert(&copy_fn, name)?;
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/c/array.h"),
+            name = name,
+            prefix = self.prefix,
+            prefix_upper = self.prefix.to_ascii_uppercase(),
+            suffix = &copy_fn["copy_".len()..],
+            futhark_type = futhark_type,
+            c_elemtype = scalar_type(a.elemtype),
+            rank = a.rank,
+            shape_fn = a.ops.shape,
+            values_fn = a.ops.values,
+        )?;
+
+        Ok(())
+    }
+
+    fn opaque_type(
+        &mut self,
+        _pkg: &Package,
+        _config: &mut Config,
+        _name: &str,
+        _ty: &manifest::OpaqueType,
+    ) -> Result<(), Error> {
+        // Opaque values are passed to the helpers using the types from the Futhark header
+        Ok(())
+    }
+
+    fn entry(
+        &mut self,
+        pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        entry: &manifest::Entry,
+    ) -> Result<(), Error> {
+        let prefix = &self.prefix;
+        let mut params = vec![
+            "struct futhark_context *ctx".to_string(),
+            format!("{prefix}_error *err"),
+        ];
+        let mut call_args = vec!["ctx".to_string()];
+        let mut decls = String::new();
+        let mut setup = String::new();
+        let mut copy_outputs = String::new();
+        let mut cleanup = String::new();
+
+        // Output arguments
+        for (i, arg) in entry.outputs.iter().enumerate() {
+            let out = format!("out{i}");
+            match self.types.get(&arg.r#type) {
+                TypeRef::Scalar(e) => {
+                    params.push(format!("{} *{out}", scalar_type(e)));
+                    call_args.push(out);
+                }
+                TypeRef::Array {
+                    cname,
+                    elemtype,
+                    rank,
+                } => {
+                    params.push(format!("{} **{out}", scalar_type(elemtype)));
+                    params.push(format!("int64_t {out}_shape[{rank}]"));
+                    decls.push_str(&format!(
+                        "  struct {cname} *{out}_arr = NULL;\n  *{out} = NULL;\n"
+                    ));
+                    call_args.push(format!("&{out}_arr"));
+                    copy_outputs.push_str(&format!(
+                        "  rc = {prefix}_{}(ctx, err, {out}_arr, {out}, {out}_shape);\n  if (rc != 0) {{\n    goto done;\n  }}\n",
+                        copy_name(&cname),
+                    ));
+                    cleanup.push_str(&format!(
+                        "  if ({out}_arr != NULL) {{\n    {}(ctx, {out}_arr);\n  }}\n",
+                        free_fn(pkg, &arg.r#type),
+                    ));
+                }
+                ty => {
+                    let cname = ty.cname().unwrap();
+                    params.push(format!("struct {cname} **{out}"));
+                    decls.push_str(&format!("  *{out} = NULL;\n"));
+                    call_args.push(out.clone());
+                    cleanup.push_str(&format!(
+                        "  if (rc != 0 && *{out} != NULL) {{\n    {}(ctx, *{out});\n    *{out} = NULL;\n  }}\n",
+                        free_fn(pkg, &arg.r#type),
+                    ));
+                }
+            }
+        }
+
+        // Input arguments
+        for (i, arg) in entry.inputs.iter().enumerate() {
+            let input = format!("in{i}");
+            match self.types.get(&arg.r#type) {
+                TypeRef::Scalar(e) => {
+                    params.push(format!("{} {input}", scalar_type(e)));
+                    call_args.push(input);
+                }
+                TypeRef::Array {
+                    cname,
+                    elemtype,
+                    rank,
+                } => {
+                    let new_fn = match &pkg.manifest.types[&arg.r#type] {
+                        manifest::Type::Array(a) => &a.ops.new,
+                        manifest::Type::Opaque(_) => unreachable!(),
+                    };
+                    let dims: Vec<_> = (0..rank).map(|d| format!("{input}_shape[{d}]")).collect();
+                    params.push(format!("const {} *{input}", scalar_type(elemtype)));
+                    params.push(format!("const int64_t {input}_shape[{rank}]"));
+                    decls.push_str(&format!("  struct {cname} *{input}_arr = NULL;\n"));
+                    setup.push_str(&format!(
+                        "  {input}_arr = {new_fn}(ctx, {input}, {});\n  if ({input}_arr == NULL) {{\n    rc = {prefix}_set_error(ctx, err, {}_ERR_NULL_PTR);\n    goto done;\n  }}\n",
+                        dims.join(", "),
+                        prefix.to_ascii_uppercase(),
+                    ));
+                    call_args.push(format!("{input}_arr"));
+                    cleanup.push_str(&format!(
+                        "  if ({input}_arr != NULL) {{\n    {}(ctx, {input}_arr);\n  }}\n",
+                        free_fn(pkg, &arg.r#type),
+                    ));
+                }
+                ty => {
+                    params.push(format!("const struct {} *{input}", ty.cname().unwrap()));
+                    call_args.push(input);
+                }
+            }
+        }
+
+        // Host buffers of array outputs are freed if any later step fails
+        for (i, arg) in entry.outputs.iter().enumerate() {
+            if let TypeRef::Array { .. } = self.types.get(&arg.r#type) {
+                cleanup.push_str(&format!(
+                    "  if (rc != 0) {{\n    free(*out{i});\n    *out{i} = NULL;\n  }}\n"
+                ));
+            }
+        }
+
+        let entry_name = config.naming.entry_name(name);
+        self.names.insert(&entry_name, name)?;
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/c/entry.h"),
+            name = name,
+            prefix = prefix,
+            entry_name = entry_name,
+            entry_fn = entry.cfun,
+            params = params.join(", "),
+            decls = decls,
+            setup = setup,
+            call_args = call_args.join(", "),
+            copy_outputs = copy_outputs,
+            cleanup = cleanup,
+        )?;
+
+        Ok(())
+    }
+
+    fn finish(&mut self, _pkg: &Package, config: &mut Config) -> Result<(), Error> {
+        writeln!(
+            config.output_file,
+            "#endif // {}_H",
+            self.prefix.to_ascii_uppercase()
+        )?;
+        Ok(())
+    }
+
+    fn format(&mut self, path: &std::path::Path) -> Result<(), Error> {
+        let _ = std::process::Command::new("clang-format")
+            .arg("-i")
+            .arg(path)
+            .status();
+        Ok(())
+    }
+}
@@ -0,0 +1,394 @@
+use crate::generate::{
+    camel_case, first_uppercase, module_name, pascal_field_name, Names, TypeRef, Types,
+};
+use crate::*;
+use std::io::Write;
+
+/// C# codegen, generates P/Invoke bindings in a namespace named after the output file
+pub struct CSharp {
+    typemap: BTreeMap<String, String>,
+    types: Types,
+    type_names: Names,
+    entry_names: Names,
+}
+
+// Types defined in `templates/csharp/context.cs` and types used without qualification
+const CSHARP_RESERVED_TYPES: &[&str] = &[
+    "Native",
+    "Context",
+    "Options",
+    "FutharkException",
+    "NullPtrException",
+    "ContextHandle",
+    "FutharkHandle",
+    "Exception",
+    "ArgumentException",
+    "IDisposable",
+    "IntPtr",
+    "Span",
+    "ReadOnlySpan",
+    "SafeHandle",
+    "Marshal",
+    "MemoryMarshal",
+    "UnmanagedType",
+];
+
+// Members of `Context` in `templates/csharp/context.cs`
+const CSHARP_RESERVED_METHODS: &[&str] = &[
+    "Context",
+    "AutoSync",
+    "Dispose",
+    "Check",
+    "Sync",
+    "MaybeSync",
+    "ClearCaches",
+    "PauseProfiling",
+    "UnpauseProfiling",
+    "GetError",
+    "Report",
+    "TakeString",
+    "Equals",
+    "GetHashCode",
+    "GetType",
+    "ToString",
+];
+
+/// C# type used for a scalar, C# has a `Half` type but it can't be passed to native code, so
+/// `f16` values are passed as their bits
+fn scalar_type(e: manifest::ElemType) -> &'static str {
+    use manifest::ElemType::*;
+    match e {
+        I8 => "sbyte",
+        I16 => "short",
+        I32 => "int",
+        I64 => "long",
+        U8 => "byte",
+        U16 => "ushort",
+        U32 => "uint",
+        U64 => "ulong",
+        F16 => "ushort",
+        F32 => "float",
+        F64 => "double",
+        Bool => "bool",
+    }
+}
+
+impl Default for CSharp {
+    fn default() -> Self {
+        CSharp {
+            typemap: BTreeMap::new(),
+            types: Types::default(),
+            type_names: Names::new(CSHARP_RESERVED_TYPES),
+            entry_names: Names::new(CSHARP_RESERVED_METHODS),
+        }
+    }
+}
+
+impl CSharp {
+    /// Determine the class names of all array and opaque types
+    fn resolve_names(&mut self, pkg: &Package, config: &Config) -> Result<(), Error> {
+        for name in pkg.manifest.types.keys() {
+            let cs_type = config.naming.pascal_name(name, &self.types.get(name));
+            self.type_names.insert(&cs_type, name)?;
+            self.typemap.insert(name.clone(), cs_type);
+        }
+        Ok(())
+    }
+
+    /// Get the C# type used for a Futhark type
+    fn get_type(&self, t: &str) -> String {
+        match self.types.get(t) {
+            TypeRef::Scalar(e) => scalar_type(e).to_string(),
+            _ => self.typemap[t].clone(),
+        }
+    }
+
+    /// Get the type of an input parameter of a native function, `bool` has to be marshalled as
+    /// a single byte
+    fn native_input(&self, t: &str) -> String {
+        match self.types.get(t) {
+            TypeRef::Scalar(manifest::ElemType::Bool) => {
+                "[MarshalAs(UnmanagedType.U1)] bool".to_string()
+            }
+            TypeRef::Scalar(e) => scalar_type(e).to_string(),
+            _ => "FutharkHandle".to_string(),
+        }
+    }
+
+    /// Get the type of an output parameter of a native function
+    fn native_output(&self, t: &str) -> String {
+        match self.types.get(t) {
+            TypeRef::Scalar(manifest::ElemType::Bool) => {
+                "[MarshalAs(UnmanagedType.U1)] out bool".to_string()
+            }
+            TypeRef::Scalar(e) => format!("out {}", scalar_type(e)),
+            _ => "out IntPtr".to_string(),
+        }
+    }
+
+    /// Get the expression used to pass `x` to a native function
+    fn input_arg(&self, t: &str, x: &str) -> String {
+        match self.types.get(t) {
+            TypeRef::Scalar(_) => x.to_string(),
+            _ => format!("{x}.handle"),
+        }
+    }
+
+    /// Get the expression used to wrap the output value `x`
+    fn output_value(&self, t: &str, x: &str, ctx: &str) -> String {
+        match self.types.get(t) {
+            TypeRef::Scalar(_) => x.to_string(),
+            _ => format!("new {}({ctx}, {x})", self.typemap[t]),
+        }
+    }
+
+    /// Get the type used to declare the output variable `out` at the call site
+    fn out_type(&self, t: &str) -> String {
+        match self.types.get(t) {
+            TypeRef::Scalar(e) => scalar_type(e).to_string(),
+            _ => "IntPtr".to_string(),
+        }
+    }
+}
+
+impl Generate for CSharp {
+    fn bindings(&mut self, pkg: &Package, config: &mut Config) -> Result<(), Error> {
+        self.types = Types::new(&pkg.manifest)?;
+        self.resolve_names(pkg, config)?;
+
+        writeln!(
+            config.output_file,
+            "// <auto-generated />\n// Generated by futhark-bindgen\n"
+        )?;
+
+        let (backend_extern_functions, backend_options, configure_backend) =
+            match pkg.manifest.backend {
+                Backend::Multicore => (
+                    "\n    [DllImport(Native.Lib)]\n    private static extern void futhark_context_config_set_num_threads(IntPtr config, int n);\n",
+                    "    public int NumThreads { get; set; }\n",
+                    "        futhark_context_config_set_num_threads(config, options.NumThreads);",
+                ),
+                Backend::CUDA | Backend::OpenCL => (
+                    "\n    [DllImport(Native.Lib)]\n    private static extern void futhark_context_config_set_device(IntPtr config, [MarshalAs(UnmanagedType.LPUTF8Str)] string device);\n",
+                    "    public string? Device { get; set; }\n",
+                    "        if (options.Device != null)\n        {\n            futhark_context_config_set_device(config, options.Device);\n        }",
+                ),
+                _ => ("", "", ""),
+            };
+
+        let lib = config
+            .output_path
+            .file_stem()
+            .map(|x| x.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/csharp/context.cs"),
+            namespace = module_name(&config.output_path),
+            lib = lib,
+            backend_extern_functions = backend_extern_functions,
+            backend_options = backend_options,
+            configure_backend = configure_backend,
+        )?;
+
+        Ok(())
+    }
+
+    fn array_type(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        a: &manifest::ArrayType,
+    ) -> Result<(), Error> {
+        let cs_elemtype = scalar_type(a.elemtype);
+
+        // `bool` isn't blittable, so the data is passed as bytes
+        let (native_elemtype, native_data, native_output) =
+            if a.elemtype == manifest::ElemType::Bool {
+                (
+                    "byte",
+                    "MemoryMarshal.Cast<bool, byte>(data)",
+                    "MemoryMarshal.Cast<bool, byte>(output)",
+                )
+            } else {
+                (cs_elemtype, "data", "output")
+            };
+
+        let dim_params: Vec<_> = (0..a.rank).map(|i| format!("long dim{i}")).collect();
+        let dim_args: Vec<_> = (0..a.rank).map(|i| format!("dims[{i}]")).collect();
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/csharp/array.cs"),
+            cs_type = self.typemap[name],
+            rank = a.rank,
+            elemtype = a.elemtype.to_str(),
+            cs_elemtype = cs_elemtype,
+            native_elemtype = native_elemtype,
+            native_data = native_data,
+            native_output = native_output,
+            new_fn = a.ops.new,
+            free_fn = a.ops.free,
+            values_fn = a.ops.values,
+            shape_fn = a.ops.shape,
+            dim_params = dim_params.join(", "),
+            dim_args = dim_args.join(", "),
+        )?;
+
+        Ok(())
+    }
+
+    fn opaque_type(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        ty: &manifest::OpaqueType,
+    ) -> Result<(), Error> {
+        let cs_type = &self.typemap[name];
+
+        // Record constructors and accessors are declared inside of the class
+        let mut record_externs = String::new();
+        let mut record_methods = Vec::new();
+
+        if let Some(record) = &ty.record {
+            let mut new_params = vec![];
+            let mut new_extern_params = vec![
+                "ContextHandle ctx".to_string(),
+                "out IntPtr obj".to_string(),
+            ];
+            let mut new_call_args = vec![];
+            for field in record.fields.iter() {
+                let param = format!("field{}", field.name);
+                let t = self.get_type(&field.r#type);
+
+                new_extern_params.push(format!("{} {param}", self.native_input(&field.r#type)));
+                new_call_args.push(self.input_arg(&field.r#type, &param));
+                new_params.push(format!("{t} {param}"));
+
+                record_externs.push_str(&format!(
+                    "\n    [DllImport(Native.Lib)]\n    private static extern int {}(ContextHandle ctx, {} output, FutharkHandle obj);\n",
+                    field.project,
+                    self.native_output(&field.r#type),
+                ));
+
+                let mut s = Vec::new();
+                write!(
+                    s,
+                    include_str!("templates/csharp/record_project.cs"),
+                    field_name = field.name,
+                    getter = format!("Get{}", pascal_field_name(&field.name)),
+                    cs_field_type = t,
+                    project_fn = field.project,
+                    out_type = self.out_type(&field.r#type),
+                    output = self.output_value(&field.r#type, "output", "ctx"),
+                )?;
+                record_methods.push(String::from_utf8(s).unwrap());
+            }
+
+            record_externs.insert_str(
+                0,
+                &format!(
+                    "\n    [DllImport(Native.Lib)]\n    private static extern int {}({});\n",
+                    record.new,
+                    new_extern_params.join(", ")
+                ),
+            );
+
+            let mut s = Vec::new();
+            write!(
+                s,
+                include_str!("templates/csharp/record.cs"),
+                cs_type = cs_type,
+                new_fn = record.new,
+                new_params = new_params.join(", "),
+                new_call_args = new_call_args.join(", "),
+            )?;
+            record_methods.insert(0, String::from_utf8(s).unwrap());
+        }
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/csharp/opaque.cs"),
+            cs_type = cs_type,
+            free_fn = ty.ops.free,
+            record_externs = record_externs,
+            record_methods = record_methods.join(""),
+        )?;
+
+        Ok(())
+    }
+
+    fn entry(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        entry: &manifest::Entry,
+    ) -> Result<(), Error> {
+        let mut extern_params = vec!["ContextHandle ctx".to_string()];
+        let mut call_args = vec!["handle".to_string()];
+        let mut entry_params = Vec::new();
+        let mut return_types = Vec::new();
+        let mut entry_return = Vec::new();
+
+        // Output arguments
+        for (i, arg) in entry.outputs.iter().enumerate() {
+            let out = format!("out{i}");
+            extern_params.push(format!("{} {out}", self.native_output(&arg.r#type)));
+            call_args.push(format!("out {} {out}", self.out_type(&arg.r#type)));
+            return_types.push(self.get_type(&arg.r#type));
+            entry_return.push(self.output_value(&arg.r#type, &out, "this"));
+        }
+
+        // Input arguments
+        for (i, arg) in entry.inputs.iter().enumerate() {
+            let input = format!("input{i}");
+            extern_params.push(format!("{} {input}", self.native_input(&arg.r#type)));
+            call_args.push(self.input_arg(&arg.r#type, &input));
+            entry_params.push(format!("{} {input}", self.get_type(&arg.r#type)));
+        }
+
+        let entry_name = config
+            .naming
+            .entry_name_or(name, first_uppercase(&camel_case(name)));
+        self.entry_names.insert(&entry_name, name)?;
+
+        let (entry_return_type, entry_return) = match entry.outputs.len() {
+            0 => ("void".to_string(), "        MaybeSync();\n".to_string()),
+            n => {
+                let (t, x) = if n == 1 {
+                    (return_types.join(", "), entry_return.join(", "))
+                } else {
+                    (
+                        format!("({})", return_types.join(", ")),
+                        format!("({})", entry_return.join(", ")),
+                    )
+                };
+                (
+                    t.clone(),
+                    format!(
+                        "        {t} result = {x};\n        MaybeSync();\n        return result;\n"
+                    ),
+                )
+            }
+        };
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/csharp/entry.cs"),
+            name = name,
+            entry_fn = entry.cfun,
+            entry_name = entry_name,
+            extern_params = extern_params.join(", "),
+            entry_params = entry_params.join(", "),
+            entry_return_type = entry_return_type,
+            call_args = call_args.join(", "),
+            entry_return = entry_return,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,344 @@
+use crate::generate::{camel_case, first_uppercase, pascal_field_name, Names, TypeRef, Types};
+use crate::*;
+use std::io::Write;
+
+/// Go codegen, generates a cgo package named after the output file
+pub struct Go {
+    typemap: BTreeMap<String, String>,
+    types: Types,
+    type_names: Names,
+    entry_names: Names,
+}
+
+// Declarations in `templates/go/context.go`
+const GO_RESERVED_NAMES: &[&str] = &["Context", "Options", "Error", "NewContext"];
+
+// Methods defined on `Context` in `templates/go/context.go`
+const GO_RESERVED_METHODS: &[&str] = &[
+    "Close",
+    "Sync",
+    "ClearCaches",
+    "PauseProfiling",
+    "UnpauseProfiling",
+    "GetError",
+    "Report",
+];
+
+impl Default for Go {
+    fn default() -> Self {
+        Go {
+            typemap: BTreeMap::new(),
+            types: Types::default(),
+            type_names: Names::new(GO_RESERVED_NAMES),
+            entry_names: Names::new(GO_RESERVED_METHODS),
+        }
+    }
+}
+
+/// Go and C types used for scalars, Go has no `float16` so `f16` values are passed as their bits
+fn scalar_types(e: manifest::ElemType) -> (&'static str, &'static str) {
+    use manifest::ElemType::*;
+    match e {
+        I8 => ("int8", "int8_t"),
+        I16 => ("int16", "int16_t"),
+        I32 => ("int32", "int32_t"),
+        I64 => ("int64", "int64_t"),
+        U8 => ("uint8", "uint8_t"),
+        U16 => ("uint16", "uint16_t"),
+        U32 => ("uint32", "uint32_t"),
+        U64 => ("uint64", "uint64_t"),
+        F16 => ("uint16", "uint16_t"),
+        F32 => ("float32", "float"),
+        F64 => ("float64", "double"),
+        Bool => ("bool", "bool"),
+    }
+}
+
+/// Convert the output file name into a valid Go package name
+fn package_name(path: &std::path::Path) -> String {
+    let stem = path
+        .file_stem()
+        .map(|x| x.to_string_lossy().to_ascii_lowercase())
+        .unwrap_or_default();
+    let mut name: String = stem
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        name.insert_str(0, "futhark");
+    }
+    name
+}
+
+impl Go {
+    /// Get the Go type used for a Futhark type, arrays and opaque types are pointers
+    fn get_type(&self, t: &str) -> String {
+        match self.types.get(t) {
+            TypeRef::Scalar(e) => scalar_types(e).0.to_string(),
+            _ => format!("*{}", self.typemap[t]),
+        }
+    }
+
+    /// Determine the Go names of all array and opaque types
+    fn resolve_names(&mut self, pkg: &Package, config: &Config) -> Result<(), Error> {
+        for name in pkg.manifest.types.keys() {
+            let go_type = config.naming.pascal_name(name, &self.types.get(name));
+            self.type_names.insert(&go_type, name)?;
+            self.type_names
+                .insert(&format!("New{go_type}"), &format!("{name} constructor"))?;
+            self.typemap.insert(name.clone(), go_type);
+        }
+        Ok(())
+    }
+}
+
+impl Generate for Go {
+    fn bindings(&mut self, pkg: &Package, config: &mut Config) -> Result<(), Error> {
+        self.types = Types::new(&pkg.manifest)?;
+        self.resolve_names(pkg, config)?;
+
+        writeln!(
+            config.output_file,
+            "// Code generated by futhark-bindgen. DO NOT EDIT.\n"
+        )?;
+
+        let (backend_options, configure_backend) = match pkg.manifest.backend {
+            Backend::Multicore => (
+                "\tNumThreads int",
+                "\tC.futhark_context_config_set_num_threads(config, C.int(options.NumThreads))",
+            ),
+            Backend::CUDA | Backend::OpenCL => (
+                "\tDevice     string",
+                "\tif options.Device != \"\" {\n\t\tdevice := C.CString(options.Device)\n\t\tC.futhark_context_config_set_device(config, device)\n\t\tC.free(unsafe.Pointer(device))\n\t}",
+            ),
+            _ => ("", ""),
+        };
+
+        let mut ldflags = vec!["-lm".to_string()];
+        for lib in pkg.manifest.backend.required_c_libs() {
+            if *lib != "m" {
+                ldflags.push(format!("-l{lib}"));
+            }
+        }
+
+        let header = pkg
+            .h_file
+            .file_name()
+            .map(|x| x.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/go/context.go"),
+            package = package_name(&config.output_path),
+            ldflags = ldflags.join(" "),
+            header = header,
+            backend_options = backend_options,
+            configure_backend = configure_backend,
+        )?;
+
+        Ok(())
+    }
+
+    fn array_type(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        a: &manifest::ArrayType,
+    ) -> Result<(), Error> {
+        let futhark_type = self.types.get(name).cname().unwrap().to_string();
+        let go_type = &self.typemap[name];
+        let (go_elemtype, c_elemtype) = scalar_types(a.elemtype);
+
+        let dim_args: Vec<_> = (0..a.rank)
+            .map(|i| format!("C.int64_t(dims[{i}])"))
+            .collect();
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/go/array.go"),
+            futhark_type = futhark_type,
+            go_type = go_type,
+            rank = a.rank,
+            elemtype = a.elemtype.to_str(),
+            go_elemtype = go_elemtype,
+            c_elemtype = c_elemtype,
+            new_fn = a.ops.new,
+            free_fn = a.ops.free,
+            values_fn = a.ops.values,
+            shape_fn = a.ops.shape,
+            dim_args = dim_args.join(", "),
+        )?;
+
+        Ok(())
+    }
+
+    fn opaque_type(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        ty: &manifest::OpaqueType,
+    ) -> Result<(), Error> {
+        let futhark_type = self.types.get(name).cname().unwrap().to_string();
+        let go_type = self.typemap[name].clone();
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/go/opaque.go"),
+            futhark_type = futhark_type,
+            go_type = go_type,
+            free_fn = ty.ops.free,
+        )?;
+
+        let record = match &ty.record {
+            Some(r) => r,
+            None => return Ok(()),
+        };
+
+        let mut new_params = vec![];
+        let mut new_call_args = vec![];
+        let mut new_checks = String::new();
+        let mut new_keep_alive = String::new();
+        for field in record.fields.iter() {
+            let param = format!("field{}", field.name);
+            let t = self.get_type(&field.r#type);
+
+            let (c_field_type, output, zero) = match self.types.get(&field.r#type) {
+                TypeRef::Scalar(e) => {
+                    let (go, c) = scalar_types(e);
+                    new_call_args.push(format!("C.{c}({param})"));
+                    let zero = if go == "bool" { "false" } else { "0" };
+                    (format!("C.{c}"), format!("{go}(out)"), zero)
+                }
+                ty => {
+                    let cname = ty.cname().unwrap();
+                    new_call_args.push(format!("{param}.ptr"));
+                    new_checks.push_str(&format!(
+                        "\tif err := {param}.check(); err != nil {{\n\t\treturn nil, err\n\t}}\n"
+                    ));
+                    new_keep_alive.push_str(&format!("\truntime.KeepAlive({param})\n"));
+                    (
+                        format!("*C.struct_{cname}"),
+                        format!("new{}(t.ctx, out)", &t[1..]),
+                        "nil",
+                    )
+                }
+            };
+            new_params.push(format!("{param} {t}"));
+
+            writeln!(
+                config.output_file,
+                include_str!("templates/go/record_project.go"),
+                go_type = go_type,
+                getter = pascal_field_name(&field.name),
+                field_name = field.name,
+                go_field_type = t,
+                c_field_type = c_field_type,
+                project_fn = field.project,
+                zero = zero,
+                output = output,
+            )?;
+        }
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/go/record.go"),
+            go_type = go_type,
+            futhark_type = futhark_type,
+            new_fn = record.new,
+            free_fn = ty.ops.free,
+            new_params = new_params.join(", "),
+            new_call_args = new_call_args.join(", "),
+            new_checks = new_checks,
+            new_keep_alive = new_keep_alive,
+        )?;
+
+        Ok(())
+    }
+
+    fn entry(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        entry: &manifest::Entry,
+    ) -> Result<(), Error> {
+        let mut call_args = Vec::new();
+        let mut entry_params = Vec::new();
+        let mut entry_results = String::new();
+        let mut out_decl = String::new();
+        let mut entry_return = String::new();
+        let mut input_checks = String::new();
+        let mut keep_alive = String::new();
+
+        // Output arguments
+        for (i, arg) in entry.outputs.iter().enumerate() {
+            let t = self.get_type(&arg.r#type);
+            match self.types.get(&arg.r#type) {
+                TypeRef::Scalar(e) => {
+                    let (go, c) = scalar_types(e);
+                    out_decl.push_str(&format!("\tvar cout{i} C.{c}\n"));
+                    entry_return.push_str(&format!("\tout{i} = {go}(cout{i})\n"));
+                }
+                ty => {
+                    let cname = ty.cname().unwrap();
+                    out_decl.push_str(&format!("\tvar cout{i} *C.struct_{cname}\n"));
+                    entry_return.push_str(&format!("\tout{i} = new{}(ctx, cout{i})\n", &t[1..]));
+                }
+            }
+            entry_results.push_str(&format!("out{i} {t}, "));
+            call_args.push(format!("&cout{i}"));
+        }
+
+        // Input arguments
+        for (i, arg) in entry.inputs.iter().enumerate() {
+            let name = format!("input{i}");
+            let t = self.get_type(&arg.r#type);
+            match self.types.get(&arg.r#type) {
+                TypeRef::Scalar(e) => {
+                    call_args.push(format!("C.{}({name})", scalar_types(e).1));
+                }
+                _ => {
+                    input_checks.push_str(&format!(
+                        "\tif err = {name}.check(); err != nil {{\n\t\treturn\n\t}}\n"
+                    ));
+                    keep_alive.push_str(&format!("\truntime.KeepAlive({name})\n"));
+                    call_args.push(format!("{name}.ptr"));
+                }
+            }
+            entry_params.push(format!("{name} {t}"));
+        }
+
+        let entry_name = config
+            .naming
+            .entry_name_or(name, first_uppercase(&camel_case(name)));
+        self.entry_names.insert(&entry_name, name)?;
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/go/entry.go"),
+            name = name,
+            entry_fn = entry.cfun,
+            entry_name = entry_name,
+            entry_params = entry_params.join(", "),
+            entry_results = entry_results,
+            input_checks = input_checks,
+            out_decl = out_decl,
+            call_args = call_args.join(", "),
+            keep_alive = keep_alive,
+            entry_return = entry_return,
+        )?;
+
+        Ok(())
+    }
+
+    fn format(&mut self, path: &std::path::Path) -> Result<(), Error> {
+        let _ = std::process::Command::new("gofmt")
+            .arg("-w")
+            .arg(path)
+            .status();
+        Ok(())
+    }
+}
@@ -0,0 +1,408 @@
+use crate::generate::{
+    camel_case, first_lowercase, first_uppercase, module_name, pascal_field_name, Names, TypeRef,
+    Types,
+};
+use crate::*;
+use std::io::Write;
+
+/// Haskell codegen, generates a module named after the output file
+pub struct Haskell {
+    typemap: BTreeMap<String, String>,
+    types: Types,
+    type_names: Names,
+    value_names: Names,
+}
+
+// Types defined in `templates/haskell/context.hs` or imported by it
+const HASKELL_RESERVED_TYPES: &[&str] = &[
+    "Context",
+    "Options",
+    "FutharkException",
+    "FutharkContext",
+    "FutharkContextConfig",
+    "Bool",
+    "Double",
+    "Float",
+    "IO",
+    "Int",
+    "Maybe",
+    "Show",
+    "String",
+    "Ptr",
+    "ForeignPtr",
+    "IORef",
+];
+
+// Keywords and functions defined or used by the templates in `templates/haskell`
+const HASKELL_RESERVED_VALUES: &[&str] = &[
+    "case",
+    "class",
+    "data",
+    "default",
+    "deriving",
+    "do",
+    "else",
+    "foreign",
+    "if",
+    "import",
+    "in",
+    "infix",
+    "instance",
+    "let",
+    "module",
+    "newtype",
+    "of",
+    "then",
+    "type",
+    "where",
+    "newContext",
+    "freeContext",
+    "withContext",
+    "freeWith",
+    "defaultOptions",
+    "takeString",
+    "getError",
+    "report",
+    "check",
+    "sync",
+    "autoSync",
+    "clearCaches",
+    "pauseProfiling",
+    "unpauseProfiling",
+    "return",
+    "when",
+    "throwIO",
+    "alloca",
+    "free",
+    "peek",
+    "peekArray",
+    "fromBool",
+    "toBool",
+    "nullPtr",
+    "newCString",
+    "peekCString",
+    "withForeignPtr",
+    "finalizeForeignPtr",
+    "newIORef",
+    "readIORef",
+    "writeIORef",
+];
+
+/// Haskell type used for a scalar in the generated API and in foreign imports
+fn scalar_types(e: manifest::ElemType) -> (&'static str, &'static str) {
+    use manifest::ElemType::*;
+    match e {
+        I8 => ("Int8", "Int8"),
+        I16 => ("Int16", "Int16"),
+        I32 => ("Int32", "Int32"),
+        I64 => ("Int64", "Int64"),
+        U8 => ("Word8", "Word8"),
+        U16 => ("Word16", "Word16"),
+        U32 => ("Word32", "Word32"),
+        U64 => ("Word64", "Word64"),
+        // There is no `Half` type in base, `f16` values are passed as their bits
+        F16 => ("Word16", "Word16"),
+        F32 => ("Float", "Float"),
+        F64 => ("Double", "Double"),
+        Bool => ("Bool", "CBool"),
+    }
+}
+
+impl Default for Haskell {
+    fn default() -> Self {
+        Haskell {
+            typemap: BTreeMap::new(),
+            types: Types::default(),
+            type_names: Names::new(HASKELL_RESERVED_TYPES),
+            value_names: Names::new(HASKELL_RESERVED_VALUES),
+        }
+    }
+}
+
+impl Haskell {
+    /// Determine the Haskell names of all array and opaque types, along with the names of the
+    /// functions generated for each of them
+    fn resolve_names(&mut self, pkg: &Package, config: &Config) -> Result<(), Error> {
+        for (name, ty) in &pkg.manifest.types {
+            let t = self.types.get(name);
+            let hs_type = first_uppercase(&config.naming.pascal_name(name, &t));
+            self.type_names.insert(&hs_type, name)?;
+            self.type_names
+                .insert(&first_uppercase(t.cname().unwrap()), name)?;
+
+            let hs_fn = first_lowercase(&hs_type);
+            let mut functions = vec![
+                format!("wrap{hs_type}"),
+                format!("with{hs_type}"),
+                format!("free{hs_type}"),
+            ];
+            match ty {
+                manifest::Type::Array(_) => {
+                    functions.push(format!("new{hs_type}"));
+                    functions.push(format!("{hs_fn}Shape"));
+                    functions.push(format!("{hs_fn}ToVector"));
+                }
+                manifest::Type::Opaque(ty) => {
+                    if let Some(record) = &ty.record {
+                        functions.push(format!("new{hs_type}"));
+                        for field in &record.fields {
+                            functions.push(format!("{hs_fn}{}", pascal_field_name(&field.name)));
+                        }
+                    }
+                }
+            }
+            for f in functions {
+                self.value_names.insert(&f, name)?;
+            }
+
+            self.typemap.insert(name.clone(), hs_type);
+        }
+        Ok(())
+    }
+
+    /// Get the type used in the generated API and in foreign imports for a Futhark type
+    fn get_types(&self, t: &str) -> (String, String) {
+        match self.types.get(t) {
+            TypeRef::Scalar(e) => {
+                let (hs, c) = scalar_types(e);
+                (hs.to_string(), c.to_string())
+            }
+            ty => (
+                self.typemap[t].clone(),
+                format!("(Ptr {})", first_uppercase(ty.cname().unwrap())),
+            ),
+        }
+    }
+
+    /// Get the expression used to read a returned value from `out`
+    fn read_output(&self, t: &str, out: &str) -> String {
+        match self.types.get(t) {
+            TypeRef::Scalar(manifest::ElemType::Bool) => format!("toBool <$> peek {out}"),
+            TypeRef::Scalar(_) => format!("peek {out}"),
+            _ => format!("peek {out} >>= wrap{} ctx", self.typemap[t]),
+        }
+    }
+
+    /// Get the argument used to pass `x` to C, if `x` is an array or opaque value this adds a
+    /// `with*` call to `chain` to get the raw pointer
+    fn input_arg(&self, t: &str, x: &str, p: &str, chain: &mut Vec<String>) -> String {
+        match self.types.get(t) {
+            TypeRef::Scalar(manifest::ElemType::Bool) => format!("(fromBool {x})"),
+            TypeRef::Scalar(_) => x.to_string(),
+            _ => {
+                chain.push(format!("  with{} {x} $ \\{p} ->", self.typemap[t]));
+                p.to_string()
+            }
+        }
+    }
+}
+
+impl Generate for Haskell {
+    fn bindings(&mut self, pkg: &Package, config: &mut Config) -> Result<(), Error> {
+        self.types = Types::new(&pkg.manifest)?;
+        self.resolve_names(pkg, config)?;
+
+        writeln!(config.output_file, "-- Generated by futhark-bindgen\n")?;
+
+        let (backend_foreign_imports, backend_options, backend_defaults, configure_backend) =
+            match pkg.manifest.backend {
+                Backend::Multicore => (
+                    "foreign import ccall unsafe \"futhark_context_config_set_num_threads\" futhark_context_config_set_num_threads :: Ptr FutharkContextConfig -> CInt -> IO ()",
+                    ",\n    optNumThreads :: Int",
+                    ",\n      optNumThreads = 0",
+                    "  futhark_context_config_set_num_threads config (P.fromIntegral (optNumThreads opts))",
+                ),
+                Backend::CUDA | Backend::OpenCL => (
+                    "foreign import ccall unsafe \"futhark_context_config_set_device\" futhark_context_config_set_device :: Ptr FutharkContextConfig -> CString -> IO ()",
+                    ",\n    optDevice :: Maybe String",
+                    ",\n      optDevice = Nothing",
+                    "  P.maybe (return ()) (\\d -> withCString d (futhark_context_config_set_device config)) (optDevice opts)",
+                ),
+                _ => ("", "", "", ""),
+            };
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/haskell/context.hs"),
+            module_name = module_name(&config.output_path),
+            backend_foreign_imports = backend_foreign_imports,
+            backend_options = backend_options,
+            backend_defaults = backend_defaults,
+            configure_backend = configure_backend,
+        )?;
+
+        Ok(())
+    }
+
+    fn array_type(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        a: &manifest::ArrayType,
+    ) -> Result<(), Error> {
+        let hs_type = &self.typemap[name];
+        let c_type = first_uppercase(self.types.get(name).cname().unwrap());
+        let dim_types = vec!["Int64"; a.rank as usize];
+        let dim_args: Vec<_> = (0..a.rank).map(|i| format!("(dims P.!! {i})")).collect();
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/haskell/array.hs"),
+            hs_type = hs_type,
+            hs_fn = first_lowercase(hs_type),
+            c_type = c_type,
+            rank = a.rank,
+            elemtype = a.elemtype.to_str(),
+            hs_elemtype = scalar_types(a.elemtype).1,
+            new_fn = a.ops.new,
+            free_fn = a.ops.free,
+            values_fn = a.ops.values,
+            shape_fn = a.ops.shape,
+            dim_types = dim_types.join(" -> "),
+            dim_args = dim_args.join(" "),
+        )?;
+
+        Ok(())
+    }
+
+    fn opaque_type(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        ty: &manifest::OpaqueType,
+    ) -> Result<(), Error> {
+        let hs_type = &self.typemap[name];
+        let hs_fn = first_lowercase(hs_type);
+        let c_type = first_uppercase(self.types.get(name).cname().unwrap());
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/haskell/opaque.hs"),
+            hs_type = hs_type,
+            c_type = c_type,
+            free_fn = ty.ops.free,
+        )?;
+
+        let record = match &ty.record {
+            Some(r) => r,
+            None => return Ok(()),
+        };
+
+        let mut new_foreign_params = String::new();
+        let mut new_param_types = String::new();
+        let mut new_params = String::new();
+        let mut new_call_args = vec![];
+        let mut chain = vec!["  withContext ctx $ \\c ->".to_string()];
+        for (i, field) in record.fields.iter().enumerate() {
+            let (hs_field_type, c_field_type) = self.get_types(&field.r#type);
+            let param = format!("field{i}");
+
+            new_foreign_params.push_str(&format!("{c_field_type} -> "));
+            new_param_types.push_str(&format!("{hs_field_type} -> "));
+            new_params.push_str(&format!("{param} "));
+            new_call_args.push(self.input_arg(&field.r#type, &param, &format!("p{i}"), &mut chain));
+
+            writeln!(
+                config.output_file,
+                include_str!("templates/haskell/record_project.hs"),
+                hs_type = hs_type,
+                c_type = c_type,
+                getter = format!("{hs_fn}{}", pascal_field_name(&field.name)),
+                field_name = field.name,
+                hs_field_type = hs_field_type,
+                c_field_type = c_field_type,
+                project_fn = field.project,
+                output = self.read_output(&field.r#type, "out"),
+            )?;
+        }
+        chain.push("  alloca $ \\out ->".to_string());
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/haskell/record.hs"),
+            hs_type = hs_type,
+            c_type = c_type,
+            new_fn = record.new,
+            new_foreign_params = new_foreign_params,
+            new_param_types = new_param_types,
+            new_params = new_params,
+            new_call_args = new_call_args.join(" "),
+            chain = chain.join("\n"),
+        )?;
+
+        Ok(())
+    }
+
+    fn entry(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        entry: &manifest::Entry,
+    ) -> Result<(), Error> {
+        let mut foreign_params = String::new();
+        let mut param_types = String::new();
+        let mut params = String::new();
+        let mut call_args = vec![];
+        let mut chain = vec!["  withContext ctx $ \\c ->".to_string()];
+        let mut read_outputs = String::new();
+        let mut return_types = vec![];
+        let mut entry_return = vec![];
+
+        // Output arguments
+        for (i, arg) in entry.outputs.iter().enumerate() {
+            let (hs_type, c_type) = self.get_types(&arg.r#type);
+            foreign_params.push_str(&format!("Ptr {c_type} -> "));
+            chain.push(format!("  alloca $ \\out{i} ->"));
+            call_args.push(format!("out{i}"));
+            read_outputs.push_str(&format!(
+                "    r{i} <- {}\n",
+                self.read_output(&arg.r#type, &format!("out{i}"))
+            ));
+            return_types.push(hs_type);
+            entry_return.push(format!("r{i}"));
+        }
+
+        // Input arguments
+        for (i, arg) in entry.inputs.iter().enumerate() {
+            let (hs_type, c_type) = self.get_types(&arg.r#type);
+            let input = format!("input{i}");
+            foreign_params.push_str(&format!("{c_type} -> "));
+            param_types.push_str(&format!("{hs_type} -> "));
+            params.push_str(&format!("{input} "));
+            call_args.push(self.input_arg(&arg.r#type, &input, &format!("p{i}"), &mut chain));
+        }
+
+        let entry_name = config.naming.entry_name_or(name, camel_case(name));
+        self.value_names.insert(&entry_name, name)?;
+
+        let (return_type, entry_return) = match entry.outputs.len() {
+            0 => ("()".to_string(), "()".to_string()),
+            1 => (return_types.join(", "), entry_return.join(", ")),
+            _ => (
+                format!("({})", return_types.join(", ")),
+                format!("({})", entry_return.join(", ")),
+            ),
+        };
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/haskell/entry.hs"),
+            name = name,
+            entry_fn = entry.cfun,
+            entry_name = entry_name,
+            foreign_params = foreign_params,
+            param_types = param_types,
+            params = params,
+            chain = chain.join("\n"),
+            call_args = call_args.join(" "),
+            read_outputs = read_outputs,
+            return_type = return_type,
+            entry_return = entry_return,
+        )?;
+
+        Ok(())
+    }
+}
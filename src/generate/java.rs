@@ -0,0 +1,521 @@
+use crate::generate::{
+    camel_case, first_uppercase, module_name, pascal_field_name, Names, TypeRef, Types,
+};
+use crate::*;
+use std::io::Write;
+
+/// Java codegen using the Foreign Function and Memory API, generates a single class with nested
+/// classes for the context and each type
+pub struct Java {
+    typemap: BTreeMap<String, String>,
+    types: Types,
+    type_names: Names,
+    entry_names: Names,
+    entries: Vec<String>,
+}
+
+// Classes defined in `templates/java/bindings.java` and `templates/java/context.java`, along with
+// classes used without qualification
+const JAVA_RESERVED_TYPES: &[&str] = &[
+    "Context",
+    "Options",
+    "FutharkException",
+    "NullPtrException",
+    "ContextState",
+    "FutharkValue",
+    "Arena",
+    "FunctionDescriptor",
+    "Linker",
+    "MemorySegment",
+    "SymbolLookup",
+    "MethodHandle",
+    "Cleaner",
+    "Reference",
+    "Objects",
+    "Object",
+    "String",
+];
+
+// Methods of `Context` in `templates/java/context.java`, along with Java keywords
+const JAVA_RESERVED_METHODS: &[&str] = &[
+    "handle",
+    "close",
+    "check",
+    "maybeSync",
+    "setAutoSync",
+    "sync",
+    "clearCaches",
+    "pauseProfiling",
+    "unpauseProfiling",
+    "getError",
+    "report",
+    "equals",
+    "hashCode",
+    "toString",
+    "getClass",
+    "notify",
+    "notifyAll",
+    "wait",
+    "finalize",
+    "clone",
+    "abstract",
+    "assert",
+    "boolean",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "extends",
+    "false",
+    "final",
+    "finally",
+    "float",
+    "for",
+    "goto",
+    "if",
+    "implements",
+    "import",
+    "instanceof",
+    "int",
+    "interface",
+    "long",
+    "native",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "short",
+    "static",
+    "strictfp",
+    "super",
+    "switch",
+    "synchronized",
+    "this",
+    "throw",
+    "throws",
+    "transient",
+    "true",
+    "try",
+    "void",
+    "volatile",
+    "while",
+];
+
+/// Java type used for a scalar, Java has no unsigned types so they are passed using the signed
+/// type of the same size, `f16` values are passed as their bits
+fn scalar_type(e: manifest::ElemType) -> &'static str {
+    use manifest::ElemType::*;
+    match e {
+        I8 | U8 => "byte",
+        I16 | U16 | F16 => "short",
+        I32 | U32 => "int",
+        I64 | U64 => "long",
+        F32 => "float",
+        F64 => "double",
+        Bool => "boolean",
+    }
+}
+
+/// Memory layout used for a scalar
+fn scalar_layout(e: manifest::ElemType) -> &'static str {
+    use manifest::ElemType::*;
+    match e {
+        I8 | U8 => "JAVA_BYTE",
+        I16 | U16 | F16 => "JAVA_SHORT",
+        I32 | U32 => "JAVA_INT",
+        I64 | U64 => "JAVA_LONG",
+        F32 => "JAVA_FLOAT",
+        F64 => "JAVA_DOUBLE",
+        Bool => "JAVA_BOOLEAN",
+    }
+}
+
+/// Name of the constant holding the method handle for a C function
+fn handle_name(cfun: &str) -> String {
+    cfun.to_ascii_uppercase()
+}
+
+/// Java requires the public class to have the same name as the file, so the file stem is used
+/// when it is a valid identifier
+fn class_name(path: &std::path::Path) -> String {
+    let stem = path
+        .file_stem()
+        .map(|x| x.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let valid = stem.starts_with(|c: char| c.is_ascii_alphabetic())
+        && stem.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if valid {
+        stem
+    } else {
+        module_name(path)
+    }
+}
+
+impl Default for Java {
+    fn default() -> Self {
+        Java {
+            typemap: BTreeMap::new(),
+            types: Types::default(),
+            type_names: Names::new(JAVA_RESERVED_TYPES),
+            entry_names: Names::new(JAVA_RESERVED_METHODS),
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl Java {
+    /// Determine the class names of all array and opaque types
+    fn resolve_names(&mut self, pkg: &Package, config: &Config) -> Result<(), Error> {
+        for name in pkg.manifest.types.keys() {
+            let java_type = config.naming.pascal_name(name, &self.types.get(name));
+            self.type_names.insert(&java_type, name)?;
+            self.typemap.insert(name.clone(), java_type);
+        }
+        Ok(())
+    }
+
+    /// Get the Java type used for a Futhark type
+    fn get_type(&self, t: &str) -> String {
+        match self.types.get(t) {
+            TypeRef::Scalar(e) => scalar_type(e).to_string(),
+            _ => self.typemap[t].clone(),
+        }
+    }
+
+    /// Get the memory layout used to pass a Futhark type to a native function
+    fn layout(&self, t: &str) -> &'static str {
+        match self.types.get(t) {
+            TypeRef::Scalar(e) => scalar_layout(e),
+            _ => "ADDRESS",
+        }
+    }
+
+    /// Get the expression used to pass `x` to a native function
+    fn input_arg(&self, t: &str, x: &str) -> String {
+        match self.types.get(t) {
+            TypeRef::Scalar(_) => x.to_string(),
+            _ => format!("{x}.ptr()"),
+        }
+    }
+
+    /// Get the statement keeping `x` alive until a native function has returned, values are
+    /// freed by the cleaner once they are unreachable
+    fn fence(&self, t: &str, x: &str, indent: &str) -> String {
+        match self.types.get(t) {
+            TypeRef::Scalar(_) => String::new(),
+            _ => format!("{indent}Reference.reachabilityFence({x});\n"),
+        }
+    }
+
+    /// Get the expression used to read the output value stored in the segment `out`
+    fn output_value(&self, t: &str, out: &str, ctx: &str) -> String {
+        match self.types.get(t) {
+            TypeRef::Scalar(e) => format!("{out}.get({}, 0)", scalar_layout(e)),
+            _ => format!("new {}({ctx}, {out}.get(ADDRESS, 0))", self.typemap[t]),
+        }
+    }
+}
+
+impl Generate for Java {
+    fn bindings(&mut self, pkg: &Package, config: &mut Config) -> Result<(), Error> {
+        self.types = Types::new(&pkg.manifest)?;
+        let class_name = class_name(&config.output_path);
+        self.type_names.insert(&class_name, "bindings")?;
+        self.resolve_names(pkg, config)?;
+
+        writeln!(config.output_file, "// Generated by futhark-bindgen\n")?;
+
+        let (backend_option_fields, backend_option_setters) = match pkg.manifest.backend {
+            Backend::Multicore => (
+                "        int numThreads;\n",
+                "\n        /** Set the number of threads */\n        public Options numThreads(int numThreads) {\n            this.numThreads = numThreads;\n            return this;\n        }\n",
+            ),
+            Backend::CUDA | Backend::OpenCL => (
+                "        String device;\n",
+                "\n        /** Set the device */\n        public Options device(String device) {\n            this.device = device;\n            return this;\n        }\n",
+            ),
+            _ => ("", ""),
+        };
+
+        let stem = config
+            .output_path
+            .file_stem()
+            .map(|x| x.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/java/bindings.java"),
+            stem = stem,
+            class_name = class_name,
+            backend_option_fields = backend_option_fields,
+            backend_option_setters = backend_option_setters,
+        )?;
+
+        Ok(())
+    }
+
+    fn array_type(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        a: &manifest::ArrayType,
+    ) -> Result<(), Error> {
+        let layout = scalar_layout(a.elemtype);
+        let dim_layouts: Vec<_> = (0..a.rank).map(|_| "JAVA_LONG").collect();
+        let dim_args: Vec<_> = (0..a.rank).map(|i| format!("dims[{i}]")).collect();
+        let (to_segment, from_segment) = match a.elemtype {
+            manifest::ElemType::Bool => (
+                "toSegment(arena, data)".to_string(),
+                "toBooleans(out)".to_string(),
+            ),
+            _ => (
+                format!("arena.allocateFrom({layout}, data)"),
+                format!("out.toArray({layout})"),
+            ),
+        };
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/java/array.java"),
+            java_type = self.typemap[name],
+            rank = a.rank,
+            elemtype = a.elemtype.to_str(),
+            java_elemtype = scalar_type(a.elemtype),
+            layout = layout,
+            new_fn = a.ops.new,
+            free_fn = a.ops.free,
+            values_fn = a.ops.values,
+            shape_fn = a.ops.shape,
+            NEW_FN = handle_name(&a.ops.new),
+            FREE_FN = handle_name(&a.ops.free),
+            VALUES_FN = handle_name(&a.ops.values),
+            SHAPE_FN = handle_name(&a.ops.shape),
+            dim_layouts = dim_layouts.join(", "),
+            dim_args = dim_args.join(", "),
+            to_segment = to_segment,
+            from_segment = from_segment,
+        )?;
+
+        Ok(())
+    }
+
+    fn opaque_type(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        ty: &manifest::OpaqueType,
+    ) -> Result<(), Error> {
+        let java_type = &self.typemap[name];
+
+        let mut record_functions = String::new();
+        let mut record_methods = Vec::new();
+
+        if let Some(record) = &ty.record {
+            let mut new_params = vec![];
+            let mut new_layouts = vec!["ADDRESS", "ADDRESS"];
+            let mut new_call_args = vec![];
+            let mut new_fences = String::new();
+            for field in record.fields.iter() {
+                let param = format!("field{}", field.name);
+                let layout = self.layout(&field.r#type);
+
+                new_params.push(format!("{} {param}", self.get_type(&field.r#type)));
+                new_layouts.push(layout);
+                new_call_args.push(self.input_arg(&field.r#type, &param));
+                new_fences.push_str(&self.fence(&field.r#type, &param, "                "));
+
+                record_functions.push_str(&format!(
+                    "        private static final MethodHandle {} =\n            function(\"{}\", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));\n",
+                    handle_name(&field.project),
+                    field.project,
+                ));
+
+                let mut s = Vec::new();
+                write!(
+                    s,
+                    include_str!("templates/java/record_project.java"),
+                    field_name = field.name,
+                    field_type = self.get_type(&field.r#type),
+                    getter = format!("get{}", pascal_field_name(&field.name)),
+                    out_layout = layout,
+                    PROJECT_FN = handle_name(&field.project),
+                    output = self.output_value(&field.r#type, "out", "ctx"),
+                )?;
+                record_methods.push(String::from_utf8(s).unwrap());
+            }
+
+            record_functions.insert_str(
+                0,
+                &format!(
+                    "        private static final MethodHandle {} =\n            function(\"{}\", FunctionDescriptor.of(JAVA_INT, {}));\n",
+                    handle_name(&record.new),
+                    record.new,
+                    new_layouts.join(", "),
+                ),
+            );
+
+            let mut s = Vec::new();
+            write!(
+                s,
+                include_str!("templates/java/record.java"),
+                java_type = java_type,
+                NEW_FN = handle_name(&record.new),
+                new_params = new_params.join(", "),
+                new_call_args = new_call_args.join(", "),
+                new_fences = new_fences,
+            )?;
+            record_methods.insert(0, String::from_utf8(s).unwrap());
+        }
+
+        write!(
+            config.output_file,
+            include_str!("templates/java/opaque.java"),
+            java_type = java_type,
+            free_fn = ty.ops.free,
+            FREE_FN = handle_name(&ty.ops.free),
+            record_functions = record_functions,
+        )?;
+
+        for method in record_methods {
+            write!(config.output_file, "{method}")?;
+        }
+        writeln!(config.output_file, "    }}\n")?;
+
+        Ok(())
+    }
+
+    fn entry(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        entry: &manifest::Entry,
+    ) -> Result<(), Error> {
+        let mut layouts = vec!["ADDRESS"];
+        let mut call_args = vec!["handle()".to_string()];
+        let mut entry_params = Vec::new();
+        let mut out_decl = String::new();
+        let mut fences = String::new();
+        let mut outputs = Vec::new();
+        let mut output_types = Vec::new();
+
+        // Output arguments
+        for (i, arg) in entry.outputs.iter().enumerate() {
+            let out = format!("out{i}");
+            let layout = self.layout(&arg.r#type);
+            layouts.push("ADDRESS");
+            out_decl.push_str(&format!(
+                "                MemorySegment {out} = arena.allocate({layout});\n"
+            ));
+            call_args.push(out.clone());
+            outputs.push(self.output_value(&arg.r#type, &out, "this"));
+            output_types.push(self.get_type(&arg.r#type));
+        }
+
+        // Input arguments
+        for (i, arg) in entry.inputs.iter().enumerate() {
+            let input = format!("input{i}");
+            layouts.push(self.layout(&arg.r#type));
+            entry_params.push(format!("{} {input}", self.get_type(&arg.r#type)));
+            call_args.push(self.input_arg(&arg.r#type, &input));
+            fences.push_str(&self.fence(&arg.r#type, &input, "                "));
+        }
+
+        let entry_name = config.naming.entry_name_or(name, camel_case(name));
+        self.entry_names.insert(&entry_name, name)?;
+
+        let mut s = Vec::new();
+        let return_type = match outputs.len() {
+            0 => "void".to_string(),
+            1 => output_types[0].clone(),
+            _ => {
+                // Multiple outputs are returned using a record named after the entry point
+                let result_type = format!("{}Result", first_uppercase(&entry_name));
+                self.type_names.insert(&result_type, name)?;
+                let fields: Vec<_> = output_types
+                    .iter()
+                    .enumerate()
+                    .map(|(i, t)| format!("{t} out{i}"))
+                    .collect();
+                writeln!(
+                    s,
+                    "\n        /** Outputs of the {name} entry point */\n        public record {result_type}({}) {{\n        }}",
+                    fields.join(", ")
+                )?;
+                result_type
+            }
+        };
+
+        let entry_return = match outputs.len() {
+            0 => "                maybeSync();\n".to_string(),
+            1 => format!(
+                "                {return_type} result = {};\n                maybeSync();\n                return result;\n",
+                outputs[0]
+            ),
+            _ => format!(
+                "                {return_type} result = new {return_type}({});\n                maybeSync();\n                return result;\n",
+                outputs.join(", ")
+            ),
+        };
+
+        write!(
+            s,
+            include_str!("templates/java/entry.java"),
+            name = name,
+            entry_fn = entry.cfun,
+            ENTRY_FN = handle_name(&entry.cfun),
+            entry_name = entry_name,
+            layouts = layouts.join(", "),
+            return_type = return_type,
+            entry_params = entry_params.join(", "),
+            out_decl = out_decl,
+            call_args = call_args.join(", "),
+            fences = fences,
+            entry_return = entry_return,
+        )?;
+
+        // Entry points are methods of `Context`, which is written once all entries are known
+        self.entries.push(String::from_utf8(s).unwrap());
+
+        Ok(())
+    }
+
+    fn finish(&mut self, pkg: &Package, config: &mut Config) -> Result<(), Error> {
+        let (backend_functions, configure_backend) = match pkg.manifest.backend {
+            Backend::Multicore => (
+                "        static final MethodHandle FUTHARK_CONTEXT_CONFIG_SET_NUM_THREADS =\n            function(\"futhark_context_config_set_num_threads\", FunctionDescriptor.ofVoid(ADDRESS, JAVA_INT));\n",
+                "            if (options.numThreads != 0) {\n                call(FUTHARK_CONTEXT_CONFIG_SET_NUM_THREADS, config, options.numThreads);\n            }\n",
+            ),
+            Backend::CUDA | Backend::OpenCL => (
+                "        static final MethodHandle FUTHARK_CONTEXT_CONFIG_SET_DEVICE =\n            function(\"futhark_context_config_set_device\", FunctionDescriptor.ofVoid(ADDRESS, ADDRESS));\n",
+                "            if (options.device != null) {\n                call(FUTHARK_CONTEXT_CONFIG_SET_DEVICE, config, arena.allocateFrom(options.device));\n            }\n",
+            ),
+            _ => ("", ""),
+        };
+
+        write!(
+            config.output_file,
+            include_str!("templates/java/context.java"),
+            backend_functions = backend_functions,
+            configure_backend = configure_backend,
+            entries = self.entries.concat(),
+        )?;
+
+        Ok(())
+    }
+}
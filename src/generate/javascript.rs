@@ -0,0 +1,365 @@
+use crate::generate::{camel_case, pascal_field_name, Names, TypeRef, Types};
+use crate::*;
+use std::io::Write;
+
+/// JavaScript codegen for the WebAssembly backends, generates an ES module along with
+/// TypeScript declarations
+pub struct JavaScript {
+    typemap: BTreeMap<String, String>,
+    types: Types,
+    type_names: Names,
+    entry_names: Names,
+    dts_file: std::fs::File,
+}
+
+// Classes defined in `templates/javascript/context.js`
+const JS_RESERVED_TYPES: &[&str] = &[
+    "Context",
+    "Options",
+    "FutharkModule",
+    "FutharkError",
+    "NullPtrError",
+    "InvalidShapeError",
+];
+
+// Methods and fields defined on `Context` in `templates/javascript/context.js`
+const JS_RESERVED_METHODS: &[&str] = &[
+    "constructor",
+    "module",
+    "config",
+    "handle",
+    "autoSync",
+    "free",
+    "checkHandle",
+    "check",
+    "sync",
+    "maybeSync",
+    "clearCaches",
+    "pauseProfiling",
+    "unpauseProfiling",
+    "getError",
+    "report",
+    "withScratch",
+    "view",
+];
+
+/// Scalar info: TypeScript type, typed array used to access memory and size in bytes
+fn scalar_info(e: manifest::ElemType) -> (&'static str, &'static str, usize) {
+    use manifest::ElemType::*;
+    match e {
+        I8 => ("number", "Int8Array", 1),
+        I16 => ("number", "Int16Array", 2),
+        I32 => ("number", "Int32Array", 4),
+        I64 => ("bigint", "BigInt64Array", 8),
+        U8 => ("number", "Uint8Array", 1),
+        U16 => ("number", "Uint16Array", 2),
+        U32 => ("number", "Uint32Array", 4),
+        U64 => ("bigint", "BigUint64Array", 8),
+        // JavaScript has no 16-bit float type, `f16` values are passed as their bits
+        F16 => ("number", "Uint16Array", 2),
+        F32 => ("number", "Float32Array", 4),
+        F64 => ("number", "Float64Array", 8),
+        Bool => ("boolean", "Uint8Array", 1),
+    }
+}
+
+impl JavaScript {
+    /// Create new JavaScript codegen instance, declarations are written next to the output
+    /// file: `.d.ts` for `.js` and `.d.mts` for `.mjs`
+    pub fn new(config: &Config) -> Result<Self, Error> {
+        let ext = match config.output_path.extension() {
+            Some(x) if x == "mjs" => "d.mts",
+            _ => "d.ts",
+        };
+        let dts_file = std::fs::File::create(config.output_path.with_extension(ext))?;
+        Ok(JavaScript {
+            typemap: BTreeMap::new(),
+            types: Types::default(),
+            type_names: Names::new(JS_RESERVED_TYPES),
+            entry_names: Names::new(JS_RESERVED_METHODS),
+            dts_file,
+        })
+    }
+
+    /// Determine the class names of all array and opaque types
+    fn resolve_names(&mut self, pkg: &Package, config: &Config) -> Result<(), Error> {
+        for name in pkg.manifest.types.keys() {
+            let js_type = config.naming.pascal_name(name, &self.types.get(name));
+            self.type_names.insert(&js_type, name)?;
+            self.typemap.insert(name.clone(), js_type);
+        }
+        Ok(())
+    }
+
+    /// Get the TypeScript type used for a Futhark type
+    fn get_type(&self, t: &str) -> String {
+        match self.types.get(t) {
+            TypeRef::Scalar(e) => scalar_info(e).0.to_string(),
+            _ => self.typemap[t].clone(),
+        }
+    }
+
+    /// Get the expression used to pass `x` to a Futhark function
+    fn input_arg(&self, t: &str, x: &str) -> String {
+        match self.types.get(t) {
+            TypeRef::Scalar(manifest::ElemType::Bool) => format!("{x} ? 1 : 0"),
+            TypeRef::Scalar(_) => x.to_string(),
+            _ => format!("{x}.checkPtr()"),
+        }
+    }
+
+    /// Get the expression used to read a value returned at `ptr`
+    fn read_output(&self, t: &str, ptr: &str) -> String {
+        match self.types.get(t) {
+            TypeRef::Scalar(e) => {
+                let x = format!("ctx.view({}, {ptr}, 1)[0]", scalar_info(e).1);
+                if e == manifest::ElemType::Bool {
+                    format!("{x} !== 0")
+                } else {
+                    x
+                }
+            }
+            _ => format!(
+                "new {}(ctx, ctx.view(Uint32Array, {ptr}, 1)[0])",
+                self.typemap[t]
+            ),
+        }
+    }
+}
+
+impl Generate for JavaScript {
+    fn bindings(&mut self, pkg: &Package, config: &mut Config) -> Result<(), Error> {
+        // The bindings call the functions exported by Emscripten
+        if !matches!(pkg.manifest.backend, Backend::Wasm | Backend::WasmMulticore) {
+            return Err(Error::Unsupported(format!(
+                "JavaScript bindings require the wasm or wasm-multicore backend, not {}",
+                pkg.manifest.backend.to_str()
+            )));
+        }
+
+        self.types = Types::new(&pkg.manifest)?;
+        self.resolve_names(pkg, config)?;
+
+        writeln!(config.output_file, "// Generated by futhark-bindgen\n")?;
+        writeln!(self.dts_file, "// Generated by futhark-bindgen\n")?;
+
+        let (configure_backend, backend_options) = match pkg.manifest.backend {
+            Backend::WasmMulticore => (
+                "    if (options.numThreads !== undefined) {\n      m._futhark_context_config_set_num_threads(config, options.numThreads);\n    }",
+                "  numThreads?: number;",
+            ),
+            _ => ("", ""),
+        };
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/javascript/context.js"),
+            configure_backend = configure_backend,
+        )?;
+        writeln!(
+            self.dts_file,
+            include_str!("templates/javascript/context.d.ts"),
+            backend_options = backend_options,
+        )?;
+
+        Ok(())
+    }
+
+    fn array_type(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        a: &manifest::ArrayType,
+    ) -> Result<(), Error> {
+        let js_type = &self.typemap[name];
+        let (js_elemtype, typed_array, elem_size) = scalar_info(a.elemtype);
+        let to_typed = if js_elemtype == "bigint" {
+            format!("data instanceof {typed_array} ? data : Array.from(data, BigInt)")
+        } else {
+            "data".to_string()
+        };
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/javascript/array.js"),
+            js_type = js_type,
+            rank = a.rank,
+            elemtype = a.elemtype.to_str(),
+            typed_array = typed_array,
+            elem_size = elem_size,
+            to_typed = to_typed,
+            new_fn = a.ops.new,
+            free_fn = a.ops.free,
+            values_fn = a.ops.values,
+            shape_fn = a.ops.shape,
+        )?;
+
+        writeln!(
+            self.dts_file,
+            include_str!("templates/javascript/array.d.ts"),
+            js_type = js_type,
+            rank = a.rank,
+            elemtype = a.elemtype.to_str(),
+            js_elemtype = js_elemtype,
+            typed_array = typed_array,
+        )?;
+
+        Ok(())
+    }
+
+    fn opaque_type(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        ty: &manifest::OpaqueType,
+    ) -> Result<(), Error> {
+        let js_type = &self.typemap[name];
+
+        // Record constructors and accessors are declared inside of the class
+        let mut js_methods = Vec::new();
+        let mut dts_methods = Vec::new();
+
+        if let Some(record) = &ty.record {
+            let mut new_params = vec![];
+            let mut dts_params = vec![];
+            let mut new_call_args = vec![];
+            for field in record.fields.iter() {
+                let param = format!("field{}", field.name);
+                let t = self.get_type(&field.r#type);
+                let getter = format!("get{}", pascal_field_name(&field.name));
+
+                new_call_args.push(self.input_arg(&field.r#type, &param));
+                dts_params.push(format!("{param}: {t}"));
+                new_params.push(param);
+
+                let mut s = Vec::new();
+                write!(
+                    s,
+                    include_str!("templates/javascript/record_project.js"),
+                    field_name = field.name,
+                    getter = getter,
+                    project_fn = field.project,
+                    output = self.read_output(&field.r#type, "out"),
+                )?;
+                js_methods.push(String::from_utf8(s).unwrap());
+
+                let mut s = Vec::new();
+                write!(
+                    s,
+                    include_str!("templates/javascript/record_project.d.ts"),
+                    field_name = field.name,
+                    getter = getter,
+                    js_field_type = t,
+                )?;
+                dts_methods.push(String::from_utf8(s).unwrap());
+            }
+
+            let mut s = Vec::new();
+            write!(
+                s,
+                include_str!("templates/javascript/record.js"),
+                js_type = js_type,
+                new_fn = record.new,
+                new_params = new_params.join(", "),
+                new_call_args = new_call_args.join(", "),
+            )?;
+            js_methods.insert(0, String::from_utf8(s).unwrap());
+
+            let mut s = Vec::new();
+            write!(
+                s,
+                include_str!("templates/javascript/record.d.ts"),
+                js_type = js_type,
+                new_params = dts_params.join(", "),
+            )?;
+            dts_methods.insert(0, String::from_utf8(s).unwrap());
+        }
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/javascript/opaque.js"),
+            js_type = js_type,
+            free_fn = ty.ops.free,
+            record_methods = js_methods.join(""),
+        )?;
+
+        writeln!(
+            self.dts_file,
+            include_str!("templates/javascript/opaque.d.ts"),
+            js_type = js_type,
+            record_methods = dts_methods.join(""),
+        )?;
+
+        Ok(())
+    }
+
+    fn entry(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        entry: &manifest::Entry,
+    ) -> Result<(), Error> {
+        let mut entry_params = Vec::new();
+        let mut dts_params = Vec::new();
+        let mut call_args = Vec::new();
+        let mut return_types = Vec::new();
+        let mut entry_return = Vec::new();
+
+        // Each output gets an 8 byte slot in the scratch memory
+        for (i, arg) in entry.outputs.iter().enumerate() {
+            let ptr = if i == 0 {
+                "out".to_string()
+            } else {
+                format!("out + {}", i * 8)
+            };
+            entry_return.push(self.read_output(&arg.r#type, &ptr));
+            return_types.push(self.get_type(&arg.r#type));
+            call_args.push(ptr);
+        }
+
+        for (i, arg) in entry.inputs.iter().enumerate() {
+            let input = format!("input{i}");
+            call_args.push(self.input_arg(&arg.r#type, &input));
+            dts_params.push(format!("{input}: {}", self.get_type(&arg.r#type)));
+            entry_params.push(input);
+        }
+
+        let entry_name = config.naming.entry_name_or(name, camel_case(name));
+        self.entry_names.insert(&entry_name, name)?;
+
+        let (entry_return_type, entry_return) = match entry.outputs.len() {
+            0 => ("void".to_string(), "undefined".to_string()),
+            1 => (return_types.join(", "), entry_return.join(", ")),
+            _ => (
+                format!("[{}]", return_types.join(", ")),
+                format!("[{}]", entry_return.join(", ")),
+            ),
+        };
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/javascript/entry.js"),
+            name = name,
+            entry_name = entry_name,
+            entry_fn = entry.cfun,
+            entry_params = entry_params.join(", "),
+            scratch_size = entry.outputs.len() * 8,
+            call_args = call_args.join(", "),
+            entry_return = entry_return,
+        )?;
+
+        writeln!(
+            self.dts_file,
+            include_str!("templates/javascript/entry.d.ts"),
+            name = name,
+            entry_name = entry_name,
+            entry_params = dts_params.join(", "),
+            entry_return_type = entry_return_type,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,313 @@
+use crate::generate::{module_name, Names, TypeRef, Types};
+use crate::*;
+use std::io::Write;
+
+/// Julia codegen, generates a module named after the output file
+pub struct Julia {
+    typemap: BTreeMap<String, String>,
+    types: Types,
+    type_names: Names,
+    entry_names: Names,
+}
+
+// Types defined in `templates/julia/context.jl`
+const JULIA_RESERVED_TYPES: &[&str] = &[
+    "Context",
+    "FutharkError",
+    "NullPtrError",
+    "InvalidShapeError",
+    "LIB",
+];
+
+// Functions defined or called without qualification by the templates in `templates/julia`
+const JULIA_RESERVED_FUNCTIONS: &[&str] = &[
+    "free!",
+    "sync!",
+    "auto_sync!",
+    "clear_caches!",
+    "pause_profiling!",
+    "unpause_profiling!",
+    "get_error",
+    "report",
+    "check_handle",
+    "check_ptr",
+    "check_rc",
+    "take_string",
+    "row_major",
+    "size",
+    "prod",
+    "reverse",
+    "permutedims",
+    "ntuple",
+    "finalizer",
+    "error",
+    "print",
+    "unsafe_load",
+    "unsafe_string",
+];
+
+/// Julia type used for a scalar
+fn scalar_type(e: manifest::ElemType) -> &'static str {
+    use manifest::ElemType::*;
+    match e {
+        I8 => "Int8",
+        I16 => "Int16",
+        I32 => "Int32",
+        I64 => "Int64",
+        U8 => "UInt8",
+        U16 => "UInt16",
+        U32 => "UInt32",
+        U64 => "UInt64",
+        F16 => "Float16",
+        F32 => "Float32",
+        F64 => "Float64",
+        Bool => "Bool",
+    }
+}
+
+impl Default for Julia {
+    fn default() -> Self {
+        Julia {
+            typemap: BTreeMap::new(),
+            types: Types::default(),
+            type_names: Names::new(JULIA_RESERVED_TYPES),
+            entry_names: Names::new(JULIA_RESERVED_FUNCTIONS),
+        }
+    }
+}
+
+impl Julia {
+    /// Determine the Julia names of all array and opaque types
+    fn resolve_names(&mut self, pkg: &Package, config: &Config) -> Result<(), Error> {
+        for name in pkg.manifest.types.keys() {
+            let jl_type = config.naming.pascal_name(name, &self.types.get(name));
+            self.type_names.insert(&jl_type, name)?;
+            self.typemap.insert(name.clone(), jl_type);
+        }
+        Ok(())
+    }
+
+    /// Get the type used to pass a value to C and the expression used to wrap a returned
+    /// value, `out` is a `Ref` to the returned value
+    fn c_type(&self, t: &str, out: &str, ctx: &str) -> (String, String) {
+        match self.types.get(t) {
+            TypeRef::Scalar(e) => (scalar_type(e).to_string(), format!("{out}[]")),
+            _ => (
+                "Ptr{Cvoid}".to_string(),
+                format!("{}({ctx}, {out}[])", self.typemap[t]),
+            ),
+        }
+    }
+}
+
+impl Generate for Julia {
+    fn bindings(&mut self, pkg: &Package, config: &mut Config) -> Result<(), Error> {
+        self.types = Types::new(&pkg.manifest)?;
+        self.resolve_names(pkg, config)?;
+
+        writeln!(config.output_file, "# Generated by futhark-bindgen\n")?;
+
+        let (backend_kwargs, configure_backend) = match pkg.manifest.backend {
+            Backend::Multicore => (
+                ", num_threads::Integer=0",
+                "        ccall((:futhark_context_config_set_num_threads, LIB), Cvoid, (Ptr{Cvoid}, Cint), config, num_threads)",
+            ),
+            Backend::CUDA | Backend::OpenCL => (
+                ", device::Union{String,Nothing}=nothing",
+                "        if device !== nothing\n            ccall((:futhark_context_config_set_device, LIB), Cvoid, (Ptr{Cvoid}, Cstring), config, device)\n        end",
+            ),
+            _ => ("", ""),
+        };
+
+        let stem = config
+            .output_path
+            .file_stem()
+            .map(|x| x.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/julia/context.jl"),
+            module_name = module_name(&config.output_path),
+            stem = stem,
+            env_name = stem.to_ascii_uppercase().replace('-', "_"),
+            backend_kwargs = backend_kwargs,
+            configure_backend = configure_backend,
+        )?;
+
+        Ok(())
+    }
+
+    fn array_type(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        a: &manifest::ArrayType,
+    ) -> Result<(), Error> {
+        let dim_types = vec!["Int64"; a.rank as usize];
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/julia/array.jl"),
+            jl_type = self.typemap[name],
+            rank = a.rank,
+            elemtype = a.elemtype.to_str(),
+            jl_elemtype = scalar_type(a.elemtype),
+            new_fn = a.ops.new,
+            free_fn = a.ops.free,
+            values_fn = a.ops.values,
+            shape_fn = a.ops.shape,
+            dim_types = dim_types.join(", "),
+        )?;
+
+        Ok(())
+    }
+
+    fn opaque_type(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        ty: &manifest::OpaqueType,
+    ) -> Result<(), Error> {
+        let jl_type = &self.typemap[name];
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/julia/opaque.jl"),
+            jl_type = jl_type,
+            free_fn = ty.ops.free,
+        )?;
+
+        let record = match &ty.record {
+            Some(r) => r,
+            None => return Ok(()),
+        };
+
+        let mut new_params = vec![];
+        let mut new_arg_types = vec![];
+        let mut new_call_args = vec![];
+        let mut preserve = vec!["ctx".to_string()];
+        for field in record.fields.iter() {
+            let param = format!("field{}", field.name);
+            let (c_field_type, output) = self.c_type(&field.r#type, "out", "t.ctx");
+
+            let out_init = match self.types.get(&field.r#type) {
+                TypeRef::Scalar(_) => {
+                    new_params.push(param.clone());
+                    new_call_args.push(param);
+                    ""
+                }
+                _ => {
+                    new_params.push(format!("{param}::{}", self.typemap[&field.r#type]));
+                    new_call_args.push(format!("check_ptr({param})"));
+                    preserve.push(param);
+                    "C_NULL"
+                }
+            };
+            new_arg_types.push(c_field_type.clone());
+
+            writeln!(
+                config.output_file,
+                include_str!("templates/julia/record_project.jl"),
+                jl_type = jl_type,
+                field_name = field.name,
+                c_field_type = c_field_type,
+                out_init = out_init,
+                project_fn = field.project,
+                output = output,
+            )?;
+        }
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/julia/record.jl"),
+            jl_type = jl_type,
+            new_fn = record.new,
+            new_params = new_params.join(", "),
+            new_arg_types = new_arg_types.join(", "),
+            new_call_args = new_call_args.join(", "),
+            preserve = preserve.join(" "),
+        )?;
+
+        Ok(())
+    }
+
+    fn entry(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        entry: &manifest::Entry,
+    ) -> Result<(), Error> {
+        let mut entry_params = String::new();
+        let mut arg_types = Vec::new();
+        let mut call_args = Vec::new();
+        let mut out_decl = Vec::new();
+        let mut entry_return = Vec::new();
+        let mut preserve = vec!["ctx".to_string()];
+
+        // Output arguments
+        for (i, arg) in entry.outputs.iter().enumerate() {
+            let out = format!("out{i}");
+            let (c_type, output) = self.c_type(&arg.r#type, &out, "ctx");
+            let init = match self.types.get(&arg.r#type) {
+                TypeRef::Scalar(_) => "",
+                _ => "C_NULL",
+            };
+            out_decl.push(format!("    {out} = Ref{{{c_type}}}({init})"));
+            arg_types.push(format!("Ptr{{{c_type}}}"));
+            call_args.push(out);
+            entry_return.push(output);
+        }
+
+        // Input arguments
+        for (i, arg) in entry.inputs.iter().enumerate() {
+            let input = format!("input{i}");
+            let (c_type, _) = self.c_type(&arg.r#type, &input, "ctx");
+            match self.types.get(&arg.r#type) {
+                TypeRef::Scalar(_) => {
+                    entry_params.push_str(&format!(", {input}"));
+                    call_args.push(input);
+                }
+                _ => {
+                    entry_params.push_str(&format!(", {input}::{}", self.typemap[&arg.r#type]));
+                    call_args.push(format!("check_ptr({input})"));
+                    preserve.push(input);
+                }
+            }
+            arg_types.push(c_type);
+        }
+
+        let entry_name = config.naming.entry_name(name);
+        self.entry_names.insert(&entry_name, name)?;
+
+        let entry_return = match entry.outputs.len() {
+            0 => "nothing".to_string(),
+            1 => entry_return.join(", "),
+            _ => format!("({})", entry_return.join(", ")),
+        };
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/julia/entry.jl"),
+            name = name,
+            entry_name = entry_name,
+            entry_fn = entry.cfun,
+            entry_params = entry_params,
+            out_decl = out_decl.join("\n"),
+            preserve = preserve.join(" "),
+            arg_types = arg_types.join(", "),
+            call_args = call_args.join(", "),
+            entry_return = entry_return,
+        )?;
+
+        Ok(())
+    }
+
+    fn finish(&mut self, _pkg: &Package, config: &mut Config) -> Result<(), Error> {
+        writeln!(config.output_file, "end # module")?;
+        Ok(())
+    }
+}
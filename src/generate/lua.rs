@@ -0,0 +1,356 @@
+use crate::generate::{Names, TypeRef, Types};
+use crate::*;
+use std::io::Write;
+
+/// Lua codegen using the LuaJIT FFI, generates a module table
+pub struct Lua {
+    typemap: BTreeMap<String, String>,
+    types: Types,
+    type_names: Names,
+    entry_names: Names,
+}
+
+// Fields of the module table defined in `templates/lua/context.lua`
+const LUA_RESERVED_TYPES: &[&str] = &["Context"];
+
+// Methods and fields of `Context` in `templates/lua/context.lua`, along with Lua keywords
+const LUA_RESERVED_METHODS: &[&str] = &[
+    "new",
+    "free",
+    "check_handle",
+    "check",
+    "sync",
+    "maybe_sync",
+    "clear_caches",
+    "pause_profiling",
+    "unpause_profiling",
+    "get_error",
+    "report",
+    "handle",
+    "config",
+    "state",
+    "cache_file",
+    "auto_sync",
+    "and",
+    "break",
+    "do",
+    "else",
+    "elseif",
+    "end",
+    "false",
+    "for",
+    "function",
+    "goto",
+    "if",
+    "in",
+    "local",
+    "nil",
+    "not",
+    "or",
+    "repeat",
+    "return",
+    "then",
+    "true",
+    "until",
+    "while",
+];
+
+/// C type used for a scalar in `ffi.cdef`, `f16` values are passed as their bits
+fn c_scalar_type(e: manifest::ElemType) -> &'static str {
+    use manifest::ElemType::*;
+    match e {
+        I8 => "int8_t",
+        I16 => "int16_t",
+        I32 => "int32_t",
+        I64 => "int64_t",
+        U8 => "uint8_t",
+        U16 => "uint16_t",
+        U32 => "uint32_t",
+        U64 => "uint64_t",
+        F16 => "uint16_t",
+        F32 => "float",
+        F64 => "double",
+        Bool => "bool",
+    }
+}
+
+impl Default for Lua {
+    fn default() -> Self {
+        Lua {
+            typemap: BTreeMap::new(),
+            types: Types::default(),
+            type_names: Names::new(LUA_RESERVED_TYPES),
+            entry_names: Names::new(LUA_RESERVED_METHODS),
+        }
+    }
+}
+
+impl Lua {
+    /// Determine the names of all array and opaque types in the module table
+    fn resolve_names(&mut self, pkg: &Package, config: &Config) -> Result<(), Error> {
+        for name in pkg.manifest.types.keys() {
+            let lua_type = config.naming.pascal_name(name, &self.types.get(name));
+            self.type_names.insert(&lua_type, name)?;
+            self.typemap.insert(name.clone(), lua_type);
+        }
+        Ok(())
+    }
+
+    /// Get the C type used for a Futhark type
+    fn c_type(&self, t: &str) -> String {
+        match self.types.get(t) {
+            TypeRef::Scalar(e) => c_scalar_type(e).to_string(),
+            ty => format!("struct {} *", ty.cname().unwrap()),
+        }
+    }
+
+    /// Get the C type used for a Futhark type when it is passed as an input
+    fn c_input_type(&self, t: &str) -> String {
+        match self.types.get(t) {
+            TypeRef::Scalar(e) => c_scalar_type(e).to_string(),
+            ty => format!("const struct {} *", ty.cname().unwrap()),
+        }
+    }
+
+    /// Declare `name` with the given C type
+    fn c_decl(c_type: &str, name: &str) -> String {
+        if c_type.ends_with('*') {
+            format!("{c_type}{name}")
+        } else {
+            format!("{c_type} {name}")
+        }
+    }
+
+    /// Get the expression used to pass `x` to a Futhark function
+    fn input_arg(&self, t: &str, x: &str) -> String {
+        match self.types.get(t) {
+            TypeRef::Scalar(_) => x.to_string(),
+            _ => format!("check_ptr({x})"),
+        }
+    }
+
+    /// Get the expression used to read the value stored in the output array `out`
+    fn output_value(&self, t: &str, out: &str, ctx: &str) -> String {
+        match self.types.get(t) {
+            TypeRef::Scalar(_) => format!("{out}[0]"),
+            _ => format!("M.{}.wrap({ctx}, {out}[0])", self.typemap[t]),
+        }
+    }
+}
+
+impl Generate for Lua {
+    fn bindings(&mut self, pkg: &Package, config: &mut Config) -> Result<(), Error> {
+        self.types = Types::new(&pkg.manifest)?;
+        self.resolve_names(pkg, config)?;
+
+        writeln!(config.output_file, "-- Generated by futhark-bindgen\n")?;
+
+        let (backend_cdef, backend_option_fields, configure_backend) = match pkg.manifest.backend {
+            Backend::Multicore => (
+                "void futhark_context_config_set_num_threads(struct futhark_context_config *cfg, int n);\n",
+                ", `num_threads`",
+                "  if options.num_threads ~= nil then\n    lib.futhark_context_config_set_num_threads(config, options.num_threads)\n  end",
+            ),
+            Backend::CUDA | Backend::OpenCL => (
+                "void futhark_context_config_set_device(struct futhark_context_config *cfg, const char *s);\n",
+                ", `device`",
+                "  if options.device ~= nil then\n    lib.futhark_context_config_set_device(config, options.device)\n  end",
+            ),
+            _ => ("", "", ""),
+        };
+
+        let stem = config
+            .output_path
+            .file_stem()
+            .map(|x| x.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/lua/context.lua"),
+            stem = stem,
+            env_name = stem.to_ascii_uppercase().replace('-', "_"),
+            backend_cdef = backend_cdef,
+            backend_option_fields = backend_option_fields,
+            configure_backend = configure_backend,
+        )?;
+
+        Ok(())
+    }
+
+    fn array_type(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        a: &manifest::ArrayType,
+    ) -> Result<(), Error> {
+        let futhark_type = self.types.get(name).cname().unwrap().to_string();
+        let dim_params: Vec<_> = (0..a.rank).map(|i| format!("int64_t dim{i}")).collect();
+        let dim_args: Vec<_> = (1..=a.rank).map(|i| format!("dims[{i}]")).collect();
+        let shape: Vec<_> = (0..a.rank)
+            .map(|i| format!("tonumber(shape[{i}])"))
+            .collect();
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/lua/array.lua"),
+            futhark_type = futhark_type,
+            lua_type = self.typemap[name],
+            rank = a.rank,
+            elemtype = a.elemtype.to_str(),
+            c_elemtype = c_scalar_type(a.elemtype),
+            new_fn = a.ops.new,
+            free_fn = a.ops.free,
+            values_fn = a.ops.values,
+            shape_fn = a.ops.shape,
+            dim_params = dim_params.join(", "),
+            dim_args = dim_args.join(", "),
+            shape = shape.join(", "),
+        )?;
+
+        Ok(())
+    }
+
+    fn opaque_type(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        ty: &manifest::OpaqueType,
+    ) -> Result<(), Error> {
+        let futhark_type = self.types.get(name).cname().unwrap().to_string();
+        let lua_type = &self.typemap[name];
+
+        let mut record_cdef = String::new();
+        let mut record_methods = Vec::new();
+
+        if let Some(record) = &ty.record {
+            let mut new_params = vec![];
+            let mut new_cdef_params = vec![
+                "struct futhark_context *ctx".to_string(),
+                format!("struct {futhark_type} **out"),
+            ];
+            let mut new_call_args = vec![];
+            for field in record.fields.iter() {
+                let param = format!("field{}", field.name);
+                let c_type = self.c_type(&field.r#type);
+
+                new_cdef_params.push(Self::c_decl(&self.c_input_type(&field.r#type), &param));
+                new_call_args.push(self.input_arg(&field.r#type, &param));
+                new_params.push(param);
+
+                record_cdef.push_str(&format!(
+                    "int {}(struct futhark_context *ctx, {}, const struct {futhark_type} *obj);\n",
+                    field.project,
+                    Self::c_decl(&c_type, "*out"),
+                ));
+
+                let mut s = Vec::new();
+                write!(
+                    s,
+                    include_str!("templates/lua/record_project.lua"),
+                    lua_type = lua_type,
+                    field_name = field.name,
+                    project_fn = field.project,
+                    out_type = c_type,
+                    output = self.output_value(&field.r#type, "out", "self.ctx"),
+                )?;
+                record_methods.push(String::from_utf8(s).unwrap());
+            }
+
+            record_cdef.insert_str(
+                0,
+                &format!("int {}({});\n", record.new, new_cdef_params.join(", ")),
+            );
+
+            let mut s = Vec::new();
+            write!(
+                s,
+                include_str!("templates/lua/record.lua"),
+                lua_type = lua_type,
+                futhark_type = futhark_type,
+                new_fn = record.new,
+                new_params = new_params.join(", "),
+                new_call_args = new_call_args.join(", "),
+            )?;
+            record_methods.insert(0, String::from_utf8(s).unwrap());
+        }
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/lua/opaque.lua"),
+            futhark_type = futhark_type,
+            lua_type = lua_type,
+            free_fn = ty.ops.free,
+            record_cdef = record_cdef,
+        )?;
+
+        for method in record_methods {
+            writeln!(config.output_file, "{method}")?;
+        }
+
+        Ok(())
+    }
+
+    fn entry(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        entry: &manifest::Entry,
+    ) -> Result<(), Error> {
+        let mut cdef_params = vec!["struct futhark_context *ctx".to_string()];
+        let mut call_args = vec!["self:check_handle()".to_string()];
+        let mut entry_params = Vec::new();
+        let mut out_decl = String::new();
+        let mut entry_return = Vec::new();
+
+        // Output arguments
+        for (i, arg) in entry.outputs.iter().enumerate() {
+            let out = format!("out{i}");
+            let c_type = self.c_type(&arg.r#type);
+            cdef_params.push(Self::c_decl(&c_type, &format!("*{out}")));
+            out_decl.push_str(&format!("  local {out} = ffi.new(\"{c_type}[1]\")\n"));
+            call_args.push(out.clone());
+            entry_return.push(self.output_value(&arg.r#type, &out, "self"));
+        }
+
+        // Input arguments
+        for (i, arg) in entry.inputs.iter().enumerate() {
+            let input = format!("input{i}");
+            cdef_params.push(Self::c_decl(&self.c_input_type(&arg.r#type), &input));
+            call_args.push(self.input_arg(&arg.r#type, &input));
+            entry_params.push(input);
+        }
+
+        let entry_name = config.naming.entry_name(name);
+        self.entry_names.insert(&entry_name, name)?;
+
+        let entry_return = if entry_return.is_empty() {
+            String::new()
+        } else {
+            format!("  return {}\n", entry_return.join(", "))
+        };
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/lua/entry.lua"),
+            name = name,
+            entry_fn = entry.cfun,
+            entry_name = entry_name,
+            cdef_params = cdef_params.join(", "),
+            entry_params = entry_params.join(", "),
+            out_decl = out_decl,
+            call_args = call_args.join(", "),
+            entry_return = entry_return,
+        )?;
+
+        Ok(())
+    }
+
+    fn finish(&mut self, _pkg: &Package, config: &mut Config) -> Result<(), Error> {
+        writeln!(config.output_file, "return M")?;
+        Ok(())
+    }
+}
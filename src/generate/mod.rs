@@ -1,10 +1,30 @@
 use crate::*;
 
+mod c;
+mod csharp;
+mod go;
+mod haskell;
+mod java;
+mod javascript;
+mod julia;
+mod lua;
+mod nim;
 mod ocaml;
 mod rust;
+mod zig;
 
+pub use c::C;
+pub use csharp::CSharp;
+pub use go::Go;
+pub use haskell::Haskell;
+pub use java::Java;
+pub use javascript::JavaScript;
+pub use julia::Julia;
+pub use lua::Lua;
+pub use nim::Nim;
 pub use ocaml::OCaml;
 pub use rust::Rust;
+pub use zig::Zig;
 
 pub(crate) fn first_uppercase(s: &str) -> String {
     let mut s = s.to_string();
@@ -14,15 +34,370 @@ pub(crate) fn first_uppercase(s: &str) -> String {
     s
 }
 
-pub(crate) fn convert_struct_name(s: &str) -> &str {
-    s.strip_prefix("struct")
-        .unwrap()
-        .strip_suffix('*')
-        .unwrap()
-        .strip_prefix(|x: char| x.is_ascii_whitespace())
-        .unwrap()
-        .strip_suffix(|x: char| x.is_ascii_whitespace())
-        .unwrap()
+pub(crate) fn first_lowercase(s: &str) -> String {
+    let mut s = s.to_string();
+    if let Some(r) = s.get_mut(0..1) {
+        r.make_ascii_lowercase();
+    }
+    s
+}
+
+/// Convert a `snake_case` name to `camelCase`, `tup_mul` -> `tupMul`
+pub(crate) fn camel_case(s: &str) -> String {
+    let mut words = s.split('_').filter(|x| !x.is_empty());
+    let mut out = words.next().unwrap_or_default().to_string();
+    for w in words {
+        out.push_str(&first_uppercase(w));
+    }
+    out
+}
+
+/// `PascalCase` name of a record field, tuple fields are numeric so they get a `Field` prefix
+pub(crate) fn pascal_field_name(field: &str) -> String {
+    if field.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        first_uppercase(&camel_case(field))
+    } else {
+        format!("Field{field}")
+    }
+}
+
+/// `PascalCase` module name derived from the name of the output file, `my-lib.jl` -> `MyLib`
+pub(crate) fn module_name(path: &std::path::Path) -> String {
+    path.file_stem()
+        .map(|x| x.to_string_lossy().into_owned())
+        .unwrap_or_default()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .map(first_uppercase)
+        .collect()
+}
+
+/// Extract the struct name from a C pointer type, `struct futhark_f32_1d *` -> `futhark_f32_1d`
+pub(crate) fn c_struct_name(ctype: &str) -> Result<&str, Error> {
+    let name = ctype
+        .trim()
+        .strip_prefix("struct")
+        .and_then(|x| x.strip_suffix('*'))
+        .map(|x| x.trim())
+        .unwrap_or_default();
+    let valid = name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if name.is_empty() || !valid {
+        return Err(Error::UnsupportedType(ctype.to_string()));
+    }
+    Ok(name)
+}
+
+/// A Futhark type reference from the manifest, resolved to the kind of type it refers to
+#[derive(Debug, Clone)]
+pub(crate) enum TypeRef {
+    /// Scalar value
+    Scalar(manifest::ElemType),
+
+    /// Array of scalars
+    Array {
+        cname: String,
+        elemtype: manifest::ElemType,
+        rank: i32,
+    },
+
+    /// Opaque value without any further information
+    Opaque { cname: String },
+
+    /// Opaque record
+    Record { cname: String },
+
+    /// Opaque sum type
+    Sum { cname: String },
+}
+
+impl TypeRef {
+    /// Name of the C struct used to represent non-scalar types
+    pub(crate) fn cname(&self) -> Option<&str> {
+        match self {
+            TypeRef::Scalar(_) => None,
+            TypeRef::Array { cname, .. }
+            | TypeRef::Opaque { cname }
+            | TypeRef::Record { cname }
+            | TypeRef::Sum { cname } => Some(cname),
+        }
+    }
+}
+
+/// All types used by a manifest, resolved once and shared by the steps of a generator
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Types {
+    map: BTreeMap<String, TypeRef>,
+    scalar_records: BTreeMap<String, Vec<(String, manifest::ElemType)>>,
+}
+
+impl Types {
+    /// Resolve the types defined in a manifest, returns an error if any type definition or
+    /// reference can't be resolved
+    pub(crate) fn new(manifest: &Manifest) -> Result<Types, Error> {
+        let mut map = BTreeMap::new();
+        let mut scalar_records = BTreeMap::new();
+        for (name, ty) in &manifest.types {
+            let t = match ty {
+                manifest::Type::Array(a) => TypeRef::Array {
+                    cname: c_struct_name(&a.ctype)?.to_string(),
+                    elemtype: a.elemtype,
+                    rank: a.rank,
+                },
+                manifest::Type::Opaque(ty) => {
+                    let cname = c_struct_name(&ty.ctype)?.to_string();
+                    let fields: Option<Vec<_>> = ty
+                        .record
+                        .iter()
+                        .flat_map(|r| &r.fields)
+                        .map(|f| {
+                            manifest::ElemType::from_name(&f.r#type).map(|e| (f.name.clone(), e))
+                        })
+                        .collect();
+                    if let Some(fields) = fields.filter(|f| !f.is_empty()) {
+                        scalar_records.insert(name.clone(), fields);
+                    }
+                    match (&ty.record, &ty.sum) {
+                        (Some(_), _) => TypeRef::Record { cname },
+                        (None, Some(_)) => TypeRef::Sum { cname },
+                        (None, None) => TypeRef::Opaque { cname },
+                    }
+                }
+            };
+            map.insert(name.clone(), t);
+        }
+
+        let types = Types {
+            map,
+            scalar_records,
+        };
+
+        // Make sure all references can be resolved
+        for ty in manifest.types.values() {
+            if let manifest::Type::Opaque(ty) = ty {
+                for f in ty.record.iter().flat_map(|r| r.fields.iter()) {
+                    types.resolve(&f.r#type)?;
+                }
+                for v in ty.sum.iter().flat_map(|s| s.variants.iter()) {
+                    for t in &v.payload {
+                        types.resolve(t)?;
+                    }
+                }
+            }
+        }
+
+        for entry in manifest.entry_points.values() {
+            for t in entry.inputs.iter().map(|x| &x.r#type) {
+                types.resolve(t)?;
+            }
+            for t in entry.outputs.iter().map(|x| &x.r#type) {
+                types.resolve(t)?;
+            }
+        }
+
+        Ok(types)
+    }
+
+    fn resolve(&self, name: &str) -> Result<TypeRef, Error> {
+        if let Some(e) = manifest::ElemType::from_name(name) {
+            return Ok(TypeRef::Scalar(e));
+        }
+
+        self.map
+            .get(name)
+            .cloned()
+            .ok_or_else(|| Error::UnsupportedType(name.to_string()))
+    }
+
+    /// Get the fields of a record that only contains scalar values
+    pub(crate) fn scalar_record(&self, name: &str) -> Option<&[(String, manifest::ElemType)]> {
+        self.scalar_records.get(name).map(|x| x.as_slice())
+    }
+
+    /// Get a type by name, this should only be called with names from the same manifest
+    pub(crate) fn get(&self, name: &str) -> TypeRef {
+        self.resolve(name).expect("Unresolved type")
+    }
+}
+
+/// Case style used for generated type names
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Case {
+    /// Use the default names for each language, e.g. `ArrayF32D1` in Rust and
+    /// `Array_f32_1d` in OCaml
+    #[default]
+    Default,
+
+    /// `PascalCase`, e.g. `ArrayF32D1`
+    Pascal,
+
+    /// `snake_case`, e.g. `array_f32_d1`
+    Snake,
+}
+
+impl Case {
+    /// Return the case style specified by the given name if valid
+    pub fn from_name(name: &str) -> Option<Case> {
+        match name.to_ascii_lowercase().as_str() {
+            "default" => Some(Case::Default),
+            "pascal" => Some(Case::Pascal),
+            "snake" => Some(Case::Snake),
+            _ => None,
+        }
+    }
+
+    fn apply(&self, words: &[String]) -> String {
+        match self {
+            Case::Default | Case::Pascal => words.iter().map(|w| first_uppercase(w)).collect(),
+            Case::Snake => words.join("_"),
+        }
+    }
+}
+
+/// Naming policy for generated types and entry points
+#[derive(Debug, Clone, Default)]
+pub struct Naming {
+    /// Prefix added to generated type names
+    pub prefix: String,
+
+    /// Suffix added to generated type names
+    pub suffix: String,
+
+    /// Case style for generated type names
+    pub case: Case,
+
+    /// Explicit names for types and entry points, keyed by their Futhark name. These are used
+    /// as-is, without adding `prefix` or `suffix`
+    pub renames: BTreeMap<String, String>,
+}
+
+impl Naming {
+    /// Add an explicit name for a type or entry point
+    pub fn rename(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        self.renames.insert(from.into(), to.into());
+        self
+    }
+
+    fn type_name(&self, name: &str, default: String, words: &[String]) -> String {
+        if let Some(x) = self.renames.get(name) {
+            return x.clone();
+        }
+
+        let base = match self.case {
+            Case::Default => default,
+            case => case.apply(words),
+        };
+        format!("{}{base}{}", self.prefix, self.suffix)
+    }
+
+    /// Get the name of an array type, `default` is used when no case style is specified
+    pub(crate) fn array_name(
+        &self,
+        name: &str,
+        elemtype: manifest::ElemType,
+        rank: i32,
+        default: String,
+    ) -> String {
+        let words = [
+            "array".to_string(),
+            elemtype.to_str().to_string(),
+            format!("d{rank}"),
+        ];
+        self.type_name(name, default, &words)
+    }
+
+    /// Get the name of an opaque type from the name of its C struct, `default` is used when no
+    /// case style is specified
+    pub(crate) fn opaque_name(&self, name: &str, cname: &str, default: String) -> String {
+        let base = opaque_base_name(cname);
+        let mut words: Vec<String> = base.split('_').map(|x| x.to_string()).collect();
+        if needs_type_prefix(name, base) {
+            words.insert(0, "type".to_string());
+        }
+        self.type_name(name, default, &words)
+    }
+
+    /// Get the name of an entry point
+    pub(crate) fn entry_name(&self, name: &str) -> String {
+        self.entry_name_or(name, name.to_string())
+    }
+
+    /// Get the name of an entry point, using `default` if it hasn't been renamed
+    pub(crate) fn entry_name_or(&self, name: &str, default: String) -> String {
+        self.renames.get(name).cloned().unwrap_or(default)
+    }
+
+    /// Get the `PascalCase` name of an array or opaque type, the default for most languages,
+    /// e.g. `ArrayF32D1` or `Point`
+    pub(crate) fn pascal_name(&self, name: &str, t: &TypeRef) -> String {
+        match t {
+            TypeRef::Array { elemtype, rank, .. } => self.array_name(
+                name,
+                *elemtype,
+                *rank,
+                format!("Array{}D{rank}", first_uppercase(elemtype.to_str())),
+            ),
+            t => {
+                let cname = t.cname().expect("Scalar types are not named");
+                let base = opaque_base_name(cname);
+                let mut default = first_uppercase(base);
+                if needs_type_prefix(name, base) {
+                    default = format!("Type{default}");
+                }
+                self.opaque_name(name, cname, default)
+            }
+        }
+    }
+}
+
+/// Strip the `futhark_opaque_` prefix from the name of an opaque C struct
+pub(crate) fn opaque_base_name(cname: &str) -> &str {
+    cname.strip_prefix("futhark_opaque_").unwrap_or(cname)
+}
+
+/// Check if the name generated from `base` for the Futhark type `name` needs a `type` prefix to
+/// be a valid identifier: `base` is empty or starts with a digit, or `name` contains a space
+/// (tuples and other anonymous types are named after a hash, e.g. `futhark_opaque_2b0ad0ae`)
+pub(crate) fn needs_type_prefix(name: &str, base: &str) -> bool {
+    !base.starts_with(|c: char| !c.is_numeric()) || name.contains(' ')
+}
+
+/// Tracks generated names to detect collisions
+pub(crate) struct Names {
+    names: BTreeMap<String, String>,
+}
+
+impl Names {
+    /// Create a new set of names, `reserved` contains names that are already used by the
+    /// generated code
+    pub(crate) fn new(reserved: &[&str]) -> Names {
+        let names = reserved
+            .iter()
+            .map(|x| (x.to_string(), String::new()))
+            .collect();
+        Names { names }
+    }
+
+    /// Reserve additional names, used for code that is only generated for some configs
+    pub(crate) fn reserve(&mut self, reserved: &[&str]) {
+        for x in reserved {
+            self.names.insert(x.to_string(), String::new());
+        }
+    }
+
+    /// Register `name`, generated for the Futhark type or entry point `source`
+    pub(crate) fn insert(&mut self, name: &str, source: &str) -> Result<(), Error> {
+        match self.names.get(name) {
+            Some(x) if x.is_empty() => Err(Error::NameCollision(format!(
+                "`{name}` (generated for `{source}`) is reserved"
+            ))),
+            Some(x) => Err(Error::NameCollision(format!(
+                "`{name}` is generated for both `{x}` and `{source}`"
+            ))),
+            None => {
+                self.names.insert(name.to_string(), source.to_string());
+                Ok(())
+            }
+        }
+    }
 }
 
 /// Code generation config
@@ -32,6 +407,33 @@ pub struct Config {
 
     /// Path to output file
     pub output_file: std::fs::File,
+
+    /// Naming policy
+    pub naming: Naming,
+
+    /// Generate async variants of entry points and array accessors, currently only supported
+    /// by the Rust generator
+    pub async_api: bool,
+
+    /// Generate a handle that runs a context on a worker thread, currently only supported by the
+    /// Rust generator
+    pub context_handle: bool,
+
+    /// Return a named struct from entry points with multiple outputs instead of a tuple,
+    /// currently only supported by the Rust and OCaml generators
+    pub output_structs: bool,
+
+    /// Map records that only contain scalar fields to plain host types, which are converted at
+    /// entry points, currently only supported by the Rust and OCaml generators
+    pub plain_records: bool,
+
+    /// Generate host copies of arrays and records that can be serialized using `serde`,
+    /// currently only supported by the Rust generator
+    pub serde: bool,
+
+    /// Generate a `Report` type for parsed profiling reports, currently only supported by the
+    /// Rust and OCaml generators
+    pub parsed_report: bool,
 }
 
 impl Config {
@@ -40,8 +442,57 @@ impl Config {
         Ok(Config {
             output_path: output.as_ref().to_path_buf(),
             output_file: std::fs::File::create(output)?,
+            naming: Naming::default(),
+            async_api: false,
+            context_handle: false,
+            output_structs: false,
+            plain_records: false,
+            serde: false,
+            parsed_report: false,
         })
     }
+
+    /// Set the naming policy
+    pub fn with_naming(mut self, naming: Naming) -> Self {
+        self.naming = naming;
+        self
+    }
+
+    /// Enable async entry points and array accessors
+    pub fn with_async_api(mut self, async_api: bool) -> Self {
+        self.async_api = async_api;
+        self
+    }
+
+    /// Enable the context handle
+    pub fn with_context_handle(mut self, context_handle: bool) -> Self {
+        self.context_handle = context_handle;
+        self
+    }
+
+    /// Enable named output structs for entry points with multiple outputs
+    pub fn with_output_structs(mut self, output_structs: bool) -> Self {
+        self.output_structs = output_structs;
+        self
+    }
+
+    /// Enable plain host types for records with only scalar fields
+    pub fn with_plain_records(mut self, plain_records: bool) -> Self {
+        self.plain_records = plain_records;
+        self
+    }
+
+    /// Enable serializable host copies of arrays and records
+    pub fn with_serde(mut self, serde: bool) -> Self {
+        self.serde = serde;
+        self
+    }
+
+    /// Enable parsed profiling reports
+    pub fn with_parsed_report(mut self, parsed_report: bool) -> Self {
+        self.parsed_report = parsed_report;
+        self
+    }
 }
 
 pub trait Generate {
@@ -62,6 +513,7 @@ pub trait Generate {
         for (name, entry) in &pkg.manifest.entry_points {
             self.entry(pkg, config, name, entry)?;
         }
+        self.finish(pkg, config)?;
         self.format(&config.output_path)?;
         Ok(())
     }
@@ -96,7 +548,12 @@ pub trait Generate {
         entry: &manifest::Entry,
     ) -> Result<(), Error>;
 
-    /// Step 5: Optionally, run any formatting program or post-processing on the output file
+    /// Step 5: Optionally, generate any code that has to come after everything else
+    fn finish(&mut self, _pkg: &Package, _config: &mut Config) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Step 6: Optionally, run any formatting program or post-processing on the output file
     fn format(&mut self, _output: &std::path::Path) -> Result<(), Error> {
         Ok(())
     }
@@ -110,6 +567,46 @@ fn ocaml(config: &Config) -> Box<impl Generate> {
     Box::new(OCaml::new(config).unwrap())
 }
 
+fn go() -> Box<impl Generate> {
+    Box::<Go>::default()
+}
+
+fn julia() -> Box<impl Generate> {
+    Box::<Julia>::default()
+}
+
+fn haskell() -> Box<impl Generate> {
+    Box::<Haskell>::default()
+}
+
+fn c() -> Box<impl Generate> {
+    Box::<C>::default()
+}
+
+fn csharp() -> Box<impl Generate> {
+    Box::<CSharp>::default()
+}
+
+fn java() -> Box<impl Generate> {
+    Box::<Java>::default()
+}
+
+fn javascript(config: &Config) -> Box<impl Generate> {
+    Box::new(JavaScript::new(config).unwrap())
+}
+
+fn lua() -> Box<impl Generate> {
+    Box::<Lua>::default()
+}
+
+fn nim() -> Box<impl Generate> {
+    Box::<Nim>::default()
+}
+
+fn zig() -> Box<impl Generate> {
+    Box::<Zig>::default()
+}
+
 impl Config {
     /// Automatically detect output language
     pub fn detect(&self) -> Option<Box<dyn Generate>> {
@@ -120,6 +617,16 @@ impl Config {
         {
             Some("rs") => Some(rust()),
             Some("ml") => Some(ocaml(self)),
+            Some("zig") => Some(zig()),
+            Some("go") => Some(go()),
+            Some("jl") => Some(julia()),
+            Some("hs") => Some(haskell()),
+            Some("js" | "mjs") => Some(javascript(self)),
+            Some("cs") => Some(csharp()),
+            Some("lua") => Some(lua()),
+            Some("java") => Some(java()),
+            Some("nim") => Some(nim()),
+            Some("h") => Some(c()),
             _ => None,
         }
     }
@@ -0,0 +1,442 @@
+use crate::generate::{camel_case, pascal_field_name, Names, TypeRef, Types};
+use crate::*;
+use std::io::Write;
+
+/// Nim codegen, generates a module with `importc` procs and destructors for every type
+pub struct Nim {
+    typemap: BTreeMap<String, String>,
+    types: Types,
+    type_names: Names,
+    entry_names: Names,
+}
+
+// Types defined in `templates/nim/context.nim`, normalized using `nim_ident`
+const NIM_RESERVED_TYPES: &[&str] = &[
+    "Context",
+    "Contextobj",
+    "Options",
+    "Futharkerror",
+    "Nullptrerror",
+    "Invalidshapeerror",
+];
+
+// Procs and constants defined in `templates/nim/context.nim` along with Nim keywords, normalized
+// using `nim_ident`
+const NIM_RESERVED_PROCS: &[&str] = &[
+    "futharklib",
+    "libname",
+    "cfree",
+    "initoptions",
+    "newcontext",
+    "takestring",
+    "geterror",
+    "report",
+    "check",
+    "sync",
+    "maybesync",
+    "clearcaches",
+    "pauseprofiling",
+    "unpauseprofiling",
+    "checkptr",
+    "wrap",
+    "addr",
+    "and",
+    "as",
+    "asm",
+    "bind",
+    "block",
+    "break",
+    "case",
+    "cast",
+    "concept",
+    "const",
+    "continue",
+    "converter",
+    "defer",
+    "discard",
+    "distinct",
+    "div",
+    "do",
+    "elif",
+    "else",
+    "end",
+    "enum",
+    "except",
+    "export",
+    "finally",
+    "for",
+    "from",
+    "func",
+    "if",
+    "import",
+    "in",
+    "include",
+    "interface",
+    "is",
+    "isnot",
+    "iterator",
+    "let",
+    "macro",
+    "method",
+    "mixin",
+    "mod",
+    "nil",
+    "not",
+    "notin",
+    "object",
+    "of",
+    "or",
+    "out",
+    "proc",
+    "ptr",
+    "raise",
+    "ref",
+    "return",
+    "shl",
+    "shr",
+    "static",
+    "template",
+    "try",
+    "tuple",
+    "type",
+    "using",
+    "var",
+    "when",
+    "while",
+    "xor",
+    "yield",
+];
+
+/// Nim identifiers are compared ignoring underscores and the case of all but the first
+/// character, so names are normalized before checking for collisions
+fn nim_ident(name: &str) -> String {
+    let mut chars = name.chars().filter(|c| *c != '_');
+    let mut out = chars.next().map(String::from).unwrap_or_default();
+    out.extend(chars.map(|c| c.to_ascii_lowercase()));
+    out
+}
+
+/// Nim type used for a scalar, `f16` values are passed as their bits
+fn scalar_type(e: manifest::ElemType) -> &'static str {
+    use manifest::ElemType::*;
+    match e {
+        I8 => "int8",
+        I16 => "int16",
+        I32 => "int32",
+        I64 => "int64",
+        U8 => "uint8",
+        U16 => "uint16",
+        U32 => "uint32",
+        U64 => "uint64",
+        F16 => "uint16",
+        F32 => "float32",
+        F64 => "float64",
+        Bool => "bool",
+    }
+}
+
+/// Get the free function for an array or opaque type
+fn free_fn(ty: &manifest::Type) -> &str {
+    match ty {
+        manifest::Type::Array(a) => &a.ops.free,
+        manifest::Type::Opaque(ty) => &ty.ops.free,
+    }
+}
+
+impl Default for Nim {
+    fn default() -> Self {
+        Nim {
+            typemap: BTreeMap::new(),
+            types: Types::default(),
+            type_names: Names::new(NIM_RESERVED_TYPES),
+            entry_names: Names::new(NIM_RESERVED_PROCS),
+        }
+    }
+}
+
+impl Nim {
+    /// Determine the names of all array and opaque types, along with their constructors
+    fn resolve_names(&mut self, pkg: &Package, config: &Config) -> Result<(), Error> {
+        for name in pkg.manifest.types.keys() {
+            let nim_type = config.naming.pascal_name(name, &self.types.get(name));
+            self.type_names.insert(&nim_ident(&nim_type), name)?;
+            self.entry_names
+                .insert(&nim_ident(&format!("new{nim_type}")), name)?;
+            self.typemap.insert(name.clone(), nim_type);
+        }
+        Ok(())
+    }
+
+    /// Get the Nim type used for a Futhark type
+    fn get_type(&self, t: &str) -> String {
+        match self.types.get(t) {
+            TypeRef::Scalar(e) => scalar_type(e).to_string(),
+            _ => self.typemap[t].clone(),
+        }
+    }
+
+    /// Get the type used to pass a Futhark type to a C function
+    fn c_type(&self, t: &str) -> String {
+        match self.types.get(t) {
+            TypeRef::Scalar(e) => scalar_type(e).to_string(),
+            ty => format!("ptr {}", ty.cname().unwrap()),
+        }
+    }
+
+    /// Get the expression used to pass `x` to a C function
+    fn input_arg(&self, t: &str, x: &str) -> String {
+        match self.types.get(t) {
+            TypeRef::Scalar(_) => x.to_string(),
+            _ => format!("{x}.data"),
+        }
+    }
+
+    /// Get the expression used to wrap the output value `x`
+    fn output_value(&self, t: &str, x: &str, ctx: &str) -> String {
+        match self.types.get(t) {
+            TypeRef::Scalar(_) => x.to_string(),
+            _ => format!("wrap({ctx}, {x})"),
+        }
+    }
+}
+
+impl Generate for Nim {
+    fn bindings(&mut self, pkg: &Package, config: &mut Config) -> Result<(), Error> {
+        self.types = Types::new(&pkg.manifest)?;
+        self.resolve_names(pkg, config)?;
+
+        writeln!(config.output_file, "# Generated by futhark-bindgen\n")?;
+
+        let (
+            backend_option_fields,
+            backend_context_fields,
+            backend_functions,
+            backend_destroy,
+            configure_backend,
+        ) = match pkg.manifest.backend {
+            Backend::Multicore => (
+                "    numThreads*: int\n",
+                "",
+                "proc futhark_context_config_set_num_threads(cfg: ptr futhark_context_config, n: cint)\n",
+                "",
+                "  if options.numThreads != 0:\n    futhark_context_config_set_num_threads(config, cint(options.numThreads))\n",
+            ),
+            Backend::CUDA | Backend::OpenCL => (
+                "    device*: string\n",
+                "    device: string\n",
+                "proc futhark_context_config_set_device(cfg: ptr futhark_context_config, s: cstring)\n",
+                "  `=destroy`(c.device)\n",
+                "  result.device = options.device\n  if result.device.len > 0:\n    futhark_context_config_set_device(config, cstring(result.device))\n",
+            ),
+            _ => ("", "", "", "", ""),
+        };
+
+        let stem = config
+            .output_path
+            .file_stem()
+            .map(|x| x.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/nim/context.nim"),
+            stem = stem,
+            backend_option_fields = backend_option_fields,
+            backend_context_fields = backend_context_fields,
+            backend_functions = backend_functions,
+            backend_destroy = backend_destroy,
+            configure_backend = configure_backend,
+        )?;
+
+        // All types, destructors and `wrap` procs are declared up front, since records can refer
+        // to types that are generated later and destructors have to be defined before use
+        writeln!(config.output_file, "type")?;
+        for (name, ty) in &pkg.manifest.types {
+            let (doc, extra_fields) = match ty {
+                manifest::Type::Array(a) => (
+                    format!(
+                        "Array type with {} dimensions and {} elements",
+                        a.rank,
+                        a.elemtype.to_str()
+                    ),
+                    format!("    dims: array[{}, int64]\n", a.rank),
+                ),
+                manifest::Type::Opaque(_) => ("Futhark type".to_string(), String::new()),
+            };
+            write!(
+                config.output_file,
+                include_str!("templates/nim/types.nim"),
+                futhark_type = self.types.get(name).cname().unwrap(),
+                nim_type = self.typemap[name],
+                doc = doc,
+                extra_fields = extra_fields,
+            )?;
+        }
+        writeln!(config.output_file)?;
+
+        for (name, ty) in &pkg.manifest.types {
+            writeln!(
+                config.output_file,
+                include_str!("templates/nim/free.nim"),
+                futhark_type = self.types.get(name).cname().unwrap(),
+                nim_type = self.typemap[name],
+                free_fn = free_fn(ty),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn array_type(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        a: &manifest::ArrayType,
+    ) -> Result<(), Error> {
+        let dim_params: Vec<_> = (0..a.rank).map(|i| format!("dim{i}: int64")).collect();
+        let dim_args: Vec<_> = (0..a.rank).map(|i| format!("dims[{i}]")).collect();
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/nim/array.nim"),
+            futhark_type = self.types.get(name).cname().unwrap(),
+            nim_type = self.typemap[name],
+            rank = a.rank,
+            nim_elemtype = scalar_type(a.elemtype),
+            new_fn = a.ops.new,
+            values_fn = a.ops.values,
+            shape_fn = a.ops.shape,
+            dim_params = dim_params.join(", "),
+            dim_args = dim_args.join(", "),
+        )?;
+
+        Ok(())
+    }
+
+    fn opaque_type(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        ty: &manifest::OpaqueType,
+    ) -> Result<(), Error> {
+        let futhark_type = self.types.get(name).cname().unwrap().to_string();
+        let nim_type = &self.typemap[name];
+
+        write!(
+            config.output_file,
+            include_str!("templates/nim/opaque.nim"),
+            futhark_type = futhark_type,
+            nim_type = nim_type,
+        )?;
+
+        if let Some(record) = &ty.record {
+            let mut new_params = vec![];
+            let mut new_extern_params = vec![];
+            let mut new_call_args = vec![];
+            for field in record.fields.iter() {
+                let param = format!("field{}", field.name);
+                let c_type = self.c_type(&field.r#type);
+
+                new_params.push(format!("{param}: {}", self.get_type(&field.r#type)));
+                new_extern_params.push(format!("{param}: {c_type}"));
+                new_call_args.push(self.input_arg(&field.r#type, &param));
+
+                write!(
+                    config.output_file,
+                    include_str!("templates/nim/record_project.nim"),
+                    futhark_type = futhark_type,
+                    nim_type = nim_type,
+                    field_name = field.name,
+                    getter = format!("get{}", pascal_field_name(&field.name)),
+                    project_fn = field.project,
+                    futhark_field_type = c_type,
+                    nim_field_type = self.get_type(&field.r#type),
+                    output = self.output_value(&field.r#type, "value", "x.ctx"),
+                )?;
+            }
+
+            write!(
+                config.output_file,
+                include_str!("templates/nim/record.nim"),
+                futhark_type = futhark_type,
+                nim_type = nim_type,
+                new_fn = record.new,
+                new_params = new_params.join(", "),
+                new_extern_params = new_extern_params.join(", "),
+                new_call_args = new_call_args.join(", "),
+            )?;
+        }
+
+        writeln!(config.output_file)?;
+
+        Ok(())
+    }
+
+    fn entry(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        entry: &manifest::Entry,
+    ) -> Result<(), Error> {
+        let mut futhark_entry_params = vec!["ctx: ptr futhark_context".to_string()];
+        let mut call_args = vec!["ctx.context".to_string()];
+        let mut entry_params = vec!["ctx: Context".to_string()];
+        let mut out_decl = String::new();
+        let mut return_type = Vec::new();
+        let mut entry_return = Vec::new();
+
+        // Output arguments
+        for (i, arg) in entry.outputs.iter().enumerate() {
+            let out = format!("out{i}");
+            let c_type = self.c_type(&arg.r#type);
+            futhark_entry_params.push(format!("{out}: ptr {c_type}"));
+            out_decl.push_str(&format!("  var {out}: {c_type}\n"));
+            call_args.push(format!("addr {out}"));
+            return_type.push(self.get_type(&arg.r#type));
+            entry_return.push(self.output_value(&arg.r#type, &out, "ctx"));
+        }
+
+        // Input arguments
+        for (i, arg) in entry.inputs.iter().enumerate() {
+            let input = format!("input{i}");
+            futhark_entry_params.push(format!("{input}: {}", self.c_type(&arg.r#type)));
+            entry_params.push(format!("{input}: {}", self.get_type(&arg.r#type)));
+            call_args.push(self.input_arg(&arg.r#type, &input));
+        }
+
+        let entry_name = config.naming.entry_name_or(name, camel_case(name));
+        self.entry_names.insert(&nim_ident(&entry_name), name)?;
+
+        // Outputs are wrapped before syncing so they are freed if syncing fails
+        let (entry_return_type, entry_return) = match entry_return.len() {
+            0 => (String::new(), String::new()),
+            1 => (
+                format!(": {}", return_type[0]),
+                format!("  result = {}\n", entry_return[0]),
+            ),
+            _ => (
+                format!(": ({})", return_type.join(", ")),
+                format!("  result = ({})\n", entry_return.join(", ")),
+            ),
+        };
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/nim/entry.nim"),
+            name = name,
+            entry_fn = entry.cfun,
+            entry_name = entry_name,
+            futhark_entry_params = futhark_entry_params.join(", "),
+            entry_params = entry_params.join(", "),
+            entry_return_type = entry_return_type,
+            out_decl = out_decl,
+            call_args = call_args.join(", "),
+            entry_return = entry_return,
+        )?;
+
+        Ok(())
+    }
+}
@@ -1,6 +1,8 @@
 use std::io::Write;
 
-use crate::generate::{convert_struct_name, first_uppercase};
+use crate::generate::{
+    first_lowercase, first_uppercase, needs_type_prefix, opaque_base_name, Names, TypeRef, Types,
+};
 use crate::*;
 
 /// OCaml codegen
@@ -8,6 +10,11 @@ pub struct OCaml {
     typemap: BTreeMap<String, String>,
     ctypes_map: BTreeMap<String, String>,
     ba_map: BTreeMap<String, (String, String)>,
+    modules: BTreeMap<String, String>,
+    types: Types,
+    module_names: Names,
+    entry_names: Names,
+    type_names: Names,
     mli_file: std::fs::File,
 }
 
@@ -56,12 +63,46 @@ const OCAML_BA_TYPE_MAP: &[(&str, (&str, &str))] = &[
     ("bool", ("int", "Bigarray.int8_unsigned_elt")),
 ];
 
-fn type_is_array(t: &str) -> bool {
-    t.contains("array_f") || t.contains("array_i") || t.contains("array_u") || t.contains("array_b")
+// Modules defined in `templates/ocaml/bindings.ml` and `templates/ocaml/context.ml`
+const OCAML_RESERVED_MODULES: &[&str] = &["Bindings", "Context"];
+
+// Values defined in `templates/ocaml/bindings.ml` and `templates/ocaml/context.ml`
+const OCAML_RESERVED_VALUES: &[&str] = &[
+    "set_managed",
+    "check_use_after_free",
+    "get_ptr",
+    "get_opaque_ptr",
+];
+
+// Types defined in `templates/ocaml/bindings.ml` and `templates/ocaml/context.ml`
+const OCAML_RESERVED_TYPES: &[&str] = &["error", "futhark_array", "opaque"];
+
+// Keywords, these need to be escaped when used as identifiers
+#[rustfmt::skip]
+const OCAML_KEYWORDS: &[&str] = &[
+    "and", "as", "assert", "asr", "begin", "class", "constraint", "do", "done", "downto", "effect",
+    "else", "end", "exception", "external", "false", "for", "fun", "function", "functor", "if",
+    "in", "include", "inherit", "initializer", "land", "lazy", "let", "lor", "lsl", "lsr", "lxor",
+    "match", "method", "mod", "module", "mutable", "new", "nonrec", "object", "of", "open", "or",
+    "private", "rec", "sig", "struct", "then", "to", "true", "try", "type", "val", "virtual",
+    "when", "while", "with",
+];
+
+/// Escape `name` if it's a keyword by appending `_`
+fn ocaml_ident(name: &str) -> String {
+    if OCAML_KEYWORDS.contains(&name) {
+        format!("{name}_")
+    } else {
+        name.to_string()
+    }
 }
 
-fn type_is_opaque(t: &str) -> bool {
-    t.contains(".t")
+/// Ctypes and OCaml types of the backend memory used by `new_raw` and `values_raw` array functions
+fn raw_type(backend: Backend) -> (&'static str, &'static str) {
+    match backend {
+        Backend::CUDA => ("uint64_t", "UInt64.t"),
+        _ => ("ptr void", "unit Ctypes.ptr"),
+    }
 }
 
 fn ba_kind(t: &str) -> String {
@@ -98,6 +139,11 @@ impl OCaml {
             typemap,
             ba_map,
             ctypes_map,
+            modules: BTreeMap::new(),
+            types: Types::default(),
+            module_names: Names::new(OCAML_RESERVED_MODULES),
+            entry_names: Names::new(OCAML_RESERVED_VALUES),
+            type_names: Names::new(OCAML_RESERVED_TYPES),
             mli_file,
         })
     }
@@ -133,6 +179,37 @@ impl OCaml {
         x
     }
 
+    /// Determine the OCaml names of all array and opaque types
+    fn resolve_names(&mut self, pkg: &Package, config: &Config) -> Result<(), Error> {
+        for name in pkg.manifest.types.keys() {
+            let ocaml_name = match self.types.get(name) {
+                TypeRef::Array { elemtype, rank, .. } => config.naming.array_name(
+                    name,
+                    elemtype,
+                    rank,
+                    format!("array_{}_{rank}d", elemtype.to_str()),
+                ),
+                t => {
+                    let cname = t.cname().unwrap();
+                    let base = opaque_base_name(cname);
+                    let mut default = base.to_string();
+                    if needs_type_prefix(name, base) {
+                        default = format!("type_{default}");
+                    }
+                    config.naming.opaque_name(name, cname, default)
+                }
+            };
+            let module_name = first_uppercase(&ocaml_name);
+            self.module_names.insert(&module_name, name)?;
+            self.typemap
+                .insert(name.clone(), format!("{module_name}.t"));
+            self.ctypes_map
+                .insert(name.clone(), first_lowercase(&ocaml_name));
+            self.modules.insert(name.clone(), module_name);
+        }
+        Ok(())
+    }
+
     fn get_ba_type(&self, t: &str) -> (String, String) {
         let x = self.ba_map.get(t).cloned().unwrap();
         if x.0.is_empty() {
@@ -172,15 +249,57 @@ impl Generate for OCaml {
             _ => (),
         }
 
+        if pkg.manifest.backend == Backend::OpenCL {
+            generated_foreign_functions.push(format!(
+                "  {}",
+                self.foreign_function(
+                    "futhark_context_config_set_platform",
+                    "void",
+                    vec!["context_config", "string"]
+                )
+            ));
+        }
+
+        if matches!(
+            pkg.manifest.backend,
+            Backend::CUDA | Backend::OpenCL | Backend::HIP
+        ) {
+            for name in [
+                "futhark_context_config_set_default_group_size",
+                "futhark_context_config_set_default_tile_size",
+            ] {
+                generated_foreign_functions.push(format!(
+                    "  {}",
+                    self.foreign_function(name, "void", vec!["context_config", "int"])
+                ));
+            }
+        }
+
+        if matches!(pkg.manifest.backend, Backend::CUDA | Backend::HIP) {
+            generated_foreign_functions.push(format!(
+                "  {}",
+                self.foreign_function(
+                    "futhark_context_config_set_unified_memory",
+                    "void",
+                    vec!["context_config", "int"]
+                )
+            ));
+        }
+
+        self.types = Types::new(&pkg.manifest)?;
+        if config.parsed_report {
+            // Defined in `templates/ocaml/report.ml`
+            self.module_names.reserve(&["Report"]);
+        }
+        self.resolve_names(pkg, config)?;
+
         for (name, ty) in &pkg.manifest.types {
+            let ocaml_name = self.get_ctype(name);
             match ty {
                 manifest::Type::Array(a) => {
                     let elemtype = a.elemtype.to_str().to_string();
                     let ctypes_elemtype = self.get_ctype(&elemtype);
                     let rank = a.rank;
-                    let ocaml_name = format!("array_{elemtype}_{rank}d");
-                    self.typemap.insert(name.clone(), ocaml_name.clone());
-                    self.ctypes_map.insert(name.clone(), ocaml_name.clone());
                     let elem_ptr = format!("ptr {ctypes_elemtype}");
                     generated_foreign_functions.push(format!(
                         "  let {ocaml_name} = typedef (ptr void) \"{ocaml_name}\""
@@ -211,20 +330,34 @@ impl Generate for OCaml {
                             vec!["context", &ocaml_name]
                         )
                     ));
+                    if let Some(index) = &a.ops.index {
+                        let mut index_args = vec!["context", &elem_ptr, &ocaml_name];
+                        index_args.resize(rank as usize + 3, "int64_t");
+                        generated_foreign_functions.push(format!(
+                            "  {}",
+                            self.foreign_function(index, "int", index_args)
+                        ));
+                    }
+                    if let (Some(new_raw), Some(values_raw)) = (&a.ops.new_raw, &a.ops.values_raw) {
+                        let (raw_ctype, _) = raw_type(pkg.manifest.backend);
+                        let mut new_raw_args = vec!["context", raw_ctype];
+                        new_raw_args.resize(rank as usize + 2, "int64_t");
+                        generated_foreign_functions.push(format!(
+                            "  {}",
+                            self.foreign_function(new_raw, &ocaml_name, new_raw_args)
+                        ));
+                        generated_foreign_functions.push(format!(
+                            "  {}",
+                            self.foreign_function(
+                                values_raw,
+                                raw_ctype,
+                                vec!["context", &ocaml_name]
+                            )
+                        ));
+                    }
                 }
                 manifest::Type::Opaque(ty) => {
-                    let futhark_name = convert_struct_name(&ty.ctype);
-                    let mut ocaml_name = futhark_name
-                        .strip_prefix("futhark_opaque_")
-                        .unwrap()
-                        .to_string();
-                    if ocaml_name.chars().next().unwrap().is_numeric() || name.contains(' ') {
-                        ocaml_name = format!("type_{ocaml_name}");
-                    }
-
-                    self.typemap
-                        .insert(name.clone(), format!("{}.t", first_uppercase(&ocaml_name)));
-                    self.ctypes_map.insert(name.to_string(), ocaml_name.clone());
+                    let futhark_name = self.types.get(name).cname().unwrap().to_string();
                     generated_foreign_functions.push(format!(
                         "  let {ocaml_name} = typedef (ptr void) \"{futhark_name}\""
                     ));
@@ -243,11 +376,7 @@ impl Generate for OCaml {
                     let new_fn = &record.new;
                     let mut args = vec!["context".to_string(), format!("ptr {ocaml_name}")];
                     for f in record.fields.iter() {
-                        let cty = self
-                            .ctypes_map
-                            .get(&f.r#type)
-                            .cloned()
-                            .unwrap_or_else(|| f.r#type.clone());
+                        let cty = self.get_ctype(&f.r#type);
 
                         // project function
                         generated_foreign_functions.push(format!(
@@ -291,39 +420,103 @@ impl Generate for OCaml {
 
         let generated_foreign_functions = generated_foreign_functions.join("\n");
 
+        let (error_variants, error_printers) = if config.parsed_report {
+            (
+                "\n  | InvalidReport of string",
+                "  | Error (InvalidReport msg) -> Some (Printf.sprintf \"futhark error: invalid report: %s\" msg)\n",
+            )
+        } else {
+            ("", "")
+        };
+
         writeln!(
             config.output_file,
             include_str!("templates/ocaml/bindings.ml"),
-            generated_foreign_functions = generated_foreign_functions
+            generated_foreign_functions = generated_foreign_functions,
+            error_variants = error_variants,
+            error_printers = error_printers,
         )?;
 
-        writeln!(self.mli_file, include_str!("templates/ocaml/bindings.mli"))?;
+        writeln!(
+            self.mli_file,
+            include_str!("templates/ocaml/bindings.mli"),
+            error_variants = error_variants
+        )?;
 
-        let (extra_param, extra_line, extra_mli) = match pkg.manifest.backend {
-            Backend::Multicore => (
-                "?(num_threads = 0)",
-                "    Bindings.futhark_context_config_set_num_threads config num_threads;",
-                "?num_threads:int ->",
-            ),
+        let mut extra_param = Vec::new();
+        let mut extra_line = Vec::new();
+        let mut extra_mli = Vec::new();
+        match pkg.manifest.backend {
+            Backend::Multicore => {
+                extra_param.push("?(num_threads = 0)");
+                extra_line
+                    .push("Bindings.futhark_context_config_set_num_threads config num_threads;");
+                extra_mli.push("?num_threads:int ->");
+            }
+            Backend::CUDA | Backend::OpenCL => {
+                extra_param.push("?device");
+                extra_line.push(
+                    "Option.iter (Bindings.futhark_context_config_set_device config) device;",
+                );
+                extra_mli.push("?device:string ->");
+            }
+            _ => (),
+        }
 
-            Backend::CUDA | Backend::OpenCL => (
-                "?device",
-                "    Option.iter (Bindings.futhark_context_config_set_device config) device;",
-                "?device:string ->",
-            ),
-            _ => ("", "", ""),
+        if pkg.manifest.backend == Backend::OpenCL {
+            extra_param.push("?platform");
+            extra_line.push(
+                "Option.iter (Bindings.futhark_context_config_set_platform config) platform;",
+            );
+            extra_mli.push("?platform:string ->");
+        }
+
+        if matches!(
+            pkg.manifest.backend,
+            Backend::CUDA | Backend::OpenCL | Backend::HIP
+        ) {
+            extra_param.push("?default_group_size ?default_tile_size");
+            extra_line.push("Option.iter (Bindings.futhark_context_config_set_default_group_size config) default_group_size;");
+            extra_line.push("Option.iter (Bindings.futhark_context_config_set_default_tile_size config) default_tile_size;");
+            extra_mli.push("?default_group_size:int -> ?default_tile_size:int ->");
+        }
+
+        if matches!(pkg.manifest.backend, Backend::CUDA | Backend::HIP) {
+            extra_param.push("?unified_memory");
+            extra_line.push("Option.iter (fun b -> Bindings.futhark_context_config_set_unified_memory config (if b then 1 else 0)) unified_memory;");
+            extra_mli.push("?unified_memory:bool ->");
+        }
+
+        let extra_param = extra_param.join(" ");
+        let extra_line = extra_line.join("\n    ");
+        let extra_mli = extra_mli.join(" ");
+
+        let (report, report_mli) = if config.parsed_report {
+            writeln!(
+                config.output_file,
+                include_str!("templates/ocaml/report.ml")
+            )?;
+            writeln!(self.mli_file, include_str!("templates/ocaml/report.mli"))?;
+            (
+                include_str!("templates/ocaml/context_report.ml"),
+                include_str!("templates/ocaml/context_report.mli"),
+            )
+        } else {
+            ("", "")
         };
 
         writeln!(
             config.output_file,
             include_str!("templates/ocaml/context.ml"),
             extra_param = extra_param,
-            extra_line = extra_line
+            extra_line = extra_line,
+            report = report,
         )?;
         writeln!(
             self.mli_file,
             include_str!("templates/ocaml/context.mli"),
-            extra_mli = extra_mli
+            extra_mli = extra_mli,
+            report = report_mli,
         )?;
 
         Ok(())
@@ -331,23 +524,53 @@ impl Generate for OCaml {
 
     fn array_type(
         &mut self,
-        _pkg: &Package,
+        pkg: &Package,
         config: &mut Config,
         name: &str,
         ty: &manifest::ArrayType,
     ) -> Result<(), Error> {
         let rank = ty.rank;
         let elemtype = ty.elemtype.to_str().to_string();
-        let ocaml_name = self.typemap.get(name).unwrap();
-        let module_name = first_uppercase(ocaml_name);
+        let module_name = self.modules.get(name).unwrap();
         let mut dim_args = Vec::new();
+        let mut index_args = Vec::new();
         for i in 0..rank {
             dim_args.push(format!("(Int64.of_int dims.({i}))"));
+            index_args.push(format!("(Int64.of_int idx.({i}))"));
         }
 
         let (ocaml_elemtype, ba_elemtype) = self.get_ba_type(&elemtype);
         let ocaml_ctype = self.get_ctype(&elemtype);
 
+        let index = match &ty.ops.index {
+            Some(index_fn) => format!(
+                include_str!("templates/ocaml/array_index.ml"),
+                rank = rank,
+                index_fn = index_fn,
+                index_args = index_args.join(" "),
+            ),
+            None => include_str!("templates/ocaml/array_index_unsupported.ml").to_string(),
+        };
+
+        let (raw, raw_mli) = match (&ty.ops.new_raw, &ty.ops.values_raw) {
+            (Some(new_raw_fn), Some(values_raw_fn)) => (
+                format!(
+                    include_str!("templates/ocaml/array_raw.ml"),
+                    rank = rank,
+                    new_raw_fn = new_raw_fn,
+                    values_raw_fn = values_raw_fn,
+                    dim_args = dim_args.join(" "),
+                ),
+                format!(
+                    include_str!("templates/ocaml/array_raw.mli"),
+                    raw_type = raw_type(pkg.manifest.backend).1,
+                )
+                .trim_end()
+                .to_string(),
+            ),
+            _ => (String::new(), String::new()),
+        };
+
         writeln!(
             config.output_file,
             include_str!("templates/ocaml/array.ml"),
@@ -359,6 +582,8 @@ impl Generate for OCaml {
             ba_elemtype = ba_elemtype,
             ba_kind = ba_kind(&ba_elemtype),
             ocaml_ctype = ocaml_ctype,
+            index = index,
+            raw = raw,
         )?;
 
         writeln!(
@@ -367,6 +592,7 @@ impl Generate for OCaml {
             module_name = module_name,
             ocaml_elemtype = ocaml_elemtype,
             ba_elemtype = ba_elemtype,
+            raw = raw_mli,
         )?;
 
         Ok(())
@@ -379,17 +605,8 @@ impl Generate for OCaml {
         name: &str,
         ty: &manifest::OpaqueType,
     ) -> Result<(), Error> {
-        let futhark_name = convert_struct_name(&ty.ctype);
-        let mut ocaml_name = futhark_name
-            .strip_prefix("futhark_opaque_")
-            .unwrap()
-            .to_string();
-        if ocaml_name.chars().next().unwrap().is_numeric() || name.contains(' ') {
-            ocaml_name = format!("type_{ocaml_name}");
-        }
-        let module_name = first_uppercase(&ocaml_name);
-        self.typemap
-            .insert(ocaml_name.clone(), format!("{module_name}.t"));
+        let ocaml_name = self.get_ctype(name);
+        let module_name = self.modules.get(name).unwrap().clone();
 
         let free_fn = &ty.ops.free;
 
@@ -421,16 +638,12 @@ impl Generate for OCaml {
 
             new_params.push(format!("field{}", f.name));
 
-            if type_is_array(&t) {
-                new_call_args.push(format!("(get_ptr field{})", f.name));
-                new_arg_types.push(format!("{}.t", first_uppercase(&t)));
-            } else if type_is_opaque(&t) {
-                new_call_args.push(format!("(get_opaque_ptr field{})", f.name));
-                new_arg_types.push(t.to_string());
-            } else {
-                new_call_args.push(format!("field{}", f.name));
-                new_arg_types.push(t.to_string());
+            match self.types.get(&f.r#type) {
+                TypeRef::Scalar(_) => new_call_args.push(format!("field{}", f.name)),
+                TypeRef::Array { .. } => new_call_args.push(format!("(get_ptr field{})", f.name)),
+                _ => new_call_args.push(format!("(get_opaque_ptr field{})", f.name)),
             }
+            new_arg_types.push(t);
         }
 
         writeln!(
@@ -452,25 +665,19 @@ impl Generate for OCaml {
             let name = &f.name;
             let project = &f.project;
 
-            let (out, out_type) = if type_is_opaque(&t) {
-                let call = t.replace(".t", ".of_ptr");
-                (format!("{call} t.opaque_ctx !@out"), t.to_string())
-            } else if type_is_array(&t) {
-                let array = first_uppercase(&t);
-                (
-                    format!("{array}.of_ptr t.opaque_ctx !@out"),
-                    format!("{}.t", first_uppercase(&t)),
-                )
-            } else {
-                ("!@out".to_string(), t.to_string())
-            };
-
-            let alloc_type = if type_is_array(&t) {
-                format!("Bindings.{t}")
-            } else if type_is_opaque(&t) {
-                t
-            } else {
-                self.get_ctype(&f.r#type)
+            let (out, alloc_type) = match self.types.get(&f.r#type) {
+                TypeRef::Scalar(_) => ("!@out".to_string(), self.get_ctype(&f.r#type)),
+                TypeRef::Array { .. } => {
+                    let m = &self.modules[&f.r#type];
+                    (
+                        format!("{m}.of_ptr t.opaque_ctx !@out"),
+                        format!("Bindings.{}", self.get_ctype(&f.r#type)),
+                    )
+                }
+                _ => {
+                    let m = &self.modules[&f.r#type];
+                    (format!("{m}.of_ptr t.opaque_ctx !@out"), format!("{m}.t"))
+                }
             };
 
             writeln!(
@@ -485,7 +692,59 @@ impl Generate for OCaml {
                 self.mli_file,
                 include_str!("templates/ocaml/record_project.mli"),
                 name = name,
-                out_type = out_type
+                out_type = t
+            )?;
+        }
+
+        if let Some(fields) = self
+            .types
+            .scalar_record(name)
+            .filter(|_| config.plain_records)
+        {
+            let tuple = fields.iter().all(|(f, _)| f.parse::<usize>().is_ok());
+            let types = fields.iter().map(|(_, e)| self.get_type(e.to_str()));
+            let (value_type, value_pattern, value_args, value_expr) = if tuple {
+                let names: Vec<_> = fields.iter().map(|(f, _)| format!("x{f}")).collect();
+                let getters: Vec<_> = fields.iter().map(|(f, _)| format!("get_{f} t")).collect();
+                (
+                    types.collect::<Vec<_>>().join(" * "),
+                    format!("({})", names.join(", ")),
+                    names.join(" "),
+                    format!("({})", getters.join(", ")),
+                )
+            } else {
+                let decls: Vec<_> = fields
+                    .iter()
+                    .zip(types)
+                    .map(|((f, _), t)| format!("{}: {t}", ocaml_ident(f)))
+                    .collect();
+                let args: Vec<_> = fields
+                    .iter()
+                    .map(|(f, _)| format!("value.{}", ocaml_ident(f)))
+                    .collect();
+                let getters: Vec<_> = fields
+                    .iter()
+                    .map(|(f, _)| format!("{} = get_{f} t", ocaml_ident(f)))
+                    .collect();
+                (
+                    format!("{{ {} }}", decls.join("; ")),
+                    "value".to_string(),
+                    args.join(" "),
+                    format!("{{ {} }}", getters.join("; ")),
+                )
+            };
+            writeln!(
+                config.output_file,
+                include_str!("templates/ocaml/record_plain.ml"),
+                value_type = value_type,
+                value_pattern = value_pattern,
+                value_args = value_args,
+                value_expr = value_expr,
+            )?;
+            writeln!(
+                self.mli_file,
+                include_str!("templates/ocaml/record_plain.mli"),
+                value_type = value_type,
             )?;
         }
 
@@ -508,19 +767,28 @@ impl Generate for OCaml {
         let mut call_args = Vec::new();
         let mut out_return = Vec::new();
         let mut out_decl = Vec::new();
+        let mut output_fields = Vec::new();
+        let mut output_values = Vec::new();
+        let mut field_names = Names::new(&[]);
 
         for (i, out) in entry.outputs.iter().enumerate() {
-            let t = self.get_type(&out.r#type);
-            let ct = self.get_ctype(&out.r#type);
-
-            let mut ocaml_elemtype = t.clone();
-
-            // Transform into `Module.t`
-            if ocaml_elemtype.contains("array_") {
-                ocaml_elemtype = first_uppercase(&ocaml_elemtype) + ".t"
+            let plain = config.plain_records && self.types.scalar_record(&out.r#type).is_some();
+            let t = if plain {
+                format!("{}.value", self.modules[&out.r#type])
+            } else {
+                self.get_type(&out.r#type)
+            };
+            let field = out
+                .name
+                .as_deref()
+                .map(|x| ocaml_ident(&first_lowercase(x)))
+                .unwrap_or_else(|| format!("out{i}"));
+            if config.output_structs {
+                field_names.insert(&field, &format!("{name} output {i}"))?;
             }
+            output_fields.push(format!("  {field}: {t};"));
 
-            return_type.push(ocaml_elemtype);
+            return_type.push(t);
 
             let i = if entry.outputs.len() == 1 {
                 String::new()
@@ -528,67 +796,87 @@ impl Generate for OCaml {
                 i.to_string()
             };
 
-            if type_is_array(&t) || type_is_opaque(&t) {
-                out_decl.push(format!("  let out{i}_ptr = allocate (ptr void) null in"));
-            } else {
+            if let TypeRef::Scalar(e) = self.types.get(&out.r#type) {
+                let ct = self.get_ctype(e.to_str());
                 out_decl.push(format!("  let out{i}_ptr = allocate_n {ct} ~count:1 in"));
+                out_return.push(format!("!@out{i}_ptr"));
+            } else {
+                let m = &self.modules[&out.r#type];
+                out_decl.push(format!("  let out{i}_ptr = allocate (ptr void) null in"));
+                if plain {
+                    out_return.push(format!("({m}.to_value ({m}.of_ptr ctx !@out{i}_ptr))"));
+                } else {
+                    out_return.push(format!("({m}.of_ptr ctx !@out{i}_ptr)"));
+                }
             }
 
+            output_values.push(format!("{field} = {}", out_return.last().unwrap()));
             call_args.push(format!("out{i}_ptr"));
-
-            if type_is_array(&t) {
-                let m = first_uppercase(&t);
-                out_return.push(format!("({m}.of_ptr ctx !@out{i}_ptr)"));
-            } else if type_is_opaque(&t) {
-                let m = first_uppercase(&t);
-                let m = m.strip_suffix(".t").unwrap_or(&m);
-                out_return.push(format!("({m}.of_ptr ctx !@out{i}_ptr)"));
-            } else {
-                out_return.push(format!("!@out{i}_ptr"));
-            }
         }
 
         for (i, input) in entry.inputs.iter().enumerate() {
             entry_params.push(format!("input{i}"));
 
-            let mut ocaml_elemtype = self.get_type(&input.r#type);
+            if config.plain_records && self.types.scalar_record(&input.r#type).is_some() {
+                let m = &self.modules[&input.r#type];
+                out_decl.push(format!("  let input{i} = {m}.of_value ctx input{i} in"));
+                arg_types.push(format!("{m}.value"));
+            } else {
+                arg_types.push(self.get_type(&input.r#type));
+            }
 
-            // Transform into `Module.t`
-            if type_is_array(&ocaml_elemtype) {
-                ocaml_elemtype = first_uppercase(&ocaml_elemtype) + ".t"
+            match self.types.get(&input.r#type) {
+                TypeRef::Scalar(_) => call_args.push(format!("input{i}")),
+                TypeRef::Array { .. } => call_args.push(format!("(get_ptr input{i})")),
+                _ => call_args.push(format!("(get_opaque_ptr input{i})")),
             }
+        }
 
-            arg_types.push(ocaml_elemtype);
+        let entry_name = config.naming.entry_name(name);
+        self.entry_names.insert(&entry_name, name)?;
 
-            let t = self.get_type(&input.r#type);
-            if type_is_array(&t) {
-                call_args.push(format!("(get_ptr input{i})"));
-            } else if type_is_opaque(&t) {
-                call_args.push(format!("(get_opaque_ptr input{i})"));
-            } else {
-                call_args.push(format!("input{i}"));
-            }
+        let mut return_type = if return_type.is_empty() {
+            "unit".to_string()
+        } else {
+            return_type.join(" * ")
+        };
+        let mut out_return = out_return.join(", ");
+
+        if config.output_structs && entry.outputs.len() > 1 {
+            let output_type = format!("{entry_name}_output");
+            self.type_names.insert(&output_type, name)?;
+            writeln!(
+                config.output_file,
+                include_str!("templates/ocaml/entry_output.ml"),
+                output_type = output_type,
+                output_fields = output_fields.join("\n"),
+            )?;
+            writeln!(
+                self.mli_file,
+                include_str!("templates/ocaml/entry_output.mli"),
+                name = entry_name,
+                output_type = output_type,
+                output_fields = output_fields.join("\n"),
+            )?;
+            return_type = output_type;
+            out_return = format!("{{ {} }}", output_values.join("; "));
         }
 
         writeln!(
             config.output_file,
             include_str!("templates/ocaml/entry.ml"),
-            name = name,
+            name = entry_name,
+            entry_fn = entry.cfun,
             entry_params = entry_params.join(" "),
             out_decl = out_decl.join("\n"),
             call_args = call_args.join(" "),
-            out_return = out_return.join(", ")
+            out_return = out_return
         )?;
 
-        let return_type = if return_type.is_empty() {
-            "unit".to_string()
-        } else {
-            return_type.join(" * ")
-        };
         writeln!(
             self.mli_file,
             include_str!("templates/ocaml/entry.mli"),
-            name = name,
+            name = entry_name,
             arg_types = arg_types.join(" -> "),
             return_type = return_type,
         )?;
@@ -1,22 +1,113 @@
-use crate::generate::{convert_struct_name, first_uppercase};
+use crate::generate::{pascal_field_name, Names, TypeRef, Types};
 use crate::*;
 use std::io::Write;
 
 /// Rust codegen
 pub struct Rust {
     typemap: BTreeMap<String, String>,
+    types: Types,
+    type_names: Names,
+    entry_names: Names,
+    plain_types: BTreeMap<String, String>,
+    host_types: BTreeMap<String, String>,
 }
 
-fn type_is_array(t: &str) -> bool {
-    t.contains("ArrayF") || t.contains("ArrayI") || t.contains("ArrayU") || t.contains("ArrayB")
+// Rust `f16` codgen requires the `half` crate
+const RUST_TYPE_MAP: &[(&str, &str)] = &[("f16", "half::f16")];
+
+// Types defined in `templates/rust/context.rs`
+const RUST_RESERVED_TYPES: &[&str] = &["Context", "Options", "Error"];
+
+// Types defined in `templates/rust/async.rs`
+const RUST_ASYNC_TYPES: &[&str] = &[
+    "SyncState",
+    "ContextPtr",
+    "SyncWorker",
+    "SyncFuture",
+    "ValuesFuture",
+];
+
+// Types defined in `templates/rust/handle.rs`
+const RUST_HANDLE_TYPES: &[&str] = &["Job", "JobState", "JobResult", "JobFuture", "ContextHandle"];
+
+// Types defined in `templates/rust/report.rs`
+const RUST_REPORT_TYPES: &[&str] = &["Report", "KernelStats", "ReportJson", "ReportParser"];
+
+// Methods defined on `Context` in `templates/rust/context.rs`
+const RUST_RESERVED_METHODS: &[&str] = &[
+    "new",
+    "new_with_options",
+    "sync",
+    "auto_sync",
+    "clear_caches",
+    "pause_profiling",
+    "unpause_profiling",
+    "get_error",
+    "report",
+];
+
+// Methods defined on `Context` in `templates/rust/async.rs`
+const RUST_ASYNC_METHODS: &[&str] = &["sync_async", "sync_worker"];
+
+// Methods defined on `Context` in `templates/rust/report.rs`
+const RUST_REPORT_METHODS: &[&str] = &["parsed_report", "profile"];
+
+// Methods defined on builders in `templates/rust/record_builder.rs`, `default` is reserved
+// because it's called by `new`
+const RUST_BUILDER_METHODS: &[&str] = &["new", "build", "default"];
+
+// Strict and reserved keywords, these need to be escaped when used as identifiers
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Escape `name` if it's a keyword, using a raw identifier when possible
+fn rust_ident(name: &str) -> String {
+    match name {
+        "crate" | "self" | "Self" | "super" => format!("{name}_"),
+        _ if RUST_KEYWORDS.contains(&name) => format!("r#{name}"),
+        _ => name.to_string(),
+    }
 }
 
-fn type_is_opaque(a: &str) -> bool {
-    a.contains("futhark_opaque_")
+/// Type of the backend memory used by `new_raw` and `values_raw` array functions
+fn raw_type(backend: Backend) -> &'static str {
+    match backend {
+        Backend::CUDA => "u64",
+        Backend::OpenCL | Backend::HIP => "*mut std::ffi::c_void",
+        _ => "*mut u8",
+    }
 }
 
-// Rust `f16` codgen requires the `half` crate
-const RUST_TYPE_MAP: &[(&str, &str)] = &[("f16", "half::f16")];
+/// Body of a struct declaration, records with numeric field names (tuples) become tuple structs
+fn struct_body(fields: &[(&str, String)]) -> String {
+    if fields.iter().all(|(f, _)| f.parse::<usize>().is_ok()) {
+        let fields: Vec<_> = fields.iter().map(|(_, t)| format!("pub {t}")).collect();
+        format!("({});", fields.join(", "))
+    } else {
+        let fields: Vec<_> = fields
+            .iter()
+            .map(|(f, t)| format!("    pub {}: {t},", rust_ident(f)))
+            .collect();
+        format!(" {{\n{}\n}}", fields.join("\n"))
+    }
+}
+
+/// Field names of the output struct for entry point `name`, unnamed outputs are called `out{i}`
+fn output_field_names(name: &str, entry: &manifest::Entry) -> Result<Vec<String>, Error> {
+    let mut names = Names::new(&[]);
+    let mut fields = Vec::new();
+    for (i, arg) in entry.outputs.iter().enumerate() {
+        let field = rust_ident(arg.name.as_deref().unwrap_or(&format!("out{i}")));
+        names.insert(&field, &format!("{name} output {i}"))?;
+        fields.push(field);
+    }
+    Ok(fields)
+}
 
 impl Default for Rust {
     fn default() -> Self {
@@ -24,66 +115,278 @@ impl Default for Rust {
             .iter()
             .map(|(a, b)| (a.to_string(), b.to_string()))
             .collect();
-        Rust { typemap }
+        Rust {
+            typemap,
+            types: Types::default(),
+            type_names: Names::new(RUST_RESERVED_TYPES),
+            entry_names: Names::new(RUST_RESERVED_METHODS),
+            plain_types: BTreeMap::new(),
+            host_types: BTreeMap::new(),
+        }
     }
 }
 
-struct ArrayInfo {
-    futhark_type: String,
-    rust_type: String,
-    #[allow(unused)]
-    elem: String,
-}
-
 impl Rust {
-    fn get_type(typemap: &BTreeMap<String, String>, t: &str) -> String {
-        let a = typemap.get(t);
-        let x = match a {
-            Some(t) => t.clone(),
-            None => t.to_string(),
-        };
+    /// Get the Rust type used for a Futhark type
+    fn get_type(&self, t: &str) -> String {
+        let x = self
+            .typemap
+            .get(t)
+            .cloned()
+            .unwrap_or_else(|| t.to_string());
         if x.is_empty() {
             panic!("Unsupported type: {t}");
         }
         x
     }
+
+    /// Check whether a record can be copied to the host, which requires all of its fields to be
+    /// scalars, arrays or records that can be copied to the host
+    fn has_host_type(&self, pkg: &Package, name: &str) -> bool {
+        let record = match pkg.manifest.types.get(name) {
+            Some(manifest::Type::Opaque(ty)) => ty.record.as_ref(),
+            _ => None,
+        };
+        record.is_some_and(|r| {
+            r.fields.iter().all(|f| match self.types.get(&f.r#type) {
+                TypeRef::Scalar(_) | TypeRef::Array { .. } => true,
+                TypeRef::Record { .. } => self.has_host_type(pkg, &f.r#type),
+                _ => false,
+            })
+        })
+    }
+
+    /// Get the host type used for an array or record by `to_host` and `from_host`
+    fn host_type(&self, t: &str) -> String {
+        match self.types.get(t) {
+            TypeRef::Scalar(_) => self.get_type(t),
+            TypeRef::Array { elemtype, rank, .. } => {
+                format!("HostArray<{}, {rank}>", self.get_type(elemtype.to_str()))
+            }
+            _ => self.host_types[t].clone(),
+        }
+    }
+
+    /// Determine the Rust names of all array and opaque types
+    fn resolve_names(&mut self, pkg: &Package, config: &Config) -> Result<(), Error> {
+        if config.async_api {
+            self.type_names.reserve(RUST_ASYNC_TYPES);
+            self.entry_names.reserve(RUST_ASYNC_METHODS);
+        }
+        if config.context_handle {
+            self.type_names.reserve(RUST_HANDLE_TYPES);
+        }
+        if config.context_handle || config.serde {
+            self.type_names.reserve(&["HostArray"]);
+        }
+        if config.parsed_report {
+            self.type_names.reserve(RUST_REPORT_TYPES);
+            self.entry_names.reserve(RUST_REPORT_METHODS);
+        }
+
+        for name in pkg.manifest.types.keys() {
+            let mut rust_type = config.naming.pascal_name(name, &self.types.get(name));
+            if (config.serde || config.context_handle) && self.has_host_type(pkg, name) {
+                let host_type = format!("{rust_type}Host");
+                self.type_names.insert(&host_type, name)?;
+                self.host_types.insert(name.clone(), host_type);
+            }
+            if config.plain_records && self.types.scalar_record(name).is_some() {
+                // The plain struct takes the type name, the opaque value is still available
+                self.type_names.insert(&rust_type, name)?;
+                self.plain_types.insert(name.clone(), rust_type.clone());
+                rust_type = format!("{rust_type}Opaque");
+            }
+            self.type_names.insert(&rust_type, name)?;
+            if let TypeRef::Record { .. } = self.types.get(name) {
+                self.type_names
+                    .insert(&format!("{rust_type}Builder"), name)?;
+            }
+            self.typemap.insert(name.clone(), rust_type);
+        }
+        Ok(())
+    }
+}
+
+impl Rust {
+    /// Generate a `ContextHandle` method for an entry point, host data is used in place of
+    /// arrays and records since they can't be sent to the worker thread. Other opaque types
+    /// have no host representation, so entry points using them are unsupported.
+    fn handle_entry(
+        &self,
+        config: &mut Config,
+        name: &str,
+        entry_name: &str,
+        entry: &manifest::Entry,
+    ) -> Result<(), Error> {
+        let unsupported = |t: &str| {
+            Error::Unsupported(format!(
+                "entry point `{name}` can't be called using `ContextHandle`, `{t}` has no host \
+                 type (use `ContextHandle::run` instead)"
+            ))
+        };
+        let mut entry_params = Vec::new();
+        let mut entry_args = Vec::new();
+        let mut convert_inputs = Vec::new();
+        let mut return_type = Vec::new();
+        let mut outputs = Vec::new();
+        let mut convert_outputs = Vec::new();
+
+        for (i, arg) in entry.inputs.iter().enumerate() {
+            let name = format!("input{i}");
+            if let Some(plain) = self.plain_types.get(&arg.r#type) {
+                entry_params.push(format!("{name}: {plain}"));
+                entry_args.push(name);
+                continue;
+            }
+            match self.types.get(&arg.r#type) {
+                TypeRef::Scalar(_) => {
+                    entry_params.push(format!("{name}: {}", self.get_type(&arg.r#type)));
+                    entry_args.push(name);
+                }
+                TypeRef::Array { elemtype, rank, .. } => {
+                    let t = self.get_type(&arg.r#type);
+                    entry_params.push(format!(
+                        "{name}: HostArray<{}, {rank}>",
+                        self.get_type(elemtype.to_str())
+                    ));
+                    convert_inputs.push(format!(
+                        "let {name} = {t}::new(ctx, {name}.shape, &{name}.data)?;"
+                    ));
+                    entry_args.push(format!("&{name}"));
+                }
+                TypeRef::Record { .. } if self.host_types.contains_key(&arg.r#type) => {
+                    let t = self.get_type(&arg.r#type);
+                    entry_params.push(format!("{name}: {}", self.host_types[&arg.r#type]));
+                    convert_inputs.push(format!("let {name} = {t}::from_host(ctx, &{name})?;"));
+                    entry_args.push(format!("&{name}"));
+                }
+                _ => return Err(unsupported(&arg.r#type)),
+            }
+        }
+
+        for (i, arg) in entry.outputs.iter().enumerate() {
+            let name = format!("out{i}");
+            if let Some(plain) = self.plain_types.get(&arg.r#type) {
+                return_type.push(plain.clone());
+                convert_outputs.push(name.clone());
+                outputs.push(name);
+                continue;
+            }
+            match self.types.get(&arg.r#type) {
+                TypeRef::Scalar(_) => {
+                    return_type.push(self.get_type(&arg.r#type));
+                    convert_outputs.push(name.clone());
+                }
+                TypeRef::Array { elemtype, rank, .. } => {
+                    return_type.push(format!(
+                        "HostArray<{}, {rank}>",
+                        self.get_type(elemtype.to_str())
+                    ));
+                    convert_outputs.push(format!(
+                        "HostArray {{ shape: *{name}.shape(), data: {name}.get()? }}"
+                    ));
+                }
+                TypeRef::Record { .. } if self.host_types.contains_key(&arg.r#type) => {
+                    return_type.push(self.host_types[&arg.r#type].clone());
+                    convert_outputs.push(format!("{name}.to_host()?"));
+                }
+                _ => return Err(unsupported(&arg.r#type)),
+            }
+            outputs.push(name);
+        }
+
+        let (entry_return_type, call_entry, convert_outputs) = match outputs.len() {
+            0 => (
+                "()".to_string(),
+                format!("ctx.{entry_name}({})?;", entry_args.join(", ")),
+                "Ok(())".to_string(),
+            ),
+            1 => (
+                return_type.join(", "),
+                format!("let out0 = ctx.{entry_name}({})?;", entry_args.join(", ")),
+                format!("Ok({})", convert_outputs.join(", ")),
+            ),
+            _ => {
+                // Output structs are destructured, the handle always returns a tuple
+                let pattern = if config.output_structs {
+                    let fields: Vec<_> = output_field_names(name, entry)?
+                        .iter()
+                        .zip(&outputs)
+                        .map(|(field, out)| {
+                            if field == out {
+                                out.clone()
+                            } else {
+                                format!("{field}: {out}")
+                            }
+                        })
+                        .collect();
+                    format!(
+                        "{}Output {{ {} }}",
+                        pascal_field_name(entry_name),
+                        fields.join(", ")
+                    )
+                } else {
+                    format!("({})", outputs.join(", "))
+                };
+                (
+                    format!("({})", return_type.join(", ")),
+                    format!(
+                        "let {pattern} = ctx.{entry_name}({})?;",
+                        entry_args.join(", ")
+                    ),
+                    format!("Ok(({}))", convert_outputs.join(", ")),
+                )
+            }
+        };
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/rust/entry_handle.rs"),
+            entry_name = entry_name,
+            entry_params = entry_params.join(", "),
+            entry_return_type = entry_return_type,
+            convert_inputs = convert_inputs.join("\n            "),
+            call_entry = call_entry,
+            convert_outputs = convert_outputs,
+        )?;
+
+        Ok(())
+    }
 }
 
 impl Generate for Rust {
     fn array_type(
         &mut self,
-        _pkg: &Package,
+        pkg: &Package,
         config: &mut Config,
         name: &str,
         a: &manifest::ArrayType,
     ) -> Result<(), Error> {
-        let elemtype = a.elemtype.to_str();
-        let rank = a.rank;
-
-        let futhark_type = convert_struct_name(&a.ctype).to_string();
-        let rust_type = format!("Array{}D{rank}", first_uppercase(elemtype));
-        let info = ArrayInfo {
-            futhark_type,
-            rust_type,
-            elem: elemtype.to_string(),
-        };
+        let futhark_type = self.types.get(name).cname().unwrap().to_string();
+        let rust_type = self.get_type(name);
 
         let mut dim_params = Vec::new();
         let mut new_dim_args = Vec::new();
+        let mut index_params = Vec::new();
+        let mut index_args = Vec::new();
 
         for i in 0..a.rank {
             let dim = format!("dims[{i}]");
             dim_params.push(dim);
             new_dim_args.push(format!("dim{i}: i64"));
+            index_params.push(format!("index[{i}]"));
+            index_args.push(format!("i{i}: i64"));
         }
 
         writeln!(
             config.output_file,
             include_str!("templates/rust/array.rs"),
-            futhark_type = info.futhark_type,
-            rust_type = info.rust_type,
+            futhark_type = futhark_type,
+            rust_type = rust_type,
             rank = a.rank,
-            elemtype = info.elem,
+            elemtype = a.elemtype.to_str(),
+            rust_elemtype = self.get_type(a.elemtype.to_str()),
             new_fn = a.ops.new,
             free_fn = a.ops.free,
             values_fn = a.ops.values,
@@ -92,9 +395,62 @@ impl Generate for Rust {
             new_dim_args = new_dim_args.join(", ")
         )?;
 
-        self.typemap
-            .insert(name.to_string(), info.futhark_type.clone());
-        self.typemap.insert(info.futhark_type, info.rust_type);
+        match &a.ops.index {
+            Some(index_fn) => writeln!(
+                config.output_file,
+                include_str!("templates/rust/array_index.rs"),
+                futhark_type = futhark_type,
+                rust_type = rust_type,
+                rank = a.rank,
+                rust_elemtype = self.get_type(a.elemtype.to_str()),
+                index_fn = index_fn,
+                index_params = index_params.join(", "),
+                index_args = index_args.join(", ")
+            )?,
+            None => writeln!(
+                config.output_file,
+                include_str!("templates/rust/array_index_unsupported.rs"),
+                rust_type = rust_type,
+                rank = a.rank,
+                rust_elemtype = self.get_type(a.elemtype.to_str()),
+            )?,
+        }
+
+        if config.serde || config.context_handle {
+            writeln!(
+                config.output_file,
+                include_str!("templates/rust/array_host.rs"),
+                rust_type = rust_type,
+                rank = a.rank,
+                rust_elemtype = self.get_type(a.elemtype.to_str()),
+            )?;
+        }
+
+        if let (Some(new_raw_fn), Some(values_raw_fn)) = (&a.ops.new_raw, &a.ops.values_raw) {
+            writeln!(
+                config.output_file,
+                include_str!("templates/rust/array_raw.rs"),
+                futhark_type = futhark_type,
+                rust_type = rust_type,
+                rank = a.rank,
+                raw_type = raw_type(pkg.manifest.backend),
+                new_raw_fn = new_raw_fn,
+                values_raw_fn = values_raw_fn,
+                dim_params = dim_params.join(", "),
+                new_dim_args = new_dim_args.join(", ")
+            )?;
+        }
+
+        if config.async_api {
+            writeln!(
+                config.output_file,
+                include_str!("templates/rust/array_async.rs"),
+                rust_type = rust_type,
+                rust_elemtype = self.get_type(a.elemtype.to_str()),
+                values_fn = a.ops.values,
+            )?;
+        }
+
         Ok(())
     }
 
@@ -105,11 +461,8 @@ impl Generate for Rust {
         name: &str,
         ty: &manifest::OpaqueType,
     ) -> Result<(), Error> {
-        let futhark_type = convert_struct_name(&ty.ctype).to_string();
-        let mut rust_type = first_uppercase(futhark_type.strip_prefix("futhark_opaque_").unwrap());
-        if rust_type.chars().next().unwrap().is_numeric() || name.contains(' ') {
-            rust_type = format!("Type{}", rust_type);
-        }
+        let futhark_type = self.types.get(name).cname().unwrap().to_string();
+        let rust_type = self.get_type(name);
 
         writeln!(
             config.output_file,
@@ -119,52 +472,57 @@ impl Generate for Rust {
             free_fn = ty.ops.free,
         )?;
 
+        if config.async_api {
+            writeln!(
+                config.output_file,
+                include_str!("templates/rust/opaque_async.rs"),
+                rust_type = rust_type,
+            )?;
+        }
+
         let record = match &ty.record {
             Some(r) => r,
-            None => {
-                self.typemap.insert(name.to_string(), futhark_type.clone());
-                self.typemap.insert(futhark_type, rust_type);
-                return Ok(());
-            }
+            None => return Ok(()),
         };
 
         let mut new_call_args = vec![];
         let mut new_params = vec![];
         let mut new_extern_params = vec![];
         for field in record.fields.iter() {
-            // Build new function
-            let a = Self::get_type(&self.typemap, &field.r#type);
-            let t = Self::get_type(&self.typemap, &a);
-
-            let u = if t == field.r#type {
-                t.to_string()
-            } else {
-                format!("&{t}")
-            };
+            let field_type = self.types.get(&field.r#type);
+            let t = self.get_type(&field.r#type);
 
-            if type_is_opaque(&a) {
-                new_call_args.push(format!("field{}.data", field.name));
-                new_extern_params.push(format!("field{}: *const {a}", field.name));
-            } else if type_is_array(&t) {
-                new_call_args.push(format!("field{}.ptr", field.name));
-                new_extern_params.push(format!("field{}: *const {a}", field.name));
-            } else {
-                new_call_args.push(format!("field{}", field.name));
-                new_extern_params.push(format!("field{}: {a}", field.name));
+            // Build new function
+            match &field_type {
+                TypeRef::Scalar(e) => {
+                    let t = self.get_type(e.to_str());
+                    new_call_args.push(format!("field{}", field.name));
+                    new_extern_params.push(format!("field{}: {t}", field.name));
+                    new_params.push(format!("field{}: {t}", field.name));
+                }
+                TypeRef::Array { cname, .. } => {
+                    new_call_args.push(format!("field{}.ptr", field.name));
+                    new_extern_params.push(format!("field{}: *const {cname}", field.name));
+                    new_params.push(format!("field{}: &{t}", field.name));
+                }
+                _ => {
+                    let cname = field_type.cname().unwrap();
+                    new_call_args.push(format!("field{}.data", field.name));
+                    new_extern_params.push(format!("field{}: *const {cname}", field.name));
+                    new_params.push(format!("field{}: &{t}", field.name));
+                }
             }
 
-            new_params.push(format!("field{}: {u}", field.name));
-
             // Implement get function
 
             // If the output type is an array or opaque type then we need to wrap the return value
-            let (output, futhark_field_type) = if type_is_opaque(&a) || type_is_array(&t) {
-                (
+            let (output, futhark_field_type, rust_field_type) = match field_type.cname() {
+                Some(cname) => (
                     format!("Ok({t}::from_ptr(self.ctx, out))"),
-                    format!("*mut {a}"),
-                )
-            } else {
-                ("Ok(out)".to_string(), a)
+                    format!("*mut {cname}"),
+                    format!("{t}<'_>"),
+                ),
+                None => ("Ok(out)".to_string(), t.clone(), t.clone()),
             };
 
             writeln!(
@@ -175,7 +533,7 @@ impl Generate for Rust {
                 futhark_type = futhark_type,
                 field_name = field.name,
                 futhark_field_type = futhark_field_type,
-                rust_field_type = t,
+                rust_field_type = rust_field_type,
                 output = output
             )?;
         }
@@ -191,8 +549,118 @@ impl Generate for Rust {
             new_extern_params = new_extern_params.join(", "),
         )?;
 
-        self.typemap.insert(name.to_string(), futhark_type.clone());
-        self.typemap.insert(futhark_type, rust_type);
+        let has_refs = record
+            .fields
+            .iter()
+            .any(|f| !matches!(self.types.get(&f.r#type), TypeRef::Scalar(_)));
+        let mut setter_names = Names::new(RUST_BUILDER_METHODS);
+        let mut builder_fields = vec![];
+        let mut setters = vec![];
+        let mut build_fields = vec![];
+        let mut build_args = vec![];
+        for field in record.fields.iter() {
+            let t = match self.types.get(&field.r#type) {
+                TypeRef::Scalar(e) => self.get_type(e.to_str()),
+                _ => format!("&'b {}<'b>", self.get_type(&field.r#type)),
+            };
+            let name = &field.name;
+            let setter = if name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+                rust_ident(name)
+            } else {
+                format!("field{name}")
+            };
+            setter_names.insert(&setter, &format!("{rust_type}.{name}"))?;
+            builder_fields.push(format!("    field{name}: std::option::Option<{t}>,"));
+            setters.push(format!(
+                include_str!("templates/rust/record_builder_setter.rs"),
+                field_name = name,
+                setter = setter,
+                field_type = t,
+            ));
+            build_fields.push(format!(
+                "let field{name} = self.field{name}.ok_or(Error::MissingField(\"{name}\"))?;"
+            ));
+            build_args.push(format!("field{name}"));
+        }
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/rust/record_builder.rs"),
+            rust_type = rust_type,
+            builder_type = format!("{rust_type}Builder"),
+            lifetime = if has_refs { "<'b>" } else { "" },
+            builder_fields = builder_fields.join("\n"),
+            setters = setters.concat(),
+            build_fields = build_fields.join("\n        "),
+            build_args = build_args.join(", "),
+        )?;
+
+        if let (Some(plain_type), Some(fields)) =
+            (self.plain_types.get(name), self.types.scalar_record(name))
+        {
+            let plain_fields: Vec<_> = fields
+                .iter()
+                .map(|(f, e)| (f.as_str(), self.get_type(e.to_str())))
+                .collect();
+            let field_values = fields
+                .iter()
+                .map(|(f, _)| format!("self.{}", rust_ident(f)))
+                .collect::<Vec<_>>();
+            let get_fields = fields
+                .iter()
+                .map(|(f, _)| format!("{}: self.get_{f}()?", rust_ident(f)))
+                .collect::<Vec<_>>();
+            writeln!(
+                config.output_file,
+                include_str!("templates/rust/record_plain.rs"),
+                rust_type = rust_type,
+                plain_type = plain_type,
+                plain_fields = struct_body(&plain_fields),
+                field_values = field_values.join(", "),
+                get_fields = get_fields.join(", "),
+            )?;
+        }
+
+        if let Some(host_type) = self.host_types.get(name) {
+            let mut host_fields = Vec::new();
+            let mut to_host_fields = Vec::new();
+            let mut from_host = Vec::new();
+            let mut from_host_args = Vec::new();
+            for field in record.fields.iter() {
+                let f = &field.name;
+                let ident = rust_ident(f);
+                host_fields.push((f.as_str(), self.host_type(&field.r#type)));
+                match self.types.get(&field.r#type) {
+                    TypeRef::Scalar(_) => {
+                        to_host_fields.push(format!("{ident}: self.get_{f}()?"));
+                        from_host_args.push(format!("host.{ident}"));
+                    }
+                    _ => {
+                        let t = self.get_type(&field.r#type);
+                        to_host_fields.push(format!("{ident}: self.get_{f}()?.to_host()?"));
+                        from_host.push(format!(
+                            "let field{f} = {t}::from_host(ctx, &host.{ident})?;"
+                        ));
+                        from_host_args.push(format!("&field{f}"));
+                    }
+                }
+            }
+            from_host.push(format!("Self::new(ctx, {})", from_host_args.join(", ")));
+            writeln!(
+                config.output_file,
+                include_str!("templates/rust/record_host.rs"),
+                rust_type = rust_type,
+                host_type = host_type,
+                serde_derive = if config.serde {
+                    ", serde::Serialize, serde::Deserialize"
+                } else {
+                    ""
+                },
+                host_fields = struct_body(&host_fields),
+                to_host_fields = to_host_fields.join(", "),
+                from_host = from_host.join("\n        "),
+            )?;
+        }
 
         Ok(())
     }
@@ -206,63 +674,138 @@ impl Generate for Rust {
     ) -> Result<(), Error> {
         let mut call_args = Vec::new();
         let mut entry_params = Vec::new();
+        let mut entry_args = Vec::new();
         let mut return_type = Vec::new();
         let mut out_decl = Vec::new();
         let mut futhark_entry_params = Vec::new();
         let mut entry_return = Vec::new();
 
+        // Async entry points write out the lifetimes of their parameters
+        let mut async_entry_params = Vec::new();
+
+        // Fields of the output struct, if enabled
+        let mut output_fields = Vec::new();
+        let mut output_values = Vec::new();
+        let field_names = if config.output_structs {
+            output_field_names(name, entry)?
+        } else {
+            Vec::new()
+        };
+
         // Output arguments
         for (i, arg) in entry.outputs.iter().enumerate() {
-            let a = Self::get_type(&self.typemap, &arg.r#type);
-
             let name = format!("out{i}");
+            let t = self.get_type(&arg.r#type);
+            let field = field_names.get(i).cloned().unwrap_or_default();
+
+            match self.types.get(&arg.r#type).cname() {
+                Some(cname) => {
+                    futhark_entry_params.push(format!("{name}: *mut *mut {cname}"));
+                    entry_return.push(format!("{t}::from_ptr(self, {name}.assume_init())",));
+                }
+                None => {
+                    futhark_entry_params.push(format!("{name}: *mut {t}"));
+                    entry_return.push(format!("{name}.assume_init()"));
+                }
+            }
 
-            let t = Self::get_type(&self.typemap, &a);
+            out_decl.push(format!("let mut {name} = std::mem::MaybeUninit::zeroed()"));
+            call_args.push(format!("{name}.as_mut_ptr()"));
 
-            if type_is_array(&t) || type_is_opaque(&a) {
-                futhark_entry_params.push(format!("{name}: *mut *mut {a}"));
-            } else {
-                futhark_entry_params.push(format!("{name}: *mut {a}"));
+            // Plain records are loaded into their host representation
+            if let Some(plain) = self.plain_types.get(&arg.r#type) {
+                let opaque = entry_return.pop().unwrap();
+                entry_return.push(format!("{opaque}.get()?"));
+                output_fields.push(format!("    pub {field}: {plain},"));
+                output_values.push(format!("{field}: {}", entry_return.last().unwrap()));
+                return_type.push(plain.clone());
+                continue;
             }
 
-            if type_is_array(&t) || type_is_opaque(&a) {
-                entry_return.push(format!("{t}::from_ptr(self, {name}.assume_init())",));
-            } else {
-                entry_return.push(format!("{name}.assume_init()"));
+            match self.types.get(&arg.r#type) {
+                TypeRef::Scalar(_) => {
+                    output_fields.push(format!("    pub {field}: {t},"));
+                    return_type.push(t);
+                }
+                _ => {
+                    output_fields.push(format!("    pub {field}: {t}<'a>,"));
+                    return_type.push(format!("{t}<'_>"));
+                }
             }
-
-            out_decl.push(format!("let mut {name} = std::mem::MaybeUninit::zeroed();"));
-            call_args.push(format!("{name}.as_mut_ptr()"));
-            return_type.push(t);
+            output_values.push(format!("{field}: {}", entry_return.last().unwrap()));
         }
 
         // Input arguments
         for (i, arg) in entry.inputs.iter().enumerate() {
-            let a = Self::get_type(&self.typemap, &arg.r#type);
             let name = format!("input{i}");
-
-            let t = Self::get_type(&self.typemap, &a);
-
-            if type_is_array(&t) {
-                futhark_entry_params.push(format!("{name}: *const {a}"));
-
-                entry_params.push(format!("{name}: &{t}"));
-                call_args.push(format!("{name}.ptr as *mut _"));
-            } else if type_is_opaque(&a) {
-                futhark_entry_params.push(format!("{name}: *const {a}"));
-
-                entry_params.push(format!("{name}: &{t}"));
+            let t = self.get_type(&arg.r#type);
+            entry_args.push(name.clone());
+
+            if let Some(plain) = self.plain_types.get(&arg.r#type) {
+                futhark_entry_params.push(format!(
+                    "{name}: *const {}",
+                    self.types.get(&arg.r#type).cname().unwrap()
+                ));
+                entry_params.push(format!("{name}: {plain}"));
+                async_entry_params.push(format!("{name}: {plain}"));
+                out_decl.push(format!("let {name} = {name}.to_opaque(self)?"));
                 call_args.push(format!("{name}.data as *mut _"));
-            } else {
-                futhark_entry_params.push(format!("{name}: {a}"));
-                entry_params.push(format!("{name}: {t}"));
-                call_args.push(name);
+                continue;
+            }
+
+            match self.types.get(&arg.r#type) {
+                TypeRef::Scalar(_) => {
+                    futhark_entry_params.push(format!("{name}: {t}"));
+                    entry_params.push(format!("{name}: {t}"));
+                    async_entry_params.push(format!("{name}: {t}"));
+                    call_args.push(name);
+                }
+                TypeRef::Array { cname, .. } => {
+                    futhark_entry_params.push(format!("{name}: *const {cname}"));
+                    entry_params.push(format!("{name}: &{t}"));
+                    async_entry_params.push(format!("{name}: &{t}<'_>"));
+                    call_args.push(format!("{name}.ptr as *mut _"));
+                }
+                ty => {
+                    futhark_entry_params.push(format!("{name}: *const {}", ty.cname().unwrap()));
+                    entry_params.push(format!("{name}: &{t}"));
+                    async_entry_params.push(format!("{name}: &{t}<'_>"));
+                    call_args.push(format!("{name}.data as *mut _"));
+                }
             }
         }
 
+        let entry_name = config.naming.entry_name(name);
+        self.entry_names.insert(&entry_name, name)?;
+
         let (entry_return_type, entry_return) = match entry.outputs.len() {
             0 => ("()".to_string(), "()".to_string()),
             1 => (return_type.join(", "), entry_return.join(", ")),
+            _ if config.output_structs => {
+                let output_type = format!("{}Output", pascal_field_name(&entry_name));
+                self.type_names.insert(&output_type, name)?;
+                let has_lifetime = entry.outputs.iter().any(|x| {
+                    !matches!(self.types.get(&x.r#type), TypeRef::Scalar(_))
+                        && !self.plain_types.contains_key(&x.r#type)
+                });
+                writeln!(
+                    config.output_file,
+                    include_str!("templates/rust/entry_output.rs"),
+                    entry_name = entry_name,
+                    output_type = output_type,
+                    lifetime = if has_lifetime { "<'a>" } else { "" },
+                    output_fields = output_fields.join("\n"),
+                )?;
+                let return_type = if has_lifetime {
+                    format!("{output_type}<'_>")
+                } else {
+                    output_type.clone()
+                };
+                (
+                    return_type,
+                    format!("{output_type} {{ {} }}", output_values.join(", ")),
+                )
+            }
             _ => (
                 format!("({})", return_type.join(", ")),
                 format!("({})", entry_return.join(", ")),
@@ -273,64 +816,168 @@ impl Generate for Rust {
             config.output_file,
             include_str!("templates/rust/entry.rs"),
             entry_fn = entry.cfun,
-            entry_name = name,
+            entry_name = entry_name,
             entry_params = entry_params.join(", "),
             entry_return_type = entry_return_type,
-            out_decl = out_decl.join(";\n"),
+            out_decl = out_decl.join(";\n        "),
             call_args = call_args.join(", "),
             entry_return = entry_return,
             futhark_entry_params = futhark_entry_params.join(", "),
         )?;
 
+        if config.async_api {
+            self.entry_names
+                .insert(&format!("{entry_name}_async"), name)?;
+            writeln!(
+                config.output_file,
+                include_str!("templates/rust/entry_async.rs"),
+                entry_name = entry_name,
+                entry_params = async_entry_params.join(", "),
+                entry_args = entry_args.join(", "),
+                entry_return_type = entry_return_type,
+            )?;
+        }
+
+        if config.context_handle {
+            self.handle_entry(config, name, &entry_name, entry)?;
+        }
+
         Ok(())
     }
 
     fn bindings(&mut self, pkg: &Package, config: &mut Config) -> Result<(), Error> {
+        self.types = Types::new(&pkg.manifest)?;
+        self.resolve_names(pkg, config)?;
+
         writeln!(config.output_file, "// Generated by futhark-bindgen\n")?;
-        let backend_extern_functions = match &pkg.manifest.backend {
-            Backend::Multicore => {
-                "fn futhark_context_config_set_num_threads(_: *mut futhark_context_config, _: std::os::raw::c_int);"
-            }
-            Backend::OpenCL | Backend::CUDA => {
-                "fn futhark_context_config_set_device(_: *mut futhark_context_config, _: *const std::os::raw::c_char);"
-            }
-            _ => "",
-        };
+        let backend = pkg.manifest.backend;
+        let gpu = matches!(backend, Backend::CUDA | Backend::OpenCL | Backend::HIP);
+        let mut backend_extern_functions = Vec::new();
+        let mut backend_fields = Vec::new();
+        let mut backend_defaults = Vec::new();
+        let mut backend_options = Vec::new();
+        let mut configure_backend = Vec::new();
+
+        if backend == Backend::Multicore {
+            backend_extern_functions.push("fn futhark_context_config_set_num_threads(_: *mut futhark_context_config, _: std::os::raw::c_int);");
+            backend_fields.push("num_threads: u32,");
+            backend_defaults.push("num_threads: 0,");
+            backend_options.push(
+                "pub fn threads(mut self, n: u32) -> Options { self.num_threads = n as u32; self }",
+            );
+            configure_backend.push("futhark_context_config_set_num_threads(config, options.num_threads as std::os::raw::c_int);");
+        }
 
-        let backend_options = match pkg.manifest.backend {
-            Backend::Multicore => {
-                "pub fn threads(mut self, n: u32) -> Options { self.num_threads = n as u32; self }"
-            }
-            Backend::CUDA | Backend::OpenCL => {
-                "pub fn device(mut self, s: impl AsRef<str>) -> Options { self.device = Some(std::ffi::CString::new(s.as_ref()).expect(\"Invalid device\")); self }"
-            }
-            _ => "",
-        };
+        if matches!(backend, Backend::CUDA | Backend::OpenCL) {
+            backend_extern_functions.push("fn futhark_context_config_set_device(_: *mut futhark_context_config, _: *const std::os::raw::c_char);");
+            backend_fields.push("device: std::option::Option<std::ffi::CString>,");
+            backend_defaults.push("device: None,");
+            backend_options.push("pub fn device(mut self, s: impl AsRef<str>) -> Options { self.device = Some(std::ffi::CString::new(s.as_ref()).expect(\"Invalid device\")); self }");
+            configure_backend.push("if let Some(d) = &options.device { futhark_context_config_set_device(config, d.as_ptr()); }");
+        }
 
-        let configure_num_threads = if pkg.manifest.backend == Backend::Multicore {
-            "futhark_context_config_set_num_threads(config, options.num_threads as std::os::raw::c_int);"
-        } else {
-            "let _ = &options.num_threads;"
-        };
+        if backend == Backend::OpenCL {
+            backend_extern_functions.push("fn futhark_context_config_set_platform(_: *mut futhark_context_config, _: *const std::os::raw::c_char);");
+            backend_fields.push("platform: std::option::Option<std::ffi::CString>,");
+            backend_defaults.push("platform: None,");
+            backend_options.push("/// Select the OpenCL platform by name\n    pub fn platform(mut self, s: impl AsRef<str>) -> Options { self.platform = Some(std::ffi::CString::new(s.as_ref()).expect(\"Invalid platform\")); self }");
+            configure_backend.push("if let Some(p) = &options.platform { futhark_context_config_set_platform(config, p.as_ptr()); }");
+        }
 
-        let configure_set_device = if matches!(
-            pkg.manifest.backend,
-            Backend::CUDA | Backend::OpenCL
-        ) {
-            "if let Some(d) = &options.device { futhark_context_config_set_device(config, d.as_ptr()); }"
+        if gpu {
+            backend_extern_functions.push("fn futhark_context_config_set_default_group_size(_: *mut futhark_context_config, _: std::os::raw::c_int);");
+            backend_extern_functions.push("fn futhark_context_config_set_default_tile_size(_: *mut futhark_context_config, _: std::os::raw::c_int);");
+            backend_fields.push("default_group_size: std::option::Option<u32>,");
+            backend_fields.push("default_tile_size: std::option::Option<u32>,");
+            backend_defaults.push("default_group_size: None,");
+            backend_defaults.push("default_tile_size: None,");
+            backend_options.push("/// Set the default group size\n    pub fn default_group_size(mut self, n: u32) -> Options { self.default_group_size = Some(n); self }");
+            backend_options.push("/// Set the default tile size\n    pub fn default_tile_size(mut self, n: u32) -> Options { self.default_tile_size = Some(n); self }");
+            configure_backend.push("if let Some(n) = options.default_group_size { futhark_context_config_set_default_group_size(config, n as std::os::raw::c_int); }");
+            configure_backend.push("if let Some(n) = options.default_tile_size { futhark_context_config_set_default_tile_size(config, n as std::os::raw::c_int); }");
+        }
+
+        if matches!(backend, Backend::CUDA | Backend::HIP) {
+            backend_extern_functions.push("fn futhark_context_config_set_unified_memory(_: *mut futhark_context_config, _: std::os::raw::c_int);");
+            backend_fields.push("unified_memory: std::option::Option<bool>,");
+            backend_defaults.push("unified_memory: None,");
+            backend_options.push("/// Enable or disable unified memory, by default it's used when supported by the device\n    pub fn unified_memory(mut self, enable: bool) -> Options { self.unified_memory = Some(enable); self }");
+            configure_backend.push("if let Some(x) = options.unified_memory { futhark_context_config_set_unified_memory(config, x as std::os::raw::c_int); }");
+        }
+
+        let (async_context_field, async_context_init, async_context_drop) = if config.async_api {
+            (
+                "\n    sync_worker: std::sync::OnceLock<SyncWorker>,",
+                ", sync_worker: std::sync::OnceLock::new()",
+                "\n        if let Some(worker) = self.sync_worker.take() {\n            worker.stop();\n        }",
+            )
         } else {
-            "let _ = &options.device;"
+            ("", "", "")
         };
 
+        let mut error_variants = String::new();
+        let mut error_display = String::new();
+        if config.context_handle {
+            error_variants.push_str("\n    WorkerStopped,");
+            error_display.push_str(
+                "\n            Error::WorkerStopped => write!(fmt, \"Context worker thread has stopped\"),",
+            );
+        }
+        if config.parsed_report {
+            error_variants.push_str("\n    InvalidReport(String),");
+            error_display.push_str(
+                "\n            Error::InvalidReport(msg) => write!(fmt, \"Invalid report: {msg}\"),",
+            );
+        }
+
         writeln!(
             config.output_file,
             include_str!("templates/rust/context.rs"),
-            backend_options = backend_options,
-            configure_num_threads = configure_num_threads,
-            configure_set_device = configure_set_device,
-            backend_extern_functions = backend_extern_functions,
+            error_variants = error_variants,
+            error_display = error_display,
+            backend_fields = backend_fields
+                .iter()
+                .map(|x| format!("\n    {x}"))
+                .collect::<String>(),
+            backend_defaults = backend_defaults
+                .iter()
+                .map(|x| format!("\n            {x}"))
+                .collect::<String>(),
+            backend_options = backend_options.join("\n\n    "),
+            configure_backend = configure_backend.join("\n            "),
+            backend_extern_functions = backend_extern_functions.join("\n    "),
+            async_context_field = async_context_field,
+            async_context_init = async_context_init,
+            async_context_drop = async_context_drop,
         )?;
 
+        if config.parsed_report {
+            writeln!(config.output_file, include_str!("templates/rust/report.rs"))?;
+        }
+
+        if config.async_api {
+            writeln!(config.output_file, include_str!("templates/rust/async.rs"))?;
+        }
+
+        if config.context_handle || config.serde {
+            let serde_derive = if config.serde {
+                include_str!("templates/rust/host_array_serde.rs")
+            } else {
+                ""
+            };
+            writeln!(
+                config.output_file,
+                include_str!("templates/rust/host_array.rs"),
+                serde_derive = serde_derive.trim_end(),
+            )?;
+        }
+
+        if config.context_handle {
+            // `run` is the only method on `ContextHandle` that isn't generated for an entry point
+            self.entry_names.insert("run", "ContextHandle")?;
+            writeln!(config.output_file, include_str!("templates/rust/handle.rs"))?;
+        }
+
         Ok(())
     }
 
@@ -0,0 +1,25 @@
+// Copy a `{name}` array into a new host buffer, which must be freed using `free`
+static inline int {prefix}_copy_{suffix}(struct futhark_context *ctx, {prefix}_error *err,
+    struct {futhark_type} *arr, {c_elemtype} **data, int64_t shape[{rank}]) {{
+  const int64_t *dims = {shape_fn}(ctx, arr);
+  int64_t size = 1;
+  for (int i = 0; i < {rank}; i++) {{
+    shape[i] = dims[i];
+    size *= dims[i];
+  }}
+  // Allocate at least one element, `malloc(0)` may return NULL
+  *data = ({c_elemtype} *)malloc((size > 0 ? (size_t)size : 1) * sizeof({c_elemtype}));
+  if (*data == NULL) {{
+    return {prefix}_set_error(ctx, err, {prefix_upper}_ERR_ALLOC);
+  }}
+  int rc = {values_fn}(ctx, arr, *data);
+  if (rc == 0) {{
+    rc = futhark_context_sync(ctx);
+  }}
+  if (rc != 0) {{
+    free(*data);
+    *data = NULL;
+    return {prefix}_set_error(ctx, err, rc);
+  }}
+  return 0;
+}}
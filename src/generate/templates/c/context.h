@@ -0,0 +1,60 @@
+#ifndef {guard}
+#define {guard}
+
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "{header}"
+
+// Error codes for failures that aren't reported by Futhark
+#define {prefix_upper}_ERR_NULL_PTR (-1)
+#define {prefix_upper}_ERR_ALLOC (-2)
+
+// Error reported by the helper functions, `code` is zero on success
+typedef struct {prefix}_error {{
+  int code;
+  // Error message, freed by `{prefix}_error_free`
+  char *message;
+}} {prefix}_error;
+
+// Free the message stored in `err` and reset it
+static inline void {prefix}_error_free({prefix}_error *err) {{
+  if (err == NULL) {{
+    return;
+  }}
+  free(err->message);
+  err->message = NULL;
+  err->code = 0;
+}}
+
+static inline char *{prefix}_copy_string(const char *s) {{
+  size_t n = strlen(s) + 1;
+  char *copy = (char *)malloc(n);
+  if (copy != NULL) {{
+    memcpy(copy, s, n);
+  }}
+  return copy;
+}}
+
+// Store `code` in `err` and return it, Futhark errors take the message from the context
+static inline int {prefix}_set_error(struct futhark_context *ctx, {prefix}_error *err, int code) {{
+  if (err == NULL) {{
+    return code;
+  }}
+  free(err->message);
+  err->code = code;
+  switch (code) {{
+  case {prefix_upper}_ERR_NULL_PTR:
+    err->message = {prefix}_copy_string("NULL pointer encountered");
+    break;
+  case {prefix_upper}_ERR_ALLOC:
+    err->message = {prefix}_copy_string("Unable to allocate host memory");
+    break;
+  default:
+    err->message = futhark_context_get_error(ctx);
+    break;
+  }}
+  return code;
+}}
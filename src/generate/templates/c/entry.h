@@ -0,0 +1,16 @@
+// Run the `{name}` entry point and wait for it to finish, array outputs are copied into new
+// host buffers which must be freed using `free`. On failure the outputs are set to NULL and
+// the error is stored in `err`
+static inline int {prefix}_{entry_name}({params}) {{
+  int rc = 0;
+{decls}{setup}  rc = {entry_fn}({call_args});
+  if (rc == 0) {{
+    rc = futhark_context_sync(ctx);
+  }}
+  if (rc != 0) {{
+    rc = {prefix}_set_error(ctx, err, rc);
+    goto done;
+  }}
+{copy_outputs}done:
+{cleanup}  return rc;
+}}
@@ -0,0 +1,99 @@
+/// <summary>Array type with {rank} dimensions and {elemtype} elements</summary>
+public sealed class {cs_type} : IDisposable
+{{
+    [DllImport(Native.Lib)]
+    private static extern IntPtr {new_fn}(ContextHandle ctx, ref {native_elemtype} data, {dim_params});
+
+    [DllImport(Native.Lib)]
+    private static extern int {free_fn}(IntPtr ctx, IntPtr arr);
+
+    [DllImport(Native.Lib)]
+    private static extern int {values_fn}(ContextHandle ctx, FutharkHandle arr, ref {native_elemtype} data);
+
+    [DllImport(Native.Lib)]
+    private static extern IntPtr {shape_fn}(ContextHandle ctx, FutharkHandle arr);
+
+    private sealed class Handle : FutharkHandle
+    {{
+        public Handle(ContextHandle ctx, IntPtr ptr) : base(ctx, ptr)
+        {{
+        }}
+
+        protected override void Free(IntPtr ctx, IntPtr ptr)
+        {{
+            {free_fn}(ctx, ptr);
+        }}
+    }}
+
+    private readonly Context ctx;
+    internal readonly FutharkHandle handle;
+    private readonly long[] shape = new long[{rank}];
+
+    internal {cs_type}(Context ctx, IntPtr ptr)
+    {{
+        this.ctx = ctx;
+        handle = new Handle(ctx.handle, ptr);
+        Marshal.Copy({shape_fn}(ctx.handle, handle), shape, 0, {rank});
+    }}
+
+    /// <summary>Create a new array with the shape <paramref name="dims"/>, initialized with the values from <paramref name="data"/></summary>
+    public {cs_type}(Context ctx, ReadOnlySpan<{cs_elemtype}> data, params long[] dims)
+    {{
+        long size = 1;
+        foreach (long d in dims)
+        {{
+            size *= d;
+        }}
+        if (dims.Length != {rank} || size != data.Length)
+        {{
+            throw new ArgumentException($"Invalid shape: expected {{size}} elements, got {{data.Length}}", nameof(data));
+        }}
+        this.ctx = ctx;
+        IntPtr ptr = {new_fn}(ctx.handle, ref MemoryMarshal.GetReference({native_data}), {dim_args});
+        handle = new Handle(ctx.handle, ptr);
+        dims.CopyTo(shape, 0);
+        ctx.MaybeSync();
+    }}
+
+    /// <summary>Shape of the array</summary>
+    public ReadOnlySpan<long> Shape => shape;
+
+    /// <summary>Number of elements in the array</summary>
+    public long Length
+    {{
+        get
+        {{
+            long size = 1;
+            foreach (long d in shape)
+            {{
+                size *= d;
+            }}
+            return size;
+        }}
+    }}
+
+    /// <summary>Copy the values into <paramref name="output"/>, which must have the same number of elements as the array</summary>
+    public void Values(Span<{cs_elemtype}> output)
+    {{
+        if (output.Length != Length)
+        {{
+            throw new ArgumentException($"Invalid shape: expected {{Length}} elements, got {{output.Length}}", nameof(output));
+        }}
+        ctx.Check({values_fn}(ctx.handle, handle, ref MemoryMarshal.GetReference({native_output})));
+        ctx.MaybeSync();
+    }}
+
+    /// <summary>Copy the values into a new array</summary>
+    public {cs_elemtype}[] ToArray()
+    {{
+        var output = new {cs_elemtype}[Length];
+        Values(output);
+        return output;
+    }}
+
+    /// <summary>Free the array, it is also freed when it is garbage collected</summary>
+    public void Dispose()
+    {{
+        handle.Dispose();
+    }}
+}}
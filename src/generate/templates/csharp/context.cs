@@ -0,0 +1,252 @@
+#nullable enable
+
+using System;
+using System.Runtime.InteropServices;
+
+namespace {namespace};
+
+internal static class Native
+{{
+    // Resolved as `lib{lib}.so` on Linux
+    public const string Lib = "{lib}";
+}}
+
+/// <summary>Thrown when a Futhark function returns a non-zero error code</summary>
+public class FutharkException : Exception
+{{
+    public int Code {{ get; }}
+
+    public FutharkException(int code, string message) : base($"Futhark error code {{code}}: {{message}}")
+    {{
+        Code = code;
+    }}
+}}
+
+/// <summary>Thrown when a Futhark function returns a NULL pointer</summary>
+public class NullPtrException : Exception
+{{
+    public NullPtrException() : base("NULL pointer encountered")
+    {{
+    }}
+}}
+
+/// <summary>Options used to configure a <see cref="Context"/></summary>
+public sealed class Options
+{{
+    public bool Debug {{ get; set; }}
+    public bool Profile {{ get; set; }}
+    public bool Logging {{ get; set; }}
+    public string? CacheFile {{ get; set; }}
+    public bool AutoSync {{ get; set; }} = true;
+{backend_options}}}
+
+internal sealed class ContextHandle : SafeHandle
+{{
+    [DllImport(Native.Lib)]
+    private static extern int futhark_context_sync(IntPtr ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_free(IntPtr ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_config_free(IntPtr config);
+
+    private readonly IntPtr config;
+    private readonly IntPtr cacheFile;
+
+    public ContextHandle(IntPtr config, IntPtr ctx, IntPtr cacheFile) : base(IntPtr.Zero, true)
+    {{
+        this.config = config;
+        this.cacheFile = cacheFile;
+        SetHandle(ctx);
+    }}
+
+    public override bool IsInvalid => handle == IntPtr.Zero;
+
+    protected override bool ReleaseHandle()
+    {{
+        futhark_context_sync(handle);
+        futhark_context_free(handle);
+        futhark_context_config_free(config);
+        Marshal.FreeCoTaskMem(cacheFile);
+        return true;
+    }}
+}}
+
+// Handle to a value owned by a context, the context isn't freed until all of its values are
+internal abstract class FutharkHandle : SafeHandle
+{{
+    private readonly ContextHandle ctx;
+
+    protected FutharkHandle(ContextHandle ctx, IntPtr ptr) : base(IntPtr.Zero, true)
+    {{
+        if (ptr == IntPtr.Zero)
+        {{
+            throw new NullPtrException();
+        }}
+        bool added = false;
+        ctx.DangerousAddRef(ref added);
+        this.ctx = ctx;
+        SetHandle(ptr);
+    }}
+
+    public override bool IsInvalid => handle == IntPtr.Zero;
+
+    protected abstract void Free(IntPtr ctx, IntPtr ptr);
+
+    protected override bool ReleaseHandle()
+    {{
+        Free(ctx.DangerousGetHandle(), handle);
+        ctx.DangerousRelease();
+        return true;
+    }}
+}}
+
+/// <summary>Futhark context</summary>
+/// <remarks>Values created using the context keep it alive until they are disposed</remarks>
+public sealed partial class Context : IDisposable
+{{
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_context_config_new();
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_config_free(IntPtr config);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_config_set_debugging(IntPtr config, int flag);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_config_set_profiling(IntPtr config, int flag);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_config_set_logging(IntPtr config, int flag);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_config_set_cache_file(IntPtr config, IntPtr path);
+{backend_extern_functions}
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_context_new(IntPtr config);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_context_sync(ContextHandle ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_context_clear_caches(ContextHandle ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_pause_profiling(ContextHandle ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_unpause_profiling(ContextHandle ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_context_get_error(ContextHandle ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_context_report(ContextHandle ctx);
+
+    internal readonly ContextHandle handle;
+
+    /// <summary>Sync after every operation</summary>
+    public bool AutoSync {{ get; set; }}
+
+    /// <summary>Create a new context, using the default options if <paramref name="options"/> is null</summary>
+    public Context(Options? options = null)
+    {{
+        options ??= new Options();
+        IntPtr config = futhark_context_config_new();
+        if (config == IntPtr.Zero)
+        {{
+            throw new NullPtrException();
+        }}
+        futhark_context_config_set_debugging(config, options.Debug ? 1 : 0);
+        futhark_context_config_set_profiling(config, options.Profile ? 1 : 0);
+        futhark_context_config_set_logging(config, options.Logging ? 1 : 0);
+        IntPtr cacheFile = IntPtr.Zero;
+        if (options.CacheFile != null)
+        {{
+            cacheFile = Marshal.StringToCoTaskMemUTF8(options.CacheFile);
+            futhark_context_config_set_cache_file(config, cacheFile);
+        }}
+{configure_backend}
+        IntPtr ctx = futhark_context_new(config);
+        if (ctx == IntPtr.Zero)
+        {{
+            futhark_context_config_free(config);
+            Marshal.FreeCoTaskMem(cacheFile);
+            throw new NullPtrException();
+        }}
+        handle = new ContextHandle(config, ctx, cacheFile);
+        AutoSync = options.AutoSync;
+    }}
+
+    /// <summary>Free the context once all values created using it have been disposed</summary>
+    public void Dispose()
+    {{
+        handle.Dispose();
+    }}
+
+    internal void Check(int rc)
+    {{
+        if (rc != 0)
+        {{
+            throw new FutharkException(rc, GetError() ?? "");
+        }}
+    }}
+
+    /// <summary>Wait for all pending operations to finish</summary>
+    public void Sync()
+    {{
+        Check(futhark_context_sync(handle));
+    }}
+
+    internal void MaybeSync()
+    {{
+        if (AutoSync)
+        {{
+            Sync();
+        }}
+    }}
+
+    /// <summary>Free any cached memory</summary>
+    public void ClearCaches()
+    {{
+        Check(futhark_context_clear_caches(handle));
+    }}
+
+    /// <summary>Pause profiling</summary>
+    public void PauseProfiling()
+    {{
+        futhark_context_pause_profiling(handle);
+    }}
+
+    /// <summary>Resume profiling</summary>
+    public void UnpauseProfiling()
+    {{
+        futhark_context_unpause_profiling(handle);
+    }}
+
+    /// <summary>Get the last error message</summary>
+    public string? GetError()
+    {{
+        return TakeString(futhark_context_get_error(handle));
+    }}
+
+    /// <summary>Get the profiling report</summary>
+    public string? Report()
+    {{
+        return TakeString(futhark_context_report(handle));
+    }}
+
+    private static string? TakeString(IntPtr s)
+    {{
+        if (s == IntPtr.Zero)
+        {{
+            return null;
+        }}
+        string? result = Marshal.PtrToStringUTF8(s);
+        // Futhark allocates strings using `malloc`, `NativeMemory.Free` calls the matching `free`
+        unsafe {{ NativeMemory.Free((void*)s); }}
+        return result;
+    }}
+}}
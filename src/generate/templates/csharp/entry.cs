@@ -0,0 +1,11 @@
+public sealed partial class Context
+{{
+    [DllImport(Native.Lib)]
+    private static extern int {entry_fn}({extern_params});
+
+    /// <summary>Entry point: {name}</summary>
+    public {entry_return_type} {entry_name}({entry_params})
+    {{
+        Check({entry_fn}({call_args}));
+{entry_return}    }}
+}}
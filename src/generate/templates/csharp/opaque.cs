@@ -0,0 +1,33 @@
+/// <summary>Futhark type</summary>
+public sealed class {cs_type} : IDisposable
+{{
+    [DllImport(Native.Lib)]
+    private static extern int {free_fn}(IntPtr ctx, IntPtr obj);
+{record_externs}
+    private sealed class Handle : FutharkHandle
+    {{
+        public Handle(ContextHandle ctx, IntPtr ptr) : base(ctx, ptr)
+        {{
+        }}
+
+        protected override void Free(IntPtr ctx, IntPtr ptr)
+        {{
+            {free_fn}(ctx, ptr);
+        }}
+    }}
+
+    private readonly Context ctx;
+    internal readonly FutharkHandle handle;
+
+    internal {cs_type}(Context ctx, IntPtr ptr)
+    {{
+        this.ctx = ctx;
+        handle = new Handle(ctx.handle, ptr);
+    }}
+
+    /// <summary>Free the value, it is also freed when it is garbage collected</summary>
+    public void Dispose()
+    {{
+        handle.Dispose();
+    }}
+{record_methods}}}
@@ -0,0 +1,9 @@
+
+    /// <summary>Create a new {cs_type} from its fields</summary>
+    public {cs_type}(Context ctx, {new_params})
+    {{
+        this.ctx = ctx;
+        ctx.Check({new_fn}(ctx.handle, out IntPtr ptr, {new_call_args}));
+        handle = new Handle(ctx.handle, ptr);
+        ctx.MaybeSync();
+    }}
@@ -0,0 +1,9 @@
+
+    /// <summary>Get the {field_name} field</summary>
+    public {cs_field_type} {getter}()
+    {{
+        ctx.Check({project_fn}(ctx.handle, out {out_type} output, handle));
+        {cs_field_type} result = {output};
+        ctx.MaybeSync();
+        return result;
+    }}
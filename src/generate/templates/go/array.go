@@ -0,0 +1,102 @@
+// {go_type} is an array with {rank} dimensions and {elemtype} elements
+type {go_type} struct {{
+	ptr   *C.struct_{futhark_type}
+	shape [{rank}]int64
+	ctx   *Context
+}}
+
+// New{go_type} creates a new array with the shape `dims`, initialized with the values from `data`
+func New{go_type}(ctx *Context, data []{go_elemtype}, dims [{rank}]int64) (*{go_type}, error) {{
+	if err := ctx.check(); err != nil {{
+		return nil, err
+	}}
+	size := int64(1)
+	for _, d := range dims {{
+		size *= d
+	}}
+	if int64(len(data)) != size {{
+		return nil, ErrInvalidShape
+	}}
+	var p *C.{c_elemtype}
+	if len(data) > 0 {{
+		p = (*C.{c_elemtype})(unsafe.Pointer(&data[0]))
+	}}
+	ptr := C.{new_fn}(ctx.handle, p, {dim_args})
+	if ptr == nil {{
+		return nil, ErrNullPtr
+	}}
+	if err := ctx.maybeSync(); err != nil {{
+		C.{free_fn}(ctx.handle, ptr)
+		return nil, err
+	}}
+	return new{go_type}(ctx, ptr), nil
+}}
+
+func new{go_type}(ctx *Context, ptr *C.struct_{futhark_type}) *{go_type} {{
+	a := &{go_type}{{ptr: ptr, ctx: ctx}}
+	shape := unsafe.Slice(C.{shape_fn}(ctx.handle, ptr), {rank})
+	for i := range a.shape {{
+		a.shape[i] = int64(shape[i])
+	}}
+	runtime.SetFinalizer(a, (*{go_type}).Free)
+	return a
+}}
+
+// Free frees the array, it is also freed when it is garbage collected
+func (a *{go_type}) Free() {{
+	if a.ptr != nil && a.ctx.handle != nil {{
+		C.{free_fn}(a.ctx.handle, a.ptr)
+	}}
+	a.ptr = nil
+	runtime.SetFinalizer(a, nil)
+}}
+
+func (a *{go_type}) check() error {{
+	if a.ptr == nil {{
+		return ErrFreed
+	}}
+	return a.ctx.check()
+}}
+
+// Shape returns the shape of the array
+func (a *{go_type}) Shape() []int64 {{
+	return a.shape[:]
+}}
+
+// Len returns the number of elements in the array
+func (a *{go_type}) Len() int {{
+	size := int64(1)
+	for _, d := range a.shape {{
+		size *= d
+	}}
+	return int(size)
+}}
+
+// Values copies the values into `out`, which must have the same number of elements as the array
+func (a *{go_type}) Values(out []{go_elemtype}) error {{
+	if err := a.check(); err != nil {{
+		return err
+	}}
+	if len(out) != a.Len() {{
+		return ErrInvalidShape
+	}}
+	var p *C.{c_elemtype}
+	if len(out) > 0 {{
+		p = (*C.{c_elemtype})(unsafe.Pointer(&out[0]))
+	}}
+	rc := C.{values_fn}(a.ctx.handle, a.ptr, p)
+	runtime.KeepAlive(a)
+	if rc != 0 {{
+		return a.ctx.error(rc)
+	}}
+	return a.ctx.maybeSync()
+}}
+
+// Get copies the values into a new slice
+func (a *{go_type}) Get() ([]{go_elemtype}, error) {{
+	out := make([]{go_elemtype}, a.Len())
+	if err := a.Values(out); err != nil {{
+		return nil, err
+	}}
+	return out, nil
+}}
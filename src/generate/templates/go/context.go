@@ -0,0 +1,187 @@
+package {package}
+
+/*
+#cgo LDFLAGS: {ldflags}
+#include <stdlib.h>
+#include "{header}"
+*/
+import "C"
+
+import (
+	"errors"
+	"fmt"
+	"runtime"
+	"unsafe"
+)
+
+var (
+	// ErrNullPtr is returned when a Futhark function returns a NULL pointer
+	ErrNullPtr = errors.New("futhark: NULL pointer encountered")
+
+	// ErrInvalidShape is returned when data doesn't match the shape of an array
+	ErrInvalidShape = errors.New("futhark: invalid shape")
+
+	// ErrFreed is returned when a value is used after it has been freed
+	ErrFreed = errors.New("futhark: use after free")
+)
+
+// Error is returned when a Futhark function returns a non-zero error code
+type Error struct {{
+	Code    int
+	Message string
+}}
+
+func (e *Error) Error() string {{
+	return fmt.Sprintf("futhark: error code %d: %s", e.Code, e.Message)
+}}
+
+// Options used to configure a Context
+type Options struct {{
+	Debug      bool
+	Profile    bool
+	Logging    bool
+	CacheFile  string
+	NoAutoSync bool
+{backend_options}
+}}
+
+// Context is a Futhark context, it is freed by Close or when it is garbage collected
+type Context struct {{
+	config    *C.struct_futhark_context_config
+	handle    *C.struct_futhark_context
+	autoSync  bool
+	cacheFile *C.char
+}}
+
+func boolToInt(b bool) C.int {{
+	if b {{
+		return 1
+	}}
+	return 0
+}}
+
+// NewContext creates a new context, options may be nil
+func NewContext(options *Options) (*Context, error) {{
+	if options == nil {{
+		options = &Options{{}}
+	}}
+	config := C.futhark_context_config_new()
+	if config == nil {{
+		return nil, ErrNullPtr
+	}}
+	C.futhark_context_config_set_debugging(config, boolToInt(options.Debug))
+	C.futhark_context_config_set_profiling(config, boolToInt(options.Profile))
+	C.futhark_context_config_set_logging(config, boolToInt(options.Logging))
+	var cacheFile *C.char
+	if options.CacheFile != "" {{
+		cacheFile = C.CString(options.CacheFile)
+		C.futhark_context_config_set_cache_file(config, cacheFile)
+	}}
+{configure_backend}
+	handle := C.futhark_context_new(config)
+	if handle == nil {{
+		C.futhark_context_config_free(config)
+		C.free(unsafe.Pointer(cacheFile))
+		return nil, ErrNullPtr
+	}}
+	ctx := &Context{{config: config, handle: handle, autoSync: !options.NoAutoSync, cacheFile: cacheFile}}
+	runtime.SetFinalizer(ctx, (*Context).Close)
+	return ctx, nil
+}}
+
+// Close frees the context, values created using the context can no longer be used
+func (ctx *Context) Close() {{
+	if ctx.handle == nil {{
+		return
+	}}
+	C.futhark_context_sync(ctx.handle)
+	C.futhark_context_free(ctx.handle)
+	C.futhark_context_config_free(ctx.config)
+	C.free(unsafe.Pointer(ctx.cacheFile))
+	ctx.handle = nil
+	ctx.cacheFile = nil
+	runtime.SetFinalizer(ctx, nil)
+}}
+
+func (ctx *Context) error(rc C.int) error {{
+	msg, _ := ctx.GetError()
+	return &Error{{Code: int(rc), Message: msg}}
+}}
+
+func (ctx *Context) check() error {{
+	if ctx.handle == nil {{
+		return ErrFreed
+	}}
+	return nil
+}}
+
+// Sync waits for all pending operations to finish
+func (ctx *Context) Sync() error {{
+	if err := ctx.check(); err != nil {{
+		return err
+	}}
+	if rc := C.futhark_context_sync(ctx.handle); rc != 0 {{
+		return ctx.error(rc)
+	}}
+	return nil
+}}
+
+func (ctx *Context) maybeSync() error {{
+	if ctx.autoSync {{
+		return ctx.Sync()
+	}}
+	return nil
+}}
+
+// ClearCaches frees any cached memory
+func (ctx *Context) ClearCaches() error {{
+	if err := ctx.check(); err != nil {{
+		return err
+	}}
+	if rc := C.futhark_context_clear_caches(ctx.handle); rc != 0 {{
+		return ctx.error(rc)
+	}}
+	return nil
+}}
+
+// PauseProfiling pauses profiling
+func (ctx *Context) PauseProfiling() error {{
+	if err := ctx.check(); err != nil {{
+		return err
+	}}
+	C.futhark_context_pause_profiling(ctx.handle)
+	return nil
+}}
+
+// UnpauseProfiling resumes profiling
+func (ctx *Context) UnpauseProfiling() error {{
+	if err := ctx.check(); err != nil {{
+		return err
+	}}
+	C.futhark_context_unpause_profiling(ctx.handle)
+	return nil
+}}
+
+func takeString(s *C.char) string {{
+	if s == nil {{
+		return ""
+	}}
+	defer C.free(unsafe.Pointer(s))
+	return C.GoString(s)
+}}
+
+// GetError returns the last error message
+func (ctx *Context) GetError() (string, error) {{
+	if err := ctx.check(); err != nil {{
+		return "", err
+	}}
+	return takeString(C.futhark_context_get_error(ctx.handle)), nil
+}}
+
+// Report returns the profiling report
+func (ctx *Context) Report() (string, error) {{
+	if err := ctx.check(); err != nil {{
+		return "", err
+	}}
+	return takeString(C.futhark_context_report(ctx.handle)), nil
+}}
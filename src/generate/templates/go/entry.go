@@ -0,0 +1,13 @@
+// {entry_name} calls the {name} entry point
+func (ctx *Context) {entry_name}({entry_params}) ({entry_results}err error) {{
+	if err = ctx.check(); err != nil {{
+		return
+	}}
+{input_checks}{out_decl}	rc := C.{entry_fn}(ctx.handle, {call_args})
+{keep_alive}	if rc != 0 {{
+		err = ctx.error(rc)
+		return
+	}}
+{entry_return}	err = ctx.maybeSync()
+	return
+}}
@@ -0,0 +1,27 @@
+// {go_type} is an opaque Futhark value
+type {go_type} struct {{
+	ptr *C.struct_{futhark_type}
+	ctx *Context
+}}
+
+func new{go_type}(ctx *Context, ptr *C.struct_{futhark_type}) *{go_type} {{
+	t := &{go_type}{{ptr: ptr, ctx: ctx}}
+	runtime.SetFinalizer(t, (*{go_type}).Free)
+	return t
+}}
+
+// Free frees the value, it is also freed when it is garbage collected
+func (t *{go_type}) Free() {{
+	if t.ptr != nil && t.ctx.handle != nil {{
+		C.{free_fn}(t.ctx.handle, t.ptr)
+	}}
+	t.ptr = nil
+	runtime.SetFinalizer(t, nil)
+}}
+
+func (t *{go_type}) check() error {{
+	if t.ptr == nil {{
+		return ErrFreed
+	}}
+	return t.ctx.check()
+}}
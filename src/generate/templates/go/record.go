@@ -0,0 +1,16 @@
+// New{go_type} creates a new {go_type} from its fields
+func New{go_type}(ctx *Context, {new_params}) (*{go_type}, error) {{
+	if err := ctx.check(); err != nil {{
+		return nil, err
+	}}
+{new_checks}	var out *C.struct_{futhark_type}
+	rc := C.{new_fn}(ctx.handle, &out, {new_call_args})
+{new_keep_alive}	if rc != 0 {{
+		return nil, ctx.error(rc)
+	}}
+	if err := ctx.maybeSync(); err != nil {{
+		C.{free_fn}(ctx.handle, out)
+		return nil, err
+	}}
+	return new{go_type}(ctx, out), nil
+}}
@@ -0,0 +1,16 @@
+// {getter} returns the {field_name} field
+func (t *{go_type}) {getter}() ({go_field_type}, error) {{
+	if err := t.check(); err != nil {{
+		return {zero}, err
+	}}
+	var out {c_field_type}
+	rc := C.{project_fn}(t.ctx.handle, &out, t.ptr)
+	runtime.KeepAlive(t)
+	if rc != 0 {{
+		return {zero}, t.ctx.error(rc)
+	}}
+	if err := t.ctx.maybeSync(); err != nil {{
+		return {zero}, err
+	}}
+	return {output}, nil
+}}
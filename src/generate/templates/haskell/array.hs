@@ -0,0 +1,47 @@
+data {c_type}
+
+foreign import ccall unsafe "{new_fn}" {new_fn} :: Ptr FutharkContext -> Ptr {hs_elemtype} -> {dim_types} -> IO (Ptr {c_type})
+foreign import ccall unsafe "{free_fn}" {free_fn} :: Ptr FutharkContext -> Ptr {c_type} -> IO CInt
+foreign import ccall safe "{values_fn}" {values_fn} :: Ptr FutharkContext -> Ptr {c_type} -> Ptr {hs_elemtype} -> IO CInt
+foreign import ccall unsafe "{shape_fn}" {shape_fn} :: Ptr FutharkContext -> Ptr {c_type} -> IO (Ptr Int64)
+
+-- | Array type with {rank} dimensions and {elemtype} elements
+data {hs_type} = {hs_type} (ForeignPtr {c_type}) [Int64] Context
+
+wrap{hs_type} :: Context -> Ptr {c_type} -> IO {hs_type}
+wrap{hs_type} ctx ptr = do
+  shape <- withContext ctx $ \c -> {shape_fn} c ptr >>= peekArray {rank}
+  fp <- FC.newForeignPtr ptr (freeWith ctx (\c -> {free_fn} c ptr))
+  return ({hs_type} fp shape ctx)
+
+-- | Create a new array with the shape `dims`, initialized with the values from `v`
+new{hs_type} :: Context -> [Int64] -> V.Vector {hs_elemtype} -> IO {hs_type}
+new{hs_type} ctx dims v = do
+  when (P.length dims /= {rank}) $ throwIO (InvalidShape {rank} (P.length dims))
+  let size = P.fromIntegral (P.product dims)
+  when (size /= V.length v) $ throwIO (InvalidShape size (V.length v))
+  ptr <- withContext ctx $ \c -> V.unsafeWith v $ \p -> {new_fn} c p {dim_args}
+  when (ptr == nullPtr) $ throwIO NullPtr
+  autoSync ctx
+  wrap{hs_type} ctx ptr
+
+-- | Use the raw array pointer
+with{hs_type} :: {hs_type} -> (Ptr {c_type} -> IO a) -> IO a
+with{hs_type} ({hs_type} fp _ ctx) f = withContext ctx $ \_ -> withForeignPtr fp f
+
+-- | Free the array, it is also freed when it is garbage collected
+free{hs_type} :: {hs_type} -> IO ()
+free{hs_type} ({hs_type} fp _ _) = finalizeForeignPtr fp
+
+-- | Get the shape of the array
+{hs_fn}Shape :: {hs_type} -> [Int64]
+{hs_fn}Shape ({hs_type} _ shape _) = shape
+
+-- | Copy the values into a new vector
+{hs_fn}ToVector :: {hs_type} -> IO (V.Vector {hs_elemtype})
+{hs_fn}ToVector arr@({hs_type} _ shape ctx) = do
+  mv <- MV.new (P.fromIntegral (P.product shape))
+  rc <- withContext ctx $ \c -> with{hs_type} arr $ \p -> MV.unsafeWith mv ({values_fn} c p)
+  check ctx rc
+  autoSync ctx
+  V.unsafeFreeze mv
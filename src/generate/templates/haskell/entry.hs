@@ -0,0 +1,10 @@
+foreign import ccall safe "{entry_fn}" {entry_fn} :: Ptr FutharkContext -> {foreign_params}IO CInt
+
+-- | Entry point: {name}
+{entry_name} :: Context -> {param_types}IO {return_type}
+{entry_name} ctx {params}=
+{chain} do
+    rc <- {entry_fn} c {call_args}
+    check ctx rc
+    autoSync ctx
+{read_outputs}    return {entry_return}
@@ -0,0 +1,19 @@
+data {c_type}
+
+foreign import ccall unsafe "{free_fn}" {free_fn} :: Ptr FutharkContext -> Ptr {c_type} -> IO CInt
+
+-- | Futhark type
+data {hs_type} = {hs_type} (ForeignPtr {c_type}) Context
+
+wrap{hs_type} :: Context -> Ptr {c_type} -> IO {hs_type}
+wrap{hs_type} ctx ptr = do
+  fp <- FC.newForeignPtr ptr (freeWith ctx (\c -> {free_fn} c ptr))
+  return ({hs_type} fp ctx)
+
+-- | Use the raw pointer
+with{hs_type} :: {hs_type} -> (Ptr {c_type} -> IO a) -> IO a
+with{hs_type} ({hs_type} fp ctx) f = withContext ctx $ \_ -> withForeignPtr fp f
+
+-- | Free the value, it is also freed when it is garbage collected
+free{hs_type} :: {hs_type} -> IO ()
+free{hs_type} ({hs_type} fp _) = finalizeForeignPtr fp
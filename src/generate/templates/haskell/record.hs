@@ -0,0 +1,10 @@
+foreign import ccall unsafe "{new_fn}" {new_fn} :: Ptr FutharkContext -> Ptr (Ptr {c_type}) -> {new_foreign_params}IO CInt
+
+-- | Create a new {hs_type} from its fields
+new{hs_type} :: Context -> {new_param_types}IO {hs_type}
+new{hs_type} ctx {new_params}=
+{chain} do
+    rc <- {new_fn} c out {new_call_args}
+    check ctx rc
+    autoSync ctx
+    peek out >>= wrap{hs_type} ctx
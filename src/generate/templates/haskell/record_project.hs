@@ -0,0 +1,12 @@
+foreign import ccall unsafe "{project_fn}" {project_fn} :: Ptr FutharkContext -> Ptr {c_field_type} -> Ptr {c_type} -> IO CInt
+
+-- | Get the {field_name} field
+{getter} :: {hs_type} -> IO {hs_field_type}
+{getter} t@({hs_type} _ ctx) =
+  withContext ctx $ \c ->
+  with{hs_type} t $ \p ->
+  alloca $ \out -> do
+    rc <- {project_fn} c out p
+    check ctx rc
+    autoSync ctx
+    {output}
@@ -0,0 +1,73 @@
+    /** Array type with {rank} dimensions and {elemtype} elements */
+    public static final class {java_type} extends FutharkValue {{
+        private static final MethodHandle {NEW_FN} =
+            function("{new_fn}", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS, {dim_layouts}));
+        private static final MethodHandle {FREE_FN} =
+            function("{free_fn}", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS));
+        private static final MethodHandle {VALUES_FN} =
+            function("{values_fn}", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+        private static final MethodHandle {SHAPE_FN} =
+            function("{shape_fn}", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS));
+
+        private final long[] shape;
+
+        {java_type}(Context ctx, MemorySegment ptr) {{
+            super(ctx, ptr, {FREE_FN});
+            MemorySegment dims = (MemorySegment) call({SHAPE_FN}, ctx.handle(), ptr);
+            this.shape = dims.reinterpret({rank} * JAVA_LONG.byteSize()).toArray(JAVA_LONG);
+        }}
+
+        /** Create a new array with the shape {{@code dims}} from the values in {{@code data}} */
+        public static {java_type} from(Context ctx, {java_elemtype}[] data, long... dims) {{
+            if (product(dims) != data.length) {{
+                throw new IllegalArgumentException("Invalid shape, expected " + product(dims) + " elements, got " + data.length);
+            }}
+            try (Arena arena = Arena.ofConfined()) {{
+                return from(ctx, {to_segment}, dims);
+            }}
+        }}
+
+        /** Create a new array with the shape {{@code dims}} by copying the values in {{@code data}} */
+        public static {java_type} from(Context ctx, MemorySegment data, long... dims) {{
+            if (dims.length != {rank}) {{
+                throw new IllegalArgumentException("Expected {rank} dimensions, got " + dims.length);
+            }}
+            if (data.byteSize() < product(dims) * {layout}.byteSize()) {{
+                throw new IllegalArgumentException("Memory segment is too small for the given shape");
+            }}
+            MemorySegment ptr = (MemorySegment) call({NEW_FN}, ctx.handle(), data, {dim_args});
+            {java_type} array = new {java_type}(ctx, ptr);
+            ctx.maybeSync();
+            return array;
+        }}
+
+        /** Get the shape of the array */
+        public long[] shape() {{
+            return shape.clone();
+        }}
+
+        /** Get the number of elements in the array */
+        public long size() {{
+            return product(shape);
+        }}
+
+        /** Copy the values of the array into {{@code out}} */
+        public void values(MemorySegment out) {{
+            if (out.byteSize() < size() * {layout}.byteSize()) {{
+                throw new IllegalArgumentException("Memory segment is too small for the array");
+            }}
+            int rc = (int) call({VALUES_FN}, ctx.handle(), ptr(), out);
+            Reference.reachabilityFence(this);
+            ctx.check(rc);
+            ctx.maybeSync();
+        }}
+
+        /** Copy the values of the array into a new Java array */
+        public {java_elemtype}[] toArray() {{
+            try (Arena arena = Arena.ofConfined()) {{
+                MemorySegment out = arena.allocate({layout}, size());
+                values(out);
+                return {from_segment};
+            }}
+        }}
+    }}
@@ -0,0 +1,200 @@
+import java.lang.foreign.Arena;
+import java.lang.foreign.FunctionDescriptor;
+import java.lang.foreign.Linker;
+import java.lang.foreign.MemorySegment;
+import java.lang.foreign.SymbolLookup;
+import java.lang.invoke.MethodHandle;
+import java.lang.ref.Cleaner;
+import java.lang.ref.Reference;
+import java.util.Objects;
+
+import static java.lang.foreign.ValueLayout.*;
+
+/** Bindings for the Futhark library {{@code {stem}}}, using the Foreign Function and Memory API */
+public final class {class_name} {{
+    private {class_name}() {{
+    }}
+
+    // Loaded from the path in the `futhark.lib.{stem}` system property, or `lib{stem}.so`
+    private static final SymbolLookup LIB = SymbolLookup.libraryLookup(
+        System.getProperty("futhark.lib.{stem}", System.mapLibraryName("{stem}")), Arena.global());
+    private static final Linker LINKER = Linker.nativeLinker();
+    private static final Cleaner CLEANER = Cleaner.create();
+    private static final MethodHandle FREE =
+        LINKER.downcallHandle(LINKER.defaultLookup().find("free").orElseThrow(), FunctionDescriptor.ofVoid(ADDRESS));
+
+    static MethodHandle function(String name, FunctionDescriptor desc) {{
+        MemorySegment f = LIB.find(name).orElseThrow(() -> new UnsatisfiedLinkError("Missing symbol: " + name));
+        return LINKER.downcallHandle(f, desc);
+    }}
+
+    static Object call(MethodHandle f, Object... args) {{
+        try {{
+            return f.invokeWithArguments(args);
+        }} catch (RuntimeException | Error e) {{
+            throw e;
+        }} catch (Throwable e) {{
+            throw new RuntimeException(e);
+        }}
+    }}
+
+    static String takeString(MemorySegment s) {{
+        if (s.equals(MemorySegment.NULL)) {{
+            return null;
+        }}
+        String result = s.reinterpret(Long.MAX_VALUE).getString(0);
+        call(FREE, s);
+        return result;
+    }}
+
+    static long product(long[] dims) {{
+        long size = 1;
+        for (long d : dims) {{
+            size *= d;
+        }}
+        return size;
+    }}
+
+    // `boolean` arrays can't be copied to and from memory segments directly
+    static MemorySegment toSegment(Arena arena, boolean[] data) {{
+        MemorySegment out = arena.allocate(JAVA_BOOLEAN, data.length);
+        for (int i = 0; i < data.length; i++) {{
+            out.setAtIndex(JAVA_BOOLEAN, i, data[i]);
+        }}
+        return out;
+    }}
+
+    static boolean[] toBooleans(MemorySegment data) {{
+        boolean[] out = new boolean[(int) data.byteSize()];
+        for (int i = 0; i < out.length; i++) {{
+            out[i] = data.getAtIndex(JAVA_BOOLEAN, i);
+        }}
+        return out;
+    }}
+
+    /** Thrown when a Futhark function returns a non-zero error code */
+    public static class FutharkException extends RuntimeException {{
+        private final int code;
+
+        public FutharkException(int code, String message) {{
+            super("Futhark error code " + code + ": " + message);
+            this.code = code;
+        }}
+
+        public int code() {{
+            return code;
+        }}
+    }}
+
+    /** Thrown when a Futhark function returns a NULL pointer */
+    public static class NullPtrException extends RuntimeException {{
+        public NullPtrException() {{
+            super("NULL pointer encountered");
+        }}
+    }}
+
+    /** Options used to configure a {{@link Context}} */
+    public static final class Options {{
+        boolean debug;
+        boolean profile;
+        boolean logging;
+        String cacheFile;
+        boolean autoSync = true;
+{backend_option_fields}
+        /** Enable debugging */
+        public Options debug(boolean debug) {{
+            this.debug = debug;
+            return this;
+        }}
+
+        /** Enable profiling */
+        public Options profile(boolean profile) {{
+            this.profile = profile;
+            return this;
+        }}
+
+        /** Enable logging */
+        public Options logging(boolean logging) {{
+            this.logging = logging;
+            return this;
+        }}
+
+        /** Set the Futhark cache file */
+        public Options cacheFile(String cacheFile) {{
+            this.cacheFile = cacheFile;
+            return this;
+        }}
+
+        /** Sync after every operation, enabled by default */
+        public Options autoSync(boolean autoSync) {{
+            this.autoSync = autoSync;
+            return this;
+        }}
+{backend_option_setters}    }}
+
+    // Shared by a context and the values created using it, the context is freed once all of
+    // them have been closed
+    static final class ContextState {{
+        final MemorySegment config;
+        final MemorySegment handle;
+        final Arena arena;
+        private int refs = 1;
+
+        ContextState(MemorySegment config, MemorySegment handle, Arena arena) {{
+            this.config = config;
+            this.handle = handle;
+            this.arena = arena;
+        }}
+
+        synchronized void retain() {{
+            if (refs == 0) {{
+                throw new IllegalStateException("Context has already been freed");
+            }}
+            refs++;
+        }}
+
+        synchronized void release() {{
+            if (--refs == 0) {{
+                call(Context.FUTHARK_CONTEXT_SYNC, handle);
+                call(Context.FUTHARK_CONTEXT_FREE, handle);
+                call(Context.FUTHARK_CONTEXT_CONFIG_FREE, config);
+                arena.close();
+            }}
+        }}
+    }}
+
+    /** Base class of arrays and opaque values, freed by {{@link #close}} or when they are garbage collected */
+    public abstract static class FutharkValue implements AutoCloseable {{
+        final Context ctx;
+        private final MemorySegment ptr;
+        private final Cleaner.Cleanable cleanable;
+        private volatile boolean closed;
+
+        FutharkValue(Context ctx, MemorySegment ptr, MethodHandle free) {{
+            if (ptr.equals(MemorySegment.NULL)) {{
+                throw new NullPtrException();
+            }}
+            ContextState state = ctx.state;
+            state.retain();
+            this.ctx = ctx;
+            this.ptr = ptr;
+            this.cleanable = CLEANER.register(this, () -> {{
+                call(free, state.handle, ptr);
+                state.release();
+            }});
+        }}
+
+        MemorySegment ptr() {{
+            if (closed) {{
+                throw new IllegalStateException("Value has already been closed");
+            }}
+            return ptr;
+        }}
+
+        /** Free the value */
+        @Override
+        public void close() {{
+            closed = true;
+            cleanable.clean();
+        }}
+    }}
@@ -0,0 +1,130 @@
+    /** Futhark context, values created using the context keep it alive until they are closed */
+    public static final class Context implements AutoCloseable {{
+        static final MethodHandle FUTHARK_CONTEXT_CONFIG_NEW =
+            function("futhark_context_config_new", FunctionDescriptor.of(ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_CONFIG_FREE =
+            function("futhark_context_config_free", FunctionDescriptor.ofVoid(ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_CONFIG_SET_DEBUGGING =
+            function("futhark_context_config_set_debugging", FunctionDescriptor.ofVoid(ADDRESS, JAVA_INT));
+        static final MethodHandle FUTHARK_CONTEXT_CONFIG_SET_PROFILING =
+            function("futhark_context_config_set_profiling", FunctionDescriptor.ofVoid(ADDRESS, JAVA_INT));
+        static final MethodHandle FUTHARK_CONTEXT_CONFIG_SET_LOGGING =
+            function("futhark_context_config_set_logging", FunctionDescriptor.ofVoid(ADDRESS, JAVA_INT));
+        static final MethodHandle FUTHARK_CONTEXT_CONFIG_SET_CACHE_FILE =
+            function("futhark_context_config_set_cache_file", FunctionDescriptor.ofVoid(ADDRESS, ADDRESS));
+{backend_functions}        static final MethodHandle FUTHARK_CONTEXT_NEW =
+            function("futhark_context_new", FunctionDescriptor.of(ADDRESS, ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_FREE =
+            function("futhark_context_free", FunctionDescriptor.ofVoid(ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_SYNC =
+            function("futhark_context_sync", FunctionDescriptor.of(JAVA_INT, ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_CLEAR_CACHES =
+            function("futhark_context_clear_caches", FunctionDescriptor.of(JAVA_INT, ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_PAUSE_PROFILING =
+            function("futhark_context_pause_profiling", FunctionDescriptor.ofVoid(ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_UNPAUSE_PROFILING =
+            function("futhark_context_unpause_profiling", FunctionDescriptor.ofVoid(ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_GET_ERROR =
+            function("futhark_context_get_error", FunctionDescriptor.of(ADDRESS, ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_REPORT =
+            function("futhark_context_report", FunctionDescriptor.of(ADDRESS, ADDRESS));
+
+        final ContextState state;
+        private final Cleaner.Cleanable cleanable;
+        private volatile boolean closed;
+        private volatile boolean autoSync;
+
+        /** Create a new context using the default options */
+        public Context() {{
+            this(new Options());
+        }}
+
+        /** Create a new context */
+        public Context(Options options) {{
+            MemorySegment config = (MemorySegment) call(FUTHARK_CONTEXT_CONFIG_NEW);
+            if (config.equals(MemorySegment.NULL)) {{
+                throw new NullPtrException();
+            }}
+            call(FUTHARK_CONTEXT_CONFIG_SET_DEBUGGING, config, options.debug ? 1 : 0);
+            call(FUTHARK_CONTEXT_CONFIG_SET_PROFILING, config, options.profile ? 1 : 0);
+            call(FUTHARK_CONTEXT_CONFIG_SET_LOGGING, config, options.logging ? 1 : 0);
+            // Strings passed to the config are kept alive until the context is freed
+            Arena arena = Arena.ofShared();
+            if (options.cacheFile != null) {{
+                call(FUTHARK_CONTEXT_CONFIG_SET_CACHE_FILE, config, arena.allocateFrom(options.cacheFile));
+            }}
+{configure_backend}
+            MemorySegment handle = (MemorySegment) call(FUTHARK_CONTEXT_NEW, config);
+            if (handle.equals(MemorySegment.NULL)) {{
+                call(FUTHARK_CONTEXT_CONFIG_FREE, config);
+                arena.close();
+                throw new NullPtrException();
+            }}
+            ContextState state = new ContextState(config, handle, arena);
+            this.state = state;
+            this.cleanable = CLEANER.register(this, state::release);
+            this.autoSync = options.autoSync;
+        }}
+
+        MemorySegment handle() {{
+            if (closed) {{
+                throw new IllegalStateException("Context has already been closed");
+            }}
+            return state.handle;
+        }}
+
+        /** Free the context once all values created using it have been closed */
+        @Override
+        public void close() {{
+            closed = true;
+            cleanable.clean();
+        }}
+
+        void check(int rc) {{
+            if (rc != 0) {{
+                throw new FutharkException(rc, Objects.requireNonNullElse(getError(), ""));
+            }}
+        }}
+
+        void maybeSync() {{
+            if (autoSync) {{
+                sync();
+            }}
+        }}
+
+        /** Sync after every operation */
+        public void setAutoSync(boolean autoSync) {{
+            this.autoSync = autoSync;
+        }}
+
+        /** Wait for all pending operations to finish */
+        public void sync() {{
+            check((int) call(FUTHARK_CONTEXT_SYNC, handle()));
+        }}
+
+        /** Free any cached memory */
+        public void clearCaches() {{
+            check((int) call(FUTHARK_CONTEXT_CLEAR_CACHES, handle()));
+        }}
+
+        /** Pause profiling */
+        public void pauseProfiling() {{
+            call(FUTHARK_CONTEXT_PAUSE_PROFILING, handle());
+        }}
+
+        /** Resume profiling */
+        public void unpauseProfiling() {{
+            call(FUTHARK_CONTEXT_UNPAUSE_PROFILING, handle());
+        }}
+
+        /** Get the last error message */
+        public String getError() {{
+            return takeString((MemorySegment) call(FUTHARK_CONTEXT_GET_ERROR, handle()));
+        }}
+
+        /** Get the profiling report */
+        public String report() {{
+            return takeString((MemorySegment) call(FUTHARK_CONTEXT_REPORT, handle()));
+        }}
+{entries}    }}
+}}
@@ -0,0 +1,11 @@
+
+        private static final MethodHandle {ENTRY_FN} =
+            function("{entry_fn}", FunctionDescriptor.of(JAVA_INT, {layouts}));
+
+        /** Entry point: {name} */
+        public {return_type} {entry_name}({entry_params}) {{
+            try (Arena arena = Arena.ofConfined()) {{
+{out_decl}                int rc = (int) call({ENTRY_FN}, {call_args});
+{fences}                check(rc);
+{entry_return}            }}
+        }}
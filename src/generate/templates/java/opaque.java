@@ -0,0 +1,8 @@
+    /** Opaque Futhark type */
+    public static final class {java_type} extends FutharkValue {{
+        private static final MethodHandle {FREE_FN} =
+            function("{free_fn}", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS));
+{record_functions}
+        {java_type}(Context ctx, MemorySegment ptr) {{
+            super(ctx, ptr, {FREE_FN});
+        }}
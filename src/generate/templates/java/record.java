@@ -0,0 +1,12 @@
+
+        /** Create a new {java_type} from its fields */
+        public static {java_type} create(Context ctx, {new_params}) {{
+            try (Arena arena = Arena.ofConfined()) {{
+                MemorySegment out = arena.allocate(ADDRESS);
+                int rc = (int) call({NEW_FN}, ctx.handle(), out, {new_call_args});
+{new_fences}                ctx.check(rc);
+                {java_type} result = new {java_type}(ctx, out.get(ADDRESS, 0));
+                ctx.maybeSync();
+                return result;
+            }}
+        }}
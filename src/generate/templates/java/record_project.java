@@ -0,0 +1,13 @@
+
+        /** Get the {field_name} field */
+        public {field_type} {getter}() {{
+            try (Arena arena = Arena.ofConfined()) {{
+                MemorySegment out = arena.allocate({out_layout});
+                int rc = (int) call({PROJECT_FN}, ctx.handle(), out, ptr());
+                Reference.reachabilityFence(this);
+                ctx.check(rc);
+                {field_type} result = {output};
+                ctx.maybeSync();
+                return result;
+            }}
+        }}
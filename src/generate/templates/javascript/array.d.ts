@@ -0,0 +1,16 @@
+/** Array type with {rank} dimensions and {elemtype} elements */
+export declare class {js_type} {{
+  /** Create a new array with the shape `dims`, initialized with the values from `data` */
+  static from(ctx: Context, data: {typed_array} | ArrayLike<{js_elemtype}>, dims?: number[]): {js_type};
+
+  readonly shape: number[];
+
+  /** Number of elements in the array */
+  readonly length: number;
+
+  /** Copy the values into a new typed array */
+  values(): {typed_array};
+
+  /** Free the array, it is also freed when it is garbage collected */
+  free(): void;
+}}
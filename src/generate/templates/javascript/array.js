@@ -0,0 +1,56 @@
+/** Array type with {rank} dimensions and {elemtype} elements */
+export class {js_type} {{
+  constructor(ctx, ptr) {{
+    const shape = ctx.module._{shape_fn}(ctx.handle, ptr);
+    this.ctx = ctx;
+    this.ptr = ptr;
+    this.shape = Array.from(ctx.view(BigInt64Array, shape, {rank}), Number);
+    registry?.register(this, () => {{
+      if (ctx.handle !== 0) ctx.module._{free_fn}(ctx.handle, ptr);
+    }}, this);
+  }}
+
+  /** Create a new array with the shape `dims`, initialized with the values from `data` */
+  static from(ctx, data, dims = [data.length]) {{
+    const size = dims.reduce((a, b) => a * b, 1);
+    if (dims.length !== {rank} || size !== data.length) {{
+      throw new InvalidShapeError(size, data.length);
+    }}
+    const ptr = ctx.withScratch(size * {elem_size}, (p) => {{
+      ctx.view({typed_array}, p, size).set({to_typed});
+      return ctx.module._{new_fn}(ctx.checkHandle(), p, ...dims.map(BigInt));
+    }});
+    if (ptr === 0) throw new NullPtrError();
+    ctx.maybeSync();
+    return new {js_type}(ctx, ptr);
+  }}
+
+  checkPtr() {{
+    if (this.ptr === 0) throw new Error("Array has already been freed");
+    return this.ptr;
+  }}
+
+  /** Number of elements in the array */
+  get length() {{
+    return this.shape.reduce((a, b) => a * b, 1);
+  }}
+
+  /** Copy the values into a new typed array */
+  values() {{
+    const ctx = this.ctx;
+    return ctx.withScratch(this.length * {elem_size}, (p) => {{
+      ctx.check(ctx.module._{values_fn}(ctx.checkHandle(), this.checkPtr(), p));
+      ctx.maybeSync();
+      return ctx.view({typed_array}, p, this.length).slice();
+    }});
+  }}
+
+  /** Free the array, it is also freed when it is garbage collected */
+  free() {{
+    if (this.ptr !== 0 && this.ctx.handle !== 0) {{
+      this.ctx.module._{free_fn}(this.ctx.handle, this.ptr);
+    }}
+    this.ptr = 0;
+    registry?.unregister(this);
+  }}
+}}
@@ -0,0 +1,55 @@
+/** Instantiated Emscripten module created by `futhark wasm --library` */
+export interface FutharkModule {{
+  HEAPU8: Uint8Array;
+  _malloc(size: number): number;
+  _free(ptr: number): void;
+  [name: string]: any;
+}}
+
+/** Context options */
+export interface Options {{
+  debug?: boolean;
+  profile?: boolean;
+  logging?: boolean;
+  autoSync?: boolean;
+{backend_options}
+}}
+
+/** Thrown when a Futhark function returns a non-zero error code */
+export declare class FutharkError extends Error {{
+  readonly code: number;
+}}
+
+/** Thrown when a Futhark function returns a NULL pointer */
+export declare class NullPtrError extends Error {{}}
+
+/** Thrown when data doesn't match the shape of an array */
+export declare class InvalidShapeError extends Error {{}}
+
+/** Futhark context */
+export declare class Context {{
+  constructor(module: FutharkModule, options?: Options);
+  readonly module: FutharkModule;
+  autoSync: boolean;
+
+  /** Free the context, values created using the context can no longer be used */
+  free(): void;
+
+  /** Wait for all pending operations to finish */
+  sync(): void;
+
+  /** Free any cached memory */
+  clearCaches(): void;
+
+  /** Pause profiling */
+  pauseProfiling(): void;
+
+  /** Resume profiling */
+  unpauseProfiling(): void;
+
+  /** Get the last error message */
+  getError(): string | null;
+
+  /** Get the profiling report */
+  report(): string | null;
+}}
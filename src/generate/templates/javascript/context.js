@@ -0,0 +1,128 @@
+// `module` is an instantiated Emscripten module created by `futhark wasm --library` or
+// `futhark wasm-multicore --library`, 64-bit integers are passed as `BigInt` values
+
+/** Thrown when a Futhark function returns a non-zero error code */
+export class FutharkError extends Error {{
+  constructor(code, message) {{
+    super(`Futhark error code ${{code}}: ${{message}}`);
+    this.code = code;
+  }}
+}}
+
+/** Thrown when a Futhark function returns a NULL pointer */
+export class NullPtrError extends Error {{
+  constructor() {{
+    super("NULL pointer encountered");
+  }}
+}}
+
+/** Thrown when data doesn't match the shape of an array */
+export class InvalidShapeError extends Error {{
+  constructor(expected, actual) {{
+    super(`Invalid shape: expected ${{expected}} elements, got ${{actual}}`);
+  }}
+}}
+
+// Frees values that are garbage collected without calling `free`
+const registry =
+  typeof FinalizationRegistry !== "undefined"
+    ? new FinalizationRegistry((free) => free())
+    : null;
+
+function readString(module, ptr) {{
+  if (ptr === 0) return null;
+  let end = ptr;
+  while (module.HEAPU8[end] !== 0) end++;
+  const s = new TextDecoder().decode(module.HEAPU8.slice(ptr, end));
+  module._free(ptr);
+  return s;
+}}
+
+/** Futhark context */
+export class Context {{
+  constructor(module, options = {{}}) {{
+    const m = module;
+    const config = m._futhark_context_config_new();
+    if (config === 0) throw new NullPtrError();
+    m._futhark_context_config_set_debugging(config, options.debug ? 1 : 0);
+    m._futhark_context_config_set_profiling(config, options.profile ? 1 : 0);
+    m._futhark_context_config_set_logging(config, options.logging ? 1 : 0);
+{configure_backend}
+    const handle = m._futhark_context_new(config);
+    if (handle === 0) {{
+      m._futhark_context_config_free(config);
+      throw new NullPtrError();
+    }}
+    this.module = m;
+    this.config = config;
+    this.handle = handle;
+    this.autoSync = options.autoSync ?? true;
+  }}
+
+  /** Free the context, values created using the context can no longer be used */
+  free() {{
+    if (this.handle === 0) return;
+    this.module._futhark_context_sync(this.handle);
+    this.module._futhark_context_free(this.handle);
+    this.module._futhark_context_config_free(this.config);
+    this.handle = 0;
+  }}
+
+  checkHandle() {{
+    if (this.handle === 0) throw new Error("Context has already been freed");
+    return this.handle;
+  }}
+
+  check(rc) {{
+    if (rc !== 0) throw new FutharkError(rc, this.getError() ?? "");
+  }}
+
+  /** Wait for all pending operations to finish */
+  sync() {{
+    this.check(this.module._futhark_context_sync(this.checkHandle()));
+  }}
+
+  maybeSync() {{
+    if (this.autoSync) this.sync();
+  }}
+
+  /** Free any cached memory */
+  clearCaches() {{
+    this.check(this.module._futhark_context_clear_caches(this.checkHandle()));
+  }}
+
+  /** Pause profiling */
+  pauseProfiling() {{
+    this.module._futhark_context_pause_profiling(this.checkHandle());
+  }}
+
+  /** Resume profiling */
+  unpauseProfiling() {{
+    this.module._futhark_context_unpause_profiling(this.checkHandle());
+  }}
+
+  /** Get the last error message */
+  getError() {{
+    return readString(this.module, this.module._futhark_context_get_error(this.checkHandle()));
+  }}
+
+  /** Get the profiling report */
+  report() {{
+    return readString(this.module, this.module._futhark_context_report(this.checkHandle()));
+  }}
+
+  // Allocate `size` bytes of scratch memory, pass it to `f` and free it afterwards
+  withScratch(size, f) {{
+    const ptr = this.module._malloc(Math.max(size, 8));
+    if (ptr === 0) throw new NullPtrError();
+    try {{
+      return f(ptr);
+    }} finally {{
+      this.module._free(ptr);
+    }}
+  }}
+
+  view(type, ptr, length) {{
+    return new type(this.module.HEAPU8.buffer, ptr, length);
+  }}
+}}
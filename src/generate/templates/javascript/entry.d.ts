@@ -0,0 +1,4 @@
+export interface Context {{
+  /** Entry point: {name} */
+  {entry_name}({entry_params}): {entry_return_type};
+}}
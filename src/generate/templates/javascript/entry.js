@@ -0,0 +1,9 @@
+/** Entry point: {name} */
+Context.prototype.{entry_name} = function ({entry_params}) {{
+  const ctx = this;
+  return ctx.withScratch({scratch_size}, (out) => {{
+    ctx.check(ctx.module._{entry_fn}(ctx.checkHandle(), {call_args}));
+    ctx.maybeSync();
+    return {entry_return};
+  }});
+}};
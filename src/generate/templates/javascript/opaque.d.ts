@@ -0,0 +1,5 @@
+/** Futhark type */
+export declare class {js_type} {{
+  /** Free the value, it is also freed when it is garbage collected */
+  free(): void;
+{record_methods}}}
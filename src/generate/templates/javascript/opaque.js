@@ -0,0 +1,24 @@
+/** Futhark type */
+export class {js_type} {{
+  constructor(ctx, ptr) {{
+    this.ctx = ctx;
+    this.ptr = ptr;
+    registry?.register(this, () => {{
+      if (ctx.handle !== 0) ctx.module._{free_fn}(ctx.handle, ptr);
+    }}, this);
+  }}
+
+  checkPtr() {{
+    if (this.ptr === 0) throw new Error("Value has already been freed");
+    return this.ptr;
+  }}
+
+  /** Free the value, it is also freed when it is garbage collected */
+  free() {{
+    if (this.ptr !== 0 && this.ctx.handle !== 0) {{
+      this.ctx.module._{free_fn}(this.ctx.handle, this.ptr);
+    }}
+    this.ptr = 0;
+    registry?.unregister(this);
+  }}
+{record_methods}}}
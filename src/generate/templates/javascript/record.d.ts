@@ -0,0 +1,3 @@
+
+  /** Create a new {js_type} from its fields */
+  static create(ctx: Context, {new_params}): {js_type};
@@ -0,0 +1,9 @@
+
+  /** Create a new {js_type} from its fields */
+  static create(ctx, {new_params}) {{
+    return ctx.withScratch(4, (out) => {{
+      ctx.check(ctx.module._{new_fn}(ctx.checkHandle(), out, {new_call_args}));
+      ctx.maybeSync();
+      return new {js_type}(ctx, ctx.view(Uint32Array, out, 1)[0]);
+    }});
+  }}
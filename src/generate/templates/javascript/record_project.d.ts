@@ -0,0 +1,3 @@
+
+  /** Get the {field_name} field */
+  {getter}(): {js_field_type};
@@ -0,0 +1,10 @@
+
+  /** Get the {field_name} field */
+  {getter}() {{
+    const ctx = this.ctx;
+    return ctx.withScratch(8, (out) => {{
+      ctx.check(ctx.module._{project_fn}(ctx.checkHandle(), out, this.checkPtr()));
+      ctx.maybeSync();
+      return {output};
+    }});
+  }}
@@ -0,0 +1,56 @@
+"""
+Array type with {rank} dimensions and {elemtype} elements
+"""
+mutable struct {jl_type}
+    ptr::Ptr{{Cvoid}}
+    shape::NTuple{{{rank},Int64}}
+    ctx::Context
+
+    function {jl_type}(ctx::Context, ptr::Ptr{{Cvoid}})
+        shape = unsafe_load(ccall((:{shape_fn}, LIB), Ptr{{NTuple{{{rank},Int64}}}}, (Ptr{{Cvoid}}, Ptr{{Cvoid}}), ctx.handle, ptr))
+        finalizer(free!, new(ptr, shape, ctx))
+    end
+end
+
+"""
+Create a new array from a Julia array, the array keeps the same shape
+"""
+function {jl_type}(ctx::Context, data::AbstractArray{{{jl_elemtype},{rank}}})
+    dims = size(data)
+    data = row_major(data)
+    ptr = ccall((:{new_fn}, LIB), Ptr{{Cvoid}}, (Ptr{{Cvoid}}, Ptr{{{jl_elemtype}}}, {dim_types}), check_handle(ctx), data, dims...)
+    ptr == C_NULL && throw(NullPtrError())
+    auto_sync!(ctx)
+    {jl_type}(ctx, ptr)
+end
+
+"""
+Free the array, it is also freed when it is garbage collected
+"""
+function free!(a::{jl_type})
+    if a.ptr != C_NULL && a.ctx.handle != C_NULL
+        ccall((:{free_fn}, LIB), Cint, (Ptr{{Cvoid}}, Ptr{{Cvoid}}), a.ctx.handle, a.ptr)
+    end
+    a.ptr = C_NULL
+    nothing
+end
+
+function check_ptr(a::{jl_type})
+    a.ptr == C_NULL && error("Array has already been freed")
+    a.ptr
+end
+
+Base.size(a::{jl_type}) = Int.(a.shape)
+Base.length(a::{jl_type}) = prod(a.shape)
+Base.eltype(::Type{{{jl_type}}}) = {jl_elemtype}
+
+"""
+Copy the values into a new Julia array with the same shape
+"""
+function Base.Array(a::{jl_type})
+    out = Array{{{jl_elemtype},{rank}}}(undef, reverse(size(a))...)
+    rc = GC.@preserve a ccall((:{values_fn}, LIB), Cint, (Ptr{{Cvoid}}, Ptr{{Cvoid}}, Ptr{{{jl_elemtype}}}), check_handle(a.ctx), check_ptr(a), out)
+    check_rc(a.ctx, rc)
+    auto_sync!(a.ctx)
+    row_major(out)
+end
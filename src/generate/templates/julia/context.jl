@@ -0,0 +1,121 @@
+module {module_name}
+
+export Context, FutharkError, NullPtrError, InvalidShapeError, free!, sync!
+
+# Path to the shared library containing the compiled Futhark code
+const LIB = get(ENV, "FUTHARK_LIB_{env_name}", joinpath(@__DIR__, "lib{stem}.so"))
+
+"""
+Returned when a Futhark function returns a non-zero error code
+"""
+struct FutharkError <: Exception
+    code::Cint
+    message::String
+end
+
+Base.showerror(io::IO, e::FutharkError) = print(io, "Futhark error code $(e.code): $(e.message)")
+
+"""
+Returned when a Futhark function returns a NULL pointer
+"""
+struct NullPtrError <: Exception end
+
+"""
+Returned when data doesn't match the shape of an array
+"""
+struct InvalidShapeError <: Exception
+    expected::Int
+    actual::Int
+end
+
+"""
+Futhark context, freed by `free!` or when it is garbage collected
+"""
+mutable struct Context
+    config::Ptr{{Cvoid}}
+    handle::Ptr{{Cvoid}}
+    auto_sync::Bool
+
+    function Context(; debug::Bool=false, profile::Bool=false, logging::Bool=false,
+                     cache_file::Union{{String,Nothing}}=nothing, auto_sync::Bool=true{backend_kwargs})
+        config = ccall((:futhark_context_config_new, LIB), Ptr{{Cvoid}}, ())
+        config == C_NULL && throw(NullPtrError())
+        ccall((:futhark_context_config_set_debugging, LIB), Cvoid, (Ptr{{Cvoid}}, Cint), config, debug)
+        ccall((:futhark_context_config_set_profiling, LIB), Cvoid, (Ptr{{Cvoid}}, Cint), config, profile)
+        ccall((:futhark_context_config_set_logging, LIB), Cvoid, (Ptr{{Cvoid}}, Cint), config, logging)
+        if cache_file !== nothing
+            ccall((:futhark_context_config_set_cache_file, LIB), Cvoid, (Ptr{{Cvoid}}, Cstring), config, cache_file)
+        end
+{configure_backend}
+        handle = ccall((:futhark_context_new, LIB), Ptr{{Cvoid}}, (Ptr{{Cvoid}},), config)
+        if handle == C_NULL
+            ccall((:futhark_context_config_free, LIB), Cvoid, (Ptr{{Cvoid}},), config)
+            throw(NullPtrError())
+        end
+        finalizer(free!, new(config, handle, auto_sync))
+    end
+end
+
+"""
+Free the context, values created using the context can no longer be used
+"""
+function free!(ctx::Context)
+    if ctx.handle != C_NULL
+        ccall((:futhark_context_sync, LIB), Cint, (Ptr{{Cvoid}},), ctx.handle)
+        ccall((:futhark_context_free, LIB), Cvoid, (Ptr{{Cvoid}},), ctx.handle)
+        ccall((:futhark_context_config_free, LIB), Cvoid, (Ptr{{Cvoid}},), ctx.config)
+        ctx.handle = C_NULL
+        ctx.config = C_NULL
+    end
+    nothing
+end
+
+function check_handle(ctx::Context)
+    ctx.handle == C_NULL && error("Context has already been freed")
+    ctx.handle
+end
+
+function take_string(ptr::Ptr{{Cchar}})
+    ptr == C_NULL && return nothing
+    s = unsafe_string(ptr)
+    Libc.free(ptr)
+    s
+end
+
+"""
+Get the last error message, or `nothing`
+"""
+get_error(ctx::Context) = take_string(ccall((:futhark_context_get_error, LIB), Ptr{{Cchar}}, (Ptr{{Cvoid}},), check_handle(ctx)))
+
+"""
+Get the profiling report
+"""
+report(ctx::Context) = take_string(ccall((:futhark_context_report, LIB), Ptr{{Cchar}}, (Ptr{{Cvoid}},), check_handle(ctx)))
+
+function check_rc(ctx::Context, rc::Cint)
+    if rc != 0
+        msg = get_error(ctx)
+        throw(FutharkError(rc, msg === nothing ? "" : msg))
+    end
+    nothing
+end
+
+"""
+Wait for all pending operations to finish
+"""
+sync!(ctx::Context) = check_rc(ctx, ccall((:futhark_context_sync, LIB), Cint, (Ptr{{Cvoid}},), check_handle(ctx)))
+
+auto_sync!(ctx::Context) = ctx.auto_sync && sync!(ctx)
+
+"""
+Free any cached memory
+"""
+clear_caches!(ctx::Context) = check_rc(ctx, ccall((:futhark_context_clear_caches, LIB), Cint, (Ptr{{Cvoid}},), check_handle(ctx)))
+
+pause_profiling!(ctx::Context) = ccall((:futhark_context_pause_profiling, LIB), Cvoid, (Ptr{{Cvoid}},), check_handle(ctx))
+
+unpause_profiling!(ctx::Context) = ccall((:futhark_context_unpause_profiling, LIB), Cvoid, (Ptr{{Cvoid}},), check_handle(ctx))
+
+# Futhark arrays are row-major and Julia arrays are column-major, so the order of the
+# dimensions is reversed when copying data in either direction
+row_major(a::AbstractArray{{T,N}}) where {{T,N}} = permutedims(a, ntuple(i -> N - i + 1, N))
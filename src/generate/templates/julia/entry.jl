@@ -0,0 +1,10 @@
+"""
+Entry point: {name}
+"""
+function {entry_name}(ctx::Context{entry_params})
+{out_decl}
+    rc = GC.@preserve {preserve} ccall((:{entry_fn}, LIB), Cint, (Ptr{{Cvoid}}, {arg_types}), check_handle(ctx), {call_args})
+    check_rc(ctx, rc)
+    auto_sync!(ctx)
+    {entry_return}
+end
@@ -0,0 +1,25 @@
+"""
+Futhark type
+"""
+mutable struct {jl_type}
+    ptr::Ptr{{Cvoid}}
+    ctx::Context
+
+    {jl_type}(ctx::Context, ptr::Ptr{{Cvoid}}) = finalizer(free!, new(ptr, ctx))
+end
+
+"""
+Free the value, it is also freed when it is garbage collected
+"""
+function free!(t::{jl_type})
+    if t.ptr != C_NULL && t.ctx.handle != C_NULL
+        ccall((:{free_fn}, LIB), Cint, (Ptr{{Cvoid}}, Ptr{{Cvoid}}), t.ctx.handle, t.ptr)
+    end
+    t.ptr = C_NULL
+    nothing
+end
+
+function check_ptr(t::{jl_type})
+    t.ptr == C_NULL && error("Value has already been freed")
+    t.ptr
+end
@@ -0,0 +1,10 @@
+"""
+Create a new {jl_type} from its fields
+"""
+function {jl_type}(ctx::Context, {new_params})
+    out = Ref{{Ptr{{Cvoid}}}}(C_NULL)
+    rc = GC.@preserve {preserve} ccall((:{new_fn}, LIB), Cint, (Ptr{{Cvoid}}, Ptr{{Ptr{{Cvoid}}}}, {new_arg_types}), check_handle(ctx), out, {new_call_args})
+    check_rc(ctx, rc)
+    auto_sync!(ctx)
+    {jl_type}(ctx, out[])
+end
@@ -0,0 +1,10 @@
+"""
+Get the {field_name} field
+"""
+function get_{field_name}(t::{jl_type})
+    out = Ref{{{c_field_type}}}({out_init})
+    rc = GC.@preserve t ccall((:{project_fn}, LIB), Cint, (Ptr{{Cvoid}}, Ptr{{{c_field_type}}}, Ptr{{Cvoid}}), check_handle(t.ctx), out, check_ptr(t))
+    check_rc(t.ctx, rc)
+    auto_sync!(t.ctx)
+    {output}
+end
@@ -0,0 +1,77 @@
+ffi.cdef[[
+struct {futhark_type};
+struct {futhark_type} *{new_fn}(struct futhark_context *ctx, const {c_elemtype} *data, {dim_params});
+int {free_fn}(struct futhark_context *ctx, struct {futhark_type} *arr);
+int {values_fn}(struct futhark_context *ctx, struct {futhark_type} *arr, {c_elemtype} *data);
+const int64_t *{shape_fn}(struct futhark_context *ctx, struct {futhark_type} *arr);
+]]
+
+--- Array type with {rank} dimensions and {elemtype} elements
+M.{lua_type} = {{}}
+M.{lua_type}.__index = M.{lua_type}
+
+function M.{lua_type}.wrap(ctx, ptr)
+  ptr = manage(ctx, ptr, lib.{free_fn})
+  local shape = lib.{shape_fn}(ctx.handle, ptr)
+  return setmetatable({{ ctx = ctx, ptr = ptr, shape = {{ {shape} }} }}, M.{lua_type})
+end
+
+--- Create a new array with the shape `dims` from a flat table or a pointer to `{c_elemtype}`
+--- values, `dims` defaults to the length of the table
+function M.{lua_type}.new(ctx, data, dims)
+  local buf = data
+  if type(data) == "table" then
+    dims = dims or {{ #data }}
+  elseif dims == nil then
+    error("dims are required when data is not a table", 2)
+  end
+  local size = 1
+  for _, d in ipairs(dims) do
+    size = size * d
+  end
+  if type(data) == "table" then
+    if #dims ~= {rank} or size ~= #data then
+      error(string.format("invalid shape: expected %d elements, got %d", size, #data), 2)
+    end
+    buf = ffi.new("{c_elemtype}[?]", size)
+    for i = 1, size do
+      buf[i - 1] = data[i]
+    end
+  end
+  local ptr = lib.{new_fn}(ctx:check_handle(), buf, {dim_args})
+  ctx:maybe_sync()
+  return M.{lua_type}.wrap(ctx, ptr)
+end
+
+--- Number of elements in the array
+function M.{lua_type}:size()
+  local size = 1
+  for _, d in ipairs(self.shape) do
+    size = size * d
+  end
+  return size
+end
+
+--- Copy the values into a new `{c_elemtype}` array
+function M.{lua_type}:values()
+  local out = ffi.new("{c_elemtype}[?]", self:size())
+  self.ctx:check(lib.{values_fn}(self.ctx:check_handle(), check_ptr(self), out))
+  self.ctx:maybe_sync()
+  return out
+end
+
+--- Copy the values into a new flat table
+function M.{lua_type}:to_table()
+  local out = self:values()
+  local t = {{}}
+  for i = 1, self:size() do
+    t[i] = out[i - 1]
+  end
+  return t
+end
+
+--- Free the array, it is also freed when it is garbage collected
+function M.{lua_type}:free()
+  release(self.ctx, self.ptr, lib.{free_fn})
+  self.ptr = nil
+end
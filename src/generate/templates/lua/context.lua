@@ -0,0 +1,170 @@
+local ffi = require("ffi")
+
+ffi.cdef[[
+struct futhark_context_config;
+struct futhark_context;
+struct futhark_context_config *futhark_context_config_new(void);
+void futhark_context_config_free(struct futhark_context_config *cfg);
+void futhark_context_config_set_debugging(struct futhark_context_config *cfg, int flag);
+void futhark_context_config_set_profiling(struct futhark_context_config *cfg, int flag);
+void futhark_context_config_set_logging(struct futhark_context_config *cfg, int flag);
+void futhark_context_config_set_cache_file(struct futhark_context_config *cfg, const char *f);
+{backend_cdef}struct futhark_context *futhark_context_new(struct futhark_context_config *cfg);
+void futhark_context_free(struct futhark_context *ctx);
+int futhark_context_sync(struct futhark_context *ctx);
+int futhark_context_clear_caches(struct futhark_context *ctx);
+void futhark_context_pause_profiling(struct futhark_context *ctx);
+void futhark_context_unpause_profiling(struct futhark_context *ctx);
+char *futhark_context_get_error(struct futhark_context *ctx);
+char *futhark_context_report(struct futhark_context *ctx);
+void free(void *ptr);
+]]
+
+-- Shared library containing the compiled Futhark code
+local lib = ffi.load(os.getenv("FUTHARK_LIB_{env_name}") or "{stem}")
+
+local M = {{}}
+
+local function take_string(s)
+  if s == nil then
+    return nil
+  end
+  local result = ffi.string(s)
+  ffi.C.free(s)
+  return result
+end
+
+--- Futhark context, freed by `free` or when it is garbage collected
+M.Context = {{}}
+M.Context.__index = M.Context
+
+--- Create a new context, `options` is an optional table with the fields `debug`, `profile`,
+--- `logging`, `cache_file`{backend_option_fields} and `auto_sync`
+function M.Context.new(options)
+  options = options or {{}}
+  local config = lib.futhark_context_config_new()
+  if config == nil then
+    error("NULL pointer encountered", 2)
+  end
+  lib.futhark_context_config_set_debugging(config, options.debug and 1 or 0)
+  lib.futhark_context_config_set_profiling(config, options.profile and 1 or 0)
+  lib.futhark_context_config_set_logging(config, options.logging and 1 or 0)
+  if options.cache_file ~= nil then
+    lib.futhark_context_config_set_cache_file(config, options.cache_file)
+  end
+{configure_backend}
+  local handle = lib.futhark_context_new(config)
+  if handle == nil then
+    lib.futhark_context_config_free(config)
+    error("NULL pointer encountered", 2)
+  end
+
+  -- Shared with the finalizers of values created using the context
+  local state = {{ alive = true }}
+  local self = setmetatable({{
+    config = config,
+    state = state,
+    cache_file = options.cache_file,
+    auto_sync = options.auto_sync ~= false,
+  }}, M.Context)
+  self.handle = ffi.gc(handle, function(h)
+    state.alive = false
+    lib.futhark_context_sync(h)
+    lib.futhark_context_free(h)
+    lib.futhark_context_config_free(config)
+  end)
+  return self
+end
+
+--- Free the context, values created using the context can no longer be used
+function M.Context:free()
+  if self.handle == nil then
+    return
+  end
+  local handle = ffi.gc(self.handle, nil)
+  self.state.alive = false
+  lib.futhark_context_sync(handle)
+  lib.futhark_context_free(handle)
+  lib.futhark_context_config_free(self.config)
+  self.handle = nil
+end
+
+function M.Context:check_handle()
+  if self.handle == nil then
+    error("context has already been freed", 3)
+  end
+  return self.handle
+end
+
+function M.Context:check(rc)
+  if rc ~= 0 then
+    error(string.format("Futhark error code %d: %s", rc, self:get_error() or ""), 3)
+  end
+end
+
+--- Wait for all pending operations to finish
+function M.Context:sync()
+  self:check(lib.futhark_context_sync(self:check_handle()))
+end
+
+function M.Context:maybe_sync()
+  if self.auto_sync then
+    self:sync()
+  end
+end
+
+--- Free any cached memory
+function M.Context:clear_caches()
+  self:check(lib.futhark_context_clear_caches(self:check_handle()))
+end
+
+--- Pause profiling
+function M.Context:pause_profiling()
+  lib.futhark_context_pause_profiling(self:check_handle())
+end
+
+--- Resume profiling
+function M.Context:unpause_profiling()
+  lib.futhark_context_unpause_profiling(self:check_handle())
+end
+
+--- Get the last error message
+function M.Context:get_error()
+  return take_string(lib.futhark_context_get_error(self:check_handle()))
+end
+
+--- Get the profiling report
+function M.Context:report()
+  return take_string(lib.futhark_context_report(self:check_handle()))
+end
+
+-- Attach a finalizer to `ptr` that frees it unless the context has been freed first, the
+-- finalizer keeps the context handle alive until the value is collected
+local function manage(ctx, ptr, free)
+  if ptr == nil then
+    error("NULL pointer encountered", 3)
+  end
+  local handle, state = ctx.handle, ctx.state
+  return ffi.gc(ptr, function(p)
+    if state.alive then
+      free(handle, p)
+    end
+  end)
+end
+
+-- Free a value created using `manage`
+local function release(ctx, ptr, free)
+  if ptr ~= nil then
+    ffi.gc(ptr, nil)
+    if ctx.state.alive then
+      free(ctx.handle, ptr)
+    end
+  end
+end
+
+local function check_ptr(value)
+  if value.ptr == nil then
+    error("value has already been freed", 3)
+  end
+  return value.ptr
+end
@@ -0,0 +1,9 @@
+ffi.cdef[[
+int {entry_fn}({cdef_params});
+]]
+
+--- Entry point: {name}
+function M.Context:{entry_name}({entry_params})
+{out_decl}  self:check(lib.{entry_fn}({call_args}))
+  self:maybe_sync()
+{entry_return}end
@@ -0,0 +1,18 @@
+ffi.cdef[[
+struct {futhark_type};
+int {free_fn}(struct futhark_context *ctx, struct {futhark_type} *obj);
+{record_cdef}]]
+
+--- Futhark type
+M.{lua_type} = {{}}
+M.{lua_type}.__index = M.{lua_type}
+
+function M.{lua_type}.wrap(ctx, ptr)
+  return setmetatable({{ ctx = ctx, ptr = manage(ctx, ptr, lib.{free_fn}) }}, M.{lua_type})
+end
+
+--- Free the value, it is also freed when it is garbage collected
+function M.{lua_type}:free()
+  release(self.ctx, self.ptr, lib.{free_fn})
+  self.ptr = nil
+end
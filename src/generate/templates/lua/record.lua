@@ -0,0 +1,7 @@
+--- Create a new {lua_type} from its fields
+function M.{lua_type}.new(ctx, {new_params})
+  local out = ffi.new("struct {futhark_type} *[1]")
+  ctx:check(lib.{new_fn}(ctx:check_handle(), out, {new_call_args}))
+  ctx:maybe_sync()
+  return M.{lua_type}.wrap(ctx, out[0])
+end
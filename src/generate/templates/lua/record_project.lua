@@ -0,0 +1,7 @@
+--- Get the {field_name} field
+function M.{lua_type}:get_{field_name}()
+  local out = ffi.new("{out_type}[1]")
+  self.ctx:check(lib.{project_fn}(self.ctx:check_handle(), out, check_ptr(self)))
+  self.ctx:maybe_sync()
+  return {output}
+end
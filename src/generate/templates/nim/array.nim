@@ -0,0 +1,46 @@
+{{.push importc, dynlib: libName, cdecl.}}
+proc {new_fn}(ctx: ptr futhark_context, data: ptr {nim_elemtype}, {dim_params}): ptr {futhark_type}
+proc {values_fn}(ctx: ptr futhark_context, arr: ptr {futhark_type}, data: ptr {nim_elemtype}): cint
+proc {shape_fn}(ctx: ptr futhark_context, arr: ptr {futhark_type}): ptr UncheckedArray[int64]
+{{.pop.}}
+
+proc wrap(ctx: Context, data: ptr {futhark_type}): {nim_type} =
+  let shape = {shape_fn}(ctx.context, checkPtr(data))
+  result = {nim_type}(data: data, ctx: ctx)
+  for i in 0 ..< {rank}:
+    result.dims[i] = shape[i]
+
+proc new{nim_type}*(ctx: Context, data: openArray[{nim_elemtype}], dims: array[{rank}, int64]): {nim_type} =
+  ## Create a new array of `dims` dimensions and initialize it with the values from `data`
+  var size = 1'i64
+  for d in dims:
+    size *= d
+  if data.len.int64 != size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  let arr = checkPtr({new_fn}(ctx.context, p, {dim_args}))
+  result = {nim_type}(data: arr, ctx: ctx, dims: dims)
+  ctx.maybeSync()
+
+proc shape*(x: {nim_type}): array[{rank}, int64] =
+  ## Get the array shape
+  x.dims
+
+proc size*(x: {nim_type}): int64 =
+  ## Get the number of elements in the array
+  result = 1
+  for d in x.dims:
+    result *= d
+
+proc values*(x: {nim_type}, data: var openArray[{nim_elemtype}]) =
+  ## Load values into `data`
+  if data.len.int64 != x.size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  x.ctx.check({values_fn}(x.ctx.context, x.data, p))
+  x.ctx.maybeSync()
+
+proc get*(x: {nim_type}): seq[{nim_elemtype}] =
+  ## Load values into a `seq`
+  result = newSeq[{nim_elemtype}](x.size)
+  x.values(result)
@@ -0,0 +1,127 @@
+import std/[os, strutils]
+
+# The library can be selected using `-d:futharkLib=path/to/lib{stem}.so`
+const futharkLib {{.strdefine.}} = ""
+const libName = (if futharkLib.len > 0: futharkLib else: DynlibFormat % "{stem}")
+
+type
+  FutharkError* = object of CatchableError
+    ## Raised when a Futhark function returns a non-zero error code
+    code*: cint
+
+  NullPtrError* = object of CatchableError
+    ## Raised when a Futhark function returns a NULL pointer
+
+  InvalidShapeError* = object of CatchableError
+    ## Raised when the data of an array doesn't match its shape
+
+  futhark_context_config = object
+  futhark_context = object
+
+  Options* = object
+    ## Options used to create a `Context`
+    debug*: bool
+    profile*: bool
+    logging*: bool
+    cacheFile*: string
+    autoSync*: bool
+{backend_option_fields}
+  ContextObj = object
+    config: ptr futhark_context_config
+    context: ptr futhark_context
+    autoSync: bool
+    cacheFile: string
+{backend_context_fields}
+  Context* = ref ContextObj
+    ## Futhark context
+
+{{.push importc, dynlib: libName, cdecl.}}
+proc futhark_context_config_new(): ptr futhark_context_config
+proc futhark_context_config_free(cfg: ptr futhark_context_config)
+proc futhark_context_config_set_debugging(cfg: ptr futhark_context_config, flag: cint)
+proc futhark_context_config_set_profiling(cfg: ptr futhark_context_config, flag: cint)
+proc futhark_context_config_set_logging(cfg: ptr futhark_context_config, flag: cint)
+proc futhark_context_config_set_cache_file(cfg: ptr futhark_context_config, f: cstring)
+{backend_functions}proc futhark_context_new(cfg: ptr futhark_context_config): ptr futhark_context
+proc futhark_context_free(ctx: ptr futhark_context)
+proc futhark_context_sync(ctx: ptr futhark_context): cint
+proc futhark_context_clear_caches(ctx: ptr futhark_context): cint
+proc futhark_context_pause_profiling(ctx: ptr futhark_context)
+proc futhark_context_unpause_profiling(ctx: ptr futhark_context)
+proc futhark_context_get_error(ctx: ptr futhark_context): cstring
+proc futhark_context_report(ctx: ptr futhark_context): cstring
+{{.pop.}}
+
+proc cFree(p: pointer) {{.importc: "free", header: "<stdlib.h>".}}
+
+proc `=destroy`(c: ContextObj) =
+  if c.context != nil:
+    discard futhark_context_sync(c.context)
+    futhark_context_free(c.context)
+  if c.config != nil:
+    futhark_context_config_free(c.config)
+  `=destroy`(c.cacheFile)
+{backend_destroy}
+proc `=copy`(dst: var ContextObj, src: ContextObj) {{.error.}}
+
+proc initOptions*(): Options =
+  ## Create new `Options` with default settings
+  Options(autoSync: true)
+
+proc newContext*(options = initOptions()): Context =
+  ## Create a new context
+  let config = futhark_context_config_new()
+  if config == nil:
+    raise newException(NullPtrError, "NULL pointer encountered")
+  result = Context(config: config, autoSync: options.autoSync, cacheFile: options.cacheFile)
+  futhark_context_config_set_debugging(config, cint(options.debug))
+  futhark_context_config_set_profiling(config, cint(options.profile))
+  futhark_context_config_set_logging(config, cint(options.logging))
+  if result.cacheFile.len > 0:
+    futhark_context_config_set_cache_file(config, cstring(result.cacheFile))
+{configure_backend}  result.context = futhark_context_new(config)
+  if result.context == nil:
+    raise newException(NullPtrError, "NULL pointer encountered")
+
+proc takeString(s: cstring): string =
+  if s == nil:
+    return ""
+  result = $s
+  cFree(cast[pointer](s))
+
+proc getError*(ctx: Context): string =
+  ## Get the last error message, or an empty string
+  takeString(futhark_context_get_error(ctx.context))
+
+proc report*(ctx: Context): string =
+  ## Get the profiling report
+  takeString(futhark_context_report(ctx.context))
+
+proc check(ctx: Context, rc: cint) =
+  if rc != 0:
+    raise (ref FutharkError)(code: rc, msg: "Futhark error code " & $rc & ": " & ctx.getError())
+
+proc sync*(ctx: Context) =
+  ## Sync the context, if `autoSync` is enabled this shouldn't be needed
+  ctx.check(futhark_context_sync(ctx.context))
+
+proc maybeSync(ctx: Context) =
+  if ctx.autoSync:
+    ctx.sync()
+
+proc clearCaches*(ctx: Context) =
+  ## Clear Futhark caches
+  ctx.check(futhark_context_clear_caches(ctx.context))
+
+proc pauseProfiling*(ctx: Context) =
+  ## Pause Futhark profiling
+  futhark_context_pause_profiling(ctx.context)
+
+proc unpauseProfiling*(ctx: Context) =
+  ## Resume profiling
+  futhark_context_unpause_profiling(ctx.context)
+
+proc checkPtr[T](p: ptr T): ptr T =
+  if p == nil:
+    raise newException(NullPtrError, "NULL pointer encountered")
+  p
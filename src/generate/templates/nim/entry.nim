@@ -0,0 +1,6 @@
+proc {entry_fn}({futhark_entry_params}): cint {{.importc, dynlib: libName, cdecl.}}
+
+proc {entry_name}*({entry_params}){entry_return_type} =
+  ## Entry point: {name}
+{out_decl}  ctx.check({entry_fn}({call_args}))
+{entry_return}  ctx.maybeSync()
@@ -0,0 +1,10 @@
+proc {free_fn}(ctx: ptr futhark_context, data: ptr {futhark_type}): cint {{.importc, dynlib: libName, cdecl.}}
+
+proc `=destroy`(x: {nim_type}) =
+  if x.data != nil:
+    discard {free_fn}(x.ctx.context, x.data)
+  `=destroy`(x.ctx)
+
+proc `=copy`(dst: var {nim_type}, src: {nim_type}) {{.error.}}
+
+proc wrap(ctx: Context, data: ptr {futhark_type}): {nim_type}
@@ -0,0 +1,2 @@
+proc wrap(ctx: Context, data: ptr {futhark_type}): {nim_type} =
+  {nim_type}(data: checkPtr(data), ctx: ctx)
@@ -0,0 +1,9 @@
+
+proc {new_fn}(ctx: ptr futhark_context, res: ptr ptr {futhark_type}, {new_extern_params}): cint {{.importc, dynlib: libName, cdecl.}}
+
+proc new{nim_type}*(ctx: Context, {new_params}): {nim_type} =
+  ## Create new {nim_type}
+  var data: ptr {futhark_type}
+  ctx.check({new_fn}(ctx.context, addr data, {new_call_args}))
+  result = wrap(ctx, data)
+  ctx.maybeSync()
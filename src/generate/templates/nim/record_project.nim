@@ -0,0 +1,9 @@
+
+proc {project_fn}(ctx: ptr futhark_context, res: ptr {futhark_field_type}, obj: ptr {futhark_type}): cint {{.importc, dynlib: libName, cdecl.}}
+
+proc {getter}*(x: {nim_type}): {nim_field_type} =
+  ## Get {field_name} field
+  var value: {futhark_field_type}
+  x.ctx.check({project_fn}(x.ctx.context, addr value, x.data))
+  result = {output}
+  x.ctx.maybeSync()
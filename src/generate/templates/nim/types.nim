@@ -0,0 +1,6 @@
+  {futhark_type} = object
+  {nim_type}* = object
+    ## {doc}
+    data: ptr {futhark_type}
+    ctx: Context
+{extra_fields}
\ No newline at end of file
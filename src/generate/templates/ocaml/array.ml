@@ -48,7 +48,7 @@ module {module_name} = struct
     let g = Array1.create kind C_layout len in
     values_array1 t g;
     g
-
+{index}
   let shape t = t.shape
 
   let of_array1 ctx dims arr =
@@ -71,7 +71,7 @@ module {module_name} = struct
     if is_null ptr then raise (Error NullPtr);
     let shape = ptr_shape ctx.Context.handle ptr in
     {{ ptr = Ctypes.allocate ~finalise:(free ctx) (Ctypes.ptr Ctypes.void) ptr; ctx; shape }}
-
+{raw}
   let free t = free t.ctx t.ptr
     
   let _ = of_ptr
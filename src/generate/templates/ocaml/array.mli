@@ -25,11 +25,15 @@ module {module_name}: sig
   val get_array1: t -> ({ocaml_elemtype}, {ba_elemtype}, Bigarray.c_layout) Bigarray.Array1.t
   (** Similar to [get] but returns an [Array1] *)
 
+  val index: t -> int array -> {ocaml_elemtype}
+  (** Get a single element of the array without copying the whole array, raises
+      [Error (Unsupported "index")] if the manifest doesn't include an [index] function *)
+
   val of_array: Context.t -> int array -> ({ocaml_elemtype}) array -> t
   (** Create [t] from an array of values *)
 
   val of_array1: Context.t -> int array ->  ({ocaml_elemtype}, {ba_elemtype}, Bigarray.c_layout) Bigarray.Array1.t-> t
-  (** Create [t] from an [Array1] instead of [Genarray] *)
+  (** Create [t] from an [Array1] instead of [Genarray] *){raw}
 
   val free: t -> unit
   (** Free the array *)
@@ -0,0 +1,11 @@
+
+  let index t idx =
+    check_use_after_free `context t.ctx.Context.context_free;
+    let n = Array.length idx in
+    if n <> {rank} then raise (Error (InvalidShape ({rank}, n)));
+    Array.iteri (fun i x -> if x < 0 || x >= t.shape.(i) then raise (Error IndexOutOfBounds)) idx;
+    let out = Array1.create kind C_layout 1 in
+    let rc = Bindings.{index_fn} t.ctx.Context.handle (cast @@ bigarray_start array1 out) (get_ptr t) {index_args} in
+    if rc <> 0 then raise (Error (Code rc));
+    Context.sync t.ctx;
+    out.{{0}}
@@ -0,0 +1,2 @@
+
+  let index _ _ = raise (Error (Unsupported "index"))
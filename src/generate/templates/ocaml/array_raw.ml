@@ -0,0 +1,13 @@
+
+  let of_raw ctx dims data =
+    check_use_after_free `context ctx.Context.context_free;
+    let n = Array.length dims in
+    if n <> {rank} then raise (Error (InvalidShape ({rank}, n)));
+    let ptr = Bindings.{new_raw_fn} ctx.Context.handle data {dim_args} in
+    if is_null ptr then raise (Error NullPtr);
+    Context.auto_sync ctx;
+    {{ ptr = Ctypes.allocate ~finalise:(free ctx) (Ctypes.ptr Ctypes.void) ptr; ctx; shape = dims }}
+
+  let to_raw t =
+    check_use_after_free `context t.ctx.Context.context_free;
+    Bindings.{values_raw_fn} t.ctx.Context.handle (get_ptr t)
@@ -0,0 +1,8 @@
+
+
+  val of_raw: Context.t -> int array -> {raw_type} -> t
+  (** Create [t] using existing backend memory, the memory is not copied and must stay valid
+      while [t] is alive *)
+
+  val to_raw: t -> {raw_type}
+  (** Get the backend memory used by the array, it is only valid while the array is alive *)
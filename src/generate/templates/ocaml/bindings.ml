@@ -17,6 +17,7 @@ module Bindings = struct
   let futhark_context_config_set_debugging = fn "futhark_context_config_set_debugging" (context_config @-> int @-> returning void)
   let futhark_context_config_set_logging = fn "futhark_context_config_set_logging" (context_config @-> int @-> returning void)
   let futhark_context_config_set_cache_file = fn "futhark_context_config_set_cache_file" (context_config @-> string @-> returning void)
+  let futhark_context_config_set_tuning_param = fn "futhark_context_config_set_tuning_param" (context_config @-> string @-> size_t @-> returning int)
   let futhark_context_pause_profiling = fn "futhark_context_pause_profiling" (context @-> returning void)
   let futhark_context_unpause_profiling = fn "futhark_context_unpause_profiling" (context @-> returning void)
   let futhark_context_clear_caches = fn "futhark_context_clear_caches" (context @-> returning int)
@@ -33,6 +34,9 @@ type error =
   | NullPtr
   | Code of int
   | UseAfterFree of [`context | `array | `opaque]
+  | IndexOutOfBounds
+  | Unsupported of string
+  | InvalidTuning of string{error_variants}
 
 exception Error of error
 
@@ -49,6 +53,9 @@ let () = Printexc.register_printer (function
   | Error (UseAfterFree `context) -> Some "futhark: context used after beeing freed"
   | Error (UseAfterFree `array) -> Some "futhark: array used after beeing freed"
   | Error (UseAfterFree `opaque) -> Some "futhark: opaque value used after beeing freed"
-  | _ -> None)
+  | Error IndexOutOfBounds -> Some "futhark error: index out of bounds"
+  | Error (Unsupported op) -> Some (Printf.sprintf "futhark error: %s is not supported by this version of Futhark" op)
+  | Error (InvalidTuning msg) -> Some (Printf.sprintf "futhark error: invalid tuning parameter: %s" msg)
+{error_printers}  | _ -> None)
 
 
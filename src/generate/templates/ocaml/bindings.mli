@@ -6,5 +6,8 @@ type error =
   | NullPtr 
   | Code of int
   | UseAfterFree of [`context | `array | `opaque]
+  | IndexOutOfBounds
+  | Unsupported of string
+  | InvalidTuning of string{error_variants}
 
 exception Error of error
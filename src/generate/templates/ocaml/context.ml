@@ -12,7 +12,29 @@ module Context = struct
       let () = ignore (Bindings.futhark_context_config_free t.config) in
       t.context_free <- true
 
-  let v ?(debug = false) ?(log = false) ?(profile = false) ?cache_file ?(auto_sync = true) {extra_param} () =
+  let read_tuning_file path =
+    let ic = open_in path in
+    let parse line =
+      match String.index_opt line '=' with
+      | None -> None
+      | Some i ->
+        let name = String.trim (String.sub line 0 i) in
+        let value = String.trim (String.sub line (i + 1) (String.length line - i - 1)) in
+        Option.map (fun v -> (name, v)) (int_of_string_opt value)
+    in
+    let rec loop acc =
+      match input_line ic with
+      | exception End_of_file -> close_in ic; List.rev acc
+      | line when String.trim line = "" -> loop acc
+      | line ->
+        match parse line with
+        | Some x -> loop (x :: acc)
+        | None -> close_in ic; raise (Error (InvalidTuning line))
+    in
+    loop []
+
+  let v ?(debug = false) ?(log = false) ?(profile = false) ?cache_file ?(auto_sync = true) ?(tuning_params = []) ?tuning_file {extra_param} () =
+    let tuning_params = (match tuning_file with Some f -> read_tuning_file f | None -> []) @ tuning_params in
     let config = Bindings.futhark_context_config_new () in
     if is_null config then raise (Error NullPtr);
     Bindings.futhark_context_config_set_debugging config (if debug then 1 else 0);
@@ -20,6 +42,10 @@ module Context = struct
     Bindings.futhark_context_config_set_logging config (if log then 1 else 0);
     {extra_line}
     Option.iter (Bindings.futhark_context_config_set_cache_file config) cache_file;
+    List.iter (fun (name, value) ->
+      if Bindings.futhark_context_config_set_tuning_param config name (Unsigned.Size_t.of_int value) <> 0 then
+        let () = ignore @@ Bindings.futhark_context_config_free config in
+        raise (Error (InvalidTuning name))) tuning_params;
     let handle = Bindings.futhark_context_new config in
     if is_null handle then 
       let () = ignore @@ Bindings.futhark_context_config_free config in
@@ -56,7 +82,7 @@ module Context = struct
 
   let report t = 
     check_use_after_free `context t.context_free;
-    let ptr = Bindings.futhark_context_report t.handle in string_opt_of_ptr ptr
+    let ptr = Bindings.futhark_context_report t.handle in string_opt_of_ptr ptr{report}
 
   let pause_profiling t = 
     check_use_after_free `context t.context_free;
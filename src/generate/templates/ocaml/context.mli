@@ -2,8 +2,8 @@ module Context: sig
   type t
   (** Futhark context *)
 
-  val v: ?debug:bool -> ?log:bool -> ?profile:bool -> ?cache_file:string -> ?auto_sync:bool -> {extra_mli} unit -> t
-  (** Create a new context *)
+  val v: ?debug:bool -> ?log:bool -> ?profile:bool -> ?cache_file:string -> ?auto_sync:bool -> ?tuning_params:(string * int) list -> ?tuning_file:string -> {extra_mli} unit -> t
+  (** Create a new context, [tuning_params] are applied after the parameters loaded from [tuning_file] *)
   
   val sync: t -> unit
   (** Sync the context, if auto_sync is enabled this is not needed *)
@@ -17,7 +17,7 @@ module Context: sig
   val get_error: t -> string option
   (** Get last error message or None *)
 
-  val report: t -> string option
+  val report: t -> string option{report}
   val pause_profiling: t -> unit
   val unpause_profiling: t -> unit
 end
\ No newline at end of file
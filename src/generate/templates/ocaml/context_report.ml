@@ -0,0 +1,13 @@
+
+
+  let parsed_report t =
+    match report t with
+    | Some s -> Report.parse s
+    | None -> raise (Error NullPtr)
+
+  let profile t f =
+    sync t;
+    ignore (report t);
+    let x = f () in
+    sync t;
+    (x, parsed_report t)
\ No newline at end of file
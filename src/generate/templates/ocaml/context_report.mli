@@ -0,0 +1,7 @@
+
+  val parsed_report: t -> Report.t
+  (** Get the profiling report as a [Report.t], this requires a version of Futhark that creates JSON reports *)
+
+  val profile: t -> (unit -> 'a) -> 'a * Report.t
+  (** Run a function and return its result along with a report of the work done while it was running.
+      Profiling has to be enabled using [~profile:true], the counters are reset by reading the report before calling the function *)
@@ -0,0 +1,3 @@
+type {output_type} = {{
+{output_fields}
+}}
@@ -0,0 +1,4 @@
+type {output_type} = {{
+{output_fields}
+}}
+(** Outputs of the [{name}] entry point *)
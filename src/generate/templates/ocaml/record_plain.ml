@@ -0,0 +1,5 @@
+  type value = {value_type}
+
+  let of_value ctx {value_pattern} = v ctx {value_args}
+
+  let to_value t = {value_expr}
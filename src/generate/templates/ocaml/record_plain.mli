@@ -0,0 +1,8 @@
+  type value = {value_type}
+  (** Host representation of [t] *)
+
+  val of_value: Context.t -> value -> t
+  (** Create [t] from its host representation *)
+
+  val to_value: t -> value
+  (** Load all fields into a [value] *)
@@ -0,0 +1,123 @@
+module Report = struct
+  type kernel = {{ invocations: int; total: float }}
+  type t = {{ memory: (string * int) list; kernels: (string * kernel) list }}
+
+  (* Minimal JSON representation used to parse reports, booleans and nulls are ignored *)
+  type json = Number of float | String of string | List of json list | Object of (string * json) list | Other
+
+  let parse_json s =
+    let pos = ref 0 in
+    let len = String.length s in
+    let fail () = raise (Error (InvalidReport (Printf.sprintf "unexpected input at byte %d" !pos))) in
+    let rec ws () =
+      if !pos < len && String.contains " \t\r\n" s.[!pos] then (incr pos; ws ())
+    in
+    let eat c = ws (); if !pos < len && s.[!pos] = c then (incr pos; true) else false in
+    let expect c = if not (eat c) then fail () in
+    let string () =
+      expect '"';
+      let b = Buffer.create 16 in
+      let rec loop () =
+        if !pos >= len then fail ();
+        let c = s.[!pos] in
+        incr pos;
+        match c with
+        | '"' -> Buffer.contents b
+        | '\\' ->
+          if !pos >= len then fail ();
+          let e = s.[!pos] in
+          incr pos;
+          (match e with
+           | 'n' -> Buffer.add_char b '\n'
+           | 't' -> Buffer.add_char b '\t'
+           | 'r' -> Buffer.add_char b '\r'
+           | 'b' -> Buffer.add_char b '\b'
+           | 'f' -> Buffer.add_char b '\012'
+           | 'u' ->
+             if !pos + 4 > len then fail ();
+             (match int_of_string_opt ("0x" ^ String.sub s !pos 4) with
+              | Some n -> Buffer.add_utf_8_uchar b (if Uchar.is_valid n then Uchar.of_int n else Uchar.rep)
+              | None -> fail ());
+             pos := !pos + 4
+           | e -> Buffer.add_char b e);
+          loop ()
+        | c -> Buffer.add_char b c; loop ()
+      in
+      loop ()
+    in
+    let rec value () =
+      ws ();
+      if !pos >= len then fail ();
+      match s.[!pos] with
+      | '{{' ->
+        incr pos;
+        let rec fields acc =
+          let k = string () in
+          expect ':';
+          let acc = (k, value ()) :: acc in
+          if eat ',' then fields acc else (expect '}}'; Object (List.rev acc))
+        in
+        if eat '}}' then Object [] else fields []
+      | '[' ->
+        incr pos;
+        let rec items acc =
+          let acc = value () :: acc in
+          if eat ',' then items acc else (expect ']'; List (List.rev acc))
+        in
+        if eat ']' then List [] else items []
+      | '"' -> String (string ())
+      | 't' | 'f' | 'n' ->
+        let start = !pos in
+        while !pos < len && s.[!pos] >= 'a' && s.[!pos] <= 'z' do incr pos done;
+        (match String.sub s start (!pos - start) with
+         | "true" | "false" | "null" -> Other
+         | _ -> fail ())
+      | _ ->
+        let start = !pos in
+        while !pos < len && String.contains "+-0123456789.eE" s.[!pos] do incr pos done;
+        (match float_of_string_opt (String.sub s start (!pos - start)) with
+         | Some n -> Number n
+         | None -> fail ())
+    in
+    let x = value () in
+    ws ();
+    if !pos <> len then fail ();
+    x
+
+  let number = function Number n -> Some n | _ -> None
+
+  (* Events have a name and either a start and end time or a duration, in microseconds *)
+  let add_event kernels = function
+    | Object e ->
+      let field k = Option.bind (List.assoc_opt k e) number in
+      (match List.assoc_opt "name" e with
+       | Some (String name) ->
+         let time =
+           match field "start", field "end", field "duration" with
+           | Some a, Some b, _ -> b -. a
+           | _, _, Some d -> d
+           | _ -> 0.0
+         in
+         let k = Option.value ~default:{{ invocations = 0; total = 0.0 }} (Hashtbl.find_opt kernels name) in
+         Hashtbl.replace kernels name {{ invocations = k.invocations + 1; total = k.total +. Float.max time 0.0 /. 1e6 }}
+       | _ -> ())
+    | _ -> ()
+
+  let parse s =
+    match parse_json s with
+    | Object fields ->
+      let memory =
+        match List.assoc_opt "memory" fields with
+        | Some (Object spaces) -> List.filter_map (fun (k, v) -> Option.map (fun n -> (k, int_of_float n)) (number v)) spaces
+        | _ -> []
+      in
+      let kernels = Hashtbl.create 16 in
+      (match List.assoc_opt "events" fields with
+       | Some (List events) -> List.iter (add_event kernels) events
+       | _ -> ());
+      let kernels = Hashtbl.fold (fun k v acc -> (k, v) :: acc) kernels [] |> List.sort compare in
+      {{ memory; kernels }}
+    | _ -> raise (Error (InvalidReport "expected an object"))
+
+  let total_time t = List.fold_left (fun acc (_, k) -> acc +. k.total) 0.0 t.kernels
+end
@@ -0,0 +1,13 @@
+module Report: sig
+  type kernel = {{ invocations: int; total: float }}
+  (** Statistics for a kernel (or any other event, such as a copy), [total] is the time spent running it in seconds *)
+
+  type t = {{ memory: (string * int) list; kernels: (string * kernel) list }}
+  (** Profiling report, [memory] contains the peak memory usage in bytes for each memory space and [kernels] is sorted by name *)
+
+  val parse: string -> t
+  (** Parse a JSON report, as created by recent versions of Futhark *)
+
+  val total_time: t -> float
+  (** Total time spent running kernels in seconds *)
+end
@@ -13,7 +13,7 @@ pub struct {rust_type}<'a> {{
 
 impl<'a> {rust_type}<'a> {{
     /// Create a new array of `dims` dimensions and initialize it with the values from `data`
-    pub fn new(ctx: &'a Context, dims: [i64; {rank}], data: impl AsRef<[{elemtype}]>) -> std::result::Result<Self, Error> {{
+    pub fn new(ctx: &'a Context, dims: [i64; {rank}], data: impl AsRef<[{rust_elemtype}]>) -> std::result::Result<Self, Error> {{
         let size: i64 = dims.iter().product();
         let data = data.as_ref();
         if data.len() as i64 != size {{
@@ -37,7 +37,7 @@ impl<'a> {rust_type}<'a> {{
     }}
 
     /// Load values back into a slice
-    pub fn values(&self, mut data: impl AsMut<[{elemtype}]>) -> std::result::Result<(), Error> {{
+    pub fn values(&self, mut data: impl AsMut<[{rust_elemtype}]>) -> std::result::Result<(), Error> {{
         let size: i64 = self.shape.iter().product();
         let data = data.as_mut();
         if data.len() as i64 != size {{
@@ -54,9 +54,9 @@ impl<'a> {rust_type}<'a> {{
     }}
 
     /// Load values into a `Vec`
-    pub fn get(&self) -> std::result::Result<Vec<{elemtype}>, Error> {{
+    pub fn get(&self) -> std::result::Result<Vec<{rust_elemtype}>, Error> {{
         let size: i64 = self.shape.iter().product();
-        let mut vec = vec![{elemtype}::default(); size as usize];
+        let mut vec = vec![{rust_elemtype}::default(); size as usize];
         self.values(&mut vec)?;
         Ok(vec)
     }}
@@ -64,7 +64,7 @@ impl<'a> {rust_type}<'a> {{
 
     #[allow(unused)]
     fn from_ptr(ctx: &'a Context, ptr: *mut {futhark_type}) -> Self {{
-        let len_ptr = unsafe {{ futhark_shape_{elemtype}_{rank}d(ctx.context, ptr) }};
+        let len_ptr = unsafe {{ {shape_fn}(ctx.context, ptr) }};
         let mut shape = [0i64; {rank}];
         unsafe {{
             for (i, s) in shape.iter_mut().enumerate() {{
@@ -79,7 +79,7 @@ impl<'a> {rust_type}<'a> {{
 impl<'a> Drop for {rust_type}<'a> {{
     fn drop(&mut self){{
         unsafe {{
-            futhark_free_{elemtype}_{rank}d(self.ctx.context, self.ptr as *mut _);
+            {free_fn}(self.ctx.context, self.ptr as *mut _);
         }}
     }}
 }}
@@ -93,7 +93,7 @@ extern "C" {{
 
     fn {new_fn}(
         _: *mut futhark_context,
-        _: *const {elemtype},
+        _: *const {rust_elemtype},
         {new_dim_args}
     ) -> *mut {futhark_type};
 
@@ -105,6 +105,6 @@ extern "C" {{
     fn {values_fn}(
         _: *mut futhark_context,
         _: *mut {futhark_type},
-        _: *mut {elemtype}
+        _: *mut {rust_elemtype}
     ) -> std::os::raw::c_int;
 }}
@@ -0,0 +1,27 @@
+impl<'a> {rust_type}<'a> {{
+    /// Load values back into a slice, without blocking while the context is synced. The values
+    /// are copied into `data` once they are available on the host.
+    pub fn values_async<'b>(&self, data: &'b mut [{rust_elemtype}]) -> impl std::future::Future<Output = std::result::Result<(), Error>> + Send + 'b {{
+        let size: i64 = self.shape.iter().product();
+        let values = if data.len() as i64 != size {{ Err(Error::InvalidShape) }} else {{ Ok(self.get_async()) }};
+        async move {{
+            data.copy_from_slice(&values?.await?);
+            Ok(())
+        }}
+    }}
+
+    /// Load values into a `Vec`, without blocking while the context is synced. If the future is
+    /// dropped early it blocks until the sync has finished, since the `Vec` is still being written.
+    pub fn get_async(&self) -> ValuesFuture<{rust_elemtype}> {{
+        let size: i64 = self.shape.iter().product();
+        let mut data = vec![{rust_elemtype}::default(); size as usize];
+        let rc = unsafe {{
+            {values_fn}(self.ctx.context, self.ptr, data.as_mut_ptr())
+        }};
+        ValuesFuture {{ rc, sync: Some(self.ctx.sync_async()), data }}
+    }}
+}}
+
+// Arrays are only accessed through the context, which uses an internal lock
+unsafe impl Send for {rust_type}<'_> {{}}
+unsafe impl Sync for {rust_type}<'_> {{}}
@@ -0,0 +1,11 @@
+impl<'a> {rust_type}<'a> {{
+    /// Copy the array to the host
+    pub fn to_host(&self) -> std::result::Result<HostArray<{rust_elemtype}, {rank}>, Error> {{
+        Ok(HostArray {{ shape: self.shape, data: self.get()? }})
+    }}
+
+    /// Create a new array from data stored on the host
+    pub fn from_host(ctx: &'a Context, host: &HostArray<{rust_elemtype}, {rank}>) -> std::result::Result<Self, Error> {{
+        Self::new(ctx, host.shape, &host.data)
+    }}
+}}
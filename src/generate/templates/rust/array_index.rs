@@ -0,0 +1,31 @@
+impl<'a> {rust_type}<'a> {{
+    /// Get a single element of the array, without copying the whole array to the host
+    pub fn index(&self, index: [i64; {rank}]) -> std::result::Result<{rust_elemtype}, Error> {{
+        if index.iter().zip(self.shape.iter()).any(|(i, n)| *i < 0 || i >= n) {{
+            return Err(Error::IndexOutOfBounds);
+        }}
+        let mut out = {rust_elemtype}::default();
+        let rc = unsafe {{
+            {index_fn}(self.ctx.context, &mut out, self.ptr, {index_params})
+        }};
+        if rc != 0 {{
+            return Err(Error::Code(rc));
+        }}
+        // `out` lives on the stack, so always wait for the value to be written
+        let rc = unsafe {{ futhark_context_sync(self.ctx.context) }};
+        if rc != 0 {{
+            return Err(Error::Code(rc));
+        }}
+        Ok(out)
+    }}
+}}
+
+#[allow(unused)]
+extern "C" {{
+    fn {index_fn}(
+        _: *mut futhark_context,
+        _: *mut {rust_elemtype},
+        _: *mut {futhark_type},
+        {index_args}
+    ) -> std::os::raw::c_int;
+}}
@@ -0,0 +1,7 @@
+impl<'a> {rust_type}<'a> {{
+    /// Get a single element of the array, the manifest doesn't include an `index` function so this
+    /// always returns `Error::Unsupported`
+    pub fn index(&self, _index: [i64; {rank}]) -> std::result::Result<{rust_elemtype}, Error> {{
+        Err(Error::Unsupported("index"))
+    }}
+}}
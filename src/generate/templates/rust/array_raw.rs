@@ -0,0 +1,46 @@
+impl<'a> {rust_type}<'a> {{
+    /// Create a new array of `dims` dimensions using existing backend memory, without copying it
+    ///
+    /// # Safety
+    ///
+    /// `data` must have been allocated for the same device as `ctx`, hold at least as many elements
+    /// as described by `dims` and stay valid for as long as the array is alive
+    pub unsafe fn from_raw(ctx: &'a Context, dims: [i64; {rank}], data: {raw_type}) -> std::result::Result<Self, Error> {{
+        let ptr = unsafe {{
+            {new_raw_fn}(ctx.context, data, {dim_params})
+        }};
+        if ptr.is_null() {{ return Err(Error::NullPtr); }}
+        ctx.auto_sync();
+        Ok(Self {{
+            ptr,
+            shape: dims,
+            ctx,
+        }})
+    }}
+
+    /// Get the backend memory used by the array
+    ///
+    /// # Safety
+    ///
+    /// The memory is owned by the array, it must not be freed and is only valid while the array is
+    /// alive. The context should be synced before the memory is accessed by other libraries
+    pub unsafe fn as_raw(&self) -> {raw_type} {{
+        unsafe {{
+            {values_raw_fn}(self.ctx.context, self.ptr)
+        }}
+    }}
+}}
+
+#[allow(unused)]
+extern "C" {{
+    fn {new_raw_fn}(
+        _: *mut futhark_context,
+        _: {raw_type},
+        {new_dim_args}
+    ) -> *mut {futhark_type};
+
+    fn {values_raw_fn}(
+        _: *mut futhark_context,
+        _: *mut {futhark_type}
+    ) -> {raw_type};
+}}
@@ -0,0 +1,128 @@
+// Result of a sync run by a `SyncWorker`, along with the task waiting for it
+#[derive(Default)]
+struct SyncState {{
+    inner: std::sync::Mutex<(std::option::Option<std::os::raw::c_int>, std::option::Option<std::task::Waker>)>,
+    done: std::sync::Condvar,
+}}
+
+struct ContextPtr(*mut futhark_context);
+
+// Futhark contexts use an internal lock, so syncing from another thread is safe
+unsafe impl Send for ContextPtr {{}}
+
+/// Thread owned by a `Context`, used to sync without blocking async executors
+struct SyncWorker {{
+    sender: std::sync::Mutex<std::sync::mpsc::Sender<std::sync::Arc<SyncState>>>,
+    thread: std::thread::JoinHandle<()>,
+}}
+
+impl SyncWorker {{
+    fn new(context: *mut futhark_context) -> Self {{
+        let (sender, receiver) = std::sync::mpsc::channel::<std::sync::Arc<SyncState>>();
+        let ptr = ContextPtr(context);
+        let thread = std::thread::spawn(move || {{
+            let ptr = ptr;
+            for state in receiver {{
+                let rc = unsafe {{ futhark_context_sync(ptr.0) }};
+                let mut inner = state.inner.lock().unwrap();
+                inner.0 = Some(rc);
+                state.done.notify_all();
+                if let Some(waker) = inner.1.take() {{
+                    waker.wake();
+                }}
+            }}
+        }});
+        SyncWorker {{ sender: std::sync::Mutex::new(sender), thread }}
+    }}
+
+    /// Wait for any pending syncs and stop the thread
+    fn stop(self) {{
+        drop(self.sender);
+        let _ = self.thread.join();
+    }}
+}}
+
+/// Future returned by `Context::sync_async`, resolved once the context has been synced. It
+/// doesn't borrow the context, so it can be sent to other threads.
+pub struct SyncFuture {{
+    state: std::sync::Arc<SyncState>,
+}}
+
+impl std::future::Future for SyncFuture {{
+    type Output = std::result::Result<(), Error>;
+
+    fn poll(self: std::pin::Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> std::task::Poll<Self::Output> {{
+        let mut inner = self.state.inner.lock().unwrap();
+        match inner.0 {{
+            Some(0) => std::task::Poll::Ready(Ok(())),
+            Some(rc) => std::task::Poll::Ready(Err(Error::Code(rc))),
+            None => {{
+                inner.1 = Some(cx.waker().clone());
+                std::task::Poll::Pending
+            }}
+        }}
+    }}
+}}
+
+impl SyncFuture {{
+    /// Block the current thread until the context has been synced
+    fn wait(&self) {{
+        let inner = self.state.inner.lock().unwrap();
+        let _unused = self.state.done.wait_while(inner, |inner| inner.0.is_none()).unwrap();
+    }}
+}}
+
+/// Future returned by `get_async` on arrays, resolved once the values have been copied to the
+/// host. Dropping it before then blocks until the context has been synced, since the values are
+/// still being written to the buffer it owns.
+pub struct ValuesFuture<T> {{
+    rc: std::os::raw::c_int,
+    sync: std::option::Option<SyncFuture>,
+    data: Vec<T>,
+}}
+
+impl<T: Unpin> std::future::Future for ValuesFuture<T> {{
+    type Output = std::result::Result<Vec<T>, Error>;
+
+    fn poll(mut self: std::pin::Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> std::task::Poll<Self::Output> {{
+        if let Some(sync) = &mut self.sync {{
+            std::task::ready!(std::pin::Pin::new(sync).poll(cx))?;
+            self.sync = None;
+        }}
+        if self.rc != 0 {{
+            return std::task::Poll::Ready(Err(Error::Code(self.rc)));
+        }}
+        std::task::Poll::Ready(Ok(std::mem::take(&mut self.data)))
+    }}
+}}
+
+impl<T> Drop for ValuesFuture<T> {{
+    fn drop(&mut self) {{
+        if let Some(sync) = self.sync.take() {{
+            sync.wait();
+        }}
+    }}
+}}
+
+// Futhark contexts use an internal lock, so they can be shared between threads
+unsafe impl Send for Context {{}}
+unsafe impl Sync for Context {{}}
+
+impl Context {{
+    fn sync_worker(&self) -> &SyncWorker {{
+        self.sync_worker.get_or_init(|| SyncWorker::new(self.context))
+    }}
+
+    /// Start syncing the context on a separate thread, the returned future can be awaited from
+    /// any async runtime without blocking it
+    pub fn sync_async(&self) -> SyncFuture {{
+        let state = std::sync::Arc::new(SyncState::default());
+        self.sync_worker()
+            .sender
+            .lock()
+            .unwrap()
+            .send(state.clone())
+            .expect("Sync worker has stopped");
+        SyncFuture {{ state }}
+    }}
+}}
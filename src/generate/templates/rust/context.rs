@@ -3,6 +3,10 @@ pub enum Error {{
     Code(std::os::raw::c_int),
     NullPtr,
     InvalidShape,
+    IndexOutOfBounds,
+    Unsupported(&'static str),
+    MissingField(&'static str),
+    InvalidTuning(String),{error_variants}
 }}
 
 impl std::fmt::Display for Error {{
@@ -11,6 +15,10 @@ impl std::fmt::Display for Error {{
             Error::Code(code) => write!(fmt, "Futhark error code: {{code}}"),
             Error::NullPtr => write!(fmt, "NULL pointer encountered"),
             Error::InvalidShape => write!(fmt, "Invalid image shape"),
+            Error::IndexOutOfBounds => write!(fmt, "Index out of bounds"),
+            Error::Unsupported(op) => write!(fmt, "{{op}} is not supported by this version of Futhark"),
+            Error::MissingField(field) => write!(fmt, "Missing field: {{field}}"),
+            Error::InvalidTuning(msg) => write!(fmt, "Invalid tuning parameter: {{msg}}"),{error_display}
         }}
     }} 
 }}
@@ -22,9 +30,9 @@ pub struct Options {{
     debug: bool,
     profile: bool,
     logging: bool,
-    num_threads: u32,
-    cache_file: std::option::Option<std::ffi::CString>,
-    device: std::option::Option<std::ffi::CString>,
+    cache_file: std::option::Option<std::ffi::CString>,{backend_fields}
+    tuning_params: Vec<(std::ffi::CString, usize)>,
+    tuning_file: std::option::Option<std::path::PathBuf>,
     auto_sync: bool,
 }}
 
@@ -41,9 +49,9 @@ impl Options {{
             debug: false,
             profile: false,
             logging: false,
-            num_threads: 0,
-            cache_file: None,
-            device: None,
+            cache_file: None,{backend_defaults}
+            tuning_params: Vec::new(),
+            tuning_file: None,
             auto_sync: true,
         }}
     }}
@@ -77,6 +85,36 @@ impl Options {{
         self
     }}
 
+    /// Set a tuning parameter, these are applied after the parameters from the tuning file
+    pub fn tuning_param(mut self, name: impl AsRef<str>, value: usize) -> Self {{
+        let name = std::ffi::CString::new(name.as_ref()).expect("Invalid tuning parameter");
+        self.tuning_params.push((name, value));
+        self
+    }}
+
+    /// Load tuning parameters from a `.tuning` file when the context is created
+    pub fn tuning_file(mut self, path: impl AsRef<std::path::Path>) -> Self {{
+        self.tuning_file = Some(path.as_ref().to_path_buf());
+        self
+    }}
+
+    // Read a tuning file, every line has the form `name=value`
+    fn read_tuning_file(path: &std::path::Path) -> std::result::Result<Vec<(std::ffi::CString, usize)>, Error> {{
+        let s = std::fs::read_to_string(path)
+            .map_err(|e| Error::InvalidTuning(format!("{{}}: {{e}}", path.display())))?;
+        s.lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| {{
+                let invalid = || Error::InvalidTuning(line.to_string());
+                let (name, value) = line.split_once('=').ok_or_else(invalid)?;
+                let value = value.trim().parse().map_err(|_| invalid())?;
+                let name = std::ffi::CString::new(name.trim()).map_err(|_| invalid())?;
+                Ok((name, value))
+            }})
+            .collect()
+    }}
+
 
     {backend_options}
 }}
@@ -86,7 +124,7 @@ pub struct Context {{
     config: *mut futhark_context_config,
     context: *mut futhark_context,
     auto_sync: bool,
-    _cache_file: std::option::Option<std::ffi::CString>,
+    _cache_file: std::option::Option<std::ffi::CString>,{async_context_field}
 }}
 
 impl Context {{
@@ -100,16 +138,29 @@ impl Context {{
                 futhark_context_config_free(config);
                 return Err(Error::NullPtr);
             }}
-            Ok(Context {{ config, context, auto_sync: true, _cache_file: None }})
+            Ok(Context {{ config, context, auto_sync: true, _cache_file: None{async_context_init} }})
         }}
     }}
 
     /// Create a new context with custom options
     pub fn new_with_options(options: Options) -> std::result::Result<Self, Error> {{
+        let mut tuning_params = match &options.tuning_file {{
+            Some(path) => Options::read_tuning_file(path)?,
+            None => Vec::new(),
+        }};
+        tuning_params.extend(options.tuning_params.iter().cloned());
+
         unsafe {{
             let config = futhark_context_config_new();
             if config.is_null() {{ return Err(Error::NullPtr) }}
 
+            for (name, value) in &tuning_params {{
+                if futhark_context_config_set_tuning_param(config, name.as_ptr(), *value) != 0 {{
+                    futhark_context_config_free(config);
+                    return Err(Error::InvalidTuning(name.to_string_lossy().into_owned()));
+                }}
+            }}
+
             futhark_context_config_set_debugging(config, options.debug as std::os::raw::c_int);
             futhark_context_config_set_profiling(config, options.profile as std::os::raw::c_int);
             futhark_context_config_set_logging(config, options.logging as std::os::raw::c_int);
@@ -118,15 +169,14 @@ impl Context {{
                 futhark_context_config_set_cache_file(config, c.as_ptr());
             }}
 
-            {configure_num_threads}
-            {configure_set_device}
+            {configure_backend}
 
             let context = futhark_context_new(config);
             if context.is_null() {{
                 futhark_context_config_free(config);
                 return Err(Error::NullPtr);
             }}
-            Ok(Context {{ config, context, auto_sync: options.auto_sync, _cache_file: options.cache_file }})
+            Ok(Context {{ config, context, auto_sync: options.auto_sync, _cache_file: options.cache_file{async_context_init} }})
         }}
     }}
 
@@ -188,7 +238,7 @@ impl Context {{
 }}
 
 impl Drop for Context {{
-    fn drop(&mut self) {{
+    fn drop(&mut self) {{{async_context_drop}
         unsafe {{
             futhark_context_sync(self.context);
             futhark_context_free(self.context);
@@ -234,6 +284,12 @@ extern "C" {{
         _: *const std::os::raw::c_char,
     );
 
+    fn futhark_context_config_set_tuning_param(
+        _: *mut futhark_context_config,
+        _: *const std::os::raw::c_char,
+        _: usize,
+    ) -> std::os::raw::c_int;
+
     fn futhark_context_new(
         _: *mut futhark_context_config
     ) -> *mut futhark_context;
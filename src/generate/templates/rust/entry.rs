@@ -1,9 +1,9 @@
 impl Context {{
     /// Entry point: {entry_name}
     pub fn {entry_name}(&self, {entry_params}) -> Result<{entry_return_type}, Error> {{
-        {out_decl}
+        {out_decl};
         let rc = unsafe {{
-            futhark_entry_{entry_name}(self.context, {call_args})
+            {entry_fn}(self.context, {call_args})
         }};
         if rc != 0 {{ return Err(Error::Code(rc)); }}
     
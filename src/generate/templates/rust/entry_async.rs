@@ -0,0 +1,13 @@
+impl Context {{
+    /// Entry point: {entry_name}, the result is returned once the context has been synced
+    /// without blocking the async runtime. The returned future is `Send`, the inputs are only
+    /// used before it is created.
+    pub fn {entry_name}_async(&self, {entry_params}) -> impl std::future::Future<Output = std::result::Result<{entry_return_type}, Error>> + Send + '_ {{
+        let out = self.{entry_name}({entry_args}).map(|out| (out, self.sync_async()));
+        async move {{
+            let (out, sync) = out?;
+            sync.await?;
+            Ok(out)
+        }}
+    }}
+}}
@@ -0,0 +1,10 @@
+impl ContextHandle {{
+    /// Entry point: {entry_name}, run on the worker thread
+    pub async fn {entry_name}(&self, {entry_params}) -> Result<{entry_return_type}, Error> {{
+        self.run(move |ctx| {{
+            {convert_inputs}
+            {call_entry}
+            {convert_outputs}
+        }}).await?
+    }}
+}}
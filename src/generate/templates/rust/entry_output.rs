@@ -0,0 +1,4 @@
+/// Outputs of the `{entry_name}` entry point
+pub struct {output_type}{lifetime} {{
+{output_fields}
+}}
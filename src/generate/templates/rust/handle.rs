@@ -0,0 +1,95 @@
+type Job = Box<dyn FnOnce(&Context) + Send>;
+
+struct JobState<T> {{
+    value: std::option::Option<T>,
+    finished: bool,
+    waker: std::option::Option<std::task::Waker>,
+}}
+
+// Sends the result of a job, the job is marked as finished when this is dropped, even if the
+// worker panics or has already stopped
+struct JobResult<T>(std::sync::Arc<std::sync::Mutex<JobState<T>>>);
+
+impl<T> JobResult<T> {{
+    fn set(&self, value: T) {{
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).value = Some(value);
+    }}
+}}
+
+impl<T> Drop for JobResult<T> {{
+    fn drop(&mut self) {{
+        let mut state = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        state.finished = true;
+        if let Some(waker) = state.waker.take() {{
+            waker.wake();
+        }}
+    }}
+}}
+
+/// Future returned by `ContextHandle::run`, resolved once the job has finished
+pub struct JobFuture<T> {{
+    state: std::sync::Arc<std::sync::Mutex<JobState<T>>>,
+}}
+
+impl<T> std::future::Future for JobFuture<T> {{
+    type Output = std::result::Result<T, Error>;
+
+    fn poll(self: std::pin::Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> std::task::Poll<Self::Output> {{
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(value) = state.value.take() {{
+            return std::task::Poll::Ready(Ok(value));
+        }}
+        if state.finished {{
+            return std::task::Poll::Ready(Err(Error::WorkerStopped));
+        }}
+        state.waker = Some(cx.waker().clone());
+        std::task::Poll::Pending
+    }}
+}}
+
+/// Handle to a `Context` owned by a worker thread, jobs sent using the handle are run one at a
+/// time. The worker stops once all clones of the handle have been dropped.
+#[derive(Clone)]
+pub struct ContextHandle {{
+    sender: std::sync::mpsc::Sender<Job>,
+}}
+
+impl ContextHandle {{
+    /// Spawn a worker thread owning a new context created using `options`
+    pub fn new(options: Options) -> std::result::Result<Self, Error> {{
+        let (sender, receiver) = std::sync::mpsc::channel::<Job>();
+        let (init_sender, init_receiver) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {{
+            let ctx = match Context::new_with_options(options) {{
+                Ok(ctx) => {{
+                    let _ = init_sender.send(Ok(()));
+                    ctx
+                }}
+                Err(e) => {{
+                    let _ = init_sender.send(Err(e));
+                    return;
+                }}
+            }};
+            for job in receiver {{
+                job(&ctx);
+            }}
+        }});
+        init_receiver.recv().unwrap_or(Err(Error::WorkerStopped))?;
+        Ok(ContextHandle {{ sender }})
+    }}
+
+    /// Run `f` using the context on the worker thread
+    pub fn run<T: Send + 'static>(&self, f: impl FnOnce(&Context) -> T + Send + 'static) -> JobFuture<T> {{
+        let state = std::sync::Arc::new(std::sync::Mutex::new(JobState {{
+            value: None,
+            finished: false,
+            waker: None,
+        }}));
+        let result = JobResult(state.clone());
+        // If the worker has stopped the job is dropped, which finishes the future
+        let _ = self.sender.send(Box::new(move |ctx: &Context| {{
+            result.set(f(ctx));
+        }}));
+        JobFuture {{ state }}
+    }}
+}}
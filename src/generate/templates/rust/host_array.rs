@@ -0,0 +1,7 @@
+/// Array data stored on the host, used to pass arrays to and from a `ContextHandle` and to
+/// serialize arrays
+#[derive(Debug, Clone, PartialEq)]{serde_derive}
+pub struct HostArray<T, const N: usize> {{
+    pub data: Vec<T>,
+    pub shape: [i64; N],
+}}
@@ -0,0 +1,6 @@
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(bound(
+    serialize = "T: serde::Serialize, [i64; N]: serde::Serialize",
+    deserialize = "T: serde::Deserialize<'de>, [i64; N]: serde::Deserialize<'de>"
+))]
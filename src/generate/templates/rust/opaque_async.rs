@@ -0,0 +1,3 @@
+// Opaque values are only accessed through the context, which uses an internal lock
+unsafe impl Send for {rust_type}<'_> {{}}
+unsafe impl Sync for {rust_type}<'_> {{}}
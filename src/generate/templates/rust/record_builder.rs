@@ -0,0 +1,18 @@
+/// Builder for [{rust_type}], all fields have to be set before calling `build`
+#[derive(Default)]
+pub struct {builder_type}{lifetime} {{
+{builder_fields}
+}}
+
+impl{lifetime} {builder_type}{lifetime} {{
+    /// Create a new builder without any fields set
+    pub fn new() -> Self {{
+        Self::default()
+    }}
+{setters}
+    /// Create a new [{rust_type}], returns `Error::MissingField` if any field hasn't been set
+    pub fn build<'a>(self, ctx: &'a Context) -> std::result::Result<{rust_type}<'a>, Error> {{
+        {build_fields}
+        {rust_type}::new(ctx, {build_args})
+    }}
+}}
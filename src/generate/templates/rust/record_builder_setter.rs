@@ -0,0 +1,6 @@
+
+    /// Set the `{field_name}` field
+    pub fn {setter}(mut self, value: {field_type}) -> Self {{
+        self.field{field_name} = Some(value);
+        self
+    }}
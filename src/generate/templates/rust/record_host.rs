@@ -0,0 +1,15 @@
+/// Host copy of [{rust_type}]
+#[derive(Debug, Clone, PartialEq{serde_derive})]
+pub struct {host_type}{host_fields}
+
+impl<'a> {rust_type}<'a> {{
+    /// Copy all fields to the host, including nested records and arrays
+    pub fn to_host(&self) -> std::result::Result<{host_type}, Error> {{
+        Ok({host_type} {{ {to_host_fields} }})
+    }}
+
+    /// Create a new value from a host copy
+    pub fn from_host(ctx: &'a Context, host: &{host_type}) -> std::result::Result<Self, Error> {{
+        {from_host}
+    }}
+}}
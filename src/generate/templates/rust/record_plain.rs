@@ -0,0 +1,17 @@
+/// Host representation of [{rust_type}]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct {plain_type}{plain_fields}
+
+impl {plain_type} {{
+    /// Create a new Futhark value with the same fields
+    pub fn to_opaque<'a>(&self, ctx: &'a Context) -> std::result::Result<{rust_type}<'a>, Error> {{
+        {rust_type}::new(ctx, {field_values})
+    }}
+}}
+
+impl<'a> {rust_type}<'a> {{
+    /// Load all fields into a [{plain_type}]
+    pub fn get(&self) -> std::result::Result<{plain_type}, Error> {{
+        Ok({plain_type} {{ {get_fields} }})
+    }}
+}}
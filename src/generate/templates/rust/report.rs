@@ -0,0 +1,238 @@
+/// Statistics for a kernel (or any other event, such as a copy) in a profiling `Report`
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct KernelStats {{
+    /// Number of times the kernel was run
+    pub invocations: usize,
+    /// Total time spent running the kernel
+    pub total: std::time::Duration,
+}}
+
+impl KernelStats {{
+    /// Average time per invocation
+    pub fn mean(&self) -> std::time::Duration {{
+        if self.invocations == 0 {{ return std::time::Duration::ZERO }}
+        self.total.div_f64(self.invocations as f64)
+    }}
+}}
+
+/// Profiling report, parsed from the JSON created by `Context::report`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Report {{
+    /// Peak memory usage in bytes for each memory space
+    pub memory: std::collections::BTreeMap<String, u64>,
+    /// Statistics for each kernel, by name
+    pub kernels: std::collections::BTreeMap<String, KernelStats>,
+}}
+
+impl Report {{
+    /// Parse a JSON report, as created by recent versions of Futhark
+    pub fn parse(s: &str) -> std::result::Result<Self, Error> {{
+        let mut parser = ReportParser {{ s: s.as_bytes(), pos: 0 }};
+        let value = parser.value()?;
+        parser.ws();
+        if parser.pos != parser.s.len() {{ return Err(parser.error()) }}
+
+        let fields = match value {{
+            ReportJson::Object(fields) => fields,
+            _ => return Err(Error::InvalidReport("expected an object".to_string())),
+        }};
+        let mut report = Report::default();
+        for (key, value) in fields {{
+            match (key.as_str(), value) {{
+                ("memory", ReportJson::Object(spaces)) => {{
+                    for (name, bytes) in spaces {{
+                        if let ReportJson::Number(n) = bytes {{
+                            report.memory.insert(name, n as u64);
+                        }}
+                    }}
+                }}
+                ("events", ReportJson::Array(events)) => {{
+                    for event in events {{
+                        report.add_event(event);
+                    }}
+                }}
+                _ => (),
+            }}
+        }}
+        Ok(report)
+    }}
+
+    /// Total time spent running kernels
+    pub fn total_time(&self) -> std::time::Duration {{
+        self.kernels.values().map(|k| k.total).sum()
+    }}
+
+    // Events have a name and either a start and end time or a duration, in microseconds
+    fn add_event(&mut self, event: ReportJson) {{
+        let fields = match event {{
+            ReportJson::Object(fields) => fields,
+            _ => return,
+        }};
+        let number = |key: &str| fields.iter().find_map(|(k, v)| match v {{
+            ReportJson::Number(n) if k == key => Some(*n),
+            _ => None,
+        }});
+        let name = fields.iter().find_map(|(k, v)| match v {{
+            ReportJson::String(s) if k == "name" => Some(s.clone()),
+            _ => None,
+        }});
+        let time = match (number("start"), number("end"), number("duration")) {{
+            (Some(start), Some(end), _) => end - start,
+            (_, _, Some(duration)) => duration,
+            _ => 0.0,
+        }};
+        if let Some(name) = name {{
+            let stats = self.kernels.entry(name).or_default();
+            stats.invocations += 1;
+            stats.total += std::time::Duration::from_secs_f64(time.max(0.0) / 1e6);
+        }}
+    }}
+}}
+
+impl Context {{
+    /// Get the profiling report as a `Report`, this requires a version of Futhark that creates
+    /// JSON reports
+    pub fn parsed_report(&self) -> std::result::Result<Report, Error> {{
+        let s = self.report().ok_or(Error::NullPtr)?;
+        Report::parse(&s)
+    }}
+
+    /// Run `f` and return its result along with a report of the work done while it was running
+    ///
+    /// Profiling has to be enabled using `Options::profile`. The counters are reset by reading
+    /// the report before calling `f`, the context is synced before the final report is created
+    pub fn profile<T>(&self, f: impl FnOnce() -> T) -> std::result::Result<(T, Report), Error> {{
+        self.sync();
+        let _ = self.report();
+        let x = f();
+        self.sync();
+        Ok((x, self.parsed_report()?))
+    }}
+}}
+
+// Minimal JSON representation used to parse reports, booleans and nulls are ignored
+enum ReportJson {{
+    Number(f64),
+    String(String),
+    Array(Vec<ReportJson>),
+    Object(Vec<(String, ReportJson)>),
+    Other,
+}}
+
+struct ReportParser<'a> {{
+    s: &'a [u8],
+    pos: usize,
+}}
+
+impl ReportParser<'_> {{
+    fn error(&self) -> Error {{
+        Error::InvalidReport(format!("unexpected input at byte {{}}", self.pos))
+    }}
+
+    fn ws(&mut self) {{
+        while matches!(self.s.get(self.pos), Some(c) if c.is_ascii_whitespace()) {{
+            self.pos += 1;
+        }}
+    }}
+
+    fn eat(&mut self, c: u8) -> bool {{
+        self.ws();
+        if self.s.get(self.pos) == Some(&c) {{
+            self.pos += 1;
+            return true;
+        }}
+        false
+    }}
+
+    fn expect(&mut self, c: u8) -> std::result::Result<(), Error> {{
+        if !self.eat(c) {{ return Err(self.error()) }}
+        Ok(())
+    }}
+
+    fn value(&mut self) -> std::result::Result<ReportJson, Error> {{
+        self.ws();
+        match self.s.get(self.pos) {{
+            Some(b'{{') => {{
+                self.pos += 1;
+                let mut fields = Vec::new();
+                if self.eat(b'}}') {{ return Ok(ReportJson::Object(fields)) }}
+                loop {{
+                    let key = self.string()?;
+                    self.expect(b':')?;
+                    fields.push((key, self.value()?));
+                    if !self.eat(b',') {{ break }}
+                }}
+                self.expect(b'}}')?;
+                Ok(ReportJson::Object(fields))
+            }}
+            Some(b'[') => {{
+                self.pos += 1;
+                let mut items = Vec::new();
+                if self.eat(b']') {{ return Ok(ReportJson::Array(items)) }}
+                loop {{
+                    items.push(self.value()?);
+                    if !self.eat(b',') {{ break }}
+                }}
+                self.expect(b']')?;
+                Ok(ReportJson::Array(items))
+            }}
+            Some(b'"') => Ok(ReportJson::String(self.string()?)),
+            Some(b't' | b'f' | b'n') => {{
+                for lit in ["true", "false", "null"] {{
+                    if self.s[self.pos..].starts_with(lit.as_bytes()) {{
+                        self.pos += lit.len();
+                        return Ok(ReportJson::Other);
+                    }}
+                }}
+                Err(self.error())
+            }}
+            Some(_) => {{
+                let start = self.pos;
+                while matches!(self.s.get(self.pos), Some(c) if b"+-0123456789.eE".contains(c)) {{
+                    self.pos += 1;
+                }}
+                std::str::from_utf8(&self.s[start..self.pos])
+                    .ok()
+                    .and_then(|n| n.parse().ok())
+                    .map(ReportJson::Number)
+                    .ok_or_else(|| self.error())
+            }}
+            None => Err(self.error()),
+        }}
+    }}
+
+    fn string(&mut self) -> std::result::Result<String, Error> {{
+        self.expect(b'"')?;
+        let mut out = Vec::new();
+        loop {{
+            let c = *self.s.get(self.pos).ok_or_else(|| self.error())?;
+            self.pos += 1;
+            match c {{
+                b'"' => break,
+                b'\\' => {{
+                    let c = *self.s.get(self.pos).ok_or_else(|| self.error())?;
+                    self.pos += 1;
+                    match c {{
+                        b'n' => out.push(b'\n'),
+                        b't' => out.push(b'\t'),
+                        b'r' => out.push(b'\r'),
+                        b'b' => out.push(8),
+                        b'f' => out.push(12),
+                        b'u' => {{
+                            let code = self.s.get(self.pos..self.pos + 4)
+                                .and_then(|h| std::str::from_utf8(h).ok())
+                                .and_then(|h| u32::from_str_radix(h, 16).ok())
+                                .ok_or_else(|| self.error())?;
+                            self.pos += 4;
+                            let c = char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER);
+                            out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                        }}
+                        c => out.push(c),
+                    }}
+                }}
+                c => out.push(c),
+            }}
+        }}
+        Ok(String::from_utf8_lossy(&out).into_owned())
+    }}
+}}
@@ -0,0 +1,57 @@
+const {futhark_type} = opaque {{}};
+
+extern fn {new_fn}(ctx: *futhark_context, data: [*]const {elemtype}, {new_dim_args}) ?*{futhark_type};
+extern fn {free_fn}(ctx: *futhark_context, arr: *{futhark_type}) c_int;
+extern fn {values_fn}(ctx: *futhark_context, arr: *{futhark_type}, data: [*]{elemtype}) c_int;
+extern fn {shape_fn}(ctx: *futhark_context, arr: *{futhark_type}) [*]const i64;
+
+/// Array type with {rank} dimensions and {elemtype} elements
+pub const {zig_type} = struct {{
+    ptr: *{futhark_type},
+    shape: [{rank}]i64,
+    ctx: *Context,
+
+    /// Create a new array with the shape `dims`, initialized with the values from `data`
+    pub fn init(ctx: *Context, data: []const {elemtype}, dims: [{rank}]i64) Error!{zig_type} {{
+        var size: usize = 1;
+        for (dims) |d| size *= @intCast(d);
+        if (data.len != size) return error.InvalidShape;
+        const ptr = {new_fn}(ctx.handle, data.ptr, {dim_params}) orelse return error.NullPtr;
+        try ctx.autoSync();
+        return .{{ .ptr = ptr, .shape = dims, .ctx = ctx }};
+    }}
+
+    fn fromPtr(ctx: *Context, ptr: *{futhark_type}) {zig_type} {{
+        const s = {shape_fn}(ctx.handle, ptr);
+        var shape: [{rank}]i64 = undefined;
+        for (&shape, 0..) |*d, i| d.* = s[i];
+        return .{{ .ptr = ptr, .shape = shape, .ctx = ctx }};
+    }}
+
+    /// Free the array
+    pub fn deinit(self: *{zig_type}) void {{
+        _ = {free_fn}(self.ctx.handle, self.ptr);
+    }}
+
+    /// Number of elements in the array
+    pub fn len(self: {zig_type}) usize {{
+        var size: usize = 1;
+        for (self.shape) |d| size *= @intCast(d);
+        return size;
+    }}
+
+    /// Copy the values into `out`, which must have the same number of elements as the array
+    pub fn values(self: {zig_type}, out: []{elemtype}) Error!void {{
+        if (out.len != self.len()) return error.InvalidShape;
+        if ({values_fn}(self.ctx.handle, self.ptr, out.ptr) != 0) return error.Futhark;
+        try self.ctx.autoSync();
+    }}
+
+    /// Copy the values into a new slice, the caller owns the returned memory
+    pub fn toSlice(self: {zig_type}, allocator: std.mem.Allocator) (Error || std.mem.Allocator.Error)![]{elemtype} {{
+        const out = try allocator.alloc({elemtype}, self.len());
+        errdefer allocator.free(out);
+        try self.values(out);
+        return out;
+    }}
+}};
@@ -0,0 +1,9 @@
+extern fn {entry_fn}(ctx: *futhark_context, {futhark_entry_params}) c_int;
+
+/// Entry point: {name}
+pub fn {entry_name}(ctx: *Context, {entry_params}) Error!{entry_return_type} {{
+{out_decl}
+    if ({entry_fn}(ctx.handle, {call_args}) != 0) return error.Futhark;
+    try ctx.autoSync();
+    return {entry_return};
+}}
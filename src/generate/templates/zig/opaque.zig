@@ -0,0 +1,18 @@
+const {futhark_type} = opaque {{}};
+
+extern fn {free_fn}(ctx: *futhark_context, obj: *{futhark_type}) c_int;
+
+/// Futhark type
+pub const {zig_type} = struct {{
+    ptr: *{futhark_type},
+    ctx: *Context,
+
+    fn fromPtr(ctx: *Context, ptr: *{futhark_type}) {zig_type} {{
+        return .{{ .ptr = ptr, .ctx = ctx }};
+    }}
+
+    /// Free the value
+    pub fn deinit(self: *{zig_type}) void {{
+        _ = {free_fn}(self.ctx.handle, self.ptr);
+    }}
+{record_methods}}};
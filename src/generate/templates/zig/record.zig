@@ -0,0 +1,10 @@
+
+    extern fn {new_fn}(ctx: *futhark_context, out: *?*{futhark_type}, {new_extern_params}) c_int;
+
+    /// Create a new {zig_type}
+    pub fn init(ctx: *Context, {new_params}) Error!{zig_type} {{
+        var out: ?*{futhark_type} = null;
+        if ({new_fn}(ctx.handle, &out, {new_call_args}) != 0) return error.Futhark;
+        try ctx.autoSync();
+        return .{{ .ptr = out orelse return error.NullPtr, .ctx = ctx }};
+    }}
@@ -0,0 +1,10 @@
+
+    extern fn {project_fn}(ctx: *futhark_context, out: *{futhark_field_type}, obj: *const {futhark_type}) c_int;
+
+    /// Get the {field_name} field
+    pub fn {getter}(self: {zig_type}) Error!{zig_field_type} {{
+        {out_decl}
+        if ({project_fn}(self.ctx.handle, &out, self.ptr) != 0) return error.Futhark;
+        try self.ctx.autoSync();
+        return {output};
+    }}
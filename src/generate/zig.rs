@@ -0,0 +1,362 @@
+use crate::generate::{camel_case, first_uppercase, Names, Types};
+use crate::*;
+use std::io::Write;
+
+/// Zig codegen
+pub struct Zig {
+    typemap: BTreeMap<String, String>,
+    types: Types,
+    type_names: Names,
+    entry_names: Names,
+}
+
+// Declarations in `templates/zig/context.zig`, along with the methods, parameters and locals used
+// in the other templates since Zig doesn't allow them to shadow top-level declarations
+const ZIG_RESERVED_NAMES: &[&str] = &[
+    "std",
+    "free",
+    "futhark_context",
+    "futhark_context_config",
+    "Context",
+    "Options",
+    "Error",
+    "init",
+    "deinit",
+    "sync",
+    "autoSync",
+    "clearCaches",
+    "pauseProfiling",
+    "unpauseProfiling",
+    "getError",
+    "report",
+    "fromPtr",
+    "len",
+    "values",
+    "toSlice",
+    "self",
+    "ctx",
+    "cfg",
+    "flag",
+    "f",
+    "options",
+    "config",
+    "handle",
+    "allocator",
+    "s",
+    "ptr",
+    "arr",
+    "obj",
+    "data",
+    "dims",
+    "size",
+    "shape",
+    "out",
+];
+
+impl Default for Zig {
+    fn default() -> Self {
+        Zig {
+            typemap: BTreeMap::new(),
+            types: Types::default(),
+            type_names: Names::new(ZIG_RESERVED_NAMES),
+            entry_names: Names::new(ZIG_RESERVED_NAMES),
+        }
+    }
+}
+
+impl Zig {
+    /// Get the Zig type used for a Futhark type, scalar types have the same name in both
+    fn get_type(&self, t: &str) -> String {
+        self.typemap
+            .get(t)
+            .cloned()
+            .unwrap_or_else(|| t.to_string())
+    }
+
+    /// Reserve the parameters and methods generated for the types and entry points in the
+    /// manifest: `dim{i}`, `input{i}`, `out{i}`, `field{name}` and `get{Name}`
+    fn reserve_generated_names(&mut self, pkg: &Package) {
+        let mut names = Vec::new();
+        for ty in pkg.manifest.types.values() {
+            match ty {
+                manifest::Type::Array(a) => names.extend((0..a.rank).map(|i| format!("dim{i}"))),
+                manifest::Type::Opaque(t) => {
+                    for field in t.record.iter().flat_map(|r| &r.fields) {
+                        names.push(format!("field{}", field.name));
+                        names.push(format!("get{}", first_uppercase(&field.name)));
+                    }
+                }
+            }
+        }
+        for entry in pkg.manifest.entry_points.values() {
+            names.extend((0..entry.inputs.len()).map(|i| format!("input{i}")));
+            names.extend((0..entry.outputs.len()).map(|i| format!("out{i}")));
+        }
+        let names: Vec<_> = names.iter().map(|x| x.as_str()).collect();
+        self.type_names.reserve(&names);
+        self.entry_names.reserve(&names);
+    }
+
+    /// Determine the Zig names of all array and opaque types
+    fn resolve_names(&mut self, pkg: &Package, config: &Config) -> Result<(), Error> {
+        self.reserve_generated_names(pkg);
+        for name in pkg.manifest.types.keys() {
+            let zig_type = config.naming.pascal_name(name, &self.types.get(name));
+            self.type_names.insert(&zig_type, name)?;
+            self.typemap.insert(name.clone(), zig_type);
+        }
+        Ok(())
+    }
+}
+
+impl Generate for Zig {
+    fn bindings(&mut self, pkg: &Package, config: &mut Config) -> Result<(), Error> {
+        self.types = Types::new(&pkg.manifest)?;
+        self.resolve_names(pkg, config)?;
+
+        writeln!(config.output_file, "// Generated by futhark-bindgen\n")?;
+
+        let (backend_extern_functions, backend_options, configure_backend) =
+            match pkg.manifest.backend {
+                Backend::Multicore => (
+                    "extern fn futhark_context_config_set_num_threads(cfg: *futhark_context_config, n: c_int) void;",
+                    "    num_threads: c_int = 0,",
+                    "        futhark_context_config_set_num_threads(config, options.num_threads);",
+                ),
+                Backend::CUDA | Backend::OpenCL => (
+                    "extern fn futhark_context_config_set_device(cfg: *futhark_context_config, s: [*:0]const u8) void;",
+                    "    device: ?[*:0]const u8 = null,",
+                    "        if (options.device) |d| futhark_context_config_set_device(config, d);",
+                ),
+                _ => ("", "", ""),
+            };
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/zig/context.zig"),
+            backend_extern_functions = backend_extern_functions,
+            backend_options = backend_options,
+            configure_backend = configure_backend,
+        )?;
+
+        Ok(())
+    }
+
+    fn array_type(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        a: &manifest::ArrayType,
+    ) -> Result<(), Error> {
+        let futhark_type = self.types.get(name).cname().unwrap().to_string();
+        let zig_type = self.get_type(name);
+
+        let mut dim_params = Vec::new();
+        let mut new_dim_args = Vec::new();
+
+        for i in 0..a.rank {
+            dim_params.push(format!("dims[{i}]"));
+            new_dim_args.push(format!("dim{i}: i64"));
+        }
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/zig/array.zig"),
+            futhark_type = futhark_type,
+            zig_type = zig_type,
+            rank = a.rank,
+            elemtype = a.elemtype.to_str(),
+            new_fn = a.ops.new,
+            free_fn = a.ops.free,
+            values_fn = a.ops.values,
+            shape_fn = a.ops.shape,
+            dim_params = dim_params.join(", "),
+            new_dim_args = new_dim_args.join(", ")
+        )?;
+
+        Ok(())
+    }
+
+    fn opaque_type(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        ty: &manifest::OpaqueType,
+    ) -> Result<(), Error> {
+        let futhark_type = self.types.get(name).cname().unwrap().to_string();
+        let zig_type = self.get_type(name);
+
+        // Record constructors and projections have to be declared inside of the struct
+        let mut record_methods = Vec::new();
+
+        if let Some(record) = &ty.record {
+            let mut new_call_args = vec![];
+            let mut new_params = vec![];
+            let mut new_extern_params = vec![];
+            for field in record.fields.iter() {
+                let field_type = self.types.get(&field.r#type);
+                let t = self.get_type(&field.r#type);
+                let param = format!("field{}", field.name);
+
+                let (out_decl, output, futhark_field_type) = match field_type.cname() {
+                    Some(cname) => {
+                        new_call_args.push(format!("{param}.ptr"));
+                        new_extern_params.push(format!("{param}: *const {cname}"));
+                        new_params.push(format!("{param}: *const {t}"));
+                        (
+                            format!("var out: ?*{cname} = null;"),
+                            format!("{t}.fromPtr(self.ctx, out orelse return error.NullPtr)"),
+                            format!("?*{cname}"),
+                        )
+                    }
+                    None => {
+                        new_call_args.push(param.clone());
+                        new_extern_params.push(format!("{param}: {t}"));
+                        new_params.push(format!("{param}: {t}"));
+                        (
+                            format!("var out: {t} = undefined;"),
+                            "out".to_string(),
+                            t.clone(),
+                        )
+                    }
+                };
+
+                let mut s = Vec::new();
+                write!(
+                    s,
+                    include_str!("templates/zig/record_project.zig"),
+                    project_fn = field.project,
+                    zig_type = zig_type,
+                    futhark_type = futhark_type,
+                    field_name = field.name,
+                    getter = format!("get{}", first_uppercase(&field.name)),
+                    futhark_field_type = futhark_field_type,
+                    zig_field_type = t,
+                    out_decl = out_decl,
+                    output = output,
+                )?;
+                record_methods.push(String::from_utf8(s).unwrap());
+            }
+
+            let mut s = Vec::new();
+            write!(
+                s,
+                include_str!("templates/zig/record.zig"),
+                zig_type = zig_type,
+                futhark_type = futhark_type,
+                new_fn = record.new,
+                new_params = new_params.join(", "),
+                new_call_args = new_call_args.join(", "),
+                new_extern_params = new_extern_params.join(", "),
+            )?;
+            record_methods.insert(0, String::from_utf8(s).unwrap());
+        }
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/zig/opaque.zig"),
+            futhark_type = futhark_type,
+            zig_type = zig_type,
+            free_fn = ty.ops.free,
+            record_methods = record_methods.join(""),
+        )?;
+
+        Ok(())
+    }
+
+    fn entry(
+        &mut self,
+        _pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        entry: &manifest::Entry,
+    ) -> Result<(), Error> {
+        let mut call_args = Vec::new();
+        let mut entry_params = Vec::new();
+        let mut return_type = Vec::new();
+        let mut out_decl = Vec::new();
+        let mut futhark_entry_params = Vec::new();
+        let mut entry_return = Vec::new();
+
+        // Output arguments
+        for (i, arg) in entry.outputs.iter().enumerate() {
+            let name = format!("out{i}");
+            let t = self.get_type(&arg.r#type);
+
+            match self.types.get(&arg.r#type).cname() {
+                Some(cname) => {
+                    futhark_entry_params.push(format!("{name}: *?*{cname}"));
+                    out_decl.push(format!("    var {name}: ?*{cname} = null;"));
+                    entry_return.push(format!(
+                        "{t}.fromPtr(ctx, {name} orelse return error.NullPtr)"
+                    ));
+                }
+                None => {
+                    futhark_entry_params.push(format!("{name}: *{t}"));
+                    out_decl.push(format!("    var {name}: {t} = undefined;"));
+                    entry_return.push(name.clone());
+                }
+            }
+
+            call_args.push(format!("&{name}"));
+            return_type.push(t);
+        }
+
+        // Input arguments
+        for (i, arg) in entry.inputs.iter().enumerate() {
+            let name = format!("input{i}");
+            let t = self.get_type(&arg.r#type);
+
+            match self.types.get(&arg.r#type).cname() {
+                Some(cname) => {
+                    futhark_entry_params.push(format!("{name}: *const {cname}"));
+                    entry_params.push(format!("{name}: *const {t}"));
+                    call_args.push(format!("{name}.ptr"));
+                }
+                None => {
+                    futhark_entry_params.push(format!("{name}: {t}"));
+                    entry_params.push(format!("{name}: {t}"));
+                    call_args.push(name);
+                }
+            }
+        }
+
+        let entry_name = config.naming.entry_name_or(name, camel_case(name));
+        self.entry_names.insert(&entry_name, name)?;
+
+        let (entry_return_type, entry_return) = match entry.outputs.len() {
+            0 => ("void".to_string(), "{}".to_string()),
+            1 => (return_type.join(", "), entry_return.join(", ")),
+            _ => (
+                format!("struct {{ {} }}", return_type.join(", ")),
+                format!(".{{ {} }}", entry_return.join(", ")),
+            ),
+        };
+
+        writeln!(
+            config.output_file,
+            include_str!("templates/zig/entry.zig"),
+            name = name,
+            entry_fn = entry.cfun,
+            entry_name = entry_name,
+            entry_params = entry_params.join(", "),
+            entry_return_type = entry_return_type,
+            out_decl = out_decl.join("\n"),
+            call_args = call_args.join(", "),
+            entry_return = entry_return,
+            futhark_entry_params = futhark_entry_params.join(", "),
+        )?;
+
+        Ok(())
+    }
+
+    fn format(&mut self, path: &std::path::Path) -> Result<(), Error> {
+        let _ = std::process::Command::new("zig")
+            .arg("fmt")
+            .arg(path)
+            .status();
+        Ok(())
+    }
+}
@@ -1,14 +1,19 @@
 pub(crate) use std::collections::BTreeMap;
 
 mod compiler;
+mod diff;
 mod error;
 pub(crate) mod generate;
 pub mod manifest;
 mod package;
 
 pub use compiler::Compiler;
+pub use diff::Change;
 pub use error::Error;
-pub use generate::{Config, Generate, OCaml, Rust};
+pub use generate::{
+    CSharp, Case, Config, Generate, Go, Haskell, Java, JavaScript, Julia, Lua, Naming, Nim, OCaml,
+    Rust, Zig, C,
+};
 pub use manifest::Manifest;
 pub use package::Package;
 
@@ -51,6 +56,18 @@ pub enum Backend {
     /// Requires a C compiler
     #[serde(rename = "hip")]
     HIP,
+
+    /// WebAssembly backend: `futhark wasm`
+    ///
+    /// Requires Emscripten
+    #[serde(rename = "wasm")]
+    Wasm,
+
+    /// Multicore WebAssembly backend: `futhark wasm-multicore`
+    ///
+    /// Requires Emscripten
+    #[serde(rename = "wasm-multicore")]
+    WasmMulticore,
 }
 
 impl Backend {
@@ -63,6 +80,8 @@ impl Backend {
             Backend::Multicore => "multicore",
             Backend::ISPC => "ispc",
             Backend::HIP => "hip",
+            Backend::Wasm => "wasm",
+            Backend::WasmMulticore => "wasm-multicore",
         }
     }
 
@@ -74,6 +93,9 @@ impl Backend {
             "opencl" => Some(Backend::OpenCL),
             "multicore" => Some(Backend::Multicore),
             "ispc" => Some(Backend::ISPC),
+            "hip" => Some(Backend::HIP),
+            "wasm" => Some(Backend::Wasm),
+            "wasm-multicore" => Some(Backend::WasmMulticore),
             _ => None,
         }
     }
@@ -111,6 +133,18 @@ pub fn build(
     backend: Backend,
     src: impl AsRef<std::path::Path>,
     dest: impl AsRef<std::path::Path>,
+) {
+    build_with(backend, src, dest, |config| config)
+}
+
+#[cfg(feature = "build")]
+/// Like `build`, `configure` can be used to change the codegen config, for example
+/// `|config| config.with_async_api(true)`
+pub fn build_with(
+    backend: Backend,
+    src: impl AsRef<std::path::Path>,
+    dest: impl AsRef<std::path::Path>,
+    configure: impl FnOnce(Config) -> Config,
 ) {
     let out = std::path::PathBuf::from(std::env::var("OUT_DIR").unwrap());
     let dest = std::path::PathBuf::from(&out).join(dest);
@@ -118,8 +152,9 @@ pub fn build(
         .with_output_dir(out)
         .compile()
         .expect("Compilation failed");
+    lib.check_output(&dest).expect("Invalid output file");
 
-    let mut config = Config::new(&dest).expect("Unable to configure codegen");
+    let mut config = configure(Config::new(&dest).expect("Unable to configure codegen"));
     let mut gen = config.detect().expect("Invalid output language");
     gen.generate(&lib, &mut config)
         .expect("Code generation failed");
@@ -1,7 +1,7 @@
 use crate::*;
 
 /// Scalar types
-#[derive(Clone, Debug, serde::Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize)]
 pub enum ElemType {
     /// Signed 8 bit integer
     #[serde(rename = "i8")]
@@ -69,12 +69,33 @@ impl ElemType {
             ElemType::Bool => "bool",
         }
     }
+
+    /// Return the scalar type with the given Futhark name if valid
+    pub fn from_name(name: &str) -> Option<ElemType> {
+        match name {
+            "i8" => Some(ElemType::I8),
+            "i16" => Some(ElemType::I16),
+            "i32" => Some(ElemType::I32),
+            "i64" => Some(ElemType::I64),
+            "u8" => Some(ElemType::U8),
+            "u16" => Some(ElemType::U16),
+            "u32" => Some(ElemType::U32),
+            "u64" => Some(ElemType::U64),
+            "f16" => Some(ElemType::F16),
+            "f32" => Some(ElemType::F32),
+            "f64" => Some(ElemType::F64),
+            "bool" => Some(ElemType::Bool),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, serde::Deserialize)]
 pub struct Output {
     pub r#type: String,
     pub unique: bool,
+    #[serde(default)]
+    pub name: Option<String>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize)]
@@ -97,6 +118,12 @@ pub struct ArrayOps {
     pub shape: String,
     pub values: String,
     pub new: String,
+    #[serde(default)]
+    pub new_raw: Option<String>,
+    #[serde(default)]
+    pub values_raw: Option<String>,
+    #[serde(default)]
+    pub index: Option<String>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize)]
@@ -127,11 +154,27 @@ pub struct Record {
     pub fields: Vec<Field>,
 }
 
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct Variant {
+    pub name: String,
+    pub construct: String,
+    pub destruct: String,
+    pub payload: Vec<String>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct Sum {
+    pub variant: String,
+    pub variants: Vec<Variant>,
+}
+
 #[derive(Clone, Debug, serde::Deserialize)]
 pub struct OpaqueType {
     pub ctype: String,
     pub ops: OpaqueOps,
     pub record: Option<Record>,
+    #[serde(default)]
+    pub sum: Option<Sum>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize)]
@@ -16,7 +16,29 @@ pub struct Package {
     pub src: std::path::PathBuf,
 }
 
+/// Resolve the directory containing `path`, so paths to the same file compare equal
+fn normalize(path: &std::path::Path) -> std::path::PathBuf {
+    let dir = match path.parent() {
+        Some(p) if !p.as_os_str().is_empty() => p,
+        _ => std::path::Path::new("."),
+    };
+    let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+    match path.file_name() {
+        Some(name) => dir.join(name),
+        None => dir,
+    }
+}
+
 impl Package {
+    /// Returns an error if generating code into `output` would overwrite the header generated by
+    /// Futhark
+    pub fn check_output(&self, output: impl AsRef<std::path::Path>) -> Result<(), Error> {
+        if normalize(output.as_ref()) == normalize(&self.h_file) {
+            return Err(Error::HeaderConflict(self.h_file.clone()));
+        }
+        Ok(())
+    }
+
     #[cfg(feature = "build")]
     fn build(&self, libname: &str) {
         if self.manifest.backend == Backend::ISPC {
@@ -0,0 +1,82 @@
+//! End-to-end test for `futhark_bindgen::build`, using `fake-futhark` in place of the real
+//! Futhark compiler
+//!
+//! This modifies the process environment to look like a build script, so it is kept in a
+//! separate test binary with a single test.
+
+#![cfg(all(feature = "build", target_os = "linux"))]
+
+mod support;
+
+use std::path::PathBuf;
+
+#[test]
+fn build() {
+    let tmp = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("build");
+    let _ = std::fs::remove_dir_all(&tmp);
+    let bin = tmp.join("bin");
+    let out = tmp.join("out");
+    std::fs::create_dir_all(&bin).unwrap();
+    std::fs::create_dir_all(&out).unwrap();
+
+    // `build` always runs `futhark`, so put `fake-futhark` first in `$PATH` under that name
+    std::os::unix::fs::symlink(support::fake_futhark(), bin.join("futhark")).unwrap();
+    let path = std::env::var_os("PATH").unwrap_or_default();
+    let mut paths = vec![bin];
+    paths.extend(std::env::split_paths(&path));
+    std::env::set_var("PATH", std::env::join_paths(paths).unwrap());
+
+    // Variables normally provided to build scripts by cargo
+    let target = if cfg!(target_arch = "aarch64") {
+        "aarch64-unknown-linux-gnu"
+    } else {
+        "x86_64-unknown-linux-gnu"
+    };
+    std::env::set_var("OUT_DIR", &out);
+    std::env::set_var("CARGO_PKG_NAME", "fake");
+    std::env::set_var("TARGET", target);
+    std::env::set_var("HOST", target);
+    std::env::set_var("OPT_LEVEL", "0");
+    std::env::set_var("DEBUG", "false");
+
+    let src = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/records.json");
+    futhark_bindgen::build(futhark_bindgen::Backend::C, src, "records.rs");
+
+    assert!(out.join("records.rs").exists());
+    assert!(out.join("records.c").exists());
+    assert!(out.join("libfuthark_generate_fake.a").exists());
+
+    // Link the generated bindings against the library, `link-dead-code` keeps every wrapper so
+    // all of the symbols they refer to have to be resolved
+    let main = out.join("link_check.rs");
+    std::fs::write(
+        &main,
+        "#[allow(non_camel_case_types, dead_code)]\n\
+         mod half { #[derive(Clone, Copy, Debug, Default, PartialEq)] pub struct f16(u16); }\n\
+         include!(\"records.rs\");\n\
+         fn main() {}\n",
+    )
+    .unwrap();
+    let rustc = std::env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let res = std::process::Command::new(rustc)
+        .args([
+            "--edition",
+            "2021",
+            "-C",
+            "link-dead-code",
+            "-A",
+            "warnings",
+        ])
+        .arg("-L")
+        .arg(format!("native={}", out.display()))
+        .args(["-l", "static=futhark_generate_fake", "-o"])
+        .arg(out.join("link_check"))
+        .arg(&main)
+        .output()
+        .unwrap();
+    assert!(
+        res.status.success(),
+        "linking failed:\n{}",
+        String::from_utf8_lossy(&res.stderr)
+    );
+}
@@ -0,0 +1,94 @@
+//! Tests for `Compiler`, using `fake-futhark` in place of the real Futhark compiler
+
+mod support;
+
+use futhark_bindgen::*;
+use std::path::PathBuf;
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+/// Create an empty output directory for a single test
+fn output_dir(name: &str) -> PathBuf {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR"))
+        .join("compiler")
+        .join(name);
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn compile() {
+    let out = output_dir("compile");
+    let pkg = Compiler::new(Backend::Multicore, fixture("records.json"))
+        .with_executable_name(support::fake_futhark())
+        .with_output_dir(&out)
+        .compile()
+        .unwrap();
+
+    assert_eq!(pkg.manifest.backend, Backend::Multicore);
+    assert_eq!(pkg.c_file, out.join("records.c"));
+    assert_eq!(pkg.h_file, out.join("records.h"));
+    assert!(pkg.c_file.exists());
+    assert!(pkg.h_file.exists());
+    assert!(pkg.manifest.entry_points.contains_key("distance"));
+    assert!(pkg.manifest.types.contains_key("point"));
+}
+
+#[test]
+fn compile_extra_args() {
+    let out = output_dir("compile_extra_args");
+    let pkg = Compiler::new(Backend::C, fixture("scalars.json"))
+        .with_executable_name(support::fake_futhark())
+        .with_extra_args(vec!["--safe".to_string()])
+        .with_output_dir(&out)
+        .compile()
+        .unwrap();
+
+    let header = std::fs::read_to_string(pkg.h_file).unwrap();
+    assert!(header.contains("--safe"));
+}
+
+#[test]
+fn compile_error() {
+    let out = output_dir("compile_error");
+    let res = Compiler::new(Backend::C, fixture("invalid.fut"))
+        .with_executable_name(support::fake_futhark())
+        .with_output_dir(&out)
+        .compile();
+    assert!(matches!(res, Err(Error::CompilationFailed)));
+}
+
+#[test]
+fn missing_executable() {
+    let out = output_dir("missing_executable");
+    let res = Compiler::new(Backend::C, fixture("scalars.json"))
+        .with_executable_name("futhark-bindgen-does-not-exist")
+        .with_output_dir(&out)
+        .compile();
+    assert!(matches!(res, Err(Error::Io(_))));
+}
+
+#[test]
+fn compile_and_generate() {
+    let out = output_dir("compile_and_generate");
+    let pkg = Compiler::new(Backend::C, fixture("arrays.json"))
+        .with_executable_name(support::fake_futhark())
+        .with_output_dir(&out)
+        .compile()
+        .unwrap();
+
+    for file in ["arrays.rs", "arrays.ml"] {
+        let mut config = Config::new(out.join(file)).unwrap();
+        let mut gen = config.detect().unwrap();
+        gen.generate(&pkg, &mut config).unwrap();
+    }
+
+    let rs = std::fs::read_to_string(out.join("arrays.rs")).unwrap();
+    assert!(rs.contains("fn futhark_entry_flatten"));
+    assert!(out.join("arrays.mli").exists());
+}
@@ -0,0 +1,185 @@
+//! Tests for `Manifest::diff` and the `diff` subcommand
+
+use futhark_bindgen::manifest::{Entry, Field, Type};
+use futhark_bindgen::*;
+use std::path::PathBuf;
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+fn records() -> Manifest {
+    Manifest::parse_file(fixture("records.json")).unwrap()
+}
+
+fn entry<'a>(manifest: &'a mut Manifest, name: &str) -> &'a mut Entry {
+    manifest.entry_points.get_mut(name).unwrap()
+}
+
+fn fields<'a>(manifest: &'a mut Manifest, name: &str) -> &'a mut Vec<Field> {
+    match manifest.types.get_mut(name) {
+        Some(Type::Opaque(t)) => &mut t.record.as_mut().unwrap().fields,
+        _ => panic!("`{name}` is not a record"),
+    }
+}
+
+/// Compare against the expected `(breaking, description)` pairs
+fn check(old: &Manifest, new: &Manifest, expected: &[(bool, &str)]) {
+    let changes: Vec<_> = old
+        .diff(new)
+        .into_iter()
+        .map(|c| (c.breaking, c.description))
+        .collect();
+    let expected: Vec<_> = expected
+        .iter()
+        .map(|(breaking, description)| (*breaking, description.to_string()))
+        .collect();
+    assert_eq!(changes, expected);
+}
+
+#[test]
+fn unchanged() {
+    assert!(records().diff(&records()).is_empty());
+}
+
+#[test]
+fn entry_added() {
+    let mut old = records();
+    old.entry_points.remove("distance");
+    check(&old, &records(), &[(false, "entry point `distance` added")]);
+}
+
+#[test]
+fn entry_removed() {
+    let mut new = records();
+    new.entry_points.remove("distance");
+    check(
+        &records(),
+        &new,
+        &[(true, "entry point `distance` removed")],
+    );
+}
+
+#[test]
+fn input_type_changed() {
+    let mut new = records();
+    entry(&mut new, "distance").inputs[1].r#type = "number".to_string();
+    check(
+        &records(),
+        &new,
+        &[(
+            true,
+            "entry point `distance`: input 1 changed type from `point` to `number`",
+        )],
+    );
+}
+
+#[test]
+fn output_type_changed() {
+    let mut new = records();
+    entry(&mut new, "distance").outputs[0].r#type = "f64".to_string();
+    check(
+        &records(),
+        &new,
+        &[(
+            true,
+            "entry point `distance`: output 0 changed type from `f32` to `f64`",
+        )],
+    );
+}
+
+#[test]
+fn input_uniqueness_changed() {
+    let mut unique = records();
+    entry(&mut unique, "points").inputs[0].unique = true;
+    check(
+        &records(),
+        &unique,
+        &[(
+            true,
+            "entry point `points`: input 0 is now unique (consumed)",
+        )],
+    );
+    check(
+        &unique,
+        &records(),
+        &[(false, "entry point `points`: input 0 is no longer unique")],
+    );
+}
+
+#[test]
+fn output_uniqueness_changed() {
+    let mut new = records();
+    entry(&mut new, "points").outputs[0].unique = true;
+    check(
+        &records(),
+        &new,
+        &[(
+            false,
+            "entry point `points`: output 0 uniqueness changed from false to true",
+        )],
+    );
+}
+
+#[test]
+fn record_field_added() {
+    let mut new = records();
+    let f = fields(&mut new, "point");
+    f.push(Field {
+        name: "z".to_string(),
+        project: "futhark_project_opaque_point_z".to_string(),
+        r#type: "f32".to_string(),
+    });
+    check(
+        &records(),
+        &new,
+        &[(true, "record `point`: field `z` added")],
+    );
+}
+
+#[test]
+fn record_field_removed() {
+    let mut new = records();
+    fields(&mut new, "point").pop();
+    check(
+        &records(),
+        &new,
+        &[(true, "record `point`: field `y` removed")],
+    );
+}
+
+#[test]
+fn record_fields_reordered() {
+    let mut new = records();
+    fields(&mut new, "point").reverse();
+    check(
+        &records(),
+        &new,
+        &[(true, "record `point`: fields reordered")],
+    );
+}
+
+#[cfg(feature = "bin")]
+#[test]
+fn subcommand_exit_code() {
+    let diff = |old: &str, new: &str| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_futhark-bindgen"))
+            .arg("diff")
+            .arg(fixture(old))
+            .arg(fixture(new))
+            .output()
+            .unwrap()
+    };
+
+    let same = diff("records.json", "records.json");
+    assert!(same.status.success());
+    assert!(same.stdout.is_empty());
+
+    // Every entry point in `records.json` is missing from `arrays.json`
+    let breaking = diff("records.json", "arrays.json");
+    assert_eq!(breaking.status.code(), Some(1));
+    let stdout = String::from_utf8(breaking.stdout).unwrap();
+    assert!(stdout.contains("breaking: entry point `distance` removed"));
+}
@@ -0,0 +1,1105 @@
+//! Snapshot tests for the code generators
+//!
+//! Each manifest in `tests/fixtures` is run through every generator and the output is compared
+//! against the files in `tests/snapshots`. Run with `UPDATE_SNAPSHOTS=1` to regenerate them.
+//! Rust output is also compiled with `rustc`, JavaScript and OCaml output is syntax checked when
+//! `node` and `ocamlc` are installed.
+
+use futhark_bindgen::*;
+use std::path::{Path, PathBuf};
+
+/// Wraps a generator to skip the formatting step, so the output doesn't depend on the
+/// installed version of `rustfmt`
+struct Unformatted(Box<dyn Generate>);
+
+impl Generate for Unformatted {
+    fn bindings(&mut self, pkg: &Package, config: &mut Config) -> Result<(), Error> {
+        self.0.bindings(pkg, config)
+    }
+
+    fn array_type(
+        &mut self,
+        pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        ty: &manifest::ArrayType,
+    ) -> Result<(), Error> {
+        self.0.array_type(pkg, config, name, ty)
+    }
+
+    fn opaque_type(
+        &mut self,
+        pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        ty: &manifest::OpaqueType,
+    ) -> Result<(), Error> {
+        self.0.opaque_type(pkg, config, name, ty)
+    }
+
+    fn entry(
+        &mut self,
+        pkg: &Package,
+        config: &mut Config,
+        name: &str,
+        entry: &manifest::Entry,
+    ) -> Result<(), Error> {
+        self.0.entry(pkg, config, name, entry)
+    }
+
+    fn finish(&mut self, pkg: &Package, config: &mut Config) -> Result<(), Error> {
+        self.0.finish(pkg, config)
+    }
+}
+
+fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests")
+}
+
+fn package(fixture: &str) -> Package {
+    let path = root().join("fixtures").join(fixture).with_extension("json");
+    Package {
+        manifest: Manifest::parse_file(&path).unwrap(),
+        c_file: path.with_extension("c"),
+        h_file: path.with_extension("h"),
+        src: path.with_extension("fut"),
+    }
+}
+
+fn check_snapshot(output: &Path, snapshot: &Path) {
+    check_code(output);
+
+    let actual = std::fs::read_to_string(output).unwrap();
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::write(snapshot, &actual).unwrap();
+        return;
+    }
+
+    let expected = std::fs::read_to_string(snapshot).unwrap_or_else(|_| {
+        panic!(
+            "Missing snapshot {}, run with UPDATE_SNAPSHOTS=1 to create it",
+            snapshot.display()
+        )
+    });
+    assert!(
+        actual == expected,
+        "{} does not match {}, run with UPDATE_SNAPSHOTS=1 to update it",
+        output.display(),
+        snapshot.display()
+    );
+}
+
+/// Generate code for `fixture` into `name.ext` using the given naming policy
+fn generate(fixture: &str, name: &str, ext: &str, naming: Naming) -> Result<PathBuf, Error> {
+    generate_with_config(fixture, name, ext, |config| config.with_naming(naming))
+}
+
+/// Generate code for `fixture` into `name.ext`, `configure` is applied to the default config
+fn generate_with_config(
+    fixture: &str,
+    name: &str,
+    ext: &str,
+    configure: impl FnOnce(Config) -> Config,
+) -> Result<PathBuf, Error> {
+    generate_package(&package(fixture), name, ext, configure)
+}
+
+/// Like `generate_with_config`, for a package that has been modified by the test
+fn generate_package(
+    pkg: &Package,
+    name: &str,
+    ext: &str,
+    configure: impl FnOnce(Config) -> Config,
+) -> Result<PathBuf, Error> {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("snapshots");
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let output = dir.join(name).with_extension(ext);
+    let mut config = configure(Config::new(&output)?);
+    let mut gen = Unformatted(config.detect().expect("Unknown output language"));
+    gen.generate(pkg, &mut config)?;
+    Ok(output)
+}
+
+/// Generate code for `fixture` and compare every file with extension in `exts` to its snapshot
+fn snapshot_with(fixture: &str, name: &str, exts: &[&str], naming: Naming) {
+    let output = generate(fixture, name, exts[0], naming).unwrap();
+    for ext in exts {
+        check_snapshot(
+            &output.with_extension(ext),
+            &root().join("snapshots").join(name).with_extension(ext),
+        );
+    }
+}
+
+fn snapshot(fixture: &str, exts: &[&str]) {
+    snapshot_with(fixture, fixture, exts, Naming::default())
+}
+
+/// Compile a stub `serde` crate, with derive macros that accept `#[serde(...)]` attributes and
+/// generate nothing
+fn serde_stub() -> &'static Path {
+    static LIB: std::sync::OnceLock<PathBuf> = std::sync::OnceLock::new();
+    LIB.get_or_init(|| {
+        let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("serde_stub");
+        std::fs::create_dir_all(&dir).unwrap();
+        let src = dir.join("serde.rs");
+        std::fs::write(
+            &src,
+            "extern crate proc_macro;\n\
+             use proc_macro::TokenStream;\n\
+             #[proc_macro_derive(Serialize, attributes(serde))]\n\
+             pub fn serialize(_: TokenStream) -> TokenStream { TokenStream::new() }\n\
+             #[proc_macro_derive(Deserialize, attributes(serde))]\n\
+             pub fn deserialize(_: TokenStream) -> TokenStream { TokenStream::new() }\n",
+        )
+        .unwrap();
+        let rustc = std::env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+        let status = std::process::Command::new(rustc)
+            .args(["--crate-type", "proc-macro", "--crate-name", "serde"])
+            .args(["--edition", "2021", "--out-dir"])
+            .arg(&dir)
+            .arg(&src)
+            .status()
+            .unwrap();
+        assert!(status.success(), "unable to compile the serde stub");
+        std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .find(|path| path.file_stem().is_some_and(|x| x == "libserde"))
+            .unwrap()
+    })
+}
+
+/// Compile generated Rust code as a library, with `extra` appended to it. The `half` and `serde`
+/// crates are replaced by stubs, since the generated code is compiled without dependencies.
+/// Warnings are errors, except for the default names of arrays of records (`Arr_point_1d`).
+fn compile_rust(output: &Path, extra: &str) {
+    let code = std::fs::read_to_string(output).unwrap();
+    let name = format!("{}_check", output.file_stem().unwrap().to_str().unwrap());
+    let src = output.with_file_name(&name).with_extension("rs");
+    std::fs::write(
+        &src,
+        format!(
+            "#![allow(non_camel_case_types)]\n\
+             #[allow(dead_code)]\n\
+             mod half {{ #[derive(Clone, Copy, Debug, Default, PartialEq)] #[repr(transparent)] pub struct f16(u16); }}\n\
+             {code}\n{extra}"
+        ),
+    )
+    .unwrap();
+    let rustc = std::env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let out = std::process::Command::new(rustc)
+        .args([
+            "--crate-type",
+            "lib",
+            "--edition",
+            "2021",
+            "--emit",
+            "metadata",
+        ])
+        .args(["-D", "warnings", "--extern"])
+        .arg(format!("serde={}", serde_stub().display()))
+        .arg("--out-dir")
+        .arg(src.parent().unwrap())
+        .arg(&src)
+        .output()
+        .unwrap();
+    assert!(
+        out.status.success(),
+        "{} failed to compile:\n{}",
+        output.display(),
+        String::from_utf8_lossy(&out.stderr)
+    );
+}
+
+/// Check the syntax of generated JavaScript using `node`, skipped if it isn't installed
+fn check_javascript(output: &Path) {
+    if std::process::Command::new("node")
+        .arg("--version")
+        .output()
+        .is_err()
+    {
+        eprintln!("node not found, skipping syntax check");
+        return;
+    }
+
+    // Files are only parsed as modules when read from stdin with `--input-type=module`
+    let out = std::process::Command::new("node")
+        .args(["--check", "--input-type=module"])
+        .stdin(std::fs::File::open(output).unwrap())
+        .output()
+        .unwrap();
+    assert!(
+        out.status.success(),
+        "{} is not valid JavaScript:\n{}",
+        output.display(),
+        String::from_utf8_lossy(&out.stderr)
+    );
+}
+
+/// Check the syntax of generated OCaml using `ocamlc`, skipped if it isn't installed. The code
+/// isn't type checked since that requires `ctypes`
+fn check_ocaml(output: &Path) {
+    let out = match std::process::Command::new("ocamlc")
+        .args(["-stop-after", "parsing", "-c"])
+        .arg(output)
+        .output()
+    {
+        Ok(out) => out,
+        Err(_) => {
+            eprintln!("ocamlc not found, skipping syntax check");
+            return;
+        }
+    };
+    assert!(
+        out.status.success(),
+        "{} is not valid OCaml:\n{}",
+        output.display(),
+        String::from_utf8_lossy(&out.stderr)
+    );
+}
+
+/// Compile or syntax check generated code, for the languages that have a checker available
+fn check_code(output: &Path) {
+    match output.extension().and_then(|x| x.to_str()) {
+        Some("rs") => compile_rust(output, ""),
+        Some("js") => check_javascript(output),
+        Some("ml" | "mli") => check_ocaml(output),
+        _ => (),
+    }
+}
+
+fn naming() -> Naming {
+    Naming {
+        prefix: "Fut".to_string(),
+        case: Case::Pascal,
+        ..Naming::default()
+    }
+    .rename("tup", "PairWithScale")
+    .rename("distance", "dist")
+}
+
+#[test]
+fn rust_scalars() {
+    snapshot("scalars", &["rs"]);
+}
+
+#[test]
+fn rust_arrays() {
+    snapshot("arrays", &["rs"]);
+}
+
+#[test]
+fn rust_records() {
+    snapshot("records", &["rs"]);
+}
+
+#[test]
+fn ocaml_scalars() {
+    snapshot("scalars", &["ml", "mli"]);
+}
+
+#[test]
+fn ocaml_arrays() {
+    snapshot("arrays", &["ml", "mli"]);
+}
+
+#[test]
+fn ocaml_records() {
+    snapshot("records", &["ml", "mli"]);
+}
+
+#[test]
+fn zig_scalars() {
+    snapshot("scalars", &["zig"]);
+}
+
+#[test]
+fn zig_arrays() {
+    snapshot("arrays", &["zig"]);
+}
+
+#[test]
+fn zig_records() {
+    snapshot("records", &["zig"]);
+}
+
+#[test]
+fn go_scalars() {
+    snapshot("scalars", &["go"]);
+}
+
+#[test]
+fn go_arrays() {
+    snapshot("arrays", &["go"]);
+}
+
+#[test]
+fn go_records() {
+    snapshot("records", &["go"]);
+}
+
+#[test]
+fn julia_scalars() {
+    snapshot("scalars", &["jl"]);
+}
+
+#[test]
+fn julia_arrays() {
+    snapshot("arrays", &["jl"]);
+}
+
+#[test]
+fn julia_records() {
+    snapshot("records", &["jl"]);
+}
+
+#[test]
+fn haskell_scalars() {
+    snapshot("scalars", &["hs"]);
+}
+
+#[test]
+fn haskell_arrays() {
+    snapshot("arrays", &["hs"]);
+}
+
+#[test]
+fn haskell_records() {
+    snapshot("records", &["hs"]);
+}
+
+/// JavaScript bindings require a wasm backend, other fixtures are switched to `wasm`
+fn javascript_snapshot(fixture: &str) {
+    let mut pkg = package(fixture);
+    if !matches!(pkg.manifest.backend, Backend::Wasm | Backend::WasmMulticore) {
+        pkg.manifest.backend = Backend::Wasm;
+    }
+    let output = generate_package(&pkg, fixture, "js", |c| c).unwrap();
+    for ext in ["js", "d.ts"] {
+        check_snapshot(
+            &output.with_extension(ext),
+            &root().join("snapshots").join(fixture).with_extension(ext),
+        );
+    }
+}
+
+#[test]
+fn javascript_scalars() {
+    javascript_snapshot("scalars");
+}
+
+#[test]
+fn javascript_arrays() {
+    javascript_snapshot("arrays");
+}
+
+#[test]
+fn javascript_records() {
+    javascript_snapshot("records");
+}
+
+#[test]
+fn javascript_wasm() {
+    javascript_snapshot("wasm");
+}
+
+#[test]
+fn javascript_backend() {
+    let res = generate("records", "javascript_backend", "js", Naming::default());
+    assert!(matches!(res, Err(Error::Unsupported(msg)) if msg.contains("opencl")));
+}
+
+#[test]
+fn csharp_scalars() {
+    snapshot("scalars", &["cs"]);
+}
+
+#[test]
+fn csharp_arrays() {
+    snapshot("arrays", &["cs"]);
+}
+
+#[test]
+fn csharp_records() {
+    snapshot("records", &["cs"]);
+}
+
+#[test]
+fn lua_scalars() {
+    snapshot("scalars", &["lua"]);
+}
+
+#[test]
+fn lua_arrays() {
+    snapshot("arrays", &["lua"]);
+}
+
+#[test]
+fn lua_records() {
+    snapshot("records", &["lua"]);
+}
+
+#[test]
+fn c_scalars() {
+    snapshot_with("scalars", "scalars_helpers", &["h"], Naming::default());
+}
+
+#[test]
+fn c_arrays() {
+    snapshot_with("arrays", "arrays_helpers", &["h"], Naming::default());
+}
+
+#[test]
+fn c_records() {
+    snapshot_with("records", "records_helpers", &["h"], Naming::default());
+}
+
+#[test]
+fn c_header_conflict() {
+    // The output would be included instead of the header generated by Futhark
+    let res = generate("records", "records", "h", Naming::default());
+    assert!(matches!(res, Err(Error::HeaderConflict(h)) if h.ends_with("records.h")));
+
+    let pkg = package("records");
+    assert!(pkg.check_output(&pkg.h_file).is_err());
+    assert!(pkg.check_output(pkg.h_file.with_extension("rs")).is_ok());
+}
+
+#[test]
+fn java_scalars() {
+    snapshot("scalars", &["java"]);
+}
+
+#[test]
+fn java_arrays() {
+    snapshot("arrays", &["java"]);
+}
+
+#[test]
+fn java_records() {
+    snapshot("records", &["java"]);
+}
+
+#[test]
+fn nim_scalars() {
+    snapshot("scalars", &["nim"]);
+}
+
+#[test]
+fn nim_arrays() {
+    snapshot("arrays", &["nim"]);
+}
+
+#[test]
+fn nim_records() {
+    snapshot("records", &["nim"]);
+}
+
+#[test]
+fn rust_raw() {
+    snapshot("raw", &["rs"]);
+}
+
+#[test]
+fn ocaml_raw() {
+    snapshot("raw", &["ml", "mli"]);
+}
+
+/// Compile generated Rust code as an executable with `extra` appended, along with the context
+/// functions from `tests/support/mock-context.rs`, and run it
+fn run_rust(output: &Path, extra: &str) {
+    let code = std::fs::read_to_string(output).unwrap();
+    let mock = std::fs::read_to_string(root().join("support/mock-context.rs")).unwrap();
+    let name = format!("{}_main", output.file_stem().unwrap().to_str().unwrap());
+    let src = output.with_file_name(&name).with_extension("rs");
+    std::fs::write(
+        &src,
+        format!(
+            "#[allow(dead_code)]\n\
+             mod half {{ #[derive(Clone, Copy, Debug, Default, PartialEq)] #[repr(transparent)] pub struct f16(u16); }}\n\
+             {code}\n{mock}\n{extra}"
+        ),
+    )
+    .unwrap();
+    let exe = output.with_file_name(&name);
+    let rustc = std::env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let out = std::process::Command::new(rustc)
+        .args(["--edition", "2021", "-A", "warnings", "-o"])
+        .arg(&exe)
+        .arg(&src)
+        .output()
+        .unwrap();
+    assert!(
+        out.status.success(),
+        "{} failed to compile:\n{}",
+        src.display(),
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let out = std::process::Command::new(&exe).output().unwrap();
+    assert!(
+        out.status.success(),
+        "{} failed:\n{}",
+        exe.display(),
+        String::from_utf8_lossy(&out.stderr)
+    );
+}
+
+/// Generate Rust code for `raw.json` into `name.rs` and run it with `main` appended, using the
+/// Futhark functions from `tests/support/deferred-sync.rs`
+fn run_deferred_sync(name: &str, configure: impl FnOnce(Config) -> Config, main: &str) {
+    let output = generate_with_config("raw", name, "rs", configure).unwrap();
+    let mock = std::fs::read_to_string(root().join("support/deferred-sync.rs")).unwrap();
+    run_rust(&output, &format!("{mock}\n{main}"));
+}
+
+// Indexing has to sync even when `auto_sync` is disabled, since the value is written to the stack
+#[test]
+fn rust_index_without_auto_sync() {
+    run_deferred_sync(
+        "raw_index",
+        |c| c,
+        "fn main() {
+            let ctx = Context::new_with_options(Options::new().auto_sync(false)).unwrap();
+            let arr = unsafe { ArrayF32D1::from_raw(&ctx, [4], 0) }.unwrap();
+            assert_eq!(arr.index([1]).unwrap(), 42.0);
+        }",
+    );
+}
+
+#[test]
+fn rust_naming() {
+    snapshot_with("records", "records_naming", &["rs"], naming());
+}
+
+#[test]
+fn rust_async() {
+    let output =
+        generate_with_config("records", "records_async", "rs", |c| c.with_async_api(true)).unwrap();
+    check_snapshot(&output, &root().join("snapshots/records_async.rs"));
+
+    // Futures have to be `Send` to be used with multi-threaded executors
+    compile_rust(
+        &output,
+        "fn assert_send<T: Send>(_: T) {}
+        #[allow(unused)]
+        fn check_send(ctx: &Context, a: &ArrayF32D1, p: &Point, points: &Arr_point_1d) {
+            assert_send(ctx.sync_async());
+            assert_send(a.get_async());
+            assert_send(a.values_async(&mut [0.0; 3]));
+            assert_send(ctx.distance_async(p, p));
+            assert_send(ctx.points_async(a, a));
+            assert_send(ctx.unzip_points_async(points));
+        }",
+    );
+}
+
+// The buffers written by `values` are freed when the future is dropped, so dropping it early has
+// to wait for the sync
+#[test]
+fn rust_async_drop() {
+    run_deferred_sync(
+        "raw_async",
+        |c| c.with_async_api(true),
+        "fn block_on<F: std::future::Future>(f: F) -> F::Output {
+            let mut f = std::pin::pin!(f);
+            let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+            loop {
+                if let std::task::Poll::Ready(x) = f.as_mut().poll(&mut cx) {
+                    return x;
+                }
+                std::thread::yield_now();
+            }
+        }
+
+        fn main() {
+            use std::sync::atomic::Ordering;
+            let ctx = Context::new().unwrap();
+            let arr = unsafe { ArrayF32D1::from_raw(&ctx, [4], 0) }.unwrap();
+            assert_eq!(block_on(arr.get_async()).unwrap(), [42.0; 4]);
+
+            let mut data = [0.0; 4];
+            assert!(matches!(block_on(arr.values_async(&mut data[1..])), Err(Error::InvalidShape)));
+            block_on(arr.values_async(&mut data)).unwrap();
+            assert_eq!(data, [42.0; 4]);
+
+            deferred_sync::SYNCED.store(false, Ordering::SeqCst);
+            drop(arr.get_async());
+            assert!(deferred_sync::SYNCED.load(Ordering::SeqCst), \"get_async future dropped before sync\");
+
+            deferred_sync::SYNCED.store(false, Ordering::SeqCst);
+            drop(arr.values_async(&mut data));
+            assert!(deferred_sync::SYNCED.load(Ordering::SeqCst), \"values_async future dropped before sync\");
+        }",
+    );
+}
+
+#[test]
+fn rust_context_handle() {
+    let output = generate_with_config("arrays", "arrays_handle", "rs", |c| {
+        c.with_context_handle(true)
+    })
+    .unwrap();
+    check_snapshot(&output, &root().join("snapshots/arrays_handle.rs"));
+}
+
+#[test]
+fn rust_context_handle_records() {
+    // Sum types and arrays of records have no host type
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("snapshots");
+    std::fs::create_dir_all(&dir).unwrap();
+    let mut pkg = package("records");
+    let output = dir.join("records_handle.rs");
+    let mut config = Config::new(&output).unwrap().with_context_handle(true);
+    let res = Unformatted(config.detect().unwrap()).generate(&pkg, &mut config);
+    assert!(matches!(res, Err(Error::Unsupported(msg)) if msg.contains("`points`")));
+
+    // Records are passed using their host types
+    for entry in ["points", "unzip_points", "return_option"] {
+        pkg.manifest.entry_points.remove(entry);
+    }
+    let mut config = Config::new(&output).unwrap().with_context_handle(true);
+    Unformatted(config.detect().unwrap())
+        .generate(&pkg, &mut config)
+        .unwrap();
+    check_snapshot(&output, &root().join("snapshots/records_handle.rs"));
+}
+
+/// Rename the fields of the `point` record
+fn rename_point_fields(pkg: &mut Package, x: &str, y: &str) {
+    match pkg.manifest.types.get_mut("point") {
+        Some(manifest::Type::Opaque(t)) => {
+            let fields = &mut t.record.as_mut().unwrap().fields;
+            fields[0].name = x.to_string();
+            fields[1].name = y.to_string();
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn rust_builder_names() {
+    // Keywords are escaped
+    let mut pkg = package("records");
+    rename_point_fields(&mut pkg, "fn", "self");
+    let output = generate_package(&pkg, "builder_names", "rs", |c| c).unwrap();
+    let code = std::fs::read_to_string(&output).unwrap();
+    assert!(code.contains("pub fn r#fn(mut self"));
+    assert!(code.contains("pub fn self_(mut self"));
+    compile_rust(&output, "");
+
+    // Setters can't replace the builder's own methods or each other
+    rename_point_fields(&mut pkg, "build", "y");
+    let res = generate_package(&pkg, "builder_names", "rs", |c| c);
+    assert!(matches!(res, Err(Error::NameCollision(msg)) if msg.contains("`build`")));
+    rename_point_fields(&mut pkg, "self", "self_");
+    let res = generate_package(&pkg, "builder_names", "rs", |c| c);
+    assert!(matches!(res, Err(Error::NameCollision(msg)) if msg.contains("`self_`")));
+}
+
+#[test]
+fn rust_builder_missing_field() {
+    let output = generate_with_config("records", "records_builder", "rs", |c| c).unwrap();
+    run_rust(
+        &output,
+        "mod point {
+            use super::*;
+            use std::sync::Mutex;
+
+            /// Fields passed to `futhark_new_opaque_point`
+            pub static NEW: Mutex<Vec<(f32, f32)>> = Mutex::new(Vec::new());
+
+            #[no_mangle]
+            extern \"C\" fn futhark_context_sync(_: *mut futhark_context) -> std::os::raw::c_int {
+                0
+            }
+
+            #[no_mangle]
+            extern \"C\" fn futhark_new_opaque_point(
+                _: *mut futhark_context,
+                out: *mut *mut futhark_opaque_point,
+                x: f32,
+                y: f32,
+            ) -> std::os::raw::c_int {
+                NEW.lock().unwrap().push((x, y));
+                unsafe { *out = super::mock_context::handle() };
+                0
+            }
+
+            #[no_mangle]
+            extern \"C\" fn futhark_free_opaque_point(
+                _: *mut futhark_context,
+                _: *mut futhark_opaque_point,
+            ) -> std::os::raw::c_int {
+                0
+            }
+        }
+
+        fn main() {
+            let ctx = Context::new().unwrap();
+            let res = PointBuilder::new().x(1.0).build(&ctx);
+            assert!(matches!(res, Err(Error::MissingField(\"y\"))));
+            let res = PointBuilder::new().y(2.0).build(&ctx);
+            assert!(matches!(res, Err(Error::MissingField(\"x\"))));
+            assert!(point::NEW.lock().unwrap().is_empty());
+
+            PointBuilder::new().y(2.0).x(1.0).build(&ctx).unwrap();
+            assert_eq!(*point::NEW.lock().unwrap(), [(1.0, 2.0)]);
+        }",
+    );
+}
+
+#[test]
+fn output_structs() {
+    for exts in [&["rs"][..], &["ml", "mli"]] {
+        let output = generate_with_config("arrays", "arrays_outputs", exts[0], |c| {
+            c.with_output_structs(true)
+        })
+        .unwrap();
+        for ext in exts {
+            check_snapshot(
+                &output.with_extension(ext),
+                &root().join("snapshots/arrays_outputs").with_extension(ext),
+            );
+        }
+    }
+}
+
+#[test]
+fn output_struct_labels() {
+    let mut pkg = package("arrays");
+    let entry = pkg.manifest.entry_points.get_mut("sum_rows").unwrap();
+    entry.outputs[0].name = Some("sums".to_string());
+    entry.outputs[1].name = Some("total".to_string());
+
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("snapshots");
+    std::fs::create_dir_all(&dir).unwrap();
+    for (ext, field) in [
+        ("rs", "pub sums: ArrayF32D1<'a>"),
+        ("ml", "sums: Array_f32_1d.t;"),
+    ] {
+        let output = dir.join("labels").with_extension(ext);
+        let mut config = Config::new(&output).unwrap().with_output_structs(true);
+        let mut gen = config.detect().unwrap();
+        gen.generate(&pkg, &mut config).unwrap();
+        let code = std::fs::read_to_string(&output).unwrap();
+        assert!(
+            code.contains(field),
+            "{field} not found in {}",
+            output.display()
+        );
+    }
+}
+
+/// Rename the outputs of the `sum_rows` entry point
+fn rename_sum_rows_outputs(pkg: &mut Package, a: &str, b: &str) {
+    let entry = pkg.manifest.entry_points.get_mut("sum_rows").unwrap();
+    entry.outputs[0].name = Some(a.to_string());
+    entry.outputs[1].name = Some(b.to_string());
+}
+
+#[test]
+fn output_struct_keywords() {
+    let mut pkg = package("arrays");
+    rename_sum_rows_outputs(&mut pkg, "type", "self");
+    for exts in [&["rs"][..], &["ml", "mli"]] {
+        // `ContextHandle` destructures the output struct, so it needs the same field names
+        let output = generate_package(&pkg, "arrays_output_keywords", exts[0], |c| {
+            c.with_output_structs(true)
+                .with_context_handle(exts[0] == "rs")
+        })
+        .unwrap();
+        for ext in exts {
+            check_snapshot(
+                &output.with_extension(ext),
+                &root()
+                    .join("snapshots/arrays_output_keywords")
+                    .with_extension(ext),
+            );
+        }
+    }
+
+    // Escaped names can't collide with other fields
+    for (ext, a, b) in [("rs", "self", "self_"), ("ml", "end", "end_")] {
+        rename_sum_rows_outputs(&mut pkg, a, b);
+        let res = generate_package(&pkg, "arrays_output_keywords", ext, |c| {
+            c.with_output_structs(true)
+        });
+        assert!(
+            matches!(&res, Err(Error::NameCollision(msg)) if msg.contains(&format!("`{b}`"))),
+            "{ext}: {res:?}"
+        );
+    }
+}
+
+#[test]
+fn backend_options() {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("snapshots");
+    std::fs::create_dir_all(&dir).unwrap();
+    for (backend, ext, expected, unexpected) in [
+        (
+            Backend::OpenCL,
+            "rs",
+            "futhark_context_config_set_platform(",
+            "futhark_context_config_set_unified_memory(",
+        ),
+        (
+            Backend::HIP,
+            "rs",
+            "futhark_context_config_set_unified_memory(",
+            "futhark_context_config_set_platform(",
+        ),
+        (
+            Backend::OpenCL,
+            "mli",
+            "?platform:string -> ?default_group_size:int",
+            "?unified_memory:bool",
+        ),
+        (
+            Backend::HIP,
+            "mli",
+            "?default_tile_size:int -> ?unified_memory:bool",
+            "?platform:string",
+        ),
+    ] {
+        let mut pkg = package("scalars");
+        pkg.manifest.backend = backend;
+        let output = dir.join("backend_options").with_extension(ext);
+        let mut config = Config::new(output.with_extension(&ext[..2])).unwrap();
+        let mut gen = config.detect().unwrap();
+        gen.generate(&pkg, &mut config).unwrap();
+        let code = std::fs::read_to_string(&output).unwrap();
+        assert!(code.contains(expected), "{expected} not found in {ext}");
+        assert!(!code.contains(unexpected), "{unexpected} found in {ext}");
+    }
+
+    // Only the options used by each backend are generated
+    for backend in [
+        Backend::C,
+        Backend::Multicore,
+        Backend::ISPC,
+        Backend::OpenCL,
+        Backend::CUDA,
+        Backend::HIP,
+        Backend::Wasm,
+        Backend::WasmMulticore,
+    ] {
+        let mut pkg = package("scalars");
+        pkg.manifest.backend = backend;
+        let output = generate_package(&pkg, "backend_options", "rs", |c| c).unwrap();
+        compile_rust(&output, "");
+    }
+}
+
+#[test]
+fn plain_records() {
+    for exts in [&["rs"][..], &["ml", "mli"]] {
+        let output = generate_with_config("records", "records_plain", exts[0], |c| {
+            c.with_plain_records(true)
+        })
+        .unwrap();
+        for ext in exts {
+            check_snapshot(
+                &output.with_extension(ext),
+                &root().join("snapshots/records_plain").with_extension(ext),
+            );
+        }
+    }
+}
+
+#[test]
+fn plain_record_field_names() {
+    // Fields of plain and host records are escaped
+    let mut pkg = package("records");
+    rename_point_fields(&mut pkg, "fn", "self");
+    for entry in ["points", "unzip_points", "return_option"] {
+        pkg.manifest.entry_points.remove(entry);
+    }
+    let output = generate_package(&pkg, "plain_field_names", "rs", |c| {
+        c.with_plain_records(true).with_context_handle(true)
+    })
+    .unwrap();
+    let code = std::fs::read_to_string(&output).unwrap();
+    assert!(code.contains("pub r#fn: f32,"));
+    assert!(code.contains("pub self_: f32,"));
+    compile_rust(&output, "");
+
+    let mut pkg = package("records");
+    rename_point_fields(&mut pkg, "type", "end");
+    let output = generate_package(&pkg, "records_plain_keywords", "ml", |c| {
+        c.with_plain_records(true)
+    })
+    .unwrap();
+    for ext in ["ml", "mli"] {
+        check_snapshot(
+            &output.with_extension(ext),
+            &root()
+                .join("snapshots/records_plain_keywords")
+                .with_extension(ext),
+        );
+    }
+}
+
+#[test]
+fn rust_serde() {
+    let output =
+        generate_with_config("records", "records_serde", "rs", |c| c.with_serde(true)).unwrap();
+    check_snapshot(&output, &root().join("snapshots/records_serde.rs"));
+}
+
+#[test]
+fn parsed_report() {
+    for exts in [&["rs"][..], &["ml", "mli"]] {
+        let output = generate_with_config("records", "records_report", exts[0], |c| {
+            c.with_parsed_report(true)
+        })
+        .unwrap();
+        for ext in exts {
+            check_snapshot(
+                &output.with_extension(ext),
+                &root().join("snapshots/records_report").with_extension(ext),
+            );
+        }
+    }
+}
+
+#[test]
+fn parsed_report_names() {
+    // `report` types and `profile` entry points only collide with the parsed report
+    let naming = || {
+        Naming::default()
+            .rename("point", "Report")
+            .rename("length", "profile")
+    };
+    for ext in ["rs", "ml"] {
+        generate("records", "report_names", ext, naming()).unwrap();
+        let res = generate_with_config("records", "report_names", ext, |c| {
+            c.with_naming(naming()).with_parsed_report(true)
+        });
+        assert!(matches!(res, Err(Error::NameCollision(_))));
+    }
+    let res = generate_with_config("records", "report_names", "rs", |c| {
+        c.with_naming(Naming::default().rename("length", "profile"))
+            .with_parsed_report(true)
+    });
+    assert!(matches!(res, Err(Error::NameCollision(_))));
+}
+
+#[test]
+fn ocaml_naming() {
+    snapshot_with("records", "records_naming", &["ml", "mli"], naming());
+}
+
+#[test]
+fn name_collision() {
+    // JavaScript bindings require a wasm backend, the other generators don't depend on it
+    let mut pkg = package("records");
+    pkg.manifest.backend = Backend::Wasm;
+    for ext in [
+        "rs", "ml", "zig", "go", "jl", "hs", "js", "cs", "lua", "java", "nim",
+    ] {
+        let naming = Naming::default().rename("point", "Number");
+        let res = generate_package(&pkg, "collision", ext, |c| c.with_naming(naming));
+        assert!(matches!(res, Err(Error::NameCollision(_))));
+
+        let naming = Naming::default().rename("segment", "Context");
+        let res = generate_package(&pkg, "collision", ext, |c| c.with_naming(naming));
+        assert!(matches!(res, Err(Error::NameCollision(_))));
+    }
+
+    let naming = Naming::default().rename("length", "sync");
+    let res = generate("records", "collision", "rs", naming);
+    assert!(matches!(res, Err(Error::NameCollision(_))));
+
+    // The C helpers only name entry points
+    let naming = Naming::default().rename("length", "error_free");
+    let res = generate("records", "collision", "h", naming);
+    assert!(matches!(res, Err(Error::NameCollision(_))));
+}
+
+#[test]
+fn empty_type_name() {
+    // Names generated from an empty C struct name get the same prefix as names starting with a
+    // digit, instead of panicking
+    let mut pkg = package("records");
+    pkg.manifest.backend = Backend::Wasm;
+    match pkg.manifest.types.get_mut("segment") {
+        Some(manifest::Type::Opaque(t)) => t.ctype = "struct futhark_opaque_ *".to_string(),
+        _ => unreachable!(),
+    }
+    for ext in [
+        "rs", "ml", "zig", "go", "jl", "hs", "js", "cs", "lua", "java", "nim",
+    ] {
+        let res = generate_package(&pkg, "empty_type_name", ext, |c| c);
+        assert!(res.is_ok(), "{ext}: {res:?}");
+    }
+    let code =
+        std::fs::read_to_string(generate_package(&pkg, "empty_type_name", "rs", |c| c).unwrap())
+            .unwrap();
+    assert!(code.contains("pub struct Type<'a>"));
+}
+
+#[test]
+fn zig_shadowed_names() {
+    // Zig doesn't allow parameters and locals to shadow top-level declarations
+    for name in [
+        "size", "data", "out", "ptr", "shape", "dims", "len", "out0", "input1", "fieldx",
+    ] {
+        for from in ["distance", "point"] {
+            let naming = Naming::default().rename(from, name);
+            let res = generate("records", "zig_shadowed", "zig", naming);
+            assert!(
+                matches!(&res, Err(Error::NameCollision(msg)) if msg.contains(&format!("`{name}`"))),
+                "{from} -> {name}: {res:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn optional_reserved_names() {
+    // Names used by optional code are only reserved when that code is generated
+    let rename = |name| Naming::default().rename("point", name);
+    for name in ["Job", "ContextHandle", "HostArray", "SyncWorker"] {
+        generate("records", "optional_names", "rs", rename(name)).unwrap();
+    }
+    let res = generate_with_config("records", "optional_names", "rs", |c| {
+        c.with_naming(rename("Job")).with_context_handle(true)
+    });
+    assert!(matches!(res, Err(Error::NameCollision(_))));
+    let res = generate_with_config("records", "optional_names", "rs", |c| {
+        c.with_naming(rename("HostArray")).with_serde(true)
+    });
+    assert!(matches!(res, Err(Error::NameCollision(_))));
+    let res = generate_with_config("records", "optional_names", "rs", |c| {
+        c.with_naming(rename("SyncWorker")).with_async_api(true)
+    });
+    assert!(matches!(res, Err(Error::NameCollision(_))));
+
+    let naming = || Naming::default().rename("length", "sync_async");
+    generate("records", "optional_names", "rs", naming()).unwrap();
+    let res = generate_with_config("records", "optional_names", "rs", |c| {
+        c.with_naming(naming()).with_async_api(true)
+    });
+    assert!(matches!(res, Err(Error::NameCollision(_))));
+}
+
+#[test]
+fn unsupported_type() {
+    let mut pkg = package("records");
+    pkg.manifest.backend = Backend::Wasm;
+    let entry = pkg.manifest.entry_points.get_mut("distance").unwrap();
+    entry.inputs[0].r#type = "[]quaternion".to_string();
+
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("snapshots");
+    std::fs::create_dir_all(&dir).unwrap();
+    for ext in [
+        "rs", "ml", "zig", "go", "jl", "hs", "js", "cs", "lua", "java", "nim", "h",
+    ] {
+        let mut config = Config::new(dir.join("unsupported").with_extension(ext)).unwrap();
+        let mut gen = config.detect().unwrap();
+        let res = gen.generate(&pkg, &mut config);
+        assert!(matches!(res, Err(Error::UnsupportedType(t)) if t == "[]quaternion"));
+    }
+}
@@ -0,0 +1,1705 @@
+// <auto-generated />
+// Generated by futhark-bindgen
+
+#nullable enable
+
+using System;
+using System.Runtime.InteropServices;
+
+namespace Arrays;
+
+internal static class Native
+{
+    // Resolved as `libarrays.so` on Linux
+    public const string Lib = "arrays";
+}
+
+/// <summary>Thrown when a Futhark function returns a non-zero error code</summary>
+public class FutharkException : Exception
+{
+    public int Code { get; }
+
+    public FutharkException(int code, string message) : base($"Futhark error code {code}: {message}")
+    {
+        Code = code;
+    }
+}
+
+/// <summary>Thrown when a Futhark function returns a NULL pointer</summary>
+public class NullPtrException : Exception
+{
+    public NullPtrException() : base("NULL pointer encountered")
+    {
+    }
+}
+
+/// <summary>Options used to configure a <see cref="Context"/></summary>
+public sealed class Options
+{
+    public bool Debug { get; set; }
+    public bool Profile { get; set; }
+    public bool Logging { get; set; }
+    public string? CacheFile { get; set; }
+    public bool AutoSync { get; set; } = true;
+    public int NumThreads { get; set; }
+}
+
+internal sealed class ContextHandle : SafeHandle
+{
+    [DllImport(Native.Lib)]
+    private static extern int futhark_context_sync(IntPtr ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_free(IntPtr ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_config_free(IntPtr config);
+
+    private readonly IntPtr config;
+    private readonly IntPtr cacheFile;
+
+    public ContextHandle(IntPtr config, IntPtr ctx, IntPtr cacheFile) : base(IntPtr.Zero, true)
+    {
+        this.config = config;
+        this.cacheFile = cacheFile;
+        SetHandle(ctx);
+    }
+
+    public override bool IsInvalid => handle == IntPtr.Zero;
+
+    protected override bool ReleaseHandle()
+    {
+        futhark_context_sync(handle);
+        futhark_context_free(handle);
+        futhark_context_config_free(config);
+        Marshal.FreeCoTaskMem(cacheFile);
+        return true;
+    }
+}
+
+// Handle to a value owned by a context, the context isn't freed until all of its values are
+internal abstract class FutharkHandle : SafeHandle
+{
+    private readonly ContextHandle ctx;
+
+    protected FutharkHandle(ContextHandle ctx, IntPtr ptr) : base(IntPtr.Zero, true)
+    {
+        if (ptr == IntPtr.Zero)
+        {
+            throw new NullPtrException();
+        }
+        bool added = false;
+        ctx.DangerousAddRef(ref added);
+        this.ctx = ctx;
+        SetHandle(ptr);
+    }
+
+    public override bool IsInvalid => handle == IntPtr.Zero;
+
+    protected abstract void Free(IntPtr ctx, IntPtr ptr);
+
+    protected override bool ReleaseHandle()
+    {
+        Free(ctx.DangerousGetHandle(), handle);
+        ctx.DangerousRelease();
+        return true;
+    }
+}
+
+/// <summary>Futhark context</summary>
+/// <remarks>Values created using the context keep it alive until they are disposed</remarks>
+public sealed partial class Context : IDisposable
+{
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_context_config_new();
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_config_free(IntPtr config);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_config_set_debugging(IntPtr config, int flag);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_config_set_profiling(IntPtr config, int flag);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_config_set_logging(IntPtr config, int flag);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_config_set_cache_file(IntPtr config, IntPtr path);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_config_set_num_threads(IntPtr config, int n);
+
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_context_new(IntPtr config);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_context_sync(ContextHandle ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_context_clear_caches(ContextHandle ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_pause_profiling(ContextHandle ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_unpause_profiling(ContextHandle ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_context_get_error(ContextHandle ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_context_report(ContextHandle ctx);
+
+    internal readonly ContextHandle handle;
+
+    /// <summary>Sync after every operation</summary>
+    public bool AutoSync { get; set; }
+
+    /// <summary>Create a new context, using the default options if <paramref name="options"/> is null</summary>
+    public Context(Options? options = null)
+    {
+        options ??= new Options();
+        IntPtr config = futhark_context_config_new();
+        if (config == IntPtr.Zero)
+        {
+            throw new NullPtrException();
+        }
+        futhark_context_config_set_debugging(config, options.Debug ? 1 : 0);
+        futhark_context_config_set_profiling(config, options.Profile ? 1 : 0);
+        futhark_context_config_set_logging(config, options.Logging ? 1 : 0);
+        IntPtr cacheFile = IntPtr.Zero;
+        if (options.CacheFile != null)
+        {
+            cacheFile = Marshal.StringToCoTaskMemUTF8(options.CacheFile);
+            futhark_context_config_set_cache_file(config, cacheFile);
+        }
+        futhark_context_config_set_num_threads(config, options.NumThreads);
+        IntPtr ctx = futhark_context_new(config);
+        if (ctx == IntPtr.Zero)
+        {
+            futhark_context_config_free(config);
+            Marshal.FreeCoTaskMem(cacheFile);
+            throw new NullPtrException();
+        }
+        handle = new ContextHandle(config, ctx, cacheFile);
+        AutoSync = options.AutoSync;
+    }
+
+    /// <summary>Free the context once all values created using it have been disposed</summary>
+    public void Dispose()
+    {
+        handle.Dispose();
+    }
+
+    internal void Check(int rc)
+    {
+        if (rc != 0)
+        {
+            throw new FutharkException(rc, GetError() ?? "");
+        }
+    }
+
+    /// <summary>Wait for all pending operations to finish</summary>
+    public void Sync()
+    {
+        Check(futhark_context_sync(handle));
+    }
+
+    internal void MaybeSync()
+    {
+        if (AutoSync)
+        {
+            Sync();
+        }
+    }
+
+    /// <summary>Free any cached memory</summary>
+    public void ClearCaches()
+    {
+        Check(futhark_context_clear_caches(handle));
+    }
+
+    /// <summary>Pause profiling</summary>
+    public void PauseProfiling()
+    {
+        futhark_context_pause_profiling(handle);
+    }
+
+    /// <summary>Resume profiling</summary>
+    public void UnpauseProfiling()
+    {
+        futhark_context_unpause_profiling(handle);
+    }
+
+    /// <summary>Get the last error message</summary>
+    public string? GetError()
+    {
+        return TakeString(futhark_context_get_error(handle));
+    }
+
+    /// <summary>Get the profiling report</summary>
+    public string? Report()
+    {
+        return TakeString(futhark_context_report(handle));
+    }
+
+    private static string? TakeString(IntPtr s)
+    {
+        if (s == IntPtr.Zero)
+        {
+            return null;
+        }
+        string? result = Marshal.PtrToStringUTF8(s);
+        // Futhark allocates strings using `malloc`, `NativeMemory.Free` calls the matching `free`
+        unsafe { NativeMemory.Free((void*)s); }
+        return result;
+    }
+}
+
+/// <summary>Array type with 3 dimensions and f32 elements</summary>
+public sealed class ArrayF32D3 : IDisposable
+{
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_new_f32_3d(ContextHandle ctx, ref float data, long dim0, long dim1, long dim2);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_free_f32_3d(IntPtr ctx, IntPtr arr);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_values_f32_3d(ContextHandle ctx, FutharkHandle arr, ref float data);
+
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_shape_f32_3d(ContextHandle ctx, FutharkHandle arr);
+
+    private sealed class Handle : FutharkHandle
+    {
+        public Handle(ContextHandle ctx, IntPtr ptr) : base(ctx, ptr)
+        {
+        }
+
+        protected override void Free(IntPtr ctx, IntPtr ptr)
+        {
+            futhark_free_f32_3d(ctx, ptr);
+        }
+    }
+
+    private readonly Context ctx;
+    internal readonly FutharkHandle handle;
+    private readonly long[] shape = new long[3];
+
+    internal ArrayF32D3(Context ctx, IntPtr ptr)
+    {
+        this.ctx = ctx;
+        handle = new Handle(ctx.handle, ptr);
+        Marshal.Copy(futhark_shape_f32_3d(ctx.handle, handle), shape, 0, 3);
+    }
+
+    /// <summary>Create a new array with the shape <paramref name="dims"/>, initialized with the values from <paramref name="data"/></summary>
+    public ArrayF32D3(Context ctx, ReadOnlySpan<float> data, params long[] dims)
+    {
+        long size = 1;
+        foreach (long d in dims)
+        {
+            size *= d;
+        }
+        if (dims.Length != 3 || size != data.Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {size} elements, got {data.Length}", nameof(data));
+        }
+        this.ctx = ctx;
+        IntPtr ptr = futhark_new_f32_3d(ctx.handle, ref MemoryMarshal.GetReference(data), dims[0], dims[1], dims[2]);
+        handle = new Handle(ctx.handle, ptr);
+        dims.CopyTo(shape, 0);
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Shape of the array</summary>
+    public ReadOnlySpan<long> Shape => shape;
+
+    /// <summary>Number of elements in the array</summary>
+    public long Length
+    {
+        get
+        {
+            long size = 1;
+            foreach (long d in shape)
+            {
+                size *= d;
+            }
+            return size;
+        }
+    }
+
+    /// <summary>Copy the values into <paramref name="output"/>, which must have the same number of elements as the array</summary>
+    public void Values(Span<float> output)
+    {
+        if (output.Length != Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {Length} elements, got {output.Length}", nameof(output));
+        }
+        ctx.Check(futhark_values_f32_3d(ctx.handle, handle, ref MemoryMarshal.GetReference(output)));
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Copy the values into a new array</summary>
+    public float[] ToArray()
+    {
+        var output = new float[Length];
+        Values(output);
+        return output;
+    }
+
+    /// <summary>Free the array, it is also freed when it is garbage collected</summary>
+    public void Dispose()
+    {
+        handle.Dispose();
+    }
+}
+
+/// <summary>Array type with 2 dimensions and f32 elements</summary>
+public sealed class ArrayF32D2 : IDisposable
+{
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_new_f32_2d(ContextHandle ctx, ref float data, long dim0, long dim1);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_free_f32_2d(IntPtr ctx, IntPtr arr);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_values_f32_2d(ContextHandle ctx, FutharkHandle arr, ref float data);
+
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_shape_f32_2d(ContextHandle ctx, FutharkHandle arr);
+
+    private sealed class Handle : FutharkHandle
+    {
+        public Handle(ContextHandle ctx, IntPtr ptr) : base(ctx, ptr)
+        {
+        }
+
+        protected override void Free(IntPtr ctx, IntPtr ptr)
+        {
+            futhark_free_f32_2d(ctx, ptr);
+        }
+    }
+
+    private readonly Context ctx;
+    internal readonly FutharkHandle handle;
+    private readonly long[] shape = new long[2];
+
+    internal ArrayF32D2(Context ctx, IntPtr ptr)
+    {
+        this.ctx = ctx;
+        handle = new Handle(ctx.handle, ptr);
+        Marshal.Copy(futhark_shape_f32_2d(ctx.handle, handle), shape, 0, 2);
+    }
+
+    /// <summary>Create a new array with the shape <paramref name="dims"/>, initialized with the values from <paramref name="data"/></summary>
+    public ArrayF32D2(Context ctx, ReadOnlySpan<float> data, params long[] dims)
+    {
+        long size = 1;
+        foreach (long d in dims)
+        {
+            size *= d;
+        }
+        if (dims.Length != 2 || size != data.Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {size} elements, got {data.Length}", nameof(data));
+        }
+        this.ctx = ctx;
+        IntPtr ptr = futhark_new_f32_2d(ctx.handle, ref MemoryMarshal.GetReference(data), dims[0], dims[1]);
+        handle = new Handle(ctx.handle, ptr);
+        dims.CopyTo(shape, 0);
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Shape of the array</summary>
+    public ReadOnlySpan<long> Shape => shape;
+
+    /// <summary>Number of elements in the array</summary>
+    public long Length
+    {
+        get
+        {
+            long size = 1;
+            foreach (long d in shape)
+            {
+                size *= d;
+            }
+            return size;
+        }
+    }
+
+    /// <summary>Copy the values into <paramref name="output"/>, which must have the same number of elements as the array</summary>
+    public void Values(Span<float> output)
+    {
+        if (output.Length != Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {Length} elements, got {output.Length}", nameof(output));
+        }
+        ctx.Check(futhark_values_f32_2d(ctx.handle, handle, ref MemoryMarshal.GetReference(output)));
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Copy the values into a new array</summary>
+    public float[] ToArray()
+    {
+        var output = new float[Length];
+        Values(output);
+        return output;
+    }
+
+    /// <summary>Free the array, it is also freed when it is garbage collected</summary>
+    public void Dispose()
+    {
+        handle.Dispose();
+    }
+}
+
+/// <summary>Array type with 1 dimensions and bool elements</summary>
+public sealed class ArrayBoolD1 : IDisposable
+{
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_new_bool_1d(ContextHandle ctx, ref byte data, long dim0);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_free_bool_1d(IntPtr ctx, IntPtr arr);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_values_bool_1d(ContextHandle ctx, FutharkHandle arr, ref byte data);
+
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_shape_bool_1d(ContextHandle ctx, FutharkHandle arr);
+
+    private sealed class Handle : FutharkHandle
+    {
+        public Handle(ContextHandle ctx, IntPtr ptr) : base(ctx, ptr)
+        {
+        }
+
+        protected override void Free(IntPtr ctx, IntPtr ptr)
+        {
+            futhark_free_bool_1d(ctx, ptr);
+        }
+    }
+
+    private readonly Context ctx;
+    internal readonly FutharkHandle handle;
+    private readonly long[] shape = new long[1];
+
+    internal ArrayBoolD1(Context ctx, IntPtr ptr)
+    {
+        this.ctx = ctx;
+        handle = new Handle(ctx.handle, ptr);
+        Marshal.Copy(futhark_shape_bool_1d(ctx.handle, handle), shape, 0, 1);
+    }
+
+    /// <summary>Create a new array with the shape <paramref name="dims"/>, initialized with the values from <paramref name="data"/></summary>
+    public ArrayBoolD1(Context ctx, ReadOnlySpan<bool> data, params long[] dims)
+    {
+        long size = 1;
+        foreach (long d in dims)
+        {
+            size *= d;
+        }
+        if (dims.Length != 1 || size != data.Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {size} elements, got {data.Length}", nameof(data));
+        }
+        this.ctx = ctx;
+        IntPtr ptr = futhark_new_bool_1d(ctx.handle, ref MemoryMarshal.GetReference(MemoryMarshal.Cast<bool, byte>(data)), dims[0]);
+        handle = new Handle(ctx.handle, ptr);
+        dims.CopyTo(shape, 0);
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Shape of the array</summary>
+    public ReadOnlySpan<long> Shape => shape;
+
+    /// <summary>Number of elements in the array</summary>
+    public long Length
+    {
+        get
+        {
+            long size = 1;
+            foreach (long d in shape)
+            {
+                size *= d;
+            }
+            return size;
+        }
+    }
+
+    /// <summary>Copy the values into <paramref name="output"/>, which must have the same number of elements as the array</summary>
+    public void Values(Span<bool> output)
+    {
+        if (output.Length != Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {Length} elements, got {output.Length}", nameof(output));
+        }
+        ctx.Check(futhark_values_bool_1d(ctx.handle, handle, ref MemoryMarshal.GetReference(MemoryMarshal.Cast<bool, byte>(output))));
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Copy the values into a new array</summary>
+    public bool[] ToArray()
+    {
+        var output = new bool[Length];
+        Values(output);
+        return output;
+    }
+
+    /// <summary>Free the array, it is also freed when it is garbage collected</summary>
+    public void Dispose()
+    {
+        handle.Dispose();
+    }
+}
+
+/// <summary>Array type with 1 dimensions and f16 elements</summary>
+public sealed class ArrayF16D1 : IDisposable
+{
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_new_f16_1d(ContextHandle ctx, ref ushort data, long dim0);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_free_f16_1d(IntPtr ctx, IntPtr arr);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_values_f16_1d(ContextHandle ctx, FutharkHandle arr, ref ushort data);
+
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_shape_f16_1d(ContextHandle ctx, FutharkHandle arr);
+
+    private sealed class Handle : FutharkHandle
+    {
+        public Handle(ContextHandle ctx, IntPtr ptr) : base(ctx, ptr)
+        {
+        }
+
+        protected override void Free(IntPtr ctx, IntPtr ptr)
+        {
+            futhark_free_f16_1d(ctx, ptr);
+        }
+    }
+
+    private readonly Context ctx;
+    internal readonly FutharkHandle handle;
+    private readonly long[] shape = new long[1];
+
+    internal ArrayF16D1(Context ctx, IntPtr ptr)
+    {
+        this.ctx = ctx;
+        handle = new Handle(ctx.handle, ptr);
+        Marshal.Copy(futhark_shape_f16_1d(ctx.handle, handle), shape, 0, 1);
+    }
+
+    /// <summary>Create a new array with the shape <paramref name="dims"/>, initialized with the values from <paramref name="data"/></summary>
+    public ArrayF16D1(Context ctx, ReadOnlySpan<ushort> data, params long[] dims)
+    {
+        long size = 1;
+        foreach (long d in dims)
+        {
+            size *= d;
+        }
+        if (dims.Length != 1 || size != data.Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {size} elements, got {data.Length}", nameof(data));
+        }
+        this.ctx = ctx;
+        IntPtr ptr = futhark_new_f16_1d(ctx.handle, ref MemoryMarshal.GetReference(data), dims[0]);
+        handle = new Handle(ctx.handle, ptr);
+        dims.CopyTo(shape, 0);
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Shape of the array</summary>
+    public ReadOnlySpan<long> Shape => shape;
+
+    /// <summary>Number of elements in the array</summary>
+    public long Length
+    {
+        get
+        {
+            long size = 1;
+            foreach (long d in shape)
+            {
+                size *= d;
+            }
+            return size;
+        }
+    }
+
+    /// <summary>Copy the values into <paramref name="output"/>, which must have the same number of elements as the array</summary>
+    public void Values(Span<ushort> output)
+    {
+        if (output.Length != Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {Length} elements, got {output.Length}", nameof(output));
+        }
+        ctx.Check(futhark_values_f16_1d(ctx.handle, handle, ref MemoryMarshal.GetReference(output)));
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Copy the values into a new array</summary>
+    public ushort[] ToArray()
+    {
+        var output = new ushort[Length];
+        Values(output);
+        return output;
+    }
+
+    /// <summary>Free the array, it is also freed when it is garbage collected</summary>
+    public void Dispose()
+    {
+        handle.Dispose();
+    }
+}
+
+/// <summary>Array type with 1 dimensions and f32 elements</summary>
+public sealed class ArrayF32D1 : IDisposable
+{
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_new_f32_1d(ContextHandle ctx, ref float data, long dim0);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_free_f32_1d(IntPtr ctx, IntPtr arr);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_values_f32_1d(ContextHandle ctx, FutharkHandle arr, ref float data);
+
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_shape_f32_1d(ContextHandle ctx, FutharkHandle arr);
+
+    private sealed class Handle : FutharkHandle
+    {
+        public Handle(ContextHandle ctx, IntPtr ptr) : base(ctx, ptr)
+        {
+        }
+
+        protected override void Free(IntPtr ctx, IntPtr ptr)
+        {
+            futhark_free_f32_1d(ctx, ptr);
+        }
+    }
+
+    private readonly Context ctx;
+    internal readonly FutharkHandle handle;
+    private readonly long[] shape = new long[1];
+
+    internal ArrayF32D1(Context ctx, IntPtr ptr)
+    {
+        this.ctx = ctx;
+        handle = new Handle(ctx.handle, ptr);
+        Marshal.Copy(futhark_shape_f32_1d(ctx.handle, handle), shape, 0, 1);
+    }
+
+    /// <summary>Create a new array with the shape <paramref name="dims"/>, initialized with the values from <paramref name="data"/></summary>
+    public ArrayF32D1(Context ctx, ReadOnlySpan<float> data, params long[] dims)
+    {
+        long size = 1;
+        foreach (long d in dims)
+        {
+            size *= d;
+        }
+        if (dims.Length != 1 || size != data.Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {size} elements, got {data.Length}", nameof(data));
+        }
+        this.ctx = ctx;
+        IntPtr ptr = futhark_new_f32_1d(ctx.handle, ref MemoryMarshal.GetReference(data), dims[0]);
+        handle = new Handle(ctx.handle, ptr);
+        dims.CopyTo(shape, 0);
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Shape of the array</summary>
+    public ReadOnlySpan<long> Shape => shape;
+
+    /// <summary>Number of elements in the array</summary>
+    public long Length
+    {
+        get
+        {
+            long size = 1;
+            foreach (long d in shape)
+            {
+                size *= d;
+            }
+            return size;
+        }
+    }
+
+    /// <summary>Copy the values into <paramref name="output"/>, which must have the same number of elements as the array</summary>
+    public void Values(Span<float> output)
+    {
+        if (output.Length != Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {Length} elements, got {output.Length}", nameof(output));
+        }
+        ctx.Check(futhark_values_f32_1d(ctx.handle, handle, ref MemoryMarshal.GetReference(output)));
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Copy the values into a new array</summary>
+    public float[] ToArray()
+    {
+        var output = new float[Length];
+        Values(output);
+        return output;
+    }
+
+    /// <summary>Free the array, it is also freed when it is garbage collected</summary>
+    public void Dispose()
+    {
+        handle.Dispose();
+    }
+}
+
+/// <summary>Array type with 1 dimensions and f64 elements</summary>
+public sealed class ArrayF64D1 : IDisposable
+{
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_new_f64_1d(ContextHandle ctx, ref double data, long dim0);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_free_f64_1d(IntPtr ctx, IntPtr arr);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_values_f64_1d(ContextHandle ctx, FutharkHandle arr, ref double data);
+
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_shape_f64_1d(ContextHandle ctx, FutharkHandle arr);
+
+    private sealed class Handle : FutharkHandle
+    {
+        public Handle(ContextHandle ctx, IntPtr ptr) : base(ctx, ptr)
+        {
+        }
+
+        protected override void Free(IntPtr ctx, IntPtr ptr)
+        {
+            futhark_free_f64_1d(ctx, ptr);
+        }
+    }
+
+    private readonly Context ctx;
+    internal readonly FutharkHandle handle;
+    private readonly long[] shape = new long[1];
+
+    internal ArrayF64D1(Context ctx, IntPtr ptr)
+    {
+        this.ctx = ctx;
+        handle = new Handle(ctx.handle, ptr);
+        Marshal.Copy(futhark_shape_f64_1d(ctx.handle, handle), shape, 0, 1);
+    }
+
+    /// <summary>Create a new array with the shape <paramref name="dims"/>, initialized with the values from <paramref name="data"/></summary>
+    public ArrayF64D1(Context ctx, ReadOnlySpan<double> data, params long[] dims)
+    {
+        long size = 1;
+        foreach (long d in dims)
+        {
+            size *= d;
+        }
+        if (dims.Length != 1 || size != data.Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {size} elements, got {data.Length}", nameof(data));
+        }
+        this.ctx = ctx;
+        IntPtr ptr = futhark_new_f64_1d(ctx.handle, ref MemoryMarshal.GetReference(data), dims[0]);
+        handle = new Handle(ctx.handle, ptr);
+        dims.CopyTo(shape, 0);
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Shape of the array</summary>
+    public ReadOnlySpan<long> Shape => shape;
+
+    /// <summary>Number of elements in the array</summary>
+    public long Length
+    {
+        get
+        {
+            long size = 1;
+            foreach (long d in shape)
+            {
+                size *= d;
+            }
+            return size;
+        }
+    }
+
+    /// <summary>Copy the values into <paramref name="output"/>, which must have the same number of elements as the array</summary>
+    public void Values(Span<double> output)
+    {
+        if (output.Length != Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {Length} elements, got {output.Length}", nameof(output));
+        }
+        ctx.Check(futhark_values_f64_1d(ctx.handle, handle, ref MemoryMarshal.GetReference(output)));
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Copy the values into a new array</summary>
+    public double[] ToArray()
+    {
+        var output = new double[Length];
+        Values(output);
+        return output;
+    }
+
+    /// <summary>Free the array, it is also freed when it is garbage collected</summary>
+    public void Dispose()
+    {
+        handle.Dispose();
+    }
+}
+
+/// <summary>Array type with 1 dimensions and i16 elements</summary>
+public sealed class ArrayI16D1 : IDisposable
+{
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_new_i16_1d(ContextHandle ctx, ref short data, long dim0);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_free_i16_1d(IntPtr ctx, IntPtr arr);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_values_i16_1d(ContextHandle ctx, FutharkHandle arr, ref short data);
+
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_shape_i16_1d(ContextHandle ctx, FutharkHandle arr);
+
+    private sealed class Handle : FutharkHandle
+    {
+        public Handle(ContextHandle ctx, IntPtr ptr) : base(ctx, ptr)
+        {
+        }
+
+        protected override void Free(IntPtr ctx, IntPtr ptr)
+        {
+            futhark_free_i16_1d(ctx, ptr);
+        }
+    }
+
+    private readonly Context ctx;
+    internal readonly FutharkHandle handle;
+    private readonly long[] shape = new long[1];
+
+    internal ArrayI16D1(Context ctx, IntPtr ptr)
+    {
+        this.ctx = ctx;
+        handle = new Handle(ctx.handle, ptr);
+        Marshal.Copy(futhark_shape_i16_1d(ctx.handle, handle), shape, 0, 1);
+    }
+
+    /// <summary>Create a new array with the shape <paramref name="dims"/>, initialized with the values from <paramref name="data"/></summary>
+    public ArrayI16D1(Context ctx, ReadOnlySpan<short> data, params long[] dims)
+    {
+        long size = 1;
+        foreach (long d in dims)
+        {
+            size *= d;
+        }
+        if (dims.Length != 1 || size != data.Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {size} elements, got {data.Length}", nameof(data));
+        }
+        this.ctx = ctx;
+        IntPtr ptr = futhark_new_i16_1d(ctx.handle, ref MemoryMarshal.GetReference(data), dims[0]);
+        handle = new Handle(ctx.handle, ptr);
+        dims.CopyTo(shape, 0);
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Shape of the array</summary>
+    public ReadOnlySpan<long> Shape => shape;
+
+    /// <summary>Number of elements in the array</summary>
+    public long Length
+    {
+        get
+        {
+            long size = 1;
+            foreach (long d in shape)
+            {
+                size *= d;
+            }
+            return size;
+        }
+    }
+
+    /// <summary>Copy the values into <paramref name="output"/>, which must have the same number of elements as the array</summary>
+    public void Values(Span<short> output)
+    {
+        if (output.Length != Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {Length} elements, got {output.Length}", nameof(output));
+        }
+        ctx.Check(futhark_values_i16_1d(ctx.handle, handle, ref MemoryMarshal.GetReference(output)));
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Copy the values into a new array</summary>
+    public short[] ToArray()
+    {
+        var output = new short[Length];
+        Values(output);
+        return output;
+    }
+
+    /// <summary>Free the array, it is also freed when it is garbage collected</summary>
+    public void Dispose()
+    {
+        handle.Dispose();
+    }
+}
+
+/// <summary>Array type with 1 dimensions and i32 elements</summary>
+public sealed class ArrayI32D1 : IDisposable
+{
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_new_i32_1d(ContextHandle ctx, ref int data, long dim0);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_free_i32_1d(IntPtr ctx, IntPtr arr);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_values_i32_1d(ContextHandle ctx, FutharkHandle arr, ref int data);
+
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_shape_i32_1d(ContextHandle ctx, FutharkHandle arr);
+
+    private sealed class Handle : FutharkHandle
+    {
+        public Handle(ContextHandle ctx, IntPtr ptr) : base(ctx, ptr)
+        {
+        }
+
+        protected override void Free(IntPtr ctx, IntPtr ptr)
+        {
+            futhark_free_i32_1d(ctx, ptr);
+        }
+    }
+
+    private readonly Context ctx;
+    internal readonly FutharkHandle handle;
+    private readonly long[] shape = new long[1];
+
+    internal ArrayI32D1(Context ctx, IntPtr ptr)
+    {
+        this.ctx = ctx;
+        handle = new Handle(ctx.handle, ptr);
+        Marshal.Copy(futhark_shape_i32_1d(ctx.handle, handle), shape, 0, 1);
+    }
+
+    /// <summary>Create a new array with the shape <paramref name="dims"/>, initialized with the values from <paramref name="data"/></summary>
+    public ArrayI32D1(Context ctx, ReadOnlySpan<int> data, params long[] dims)
+    {
+        long size = 1;
+        foreach (long d in dims)
+        {
+            size *= d;
+        }
+        if (dims.Length != 1 || size != data.Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {size} elements, got {data.Length}", nameof(data));
+        }
+        this.ctx = ctx;
+        IntPtr ptr = futhark_new_i32_1d(ctx.handle, ref MemoryMarshal.GetReference(data), dims[0]);
+        handle = new Handle(ctx.handle, ptr);
+        dims.CopyTo(shape, 0);
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Shape of the array</summary>
+    public ReadOnlySpan<long> Shape => shape;
+
+    /// <summary>Number of elements in the array</summary>
+    public long Length
+    {
+        get
+        {
+            long size = 1;
+            foreach (long d in shape)
+            {
+                size *= d;
+            }
+            return size;
+        }
+    }
+
+    /// <summary>Copy the values into <paramref name="output"/>, which must have the same number of elements as the array</summary>
+    public void Values(Span<int> output)
+    {
+        if (output.Length != Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {Length} elements, got {output.Length}", nameof(output));
+        }
+        ctx.Check(futhark_values_i32_1d(ctx.handle, handle, ref MemoryMarshal.GetReference(output)));
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Copy the values into a new array</summary>
+    public int[] ToArray()
+    {
+        var output = new int[Length];
+        Values(output);
+        return output;
+    }
+
+    /// <summary>Free the array, it is also freed when it is garbage collected</summary>
+    public void Dispose()
+    {
+        handle.Dispose();
+    }
+}
+
+/// <summary>Array type with 1 dimensions and i64 elements</summary>
+public sealed class ArrayI64D1 : IDisposable
+{
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_new_i64_1d(ContextHandle ctx, ref long data, long dim0);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_free_i64_1d(IntPtr ctx, IntPtr arr);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_values_i64_1d(ContextHandle ctx, FutharkHandle arr, ref long data);
+
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_shape_i64_1d(ContextHandle ctx, FutharkHandle arr);
+
+    private sealed class Handle : FutharkHandle
+    {
+        public Handle(ContextHandle ctx, IntPtr ptr) : base(ctx, ptr)
+        {
+        }
+
+        protected override void Free(IntPtr ctx, IntPtr ptr)
+        {
+            futhark_free_i64_1d(ctx, ptr);
+        }
+    }
+
+    private readonly Context ctx;
+    internal readonly FutharkHandle handle;
+    private readonly long[] shape = new long[1];
+
+    internal ArrayI64D1(Context ctx, IntPtr ptr)
+    {
+        this.ctx = ctx;
+        handle = new Handle(ctx.handle, ptr);
+        Marshal.Copy(futhark_shape_i64_1d(ctx.handle, handle), shape, 0, 1);
+    }
+
+    /// <summary>Create a new array with the shape <paramref name="dims"/>, initialized with the values from <paramref name="data"/></summary>
+    public ArrayI64D1(Context ctx, ReadOnlySpan<long> data, params long[] dims)
+    {
+        long size = 1;
+        foreach (long d in dims)
+        {
+            size *= d;
+        }
+        if (dims.Length != 1 || size != data.Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {size} elements, got {data.Length}", nameof(data));
+        }
+        this.ctx = ctx;
+        IntPtr ptr = futhark_new_i64_1d(ctx.handle, ref MemoryMarshal.GetReference(data), dims[0]);
+        handle = new Handle(ctx.handle, ptr);
+        dims.CopyTo(shape, 0);
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Shape of the array</summary>
+    public ReadOnlySpan<long> Shape => shape;
+
+    /// <summary>Number of elements in the array</summary>
+    public long Length
+    {
+        get
+        {
+            long size = 1;
+            foreach (long d in shape)
+            {
+                size *= d;
+            }
+            return size;
+        }
+    }
+
+    /// <summary>Copy the values into <paramref name="output"/>, which must have the same number of elements as the array</summary>
+    public void Values(Span<long> output)
+    {
+        if (output.Length != Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {Length} elements, got {output.Length}", nameof(output));
+        }
+        ctx.Check(futhark_values_i64_1d(ctx.handle, handle, ref MemoryMarshal.GetReference(output)));
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Copy the values into a new array</summary>
+    public long[] ToArray()
+    {
+        var output = new long[Length];
+        Values(output);
+        return output;
+    }
+
+    /// <summary>Free the array, it is also freed when it is garbage collected</summary>
+    public void Dispose()
+    {
+        handle.Dispose();
+    }
+}
+
+/// <summary>Array type with 1 dimensions and i8 elements</summary>
+public sealed class ArrayI8D1 : IDisposable
+{
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_new_i8_1d(ContextHandle ctx, ref sbyte data, long dim0);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_free_i8_1d(IntPtr ctx, IntPtr arr);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_values_i8_1d(ContextHandle ctx, FutharkHandle arr, ref sbyte data);
+
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_shape_i8_1d(ContextHandle ctx, FutharkHandle arr);
+
+    private sealed class Handle : FutharkHandle
+    {
+        public Handle(ContextHandle ctx, IntPtr ptr) : base(ctx, ptr)
+        {
+        }
+
+        protected override void Free(IntPtr ctx, IntPtr ptr)
+        {
+            futhark_free_i8_1d(ctx, ptr);
+        }
+    }
+
+    private readonly Context ctx;
+    internal readonly FutharkHandle handle;
+    private readonly long[] shape = new long[1];
+
+    internal ArrayI8D1(Context ctx, IntPtr ptr)
+    {
+        this.ctx = ctx;
+        handle = new Handle(ctx.handle, ptr);
+        Marshal.Copy(futhark_shape_i8_1d(ctx.handle, handle), shape, 0, 1);
+    }
+
+    /// <summary>Create a new array with the shape <paramref name="dims"/>, initialized with the values from <paramref name="data"/></summary>
+    public ArrayI8D1(Context ctx, ReadOnlySpan<sbyte> data, params long[] dims)
+    {
+        long size = 1;
+        foreach (long d in dims)
+        {
+            size *= d;
+        }
+        if (dims.Length != 1 || size != data.Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {size} elements, got {data.Length}", nameof(data));
+        }
+        this.ctx = ctx;
+        IntPtr ptr = futhark_new_i8_1d(ctx.handle, ref MemoryMarshal.GetReference(data), dims[0]);
+        handle = new Handle(ctx.handle, ptr);
+        dims.CopyTo(shape, 0);
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Shape of the array</summary>
+    public ReadOnlySpan<long> Shape => shape;
+
+    /// <summary>Number of elements in the array</summary>
+    public long Length
+    {
+        get
+        {
+            long size = 1;
+            foreach (long d in shape)
+            {
+                size *= d;
+            }
+            return size;
+        }
+    }
+
+    /// <summary>Copy the values into <paramref name="output"/>, which must have the same number of elements as the array</summary>
+    public void Values(Span<sbyte> output)
+    {
+        if (output.Length != Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {Length} elements, got {output.Length}", nameof(output));
+        }
+        ctx.Check(futhark_values_i8_1d(ctx.handle, handle, ref MemoryMarshal.GetReference(output)));
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Copy the values into a new array</summary>
+    public sbyte[] ToArray()
+    {
+        var output = new sbyte[Length];
+        Values(output);
+        return output;
+    }
+
+    /// <summary>Free the array, it is also freed when it is garbage collected</summary>
+    public void Dispose()
+    {
+        handle.Dispose();
+    }
+}
+
+/// <summary>Array type with 1 dimensions and u16 elements</summary>
+public sealed class ArrayU16D1 : IDisposable
+{
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_new_u16_1d(ContextHandle ctx, ref ushort data, long dim0);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_free_u16_1d(IntPtr ctx, IntPtr arr);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_values_u16_1d(ContextHandle ctx, FutharkHandle arr, ref ushort data);
+
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_shape_u16_1d(ContextHandle ctx, FutharkHandle arr);
+
+    private sealed class Handle : FutharkHandle
+    {
+        public Handle(ContextHandle ctx, IntPtr ptr) : base(ctx, ptr)
+        {
+        }
+
+        protected override void Free(IntPtr ctx, IntPtr ptr)
+        {
+            futhark_free_u16_1d(ctx, ptr);
+        }
+    }
+
+    private readonly Context ctx;
+    internal readonly FutharkHandle handle;
+    private readonly long[] shape = new long[1];
+
+    internal ArrayU16D1(Context ctx, IntPtr ptr)
+    {
+        this.ctx = ctx;
+        handle = new Handle(ctx.handle, ptr);
+        Marshal.Copy(futhark_shape_u16_1d(ctx.handle, handle), shape, 0, 1);
+    }
+
+    /// <summary>Create a new array with the shape <paramref name="dims"/>, initialized with the values from <paramref name="data"/></summary>
+    public ArrayU16D1(Context ctx, ReadOnlySpan<ushort> data, params long[] dims)
+    {
+        long size = 1;
+        foreach (long d in dims)
+        {
+            size *= d;
+        }
+        if (dims.Length != 1 || size != data.Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {size} elements, got {data.Length}", nameof(data));
+        }
+        this.ctx = ctx;
+        IntPtr ptr = futhark_new_u16_1d(ctx.handle, ref MemoryMarshal.GetReference(data), dims[0]);
+        handle = new Handle(ctx.handle, ptr);
+        dims.CopyTo(shape, 0);
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Shape of the array</summary>
+    public ReadOnlySpan<long> Shape => shape;
+
+    /// <summary>Number of elements in the array</summary>
+    public long Length
+    {
+        get
+        {
+            long size = 1;
+            foreach (long d in shape)
+            {
+                size *= d;
+            }
+            return size;
+        }
+    }
+
+    /// <summary>Copy the values into <paramref name="output"/>, which must have the same number of elements as the array</summary>
+    public void Values(Span<ushort> output)
+    {
+        if (output.Length != Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {Length} elements, got {output.Length}", nameof(output));
+        }
+        ctx.Check(futhark_values_u16_1d(ctx.handle, handle, ref MemoryMarshal.GetReference(output)));
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Copy the values into a new array</summary>
+    public ushort[] ToArray()
+    {
+        var output = new ushort[Length];
+        Values(output);
+        return output;
+    }
+
+    /// <summary>Free the array, it is also freed when it is garbage collected</summary>
+    public void Dispose()
+    {
+        handle.Dispose();
+    }
+}
+
+/// <summary>Array type with 1 dimensions and u32 elements</summary>
+public sealed class ArrayU32D1 : IDisposable
+{
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_new_u32_1d(ContextHandle ctx, ref uint data, long dim0);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_free_u32_1d(IntPtr ctx, IntPtr arr);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_values_u32_1d(ContextHandle ctx, FutharkHandle arr, ref uint data);
+
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_shape_u32_1d(ContextHandle ctx, FutharkHandle arr);
+
+    private sealed class Handle : FutharkHandle
+    {
+        public Handle(ContextHandle ctx, IntPtr ptr) : base(ctx, ptr)
+        {
+        }
+
+        protected override void Free(IntPtr ctx, IntPtr ptr)
+        {
+            futhark_free_u32_1d(ctx, ptr);
+        }
+    }
+
+    private readonly Context ctx;
+    internal readonly FutharkHandle handle;
+    private readonly long[] shape = new long[1];
+
+    internal ArrayU32D1(Context ctx, IntPtr ptr)
+    {
+        this.ctx = ctx;
+        handle = new Handle(ctx.handle, ptr);
+        Marshal.Copy(futhark_shape_u32_1d(ctx.handle, handle), shape, 0, 1);
+    }
+
+    /// <summary>Create a new array with the shape <paramref name="dims"/>, initialized with the values from <paramref name="data"/></summary>
+    public ArrayU32D1(Context ctx, ReadOnlySpan<uint> data, params long[] dims)
+    {
+        long size = 1;
+        foreach (long d in dims)
+        {
+            size *= d;
+        }
+        if (dims.Length != 1 || size != data.Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {size} elements, got {data.Length}", nameof(data));
+        }
+        this.ctx = ctx;
+        IntPtr ptr = futhark_new_u32_1d(ctx.handle, ref MemoryMarshal.GetReference(data), dims[0]);
+        handle = new Handle(ctx.handle, ptr);
+        dims.CopyTo(shape, 0);
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Shape of the array</summary>
+    public ReadOnlySpan<long> Shape => shape;
+
+    /// <summary>Number of elements in the array</summary>
+    public long Length
+    {
+        get
+        {
+            long size = 1;
+            foreach (long d in shape)
+            {
+                size *= d;
+            }
+            return size;
+        }
+    }
+
+    /// <summary>Copy the values into <paramref name="output"/>, which must have the same number of elements as the array</summary>
+    public void Values(Span<uint> output)
+    {
+        if (output.Length != Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {Length} elements, got {output.Length}", nameof(output));
+        }
+        ctx.Check(futhark_values_u32_1d(ctx.handle, handle, ref MemoryMarshal.GetReference(output)));
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Copy the values into a new array</summary>
+    public uint[] ToArray()
+    {
+        var output = new uint[Length];
+        Values(output);
+        return output;
+    }
+
+    /// <summary>Free the array, it is also freed when it is garbage collected</summary>
+    public void Dispose()
+    {
+        handle.Dispose();
+    }
+}
+
+/// <summary>Array type with 1 dimensions and u64 elements</summary>
+public sealed class ArrayU64D1 : IDisposable
+{
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_new_u64_1d(ContextHandle ctx, ref ulong data, long dim0);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_free_u64_1d(IntPtr ctx, IntPtr arr);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_values_u64_1d(ContextHandle ctx, FutharkHandle arr, ref ulong data);
+
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_shape_u64_1d(ContextHandle ctx, FutharkHandle arr);
+
+    private sealed class Handle : FutharkHandle
+    {
+        public Handle(ContextHandle ctx, IntPtr ptr) : base(ctx, ptr)
+        {
+        }
+
+        protected override void Free(IntPtr ctx, IntPtr ptr)
+        {
+            futhark_free_u64_1d(ctx, ptr);
+        }
+    }
+
+    private readonly Context ctx;
+    internal readonly FutharkHandle handle;
+    private readonly long[] shape = new long[1];
+
+    internal ArrayU64D1(Context ctx, IntPtr ptr)
+    {
+        this.ctx = ctx;
+        handle = new Handle(ctx.handle, ptr);
+        Marshal.Copy(futhark_shape_u64_1d(ctx.handle, handle), shape, 0, 1);
+    }
+
+    /// <summary>Create a new array with the shape <paramref name="dims"/>, initialized with the values from <paramref name="data"/></summary>
+    public ArrayU64D1(Context ctx, ReadOnlySpan<ulong> data, params long[] dims)
+    {
+        long size = 1;
+        foreach (long d in dims)
+        {
+            size *= d;
+        }
+        if (dims.Length != 1 || size != data.Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {size} elements, got {data.Length}", nameof(data));
+        }
+        this.ctx = ctx;
+        IntPtr ptr = futhark_new_u64_1d(ctx.handle, ref MemoryMarshal.GetReference(data), dims[0]);
+        handle = new Handle(ctx.handle, ptr);
+        dims.CopyTo(shape, 0);
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Shape of the array</summary>
+    public ReadOnlySpan<long> Shape => shape;
+
+    /// <summary>Number of elements in the array</summary>
+    public long Length
+    {
+        get
+        {
+            long size = 1;
+            foreach (long d in shape)
+            {
+                size *= d;
+            }
+            return size;
+        }
+    }
+
+    /// <summary>Copy the values into <paramref name="output"/>, which must have the same number of elements as the array</summary>
+    public void Values(Span<ulong> output)
+    {
+        if (output.Length != Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {Length} elements, got {output.Length}", nameof(output));
+        }
+        ctx.Check(futhark_values_u64_1d(ctx.handle, handle, ref MemoryMarshal.GetReference(output)));
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Copy the values into a new array</summary>
+    public ulong[] ToArray()
+    {
+        var output = new ulong[Length];
+        Values(output);
+        return output;
+    }
+
+    /// <summary>Free the array, it is also freed when it is garbage collected</summary>
+    public void Dispose()
+    {
+        handle.Dispose();
+    }
+}
+
+/// <summary>Array type with 1 dimensions and u8 elements</summary>
+public sealed class ArrayU8D1 : IDisposable
+{
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_new_u8_1d(ContextHandle ctx, ref byte data, long dim0);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_free_u8_1d(IntPtr ctx, IntPtr arr);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_values_u8_1d(ContextHandle ctx, FutharkHandle arr, ref byte data);
+
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_shape_u8_1d(ContextHandle ctx, FutharkHandle arr);
+
+    private sealed class Handle : FutharkHandle
+    {
+        public Handle(ContextHandle ctx, IntPtr ptr) : base(ctx, ptr)
+        {
+        }
+
+        protected override void Free(IntPtr ctx, IntPtr ptr)
+        {
+            futhark_free_u8_1d(ctx, ptr);
+        }
+    }
+
+    private readonly Context ctx;
+    internal readonly FutharkHandle handle;
+    private readonly long[] shape = new long[1];
+
+    internal ArrayU8D1(Context ctx, IntPtr ptr)
+    {
+        this.ctx = ctx;
+        handle = new Handle(ctx.handle, ptr);
+        Marshal.Copy(futhark_shape_u8_1d(ctx.handle, handle), shape, 0, 1);
+    }
+
+    /// <summary>Create a new array with the shape <paramref name="dims"/>, initialized with the values from <paramref name="data"/></summary>
+    public ArrayU8D1(Context ctx, ReadOnlySpan<byte> data, params long[] dims)
+    {
+        long size = 1;
+        foreach (long d in dims)
+        {
+            size *= d;
+        }
+        if (dims.Length != 1 || size != data.Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {size} elements, got {data.Length}", nameof(data));
+        }
+        this.ctx = ctx;
+        IntPtr ptr = futhark_new_u8_1d(ctx.handle, ref MemoryMarshal.GetReference(data), dims[0]);
+        handle = new Handle(ctx.handle, ptr);
+        dims.CopyTo(shape, 0);
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Shape of the array</summary>
+    public ReadOnlySpan<long> Shape => shape;
+
+    /// <summary>Number of elements in the array</summary>
+    public long Length
+    {
+        get
+        {
+            long size = 1;
+            foreach (long d in shape)
+            {
+                size *= d;
+            }
+            return size;
+        }
+    }
+
+    /// <summary>Copy the values into <paramref name="output"/>, which must have the same number of elements as the array</summary>
+    public void Values(Span<byte> output)
+    {
+        if (output.Length != Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {Length} elements, got {output.Length}", nameof(output));
+        }
+        ctx.Check(futhark_values_u8_1d(ctx.handle, handle, ref MemoryMarshal.GetReference(output)));
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Copy the values into a new array</summary>
+    public byte[] ToArray()
+    {
+        var output = new byte[Length];
+        Values(output);
+        return output;
+    }
+
+    /// <summary>Free the array, it is also freed when it is garbage collected</summary>
+    public void Dispose()
+    {
+        handle.Dispose();
+    }
+}
+
+public sealed partial class Context
+{
+    [DllImport(Native.Lib)]
+    private static extern int futhark_entry_flatten(ContextHandle ctx, out IntPtr out0, FutharkHandle input0);
+
+    /// <summary>Entry point: flatten</summary>
+    public ArrayF32D2 Flatten(ArrayF32D3 input0)
+    {
+        Check(futhark_entry_flatten(handle, out IntPtr out0, input0.handle));
+        ArrayF32D2 result = new ArrayF32D2(this, out0);
+        MaybeSync();
+        return result;
+    }
+}
+
+public sealed partial class Context
+{
+    [DllImport(Native.Lib)]
+    private static extern int futhark_entry_lengths(ContextHandle ctx, out long out0, FutharkHandle input0, FutharkHandle input1, FutharkHandle input2, FutharkHandle input3, FutharkHandle input4, FutharkHandle input5, FutharkHandle input6, FutharkHandle input7, FutharkHandle input8, FutharkHandle input9, FutharkHandle input10, FutharkHandle input11);
+
+    /// <summary>Entry point: lengths</summary>
+    public long Lengths(ArrayI8D1 input0, ArrayI16D1 input1, ArrayI32D1 input2, ArrayI64D1 input3, ArrayU8D1 input4, ArrayU16D1 input5, ArrayU32D1 input6, ArrayU64D1 input7, ArrayF16D1 input8, ArrayF32D1 input9, ArrayF64D1 input10, ArrayBoolD1 input11)
+    {
+        Check(futhark_entry_lengths(handle, out long out0, input0.handle, input1.handle, input2.handle, input3.handle, input4.handle, input5.handle, input6.handle, input7.handle, input8.handle, input9.handle, input10.handle, input11.handle));
+        long result = out0;
+        MaybeSync();
+        return result;
+    }
+}
+
+public sealed partial class Context
+{
+    [DllImport(Native.Lib)]
+    private static extern int futhark_entry_sum_rows(ContextHandle ctx, out IntPtr out0, out float out1, FutharkHandle input0);
+
+    /// <summary>Entry point: sum_rows</summary>
+    public (ArrayF32D1, float) SumRows(ArrayF32D2 input0)
+    {
+        Check(futhark_entry_sum_rows(handle, out IntPtr out0, out float out1, input0.handle));
+        (ArrayF32D1, float) result = (new ArrayF32D1(this, out0), out1);
+        MaybeSync();
+        return result;
+    }
+}
+
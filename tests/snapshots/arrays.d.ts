@@ -0,0 +1,311 @@
+// Generated by futhark-bindgen
+
+/** Instantiated Emscripten module created by `futhark wasm --library` */
+export interface FutharkModule {
+  HEAPU8: Uint8Array;
+  _malloc(size: number): number;
+  _free(ptr: number): void;
+  [name: string]: any;
+}
+
+/** Context options */
+export interface Options {
+  debug?: boolean;
+  profile?: boolean;
+  logging?: boolean;
+  autoSync?: boolean;
+
+}
+
+/** Thrown when a Futhark function returns a non-zero error code */
+export declare class FutharkError extends Error {
+  readonly code: number;
+}
+
+/** Thrown when a Futhark function returns a NULL pointer */
+export declare class NullPtrError extends Error {}
+
+/** Thrown when data doesn't match the shape of an array */
+export declare class InvalidShapeError extends Error {}
+
+/** Futhark context */
+export declare class Context {
+  constructor(module: FutharkModule, options?: Options);
+  readonly module: FutharkModule;
+  autoSync: boolean;
+
+  /** Free the context, values created using the context can no longer be used */
+  free(): void;
+
+  /** Wait for all pending operations to finish */
+  sync(): void;
+
+  /** Free any cached memory */
+  clearCaches(): void;
+
+  /** Pause profiling */
+  pauseProfiling(): void;
+
+  /** Resume profiling */
+  unpauseProfiling(): void;
+
+  /** Get the last error message */
+  getError(): string | null;
+
+  /** Get the profiling report */
+  report(): string | null;
+}
+
+/** Array type with 3 dimensions and f32 elements */
+export declare class ArrayF32D3 {
+  /** Create a new array with the shape `dims`, initialized with the values from `data` */
+  static from(ctx: Context, data: Float32Array | ArrayLike<number>, dims?: number[]): ArrayF32D3;
+
+  readonly shape: number[];
+
+  /** Number of elements in the array */
+  readonly length: number;
+
+  /** Copy the values into a new typed array */
+  values(): Float32Array;
+
+  /** Free the array, it is also freed when it is garbage collected */
+  free(): void;
+}
+
+/** Array type with 2 dimensions and f32 elements */
+export declare class ArrayF32D2 {
+  /** Create a new array with the shape `dims`, initialized with the values from `data` */
+  static from(ctx: Context, data: Float32Array | ArrayLike<number>, dims?: number[]): ArrayF32D2;
+
+  readonly shape: number[];
+
+  /** Number of elements in the array */
+  readonly length: number;
+
+  /** Copy the values into a new typed array */
+  values(): Float32Array;
+
+  /** Free the array, it is also freed when it is garbage collected */
+  free(): void;
+}
+
+/** Array type with 1 dimensions and bool elements */
+export declare class ArrayBoolD1 {
+  /** Create a new array with the shape `dims`, initialized with the values from `data` */
+  static from(ctx: Context, data: Uint8Array | ArrayLike<boolean>, dims?: number[]): ArrayBoolD1;
+
+  readonly shape: number[];
+
+  /** Number of elements in the array */
+  readonly length: number;
+
+  /** Copy the values into a new typed array */
+  values(): Uint8Array;
+
+  /** Free the array, it is also freed when it is garbage collected */
+  free(): void;
+}
+
+/** Array type with 1 dimensions and f16 elements */
+export declare class ArrayF16D1 {
+  /** Create a new array with the shape `dims`, initialized with the values from `data` */
+  static from(ctx: Context, data: Uint16Array | ArrayLike<number>, dims?: number[]): ArrayF16D1;
+
+  readonly shape: number[];
+
+  /** Number of elements in the array */
+  readonly length: number;
+
+  /** Copy the values into a new typed array */
+  values(): Uint16Array;
+
+  /** Free the array, it is also freed when it is garbage collected */
+  free(): void;
+}
+
+/** Array type with 1 dimensions and f32 elements */
+export declare class ArrayF32D1 {
+  /** Create a new array with the shape `dims`, initialized with the values from `data` */
+  static from(ctx: Context, data: Float32Array | ArrayLike<number>, dims?: number[]): ArrayF32D1;
+
+  readonly shape: number[];
+
+  /** Number of elements in the array */
+  readonly length: number;
+
+  /** Copy the values into a new typed array */
+  values(): Float32Array;
+
+  /** Free the array, it is also freed when it is garbage collected */
+  free(): void;
+}
+
+/** Array type with 1 dimensions and f64 elements */
+export declare class ArrayF64D1 {
+  /** Create a new array with the shape `dims`, initialized with the values from `data` */
+  static from(ctx: Context, data: Float64Array | ArrayLike<number>, dims?: number[]): ArrayF64D1;
+
+  readonly shape: number[];
+
+  /** Number of elements in the array */
+  readonly length: number;
+
+  /** Copy the values into a new typed array */
+  values(): Float64Array;
+
+  /** Free the array, it is also freed when it is garbage collected */
+  free(): void;
+}
+
+/** Array type with 1 dimensions and i16 elements */
+export declare class ArrayI16D1 {
+  /** Create a new array with the shape `dims`, initialized with the values from `data` */
+  static from(ctx: Context, data: Int16Array | ArrayLike<number>, dims?: number[]): ArrayI16D1;
+
+  readonly shape: number[];
+
+  /** Number of elements in the array */
+  readonly length: number;
+
+  /** Copy the values into a new typed array */
+  values(): Int16Array;
+
+  /** Free the array, it is also freed when it is garbage collected */
+  free(): void;
+}
+
+/** Array type with 1 dimensions and i32 elements */
+export declare class ArrayI32D1 {
+  /** Create a new array with the shape `dims`, initialized with the values from `data` */
+  static from(ctx: Context, data: Int32Array | ArrayLike<number>, dims?: number[]): ArrayI32D1;
+
+  readonly shape: number[];
+
+  /** Number of elements in the array */
+  readonly length: number;
+
+  /** Copy the values into a new typed array */
+  values(): Int32Array;
+
+  /** Free the array, it is also freed when it is garbage collected */
+  free(): void;
+}
+
+/** Array type with 1 dimensions and i64 elements */
+export declare class ArrayI64D1 {
+  /** Create a new array with the shape `dims`, initialized with the values from `data` */
+  static from(ctx: Context, data: BigInt64Array | ArrayLike<bigint>, dims?: number[]): ArrayI64D1;
+
+  readonly shape: number[];
+
+  /** Number of elements in the array */
+  readonly length: number;
+
+  /** Copy the values into a new typed array */
+  values(): BigInt64Array;
+
+  /** Free the array, it is also freed when it is garbage collected */
+  free(): void;
+}
+
+/** Array type with 1 dimensions and i8 elements */
+export declare class ArrayI8D1 {
+  /** Create a new array with the shape `dims`, initialized with the values from `data` */
+  static from(ctx: Context, data: Int8Array | ArrayLike<number>, dims?: number[]): ArrayI8D1;
+
+  readonly shape: number[];
+
+  /** Number of elements in the array */
+  readonly length: number;
+
+  /** Copy the values into a new typed array */
+  values(): Int8Array;
+
+  /** Free the array, it is also freed when it is garbage collected */
+  free(): void;
+}
+
+/** Array type with 1 dimensions and u16 elements */
+export declare class ArrayU16D1 {
+  /** Create a new array with the shape `dims`, initialized with the values from `data` */
+  static from(ctx: Context, data: Uint16Array | ArrayLike<number>, dims?: number[]): ArrayU16D1;
+
+  readonly shape: number[];
+
+  /** Number of elements in the array */
+  readonly length: number;
+
+  /** Copy the values into a new typed array */
+  values(): Uint16Array;
+
+  /** Free the array, it is also freed when it is garbage collected */
+  free(): void;
+}
+
+/** Array type with 1 dimensions and u32 elements */
+export declare class ArrayU32D1 {
+  /** Create a new array with the shape `dims`, initialized with the values from `data` */
+  static from(ctx: Context, data: Uint32Array | ArrayLike<number>, dims?: number[]): ArrayU32D1;
+
+  readonly shape: number[];
+
+  /** Number of elements in the array */
+  readonly length: number;
+
+  /** Copy the values into a new typed array */
+  values(): Uint32Array;
+
+  /** Free the array, it is also freed when it is garbage collected */
+  free(): void;
+}
+
+/** Array type with 1 dimensions and u64 elements */
+export declare class ArrayU64D1 {
+  /** Create a new array with the shape `dims`, initialized with the values from `data` */
+  static from(ctx: Context, data: BigUint64Array | ArrayLike<bigint>, dims?: number[]): ArrayU64D1;
+
+  readonly shape: number[];
+
+  /** Number of elements in the array */
+  readonly length: number;
+
+  /** Copy the values into a new typed array */
+  values(): BigUint64Array;
+
+  /** Free the array, it is also freed when it is garbage collected */
+  free(): void;
+}
+
+/** Array type with 1 dimensions and u8 elements */
+export declare class ArrayU8D1 {
+  /** Create a new array with the shape `dims`, initialized with the values from `data` */
+  static from(ctx: Context, data: Uint8Array | ArrayLike<number>, dims?: number[]): ArrayU8D1;
+
+  readonly shape: number[];
+
+  /** Number of elements in the array */
+  readonly length: number;
+
+  /** Copy the values into a new typed array */
+  values(): Uint8Array;
+
+  /** Free the array, it is also freed when it is garbage collected */
+  free(): void;
+}
+
+export interface Context {
+  /** Entry point: flatten */
+  flatten(input0: ArrayF32D3): ArrayF32D2;
+}
+
+export interface Context {
+  /** Entry point: lengths */
+  lengths(input0: ArrayI8D1, input1: ArrayI16D1, input2: ArrayI32D1, input3: ArrayI64D1, input4: ArrayU8D1, input5: ArrayU16D1, input6: ArrayU32D1, input7: ArrayU64D1, input8: ArrayF16D1, input9: ArrayF32D1, input10: ArrayF64D1, input11: ArrayBoolD1): bigint;
+}
+
+export interface Context {
+  /** Entry point: sum_rows */
+  sumRows(input0: ArrayF32D2): [ArrayF32D1, number];
+}
+
@@ -0,0 +1,1738 @@
+// Code generated by futhark-bindgen. DO NOT EDIT.
+
+package arrays
+
+/*
+#cgo LDFLAGS: -lm -lpthread
+#include <stdlib.h>
+#include "arrays.h"
+*/
+import "C"
+
+import (
+	"errors"
+	"fmt"
+	"runtime"
+	"unsafe"
+)
+
+var (
+	// ErrNullPtr is returned when a Futhark function returns a NULL pointer
+	ErrNullPtr = errors.New("futhark: NULL pointer encountered")
+
+	// ErrInvalidShape is returned when data doesn't match the shape of an array
+	ErrInvalidShape = errors.New("futhark: invalid shape")
+
+	// ErrFreed is returned when a value is used after it has been freed
+	ErrFreed = errors.New("futhark: use after free")
+)
+
+// Error is returned when a Futhark function returns a non-zero error code
+type Error struct {
+	Code    int
+	Message string
+}
+
+func (e *Error) Error() string {
+	return fmt.Sprintf("futhark: error code %d: %s", e.Code, e.Message)
+}
+
+// Options used to configure a Context
+type Options struct {
+	Debug      bool
+	Profile    bool
+	Logging    bool
+	CacheFile  string
+	NoAutoSync bool
+	NumThreads int
+}
+
+// Context is a Futhark context, it is freed by Close or when it is garbage collected
+type Context struct {
+	config    *C.struct_futhark_context_config
+	handle    *C.struct_futhark_context
+	autoSync  bool
+	cacheFile *C.char
+}
+
+func boolToInt(b bool) C.int {
+	if b {
+		return 1
+	}
+	return 0
+}
+
+// NewContext creates a new context, options may be nil
+func NewContext(options *Options) (*Context, error) {
+	if options == nil {
+		options = &Options{}
+	}
+	config := C.futhark_context_config_new()
+	if config == nil {
+		return nil, ErrNullPtr
+	}
+	C.futhark_context_config_set_debugging(config, boolToInt(options.Debug))
+	C.futhark_context_config_set_profiling(config, boolToInt(options.Profile))
+	C.futhark_context_config_set_logging(config, boolToInt(options.Logging))
+	var cacheFile *C.char
+	if options.CacheFile != "" {
+		cacheFile = C.CString(options.CacheFile)
+		C.futhark_context_config_set_cache_file(config, cacheFile)
+	}
+	C.futhark_context_config_set_num_threads(config, C.int(options.NumThreads))
+	handle := C.futhark_context_new(config)
+	if handle == nil {
+		C.futhark_context_config_free(config)
+		C.free(unsafe.Pointer(cacheFile))
+		return nil, ErrNullPtr
+	}
+	ctx := &Context{config: config, handle: handle, autoSync: !options.NoAutoSync, cacheFile: cacheFile}
+	runtime.SetFinalizer(ctx, (*Context).Close)
+	return ctx, nil
+}
+
+// Close frees the context, values created using the context can no longer be used
+func (ctx *Context) Close() {
+	if ctx.handle == nil {
+		return
+	}
+	C.futhark_context_sync(ctx.handle)
+	C.futhark_context_free(ctx.handle)
+	C.futhark_context_config_free(ctx.config)
+	C.free(unsafe.Pointer(ctx.cacheFile))
+	ctx.handle = nil
+	ctx.cacheFile = nil
+	runtime.SetFinalizer(ctx, nil)
+}
+
+func (ctx *Context) error(rc C.int) error {
+	msg, _ := ctx.GetError()
+	return &Error{Code: int(rc), Message: msg}
+}
+
+func (ctx *Context) check() error {
+	if ctx.handle == nil {
+		return ErrFreed
+	}
+	return nil
+}
+
+// Sync waits for all pending operations to finish
+func (ctx *Context) Sync() error {
+	if err := ctx.check(); err != nil {
+		return err
+	}
+	if rc := C.futhark_context_sync(ctx.handle); rc != 0 {
+		return ctx.error(rc)
+	}
+	return nil
+}
+
+func (ctx *Context) maybeSync() error {
+	if ctx.autoSync {
+		return ctx.Sync()
+	}
+	return nil
+}
+
+// ClearCaches frees any cached memory
+func (ctx *Context) ClearCaches() error {
+	if err := ctx.check(); err != nil {
+		return err
+	}
+	if rc := C.futhark_context_clear_caches(ctx.handle); rc != 0 {
+		return ctx.error(rc)
+	}
+	return nil
+}
+
+// PauseProfiling pauses profiling
+func (ctx *Context) PauseProfiling() error {
+	if err := ctx.check(); err != nil {
+		return err
+	}
+	C.futhark_context_pause_profiling(ctx.handle)
+	return nil
+}
+
+// UnpauseProfiling resumes profiling
+func (ctx *Context) UnpauseProfiling() error {
+	if err := ctx.check(); err != nil {
+		return err
+	}
+	C.futhark_context_unpause_profiling(ctx.handle)
+	return nil
+}
+
+func takeString(s *C.char) string {
+	if s == nil {
+		return ""
+	}
+	defer C.free(unsafe.Pointer(s))
+	return C.GoString(s)
+}
+
+// GetError returns the last error message
+func (ctx *Context) GetError() (string, error) {
+	if err := ctx.check(); err != nil {
+		return "", err
+	}
+	return takeString(C.futhark_context_get_error(ctx.handle)), nil
+}
+
+// Report returns the profiling report
+func (ctx *Context) Report() (string, error) {
+	if err := ctx.check(); err != nil {
+		return "", err
+	}
+	return takeString(C.futhark_context_report(ctx.handle)), nil
+}
+
+// ArrayF32D3 is an array with 3 dimensions and f32 elements
+type ArrayF32D3 struct {
+	ptr   *C.struct_futhark_f32_3d
+	shape [3]int64
+	ctx   *Context
+}
+
+// NewArrayF32D3 creates a new array with the shape `dims`, initialized with the values from `data`
+func NewArrayF32D3(ctx *Context, data []float32, dims [3]int64) (*ArrayF32D3, error) {
+	if err := ctx.check(); err != nil {
+		return nil, err
+	}
+	size := int64(1)
+	for _, d := range dims {
+		size *= d
+	}
+	if int64(len(data)) != size {
+		return nil, ErrInvalidShape
+	}
+	var p *C.float
+	if len(data) > 0 {
+		p = (*C.float)(unsafe.Pointer(&data[0]))
+	}
+	ptr := C.futhark_new_f32_3d(ctx.handle, p, C.int64_t(dims[0]), C.int64_t(dims[1]), C.int64_t(dims[2]))
+	if ptr == nil {
+		return nil, ErrNullPtr
+	}
+	if err := ctx.maybeSync(); err != nil {
+		C.futhark_free_f32_3d(ctx.handle, ptr)
+		return nil, err
+	}
+	return newArrayF32D3(ctx, ptr), nil
+}
+
+func newArrayF32D3(ctx *Context, ptr *C.struct_futhark_f32_3d) *ArrayF32D3 {
+	a := &ArrayF32D3{ptr: ptr, ctx: ctx}
+	shape := unsafe.Slice(C.futhark_shape_f32_3d(ctx.handle, ptr), 3)
+	for i := range a.shape {
+		a.shape[i] = int64(shape[i])
+	}
+	runtime.SetFinalizer(a, (*ArrayF32D3).Free)
+	return a
+}
+
+// Free frees the array, it is also freed when it is garbage collected
+func (a *ArrayF32D3) Free() {
+	if a.ptr != nil && a.ctx.handle != nil {
+		C.futhark_free_f32_3d(a.ctx.handle, a.ptr)
+	}
+	a.ptr = nil
+	runtime.SetFinalizer(a, nil)
+}
+
+func (a *ArrayF32D3) check() error {
+	if a.ptr == nil {
+		return ErrFreed
+	}
+	return a.ctx.check()
+}
+
+// Shape returns the shape of the array
+func (a *ArrayF32D3) Shape() []int64 {
+	return a.shape[:]
+}
+
+// Len returns the number of elements in the array
+func (a *ArrayF32D3) Len() int {
+	size := int64(1)
+	for _, d := range a.shape {
+		size *= d
+	}
+	return int(size)
+}
+
+// Values copies the values into `out`, which must have the same number of elements as the array
+func (a *ArrayF32D3) Values(out []float32) error {
+	if err := a.check(); err != nil {
+		return err
+	}
+	if len(out) != a.Len() {
+		return ErrInvalidShape
+	}
+	var p *C.float
+	if len(out) > 0 {
+		p = (*C.float)(unsafe.Pointer(&out[0]))
+	}
+	rc := C.futhark_values_f32_3d(a.ctx.handle, a.ptr, p)
+	runtime.KeepAlive(a)
+	if rc != 0 {
+		return a.ctx.error(rc)
+	}
+	return a.ctx.maybeSync()
+}
+
+// Get copies the values into a new slice
+func (a *ArrayF32D3) Get() ([]float32, error) {
+	out := make([]float32, a.Len())
+	if err := a.Values(out); err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+// ArrayF32D2 is an array with 2 dimensions and f32 elements
+type ArrayF32D2 struct {
+	ptr   *C.struct_futhark_f32_2d
+	shape [2]int64
+	ctx   *Context
+}
+
+// NewArrayF32D2 creates a new array with the shape `dims`, initialized with the values from `data`
+func NewArrayF32D2(ctx *Context, data []float32, dims [2]int64) (*ArrayF32D2, error) {
+	if err := ctx.check(); err != nil {
+		return nil, err
+	}
+	size := int64(1)
+	for _, d := range dims {
+		size *= d
+	}
+	if int64(len(data)) != size {
+		return nil, ErrInvalidShape
+	}
+	var p *C.float
+	if len(data) > 0 {
+		p = (*C.float)(unsafe.Pointer(&data[0]))
+	}
+	ptr := C.futhark_new_f32_2d(ctx.handle, p, C.int64_t(dims[0]), C.int64_t(dims[1]))
+	if ptr == nil {
+		return nil, ErrNullPtr
+	}
+	if err := ctx.maybeSync(); err != nil {
+		C.futhark_free_f32_2d(ctx.handle, ptr)
+		return nil, err
+	}
+	return newArrayF32D2(ctx, ptr), nil
+}
+
+func newArrayF32D2(ctx *Context, ptr *C.struct_futhark_f32_2d) *ArrayF32D2 {
+	a := &ArrayF32D2{ptr: ptr, ctx: ctx}
+	shape := unsafe.Slice(C.futhark_shape_f32_2d(ctx.handle, ptr), 2)
+	for i := range a.shape {
+		a.shape[i] = int64(shape[i])
+	}
+	runtime.SetFinalizer(a, (*ArrayF32D2).Free)
+	return a
+}
+
+// Free frees the array, it is also freed when it is garbage collected
+func (a *ArrayF32D2) Free() {
+	if a.ptr != nil && a.ctx.handle != nil {
+		C.futhark_free_f32_2d(a.ctx.handle, a.ptr)
+	}
+	a.ptr = nil
+	runtime.SetFinalizer(a, nil)
+}
+
+func (a *ArrayF32D2) check() error {
+	if a.ptr == nil {
+		return ErrFreed
+	}
+	return a.ctx.check()
+}
+
+// Shape returns the shape of the array
+func (a *ArrayF32D2) Shape() []int64 {
+	return a.shape[:]
+}
+
+// Len returns the number of elements in the array
+func (a *ArrayF32D2) Len() int {
+	size := int64(1)
+	for _, d := range a.shape {
+		size *= d
+	}
+	return int(size)
+}
+
+// Values copies the values into `out`, which must have the same number of elements as the array
+func (a *ArrayF32D2) Values(out []float32) error {
+	if err := a.check(); err != nil {
+		return err
+	}
+	if len(out) != a.Len() {
+		return ErrInvalidShape
+	}
+	var p *C.float
+	if len(out) > 0 {
+		p = (*C.float)(unsafe.Pointer(&out[0]))
+	}
+	rc := C.futhark_values_f32_2d(a.ctx.handle, a.ptr, p)
+	runtime.KeepAlive(a)
+	if rc != 0 {
+		return a.ctx.error(rc)
+	}
+	return a.ctx.maybeSync()
+}
+
+// Get copies the values into a new slice
+func (a *ArrayF32D2) Get() ([]float32, error) {
+	out := make([]float32, a.Len())
+	if err := a.Values(out); err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+// ArrayBoolD1 is an array with 1 dimensions and bool elements
+type ArrayBoolD1 struct {
+	ptr   *C.struct_futhark_bool_1d
+	shape [1]int64
+	ctx   *Context
+}
+
+// NewArrayBoolD1 creates a new array with the shape `dims`, initialized with the values from `data`
+func NewArrayBoolD1(ctx *Context, data []bool, dims [1]int64) (*ArrayBoolD1, error) {
+	if err := ctx.check(); err != nil {
+		return nil, err
+	}
+	size := int64(1)
+	for _, d := range dims {
+		size *= d
+	}
+	if int64(len(data)) != size {
+		return nil, ErrInvalidShape
+	}
+	var p *C.bool
+	if len(data) > 0 {
+		p = (*C.bool)(unsafe.Pointer(&data[0]))
+	}
+	ptr := C.futhark_new_bool_1d(ctx.handle, p, C.int64_t(dims[0]))
+	if ptr == nil {
+		return nil, ErrNullPtr
+	}
+	if err := ctx.maybeSync(); err != nil {
+		C.futhark_free_bool_1d(ctx.handle, ptr)
+		return nil, err
+	}
+	return newArrayBoolD1(ctx, ptr), nil
+}
+
+func newArrayBoolD1(ctx *Context, ptr *C.struct_futhark_bool_1d) *ArrayBoolD1 {
+	a := &ArrayBoolD1{ptr: ptr, ctx: ctx}
+	shape := unsafe.Slice(C.futhark_shape_bool_1d(ctx.handle, ptr), 1)
+	for i := range a.shape {
+		a.shape[i] = int64(shape[i])
+	}
+	runtime.SetFinalizer(a, (*ArrayBoolD1).Free)
+	return a
+}
+
+// Free frees the array, it is also freed when it is garbage collected
+func (a *ArrayBoolD1) Free() {
+	if a.ptr != nil && a.ctx.handle != nil {
+		C.futhark_free_bool_1d(a.ctx.handle, a.ptr)
+	}
+	a.ptr = nil
+	runtime.SetFinalizer(a, nil)
+}
+
+func (a *ArrayBoolD1) check() error {
+	if a.ptr == nil {
+		return ErrFreed
+	}
+	return a.ctx.check()
+}
+
+// Shape returns the shape of the array
+func (a *ArrayBoolD1) Shape() []int64 {
+	return a.shape[:]
+}
+
+// Len returns the number of elements in the array
+func (a *ArrayBoolD1) Len() int {
+	size := int64(1)
+	for _, d := range a.shape {
+		size *= d
+	}
+	return int(size)
+}
+
+// Values copies the values into `out`, which must have the same number of elements as the array
+func (a *ArrayBoolD1) Values(out []bool) error {
+	if err := a.check(); err != nil {
+		return err
+	}
+	if len(out) != a.Len() {
+		return ErrInvalidShape
+	}
+	var p *C.bool
+	if len(out) > 0 {
+		p = (*C.bool)(unsafe.Pointer(&out[0]))
+	}
+	rc := C.futhark_values_bool_1d(a.ctx.handle, a.ptr, p)
+	runtime.KeepAlive(a)
+	if rc != 0 {
+		return a.ctx.error(rc)
+	}
+	return a.ctx.maybeSync()
+}
+
+// Get copies the values into a new slice
+func (a *ArrayBoolD1) Get() ([]bool, error) {
+	out := make([]bool, a.Len())
+	if err := a.Values(out); err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+// ArrayF16D1 is an array with 1 dimensions and f16 elements
+type ArrayF16D1 struct {
+	ptr   *C.struct_futhark_f16_1d
+	shape [1]int64
+	ctx   *Context
+}
+
+// NewArrayF16D1 creates a new array with the shape `dims`, initialized with the values from `data`
+func NewArrayF16D1(ctx *Context, data []uint16, dims [1]int64) (*ArrayF16D1, error) {
+	if err := ctx.check(); err != nil {
+		return nil, err
+	}
+	size := int64(1)
+	for _, d := range dims {
+		size *= d
+	}
+	if int64(len(data)) != size {
+		return nil, ErrInvalidShape
+	}
+	var p *C.uint16_t
+	if len(data) > 0 {
+		p = (*C.uint16_t)(unsafe.Pointer(&data[0]))
+	}
+	ptr := C.futhark_new_f16_1d(ctx.handle, p, C.int64_t(dims[0]))
+	if ptr == nil {
+		return nil, ErrNullPtr
+	}
+	if err := ctx.maybeSync(); err != nil {
+		C.futhark_free_f16_1d(ctx.handle, ptr)
+		return nil, err
+	}
+	return newArrayF16D1(ctx, ptr), nil
+}
+
+func newArrayF16D1(ctx *Context, ptr *C.struct_futhark_f16_1d) *ArrayF16D1 {
+	a := &ArrayF16D1{ptr: ptr, ctx: ctx}
+	shape := unsafe.Slice(C.futhark_shape_f16_1d(ctx.handle, ptr), 1)
+	for i := range a.shape {
+		a.shape[i] = int64(shape[i])
+	}
+	runtime.SetFinalizer(a, (*ArrayF16D1).Free)
+	return a
+}
+
+// Free frees the array, it is also freed when it is garbage collected
+func (a *ArrayF16D1) Free() {
+	if a.ptr != nil && a.ctx.handle != nil {
+		C.futhark_free_f16_1d(a.ctx.handle, a.ptr)
+	}
+	a.ptr = nil
+	runtime.SetFinalizer(a, nil)
+}
+
+func (a *ArrayF16D1) check() error {
+	if a.ptr == nil {
+		return ErrFreed
+	}
+	return a.ctx.check()
+}
+
+// Shape returns the shape of the array
+func (a *ArrayF16D1) Shape() []int64 {
+	return a.shape[:]
+}
+
+// Len returns the number of elements in the array
+func (a *ArrayF16D1) Len() int {
+	size := int64(1)
+	for _, d := range a.shape {
+		size *= d
+	}
+	return int(size)
+}
+
+// Values copies the values into `out`, which must have the same number of elements as the array
+func (a *ArrayF16D1) Values(out []uint16) error {
+	if err := a.check(); err != nil {
+		return err
+	}
+	if len(out) != a.Len() {
+		return ErrInvalidShape
+	}
+	var p *C.uint16_t
+	if len(out) > 0 {
+		p = (*C.uint16_t)(unsafe.Pointer(&out[0]))
+	}
+	rc := C.futhark_values_f16_1d(a.ctx.handle, a.ptr, p)
+	runtime.KeepAlive(a)
+	if rc != 0 {
+		return a.ctx.error(rc)
+	}
+	return a.ctx.maybeSync()
+}
+
+// Get copies the values into a new slice
+func (a *ArrayF16D1) Get() ([]uint16, error) {
+	out := make([]uint16, a.Len())
+	if err := a.Values(out); err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+// ArrayF32D1 is an array with 1 dimensions and f32 elements
+type ArrayF32D1 struct {
+	ptr   *C.struct_futhark_f32_1d
+	shape [1]int64
+	ctx   *Context
+}
+
+// NewArrayF32D1 creates a new array with the shape `dims`, initialized with the values from `data`
+func NewArrayF32D1(ctx *Context, data []float32, dims [1]int64) (*ArrayF32D1, error) {
+	if err := ctx.check(); err != nil {
+		return nil, err
+	}
+	size := int64(1)
+	for _, d := range dims {
+		size *= d
+	}
+	if int64(len(data)) != size {
+		return nil, ErrInvalidShape
+	}
+	var p *C.float
+	if len(data) > 0 {
+		p = (*C.float)(unsafe.Pointer(&data[0]))
+	}
+	ptr := C.futhark_new_f32_1d(ctx.handle, p, C.int64_t(dims[0]))
+	if ptr == nil {
+		return nil, ErrNullPtr
+	}
+	if err := ctx.maybeSync(); err != nil {
+		C.futhark_free_f32_1d(ctx.handle, ptr)
+		return nil, err
+	}
+	return newArrayF32D1(ctx, ptr), nil
+}
+
+func newArrayF32D1(ctx *Context, ptr *C.struct_futhark_f32_1d) *ArrayF32D1 {
+	a := &ArrayF32D1{ptr: ptr, ctx: ctx}
+	shape := unsafe.Slice(C.futhark_shape_f32_1d(ctx.handle, ptr), 1)
+	for i := range a.shape {
+		a.shape[i] = int64(shape[i])
+	}
+	runtime.SetFinalizer(a, (*ArrayF32D1).Free)
+	return a
+}
+
+// Free frees the array, it is also freed when it is garbage collected
+func (a *ArrayF32D1) Free() {
+	if a.ptr != nil && a.ctx.handle != nil {
+		C.futhark_free_f32_1d(a.ctx.handle, a.ptr)
+	}
+	a.ptr = nil
+	runtime.SetFinalizer(a, nil)
+}
+
+func (a *ArrayF32D1) check() error {
+	if a.ptr == nil {
+		return ErrFreed
+	}
+	return a.ctx.check()
+}
+
+// Shape returns the shape of the array
+func (a *ArrayF32D1) Shape() []int64 {
+	return a.shape[:]
+}
+
+// Len returns the number of elements in the array
+func (a *ArrayF32D1) Len() int {
+	size := int64(1)
+	for _, d := range a.shape {
+		size *= d
+	}
+	return int(size)
+}
+
+// Values copies the values into `out`, which must have the same number of elements as the array
+func (a *ArrayF32D1) Values(out []float32) error {
+	if err := a.check(); err != nil {
+		return err
+	}
+	if len(out) != a.Len() {
+		return ErrInvalidShape
+	}
+	var p *C.float
+	if len(out) > 0 {
+		p = (*C.float)(unsafe.Pointer(&out[0]))
+	}
+	rc := C.futhark_values_f32_1d(a.ctx.handle, a.ptr, p)
+	runtime.KeepAlive(a)
+	if rc != 0 {
+		return a.ctx.error(rc)
+	}
+	return a.ctx.maybeSync()
+}
+
+// Get copies the values into a new slice
+func (a *ArrayF32D1) Get() ([]float32, error) {
+	out := make([]float32, a.Len())
+	if err := a.Values(out); err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+// ArrayF64D1 is an array with 1 dimensions and f64 elements
+type ArrayF64D1 struct {
+	ptr   *C.struct_futhark_f64_1d
+	shape [1]int64
+	ctx   *Context
+}
+
+// NewArrayF64D1 creates a new array with the shape `dims`, initialized with the values from `data`
+func NewArrayF64D1(ctx *Context, data []float64, dims [1]int64) (*ArrayF64D1, error) {
+	if err := ctx.check(); err != nil {
+		return nil, err
+	}
+	size := int64(1)
+	for _, d := range dims {
+		size *= d
+	}
+	if int64(len(data)) != size {
+		return nil, ErrInvalidShape
+	}
+	var p *C.double
+	if len(data) > 0 {
+		p = (*C.double)(unsafe.Pointer(&data[0]))
+	}
+	ptr := C.futhark_new_f64_1d(ctx.handle, p, C.int64_t(dims[0]))
+	if ptr == nil {
+		return nil, ErrNullPtr
+	}
+	if err := ctx.maybeSync(); err != nil {
+		C.futhark_free_f64_1d(ctx.handle, ptr)
+		return nil, err
+	}
+	return newArrayF64D1(ctx, ptr), nil
+}
+
+func newArrayF64D1(ctx *Context, ptr *C.struct_futhark_f64_1d) *ArrayF64D1 {
+	a := &ArrayF64D1{ptr: ptr, ctx: ctx}
+	shape := unsafe.Slice(C.futhark_shape_f64_1d(ctx.handle, ptr), 1)
+	for i := range a.shape {
+		a.shape[i] = int64(shape[i])
+	}
+	runtime.SetFinalizer(a, (*ArrayF64D1).Free)
+	return a
+}
+
+// Free frees the array, it is also freed when it is garbage collected
+func (a *ArrayF64D1) Free() {
+	if a.ptr != nil && a.ctx.handle != nil {
+		C.futhark_free_f64_1d(a.ctx.handle, a.ptr)
+	}
+	a.ptr = nil
+	runtime.SetFinalizer(a, nil)
+}
+
+func (a *ArrayF64D1) check() error {
+	if a.ptr == nil {
+		return ErrFreed
+	}
+	return a.ctx.check()
+}
+
+// Shape returns the shape of the array
+func (a *ArrayF64D1) Shape() []int64 {
+	return a.shape[:]
+}
+
+// Len returns the number of elements in the array
+func (a *ArrayF64D1) Len() int {
+	size := int64(1)
+	for _, d := range a.shape {
+		size *= d
+	}
+	return int(size)
+}
+
+// Values copies the values into `out`, which must have the same number of elements as the array
+func (a *ArrayF64D1) Values(out []float64) error {
+	if err := a.check(); err != nil {
+		return err
+	}
+	if len(out) != a.Len() {
+		return ErrInvalidShape
+	}
+	var p *C.double
+	if len(out) > 0 {
+		p = (*C.double)(unsafe.Pointer(&out[0]))
+	}
+	rc := C.futhark_values_f64_1d(a.ctx.handle, a.ptr, p)
+	runtime.KeepAlive(a)
+	if rc != 0 {
+		return a.ctx.error(rc)
+	}
+	return a.ctx.maybeSync()
+}
+
+// Get copies the values into a new slice
+func (a *ArrayF64D1) Get() ([]float64, error) {
+	out := make([]float64, a.Len())
+	if err := a.Values(out); err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+// ArrayI16D1 is an array with 1 dimensions and i16 elements
+type ArrayI16D1 struct {
+	ptr   *C.struct_futhark_i16_1d
+	shape [1]int64
+	ctx   *Context
+}
+
+// NewArrayI16D1 creates a new array with the shape `dims`, initialized with the values from `data`
+func NewArrayI16D1(ctx *Context, data []int16, dims [1]int64) (*ArrayI16D1, error) {
+	if err := ctx.check(); err != nil {
+		return nil, err
+	}
+	size := int64(1)
+	for _, d := range dims {
+		size *= d
+	}
+	if int64(len(data)) != size {
+		return nil, ErrInvalidShape
+	}
+	var p *C.int16_t
+	if len(data) > 0 {
+		p = (*C.int16_t)(unsafe.Pointer(&data[0]))
+	}
+	ptr := C.futhark_new_i16_1d(ctx.handle, p, C.int64_t(dims[0]))
+	if ptr == nil {
+		return nil, ErrNullPtr
+	}
+	if err := ctx.maybeSync(); err != nil {
+		C.futhark_free_i16_1d(ctx.handle, ptr)
+		return nil, err
+	}
+	return newArrayI16D1(ctx, ptr), nil
+}
+
+func newArrayI16D1(ctx *Context, ptr *C.struct_futhark_i16_1d) *ArrayI16D1 {
+	a := &ArrayI16D1{ptr: ptr, ctx: ctx}
+	shape := unsafe.Slice(C.futhark_shape_i16_1d(ctx.handle, ptr), 1)
+	for i := range a.shape {
+		a.shape[i] = int64(shape[i])
+	}
+	runtime.SetFinalizer(a, (*ArrayI16D1).Free)
+	return a
+}
+
+// Free frees the array, it is also freed when it is garbage collected
+func (a *ArrayI16D1) Free() {
+	if a.ptr != nil && a.ctx.handle != nil {
+		C.futhark_free_i16_1d(a.ctx.handle, a.ptr)
+	}
+	a.ptr = nil
+	runtime.SetFinalizer(a, nil)
+}
+
+func (a *ArrayI16D1) check() error {
+	if a.ptr == nil {
+		return ErrFreed
+	}
+	return a.ctx.check()
+}
+
+// Shape returns the shape of the array
+func (a *ArrayI16D1) Shape() []int64 {
+	return a.shape[:]
+}
+
+// Len returns the number of elements in the array
+func (a *ArrayI16D1) Len() int {
+	size := int64(1)
+	for _, d := range a.shape {
+		size *= d
+	}
+	return int(size)
+}
+
+// Values copies the values into `out`, which must have the same number of elements as the array
+func (a *ArrayI16D1) Values(out []int16) error {
+	if err := a.check(); err != nil {
+		return err
+	}
+	if len(out) != a.Len() {
+		return ErrInvalidShape
+	}
+	var p *C.int16_t
+	if len(out) > 0 {
+		p = (*C.int16_t)(unsafe.Pointer(&out[0]))
+	}
+	rc := C.futhark_values_i16_1d(a.ctx.handle, a.ptr, p)
+	runtime.KeepAlive(a)
+	if rc != 0 {
+		return a.ctx.error(rc)
+	}
+	return a.ctx.maybeSync()
+}
+
+// Get copies the values into a new slice
+func (a *ArrayI16D1) Get() ([]int16, error) {
+	out := make([]int16, a.Len())
+	if err := a.Values(out); err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+// ArrayI32D1 is an array with 1 dimensions and i32 elements
+type ArrayI32D1 struct {
+	ptr   *C.struct_futhark_i32_1d
+	shape [1]int64
+	ctx   *Context
+}
+
+// NewArrayI32D1 creates a new array with the shape `dims`, initialized with the values from `data`
+func NewArrayI32D1(ctx *Context, data []int32, dims [1]int64) (*ArrayI32D1, error) {
+	if err := ctx.check(); err != nil {
+		return nil, err
+	}
+	size := int64(1)
+	for _, d := range dims {
+		size *= d
+	}
+	if int64(len(data)) != size {
+		return nil, ErrInvalidShape
+	}
+	var p *C.int32_t
+	if len(data) > 0 {
+		p = (*C.int32_t)(unsafe.Pointer(&data[0]))
+	}
+	ptr := C.futhark_new_i32_1d(ctx.handle, p, C.int64_t(dims[0]))
+	if ptr == nil {
+		return nil, ErrNullPtr
+	}
+	if err := ctx.maybeSync(); err != nil {
+		C.futhark_free_i32_1d(ctx.handle, ptr)
+		return nil, err
+	}
+	return newArrayI32D1(ctx, ptr), nil
+}
+
+func newArrayI32D1(ctx *Context, ptr *C.struct_futhark_i32_1d) *ArrayI32D1 {
+	a := &ArrayI32D1{ptr: ptr, ctx: ctx}
+	shape := unsafe.Slice(C.futhark_shape_i32_1d(ctx.handle, ptr), 1)
+	for i := range a.shape {
+		a.shape[i] = int64(shape[i])
+	}
+	runtime.SetFinalizer(a, (*ArrayI32D1).Free)
+	return a
+}
+
+// Free frees the array, it is also freed when it is garbage collected
+func (a *ArrayI32D1) Free() {
+	if a.ptr != nil && a.ctx.handle != nil {
+		C.futhark_free_i32_1d(a.ctx.handle, a.ptr)
+	}
+	a.ptr = nil
+	runtime.SetFinalizer(a, nil)
+}
+
+func (a *ArrayI32D1) check() error {
+	if a.ptr == nil {
+		return ErrFreed
+	}
+	return a.ctx.check()
+}
+
+// Shape returns the shape of the array
+func (a *ArrayI32D1) Shape() []int64 {
+	return a.shape[:]
+}
+
+// Len returns the number of elements in the array
+func (a *ArrayI32D1) Len() int {
+	size := int64(1)
+	for _, d := range a.shape {
+		size *= d
+	}
+	return int(size)
+}
+
+// Values copies the values into `out`, which must have the same number of elements as the array
+func (a *ArrayI32D1) Values(out []int32) error {
+	if err := a.check(); err != nil {
+		return err
+	}
+	if len(out) != a.Len() {
+		return ErrInvalidShape
+	}
+	var p *C.int32_t
+	if len(out) > 0 {
+		p = (*C.int32_t)(unsafe.Pointer(&out[0]))
+	}
+	rc := C.futhark_values_i32_1d(a.ctx.handle, a.ptr, p)
+	runtime.KeepAlive(a)
+	if rc != 0 {
+		return a.ctx.error(rc)
+	}
+	return a.ctx.maybeSync()
+}
+
+// Get copies the values into a new slice
+func (a *ArrayI32D1) Get() ([]int32, error) {
+	out := make([]int32, a.Len())
+	if err := a.Values(out); err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+// ArrayI64D1 is an array with 1 dimensions and i64 elements
+type ArrayI64D1 struct {
+	ptr   *C.struct_futhark_i64_1d
+	shape [1]int64
+	ctx   *Context
+}
+
+// NewArrayI64D1 creates a new array with the shape `dims`, initialized with the values from `data`
+func NewArrayI64D1(ctx *Context, data []int64, dims [1]int64) (*ArrayI64D1, error) {
+	if err := ctx.check(); err != nil {
+		return nil, err
+	}
+	size := int64(1)
+	for _, d := range dims {
+		size *= d
+	}
+	if int64(len(data)) != size {
+		return nil, ErrInvalidShape
+	}
+	var p *C.int64_t
+	if len(data) > 0 {
+		p = (*C.int64_t)(unsafe.Pointer(&data[0]))
+	}
+	ptr := C.futhark_new_i64_1d(ctx.handle, p, C.int64_t(dims[0]))
+	if ptr == nil {
+		return nil, ErrNullPtr
+	}
+	if err := ctx.maybeSync(); err != nil {
+		C.futhark_free_i64_1d(ctx.handle, ptr)
+		return nil, err
+	}
+	return newArrayI64D1(ctx, ptr), nil
+}
+
+func newArrayI64D1(ctx *Context, ptr *C.struct_futhark_i64_1d) *ArrayI64D1 {
+	a := &ArrayI64D1{ptr: ptr, ctx: ctx}
+	shape := unsafe.Slice(C.futhark_shape_i64_1d(ctx.handle, ptr), 1)
+	for i := range a.shape {
+		a.shape[i] = int64(shape[i])
+	}
+	runtime.SetFinalizer(a, (*ArrayI64D1).Free)
+	return a
+}
+
+// Free frees the array, it is also freed when it is garbage collected
+func (a *ArrayI64D1) Free() {
+	if a.ptr != nil && a.ctx.handle != nil {
+		C.futhark_free_i64_1d(a.ctx.handle, a.ptr)
+	}
+	a.ptr = nil
+	runtime.SetFinalizer(a, nil)
+}
+
+func (a *ArrayI64D1) check() error {
+	if a.ptr == nil {
+		return ErrFreed
+	}
+	return a.ctx.check()
+}
+
+// Shape returns the shape of the array
+func (a *ArrayI64D1) Shape() []int64 {
+	return a.shape[:]
+}
+
+// Len returns the number of elements in the array
+func (a *ArrayI64D1) Len() int {
+	size := int64(1)
+	for _, d := range a.shape {
+		size *= d
+	}
+	return int(size)
+}
+
+// Values copies the values into `out`, which must have the same number of elements as the array
+func (a *ArrayI64D1) Values(out []int64) error {
+	if err := a.check(); err != nil {
+		return err
+	}
+	if len(out) != a.Len() {
+		return ErrInvalidShape
+	}
+	var p *C.int64_t
+	if len(out) > 0 {
+		p = (*C.int64_t)(unsafe.Pointer(&out[0]))
+	}
+	rc := C.futhark_values_i64_1d(a.ctx.handle, a.ptr, p)
+	runtime.KeepAlive(a)
+	if rc != 0 {
+		return a.ctx.error(rc)
+	}
+	return a.ctx.maybeSync()
+}
+
+// Get copies the values into a new slice
+func (a *ArrayI64D1) Get() ([]int64, error) {
+	out := make([]int64, a.Len())
+	if err := a.Values(out); err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+// ArrayI8D1 is an array with 1 dimensions and i8 elements
+type ArrayI8D1 struct {
+	ptr   *C.struct_futhark_i8_1d
+	shape [1]int64
+	ctx   *Context
+}
+
+// NewArrayI8D1 creates a new array with the shape `dims`, initialized with the values from `data`
+func NewArrayI8D1(ctx *Context, data []int8, dims [1]int64) (*ArrayI8D1, error) {
+	if err := ctx.check(); err != nil {
+		return nil, err
+	}
+	size := int64(1)
+	for _, d := range dims {
+		size *= d
+	}
+	if int64(len(data)) != size {
+		return nil, ErrInvalidShape
+	}
+	var p *C.int8_t
+	if len(data) > 0 {
+		p = (*C.int8_t)(unsafe.Pointer(&data[0]))
+	}
+	ptr := C.futhark_new_i8_1d(ctx.handle, p, C.int64_t(dims[0]))
+	if ptr == nil {
+		return nil, ErrNullPtr
+	}
+	if err := ctx.maybeSync(); err != nil {
+		C.futhark_free_i8_1d(ctx.handle, ptr)
+		return nil, err
+	}
+	return newArrayI8D1(ctx, ptr), nil
+}
+
+func newArrayI8D1(ctx *Context, ptr *C.struct_futhark_i8_1d) *ArrayI8D1 {
+	a := &ArrayI8D1{ptr: ptr, ctx: ctx}
+	shape := unsafe.Slice(C.futhark_shape_i8_1d(ctx.handle, ptr), 1)
+	for i := range a.shape {
+		a.shape[i] = int64(shape[i])
+	}
+	runtime.SetFinalizer(a, (*ArrayI8D1).Free)
+	return a
+}
+
+// Free frees the array, it is also freed when it is garbage collected
+func (a *ArrayI8D1) Free() {
+	if a.ptr != nil && a.ctx.handle != nil {
+		C.futhark_free_i8_1d(a.ctx.handle, a.ptr)
+	}
+	a.ptr = nil
+	runtime.SetFinalizer(a, nil)
+}
+
+func (a *ArrayI8D1) check() error {
+	if a.ptr == nil {
+		return ErrFreed
+	}
+	return a.ctx.check()
+}
+
+// Shape returns the shape of the array
+func (a *ArrayI8D1) Shape() []int64 {
+	return a.shape[:]
+}
+
+// Len returns the number of elements in the array
+func (a *ArrayI8D1) Len() int {
+	size := int64(1)
+	for _, d := range a.shape {
+		size *= d
+	}
+	return int(size)
+}
+
+// Values copies the values into `out`, which must have the same number of elements as the array
+func (a *ArrayI8D1) Values(out []int8) error {
+	if err := a.check(); err != nil {
+		return err
+	}
+	if len(out) != a.Len() {
+		return ErrInvalidShape
+	}
+	var p *C.int8_t
+	if len(out) > 0 {
+		p = (*C.int8_t)(unsafe.Pointer(&out[0]))
+	}
+	rc := C.futhark_values_i8_1d(a.ctx.handle, a.ptr, p)
+	runtime.KeepAlive(a)
+	if rc != 0 {
+		return a.ctx.error(rc)
+	}
+	return a.ctx.maybeSync()
+}
+
+// Get copies the values into a new slice
+func (a *ArrayI8D1) Get() ([]int8, error) {
+	out := make([]int8, a.Len())
+	if err := a.Values(out); err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+// ArrayU16D1 is an array with 1 dimensions and u16 elements
+type ArrayU16D1 struct {
+	ptr   *C.struct_futhark_u16_1d
+	shape [1]int64
+	ctx   *Context
+}
+
+// NewArrayU16D1 creates a new array with the shape `dims`, initialized with the values from `data`
+func NewArrayU16D1(ctx *Context, data []uint16, dims [1]int64) (*ArrayU16D1, error) {
+	if err := ctx.check(); err != nil {
+		return nil, err
+	}
+	size := int64(1)
+	for _, d := range dims {
+		size *= d
+	}
+	if int64(len(data)) != size {
+		return nil, ErrInvalidShape
+	}
+	var p *C.uint16_t
+	if len(data) > 0 {
+		p = (*C.uint16_t)(unsafe.Pointer(&data[0]))
+	}
+	ptr := C.futhark_new_u16_1d(ctx.handle, p, C.int64_t(dims[0]))
+	if ptr == nil {
+		return nil, ErrNullPtr
+	}
+	if err := ctx.maybeSync(); err != nil {
+		C.futhark_free_u16_1d(ctx.handle, ptr)
+		return nil, err
+	}
+	return newArrayU16D1(ctx, ptr), nil
+}
+
+func newArrayU16D1(ctx *Context, ptr *C.struct_futhark_u16_1d) *ArrayU16D1 {
+	a := &ArrayU16D1{ptr: ptr, ctx: ctx}
+	shape := unsafe.Slice(C.futhark_shape_u16_1d(ctx.handle, ptr), 1)
+	for i := range a.shape {
+		a.shape[i] = int64(shape[i])
+	}
+	runtime.SetFinalizer(a, (*ArrayU16D1).Free)
+	return a
+}
+
+// Free frees the array, it is also freed when it is garbage collected
+func (a *ArrayU16D1) Free() {
+	if a.ptr != nil && a.ctx.handle != nil {
+		C.futhark_free_u16_1d(a.ctx.handle, a.ptr)
+	}
+	a.ptr = nil
+	runtime.SetFinalizer(a, nil)
+}
+
+func (a *ArrayU16D1) check() error {
+	if a.ptr == nil {
+		return ErrFreed
+	}
+	return a.ctx.check()
+}
+
+// Shape returns the shape of the array
+func (a *ArrayU16D1) Shape() []int64 {
+	return a.shape[:]
+}
+
+// Len returns the number of elements in the array
+func (a *ArrayU16D1) Len() int {
+	size := int64(1)
+	for _, d := range a.shape {
+		size *= d
+	}
+	return int(size)
+}
+
+// Values copies the values into `out`, which must have the same number of elements as the array
+func (a *ArrayU16D1) Values(out []uint16) error {
+	if err := a.check(); err != nil {
+		return err
+	}
+	if len(out) != a.Len() {
+		return ErrInvalidShape
+	}
+	var p *C.uint16_t
+	if len(out) > 0 {
+		p = (*C.uint16_t)(unsafe.Pointer(&out[0]))
+	}
+	rc := C.futhark_values_u16_1d(a.ctx.handle, a.ptr, p)
+	runtime.KeepAlive(a)
+	if rc != 0 {
+		return a.ctx.error(rc)
+	}
+	return a.ctx.maybeSync()
+}
+
+// Get copies the values into a new slice
+func (a *ArrayU16D1) Get() ([]uint16, error) {
+	out := make([]uint16, a.Len())
+	if err := a.Values(out); err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+// ArrayU32D1 is an array with 1 dimensions and u32 elements
+type ArrayU32D1 struct {
+	ptr   *C.struct_futhark_u32_1d
+	shape [1]int64
+	ctx   *Context
+}
+
+// NewArrayU32D1 creates a new array with the shape `dims`, initialized with the values from `data`
+func NewArrayU32D1(ctx *Context, data []uint32, dims [1]int64) (*ArrayU32D1, error) {
+	if err := ctx.check(); err != nil {
+		return nil, err
+	}
+	size := int64(1)
+	for _, d := range dims {
+		size *= d
+	}
+	if int64(len(data)) != size {
+		return nil, ErrInvalidShape
+	}
+	var p *C.uint32_t
+	if len(data) > 0 {
+		p = (*C.uint32_t)(unsafe.Pointer(&data[0]))
+	}
+	ptr := C.futhark_new_u32_1d(ctx.handle, p, C.int64_t(dims[0]))
+	if ptr == nil {
+		return nil, ErrNullPtr
+	}
+	if err := ctx.maybeSync(); err != nil {
+		C.futhark_free_u32_1d(ctx.handle, ptr)
+		return nil, err
+	}
+	return newArrayU32D1(ctx, ptr), nil
+}
+
+func newArrayU32D1(ctx *Context, ptr *C.struct_futhark_u32_1d) *ArrayU32D1 {
+	a := &ArrayU32D1{ptr: ptr, ctx: ctx}
+	shape := unsafe.Slice(C.futhark_shape_u32_1d(ctx.handle, ptr), 1)
+	for i := range a.shape {
+		a.shape[i] = int64(shape[i])
+	}
+	runtime.SetFinalizer(a, (*ArrayU32D1).Free)
+	return a
+}
+
+// Free frees the array, it is also freed when it is garbage collected
+func (a *ArrayU32D1) Free() {
+	if a.ptr != nil && a.ctx.handle != nil {
+		C.futhark_free_u32_1d(a.ctx.handle, a.ptr)
+	}
+	a.ptr = nil
+	runtime.SetFinalizer(a, nil)
+}
+
+func (a *ArrayU32D1) check() error {
+	if a.ptr == nil {
+		return ErrFreed
+	}
+	return a.ctx.check()
+}
+
+// Shape returns the shape of the array
+func (a *ArrayU32D1) Shape() []int64 {
+	return a.shape[:]
+}
+
+// Len returns the number of elements in the array
+func (a *ArrayU32D1) Len() int {
+	size := int64(1)
+	for _, d := range a.shape {
+		size *= d
+	}
+	return int(size)
+}
+
+// Values copies the values into `out`, which must have the same number of elements as the array
+func (a *ArrayU32D1) Values(out []uint32) error {
+	if err := a.check(); err != nil {
+		return err
+	}
+	if len(out) != a.Len() {
+		return ErrInvalidShape
+	}
+	var p *C.uint32_t
+	if len(out) > 0 {
+		p = (*C.uint32_t)(unsafe.Pointer(&out[0]))
+	}
+	rc := C.futhark_values_u32_1d(a.ctx.handle, a.ptr, p)
+	runtime.KeepAlive(a)
+	if rc != 0 {
+		return a.ctx.error(rc)
+	}
+	return a.ctx.maybeSync()
+}
+
+// Get copies the values into a new slice
+func (a *ArrayU32D1) Get() ([]uint32, error) {
+	out := make([]uint32, a.Len())
+	if err := a.Values(out); err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+// ArrayU64D1 is an array with 1 dimensions and u64 elements
+type ArrayU64D1 struct {
+	ptr   *C.struct_futhark_u64_1d
+	shape [1]int64
+	ctx   *Context
+}
+
+// NewArrayU64D1 creates a new array with the shape `dims`, initialized with the values from `data`
+func NewArrayU64D1(ctx *Context, data []uint64, dims [1]int64) (*ArrayU64D1, error) {
+	if err := ctx.check(); err != nil {
+		return nil, err
+	}
+	size := int64(1)
+	for _, d := range dims {
+		size *= d
+	}
+	if int64(len(data)) != size {
+		return nil, ErrInvalidShape
+	}
+	var p *C.uint64_t
+	if len(data) > 0 {
+		p = (*C.uint64_t)(unsafe.Pointer(&data[0]))
+	}
+	ptr := C.futhark_new_u64_1d(ctx.handle, p, C.int64_t(dims[0]))
+	if ptr == nil {
+		return nil, ErrNullPtr
+	}
+	if err := ctx.maybeSync(); err != nil {
+		C.futhark_free_u64_1d(ctx.handle, ptr)
+		return nil, err
+	}
+	return newArrayU64D1(ctx, ptr), nil
+}
+
+func newArrayU64D1(ctx *Context, ptr *C.struct_futhark_u64_1d) *ArrayU64D1 {
+	a := &ArrayU64D1{ptr: ptr, ctx: ctx}
+	shape := unsafe.Slice(C.futhark_shape_u64_1d(ctx.handle, ptr), 1)
+	for i := range a.shape {
+		a.shape[i] = int64(shape[i])
+	}
+	runtime.SetFinalizer(a, (*ArrayU64D1).Free)
+	return a
+}
+
+// Free frees the array, it is also freed when it is garbage collected
+func (a *ArrayU64D1) Free() {
+	if a.ptr != nil && a.ctx.handle != nil {
+		C.futhark_free_u64_1d(a.ctx.handle, a.ptr)
+	}
+	a.ptr = nil
+	runtime.SetFinalizer(a, nil)
+}
+
+func (a *ArrayU64D1) check() error {
+	if a.ptr == nil {
+		return ErrFreed
+	}
+	return a.ctx.check()
+}
+
+// Shape returns the shape of the array
+func (a *ArrayU64D1) Shape() []int64 {
+	return a.shape[:]
+}
+
+// Len returns the number of elements in the array
+func (a *ArrayU64D1) Len() int {
+	size := int64(1)
+	for _, d := range a.shape {
+		size *= d
+	}
+	return int(size)
+}
+
+// Values copies the values into `out`, which must have the same number of elements as the array
+func (a *ArrayU64D1) Values(out []uint64) error {
+	if err := a.check(); err != nil {
+		return err
+	}
+	if len(out) != a.Len() {
+		return ErrInvalidShape
+	}
+	var p *C.uint64_t
+	if len(out) > 0 {
+		p = (*C.uint64_t)(unsafe.Pointer(&out[0]))
+	}
+	rc := C.futhark_values_u64_1d(a.ctx.handle, a.ptr, p)
+	runtime.KeepAlive(a)
+	if rc != 0 {
+		return a.ctx.error(rc)
+	}
+	return a.ctx.maybeSync()
+}
+
+// Get copies the values into a new slice
+func (a *ArrayU64D1) Get() ([]uint64, error) {
+	out := make([]uint64, a.Len())
+	if err := a.Values(out); err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+// ArrayU8D1 is an array with 1 dimensions and u8 elements
+type ArrayU8D1 struct {
+	ptr   *C.struct_futhark_u8_1d
+	shape [1]int64
+	ctx   *Context
+}
+
+// NewArrayU8D1 creates a new array with the shape `dims`, initialized with the values from `data`
+func NewArrayU8D1(ctx *Context, data []uint8, dims [1]int64) (*ArrayU8D1, error) {
+	if err := ctx.check(); err != nil {
+		return nil, err
+	}
+	size := int64(1)
+	for _, d := range dims {
+		size *= d
+	}
+	if int64(len(data)) != size {
+		return nil, ErrInvalidShape
+	}
+	var p *C.uint8_t
+	if len(data) > 0 {
+		p = (*C.uint8_t)(unsafe.Pointer(&data[0]))
+	}
+	ptr := C.futhark_new_u8_1d(ctx.handle, p, C.int64_t(dims[0]))
+	if ptr == nil {
+		return nil, ErrNullPtr
+	}
+	if err := ctx.maybeSync(); err != nil {
+		C.futhark_free_u8_1d(ctx.handle, ptr)
+		return nil, err
+	}
+	return newArrayU8D1(ctx, ptr), nil
+}
+
+func newArrayU8D1(ctx *Context, ptr *C.struct_futhark_u8_1d) *ArrayU8D1 {
+	a := &ArrayU8D1{ptr: ptr, ctx: ctx}
+	shape := unsafe.Slice(C.futhark_shape_u8_1d(ctx.handle, ptr), 1)
+	for i := range a.shape {
+		a.shape[i] = int64(shape[i])
+	}
+	runtime.SetFinalizer(a, (*ArrayU8D1).Free)
+	return a
+}
+
+// Free frees the array, it is also freed when it is garbage collected
+func (a *ArrayU8D1) Free() {
+	if a.ptr != nil && a.ctx.handle != nil {
+		C.futhark_free_u8_1d(a.ctx.handle, a.ptr)
+	}
+	a.ptr = nil
+	runtime.SetFinalizer(a, nil)
+}
+
+func (a *ArrayU8D1) check() error {
+	if a.ptr == nil {
+		return ErrFreed
+	}
+	return a.ctx.check()
+}
+
+// Shape returns the shape of the array
+func (a *ArrayU8D1) Shape() []int64 {
+	return a.shape[:]
+}
+
+// Len returns the number of elements in the array
+func (a *ArrayU8D1) Len() int {
+	size := int64(1)
+	for _, d := range a.shape {
+		size *= d
+	}
+	return int(size)
+}
+
+// Values copies the values into `out`, which must have the same number of elements as the array
+func (a *ArrayU8D1) Values(out []uint8) error {
+	if err := a.check(); err != nil {
+		return err
+	}
+	if len(out) != a.Len() {
+		return ErrInvalidShape
+	}
+	var p *C.uint8_t
+	if len(out) > 0 {
+		p = (*C.uint8_t)(unsafe.Pointer(&out[0]))
+	}
+	rc := C.futhark_values_u8_1d(a.ctx.handle, a.ptr, p)
+	runtime.KeepAlive(a)
+	if rc != 0 {
+		return a.ctx.error(rc)
+	}
+	return a.ctx.maybeSync()
+}
+
+// Get copies the values into a new slice
+func (a *ArrayU8D1) Get() ([]uint8, error) {
+	out := make([]uint8, a.Len())
+	if err := a.Values(out); err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+// Flatten calls the flatten entry point
+func (ctx *Context) Flatten(input0 *ArrayF32D3) (out0 *ArrayF32D2, err error) {
+	if err = ctx.check(); err != nil {
+		return
+	}
+	if err = input0.check(); err != nil {
+		return
+	}
+	var cout0 *C.struct_futhark_f32_2d
+	rc := C.futhark_entry_flatten(ctx.handle, &cout0, input0.ptr)
+	runtime.KeepAlive(input0)
+	if rc != 0 {
+		err = ctx.error(rc)
+		return
+	}
+	out0 = newArrayF32D2(ctx, cout0)
+	err = ctx.maybeSync()
+	return
+}
+
+// Lengths calls the lengths entry point
+func (ctx *Context) Lengths(input0 *ArrayI8D1, input1 *ArrayI16D1, input2 *ArrayI32D1, input3 *ArrayI64D1, input4 *ArrayU8D1, input5 *ArrayU16D1, input6 *ArrayU32D1, input7 *ArrayU64D1, input8 *ArrayF16D1, input9 *ArrayF32D1, input10 *ArrayF64D1, input11 *ArrayBoolD1) (out0 int64, err error) {
+	if err = ctx.check(); err != nil {
+		return
+	}
+	if err = input0.check(); err != nil {
+		return
+	}
+	if err = input1.check(); err != nil {
+		return
+	}
+	if err = input2.check(); err != nil {
+		return
+	}
+	if err = input3.check(); err != nil {
+		return
+	}
+	if err = input4.check(); err != nil {
+		return
+	}
+	if err = input5.check(); err != nil {
+		return
+	}
+	if err = input6.check(); err != nil {
+		return
+	}
+	if err = input7.check(); err != nil {
+		return
+	}
+	if err = input8.check(); err != nil {
+		return
+	}
+	if err = input9.check(); err != nil {
+		return
+	}
+	if err = input10.check(); err != nil {
+		return
+	}
+	if err = input11.check(); err != nil {
+		return
+	}
+	var cout0 C.int64_t
+	rc := C.futhark_entry_lengths(ctx.handle, &cout0, input0.ptr, input1.ptr, input2.ptr, input3.ptr, input4.ptr, input5.ptr, input6.ptr, input7.ptr, input8.ptr, input9.ptr, input10.ptr, input11.ptr)
+	runtime.KeepAlive(input0)
+	runtime.KeepAlive(input1)
+	runtime.KeepAlive(input2)
+	runtime.KeepAlive(input3)
+	runtime.KeepAlive(input4)
+	runtime.KeepAlive(input5)
+	runtime.KeepAlive(input6)
+	runtime.KeepAlive(input7)
+	runtime.KeepAlive(input8)
+	runtime.KeepAlive(input9)
+	runtime.KeepAlive(input10)
+	runtime.KeepAlive(input11)
+	if rc != 0 {
+		err = ctx.error(rc)
+		return
+	}
+	out0 = int64(cout0)
+	err = ctx.maybeSync()
+	return
+}
+
+// SumRows calls the sum_rows entry point
+func (ctx *Context) SumRows(input0 *ArrayF32D2) (out0 *ArrayF32D1, out1 float32, err error) {
+	if err = ctx.check(); err != nil {
+		return
+	}
+	if err = input0.check(); err != nil {
+		return
+	}
+	var cout0 *C.struct_futhark_f32_1d
+	var cout1 C.float
+	rc := C.futhark_entry_sum_rows(ctx.handle, &cout0, &cout1, input0.ptr)
+	runtime.KeepAlive(input0)
+	if rc != 0 {
+		err = ctx.error(rc)
+		return
+	}
+	out0 = newArrayF32D1(ctx, cout0)
+	out1 = float32(cout1)
+	err = ctx.maybeSync()
+	return
+}
+
@@ -0,0 +1,897 @@
+-- Generated by futhark-bindgen
+
+{-# LANGUAGE ForeignFunctionInterface #-}
+{-# OPTIONS_GHC -Wno-unused-top-binds #-}
+
+module Arrays where
+
+import Control.Exception (Exception, throwIO)
+import Control.Monad (when)
+import Data.IORef
+import Data.Int
+import Data.Word
+import qualified Data.Vector.Storable as V
+import qualified Data.Vector.Storable.Mutable as MV
+import Foreign
+import Foreign.C.String
+import Foreign.C.Types
+import qualified Foreign.Concurrent as FC
+import qualified Prelude as P
+import Prelude (Bool (..), Double, Float, IO, Int, Maybe (..), Show, String, return, ($), (.), (/=), (==), (>>=), (<$>))
+
+data FutharkContextConfig
+
+data FutharkContext
+
+foreign import ccall unsafe "futhark_context_config_new" futhark_context_config_new :: IO (Ptr FutharkContextConfig)
+foreign import ccall unsafe "futhark_context_config_free" futhark_context_config_free :: Ptr FutharkContextConfig -> IO ()
+foreign import ccall unsafe "futhark_context_config_set_debugging" futhark_context_config_set_debugging :: Ptr FutharkContextConfig -> CInt -> IO ()
+foreign import ccall unsafe "futhark_context_config_set_profiling" futhark_context_config_set_profiling :: Ptr FutharkContextConfig -> CInt -> IO ()
+foreign import ccall unsafe "futhark_context_config_set_logging" futhark_context_config_set_logging :: Ptr FutharkContextConfig -> CInt -> IO ()
+foreign import ccall unsafe "futhark_context_config_set_cache_file" futhark_context_config_set_cache_file :: Ptr FutharkContextConfig -> CString -> IO ()
+foreign import ccall unsafe "futhark_context_config_set_num_threads" futhark_context_config_set_num_threads :: Ptr FutharkContextConfig -> CInt -> IO ()
+foreign import ccall safe "futhark_context_new" futhark_context_new :: Ptr FutharkContextConfig -> IO (Ptr FutharkContext)
+foreign import ccall safe "futhark_context_free" futhark_context_free :: Ptr FutharkContext -> IO ()
+foreign import ccall safe "futhark_context_sync" futhark_context_sync :: Ptr FutharkContext -> IO CInt
+foreign import ccall safe "futhark_context_clear_caches" futhark_context_clear_caches :: Ptr FutharkContext -> IO CInt
+foreign import ccall unsafe "futhark_context_pause_profiling" futhark_context_pause_profiling :: Ptr FutharkContext -> IO ()
+foreign import ccall unsafe "futhark_context_unpause_profiling" futhark_context_unpause_profiling :: Ptr FutharkContext -> IO ()
+foreign import ccall unsafe "futhark_context_get_error" futhark_context_get_error :: Ptr FutharkContext -> IO CString
+foreign import ccall unsafe "futhark_context_report" futhark_context_report :: Ptr FutharkContext -> IO CString
+
+-- | Errors thrown by the generated functions
+data FutharkException
+  = -- | A Futhark function returned a non-zero error code
+    FutharkError Int String
+  | -- | A NULL pointer was returned
+    NullPtr
+  | -- | The data doesn't match the shape of an array, expected and actual number of elements
+    InvalidShape Int Int
+  | -- | A value was used after it has been freed
+    UseAfterFree
+  deriving (Show)
+
+instance Exception FutharkException
+
+-- | Context options
+data Options = Options
+  { optDebug :: Bool,
+    optProfile :: Bool,
+    optLogging :: Bool,
+    optCacheFile :: Maybe String,
+    optAutoSync :: Bool,
+    optNumThreads :: Int
+  }
+
+-- | Default context options
+defaultOptions :: Options
+defaultOptions =
+  Options
+    { optDebug = False,
+      optProfile = False,
+      optLogging = False,
+      optCacheFile = Nothing,
+      optAutoSync = True,
+      optNumThreads = 0
+    }
+
+-- | Futhark context, freed by 'freeContext' or when it is garbage collected
+data Context = Context
+  { contextPtr :: ForeignPtr FutharkContext,
+    contextAlive :: IORef Bool,
+    contextAutoSync :: Bool
+  }
+
+-- | Create a new context
+newContext :: Options -> IO Context
+newContext opts = do
+  config <- futhark_context_config_new
+  when (config == nullPtr) $ throwIO NullPtr
+  futhark_context_config_set_debugging config (fromBool (optDebug opts))
+  futhark_context_config_set_profiling config (fromBool (optProfile opts))
+  futhark_context_config_set_logging config (fromBool (optLogging opts))
+  cacheFile <- P.maybe (return nullPtr) newCString (optCacheFile opts)
+  when (cacheFile /= nullPtr) $ futhark_context_config_set_cache_file config cacheFile
+  futhark_context_config_set_num_threads config (P.fromIntegral (optNumThreads opts))
+  ptr <- futhark_context_new config
+  when (ptr == nullPtr) $ do
+    futhark_context_config_free config
+    free cacheFile
+    throwIO NullPtr
+  alive <- newIORef True
+  fp <- FC.newForeignPtr ptr $ do
+    writeIORef alive False
+    _ <- futhark_context_sync ptr
+    futhark_context_free ptr
+    futhark_context_config_free config
+    free cacheFile
+  return (Context fp alive (optAutoSync opts))
+
+-- | Free the context, values created using the context can no longer be used
+freeContext :: Context -> IO ()
+freeContext = finalizeForeignPtr . contextPtr
+
+-- | Use the raw context pointer
+withContext :: Context -> (Ptr FutharkContext -> IO a) -> IO a
+withContext ctx f = do
+  alive <- readIORef (contextAlive ctx)
+  when (P.not alive) $ throwIO UseAfterFree
+  withForeignPtr (contextPtr ctx) f
+
+-- | Free a value using `f` unless the context has already been freed
+freeWith :: Context -> (Ptr FutharkContext -> IO CInt) -> IO ()
+freeWith ctx f = do
+  alive <- readIORef (contextAlive ctx)
+  when alive $ withForeignPtr (contextPtr ctx) $ \c -> do
+    _ <- f c
+    return ()
+
+takeString :: CString -> IO (Maybe String)
+takeString s
+  | s == nullPtr = return Nothing
+  | P.otherwise = do
+      x <- peekCString s
+      free s
+      return (Just x)
+
+-- | Get the last error message
+getError :: Context -> IO (Maybe String)
+getError ctx = withContext ctx $ \c -> futhark_context_get_error c >>= takeString
+
+-- | Get the profiling report
+report :: Context -> IO (Maybe String)
+report ctx = withContext ctx $ \c -> futhark_context_report c >>= takeString
+
+-- | Throw a 'FutharkError' if `rc` is non-zero
+check :: Context -> CInt -> IO ()
+check ctx rc = when (rc /= 0) $ do
+  msg <- getError ctx
+  throwIO (FutharkError (P.fromIntegral rc) (P.maybe "" P.id msg))
+
+-- | Wait for all pending operations to finish
+sync :: Context -> IO ()
+sync ctx = withContext ctx futhark_context_sync >>= check ctx
+
+-- | Sync if auto sync is enabled
+autoSync :: Context -> IO ()
+autoSync ctx = when (contextAutoSync ctx) (sync ctx)
+
+-- | Free any cached memory
+clearCaches :: Context -> IO ()
+clearCaches ctx = withContext ctx futhark_context_clear_caches >>= check ctx
+
+-- | Pause profiling
+pauseProfiling :: Context -> IO ()
+pauseProfiling ctx = withContext ctx futhark_context_pause_profiling
+
+-- | Resume profiling
+unpauseProfiling :: Context -> IO ()
+unpauseProfiling ctx = withContext ctx futhark_context_unpause_profiling
+
+data Futhark_f32_3d
+
+foreign import ccall unsafe "futhark_new_f32_3d" futhark_new_f32_3d :: Ptr FutharkContext -> Ptr Float -> Int64 -> Int64 -> Int64 -> IO (Ptr Futhark_f32_3d)
+foreign import ccall unsafe "futhark_free_f32_3d" futhark_free_f32_3d :: Ptr FutharkContext -> Ptr Futhark_f32_3d -> IO CInt
+foreign import ccall safe "futhark_values_f32_3d" futhark_values_f32_3d :: Ptr FutharkContext -> Ptr Futhark_f32_3d -> Ptr Float -> IO CInt
+foreign import ccall unsafe "futhark_shape_f32_3d" futhark_shape_f32_3d :: Ptr FutharkContext -> Ptr Futhark_f32_3d -> IO (Ptr Int64)
+
+-- | Array type with 3 dimensions and f32 elements
+data ArrayF32D3 = ArrayF32D3 (ForeignPtr Futhark_f32_3d) [Int64] Context
+
+wrapArrayF32D3 :: Context -> Ptr Futhark_f32_3d -> IO ArrayF32D3
+wrapArrayF32D3 ctx ptr = do
+  shape <- withContext ctx $ \c -> futhark_shape_f32_3d c ptr >>= peekArray 3
+  fp <- FC.newForeignPtr ptr (freeWith ctx (\c -> futhark_free_f32_3d c ptr))
+  return (ArrayF32D3 fp shape ctx)
+
+-- | Create a new array with the shape `dims`, initialized with the values from `v`
+newArrayF32D3 :: Context -> [Int64] -> V.Vector Float -> IO ArrayF32D3
+newArrayF32D3 ctx dims v = do
+  when (P.length dims /= 3) $ throwIO (InvalidShape 3 (P.length dims))
+  let size = P.fromIntegral (P.product dims)
+  when (size /= V.length v) $ throwIO (InvalidShape size (V.length v))
+  ptr <- withContext ctx $ \c -> V.unsafeWith v $ \p -> futhark_new_f32_3d c p (dims P.!! 0) (dims P.!! 1) (dims P.!! 2)
+  when (ptr == nullPtr) $ throwIO NullPtr
+  autoSync ctx
+  wrapArrayF32D3 ctx ptr
+
+-- | Use the raw array pointer
+withArrayF32D3 :: ArrayF32D3 -> (Ptr Futhark_f32_3d -> IO a) -> IO a
+withArrayF32D3 (ArrayF32D3 fp _ ctx) f = withContext ctx $ \_ -> withForeignPtr fp f
+
+-- | Free the array, it is also freed when it is garbage collected
+freeArrayF32D3 :: ArrayF32D3 -> IO ()
+freeArrayF32D3 (ArrayF32D3 fp _ _) = finalizeForeignPtr fp
+
+-- | Get the shape of the array
+arrayF32D3Shape :: ArrayF32D3 -> [Int64]
+arrayF32D3Shape (ArrayF32D3 _ shape _) = shape
+
+-- | Copy the values into a new vector
+arrayF32D3ToVector :: ArrayF32D3 -> IO (V.Vector Float)
+arrayF32D3ToVector arr@(ArrayF32D3 _ shape ctx) = do
+  mv <- MV.new (P.fromIntegral (P.product shape))
+  rc <- withContext ctx $ \c -> withArrayF32D3 arr $ \p -> MV.unsafeWith mv (futhark_values_f32_3d c p)
+  check ctx rc
+  autoSync ctx
+  V.unsafeFreeze mv
+
+data Futhark_f32_2d
+
+foreign import ccall unsafe "futhark_new_f32_2d" futhark_new_f32_2d :: Ptr FutharkContext -> Ptr Float -> Int64 -> Int64 -> IO (Ptr Futhark_f32_2d)
+foreign import ccall unsafe "futhark_free_f32_2d" futhark_free_f32_2d :: Ptr FutharkContext -> Ptr Futhark_f32_2d -> IO CInt
+foreign import ccall safe "futhark_values_f32_2d" futhark_values_f32_2d :: Ptr FutharkContext -> Ptr Futhark_f32_2d -> Ptr Float -> IO CInt
+foreign import ccall unsafe "futhark_shape_f32_2d" futhark_shape_f32_2d :: Ptr FutharkContext -> Ptr Futhark_f32_2d -> IO (Ptr Int64)
+
+-- | Array type with 2 dimensions and f32 elements
+data ArrayF32D2 = ArrayF32D2 (ForeignPtr Futhark_f32_2d) [Int64] Context
+
+wrapArrayF32D2 :: Context -> Ptr Futhark_f32_2d -> IO ArrayF32D2
+wrapArrayF32D2 ctx ptr = do
+  shape <- withContext ctx $ \c -> futhark_shape_f32_2d c ptr >>= peekArray 2
+  fp <- FC.newForeignPtr ptr (freeWith ctx (\c -> futhark_free_f32_2d c ptr))
+  return (ArrayF32D2 fp shape ctx)
+
+-- | Create a new array with the shape `dims`, initialized with the values from `v`
+newArrayF32D2 :: Context -> [Int64] -> V.Vector Float -> IO ArrayF32D2
+newArrayF32D2 ctx dims v = do
+  when (P.length dims /= 2) $ throwIO (InvalidShape 2 (P.length dims))
+  let size = P.fromIntegral (P.product dims)
+  when (size /= V.length v) $ throwIO (InvalidShape size (V.length v))
+  ptr <- withContext ctx $ \c -> V.unsafeWith v $ \p -> futhark_new_f32_2d c p (dims P.!! 0) (dims P.!! 1)
+  when (ptr == nullPtr) $ throwIO NullPtr
+  autoSync ctx
+  wrapArrayF32D2 ctx ptr
+
+-- | Use the raw array pointer
+withArrayF32D2 :: ArrayF32D2 -> (Ptr Futhark_f32_2d -> IO a) -> IO a
+withArrayF32D2 (ArrayF32D2 fp _ ctx) f = withContext ctx $ \_ -> withForeignPtr fp f
+
+-- | Free the array, it is also freed when it is garbage collected
+freeArrayF32D2 :: ArrayF32D2 -> IO ()
+freeArrayF32D2 (ArrayF32D2 fp _ _) = finalizeForeignPtr fp
+
+-- | Get the shape of the array
+arrayF32D2Shape :: ArrayF32D2 -> [Int64]
+arrayF32D2Shape (ArrayF32D2 _ shape _) = shape
+
+-- | Copy the values into a new vector
+arrayF32D2ToVector :: ArrayF32D2 -> IO (V.Vector Float)
+arrayF32D2ToVector arr@(ArrayF32D2 _ shape ctx) = do
+  mv <- MV.new (P.fromIntegral (P.product shape))
+  rc <- withContext ctx $ \c -> withArrayF32D2 arr $ \p -> MV.unsafeWith mv (futhark_values_f32_2d c p)
+  check ctx rc
+  autoSync ctx
+  V.unsafeFreeze mv
+
+data Futhark_bool_1d
+
+foreign import ccall unsafe "futhark_new_bool_1d" futhark_new_bool_1d :: Ptr FutharkContext -> Ptr CBool -> Int64 -> IO (Ptr Futhark_bool_1d)
+foreign import ccall unsafe "futhark_free_bool_1d" futhark_free_bool_1d :: Ptr FutharkContext -> Ptr Futhark_bool_1d -> IO CInt
+foreign import ccall safe "futhark_values_bool_1d" futhark_values_bool_1d :: Ptr FutharkContext -> Ptr Futhark_bool_1d -> Ptr CBool -> IO CInt
+foreign import ccall unsafe "futhark_shape_bool_1d" futhark_shape_bool_1d :: Ptr FutharkContext -> Ptr Futhark_bool_1d -> IO (Ptr Int64)
+
+-- | Array type with 1 dimensions and bool elements
+data ArrayBoolD1 = ArrayBoolD1 (ForeignPtr Futhark_bool_1d) [Int64] Context
+
+wrapArrayBoolD1 :: Context -> Ptr Futhark_bool_1d -> IO ArrayBoolD1
+wrapArrayBoolD1 ctx ptr = do
+  shape <- withContext ctx $ \c -> futhark_shape_bool_1d c ptr >>= peekArray 1
+  fp <- FC.newForeignPtr ptr (freeWith ctx (\c -> futhark_free_bool_1d c ptr))
+  return (ArrayBoolD1 fp shape ctx)
+
+-- | Create a new array with the shape `dims`, initialized with the values from `v`
+newArrayBoolD1 :: Context -> [Int64] -> V.Vector CBool -> IO ArrayBoolD1
+newArrayBoolD1 ctx dims v = do
+  when (P.length dims /= 1) $ throwIO (InvalidShape 1 (P.length dims))
+  let size = P.fromIntegral (P.product dims)
+  when (size /= V.length v) $ throwIO (InvalidShape size (V.length v))
+  ptr <- withContext ctx $ \c -> V.unsafeWith v $ \p -> futhark_new_bool_1d c p (dims P.!! 0)
+  when (ptr == nullPtr) $ throwIO NullPtr
+  autoSync ctx
+  wrapArrayBoolD1 ctx ptr
+
+-- | Use the raw array pointer
+withArrayBoolD1 :: ArrayBoolD1 -> (Ptr Futhark_bool_1d -> IO a) -> IO a
+withArrayBoolD1 (ArrayBoolD1 fp _ ctx) f = withContext ctx $ \_ -> withForeignPtr fp f
+
+-- | Free the array, it is also freed when it is garbage collected
+freeArrayBoolD1 :: ArrayBoolD1 -> IO ()
+freeArrayBoolD1 (ArrayBoolD1 fp _ _) = finalizeForeignPtr fp
+
+-- | Get the shape of the array
+arrayBoolD1Shape :: ArrayBoolD1 -> [Int64]
+arrayBoolD1Shape (ArrayBoolD1 _ shape _) = shape
+
+-- | Copy the values into a new vector
+arrayBoolD1ToVector :: ArrayBoolD1 -> IO (V.Vector CBool)
+arrayBoolD1ToVector arr@(ArrayBoolD1 _ shape ctx) = do
+  mv <- MV.new (P.fromIntegral (P.product shape))
+  rc <- withContext ctx $ \c -> withArrayBoolD1 arr $ \p -> MV.unsafeWith mv (futhark_values_bool_1d c p)
+  check ctx rc
+  autoSync ctx
+  V.unsafeFreeze mv
+
+data Futhark_f16_1d
+
+foreign import ccall unsafe "futhark_new_f16_1d" futhark_new_f16_1d :: Ptr FutharkContext -> Ptr Word16 -> Int64 -> IO (Ptr Futhark_f16_1d)
+foreign import ccall unsafe "futhark_free_f16_1d" futhark_free_f16_1d :: Ptr FutharkContext -> Ptr Futhark_f16_1d -> IO CInt
+foreign import ccall safe "futhark_values_f16_1d" futhark_values_f16_1d :: Ptr FutharkContext -> Ptr Futhark_f16_1d -> Ptr Word16 -> IO CInt
+foreign import ccall unsafe "futhark_shape_f16_1d" futhark_shape_f16_1d :: Ptr FutharkContext -> Ptr Futhark_f16_1d -> IO (Ptr Int64)
+
+-- | Array type with 1 dimensions and f16 elements
+data ArrayF16D1 = ArrayF16D1 (ForeignPtr Futhark_f16_1d) [Int64] Context
+
+wrapArrayF16D1 :: Context -> Ptr Futhark_f16_1d -> IO ArrayF16D1
+wrapArrayF16D1 ctx ptr = do
+  shape <- withContext ctx $ \c -> futhark_shape_f16_1d c ptr >>= peekArray 1
+  fp <- FC.newForeignPtr ptr (freeWith ctx (\c -> futhark_free_f16_1d c ptr))
+  return (ArrayF16D1 fp shape ctx)
+
+-- | Create a new array with the shape `dims`, initialized with the values from `v`
+newArrayF16D1 :: Context -> [Int64] -> V.Vector Word16 -> IO ArrayF16D1
+newArrayF16D1 ctx dims v = do
+  when (P.length dims /= 1) $ throwIO (InvalidShape 1 (P.length dims))
+  let size = P.fromIntegral (P.product dims)
+  when (size /= V.length v) $ throwIO (InvalidShape size (V.length v))
+  ptr <- withContext ctx $ \c -> V.unsafeWith v $ \p -> futhark_new_f16_1d c p (dims P.!! 0)
+  when (ptr == nullPtr) $ throwIO NullPtr
+  autoSync ctx
+  wrapArrayF16D1 ctx ptr
+
+-- | Use the raw array pointer
+withArrayF16D1 :: ArrayF16D1 -> (Ptr Futhark_f16_1d -> IO a) -> IO a
+withArrayF16D1 (ArrayF16D1 fp _ ctx) f = withContext ctx $ \_ -> withForeignPtr fp f
+
+-- | Free the array, it is also freed when it is garbage collected
+freeArrayF16D1 :: ArrayF16D1 -> IO ()
+freeArrayF16D1 (ArrayF16D1 fp _ _) = finalizeForeignPtr fp
+
+-- | Get the shape of the array
+arrayF16D1Shape :: ArrayF16D1 -> [Int64]
+arrayF16D1Shape (ArrayF16D1 _ shape _) = shape
+
+-- | Copy the values into a new vector
+arrayF16D1ToVector :: ArrayF16D1 -> IO (V.Vector Word16)
+arrayF16D1ToVector arr@(ArrayF16D1 _ shape ctx) = do
+  mv <- MV.new (P.fromIntegral (P.product shape))
+  rc <- withContext ctx $ \c -> withArrayF16D1 arr $ \p -> MV.unsafeWith mv (futhark_values_f16_1d c p)
+  check ctx rc
+  autoSync ctx
+  V.unsafeFreeze mv
+
+data Futhark_f32_1d
+
+foreign import ccall unsafe "futhark_new_f32_1d" futhark_new_f32_1d :: Ptr FutharkContext -> Ptr Float -> Int64 -> IO (Ptr Futhark_f32_1d)
+foreign import ccall unsafe "futhark_free_f32_1d" futhark_free_f32_1d :: Ptr FutharkContext -> Ptr Futhark_f32_1d -> IO CInt
+foreign import ccall safe "futhark_values_f32_1d" futhark_values_f32_1d :: Ptr FutharkContext -> Ptr Futhark_f32_1d -> Ptr Float -> IO CInt
+foreign import ccall unsafe "futhark_shape_f32_1d" futhark_shape_f32_1d :: Ptr FutharkContext -> Ptr Futhark_f32_1d -> IO (Ptr Int64)
+
+-- | Array type with 1 dimensions and f32 elements
+data ArrayF32D1 = ArrayF32D1 (ForeignPtr Futhark_f32_1d) [Int64] Context
+
+wrapArrayF32D1 :: Context -> Ptr Futhark_f32_1d -> IO ArrayF32D1
+wrapArrayF32D1 ctx ptr = do
+  shape <- withContext ctx $ \c -> futhark_shape_f32_1d c ptr >>= peekArray 1
+  fp <- FC.newForeignPtr ptr (freeWith ctx (\c -> futhark_free_f32_1d c ptr))
+  return (ArrayF32D1 fp shape ctx)
+
+-- | Create a new array with the shape `dims`, initialized with the values from `v`
+newArrayF32D1 :: Context -> [Int64] -> V.Vector Float -> IO ArrayF32D1
+newArrayF32D1 ctx dims v = do
+  when (P.length dims /= 1) $ throwIO (InvalidShape 1 (P.length dims))
+  let size = P.fromIntegral (P.product dims)
+  when (size /= V.length v) $ throwIO (InvalidShape size (V.length v))
+  ptr <- withContext ctx $ \c -> V.unsafeWith v $ \p -> futhark_new_f32_1d c p (dims P.!! 0)
+  when (ptr == nullPtr) $ throwIO NullPtr
+  autoSync ctx
+  wrapArrayF32D1 ctx ptr
+
+-- | Use the raw array pointer
+withArrayF32D1 :: ArrayF32D1 -> (Ptr Futhark_f32_1d -> IO a) -> IO a
+withArrayF32D1 (ArrayF32D1 fp _ ctx) f = withContext ctx $ \_ -> withForeignPtr fp f
+
+-- | Free the array, it is also freed when it is garbage collected
+freeArrayF32D1 :: ArrayF32D1 -> IO ()
+freeArrayF32D1 (ArrayF32D1 fp _ _) = finalizeForeignPtr fp
+
+-- | Get the shape of the array
+arrayF32D1Shape :: ArrayF32D1 -> [Int64]
+arrayF32D1Shape (ArrayF32D1 _ shape _) = shape
+
+-- | Copy the values into a new vector
+arrayF32D1ToVector :: ArrayF32D1 -> IO (V.Vector Float)
+arrayF32D1ToVector arr@(ArrayF32D1 _ shape ctx) = do
+  mv <- MV.new (P.fromIntegral (P.product shape))
+  rc <- withContext ctx $ \c -> withArrayF32D1 arr $ \p -> MV.unsafeWith mv (futhark_values_f32_1d c p)
+  check ctx rc
+  autoSync ctx
+  V.unsafeFreeze mv
+
+data Futhark_f64_1d
+
+foreign import ccall unsafe "futhark_new_f64_1d" futhark_new_f64_1d :: Ptr FutharkContext -> Ptr Double -> Int64 -> IO (Ptr Futhark_f64_1d)
+foreign import ccall unsafe "futhark_free_f64_1d" futhark_free_f64_1d :: Ptr FutharkContext -> Ptr Futhark_f64_1d -> IO CInt
+foreign import ccall safe "futhark_values_f64_1d" futhark_values_f64_1d :: Ptr FutharkContext -> Ptr Futhark_f64_1d -> Ptr Double -> IO CInt
+foreign import ccall unsafe "futhark_shape_f64_1d" futhark_shape_f64_1d :: Ptr FutharkContext -> Ptr Futhark_f64_1d -> IO (Ptr Int64)
+
+-- | Array type with 1 dimensions and f64 elements
+data ArrayF64D1 = ArrayF64D1 (ForeignPtr Futhark_f64_1d) [Int64] Context
+
+wrapArrayF64D1 :: Context -> Ptr Futhark_f64_1d -> IO ArrayF64D1
+wrapArrayF64D1 ctx ptr = do
+  shape <- withContext ctx $ \c -> futhark_shape_f64_1d c ptr >>= peekArray 1
+  fp <- FC.newForeignPtr ptr (freeWith ctx (\c -> futhark_free_f64_1d c ptr))
+  return (ArrayF64D1 fp shape ctx)
+
+-- | Create a new array with the shape `dims`, initialized with the values from `v`
+newArrayF64D1 :: Context -> [Int64] -> V.Vector Double -> IO ArrayF64D1
+newArrayF64D1 ctx dims v = do
+  when (P.length dims /= 1) $ throwIO (InvalidShape 1 (P.length dims))
+  let size = P.fromIntegral (P.product dims)
+  when (size /= V.length v) $ throwIO (InvalidShape size (V.length v))
+  ptr <- withContext ctx $ \c -> V.unsafeWith v $ \p -> futhark_new_f64_1d c p (dims P.!! 0)
+  when (ptr == nullPtr) $ throwIO NullPtr
+  autoSync ctx
+  wrapArrayF64D1 ctx ptr
+
+-- | Use the raw array pointer
+withArrayF64D1 :: ArrayF64D1 -> (Ptr Futhark_f64_1d -> IO a) -> IO a
+withArrayF64D1 (ArrayF64D1 fp _ ctx) f = withContext ctx $ \_ -> withForeignPtr fp f
+
+-- | Free the array, it is also freed when it is garbage collected
+freeArrayF64D1 :: ArrayF64D1 -> IO ()
+freeArrayF64D1 (ArrayF64D1 fp _ _) = finalizeForeignPtr fp
+
+-- | Get the shape of the array
+arrayF64D1Shape :: ArrayF64D1 -> [Int64]
+arrayF64D1Shape (ArrayF64D1 _ shape _) = shape
+
+-- | Copy the values into a new vector
+arrayF64D1ToVector :: ArrayF64D1 -> IO (V.Vector Double)
+arrayF64D1ToVector arr@(ArrayF64D1 _ shape ctx) = do
+  mv <- MV.new (P.fromIntegral (P.product shape))
+  rc <- withContext ctx $ \c -> withArrayF64D1 arr $ \p -> MV.unsafeWith mv (futhark_values_f64_1d c p)
+  check ctx rc
+  autoSync ctx
+  V.unsafeFreeze mv
+
+data Futhark_i16_1d
+
+foreign import ccall unsafe "futhark_new_i16_1d" futhark_new_i16_1d :: Ptr FutharkContext -> Ptr Int16 -> Int64 -> IO (Ptr Futhark_i16_1d)
+foreign import ccall unsafe "futhark_free_i16_1d" futhark_free_i16_1d :: Ptr FutharkContext -> Ptr Futhark_i16_1d -> IO CInt
+foreign import ccall safe "futhark_values_i16_1d" futhark_values_i16_1d :: Ptr FutharkContext -> Ptr Futhark_i16_1d -> Ptr Int16 -> IO CInt
+foreign import ccall unsafe "futhark_shape_i16_1d" futhark_shape_i16_1d :: Ptr FutharkContext -> Ptr Futhark_i16_1d -> IO (Ptr Int64)
+
+-- | Array type with 1 dimensions and i16 elements
+data ArrayI16D1 = ArrayI16D1 (ForeignPtr Futhark_i16_1d) [Int64] Context
+
+wrapArrayI16D1 :: Context -> Ptr Futhark_i16_1d -> IO ArrayI16D1
+wrapArrayI16D1 ctx ptr = do
+  shape <- withContext ctx $ \c -> futhark_shape_i16_1d c ptr >>= peekArray 1
+  fp <- FC.newForeignPtr ptr (freeWith ctx (\c -> futhark_free_i16_1d c ptr))
+  return (ArrayI16D1 fp shape ctx)
+
+-- | Create a new array with the shape `dims`, initialized with the values from `v`
+newArrayI16D1 :: Context -> [Int64] -> V.Vector Int16 -> IO ArrayI16D1
+newArrayI16D1 ctx dims v = do
+  when (P.length dims /= 1) $ throwIO (InvalidShape 1 (P.length dims))
+  let size = P.fromIntegral (P.product dims)
+  when (size /= V.length v) $ throwIO (InvalidShape size (V.length v))
+  ptr <- withContext ctx $ \c -> V.unsafeWith v $ \p -> futhark_new_i16_1d c p (dims P.!! 0)
+  when (ptr == nullPtr) $ throwIO NullPtr
+  autoSync ctx
+  wrapArrayI16D1 ctx ptr
+
+-- | Use the raw array pointer
+withArrayI16D1 :: ArrayI16D1 -> (Ptr Futhark_i16_1d -> IO a) -> IO a
+withArrayI16D1 (ArrayI16D1 fp _ ctx) f = withContext ctx $ \_ -> withForeignPtr fp f
+
+-- | Free the array, it is also freed when it is garbage collected
+freeArrayI16D1 :: ArrayI16D1 -> IO ()
+freeArrayI16D1 (ArrayI16D1 fp _ _) = finalizeForeignPtr fp
+
+-- | Get the shape of the array
+arrayI16D1Shape :: ArrayI16D1 -> [Int64]
+arrayI16D1Shape (ArrayI16D1 _ shape _) = shape
+
+-- | Copy the values into a new vector
+arrayI16D1ToVector :: ArrayI16D1 -> IO (V.Vector Int16)
+arrayI16D1ToVector arr@(ArrayI16D1 _ shape ctx) = do
+  mv <- MV.new (P.fromIntegral (P.product shape))
+  rc <- withContext ctx $ \c -> withArrayI16D1 arr $ \p -> MV.unsafeWith mv (futhark_values_i16_1d c p)
+  check ctx rc
+  autoSync ctx
+  V.unsafeFreeze mv
+
+data Futhark_i32_1d
+
+foreign import ccall unsafe "futhark_new_i32_1d" futhark_new_i32_1d :: Ptr FutharkContext -> Ptr Int32 -> Int64 -> IO (Ptr Futhark_i32_1d)
+foreign import ccall unsafe "futhark_free_i32_1d" futhark_free_i32_1d :: Ptr FutharkContext -> Ptr Futhark_i32_1d -> IO CInt
+foreign import ccall safe "futhark_values_i32_1d" futhark_values_i32_1d :: Ptr FutharkContext -> Ptr Futhark_i32_1d -> Ptr Int32 -> IO CInt
+foreign import ccall unsafe "futhark_shape_i32_1d" futhark_shape_i32_1d :: Ptr FutharkContext -> Ptr Futhark_i32_1d -> IO (Ptr Int64)
+
+-- | Array type with 1 dimensions and i32 elements
+data ArrayI32D1 = ArrayI32D1 (ForeignPtr Futhark_i32_1d) [Int64] Context
+
+wrapArrayI32D1 :: Context -> Ptr Futhark_i32_1d -> IO ArrayI32D1
+wrapArrayI32D1 ctx ptr = do
+  shape <- withContext ctx $ \c -> futhark_shape_i32_1d c ptr >>= peekArray 1
+  fp <- FC.newForeignPtr ptr (freeWith ctx (\c -> futhark_free_i32_1d c ptr))
+  return (ArrayI32D1 fp shape ctx)
+
+-- | Create a new array with the shape `dims`, initialized with the values from `v`
+newArrayI32D1 :: Context -> [Int64] -> V.Vector Int32 -> IO ArrayI32D1
+newArrayI32D1 ctx dims v = do
+  when (P.length dims /= 1) $ throwIO (InvalidShape 1 (P.length dims))
+  let size = P.fromIntegral (P.product dims)
+  when (size /= V.length v) $ throwIO (InvalidShape size (V.length v))
+  ptr <- withContext ctx $ \c -> V.unsafeWith v $ \p -> futhark_new_i32_1d c p (dims P.!! 0)
+  when (ptr == nullPtr) $ throwIO NullPtr
+  autoSync ctx
+  wrapArrayI32D1 ctx ptr
+
+-- | Use the raw array pointer
+withArrayI32D1 :: ArrayI32D1 -> (Ptr Futhark_i32_1d -> IO a) -> IO a
+withArrayI32D1 (ArrayI32D1 fp _ ctx) f = withContext ctx $ \_ -> withForeignPtr fp f
+
+-- | Free the array, it is also freed when it is garbage collected
+freeArrayI32D1 :: ArrayI32D1 -> IO ()
+freeArrayI32D1 (ArrayI32D1 fp _ _) = finalizeForeignPtr fp
+
+-- | Get the shape of the array
+arrayI32D1Shape :: ArrayI32D1 -> [Int64]
+arrayI32D1Shape (ArrayI32D1 _ shape _) = shape
+
+-- | Copy the values into a new vector
+arrayI32D1ToVector :: ArrayI32D1 -> IO (V.Vector Int32)
+arrayI32D1ToVector arr@(ArrayI32D1 _ shape ctx) = do
+  mv <- MV.new (P.fromIntegral (P.product shape))
+  rc <- withContext ctx $ \c -> withArrayI32D1 arr $ \p -> MV.unsafeWith mv (futhark_values_i32_1d c p)
+  check ctx rc
+  autoSync ctx
+  V.unsafeFreeze mv
+
+data Futhark_i64_1d
+
+foreign import ccall unsafe "futhark_new_i64_1d" futhark_new_i64_1d :: Ptr FutharkContext -> Ptr Int64 -> Int64 -> IO (Ptr Futhark_i64_1d)
+foreign import ccall unsafe "futhark_free_i64_1d" futhark_free_i64_1d :: Ptr FutharkContext -> Ptr Futhark_i64_1d -> IO CInt
+foreign import ccall safe "futhark_values_i64_1d" futhark_values_i64_1d :: Ptr FutharkContext -> Ptr Futhark_i64_1d -> Ptr Int64 -> IO CInt
+foreign import ccall unsafe "futhark_shape_i64_1d" futhark_shape_i64_1d :: Ptr FutharkContext -> Ptr Futhark_i64_1d -> IO (Ptr Int64)
+
+-- | Array type with 1 dimensions and i64 elements
+data ArrayI64D1 = ArrayI64D1 (ForeignPtr Futhark_i64_1d) [Int64] Context
+
+wrapArrayI64D1 :: Context -> Ptr Futhark_i64_1d -> IO ArrayI64D1
+wrapArrayI64D1 ctx ptr = do
+  shape <- withContext ctx $ \c -> futhark_shape_i64_1d c ptr >>= peekArray 1
+  fp <- FC.newForeignPtr ptr (freeWith ctx (\c -> futhark_free_i64_1d c ptr))
+  return (ArrayI64D1 fp shape ctx)
+
+-- | Create a new array with the shape `dims`, initialized with the values from `v`
+newArrayI64D1 :: Context -> [Int64] -> V.Vector Int64 -> IO ArrayI64D1
+newArrayI64D1 ctx dims v = do
+  when (P.length dims /= 1) $ throwIO (InvalidShape 1 (P.length dims))
+  let size = P.fromIntegral (P.product dims)
+  when (size /= V.length v) $ throwIO (InvalidShape size (V.length v))
+  ptr <- withContext ctx $ \c -> V.unsafeWith v $ \p -> futhark_new_i64_1d c p (dims P.!! 0)
+  when (ptr == nullPtr) $ throwIO NullPtr
+  autoSync ctx
+  wrapArrayI64D1 ctx ptr
+
+-- | Use the raw array pointer
+withArrayI64D1 :: ArrayI64D1 -> (Ptr Futhark_i64_1d -> IO a) -> IO a
+withArrayI64D1 (ArrayI64D1 fp _ ctx) f = withContext ctx $ \_ -> withForeignPtr fp f
+
+-- | Free the array, it is also freed when it is garbage collected
+freeArrayI64D1 :: ArrayI64D1 -> IO ()
+freeArrayI64D1 (ArrayI64D1 fp _ _) = finalizeForeignPtr fp
+
+-- | Get the shape of the array
+arrayI64D1Shape :: ArrayI64D1 -> [Int64]
+arrayI64D1Shape (ArrayI64D1 _ shape _) = shape
+
+-- | Copy the values into a new vector
+arrayI64D1ToVector :: ArrayI64D1 -> IO (V.Vector Int64)
+arrayI64D1ToVector arr@(ArrayI64D1 _ shape ctx) = do
+  mv <- MV.new (P.fromIntegral (P.product shape))
+  rc <- withContext ctx $ \c -> withArrayI64D1 arr $ \p -> MV.unsafeWith mv (futhark_values_i64_1d c p)
+  check ctx rc
+  autoSync ctx
+  V.unsafeFreeze mv
+
+data Futhark_i8_1d
+
+foreign import ccall unsafe "futhark_new_i8_1d" futhark_new_i8_1d :: Ptr FutharkContext -> Ptr Int8 -> Int64 -> IO (Ptr Futhark_i8_1d)
+foreign import ccall unsafe "futhark_free_i8_1d" futhark_free_i8_1d :: Ptr FutharkContext -> Ptr Futhark_i8_1d -> IO CInt
+foreign import ccall safe "futhark_values_i8_1d" futhark_values_i8_1d :: Ptr FutharkContext -> Ptr Futhark_i8_1d -> Ptr Int8 -> IO CInt
+foreign import ccall unsafe "futhark_shape_i8_1d" futhark_shape_i8_1d :: Ptr FutharkContext -> Ptr Futhark_i8_1d -> IO (Ptr Int64)
+
+-- | Array type with 1 dimensions and i8 elements
+data ArrayI8D1 = ArrayI8D1 (ForeignPtr Futhark_i8_1d) [Int64] Context
+
+wrapArrayI8D1 :: Context -> Ptr Futhark_i8_1d -> IO ArrayI8D1
+wrapArrayI8D1 ctx ptr = do
+  shape <- withContext ctx $ \c -> futhark_shape_i8_1d c ptr >>= peekArray 1
+  fp <- FC.newForeignPtr ptr (freeWith ctx (\c -> futhark_free_i8_1d c ptr))
+  return (ArrayI8D1 fp shape ctx)
+
+-- | Create a new array with the shape `dims`, initialized with the values from `v`
+newArrayI8D1 :: Context -> [Int64] -> V.Vector Int8 -> IO ArrayI8D1
+newArrayI8D1 ctx dims v = do
+  when (P.length dims /= 1) $ throwIO (InvalidShape 1 (P.length dims))
+  let size = P.fromIntegral (P.product dims)
+  when (size /= V.length v) $ throwIO (InvalidShape size (V.length v))
+  ptr <- withContext ctx $ \c -> V.unsafeWith v $ \p -> futhark_new_i8_1d c p (dims P.!! 0)
+  when (ptr == nullPtr) $ throwIO NullPtr
+  autoSync ctx
+  wrapArrayI8D1 ctx ptr
+
+-- | Use the raw array pointer
+withArrayI8D1 :: ArrayI8D1 -> (Ptr Futhark_i8_1d -> IO a) -> IO a
+withArrayI8D1 (ArrayI8D1 fp _ ctx) f = withContext ctx $ \_ -> withForeignPtr fp f
+
+-- | Free the array, it is also freed when it is garbage collected
+freeArrayI8D1 :: ArrayI8D1 -> IO ()
+freeArrayI8D1 (ArrayI8D1 fp _ _) = finalizeForeignPtr fp
+
+-- | Get the shape of the array
+arrayI8D1Shape :: ArrayI8D1 -> [Int64]
+arrayI8D1Shape (ArrayI8D1 _ shape _) = shape
+
+-- | Copy the values into a new vector
+arrayI8D1ToVector :: ArrayI8D1 -> IO (V.Vector Int8)
+arrayI8D1ToVector arr@(ArrayI8D1 _ shape ctx) = do
+  mv <- MV.new (P.fromIntegral (P.product shape))
+  rc <- withContext ctx $ \c -> withArrayI8D1 arr $ \p -> MV.unsafeWith mv (futhark_values_i8_1d c p)
+  check ctx rc
+  autoSync ctx
+  V.unsafeFreeze mv
+
+data Futhark_u16_1d
+
+foreign import ccall unsafe "futhark_new_u16_1d" futhark_new_u16_1d :: Ptr FutharkContext -> Ptr Word16 -> Int64 -> IO (Ptr Futhark_u16_1d)
+foreign import ccall unsafe "futhark_free_u16_1d" futhark_free_u16_1d :: Ptr FutharkContext -> Ptr Futhark_u16_1d -> IO CInt
+foreign import ccall safe "futhark_values_u16_1d" futhark_values_u16_1d :: Ptr FutharkContext -> Ptr Futhark_u16_1d -> Ptr Word16 -> IO CInt
+foreign import ccall unsafe "futhark_shape_u16_1d" futhark_shape_u16_1d :: Ptr FutharkContext -> Ptr Futhark_u16_1d -> IO (Ptr Int64)
+
+-- | Array type with 1 dimensions and u16 elements
+data ArrayU16D1 = ArrayU16D1 (ForeignPtr Futhark_u16_1d) [Int64] Context
+
+wrapArrayU16D1 :: Context -> Ptr Futhark_u16_1d -> IO ArrayU16D1
+wrapArrayU16D1 ctx ptr = do
+  shape <- withContext ctx $ \c -> futhark_shape_u16_1d c ptr >>= peekArray 1
+  fp <- FC.newForeignPtr ptr (freeWith ctx (\c -> futhark_free_u16_1d c ptr))
+  return (ArrayU16D1 fp shape ctx)
+
+-- | Create a new array with the shape `dims`, initialized with the values from `v`
+newArrayU16D1 :: Context -> [Int64] -> V.Vector Word16 -> IO ArrayU16D1
+newArrayU16D1 ctx dims v = do
+  when (P.length dims /= 1) $ throwIO (InvalidShape 1 (P.length dims))
+  let size = P.fromIntegral (P.product dims)
+  when (size /= V.length v) $ throwIO (InvalidShape size (V.length v))
+  ptr <- withContext ctx $ \c -> V.unsafeWith v $ \p -> futhark_new_u16_1d c p (dims P.!! 0)
+  when (ptr == nullPtr) $ throwIO NullPtr
+  autoSync ctx
+  wrapArrayU16D1 ctx ptr
+
+-- | Use the raw array pointer
+withArrayU16D1 :: ArrayU16D1 -> (Ptr Futhark_u16_1d -> IO a) -> IO a
+withArrayU16D1 (ArrayU16D1 fp _ ctx) f = withContext ctx $ \_ -> withForeignPtr fp f
+
+-- | Free the array, it is also freed when it is garbage collected
+freeArrayU16D1 :: ArrayU16D1 -> IO ()
+freeArrayU16D1 (ArrayU16D1 fp _ _) = finalizeForeignPtr fp
+
+-- | Get the shape of the array
+arrayU16D1Shape :: ArrayU16D1 -> [Int64]
+arrayU16D1Shape (ArrayU16D1 _ shape _) = shape
+
+-- | Copy the values into a new vector
+arrayU16D1ToVector :: ArrayU16D1 -> IO (V.Vector Word16)
+arrayU16D1ToVector arr@(ArrayU16D1 _ shape ctx) = do
+  mv <- MV.new (P.fromIntegral (P.product shape))
+  rc <- withContext ctx $ \c -> withArrayU16D1 arr $ \p -> MV.unsafeWith mv (futhark_values_u16_1d c p)
+  check ctx rc
+  autoSync ctx
+  V.unsafeFreeze mv
+
+data Futhark_u32_1d
+
+foreign import ccall unsafe "futhark_new_u32_1d" futhark_new_u32_1d :: Ptr FutharkContext -> Ptr Word32 -> Int64 -> IO (Ptr Futhark_u32_1d)
+foreign import ccall unsafe "futhark_free_u32_1d" futhark_free_u32_1d :: Ptr FutharkContext -> Ptr Futhark_u32_1d -> IO CInt
+foreign import ccall safe "futhark_values_u32_1d" futhark_values_u32_1d :: Ptr FutharkContext -> Ptr Futhark_u32_1d -> Ptr Word32 -> IO CInt
+foreign import ccall unsafe "futhark_shape_u32_1d" futhark_shape_u32_1d :: Ptr FutharkContext -> Ptr Futhark_u32_1d -> IO (Ptr Int64)
+
+-- | Array type with 1 dimensions and u32 elements
+data ArrayU32D1 = ArrayU32D1 (ForeignPtr Futhark_u32_1d) [Int64] Context
+
+wrapArrayU32D1 :: Context -> Ptr Futhark_u32_1d -> IO ArrayU32D1
+wrapArrayU32D1 ctx ptr = do
+  shape <- withContext ctx $ \c -> futhark_shape_u32_1d c ptr >>= peekArray 1
+  fp <- FC.newForeignPtr ptr (freeWith ctx (\c -> futhark_free_u32_1d c ptr))
+  return (ArrayU32D1 fp shape ctx)
+
+-- | Create a new array with the shape `dims`, initialized with the values from `v`
+newArrayU32D1 :: Context -> [Int64] -> V.Vector Word32 -> IO ArrayU32D1
+newArrayU32D1 ctx dims v = do
+  when (P.length dims /= 1) $ throwIO (InvalidShape 1 (P.length dims))
+  let size = P.fromIntegral (P.product dims)
+  when (size /= V.length v) $ throwIO (InvalidShape size (V.length v))
+  ptr <- withContext ctx $ \c -> V.unsafeWith v $ \p -> futhark_new_u32_1d c p (dims P.!! 0)
+  when (ptr == nullPtr) $ throwIO NullPtr
+  autoSync ctx
+  wrapArrayU32D1 ctx ptr
+
+-- | Use the raw array pointer
+withArrayU32D1 :: ArrayU32D1 -> (Ptr Futhark_u32_1d -> IO a) -> IO a
+withArrayU32D1 (ArrayU32D1 fp _ ctx) f = withContext ctx $ \_ -> withForeignPtr fp f
+
+-- | Free the array, it is also freed when it is garbage collected
+freeArrayU32D1 :: ArrayU32D1 -> IO ()
+freeArrayU32D1 (ArrayU32D1 fp _ _) = finalizeForeignPtr fp
+
+-- | Get the shape of the array
+arrayU32D1Shape :: ArrayU32D1 -> [Int64]
+arrayU32D1Shape (ArrayU32D1 _ shape _) = shape
+
+-- | Copy the values into a new vector
+arrayU32D1ToVector :: ArrayU32D1 -> IO (V.Vector Word32)
+arrayU32D1ToVector arr@(ArrayU32D1 _ shape ctx) = do
+  mv <- MV.new (P.fromIntegral (P.product shape))
+  rc <- withContext ctx $ \c -> withArrayU32D1 arr $ \p -> MV.unsafeWith mv (futhark_values_u32_1d c p)
+  check ctx rc
+  autoSync ctx
+  V.unsafeFreeze mv
+
+data Futhark_u64_1d
+
+foreign import ccall unsafe "futhark_new_u64_1d" futhark_new_u64_1d :: Ptr FutharkContext -> Ptr Word64 -> Int64 -> IO (Ptr Futhark_u64_1d)
+foreign import ccall unsafe "futhark_free_u64_1d" futhark_free_u64_1d :: Ptr FutharkContext -> Ptr Futhark_u64_1d -> IO CInt
+foreign import ccall safe "futhark_values_u64_1d" futhark_values_u64_1d :: Ptr FutharkContext -> Ptr Futhark_u64_1d -> Ptr Word64 -> IO CInt
+foreign import ccall unsafe "futhark_shape_u64_1d" futhark_shape_u64_1d :: Ptr FutharkContext -> Ptr Futhark_u64_1d -> IO (Ptr Int64)
+
+-- | Array type with 1 dimensions and u64 elements
+data ArrayU64D1 = ArrayU64D1 (ForeignPtr Futhark_u64_1d) [Int64] Context
+
+wrapArrayU64D1 :: Context -> Ptr Futhark_u64_1d -> IO ArrayU64D1
+wrapArrayU64D1 ctx ptr = do
+  shape <- withContext ctx $ \c -> futhark_shape_u64_1d c ptr >>= peekArray 1
+  fp <- FC.newForeignPtr ptr (freeWith ctx (\c -> futhark_free_u64_1d c ptr))
+  return (ArrayU64D1 fp shape ctx)
+
+-- | Create a new array with the shape `dims`, initialized with the values from `v`
+newArrayU64D1 :: Context -> [Int64] -> V.Vector Word64 -> IO ArrayU64D1
+newArrayU64D1 ctx dims v = do
+  when (P.length dims /= 1) $ throwIO (InvalidShape 1 (P.length dims))
+  let size = P.fromIntegral (P.product dims)
+  when (size /= V.length v) $ throwIO (InvalidShape size (V.length v))
+  ptr <- withContext ctx $ \c -> V.unsafeWith v $ \p -> futhark_new_u64_1d c p (dims P.!! 0)
+  when (ptr == nullPtr) $ throwIO NullPtr
+  autoSync ctx
+  wrapArrayU64D1 ctx ptr
+
+-- | Use the raw array pointer
+withArrayU64D1 :: ArrayU64D1 -> (Ptr Futhark_u64_1d -> IO a) -> IO a
+withArrayU64D1 (ArrayU64D1 fp _ ctx) f = withContext ctx $ \_ -> withForeignPtr fp f
+
+-- | Free the array, it is also freed when it is garbage collected
+freeArrayU64D1 :: ArrayU64D1 -> IO ()
+freeArrayU64D1 (ArrayU64D1 fp _ _) = finalizeForeignPtr fp
+
+-- | Get the shape of the array
+arrayU64D1Shape :: ArrayU64D1 -> [Int64]
+arrayU64D1Shape (ArrayU64D1 _ shape _) = shape
+
+-- | Copy the values into a new vector
+arrayU64D1ToVector :: ArrayU64D1 -> IO (V.Vector Word64)
+arrayU64D1ToVector arr@(ArrayU64D1 _ shape ctx) = do
+  mv <- MV.new (P.fromIntegral (P.product shape))
+  rc <- withContext ctx $ \c -> withArrayU64D1 arr $ \p -> MV.unsafeWith mv (futhark_values_u64_1d c p)
+  check ctx rc
+  autoSync ctx
+  V.unsafeFreeze mv
+
+data Futhark_u8_1d
+
+foreign import ccall unsafe "futhark_new_u8_1d" futhark_new_u8_1d :: Ptr FutharkContext -> Ptr Word8 -> Int64 -> IO (Ptr Futhark_u8_1d)
+foreign import ccall unsafe "futhark_free_u8_1d" futhark_free_u8_1d :: Ptr FutharkContext -> Ptr Futhark_u8_1d -> IO CInt
+foreign import ccall safe "futhark_values_u8_1d" futhark_values_u8_1d :: Ptr FutharkContext -> Ptr Futhark_u8_1d -> Ptr Word8 -> IO CInt
+foreign import ccall unsafe "futhark_shape_u8_1d" futhark_shape_u8_1d :: Ptr FutharkContext -> Ptr Futhark_u8_1d -> IO (Ptr Int64)
+
+-- | Array type with 1 dimensions and u8 elements
+data ArrayU8D1 = ArrayU8D1 (ForeignPtr Futhark_u8_1d) [Int64] Context
+
+wrapArrayU8D1 :: Context -> Ptr Futhark_u8_1d -> IO ArrayU8D1
+wrapArrayU8D1 ctx ptr = do
+  shape <- withContext ctx $ \c -> futhark_shape_u8_1d c ptr >>= peekArray 1
+  fp <- FC.newForeignPtr ptr (freeWith ctx (\c -> futhark_free_u8_1d c ptr))
+  return (ArrayU8D1 fp shape ctx)
+
+-- | Create a new array with the shape `dims`, initialized with the values from `v`
+newArrayU8D1 :: Context -> [Int64] -> V.Vector Word8 -> IO ArrayU8D1
+newArrayU8D1 ctx dims v = do
+  when (P.length dims /= 1) $ throwIO (InvalidShape 1 (P.length dims))
+  let size = P.fromIntegral (P.product dims)
+  when (size /= V.length v) $ throwIO (InvalidShape size (V.length v))
+  ptr <- withContext ctx $ \c -> V.unsafeWith v $ \p -> futhark_new_u8_1d c p (dims P.!! 0)
+  when (ptr == nullPtr) $ throwIO NullPtr
+  autoSync ctx
+  wrapArrayU8D1 ctx ptr
+
+-- | Use the raw array pointer
+withArrayU8D1 :: ArrayU8D1 -> (Ptr Futhark_u8_1d -> IO a) -> IO a
+withArrayU8D1 (ArrayU8D1 fp _ ctx) f = withContext ctx $ \_ -> withForeignPtr fp f
+
+-- | Free the array, it is also freed when it is garbage collected
+freeArrayU8D1 :: ArrayU8D1 -> IO ()
+freeArrayU8D1 (ArrayU8D1 fp _ _) = finalizeForeignPtr fp
+
+-- | Get the shape of the array
+arrayU8D1Shape :: ArrayU8D1 -> [Int64]
+arrayU8D1Shape (ArrayU8D1 _ shape _) = shape
+
+-- | Copy the values into a new vector
+arrayU8D1ToVector :: ArrayU8D1 -> IO (V.Vector Word8)
+arrayU8D1ToVector arr@(ArrayU8D1 _ shape ctx) = do
+  mv <- MV.new (P.fromIntegral (P.product shape))
+  rc <- withContext ctx $ \c -> withArrayU8D1 arr $ \p -> MV.unsafeWith mv (futhark_values_u8_1d c p)
+  check ctx rc
+  autoSync ctx
+  V.unsafeFreeze mv
+
+foreign import ccall safe "futhark_entry_flatten" futhark_entry_flatten :: Ptr FutharkContext -> Ptr (Ptr Futhark_f32_2d) -> (Ptr Futhark_f32_3d) -> IO CInt
+
+-- | Entry point: flatten
+flatten :: Context -> ArrayF32D3 -> IO ArrayF32D2
+flatten ctx input0 =
+  withContext ctx $ \c ->
+  alloca $ \out0 ->
+  withArrayF32D3 input0 $ \p0 -> do
+    rc <- futhark_entry_flatten c out0 p0
+    check ctx rc
+    autoSync ctx
+    r0 <- peek out0 >>= wrapArrayF32D2 ctx
+    return r0
+
+foreign import ccall safe "futhark_entry_lengths" futhark_entry_lengths :: Ptr FutharkContext -> Ptr Int64 -> (Ptr Futhark_i8_1d) -> (Ptr Futhark_i16_1d) -> (Ptr Futhark_i32_1d) -> (Ptr Futhark_i64_1d) -> (Ptr Futhark_u8_1d) -> (Ptr Futhark_u16_1d) -> (Ptr Futhark_u32_1d) -> (Ptr Futhark_u64_1d) -> (Ptr Futhark_f16_1d) -> (Ptr Futhark_f32_1d) -> (Ptr Futhark_f64_1d) -> (Ptr Futhark_bool_1d) -> IO CInt
+
+-- | Entry point: lengths
+lengths :: Context -> ArrayI8D1 -> ArrayI16D1 -> ArrayI32D1 -> ArrayI64D1 -> ArrayU8D1 -> ArrayU16D1 -> ArrayU32D1 -> ArrayU64D1 -> ArrayF16D1 -> ArrayF32D1 -> ArrayF64D1 -> ArrayBoolD1 -> IO Int64
+lengths ctx input0 input1 input2 input3 input4 input5 input6 input7 input8 input9 input10 input11 =
+  withContext ctx $ \c ->
+  alloca $ \out0 ->
+  withArrayI8D1 input0 $ \p0 ->
+  withArrayI16D1 input1 $ \p1 ->
+  withArrayI32D1 input2 $ \p2 ->
+  withArrayI64D1 input3 $ \p3 ->
+  withArrayU8D1 input4 $ \p4 ->
+  withArrayU16D1 input5 $ \p5 ->
+  withArrayU32D1 input6 $ \p6 ->
+  withArrayU64D1 input7 $ \p7 ->
+  withArrayF16D1 input8 $ \p8 ->
+  withArrayF32D1 input9 $ \p9 ->
+  withArrayF64D1 input10 $ \p10 ->
+  withArrayBoolD1 input11 $ \p11 -> do
+    rc <- futhark_entry_lengths c out0 p0 p1 p2 p3 p4 p5 p6 p7 p8 p9 p10 p11
+    check ctx rc
+    autoSync ctx
+    r0 <- peek out0
+    return r0
+
+foreign import ccall safe "futhark_entry_sum_rows" futhark_entry_sum_rows :: Ptr FutharkContext -> Ptr (Ptr Futhark_f32_1d) -> Ptr Float -> (Ptr Futhark_f32_2d) -> IO CInt
+
+-- | Entry point: sum_rows
+sumRows :: Context -> ArrayF32D2 -> IO (ArrayF32D1, Float)
+sumRows ctx input0 =
+  withContext ctx $ \c ->
+  alloca $ \out0 ->
+  alloca $ \out1 ->
+  withArrayF32D2 input0 $ \p0 -> do
+    rc <- futhark_entry_sum_rows c out0 out1 p0
+    check ctx rc
+    autoSync ctx
+    r0 <- peek out0 >>= wrapArrayF32D1 ctx
+    r1 <- peek out1
+    return (r0, r1)
+
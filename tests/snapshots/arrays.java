@@ -0,0 +1,1445 @@
+// Generated by futhark-bindgen
+
+import java.lang.foreign.Arena;
+import java.lang.foreign.FunctionDescriptor;
+import java.lang.foreign.Linker;
+import java.lang.foreign.MemorySegment;
+import java.lang.foreign.SymbolLookup;
+import java.lang.invoke.MethodHandle;
+import java.lang.ref.Cleaner;
+import java.lang.ref.Reference;
+import java.util.Objects;
+
+import static java.lang.foreign.ValueLayout.*;
+
+/** Bindings for the Futhark library {@code arrays}, using the Foreign Function and Memory API */
+public final class arrays {
+    private arrays() {
+    }
+
+    // Loaded from the path in the `futhark.lib.arrays` system property, or `libarrays.so`
+    private static final SymbolLookup LIB = SymbolLookup.libraryLookup(
+        System.getProperty("futhark.lib.arrays", System.mapLibraryName("arrays")), Arena.global());
+    private static final Linker LINKER = Linker.nativeLinker();
+    private static final Cleaner CLEANER = Cleaner.create();
+    private static final MethodHandle FREE =
+        LINKER.downcallHandle(LINKER.defaultLookup().find("free").orElseThrow(), FunctionDescriptor.ofVoid(ADDRESS));
+
+    static MethodHandle function(String name, FunctionDescriptor desc) {
+        MemorySegment f = LIB.find(name).orElseThrow(() -> new UnsatisfiedLinkError("Missing symbol: " + name));
+        return LINKER.downcallHandle(f, desc);
+    }
+
+    static Object call(MethodHandle f, Object... args) {
+        try {
+            return f.invokeWithArguments(args);
+        } catch (RuntimeException | Error e) {
+            throw e;
+        } catch (Throwable e) {
+            throw new RuntimeException(e);
+        }
+    }
+
+    static String takeString(MemorySegment s) {
+        if (s.equals(MemorySegment.NULL)) {
+            return null;
+        }
+        String result = s.reinterpret(Long.MAX_VALUE).getString(0);
+        call(FREE, s);
+        return result;
+    }
+
+    static long product(long[] dims) {
+        long size = 1;
+        for (long d : dims) {
+            size *= d;
+        }
+        return size;
+    }
+
+    // `boolean` arrays can't be copied to and from memory segments directly
+    static MemorySegment toSegment(Arena arena, boolean[] data) {
+        MemorySegment out = arena.allocate(JAVA_BOOLEAN, data.length);
+        for (int i = 0; i < data.length; i++) {
+            out.setAtIndex(JAVA_BOOLEAN, i, data[i]);
+        }
+        return out;
+    }
+
+    static boolean[] toBooleans(MemorySegment data) {
+        boolean[] out = new boolean[(int) data.byteSize()];
+        for (int i = 0; i < out.length; i++) {
+            out[i] = data.getAtIndex(JAVA_BOOLEAN, i);
+        }
+        return out;
+    }
+
+    /** Thrown when a Futhark function returns a non-zero error code */
+    public static class FutharkException extends RuntimeException {
+        private final int code;
+
+        public FutharkException(int code, String message) {
+            super("Futhark error code " + code + ": " + message);
+            this.code = code;
+        }
+
+        public int code() {
+            return code;
+        }
+    }
+
+    /** Thrown when a Futhark function returns a NULL pointer */
+    public static class NullPtrException extends RuntimeException {
+        public NullPtrException() {
+            super("NULL pointer encountered");
+        }
+    }
+
+    /** Options used to configure a {@link Context} */
+    public static final class Options {
+        boolean debug;
+        boolean profile;
+        boolean logging;
+        String cacheFile;
+        boolean autoSync = true;
+        int numThreads;
+
+        /** Enable debugging */
+        public Options debug(boolean debug) {
+            this.debug = debug;
+            return this;
+        }
+
+        /** Enable profiling */
+        public Options profile(boolean profile) {
+            this.profile = profile;
+            return this;
+        }
+
+        /** Enable logging */
+        public Options logging(boolean logging) {
+            this.logging = logging;
+            return this;
+        }
+
+        /** Set the Futhark cache file */
+        public Options cacheFile(String cacheFile) {
+            this.cacheFile = cacheFile;
+            return this;
+        }
+
+        /** Sync after every operation, enabled by default */
+        public Options autoSync(boolean autoSync) {
+            this.autoSync = autoSync;
+            return this;
+        }
+
+        /** Set the number of threads */
+        public Options numThreads(int numThreads) {
+            this.numThreads = numThreads;
+            return this;
+        }
+    }
+
+    // Shared by a context and the values created using it, the context is freed once all of
+    // them have been closed
+    static final class ContextState {
+        final MemorySegment config;
+        final MemorySegment handle;
+        final Arena arena;
+        private int refs = 1;
+
+        ContextState(MemorySegment config, MemorySegment handle, Arena arena) {
+            this.config = config;
+            this.handle = handle;
+            this.arena = arena;
+        }
+
+        synchronized void retain() {
+            if (refs == 0) {
+                throw new IllegalStateException("Context has already been freed");
+            }
+            refs++;
+        }
+
+        synchronized void release() {
+            if (--refs == 0) {
+                call(Context.FUTHARK_CONTEXT_SYNC, handle);
+                call(Context.FUTHARK_CONTEXT_FREE, handle);
+                call(Context.FUTHARK_CONTEXT_CONFIG_FREE, config);
+                arena.close();
+            }
+        }
+    }
+
+    /** Base class of arrays and opaque values, freed by {@link #close} or when they are garbage collected */
+    public abstract static class FutharkValue implements AutoCloseable {
+        final Context ctx;
+        private final MemorySegment ptr;
+        private final Cleaner.Cleanable cleanable;
+        private volatile boolean closed;
+
+        FutharkValue(Context ctx, MemorySegment ptr, MethodHandle free) {
+            if (ptr.equals(MemorySegment.NULL)) {
+                throw new NullPtrException();
+            }
+            ContextState state = ctx.state;
+            state.retain();
+            this.ctx = ctx;
+            this.ptr = ptr;
+            this.cleanable = CLEANER.register(this, () -> {
+                call(free, state.handle, ptr);
+                state.release();
+            });
+        }
+
+        MemorySegment ptr() {
+            if (closed) {
+                throw new IllegalStateException("Value has already been closed");
+            }
+            return ptr;
+        }
+
+        /** Free the value */
+        @Override
+        public void close() {
+            closed = true;
+            cleanable.clean();
+        }
+    }
+
+    /** Array type with 3 dimensions and f32 elements */
+    public static final class ArrayF32D3 extends FutharkValue {
+        private static final MethodHandle FUTHARK_NEW_F32_3D =
+            function("futhark_new_f32_3d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS, JAVA_LONG, JAVA_LONG, JAVA_LONG));
+        private static final MethodHandle FUTHARK_FREE_F32_3D =
+            function("futhark_free_f32_3d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_VALUES_F32_3D =
+            function("futhark_values_f32_3d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_SHAPE_F32_3D =
+            function("futhark_shape_f32_3d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS));
+
+        private final long[] shape;
+
+        ArrayF32D3(Context ctx, MemorySegment ptr) {
+            super(ctx, ptr, FUTHARK_FREE_F32_3D);
+            MemorySegment dims = (MemorySegment) call(FUTHARK_SHAPE_F32_3D, ctx.handle(), ptr);
+            this.shape = dims.reinterpret(3 * JAVA_LONG.byteSize()).toArray(JAVA_LONG);
+        }
+
+        /** Create a new array with the shape {@code dims} from the values in {@code data} */
+        public static ArrayF32D3 from(Context ctx, float[] data, long... dims) {
+            if (product(dims) != data.length) {
+                throw new IllegalArgumentException("Invalid shape, expected " + product(dims) + " elements, got " + data.length);
+            }
+            try (Arena arena = Arena.ofConfined()) {
+                return from(ctx, arena.allocateFrom(JAVA_FLOAT, data), dims);
+            }
+        }
+
+        /** Create a new array with the shape {@code dims} by copying the values in {@code data} */
+        public static ArrayF32D3 from(Context ctx, MemorySegment data, long... dims) {
+            if (dims.length != 3) {
+                throw new IllegalArgumentException("Expected 3 dimensions, got " + dims.length);
+            }
+            if (data.byteSize() < product(dims) * JAVA_FLOAT.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the given shape");
+            }
+            MemorySegment ptr = (MemorySegment) call(FUTHARK_NEW_F32_3D, ctx.handle(), data, dims[0], dims[1], dims[2]);
+            ArrayF32D3 array = new ArrayF32D3(ctx, ptr);
+            ctx.maybeSync();
+            return array;
+        }
+
+        /** Get the shape of the array */
+        public long[] shape() {
+            return shape.clone();
+        }
+
+        /** Get the number of elements in the array */
+        public long size() {
+            return product(shape);
+        }
+
+        /** Copy the values of the array into {@code out} */
+        public void values(MemorySegment out) {
+            if (out.byteSize() < size() * JAVA_FLOAT.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the array");
+            }
+            int rc = (int) call(FUTHARK_VALUES_F32_3D, ctx.handle(), ptr(), out);
+            Reference.reachabilityFence(this);
+            ctx.check(rc);
+            ctx.maybeSync();
+        }
+
+        /** Copy the values of the array into a new Java array */
+        public float[] toArray() {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(JAVA_FLOAT, size());
+                values(out);
+                return out.toArray(JAVA_FLOAT);
+            }
+        }
+    }
+
+    /** Array type with 2 dimensions and f32 elements */
+    public static final class ArrayF32D2 extends FutharkValue {
+        private static final MethodHandle FUTHARK_NEW_F32_2D =
+            function("futhark_new_f32_2d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS, JAVA_LONG, JAVA_LONG));
+        private static final MethodHandle FUTHARK_FREE_F32_2D =
+            function("futhark_free_f32_2d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_VALUES_F32_2D =
+            function("futhark_values_f32_2d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_SHAPE_F32_2D =
+            function("futhark_shape_f32_2d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS));
+
+        private final long[] shape;
+
+        ArrayF32D2(Context ctx, MemorySegment ptr) {
+            super(ctx, ptr, FUTHARK_FREE_F32_2D);
+            MemorySegment dims = (MemorySegment) call(FUTHARK_SHAPE_F32_2D, ctx.handle(), ptr);
+            this.shape = dims.reinterpret(2 * JAVA_LONG.byteSize()).toArray(JAVA_LONG);
+        }
+
+        /** Create a new array with the shape {@code dims} from the values in {@code data} */
+        public static ArrayF32D2 from(Context ctx, float[] data, long... dims) {
+            if (product(dims) != data.length) {
+                throw new IllegalArgumentException("Invalid shape, expected " + product(dims) + " elements, got " + data.length);
+            }
+            try (Arena arena = Arena.ofConfined()) {
+                return from(ctx, arena.allocateFrom(JAVA_FLOAT, data), dims);
+            }
+        }
+
+        /** Create a new array with the shape {@code dims} by copying the values in {@code data} */
+        public static ArrayF32D2 from(Context ctx, MemorySegment data, long... dims) {
+            if (dims.length != 2) {
+                throw new IllegalArgumentException("Expected 2 dimensions, got " + dims.length);
+            }
+            if (data.byteSize() < product(dims) * JAVA_FLOAT.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the given shape");
+            }
+            MemorySegment ptr = (MemorySegment) call(FUTHARK_NEW_F32_2D, ctx.handle(), data, dims[0], dims[1]);
+            ArrayF32D2 array = new ArrayF32D2(ctx, ptr);
+            ctx.maybeSync();
+            return array;
+        }
+
+        /** Get the shape of the array */
+        public long[] shape() {
+            return shape.clone();
+        }
+
+        /** Get the number of elements in the array */
+        public long size() {
+            return product(shape);
+        }
+
+        /** Copy the values of the array into {@code out} */
+        public void values(MemorySegment out) {
+            if (out.byteSize() < size() * JAVA_FLOAT.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the array");
+            }
+            int rc = (int) call(FUTHARK_VALUES_F32_2D, ctx.handle(), ptr(), out);
+            Reference.reachabilityFence(this);
+            ctx.check(rc);
+            ctx.maybeSync();
+        }
+
+        /** Copy the values of the array into a new Java array */
+        public float[] toArray() {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(JAVA_FLOAT, size());
+                values(out);
+                return out.toArray(JAVA_FLOAT);
+            }
+        }
+    }
+
+    /** Array type with 1 dimensions and bool elements */
+    public static final class ArrayBoolD1 extends FutharkValue {
+        private static final MethodHandle FUTHARK_NEW_BOOL_1D =
+            function("futhark_new_bool_1d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS, JAVA_LONG));
+        private static final MethodHandle FUTHARK_FREE_BOOL_1D =
+            function("futhark_free_bool_1d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_VALUES_BOOL_1D =
+            function("futhark_values_bool_1d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_SHAPE_BOOL_1D =
+            function("futhark_shape_bool_1d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS));
+
+        private final long[] shape;
+
+        ArrayBoolD1(Context ctx, MemorySegment ptr) {
+            super(ctx, ptr, FUTHARK_FREE_BOOL_1D);
+            MemorySegment dims = (MemorySegment) call(FUTHARK_SHAPE_BOOL_1D, ctx.handle(), ptr);
+            this.shape = dims.reinterpret(1 * JAVA_LONG.byteSize()).toArray(JAVA_LONG);
+        }
+
+        /** Create a new array with the shape {@code dims} from the values in {@code data} */
+        public static ArrayBoolD1 from(Context ctx, boolean[] data, long... dims) {
+            if (product(dims) != data.length) {
+                throw new IllegalArgumentException("Invalid shape, expected " + product(dims) + " elements, got " + data.length);
+            }
+            try (Arena arena = Arena.ofConfined()) {
+                return from(ctx, toSegment(arena, data), dims);
+            }
+        }
+
+        /** Create a new array with the shape {@code dims} by copying the values in {@code data} */
+        public static ArrayBoolD1 from(Context ctx, MemorySegment data, long... dims) {
+            if (dims.length != 1) {
+                throw new IllegalArgumentException("Expected 1 dimensions, got " + dims.length);
+            }
+            if (data.byteSize() < product(dims) * JAVA_BOOLEAN.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the given shape");
+            }
+            MemorySegment ptr = (MemorySegment) call(FUTHARK_NEW_BOOL_1D, ctx.handle(), data, dims[0]);
+            ArrayBoolD1 array = new ArrayBoolD1(ctx, ptr);
+            ctx.maybeSync();
+            return array;
+        }
+
+        /** Get the shape of the array */
+        public long[] shape() {
+            return shape.clone();
+        }
+
+        /** Get the number of elements in the array */
+        public long size() {
+            return product(shape);
+        }
+
+        /** Copy the values of the array into {@code out} */
+        public void values(MemorySegment out) {
+            if (out.byteSize() < size() * JAVA_BOOLEAN.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the array");
+            }
+            int rc = (int) call(FUTHARK_VALUES_BOOL_1D, ctx.handle(), ptr(), out);
+            Reference.reachabilityFence(this);
+            ctx.check(rc);
+            ctx.maybeSync();
+        }
+
+        /** Copy the values of the array into a new Java array */
+        public boolean[] toArray() {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(JAVA_BOOLEAN, size());
+                values(out);
+                return toBooleans(out);
+            }
+        }
+    }
+
+    /** Array type with 1 dimensions and f16 elements */
+    public static final class ArrayF16D1 extends FutharkValue {
+        private static final MethodHandle FUTHARK_NEW_F16_1D =
+            function("futhark_new_f16_1d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS, JAVA_LONG));
+        private static final MethodHandle FUTHARK_FREE_F16_1D =
+            function("futhark_free_f16_1d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_VALUES_F16_1D =
+            function("futhark_values_f16_1d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_SHAPE_F16_1D =
+            function("futhark_shape_f16_1d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS));
+
+        private final long[] shape;
+
+        ArrayF16D1(Context ctx, MemorySegment ptr) {
+            super(ctx, ptr, FUTHARK_FREE_F16_1D);
+            MemorySegment dims = (MemorySegment) call(FUTHARK_SHAPE_F16_1D, ctx.handle(), ptr);
+            this.shape = dims.reinterpret(1 * JAVA_LONG.byteSize()).toArray(JAVA_LONG);
+        }
+
+        /** Create a new array with the shape {@code dims} from the values in {@code data} */
+        public static ArrayF16D1 from(Context ctx, short[] data, long... dims) {
+            if (product(dims) != data.length) {
+                throw new IllegalArgumentException("Invalid shape, expected " + product(dims) + " elements, got " + data.length);
+            }
+            try (Arena arena = Arena.ofConfined()) {
+                return from(ctx, arena.allocateFrom(JAVA_SHORT, data), dims);
+            }
+        }
+
+        /** Create a new array with the shape {@code dims} by copying the values in {@code data} */
+        public static ArrayF16D1 from(Context ctx, MemorySegment data, long... dims) {
+            if (dims.length != 1) {
+                throw new IllegalArgumentException("Expected 1 dimensions, got " + dims.length);
+            }
+            if (data.byteSize() < product(dims) * JAVA_SHORT.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the given shape");
+            }
+            MemorySegment ptr = (MemorySegment) call(FUTHARK_NEW_F16_1D, ctx.handle(), data, dims[0]);
+            ArrayF16D1 array = new ArrayF16D1(ctx, ptr);
+            ctx.maybeSync();
+            return array;
+        }
+
+        /** Get the shape of the array */
+        public long[] shape() {
+            return shape.clone();
+        }
+
+        /** Get the number of elements in the array */
+        public long size() {
+            return product(shape);
+        }
+
+        /** Copy the values of the array into {@code out} */
+        public void values(MemorySegment out) {
+            if (out.byteSize() < size() * JAVA_SHORT.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the array");
+            }
+            int rc = (int) call(FUTHARK_VALUES_F16_1D, ctx.handle(), ptr(), out);
+            Reference.reachabilityFence(this);
+            ctx.check(rc);
+            ctx.maybeSync();
+        }
+
+        /** Copy the values of the array into a new Java array */
+        public short[] toArray() {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(JAVA_SHORT, size());
+                values(out);
+                return out.toArray(JAVA_SHORT);
+            }
+        }
+    }
+
+    /** Array type with 1 dimensions and f32 elements */
+    public static final class ArrayF32D1 extends FutharkValue {
+        private static final MethodHandle FUTHARK_NEW_F32_1D =
+            function("futhark_new_f32_1d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS, JAVA_LONG));
+        private static final MethodHandle FUTHARK_FREE_F32_1D =
+            function("futhark_free_f32_1d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_VALUES_F32_1D =
+            function("futhark_values_f32_1d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_SHAPE_F32_1D =
+            function("futhark_shape_f32_1d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS));
+
+        private final long[] shape;
+
+        ArrayF32D1(Context ctx, MemorySegment ptr) {
+            super(ctx, ptr, FUTHARK_FREE_F32_1D);
+            MemorySegment dims = (MemorySegment) call(FUTHARK_SHAPE_F32_1D, ctx.handle(), ptr);
+            this.shape = dims.reinterpret(1 * JAVA_LONG.byteSize()).toArray(JAVA_LONG);
+        }
+
+        /** Create a new array with the shape {@code dims} from the values in {@code data} */
+        public static ArrayF32D1 from(Context ctx, float[] data, long... dims) {
+            if (product(dims) != data.length) {
+                throw new IllegalArgumentException("Invalid shape, expected " + product(dims) + " elements, got " + data.length);
+            }
+            try (Arena arena = Arena.ofConfined()) {
+                return from(ctx, arena.allocateFrom(JAVA_FLOAT, data), dims);
+            }
+        }
+
+        /** Create a new array with the shape {@code dims} by copying the values in {@code data} */
+        public static ArrayF32D1 from(Context ctx, MemorySegment data, long... dims) {
+            if (dims.length != 1) {
+                throw new IllegalArgumentException("Expected 1 dimensions, got " + dims.length);
+            }
+            if (data.byteSize() < product(dims) * JAVA_FLOAT.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the given shape");
+            }
+            MemorySegment ptr = (MemorySegment) call(FUTHARK_NEW_F32_1D, ctx.handle(), data, dims[0]);
+            ArrayF32D1 array = new ArrayF32D1(ctx, ptr);
+            ctx.maybeSync();
+            return array;
+        }
+
+        /** Get the shape of the array */
+        public long[] shape() {
+            return shape.clone();
+        }
+
+        /** Get the number of elements in the array */
+        public long size() {
+            return product(shape);
+        }
+
+        /** Copy the values of the array into {@code out} */
+        public void values(MemorySegment out) {
+            if (out.byteSize() < size() * JAVA_FLOAT.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the array");
+            }
+            int rc = (int) call(FUTHARK_VALUES_F32_1D, ctx.handle(), ptr(), out);
+            Reference.reachabilityFence(this);
+            ctx.check(rc);
+            ctx.maybeSync();
+        }
+
+        /** Copy the values of the array into a new Java array */
+        public float[] toArray() {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(JAVA_FLOAT, size());
+                values(out);
+                return out.toArray(JAVA_FLOAT);
+            }
+        }
+    }
+
+    /** Array type with 1 dimensions and f64 elements */
+    public static final class ArrayF64D1 extends FutharkValue {
+        private static final MethodHandle FUTHARK_NEW_F64_1D =
+            function("futhark_new_f64_1d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS, JAVA_LONG));
+        private static final MethodHandle FUTHARK_FREE_F64_1D =
+            function("futhark_free_f64_1d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_VALUES_F64_1D =
+            function("futhark_values_f64_1d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_SHAPE_F64_1D =
+            function("futhark_shape_f64_1d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS));
+
+        private final long[] shape;
+
+        ArrayF64D1(Context ctx, MemorySegment ptr) {
+            super(ctx, ptr, FUTHARK_FREE_F64_1D);
+            MemorySegment dims = (MemorySegment) call(FUTHARK_SHAPE_F64_1D, ctx.handle(), ptr);
+            this.shape = dims.reinterpret(1 * JAVA_LONG.byteSize()).toArray(JAVA_LONG);
+        }
+
+        /** Create a new array with the shape {@code dims} from the values in {@code data} */
+        public static ArrayF64D1 from(Context ctx, double[] data, long... dims) {
+            if (product(dims) != data.length) {
+                throw new IllegalArgumentException("Invalid shape, expected " + product(dims) + " elements, got " + data.length);
+            }
+            try (Arena arena = Arena.ofConfined()) {
+                return from(ctx, arena.allocateFrom(JAVA_DOUBLE, data), dims);
+            }
+        }
+
+        /** Create a new array with the shape {@code dims} by copying the values in {@code data} */
+        public static ArrayF64D1 from(Context ctx, MemorySegment data, long... dims) {
+            if (dims.length != 1) {
+                throw new IllegalArgumentException("Expected 1 dimensions, got " + dims.length);
+            }
+            if (data.byteSize() < product(dims) * JAVA_DOUBLE.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the given shape");
+            }
+            MemorySegment ptr = (MemorySegment) call(FUTHARK_NEW_F64_1D, ctx.handle(), data, dims[0]);
+            ArrayF64D1 array = new ArrayF64D1(ctx, ptr);
+            ctx.maybeSync();
+            return array;
+        }
+
+        /** Get the shape of the array */
+        public long[] shape() {
+            return shape.clone();
+        }
+
+        /** Get the number of elements in the array */
+        public long size() {
+            return product(shape);
+        }
+
+        /** Copy the values of the array into {@code out} */
+        public void values(MemorySegment out) {
+            if (out.byteSize() < size() * JAVA_DOUBLE.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the array");
+            }
+            int rc = (int) call(FUTHARK_VALUES_F64_1D, ctx.handle(), ptr(), out);
+            Reference.reachabilityFence(this);
+            ctx.check(rc);
+            ctx.maybeSync();
+        }
+
+        /** Copy the values of the array into a new Java array */
+        public double[] toArray() {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(JAVA_DOUBLE, size());
+                values(out);
+                return out.toArray(JAVA_DOUBLE);
+            }
+        }
+    }
+
+    /** Array type with 1 dimensions and i16 elements */
+    public static final class ArrayI16D1 extends FutharkValue {
+        private static final MethodHandle FUTHARK_NEW_I16_1D =
+            function("futhark_new_i16_1d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS, JAVA_LONG));
+        private static final MethodHandle FUTHARK_FREE_I16_1D =
+            function("futhark_free_i16_1d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_VALUES_I16_1D =
+            function("futhark_values_i16_1d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_SHAPE_I16_1D =
+            function("futhark_shape_i16_1d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS));
+
+        private final long[] shape;
+
+        ArrayI16D1(Context ctx, MemorySegment ptr) {
+            super(ctx, ptr, FUTHARK_FREE_I16_1D);
+            MemorySegment dims = (MemorySegment) call(FUTHARK_SHAPE_I16_1D, ctx.handle(), ptr);
+            this.shape = dims.reinterpret(1 * JAVA_LONG.byteSize()).toArray(JAVA_LONG);
+        }
+
+        /** Create a new array with the shape {@code dims} from the values in {@code data} */
+        public static ArrayI16D1 from(Context ctx, short[] data, long... dims) {
+            if (product(dims) != data.length) {
+                throw new IllegalArgumentException("Invalid shape, expected " + product(dims) + " elements, got " + data.length);
+            }
+            try (Arena arena = Arena.ofConfined()) {
+                return from(ctx, arena.allocateFrom(JAVA_SHORT, data), dims);
+            }
+        }
+
+        /** Create a new array with the shape {@code dims} by copying the values in {@code data} */
+        public static ArrayI16D1 from(Context ctx, MemorySegment data, long... dims) {
+            if (dims.length != 1) {
+                throw new IllegalArgumentException("Expected 1 dimensions, got " + dims.length);
+            }
+            if (data.byteSize() < product(dims) * JAVA_SHORT.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the given shape");
+            }
+            MemorySegment ptr = (MemorySegment) call(FUTHARK_NEW_I16_1D, ctx.handle(), data, dims[0]);
+            ArrayI16D1 array = new ArrayI16D1(ctx, ptr);
+            ctx.maybeSync();
+            return array;
+        }
+
+        /** Get the shape of the array */
+        public long[] shape() {
+            return shape.clone();
+        }
+
+        /** Get the number of elements in the array */
+        public long size() {
+            return product(shape);
+        }
+
+        /** Copy the values of the array into {@code out} */
+        public void values(MemorySegment out) {
+            if (out.byteSize() < size() * JAVA_SHORT.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the array");
+            }
+            int rc = (int) call(FUTHARK_VALUES_I16_1D, ctx.handle(), ptr(), out);
+            Reference.reachabilityFence(this);
+            ctx.check(rc);
+            ctx.maybeSync();
+        }
+
+        /** Copy the values of the array into a new Java array */
+        public short[] toArray() {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(JAVA_SHORT, size());
+                values(out);
+                return out.toArray(JAVA_SHORT);
+            }
+        }
+    }
+
+    /** Array type with 1 dimensions and i32 elements */
+    public static final class ArrayI32D1 extends FutharkValue {
+        private static final MethodHandle FUTHARK_NEW_I32_1D =
+            function("futhark_new_i32_1d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS, JAVA_LONG));
+        private static final MethodHandle FUTHARK_FREE_I32_1D =
+            function("futhark_free_i32_1d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_VALUES_I32_1D =
+            function("futhark_values_i32_1d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_SHAPE_I32_1D =
+            function("futhark_shape_i32_1d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS));
+
+        private final long[] shape;
+
+        ArrayI32D1(Context ctx, MemorySegment ptr) {
+            super(ctx, ptr, FUTHARK_FREE_I32_1D);
+            MemorySegment dims = (MemorySegment) call(FUTHARK_SHAPE_I32_1D, ctx.handle(), ptr);
+            this.shape = dims.reinterpret(1 * JAVA_LONG.byteSize()).toArray(JAVA_LONG);
+        }
+
+        /** Create a new array with the shape {@code dims} from the values in {@code data} */
+        public static ArrayI32D1 from(Context ctx, int[] data, long... dims) {
+            if (product(dims) != data.length) {
+                throw new IllegalArgumentException("Invalid shape, expected " + product(dims) + " elements, got " + data.length);
+            }
+            try (Arena arena = Arena.ofConfined()) {
+                return from(ctx, arena.allocateFrom(JAVA_INT, data), dims);
+            }
+        }
+
+        /** Create a new array with the shape {@code dims} by copying the values in {@code data} */
+        public static ArrayI32D1 from(Context ctx, MemorySegment data, long... dims) {
+            if (dims.length != 1) {
+                throw new IllegalArgumentException("Expected 1 dimensions, got " + dims.length);
+            }
+            if (data.byteSize() < product(dims) * JAVA_INT.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the given shape");
+            }
+            MemorySegment ptr = (MemorySegment) call(FUTHARK_NEW_I32_1D, ctx.handle(), data, dims[0]);
+            ArrayI32D1 array = new ArrayI32D1(ctx, ptr);
+            ctx.maybeSync();
+            return array;
+        }
+
+        /** Get the shape of the array */
+        public long[] shape() {
+            return shape.clone();
+        }
+
+        /** Get the number of elements in the array */
+        public long size() {
+            return product(shape);
+        }
+
+        /** Copy the values of the array into {@code out} */
+        public void values(MemorySegment out) {
+            if (out.byteSize() < size() * JAVA_INT.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the array");
+            }
+            int rc = (int) call(FUTHARK_VALUES_I32_1D, ctx.handle(), ptr(), out);
+            Reference.reachabilityFence(this);
+            ctx.check(rc);
+            ctx.maybeSync();
+        }
+
+        /** Copy the values of the array into a new Java array */
+        public int[] toArray() {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(JAVA_INT, size());
+                values(out);
+                return out.toArray(JAVA_INT);
+            }
+        }
+    }
+
+    /** Array type with 1 dimensions and i64 elements */
+    public static final class ArrayI64D1 extends FutharkValue {
+        private static final MethodHandle FUTHARK_NEW_I64_1D =
+            function("futhark_new_i64_1d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS, JAVA_LONG));
+        private static final MethodHandle FUTHARK_FREE_I64_1D =
+            function("futhark_free_i64_1d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_VALUES_I64_1D =
+            function("futhark_values_i64_1d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_SHAPE_I64_1D =
+            function("futhark_shape_i64_1d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS));
+
+        private final long[] shape;
+
+        ArrayI64D1(Context ctx, MemorySegment ptr) {
+            super(ctx, ptr, FUTHARK_FREE_I64_1D);
+            MemorySegment dims = (MemorySegment) call(FUTHARK_SHAPE_I64_1D, ctx.handle(), ptr);
+            this.shape = dims.reinterpret(1 * JAVA_LONG.byteSize()).toArray(JAVA_LONG);
+        }
+
+        /** Create a new array with the shape {@code dims} from the values in {@code data} */
+        public static ArrayI64D1 from(Context ctx, long[] data, long... dims) {
+            if (product(dims) != data.length) {
+                throw new IllegalArgumentException("Invalid shape, expected " + product(dims) + " elements, got " + data.length);
+            }
+            try (Arena arena = Arena.ofConfined()) {
+                return from(ctx, arena.allocateFrom(JAVA_LONG, data), dims);
+            }
+        }
+
+        /** Create a new array with the shape {@code dims} by copying the values in {@code data} */
+        public static ArrayI64D1 from(Context ctx, MemorySegment data, long... dims) {
+            if (dims.length != 1) {
+                throw new IllegalArgumentException("Expected 1 dimensions, got " + dims.length);
+            }
+            if (data.byteSize() < product(dims) * JAVA_LONG.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the given shape");
+            }
+            MemorySegment ptr = (MemorySegment) call(FUTHARK_NEW_I64_1D, ctx.handle(), data, dims[0]);
+            ArrayI64D1 array = new ArrayI64D1(ctx, ptr);
+            ctx.maybeSync();
+            return array;
+        }
+
+        /** Get the shape of the array */
+        public long[] shape() {
+            return shape.clone();
+        }
+
+        /** Get the number of elements in the array */
+        public long size() {
+            return product(shape);
+        }
+
+        /** Copy the values of the array into {@code out} */
+        public void values(MemorySegment out) {
+            if (out.byteSize() < size() * JAVA_LONG.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the array");
+            }
+            int rc = (int) call(FUTHARK_VALUES_I64_1D, ctx.handle(), ptr(), out);
+            Reference.reachabilityFence(this);
+            ctx.check(rc);
+            ctx.maybeSync();
+        }
+
+        /** Copy the values of the array into a new Java array */
+        public long[] toArray() {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(JAVA_LONG, size());
+                values(out);
+                return out.toArray(JAVA_LONG);
+            }
+        }
+    }
+
+    /** Array type with 1 dimensions and i8 elements */
+    public static final class ArrayI8D1 extends FutharkValue {
+        private static final MethodHandle FUTHARK_NEW_I8_1D =
+            function("futhark_new_i8_1d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS, JAVA_LONG));
+        private static final MethodHandle FUTHARK_FREE_I8_1D =
+            function("futhark_free_i8_1d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_VALUES_I8_1D =
+            function("futhark_values_i8_1d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_SHAPE_I8_1D =
+            function("futhark_shape_i8_1d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS));
+
+        private final long[] shape;
+
+        ArrayI8D1(Context ctx, MemorySegment ptr) {
+            super(ctx, ptr, FUTHARK_FREE_I8_1D);
+            MemorySegment dims = (MemorySegment) call(FUTHARK_SHAPE_I8_1D, ctx.handle(), ptr);
+            this.shape = dims.reinterpret(1 * JAVA_LONG.byteSize()).toArray(JAVA_LONG);
+        }
+
+        /** Create a new array with the shape {@code dims} from the values in {@code data} */
+        public static ArrayI8D1 from(Context ctx, byte[] data, long... dims) {
+            if (product(dims) != data.length) {
+                throw new IllegalArgumentException("Invalid shape, expected " + product(dims) + " elements, got " + data.length);
+            }
+            try (Arena arena = Arena.ofConfined()) {
+                return from(ctx, arena.allocateFrom(JAVA_BYTE, data), dims);
+            }
+        }
+
+        /** Create a new array with the shape {@code dims} by copying the values in {@code data} */
+        public static ArrayI8D1 from(Context ctx, MemorySegment data, long... dims) {
+            if (dims.length != 1) {
+                throw new IllegalArgumentException("Expected 1 dimensions, got " + dims.length);
+            }
+            if (data.byteSize() < product(dims) * JAVA_BYTE.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the given shape");
+            }
+            MemorySegment ptr = (MemorySegment) call(FUTHARK_NEW_I8_1D, ctx.handle(), data, dims[0]);
+            ArrayI8D1 array = new ArrayI8D1(ctx, ptr);
+            ctx.maybeSync();
+            return array;
+        }
+
+        /** Get the shape of the array */
+        public long[] shape() {
+            return shape.clone();
+        }
+
+        /** Get the number of elements in the array */
+        public long size() {
+            return product(shape);
+        }
+
+        /** Copy the values of the array into {@code out} */
+        public void values(MemorySegment out) {
+            if (out.byteSize() < size() * JAVA_BYTE.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the array");
+            }
+            int rc = (int) call(FUTHARK_VALUES_I8_1D, ctx.handle(), ptr(), out);
+            Reference.reachabilityFence(this);
+            ctx.check(rc);
+            ctx.maybeSync();
+        }
+
+        /** Copy the values of the array into a new Java array */
+        public byte[] toArray() {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(JAVA_BYTE, size());
+                values(out);
+                return out.toArray(JAVA_BYTE);
+            }
+        }
+    }
+
+    /** Array type with 1 dimensions and u16 elements */
+    public static final class ArrayU16D1 extends FutharkValue {
+        private static final MethodHandle FUTHARK_NEW_U16_1D =
+            function("futhark_new_u16_1d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS, JAVA_LONG));
+        private static final MethodHandle FUTHARK_FREE_U16_1D =
+            function("futhark_free_u16_1d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_VALUES_U16_1D =
+            function("futhark_values_u16_1d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_SHAPE_U16_1D =
+            function("futhark_shape_u16_1d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS));
+
+        private final long[] shape;
+
+        ArrayU16D1(Context ctx, MemorySegment ptr) {
+            super(ctx, ptr, FUTHARK_FREE_U16_1D);
+            MemorySegment dims = (MemorySegment) call(FUTHARK_SHAPE_U16_1D, ctx.handle(), ptr);
+            this.shape = dims.reinterpret(1 * JAVA_LONG.byteSize()).toArray(JAVA_LONG);
+        }
+
+        /** Create a new array with the shape {@code dims} from the values in {@code data} */
+        public static ArrayU16D1 from(Context ctx, short[] data, long... dims) {
+            if (product(dims) != data.length) {
+                throw new IllegalArgumentException("Invalid shape, expected " + product(dims) + " elements, got " + data.length);
+            }
+            try (Arena arena = Arena.ofConfined()) {
+                return from(ctx, arena.allocateFrom(JAVA_SHORT, data), dims);
+            }
+        }
+
+        /** Create a new array with the shape {@code dims} by copying the values in {@code data} */
+        public static ArrayU16D1 from(Context ctx, MemorySegment data, long... dims) {
+            if (dims.length != 1) {
+                throw new IllegalArgumentException("Expected 1 dimensions, got " + dims.length);
+            }
+            if (data.byteSize() < product(dims) * JAVA_SHORT.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the given shape");
+            }
+            MemorySegment ptr = (MemorySegment) call(FUTHARK_NEW_U16_1D, ctx.handle(), data, dims[0]);
+            ArrayU16D1 array = new ArrayU16D1(ctx, ptr);
+            ctx.maybeSync();
+            return array;
+        }
+
+        /** Get the shape of the array */
+        public long[] shape() {
+            return shape.clone();
+        }
+
+        /** Get the number of elements in the array */
+        public long size() {
+            return product(shape);
+        }
+
+        /** Copy the values of the array into {@code out} */
+        public void values(MemorySegment out) {
+            if (out.byteSize() < size() * JAVA_SHORT.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the array");
+            }
+            int rc = (int) call(FUTHARK_VALUES_U16_1D, ctx.handle(), ptr(), out);
+            Reference.reachabilityFence(this);
+            ctx.check(rc);
+            ctx.maybeSync();
+        }
+
+        /** Copy the values of the array into a new Java array */
+        public short[] toArray() {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(JAVA_SHORT, size());
+                values(out);
+                return out.toArray(JAVA_SHORT);
+            }
+        }
+    }
+
+    /** Array type with 1 dimensions and u32 elements */
+    public static final class ArrayU32D1 extends FutharkValue {
+        private static final MethodHandle FUTHARK_NEW_U32_1D =
+            function("futhark_new_u32_1d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS, JAVA_LONG));
+        private static final MethodHandle FUTHARK_FREE_U32_1D =
+            function("futhark_free_u32_1d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_VALUES_U32_1D =
+            function("futhark_values_u32_1d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_SHAPE_U32_1D =
+            function("futhark_shape_u32_1d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS));
+
+        private final long[] shape;
+
+        ArrayU32D1(Context ctx, MemorySegment ptr) {
+            super(ctx, ptr, FUTHARK_FREE_U32_1D);
+            MemorySegment dims = (MemorySegment) call(FUTHARK_SHAPE_U32_1D, ctx.handle(), ptr);
+            this.shape = dims.reinterpret(1 * JAVA_LONG.byteSize()).toArray(JAVA_LONG);
+        }
+
+        /** Create a new array with the shape {@code dims} from the values in {@code data} */
+        public static ArrayU32D1 from(Context ctx, int[] data, long... dims) {
+            if (product(dims) != data.length) {
+                throw new IllegalArgumentException("Invalid shape, expected " + product(dims) + " elements, got " + data.length);
+            }
+            try (Arena arena = Arena.ofConfined()) {
+                return from(ctx, arena.allocateFrom(JAVA_INT, data), dims);
+            }
+        }
+
+        /** Create a new array with the shape {@code dims} by copying the values in {@code data} */
+        public static ArrayU32D1 from(Context ctx, MemorySegment data, long... dims) {
+            if (dims.length != 1) {
+                throw new IllegalArgumentException("Expected 1 dimensions, got " + dims.length);
+            }
+            if (data.byteSize() < product(dims) * JAVA_INT.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the given shape");
+            }
+            MemorySegment ptr = (MemorySegment) call(FUTHARK_NEW_U32_1D, ctx.handle(), data, dims[0]);
+            ArrayU32D1 array = new ArrayU32D1(ctx, ptr);
+            ctx.maybeSync();
+            return array;
+        }
+
+        /** Get the shape of the array */
+        public long[] shape() {
+            return shape.clone();
+        }
+
+        /** Get the number of elements in the array */
+        public long size() {
+            return product(shape);
+        }
+
+        /** Copy the values of the array into {@code out} */
+        public void values(MemorySegment out) {
+            if (out.byteSize() < size() * JAVA_INT.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the array");
+            }
+            int rc = (int) call(FUTHARK_VALUES_U32_1D, ctx.handle(), ptr(), out);
+            Reference.reachabilityFence(this);
+            ctx.check(rc);
+            ctx.maybeSync();
+        }
+
+        /** Copy the values of the array into a new Java array */
+        public int[] toArray() {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(JAVA_INT, size());
+                values(out);
+                return out.toArray(JAVA_INT);
+            }
+        }
+    }
+
+    /** Array type with 1 dimensions and u64 elements */
+    public static final class ArrayU64D1 extends FutharkValue {
+        private static final MethodHandle FUTHARK_NEW_U64_1D =
+            function("futhark_new_u64_1d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS, JAVA_LONG));
+        private static final MethodHandle FUTHARK_FREE_U64_1D =
+            function("futhark_free_u64_1d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_VALUES_U64_1D =
+            function("futhark_values_u64_1d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_SHAPE_U64_1D =
+            function("futhark_shape_u64_1d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS));
+
+        private final long[] shape;
+
+        ArrayU64D1(Context ctx, MemorySegment ptr) {
+            super(ctx, ptr, FUTHARK_FREE_U64_1D);
+            MemorySegment dims = (MemorySegment) call(FUTHARK_SHAPE_U64_1D, ctx.handle(), ptr);
+            this.shape = dims.reinterpret(1 * JAVA_LONG.byteSize()).toArray(JAVA_LONG);
+        }
+
+        /** Create a new array with the shape {@code dims} from the values in {@code data} */
+        public static ArrayU64D1 from(Context ctx, long[] data, long... dims) {
+            if (product(dims) != data.length) {
+                throw new IllegalArgumentException("Invalid shape, expected " + product(dims) + " elements, got " + data.length);
+            }
+            try (Arena arena = Arena.ofConfined()) {
+                return from(ctx, arena.allocateFrom(JAVA_LONG, data), dims);
+            }
+        }
+
+        /** Create a new array with the shape {@code dims} by copying the values in {@code data} */
+        public static ArrayU64D1 from(Context ctx, MemorySegment data, long... dims) {
+            if (dims.length != 1) {
+                throw new IllegalArgumentException("Expected 1 dimensions, got " + dims.length);
+            }
+            if (data.byteSize() < product(dims) * JAVA_LONG.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the given shape");
+            }
+            MemorySegment ptr = (MemorySegment) call(FUTHARK_NEW_U64_1D, ctx.handle(), data, dims[0]);
+            ArrayU64D1 array = new ArrayU64D1(ctx, ptr);
+            ctx.maybeSync();
+            return array;
+        }
+
+        /** Get the shape of the array */
+        public long[] shape() {
+            return shape.clone();
+        }
+
+        /** Get the number of elements in the array */
+        public long size() {
+            return product(shape);
+        }
+
+        /** Copy the values of the array into {@code out} */
+        public void values(MemorySegment out) {
+            if (out.byteSize() < size() * JAVA_LONG.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the array");
+            }
+            int rc = (int) call(FUTHARK_VALUES_U64_1D, ctx.handle(), ptr(), out);
+            Reference.reachabilityFence(this);
+            ctx.check(rc);
+            ctx.maybeSync();
+        }
+
+        /** Copy the values of the array into a new Java array */
+        public long[] toArray() {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(JAVA_LONG, size());
+                values(out);
+                return out.toArray(JAVA_LONG);
+            }
+        }
+    }
+
+    /** Array type with 1 dimensions and u8 elements */
+    public static final class ArrayU8D1 extends FutharkValue {
+        private static final MethodHandle FUTHARK_NEW_U8_1D =
+            function("futhark_new_u8_1d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS, JAVA_LONG));
+        private static final MethodHandle FUTHARK_FREE_U8_1D =
+            function("futhark_free_u8_1d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_VALUES_U8_1D =
+            function("futhark_values_u8_1d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_SHAPE_U8_1D =
+            function("futhark_shape_u8_1d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS));
+
+        private final long[] shape;
+
+        ArrayU8D1(Context ctx, MemorySegment ptr) {
+            super(ctx, ptr, FUTHARK_FREE_U8_1D);
+            MemorySegment dims = (MemorySegment) call(FUTHARK_SHAPE_U8_1D, ctx.handle(), ptr);
+            this.shape = dims.reinterpret(1 * JAVA_LONG.byteSize()).toArray(JAVA_LONG);
+        }
+
+        /** Create a new array with the shape {@code dims} from the values in {@code data} */
+        public static ArrayU8D1 from(Context ctx, byte[] data, long... dims) {
+            if (product(dims) != data.length) {
+                throw new IllegalArgumentException("Invalid shape, expected " + product(dims) + " elements, got " + data.length);
+            }
+            try (Arena arena = Arena.ofConfined()) {
+                return from(ctx, arena.allocateFrom(JAVA_BYTE, data), dims);
+            }
+        }
+
+        /** Create a new array with the shape {@code dims} by copying the values in {@code data} */
+        public static ArrayU8D1 from(Context ctx, MemorySegment data, long... dims) {
+            if (dims.length != 1) {
+                throw new IllegalArgumentException("Expected 1 dimensions, got " + dims.length);
+            }
+            if (data.byteSize() < product(dims) * JAVA_BYTE.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the given shape");
+            }
+            MemorySegment ptr = (MemorySegment) call(FUTHARK_NEW_U8_1D, ctx.handle(), data, dims[0]);
+            ArrayU8D1 array = new ArrayU8D1(ctx, ptr);
+            ctx.maybeSync();
+            return array;
+        }
+
+        /** Get the shape of the array */
+        public long[] shape() {
+            return shape.clone();
+        }
+
+        /** Get the number of elements in the array */
+        public long size() {
+            return product(shape);
+        }
+
+        /** Copy the values of the array into {@code out} */
+        public void values(MemorySegment out) {
+            if (out.byteSize() < size() * JAVA_BYTE.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the array");
+            }
+            int rc = (int) call(FUTHARK_VALUES_U8_1D, ctx.handle(), ptr(), out);
+            Reference.reachabilityFence(this);
+            ctx.check(rc);
+            ctx.maybeSync();
+        }
+
+        /** Copy the values of the array into a new Java array */
+        public byte[] toArray() {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(JAVA_BYTE, size());
+                values(out);
+                return out.toArray(JAVA_BYTE);
+            }
+        }
+    }
+
+    /** Futhark context, values created using the context keep it alive until they are closed */
+    public static final class Context implements AutoCloseable {
+        static final MethodHandle FUTHARK_CONTEXT_CONFIG_NEW =
+            function("futhark_context_config_new", FunctionDescriptor.of(ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_CONFIG_FREE =
+            function("futhark_context_config_free", FunctionDescriptor.ofVoid(ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_CONFIG_SET_DEBUGGING =
+            function("futhark_context_config_set_debugging", FunctionDescriptor.ofVoid(ADDRESS, JAVA_INT));
+        static final MethodHandle FUTHARK_CONTEXT_CONFIG_SET_PROFILING =
+            function("futhark_context_config_set_profiling", FunctionDescriptor.ofVoid(ADDRESS, JAVA_INT));
+        static final MethodHandle FUTHARK_CONTEXT_CONFIG_SET_LOGGING =
+            function("futhark_context_config_set_logging", FunctionDescriptor.ofVoid(ADDRESS, JAVA_INT));
+        static final MethodHandle FUTHARK_CONTEXT_CONFIG_SET_CACHE_FILE =
+            function("futhark_context_config_set_cache_file", FunctionDescriptor.ofVoid(ADDRESS, ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_CONFIG_SET_NUM_THREADS =
+            function("futhark_context_config_set_num_threads", FunctionDescriptor.ofVoid(ADDRESS, JAVA_INT));
+        static final MethodHandle FUTHARK_CONTEXT_NEW =
+            function("futhark_context_new", FunctionDescriptor.of(ADDRESS, ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_FREE =
+            function("futhark_context_free", FunctionDescriptor.ofVoid(ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_SYNC =
+            function("futhark_context_sync", FunctionDescriptor.of(JAVA_INT, ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_CLEAR_CACHES =
+            function("futhark_context_clear_caches", FunctionDescriptor.of(JAVA_INT, ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_PAUSE_PROFILING =
+            function("futhark_context_pause_profiling", FunctionDescriptor.ofVoid(ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_UNPAUSE_PROFILING =
+            function("futhark_context_unpause_profiling", FunctionDescriptor.ofVoid(ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_GET_ERROR =
+            function("futhark_context_get_error", FunctionDescriptor.of(ADDRESS, ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_REPORT =
+            function("futhark_context_report", FunctionDescriptor.of(ADDRESS, ADDRESS));
+
+        final ContextState state;
+        private final Cleaner.Cleanable cleanable;
+        private volatile boolean closed;
+        private volatile boolean autoSync;
+
+        /** Create a new context using the default options */
+        public Context() {
+            this(new Options());
+        }
+
+        /** Create a new context */
+        public Context(Options options) {
+            MemorySegment config = (MemorySegment) call(FUTHARK_CONTEXT_CONFIG_NEW);
+            if (config.equals(MemorySegment.NULL)) {
+                throw new NullPtrException();
+            }
+            call(FUTHARK_CONTEXT_CONFIG_SET_DEBUGGING, config, options.debug ? 1 : 0);
+            call(FUTHARK_CONTEXT_CONFIG_SET_PROFILING, config, options.profile ? 1 : 0);
+            call(FUTHARK_CONTEXT_CONFIG_SET_LOGGING, config, options.logging ? 1 : 0);
+            // Strings passed to the config are kept alive until the context is freed
+            Arena arena = Arena.ofShared();
+            if (options.cacheFile != null) {
+                call(FUTHARK_CONTEXT_CONFIG_SET_CACHE_FILE, config, arena.allocateFrom(options.cacheFile));
+            }
+            if (options.numThreads != 0) {
+                call(FUTHARK_CONTEXT_CONFIG_SET_NUM_THREADS, config, options.numThreads);
+            }
+
+            MemorySegment handle = (MemorySegment) call(FUTHARK_CONTEXT_NEW, config);
+            if (handle.equals(MemorySegment.NULL)) {
+                call(FUTHARK_CONTEXT_CONFIG_FREE, config);
+                arena.close();
+                throw new NullPtrException();
+            }
+            ContextState state = new ContextState(config, handle, arena);
+            this.state = state;
+            this.cleanable = CLEANER.register(this, state::release);
+            this.autoSync = options.autoSync;
+        }
+
+        MemorySegment handle() {
+            if (closed) {
+                throw new IllegalStateException("Context has already been closed");
+            }
+            return state.handle;
+        }
+
+        /** Free the context once all values created using it have been closed */
+        @Override
+        public void close() {
+            closed = true;
+            cleanable.clean();
+        }
+
+        void check(int rc) {
+            if (rc != 0) {
+                throw new FutharkException(rc, Objects.requireNonNullElse(getError(), ""));
+            }
+        }
+
+        void maybeSync() {
+            if (autoSync) {
+                sync();
+            }
+        }
+
+        /** Sync after every operation */
+        public void setAutoSync(boolean autoSync) {
+            this.autoSync = autoSync;
+        }
+
+        /** Wait for all pending operations to finish */
+        public void sync() {
+            check((int) call(FUTHARK_CONTEXT_SYNC, handle()));
+        }
+
+        /** Free any cached memory */
+        public void clearCaches() {
+            check((int) call(FUTHARK_CONTEXT_CLEAR_CACHES, handle()));
+        }
+
+        /** Pause profiling */
+        public void pauseProfiling() {
+            call(FUTHARK_CONTEXT_PAUSE_PROFILING, handle());
+        }
+
+        /** Resume profiling */
+        public void unpauseProfiling() {
+            call(FUTHARK_CONTEXT_UNPAUSE_PROFILING, handle());
+        }
+
+        /** Get the last error message */
+        public String getError() {
+            return takeString((MemorySegment) call(FUTHARK_CONTEXT_GET_ERROR, handle()));
+        }
+
+        /** Get the profiling report */
+        public String report() {
+            return takeString((MemorySegment) call(FUTHARK_CONTEXT_REPORT, handle()));
+        }
+
+        private static final MethodHandle FUTHARK_ENTRY_FLATTEN =
+            function("futhark_entry_flatten", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+
+        /** Entry point: flatten */
+        public ArrayF32D2 flatten(ArrayF32D3 input0) {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out0 = arena.allocate(ADDRESS);
+                int rc = (int) call(FUTHARK_ENTRY_FLATTEN, handle(), out0, input0.ptr());
+                Reference.reachabilityFence(input0);
+                check(rc);
+                ArrayF32D2 result = new ArrayF32D2(this, out0.get(ADDRESS, 0));
+                maybeSync();
+                return result;
+            }
+        }
+
+        private static final MethodHandle FUTHARK_ENTRY_LENGTHS =
+            function("futhark_entry_lengths", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS, ADDRESS, ADDRESS, ADDRESS, ADDRESS, ADDRESS, ADDRESS, ADDRESS, ADDRESS, ADDRESS, ADDRESS, ADDRESS));
+
+        /** Entry point: lengths */
+        public long lengths(ArrayI8D1 input0, ArrayI16D1 input1, ArrayI32D1 input2, ArrayI64D1 input3, ArrayU8D1 input4, ArrayU16D1 input5, ArrayU32D1 input6, ArrayU64D1 input7, ArrayF16D1 input8, ArrayF32D1 input9, ArrayF64D1 input10, ArrayBoolD1 input11) {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out0 = arena.allocate(JAVA_LONG);
+                int rc = (int) call(FUTHARK_ENTRY_LENGTHS, handle(), out0, input0.ptr(), input1.ptr(), input2.ptr(), input3.ptr(), input4.ptr(), input5.ptr(), input6.ptr(), input7.ptr(), input8.ptr(), input9.ptr(), input10.ptr(), input11.ptr());
+                Reference.reachabilityFence(input0);
+                Reference.reachabilityFence(input1);
+                Reference.reachabilityFence(input2);
+                Reference.reachabilityFence(input3);
+                Reference.reachabilityFence(input4);
+                Reference.reachabilityFence(input5);
+                Reference.reachabilityFence(input6);
+                Reference.reachabilityFence(input7);
+                Reference.reachabilityFence(input8);
+                Reference.reachabilityFence(input9);
+                Reference.reachabilityFence(input10);
+                Reference.reachabilityFence(input11);
+                check(rc);
+                long result = out0.get(JAVA_LONG, 0);
+                maybeSync();
+                return result;
+            }
+        }
+
+        /** Outputs of the sum_rows entry point */
+        public record SumRowsResult(ArrayF32D1 out0, float out1) {
+        }
+
+        private static final MethodHandle FUTHARK_ENTRY_SUM_ROWS =
+            function("futhark_entry_sum_rows", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS, ADDRESS));
+
+        /** Entry point: sum_rows */
+        public SumRowsResult sumRows(ArrayF32D2 input0) {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out0 = arena.allocate(ADDRESS);
+                MemorySegment out1 = arena.allocate(JAVA_FLOAT);
+                int rc = (int) call(FUTHARK_ENTRY_SUM_ROWS, handle(), out0, out1, input0.ptr());
+                Reference.reachabilityFence(input0);
+                check(rc);
+                SumRowsResult result = new SumRowsResult(new ArrayF32D1(this, out0.get(ADDRESS, 0)), out1.get(JAVA_FLOAT, 0));
+                maybeSync();
+                return result;
+            }
+        }
+    }
+}
@@ -0,0 +1,957 @@
+# Generated by futhark-bindgen
+
+module Arrays
+
+export Context, FutharkError, NullPtrError, InvalidShapeError, free!, sync!
+
+# Path to the shared library containing the compiled Futhark code
+const LIB = get(ENV, "FUTHARK_LIB_ARRAYS", joinpath(@__DIR__, "libarrays.so"))
+
+"""
+Returned when a Futhark function returns a non-zero error code
+"""
+struct FutharkError <: Exception
+    code::Cint
+    message::String
+end
+
+Base.showerror(io::IO, e::FutharkError) = print(io, "Futhark error code $(e.code): $(e.message)")
+
+"""
+Returned when a Futhark function returns a NULL pointer
+"""
+struct NullPtrError <: Exception end
+
+"""
+Returned when data doesn't match the shape of an array
+"""
+struct InvalidShapeError <: Exception
+    expected::Int
+    actual::Int
+end
+
+"""
+Futhark context, freed by `free!` or when it is garbage collected
+"""
+mutable struct Context
+    config::Ptr{Cvoid}
+    handle::Ptr{Cvoid}
+    auto_sync::Bool
+
+    function Context(; debug::Bool=false, profile::Bool=false, logging::Bool=false,
+                     cache_file::Union{String,Nothing}=nothing, auto_sync::Bool=true, num_threads::Integer=0)
+        config = ccall((:futhark_context_config_new, LIB), Ptr{Cvoid}, ())
+        config == C_NULL && throw(NullPtrError())
+        ccall((:futhark_context_config_set_debugging, LIB), Cvoid, (Ptr{Cvoid}, Cint), config, debug)
+        ccall((:futhark_context_config_set_profiling, LIB), Cvoid, (Ptr{Cvoid}, Cint), config, profile)
+        ccall((:futhark_context_config_set_logging, LIB), Cvoid, (Ptr{Cvoid}, Cint), config, logging)
+        if cache_file !== nothing
+            ccall((:futhark_context_config_set_cache_file, LIB), Cvoid, (Ptr{Cvoid}, Cstring), config, cache_file)
+        end
+        ccall((:futhark_context_config_set_num_threads, LIB), Cvoid, (Ptr{Cvoid}, Cint), config, num_threads)
+        handle = ccall((:futhark_context_new, LIB), Ptr{Cvoid}, (Ptr{Cvoid},), config)
+        if handle == C_NULL
+            ccall((:futhark_context_config_free, LIB), Cvoid, (Ptr{Cvoid},), config)
+            throw(NullPtrError())
+        end
+        finalizer(free!, new(config, handle, auto_sync))
+    end
+end
+
+"""
+Free the context, values created using the context can no longer be used
+"""
+function free!(ctx::Context)
+    if ctx.handle != C_NULL
+        ccall((:futhark_context_sync, LIB), Cint, (Ptr{Cvoid},), ctx.handle)
+        ccall((:futhark_context_free, LIB), Cvoid, (Ptr{Cvoid},), ctx.handle)
+        ccall((:futhark_context_config_free, LIB), Cvoid, (Ptr{Cvoid},), ctx.config)
+        ctx.handle = C_NULL
+        ctx.config = C_NULL
+    end
+    nothing
+end
+
+function check_handle(ctx::Context)
+    ctx.handle == C_NULL && error("Context has already been freed")
+    ctx.handle
+end
+
+function take_string(ptr::Ptr{Cchar})
+    ptr == C_NULL && return nothing
+    s = unsafe_string(ptr)
+    Libc.free(ptr)
+    s
+end
+
+"""
+Get the last error message, or `nothing`
+"""
+get_error(ctx::Context) = take_string(ccall((:futhark_context_get_error, LIB), Ptr{Cchar}, (Ptr{Cvoid},), check_handle(ctx)))
+
+"""
+Get the profiling report
+"""
+report(ctx::Context) = take_string(ccall((:futhark_context_report, LIB), Ptr{Cchar}, (Ptr{Cvoid},), check_handle(ctx)))
+
+function check_rc(ctx::Context, rc::Cint)
+    if rc != 0
+        msg = get_error(ctx)
+        throw(FutharkError(rc, msg === nothing ? "" : msg))
+    end
+    nothing
+end
+
+"""
+Wait for all pending operations to finish
+"""
+sync!(ctx::Context) = check_rc(ctx, ccall((:futhark_context_sync, LIB), Cint, (Ptr{Cvoid},), check_handle(ctx)))
+
+auto_sync!(ctx::Context) = ctx.auto_sync && sync!(ctx)
+
+"""
+Free any cached memory
+"""
+clear_caches!(ctx::Context) = check_rc(ctx, ccall((:futhark_context_clear_caches, LIB), Cint, (Ptr{Cvoid},), check_handle(ctx)))
+
+pause_profiling!(ctx::Context) = ccall((:futhark_context_pause_profiling, LIB), Cvoid, (Ptr{Cvoid},), check_handle(ctx))
+
+unpause_profiling!(ctx::Context) = ccall((:futhark_context_unpause_profiling, LIB), Cvoid, (Ptr{Cvoid},), check_handle(ctx))
+
+# Futhark arrays are row-major and Julia arrays are column-major, so the order of the
+# dimensions is reversed when copying data in either direction
+row_major(a::AbstractArray{T,N}) where {T,N} = permutedims(a, ntuple(i -> N - i + 1, N))
+
+"""
+Array type with 3 dimensions and f32 elements
+"""
+mutable struct ArrayF32D3
+    ptr::Ptr{Cvoid}
+    shape::NTuple{3,Int64}
+    ctx::Context
+
+    function ArrayF32D3(ctx::Context, ptr::Ptr{Cvoid})
+        shape = unsafe_load(ccall((:futhark_shape_f32_3d, LIB), Ptr{NTuple{3,Int64}}, (Ptr{Cvoid}, Ptr{Cvoid}), ctx.handle, ptr))
+        finalizer(free!, new(ptr, shape, ctx))
+    end
+end
+
+"""
+Create a new array from a Julia array, the array keeps the same shape
+"""
+function ArrayF32D3(ctx::Context, data::AbstractArray{Float32,3})
+    dims = size(data)
+    data = row_major(data)
+    ptr = ccall((:futhark_new_f32_3d, LIB), Ptr{Cvoid}, (Ptr{Cvoid}, Ptr{Float32}, Int64, Int64, Int64), check_handle(ctx), data, dims...)
+    ptr == C_NULL && throw(NullPtrError())
+    auto_sync!(ctx)
+    ArrayF32D3(ctx, ptr)
+end
+
+"""
+Free the array, it is also freed when it is garbage collected
+"""
+function free!(a::ArrayF32D3)
+    if a.ptr != C_NULL && a.ctx.handle != C_NULL
+        ccall((:futhark_free_f32_3d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}), a.ctx.handle, a.ptr)
+    end
+    a.ptr = C_NULL
+    nothing
+end
+
+function check_ptr(a::ArrayF32D3)
+    a.ptr == C_NULL && error("Array has already been freed")
+    a.ptr
+end
+
+Base.size(a::ArrayF32D3) = Int.(a.shape)
+Base.length(a::ArrayF32D3) = prod(a.shape)
+Base.eltype(::Type{ArrayF32D3}) = Float32
+
+"""
+Copy the values into a new Julia array with the same shape
+"""
+function Base.Array(a::ArrayF32D3)
+    out = Array{Float32,3}(undef, reverse(size(a))...)
+    rc = GC.@preserve a ccall((:futhark_values_f32_3d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}, Ptr{Float32}), check_handle(a.ctx), check_ptr(a), out)
+    check_rc(a.ctx, rc)
+    auto_sync!(a.ctx)
+    row_major(out)
+end
+
+"""
+Array type with 2 dimensions and f32 elements
+"""
+mutable struct ArrayF32D2
+    ptr::Ptr{Cvoid}
+    shape::NTuple{2,Int64}
+    ctx::Context
+
+    function ArrayF32D2(ctx::Context, ptr::Ptr{Cvoid})
+        shape = unsafe_load(ccall((:futhark_shape_f32_2d, LIB), Ptr{NTuple{2,Int64}}, (Ptr{Cvoid}, Ptr{Cvoid}), ctx.handle, ptr))
+        finalizer(free!, new(ptr, shape, ctx))
+    end
+end
+
+"""
+Create a new array from a Julia array, the array keeps the same shape
+"""
+function ArrayF32D2(ctx::Context, data::AbstractArray{Float32,2})
+    dims = size(data)
+    data = row_major(data)
+    ptr = ccall((:futhark_new_f32_2d, LIB), Ptr{Cvoid}, (Ptr{Cvoid}, Ptr{Float32}, Int64, Int64), check_handle(ctx), data, dims...)
+    ptr == C_NULL && throw(NullPtrError())
+    auto_sync!(ctx)
+    ArrayF32D2(ctx, ptr)
+end
+
+"""
+Free the array, it is also freed when it is garbage collected
+"""
+function free!(a::ArrayF32D2)
+    if a.ptr != C_NULL && a.ctx.handle != C_NULL
+        ccall((:futhark_free_f32_2d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}), a.ctx.handle, a.ptr)
+    end
+    a.ptr = C_NULL
+    nothing
+end
+
+function check_ptr(a::ArrayF32D2)
+    a.ptr == C_NULL && error("Array has already been freed")
+    a.ptr
+end
+
+Base.size(a::ArrayF32D2) = Int.(a.shape)
+Base.length(a::ArrayF32D2) = prod(a.shape)
+Base.eltype(::Type{ArrayF32D2}) = Float32
+
+"""
+Copy the values into a new Julia array with the same shape
+"""
+function Base.Array(a::ArrayF32D2)
+    out = Array{Float32,2}(undef, reverse(size(a))...)
+    rc = GC.@preserve a ccall((:futhark_values_f32_2d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}, Ptr{Float32}), check_handle(a.ctx), check_ptr(a), out)
+    check_rc(a.ctx, rc)
+    auto_sync!(a.ctx)
+    row_major(out)
+end
+
+"""
+Array type with 1 dimensions and bool elements
+"""
+mutable struct ArrayBoolD1
+    ptr::Ptr{Cvoid}
+    shape::NTuple{1,Int64}
+    ctx::Context
+
+    function ArrayBoolD1(ctx::Context, ptr::Ptr{Cvoid})
+        shape = unsafe_load(ccall((:futhark_shape_bool_1d, LIB), Ptr{NTuple{1,Int64}}, (Ptr{Cvoid}, Ptr{Cvoid}), ctx.handle, ptr))
+        finalizer(free!, new(ptr, shape, ctx))
+    end
+end
+
+"""
+Create a new array from a Julia array, the array keeps the same shape
+"""
+function ArrayBoolD1(ctx::Context, data::AbstractArray{Bool,1})
+    dims = size(data)
+    data = row_major(data)
+    ptr = ccall((:futhark_new_bool_1d, LIB), Ptr{Cvoid}, (Ptr{Cvoid}, Ptr{Bool}, Int64), check_handle(ctx), data, dims...)
+    ptr == C_NULL && throw(NullPtrError())
+    auto_sync!(ctx)
+    ArrayBoolD1(ctx, ptr)
+end
+
+"""
+Free the array, it is also freed when it is garbage collected
+"""
+function free!(a::ArrayBoolD1)
+    if a.ptr != C_NULL && a.ctx.handle != C_NULL
+        ccall((:futhark_free_bool_1d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}), a.ctx.handle, a.ptr)
+    end
+    a.ptr = C_NULL
+    nothing
+end
+
+function check_ptr(a::ArrayBoolD1)
+    a.ptr == C_NULL && error("Array has already been freed")
+    a.ptr
+end
+
+Base.size(a::ArrayBoolD1) = Int.(a.shape)
+Base.length(a::ArrayBoolD1) = prod(a.shape)
+Base.eltype(::Type{ArrayBoolD1}) = Bool
+
+"""
+Copy the values into a new Julia array with the same shape
+"""
+function Base.Array(a::ArrayBoolD1)
+    out = Array{Bool,1}(undef, reverse(size(a))...)
+    rc = GC.@preserve a ccall((:futhark_values_bool_1d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}, Ptr{Bool}), check_handle(a.ctx), check_ptr(a), out)
+    check_rc(a.ctx, rc)
+    auto_sync!(a.ctx)
+    row_major(out)
+end
+
+"""
+Array type with 1 dimensions and f16 elements
+"""
+mutable struct ArrayF16D1
+    ptr::Ptr{Cvoid}
+    shape::NTuple{1,Int64}
+    ctx::Context
+
+    function ArrayF16D1(ctx::Context, ptr::Ptr{Cvoid})
+        shape = unsafe_load(ccall((:futhark_shape_f16_1d, LIB), Ptr{NTuple{1,Int64}}, (Ptr{Cvoid}, Ptr{Cvoid}), ctx.handle, ptr))
+        finalizer(free!, new(ptr, shape, ctx))
+    end
+end
+
+"""
+Create a new array from a Julia array, the array keeps the same shape
+"""
+function ArrayF16D1(ctx::Context, data::AbstractArray{Float16,1})
+    dims = size(data)
+    data = row_major(data)
+    ptr = ccall((:futhark_new_f16_1d, LIB), Ptr{Cvoid}, (Ptr{Cvoid}, Ptr{Float16}, Int64), check_handle(ctx), data, dims...)
+    ptr == C_NULL && throw(NullPtrError())
+    auto_sync!(ctx)
+    ArrayF16D1(ctx, ptr)
+end
+
+"""
+Free the array, it is also freed when it is garbage collected
+"""
+function free!(a::ArrayF16D1)
+    if a.ptr != C_NULL && a.ctx.handle != C_NULL
+        ccall((:futhark_free_f16_1d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}), a.ctx.handle, a.ptr)
+    end
+    a.ptr = C_NULL
+    nothing
+end
+
+function check_ptr(a::ArrayF16D1)
+    a.ptr == C_NULL && error("Array has already been freed")
+    a.ptr
+end
+
+Base.size(a::ArrayF16D1) = Int.(a.shape)
+Base.length(a::ArrayF16D1) = prod(a.shape)
+Base.eltype(::Type{ArrayF16D1}) = Float16
+
+"""
+Copy the values into a new Julia array with the same shape
+"""
+function Base.Array(a::ArrayF16D1)
+    out = Array{Float16,1}(undef, reverse(size(a))...)
+    rc = GC.@preserve a ccall((:futhark_values_f16_1d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}, Ptr{Float16}), check_handle(a.ctx), check_ptr(a), out)
+    check_rc(a.ctx, rc)
+    auto_sync!(a.ctx)
+    row_major(out)
+end
+
+"""
+Array type with 1 dimensions and f32 elements
+"""
+mutable struct ArrayF32D1
+    ptr::Ptr{Cvoid}
+    shape::NTuple{1,Int64}
+    ctx::Context
+
+    function ArrayF32D1(ctx::Context, ptr::Ptr{Cvoid})
+        shape = unsafe_load(ccall((:futhark_shape_f32_1d, LIB), Ptr{NTuple{1,Int64}}, (Ptr{Cvoid}, Ptr{Cvoid}), ctx.handle, ptr))
+        finalizer(free!, new(ptr, shape, ctx))
+    end
+end
+
+"""
+Create a new array from a Julia array, the array keeps the same shape
+"""
+function ArrayF32D1(ctx::Context, data::AbstractArray{Float32,1})
+    dims = size(data)
+    data = row_major(data)
+    ptr = ccall((:futhark_new_f32_1d, LIB), Ptr{Cvoid}, (Ptr{Cvoid}, Ptr{Float32}, Int64), check_handle(ctx), data, dims...)
+    ptr == C_NULL && throw(NullPtrError())
+    auto_sync!(ctx)
+    ArrayF32D1(ctx, ptr)
+end
+
+"""
+Free the array, it is also freed when it is garbage collected
+"""
+function free!(a::ArrayF32D1)
+    if a.ptr != C_NULL && a.ctx.handle != C_NULL
+        ccall((:futhark_free_f32_1d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}), a.ctx.handle, a.ptr)
+    end
+    a.ptr = C_NULL
+    nothing
+end
+
+function check_ptr(a::ArrayF32D1)
+    a.ptr == C_NULL && error("Array has already been freed")
+    a.ptr
+end
+
+Base.size(a::ArrayF32D1) = Int.(a.shape)
+Base.length(a::ArrayF32D1) = prod(a.shape)
+Base.eltype(::Type{ArrayF32D1}) = Float32
+
+"""
+Copy the values into a new Julia array with the same shape
+"""
+function Base.Array(a::ArrayF32D1)
+    out = Array{Float32,1}(undef, reverse(size(a))...)
+    rc = GC.@preserve a ccall((:futhark_values_f32_1d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}, Ptr{Float32}), check_handle(a.ctx), check_ptr(a), out)
+    check_rc(a.ctx, rc)
+    auto_sync!(a.ctx)
+    row_major(out)
+end
+
+"""
+Array type with 1 dimensions and f64 elements
+"""
+mutable struct ArrayF64D1
+    ptr::Ptr{Cvoid}
+    shape::NTuple{1,Int64}
+    ctx::Context
+
+    function ArrayF64D1(ctx::Context, ptr::Ptr{Cvoid})
+        shape = unsafe_load(ccall((:futhark_shape_f64_1d, LIB), Ptr{NTuple{1,Int64}}, (Ptr{Cvoid}, Ptr{Cvoid}), ctx.handle, ptr))
+        finalizer(free!, new(ptr, shape, ctx))
+    end
+end
+
+"""
+Create a new array from a Julia array, the array keeps the same shape
+"""
+function ArrayF64D1(ctx::Context, data::AbstractArray{Float64,1})
+    dims = size(data)
+    data = row_major(data)
+    ptr = ccall((:futhark_new_f64_1d, LIB), Ptr{Cvoid}, (Ptr{Cvoid}, Ptr{Float64}, Int64), check_handle(ctx), data, dims...)
+    ptr == C_NULL && throw(NullPtrError())
+    auto_sync!(ctx)
+    ArrayF64D1(ctx, ptr)
+end
+
+"""
+Free the array, it is also freed when it is garbage collected
+"""
+function free!(a::ArrayF64D1)
+    if a.ptr != C_NULL && a.ctx.handle != C_NULL
+        ccall((:futhark_free_f64_1d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}), a.ctx.handle, a.ptr)
+    end
+    a.ptr = C_NULL
+    nothing
+end
+
+function check_ptr(a::ArrayF64D1)
+    a.ptr == C_NULL && error("Array has already been freed")
+    a.ptr
+end
+
+Base.size(a::ArrayF64D1) = Int.(a.shape)
+Base.length(a::ArrayF64D1) = prod(a.shape)
+Base.eltype(::Type{ArrayF64D1}) = Float64
+
+"""
+Copy the values into a new Julia array with the same shape
+"""
+function Base.Array(a::ArrayF64D1)
+    out = Array{Float64,1}(undef, reverse(size(a))...)
+    rc = GC.@preserve a ccall((:futhark_values_f64_1d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}, Ptr{Float64}), check_handle(a.ctx), check_ptr(a), out)
+    check_rc(a.ctx, rc)
+    auto_sync!(a.ctx)
+    row_major(out)
+end
+
+"""
+Array type with 1 dimensions and i16 elements
+"""
+mutable struct ArrayI16D1
+    ptr::Ptr{Cvoid}
+    shape::NTuple{1,Int64}
+    ctx::Context
+
+    function ArrayI16D1(ctx::Context, ptr::Ptr{Cvoid})
+        shape = unsafe_load(ccall((:futhark_shape_i16_1d, LIB), Ptr{NTuple{1,Int64}}, (Ptr{Cvoid}, Ptr{Cvoid}), ctx.handle, ptr))
+        finalizer(free!, new(ptr, shape, ctx))
+    end
+end
+
+"""
+Create a new array from a Julia array, the array keeps the same shape
+"""
+function ArrayI16D1(ctx::Context, data::AbstractArray{Int16,1})
+    dims = size(data)
+    data = row_major(data)
+    ptr = ccall((:futhark_new_i16_1d, LIB), Ptr{Cvoid}, (Ptr{Cvoid}, Ptr{Int16}, Int64), check_handle(ctx), data, dims...)
+    ptr == C_NULL && throw(NullPtrError())
+    auto_sync!(ctx)
+    ArrayI16D1(ctx, ptr)
+end
+
+"""
+Free the array, it is also freed when it is garbage collected
+"""
+function free!(a::ArrayI16D1)
+    if a.ptr != C_NULL && a.ctx.handle != C_NULL
+        ccall((:futhark_free_i16_1d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}), a.ctx.handle, a.ptr)
+    end
+    a.ptr = C_NULL
+    nothing
+end
+
+function check_ptr(a::ArrayI16D1)
+    a.ptr == C_NULL && error("Array has already been freed")
+    a.ptr
+end
+
+Base.size(a::ArrayI16D1) = Int.(a.shape)
+Base.length(a::ArrayI16D1) = prod(a.shape)
+Base.eltype(::Type{ArrayI16D1}) = Int16
+
+"""
+Copy the values into a new Julia array with the same shape
+"""
+function Base.Array(a::ArrayI16D1)
+    out = Array{Int16,1}(undef, reverse(size(a))...)
+    rc = GC.@preserve a ccall((:futhark_values_i16_1d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}, Ptr{Int16}), check_handle(a.ctx), check_ptr(a), out)
+    check_rc(a.ctx, rc)
+    auto_sync!(a.ctx)
+    row_major(out)
+end
+
+"""
+Array type with 1 dimensions and i32 elements
+"""
+mutable struct ArrayI32D1
+    ptr::Ptr{Cvoid}
+    shape::NTuple{1,Int64}
+    ctx::Context
+
+    function ArrayI32D1(ctx::Context, ptr::Ptr{Cvoid})
+        shape = unsafe_load(ccall((:futhark_shape_i32_1d, LIB), Ptr{NTuple{1,Int64}}, (Ptr{Cvoid}, Ptr{Cvoid}), ctx.handle, ptr))
+        finalizer(free!, new(ptr, shape, ctx))
+    end
+end
+
+"""
+Create a new array from a Julia array, the array keeps the same shape
+"""
+function ArrayI32D1(ctx::Context, data::AbstractArray{Int32,1})
+    dims = size(data)
+    data = row_major(data)
+    ptr = ccall((:futhark_new_i32_1d, LIB), Ptr{Cvoid}, (Ptr{Cvoid}, Ptr{Int32}, Int64), check_handle(ctx), data, dims...)
+    ptr == C_NULL && throw(NullPtrError())
+    auto_sync!(ctx)
+    ArrayI32D1(ctx, ptr)
+end
+
+"""
+Free the array, it is also freed when it is garbage collected
+"""
+function free!(a::ArrayI32D1)
+    if a.ptr != C_NULL && a.ctx.handle != C_NULL
+        ccall((:futhark_free_i32_1d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}), a.ctx.handle, a.ptr)
+    end
+    a.ptr = C_NULL
+    nothing
+end
+
+function check_ptr(a::ArrayI32D1)
+    a.ptr == C_NULL && error("Array has already been freed")
+    a.ptr
+end
+
+Base.size(a::ArrayI32D1) = Int.(a.shape)
+Base.length(a::ArrayI32D1) = prod(a.shape)
+Base.eltype(::Type{ArrayI32D1}) = Int32
+
+"""
+Copy the values into a new Julia array with the same shape
+"""
+function Base.Array(a::ArrayI32D1)
+    out = Array{Int32,1}(undef, reverse(size(a))...)
+    rc = GC.@preserve a ccall((:futhark_values_i32_1d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}, Ptr{Int32}), check_handle(a.ctx), check_ptr(a), out)
+    check_rc(a.ctx, rc)
+    auto_sync!(a.ctx)
+    row_major(out)
+end
+
+"""
+Array type with 1 dimensions and i64 elements
+"""
+mutable struct ArrayI64D1
+    ptr::Ptr{Cvoid}
+    shape::NTuple{1,Int64}
+    ctx::Context
+
+    function ArrayI64D1(ctx::Context, ptr::Ptr{Cvoid})
+        shape = unsafe_load(ccall((:futhark_shape_i64_1d, LIB), Ptr{NTuple{1,Int64}}, (Ptr{Cvoid}, Ptr{Cvoid}), ctx.handle, ptr))
+        finalizer(free!, new(ptr, shape, ctx))
+    end
+end
+
+"""
+Create a new array from a Julia array, the array keeps the same shape
+"""
+function ArrayI64D1(ctx::Context, data::AbstractArray{Int64,1})
+    dims = size(data)
+    data = row_major(data)
+    ptr = ccall((:futhark_new_i64_1d, LIB), Ptr{Cvoid}, (Ptr{Cvoid}, Ptr{Int64}, Int64), check_handle(ctx), data, dims...)
+    ptr == C_NULL && throw(NullPtrError())
+    auto_sync!(ctx)
+    ArrayI64D1(ctx, ptr)
+end
+
+"""
+Free the array, it is also freed when it is garbage collected
+"""
+function free!(a::ArrayI64D1)
+    if a.ptr != C_NULL && a.ctx.handle != C_NULL
+        ccall((:futhark_free_i64_1d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}), a.ctx.handle, a.ptr)
+    end
+    a.ptr = C_NULL
+    nothing
+end
+
+function check_ptr(a::ArrayI64D1)
+    a.ptr == C_NULL && error("Array has already been freed")
+    a.ptr
+end
+
+Base.size(a::ArrayI64D1) = Int.(a.shape)
+Base.length(a::ArrayI64D1) = prod(a.shape)
+Base.eltype(::Type{ArrayI64D1}) = Int64
+
+"""
+Copy the values into a new Julia array with the same shape
+"""
+function Base.Array(a::ArrayI64D1)
+    out = Array{Int64,1}(undef, reverse(size(a))...)
+    rc = GC.@preserve a ccall((:futhark_values_i64_1d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}, Ptr{Int64}), check_handle(a.ctx), check_ptr(a), out)
+    check_rc(a.ctx, rc)
+    auto_sync!(a.ctx)
+    row_major(out)
+end
+
+"""
+Array type with 1 dimensions and i8 elements
+"""
+mutable struct ArrayI8D1
+    ptr::Ptr{Cvoid}
+    shape::NTuple{1,Int64}
+    ctx::Context
+
+    function ArrayI8D1(ctx::Context, ptr::Ptr{Cvoid})
+        shape = unsafe_load(ccall((:futhark_shape_i8_1d, LIB), Ptr{NTuple{1,Int64}}, (Ptr{Cvoid}, Ptr{Cvoid}), ctx.handle, ptr))
+        finalizer(free!, new(ptr, shape, ctx))
+    end
+end
+
+"""
+Create a new array from a Julia array, the array keeps the same shape
+"""
+function ArrayI8D1(ctx::Context, data::AbstractArray{Int8,1})
+    dims = size(data)
+    data = row_major(data)
+    ptr = ccall((:futhark_new_i8_1d, LIB), Ptr{Cvoid}, (Ptr{Cvoid}, Ptr{Int8}, Int64), check_handle(ctx), data, dims...)
+    ptr == C_NULL && throw(NullPtrError())
+    auto_sync!(ctx)
+    ArrayI8D1(ctx, ptr)
+end
+
+"""
+Free the array, it is also freed when it is garbage collected
+"""
+function free!(a::ArrayI8D1)
+    if a.ptr != C_NULL && a.ctx.handle != C_NULL
+        ccall((:futhark_free_i8_1d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}), a.ctx.handle, a.ptr)
+    end
+    a.ptr = C_NULL
+    nothing
+end
+
+function check_ptr(a::ArrayI8D1)
+    a.ptr == C_NULL && error("Array has already been freed")
+    a.ptr
+end
+
+Base.size(a::ArrayI8D1) = Int.(a.shape)
+Base.length(a::ArrayI8D1) = prod(a.shape)
+Base.eltype(::Type{ArrayI8D1}) = Int8
+
+"""
+Copy the values into a new Julia array with the same shape
+"""
+function Base.Array(a::ArrayI8D1)
+    out = Array{Int8,1}(undef, reverse(size(a))...)
+    rc = GC.@preserve a ccall((:futhark_values_i8_1d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}, Ptr{Int8}), check_handle(a.ctx), check_ptr(a), out)
+    check_rc(a.ctx, rc)
+    auto_sync!(a.ctx)
+    row_major(out)
+end
+
+"""
+Array type with 1 dimensions and u16 elements
+"""
+mutable struct ArrayU16D1
+    ptr::Ptr{Cvoid}
+    shape::NTuple{1,Int64}
+    ctx::Context
+
+    function ArrayU16D1(ctx::Context, ptr::Ptr{Cvoid})
+        shape = unsafe_load(ccall((:futhark_shape_u16_1d, LIB), Ptr{NTuple{1,Int64}}, (Ptr{Cvoid}, Ptr{Cvoid}), ctx.handle, ptr))
+        finalizer(free!, new(ptr, shape, ctx))
+    end
+end
+
+"""
+Create a new array from a Julia array, the array keeps the same shape
+"""
+function ArrayU16D1(ctx::Context, data::AbstractArray{UInt16,1})
+    dims = size(data)
+    data = row_major(data)
+    ptr = ccall((:futhark_new_u16_1d, LIB), Ptr{Cvoid}, (Ptr{Cvoid}, Ptr{UInt16}, Int64), check_handle(ctx), data, dims...)
+    ptr == C_NULL && throw(NullPtrError())
+    auto_sync!(ctx)
+    ArrayU16D1(ctx, ptr)
+end
+
+"""
+Free the array, it is also freed when it is garbage collected
+"""
+function free!(a::ArrayU16D1)
+    if a.ptr != C_NULL && a.ctx.handle != C_NULL
+        ccall((:futhark_free_u16_1d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}), a.ctx.handle, a.ptr)
+    end
+    a.ptr = C_NULL
+    nothing
+end
+
+function check_ptr(a::ArrayU16D1)
+    a.ptr == C_NULL && error("Array has already been freed")
+    a.ptr
+end
+
+Base.size(a::ArrayU16D1) = Int.(a.shape)
+Base.length(a::ArrayU16D1) = prod(a.shape)
+Base.eltype(::Type{ArrayU16D1}) = UInt16
+
+"""
+Copy the values into a new Julia array with the same shape
+"""
+function Base.Array(a::ArrayU16D1)
+    out = Array{UInt16,1}(undef, reverse(size(a))...)
+    rc = GC.@preserve a ccall((:futhark_values_u16_1d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}, Ptr{UInt16}), check_handle(a.ctx), check_ptr(a), out)
+    check_rc(a.ctx, rc)
+    auto_sync!(a.ctx)
+    row_major(out)
+end
+
+"""
+Array type with 1 dimensions and u32 elements
+"""
+mutable struct ArrayU32D1
+    ptr::Ptr{Cvoid}
+    shape::NTuple{1,Int64}
+    ctx::Context
+
+    function ArrayU32D1(ctx::Context, ptr::Ptr{Cvoid})
+        shape = unsafe_load(ccall((:futhark_shape_u32_1d, LIB), Ptr{NTuple{1,Int64}}, (Ptr{Cvoid}, Ptr{Cvoid}), ctx.handle, ptr))
+        finalizer(free!, new(ptr, shape, ctx))
+    end
+end
+
+"""
+Create a new array from a Julia array, the array keeps the same shape
+"""
+function ArrayU32D1(ctx::Context, data::AbstractArray{UInt32,1})
+    dims = size(data)
+    data = row_major(data)
+    ptr = ccall((:futhark_new_u32_1d, LIB), Ptr{Cvoid}, (Ptr{Cvoid}, Ptr{UInt32}, Int64), check_handle(ctx), data, dims...)
+    ptr == C_NULL && throw(NullPtrError())
+    auto_sync!(ctx)
+    ArrayU32D1(ctx, ptr)
+end
+
+"""
+Free the array, it is also freed when it is garbage collected
+"""
+function free!(a::ArrayU32D1)
+    if a.ptr != C_NULL && a.ctx.handle != C_NULL
+        ccall((:futhark_free_u32_1d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}), a.ctx.handle, a.ptr)
+    end
+    a.ptr = C_NULL
+    nothing
+end
+
+function check_ptr(a::ArrayU32D1)
+    a.ptr == C_NULL && error("Array has already been freed")
+    a.ptr
+end
+
+Base.size(a::ArrayU32D1) = Int.(a.shape)
+Base.length(a::ArrayU32D1) = prod(a.shape)
+Base.eltype(::Type{ArrayU32D1}) = UInt32
+
+"""
+Copy the values into a new Julia array with the same shape
+"""
+function Base.Array(a::ArrayU32D1)
+    out = Array{UInt32,1}(undef, reverse(size(a))...)
+    rc = GC.@preserve a ccall((:futhark_values_u32_1d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}, Ptr{UInt32}), check_handle(a.ctx), check_ptr(a), out)
+    check_rc(a.ctx, rc)
+    auto_sync!(a.ctx)
+    row_major(out)
+end
+
+"""
+Array type with 1 dimensions and u64 elements
+"""
+mutable struct ArrayU64D1
+    ptr::Ptr{Cvoid}
+    shape::NTuple{1,Int64}
+    ctx::Context
+
+    function ArrayU64D1(ctx::Context, ptr::Ptr{Cvoid})
+        shape = unsafe_load(ccall((:futhark_shape_u64_1d, LIB), Ptr{NTuple{1,Int64}}, (Ptr{Cvoid}, Ptr{Cvoid}), ctx.handle, ptr))
+        finalizer(free!, new(ptr, shape, ctx))
+    end
+end
+
+"""
+Create a new array from a Julia array, the array keeps the same shape
+"""
+function ArrayU64D1(ctx::Context, data::AbstractArray{UInt64,1})
+    dims = size(data)
+    data = row_major(data)
+    ptr = ccall((:futhark_new_u64_1d, LIB), Ptr{Cvoid}, (Ptr{Cvoid}, Ptr{UInt64}, Int64), check_handle(ctx), data, dims...)
+    ptr == C_NULL && throw(NullPtrError())
+    auto_sync!(ctx)
+    ArrayU64D1(ctx, ptr)
+end
+
+"""
+Free the array, it is also freed when it is garbage collected
+"""
+function free!(a::ArrayU64D1)
+    if a.ptr != C_NULL && a.ctx.handle != C_NULL
+        ccall((:futhark_free_u64_1d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}), a.ctx.handle, a.ptr)
+    end
+    a.ptr = C_NULL
+    nothing
+end
+
+function check_ptr(a::ArrayU64D1)
+    a.ptr == C_NULL && error("Array has already been freed")
+    a.ptr
+end
+
+Base.size(a::ArrayU64D1) = Int.(a.shape)
+Base.length(a::ArrayU64D1) = prod(a.shape)
+Base.eltype(::Type{ArrayU64D1}) = UInt64
+
+"""
+Copy the values into a new Julia array with the same shape
+"""
+function Base.Array(a::ArrayU64D1)
+    out = Array{UInt64,1}(undef, reverse(size(a))...)
+    rc = GC.@preserve a ccall((:futhark_values_u64_1d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}, Ptr{UInt64}), check_handle(a.ctx), check_ptr(a), out)
+    check_rc(a.ctx, rc)
+    auto_sync!(a.ctx)
+    row_major(out)
+end
+
+"""
+Array type with 1 dimensions and u8 elements
+"""
+mutable struct ArrayU8D1
+    ptr::Ptr{Cvoid}
+    shape::NTuple{1,Int64}
+    ctx::Context
+
+    function ArrayU8D1(ctx::Context, ptr::Ptr{Cvoid})
+        shape = unsafe_load(ccall((:futhark_shape_u8_1d, LIB), Ptr{NTuple{1,Int64}}, (Ptr{Cvoid}, Ptr{Cvoid}), ctx.handle, ptr))
+        finalizer(free!, new(ptr, shape, ctx))
+    end
+end
+
+"""
+Create a new array from a Julia array, the array keeps the same shape
+"""
+function ArrayU8D1(ctx::Context, data::AbstractArray{UInt8,1})
+    dims = size(data)
+    data = row_major(data)
+    ptr = ccall((:futhark_new_u8_1d, LIB), Ptr{Cvoid}, (Ptr{Cvoid}, Ptr{UInt8}, Int64), check_handle(ctx), data, dims...)
+    ptr == C_NULL && throw(NullPtrError())
+    auto_sync!(ctx)
+    ArrayU8D1(ctx, ptr)
+end
+
+"""
+Free the array, it is also freed when it is garbage collected
+"""
+function free!(a::ArrayU8D1)
+    if a.ptr != C_NULL && a.ctx.handle != C_NULL
+        ccall((:futhark_free_u8_1d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}), a.ctx.handle, a.ptr)
+    end
+    a.ptr = C_NULL
+    nothing
+end
+
+function check_ptr(a::ArrayU8D1)
+    a.ptr == C_NULL && error("Array has already been freed")
+    a.ptr
+end
+
+Base.size(a::ArrayU8D1) = Int.(a.shape)
+Base.length(a::ArrayU8D1) = prod(a.shape)
+Base.eltype(::Type{ArrayU8D1}) = UInt8
+
+"""
+Copy the values into a new Julia array with the same shape
+"""
+function Base.Array(a::ArrayU8D1)
+    out = Array{UInt8,1}(undef, reverse(size(a))...)
+    rc = GC.@preserve a ccall((:futhark_values_u8_1d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}, Ptr{UInt8}), check_handle(a.ctx), check_ptr(a), out)
+    check_rc(a.ctx, rc)
+    auto_sync!(a.ctx)
+    row_major(out)
+end
+
+"""
+Entry point: flatten
+"""
+function flatten(ctx::Context, input0::ArrayF32D3)
+    out0 = Ref{Ptr{Cvoid}}(C_NULL)
+    rc = GC.@preserve ctx input0 ccall((:futhark_entry_flatten, LIB), Cint, (Ptr{Cvoid}, Ptr{Ptr{Cvoid}}, Ptr{Cvoid}), check_handle(ctx), out0, check_ptr(input0))
+    check_rc(ctx, rc)
+    auto_sync!(ctx)
+    ArrayF32D2(ctx, out0[])
+end
+
+"""
+Entry point: lengths
+"""
+function lengths(ctx::Context, input0::ArrayI8D1, input1::ArrayI16D1, input2::ArrayI32D1, input3::ArrayI64D1, input4::ArrayU8D1, input5::ArrayU16D1, input6::ArrayU32D1, input7::ArrayU64D1, input8::ArrayF16D1, input9::ArrayF32D1, input10::ArrayF64D1, input11::ArrayBoolD1)
+    out0 = Ref{Int64}()
+    rc = GC.@preserve ctx input0 input1 input2 input3 input4 input5 input6 input7 input8 input9 input10 input11 ccall((:futhark_entry_lengths, LIB), Cint, (Ptr{Cvoid}, Ptr{Int64}, Ptr{Cvoid}, Ptr{Cvoid}, Ptr{Cvoid}, Ptr{Cvoid}, Ptr{Cvoid}, Ptr{Cvoid}, Ptr{Cvoid}, Ptr{Cvoid}, Ptr{Cvoid}, Ptr{Cvoid}, Ptr{Cvoid}, Ptr{Cvoid}), check_handle(ctx), out0, check_ptr(input0), check_ptr(input1), check_ptr(input2), check_ptr(input3), check_ptr(input4), check_ptr(input5), check_ptr(input6), check_ptr(input7), check_ptr(input8), check_ptr(input9), check_ptr(input10), check_ptr(input11))
+    check_rc(ctx, rc)
+    auto_sync!(ctx)
+    out0[]
+end
+
+"""
+Entry point: sum_rows
+"""
+function sum_rows(ctx::Context, input0::ArrayF32D2)
+    out0 = Ref{Ptr{Cvoid}}(C_NULL)
+    out1 = Ref{Float32}()
+    rc = GC.@preserve ctx input0 ccall((:futhark_entry_sum_rows, LIB), Cint, (Ptr{Cvoid}, Ptr{Ptr{Cvoid}}, Ptr{Float32}, Ptr{Cvoid}), check_handle(ctx), out0, out1, check_ptr(input0))
+    check_rc(ctx, rc)
+    auto_sync!(ctx)
+    (ArrayF32D1(ctx, out0[]), out1[])
+end
+
+end # module
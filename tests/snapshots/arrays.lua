@@ -0,0 +1,1306 @@
+-- Generated by futhark-bindgen
+
+local ffi = require("ffi")
+
+ffi.cdef[[
+struct futhark_context_config;
+struct futhark_context;
+struct futhark_context_config *futhark_context_config_new(void);
+void futhark_context_config_free(struct futhark_context_config *cfg);
+void futhark_context_config_set_debugging(struct futhark_context_config *cfg, int flag);
+void futhark_context_config_set_profiling(struct futhark_context_config *cfg, int flag);
+void futhark_context_config_set_logging(struct futhark_context_config *cfg, int flag);
+void futhark_context_config_set_cache_file(struct futhark_context_config *cfg, const char *f);
+void futhark_context_config_set_num_threads(struct futhark_context_config *cfg, int n);
+struct futhark_context *futhark_context_new(struct futhark_context_config *cfg);
+void futhark_context_free(struct futhark_context *ctx);
+int futhark_context_sync(struct futhark_context *ctx);
+int futhark_context_clear_caches(struct futhark_context *ctx);
+void futhark_context_pause_profiling(struct futhark_context *ctx);
+void futhark_context_unpause_profiling(struct futhark_context *ctx);
+char *futhark_context_get_error(struct futhark_context *ctx);
+char *futhark_context_report(struct futhark_context *ctx);
+void free(void *ptr);
+]]
+
+-- Shared library containing the compiled Futhark code
+local lib = ffi.load(os.getenv("FUTHARK_LIB_ARRAYS") or "arrays")
+
+local M = {}
+
+local function take_string(s)
+  if s == nil then
+    return nil
+  end
+  local result = ffi.string(s)
+  ffi.C.free(s)
+  return result
+end
+
+--- Futhark context, freed by `free` or when it is garbage collected
+M.Context = {}
+M.Context.__index = M.Context
+
+--- Create a new context, `options` is an optional table with the fields `debug`, `profile`,
+--- `logging`, `cache_file`, `num_threads` and `auto_sync`
+function M.Context.new(options)
+  options = options or {}
+  local config = lib.futhark_context_config_new()
+  if config == nil then
+    error("NULL pointer encountered", 2)
+  end
+  lib.futhark_context_config_set_debugging(config, options.debug and 1 or 0)
+  lib.futhark_context_config_set_profiling(config, options.profile and 1 or 0)
+  lib.futhark_context_config_set_logging(config, options.logging and 1 or 0)
+  if options.cache_file ~= nil then
+    lib.futhark_context_config_set_cache_file(config, options.cache_file)
+  end
+  if options.num_threads ~= nil then
+    lib.futhark_context_config_set_num_threads(config, options.num_threads)
+  end
+  local handle = lib.futhark_context_new(config)
+  if handle == nil then
+    lib.futhark_context_config_free(config)
+    error("NULL pointer encountered", 2)
+  end
+
+  -- Shared with the finalizers of values created using the context
+  local state = { alive = true }
+  local self = setmetatable({
+    config = config,
+    state = state,
+    cache_file = options.cache_file,
+    auto_sync = options.auto_sync ~= false,
+  }, M.Context)
+  self.handle = ffi.gc(handle, function(h)
+    state.alive = false
+    lib.futhark_context_sync(h)
+    lib.futhark_context_free(h)
+    lib.futhark_context_config_free(config)
+  end)
+  return self
+end
+
+--- Free the context, values created using the context can no longer be used
+function M.Context:free()
+  if self.handle == nil then
+    return
+  end
+  local handle = ffi.gc(self.handle, nil)
+  self.state.alive = false
+  lib.futhark_context_sync(handle)
+  lib.futhark_context_free(handle)
+  lib.futhark_context_config_free(self.config)
+  self.handle = nil
+end
+
+function M.Context:check_handle()
+  if self.handle == nil then
+    error("context has already been freed", 3)
+  end
+  return self.handle
+end
+
+function M.Context:check(rc)
+  if rc ~= 0 then
+    error(string.format("Futhark error code %d: %s", rc, self:get_error() or ""), 3)
+  end
+end
+
+--- Wait for all pending operations to finish
+function M.Context:sync()
+  self:check(lib.futhark_context_sync(self:check_handle()))
+end
+
+function M.Context:maybe_sync()
+  if self.auto_sync then
+    self:sync()
+  end
+end
+
+--- Free any cached memory
+function M.Context:clear_caches()
+  self:check(lib.futhark_context_clear_caches(self:check_handle()))
+end
+
+--- Pause profiling
+function M.Context:pause_profiling()
+  lib.futhark_context_pause_profiling(self:check_handle())
+end
+
+--- Resume profiling
+function M.Context:unpause_profiling()
+  lib.futhark_context_unpause_profiling(self:check_handle())
+end
+
+--- Get the last error message
+function M.Context:get_error()
+  return take_string(lib.futhark_context_get_error(self:check_handle()))
+end
+
+--- Get the profiling report
+function M.Context:report()
+  return take_string(lib.futhark_context_report(self:check_handle()))
+end
+
+-- Attach a finalizer to `ptr` that frees it unless the context has been freed first, the
+-- finalizer keeps the context handle alive until the value is collected
+local function manage(ctx, ptr, free)
+  if ptr == nil then
+    error("NULL pointer encountered", 3)
+  end
+  local handle, state = ctx.handle, ctx.state
+  return ffi.gc(ptr, function(p)
+    if state.alive then
+      free(handle, p)
+    end
+  end)
+end
+
+-- Free a value created using `manage`
+local function release(ctx, ptr, free)
+  if ptr ~= nil then
+    ffi.gc(ptr, nil)
+    if ctx.state.alive then
+      free(ctx.handle, ptr)
+    end
+  end
+end
+
+local function check_ptr(value)
+  if value.ptr == nil then
+    error("value has already been freed", 3)
+  end
+  return value.ptr
+end
+
+ffi.cdef[[
+struct futhark_f32_3d;
+struct futhark_f32_3d *futhark_new_f32_3d(struct futhark_context *ctx, const float *data, int64_t dim0, int64_t dim1, int64_t dim2);
+int futhark_free_f32_3d(struct futhark_context *ctx, struct futhark_f32_3d *arr);
+int futhark_values_f32_3d(struct futhark_context *ctx, struct futhark_f32_3d *arr, float *data);
+const int64_t *futhark_shape_f32_3d(struct futhark_context *ctx, struct futhark_f32_3d *arr);
+]]
+
+--- Array type with 3 dimensions and f32 elements
+M.ArrayF32D3 = {}
+M.ArrayF32D3.__index = M.ArrayF32D3
+
+function M.ArrayF32D3.wrap(ctx, ptr)
+  ptr = manage(ctx, ptr, lib.futhark_free_f32_3d)
+  local shape = lib.futhark_shape_f32_3d(ctx.handle, ptr)
+  return setmetatable({ ctx = ctx, ptr = ptr, shape = { tonumber(shape[0]), tonumber(shape[1]), tonumber(shape[2]) } }, M.ArrayF32D3)
+end
+
+--- Create a new array with the shape `dims` from a flat table or a pointer to `float`
+--- values, `dims` defaults to the length of the table
+function M.ArrayF32D3.new(ctx, data, dims)
+  local buf = data
+  if type(data) == "table" then
+    dims = dims or { #data }
+  elseif dims == nil then
+    error("dims are required when data is not a table", 2)
+  end
+  local size = 1
+  for _, d in ipairs(dims) do
+    size = size * d
+  end
+  if type(data) == "table" then
+    if #dims ~= 3 or size ~= #data then
+      error(string.format("invalid shape: expected %d elements, got %d", size, #data), 2)
+    end
+    buf = ffi.new("float[?]", size)
+    for i = 1, size do
+      buf[i - 1] = data[i]
+    end
+  end
+  local ptr = lib.futhark_new_f32_3d(ctx:check_handle(), buf, dims[1], dims[2], dims[3])
+  ctx:maybe_sync()
+  return M.ArrayF32D3.wrap(ctx, ptr)
+end
+
+--- Number of elements in the array
+function M.ArrayF32D3:size()
+  local size = 1
+  for _, d in ipairs(self.shape) do
+    size = size * d
+  end
+  return size
+end
+
+--- Copy the values into a new `float` array
+function M.ArrayF32D3:values()
+  local out = ffi.new("float[?]", self:size())
+  self.ctx:check(lib.futhark_values_f32_3d(self.ctx:check_handle(), check_ptr(self), out))
+  self.ctx:maybe_sync()
+  return out
+end
+
+--- Copy the values into a new flat table
+function M.ArrayF32D3:to_table()
+  local out = self:values()
+  local t = {}
+  for i = 1, self:size() do
+    t[i] = out[i - 1]
+  end
+  return t
+end
+
+--- Free the array, it is also freed when it is garbage collected
+function M.ArrayF32D3:free()
+  release(self.ctx, self.ptr, lib.futhark_free_f32_3d)
+  self.ptr = nil
+end
+
+ffi.cdef[[
+struct futhark_f32_2d;
+struct futhark_f32_2d *futhark_new_f32_2d(struct futhark_context *ctx, const float *data, int64_t dim0, int64_t dim1);
+int futhark_free_f32_2d(struct futhark_context *ctx, struct futhark_f32_2d *arr);
+int futhark_values_f32_2d(struct futhark_context *ctx, struct futhark_f32_2d *arr, float *data);
+const int64_t *futhark_shape_f32_2d(struct futhark_context *ctx, struct futhark_f32_2d *arr);
+]]
+
+--- Array type with 2 dimensions and f32 elements
+M.ArrayF32D2 = {}
+M.ArrayF32D2.__index = M.ArrayF32D2
+
+function M.ArrayF32D2.wrap(ctx, ptr)
+  ptr = manage(ctx, ptr, lib.futhark_free_f32_2d)
+  local shape = lib.futhark_shape_f32_2d(ctx.handle, ptr)
+  return setmetatable({ ctx = ctx, ptr = ptr, shape = { tonumber(shape[0]), tonumber(shape[1]) } }, M.ArrayF32D2)
+end
+
+--- Create a new array with the shape `dims` from a flat table or a pointer to `float`
+--- values, `dims` defaults to the length of the table
+function M.ArrayF32D2.new(ctx, data, dims)
+  local buf = data
+  if type(data) == "table" then
+    dims = dims or { #data }
+  elseif dims == nil then
+    error("dims are required when data is not a table", 2)
+  end
+  local size = 1
+  for _, d in ipairs(dims) do
+    size = size * d
+  end
+  if type(data) == "table" then
+    if #dims ~= 2 or size ~= #data then
+      error(string.format("invalid shape: expected %d elements, got %d", size, #data), 2)
+    end
+    buf = ffi.new("float[?]", size)
+    for i = 1, size do
+      buf[i - 1] = data[i]
+    end
+  end
+  local ptr = lib.futhark_new_f32_2d(ctx:check_handle(), buf, dims[1], dims[2])
+  ctx:maybe_sync()
+  return M.ArrayF32D2.wrap(ctx, ptr)
+end
+
+--- Number of elements in the array
+function M.ArrayF32D2:size()
+  local size = 1
+  for _, d in ipairs(self.shape) do
+    size = size * d
+  end
+  return size
+end
+
+--- Copy the values into a new `float` array
+function M.ArrayF32D2:values()
+  local out = ffi.new("float[?]", self:size())
+  self.ctx:check(lib.futhark_values_f32_2d(self.ctx:check_handle(), check_ptr(self), out))
+  self.ctx:maybe_sync()
+  return out
+end
+
+--- Copy the values into a new flat table
+function M.ArrayF32D2:to_table()
+  local out = self:values()
+  local t = {}
+  for i = 1, self:size() do
+    t[i] = out[i - 1]
+  end
+  return t
+end
+
+--- Free the array, it is also freed when it is garbage collected
+function M.ArrayF32D2:free()
+  release(self.ctx, self.ptr, lib.futhark_free_f32_2d)
+  self.ptr = nil
+end
+
+ffi.cdef[[
+struct futhark_bool_1d;
+struct futhark_bool_1d *futhark_new_bool_1d(struct futhark_context *ctx, const bool *data, int64_t dim0);
+int futhark_free_bool_1d(struct futhark_context *ctx, struct futhark_bool_1d *arr);
+int futhark_values_bool_1d(struct futhark_context *ctx, struct futhark_bool_1d *arr, bool *data);
+const int64_t *futhark_shape_bool_1d(struct futhark_context *ctx, struct futhark_bool_1d *arr);
+]]
+
+--- Array type with 1 dimensions and bool elements
+M.ArrayBoolD1 = {}
+M.ArrayBoolD1.__index = M.ArrayBoolD1
+
+function M.ArrayBoolD1.wrap(ctx, ptr)
+  ptr = manage(ctx, ptr, lib.futhark_free_bool_1d)
+  local shape = lib.futhark_shape_bool_1d(ctx.handle, ptr)
+  return setmetatable({ ctx = ctx, ptr = ptr, shape = { tonumber(shape[0]) } }, M.ArrayBoolD1)
+end
+
+--- Create a new array with the shape `dims` from a flat table or a pointer to `bool`
+--- values, `dims` defaults to the length of the table
+function M.ArrayBoolD1.new(ctx, data, dims)
+  local buf = data
+  if type(data) == "table" then
+    dims = dims or { #data }
+  elseif dims == nil then
+    error("dims are required when data is not a table", 2)
+  end
+  local size = 1
+  for _, d in ipairs(dims) do
+    size = size * d
+  end
+  if type(data) == "table" then
+    if #dims ~= 1 or size ~= #data then
+      error(string.format("invalid shape: expected %d elements, got %d", size, #data), 2)
+    end
+    buf = ffi.new("bool[?]", size)
+    for i = 1, size do
+      buf[i - 1] = data[i]
+    end
+  end
+  local ptr = lib.futhark_new_bool_1d(ctx:check_handle(), buf, dims[1])
+  ctx:maybe_sync()
+  return M.ArrayBoolD1.wrap(ctx, ptr)
+end
+
+--- Number of elements in the array
+function M.ArrayBoolD1:size()
+  local size = 1
+  for _, d in ipairs(self.shape) do
+    size = size * d
+  end
+  return size
+end
+
+--- Copy the values into a new `bool` array
+function M.ArrayBoolD1:values()
+  local out = ffi.new("bool[?]", self:size())
+  self.ctx:check(lib.futhark_values_bool_1d(self.ctx:check_handle(), check_ptr(self), out))
+  self.ctx:maybe_sync()
+  return out
+end
+
+--- Copy the values into a new flat table
+function M.ArrayBoolD1:to_table()
+  local out = self:values()
+  local t = {}
+  for i = 1, self:size() do
+    t[i] = out[i - 1]
+  end
+  return t
+end
+
+--- Free the array, it is also freed when it is garbage collected
+function M.ArrayBoolD1:free()
+  release(self.ctx, self.ptr, lib.futhark_free_bool_1d)
+  self.ptr = nil
+end
+
+ffi.cdef[[
+struct futhark_f16_1d;
+struct futhark_f16_1d *futhark_new_f16_1d(struct futhark_context *ctx, const uint16_t *data, int64_t dim0);
+int futhark_free_f16_1d(struct futhark_context *ctx, struct futhark_f16_1d *arr);
+int futhark_values_f16_1d(struct futhark_context *ctx, struct futhark_f16_1d *arr, uint16_t *data);
+const int64_t *futhark_shape_f16_1d(struct futhark_context *ctx, struct futhark_f16_1d *arr);
+]]
+
+--- Array type with 1 dimensions and f16 elements
+M.ArrayF16D1 = {}
+M.ArrayF16D1.__index = M.ArrayF16D1
+
+function M.ArrayF16D1.wrap(ctx, ptr)
+  ptr = manage(ctx, ptr, lib.futhark_free_f16_1d)
+  local shape = lib.futhark_shape_f16_1d(ctx.handle, ptr)
+  return setmetatable({ ctx = ctx, ptr = ptr, shape = { tonumber(shape[0]) } }, M.ArrayF16D1)
+end
+
+--- Create a new array with the shape `dims` from a flat table or a pointer to `uint16_t`
+--- values, `dims` defaults to the length of the table
+function M.ArrayF16D1.new(ctx, data, dims)
+  local buf = data
+  if type(data) == "table" then
+    dims = dims or { #data }
+  elseif dims == nil then
+    error("dims are required when data is not a table", 2)
+  end
+  local size = 1
+  for _, d in ipairs(dims) do
+    size = size * d
+  end
+  if type(data) == "table" then
+    if #dims ~= 1 or size ~= #data then
+      error(string.format("invalid shape: expected %d elements, got %d", size, #data), 2)
+    end
+    buf = ffi.new("uint16_t[?]", size)
+    for i = 1, size do
+      buf[i - 1] = data[i]
+    end
+  end
+  local ptr = lib.futhark_new_f16_1d(ctx:check_handle(), buf, dims[1])
+  ctx:maybe_sync()
+  return M.ArrayF16D1.wrap(ctx, ptr)
+end
+
+--- Number of elements in the array
+function M.ArrayF16D1:size()
+  local size = 1
+  for _, d in ipairs(self.shape) do
+    size = size * d
+  end
+  return size
+end
+
+--- Copy the values into a new `uint16_t` array
+function M.ArrayF16D1:values()
+  local out = ffi.new("uint16_t[?]", self:size())
+  self.ctx:check(lib.futhark_values_f16_1d(self.ctx:check_handle(), check_ptr(self), out))
+  self.ctx:maybe_sync()
+  return out
+end
+
+--- Copy the values into a new flat table
+function M.ArrayF16D1:to_table()
+  local out = self:values()
+  local t = {}
+  for i = 1, self:size() do
+    t[i] = out[i - 1]
+  end
+  return t
+end
+
+--- Free the array, it is also freed when it is garbage collected
+function M.ArrayF16D1:free()
+  release(self.ctx, self.ptr, lib.futhark_free_f16_1d)
+  self.ptr = nil
+end
+
+ffi.cdef[[
+struct futhark_f32_1d;
+struct futhark_f32_1d *futhark_new_f32_1d(struct futhark_context *ctx, const float *data, int64_t dim0);
+int futhark_free_f32_1d(struct futhark_context *ctx, struct futhark_f32_1d *arr);
+int futhark_values_f32_1d(struct futhark_context *ctx, struct futhark_f32_1d *arr, float *data);
+const int64_t *futhark_shape_f32_1d(struct futhark_context *ctx, struct futhark_f32_1d *arr);
+]]
+
+--- Array type with 1 dimensions and f32 elements
+M.ArrayF32D1 = {}
+M.ArrayF32D1.__index = M.ArrayF32D1
+
+function M.ArrayF32D1.wrap(ctx, ptr)
+  ptr = manage(ctx, ptr, lib.futhark_free_f32_1d)
+  local shape = lib.futhark_shape_f32_1d(ctx.handle, ptr)
+  return setmetatable({ ctx = ctx, ptr = ptr, shape = { tonumber(shape[0]) } }, M.ArrayF32D1)
+end
+
+--- Create a new array with the shape `dims` from a flat table or a pointer to `float`
+--- values, `dims` defaults to the length of the table
+function M.ArrayF32D1.new(ctx, data, dims)
+  local buf = data
+  if type(data) == "table" then
+    dims = dims or { #data }
+  elseif dims == nil then
+    error("dims are required when data is not a table", 2)
+  end
+  local size = 1
+  for _, d in ipairs(dims) do
+    size = size * d
+  end
+  if type(data) == "table" then
+    if #dims ~= 1 or size ~= #data then
+      error(string.format("invalid shape: expected %d elements, got %d", size, #data), 2)
+    end
+    buf = ffi.new("float[?]", size)
+    for i = 1, size do
+      buf[i - 1] = data[i]
+    end
+  end
+  local ptr = lib.futhark_new_f32_1d(ctx:check_handle(), buf, dims[1])
+  ctx:maybe_sync()
+  return M.ArrayF32D1.wrap(ctx, ptr)
+end
+
+--- Number of elements in the array
+function M.ArrayF32D1:size()
+  local size = 1
+  for _, d in ipairs(self.shape) do
+    size = size * d
+  end
+  return size
+end
+
+--- Copy the values into a new `float` array
+function M.ArrayF32D1:values()
+  local out = ffi.new("float[?]", self:size())
+  self.ctx:check(lib.futhark_values_f32_1d(self.ctx:check_handle(), check_ptr(self), out))
+  self.ctx:maybe_sync()
+  return out
+end
+
+--- Copy the values into a new flat table
+function M.ArrayF32D1:to_table()
+  local out = self:values()
+  local t = {}
+  for i = 1, self:size() do
+    t[i] = out[i - 1]
+  end
+  return t
+end
+
+--- Free the array, it is also freed when it is garbage collected
+function M.ArrayF32D1:free()
+  release(self.ctx, self.ptr, lib.futhark_free_f32_1d)
+  self.ptr = nil
+end
+
+ffi.cdef[[
+struct futhark_f64_1d;
+struct futhark_f64_1d *futhark_new_f64_1d(struct futhark_context *ctx, const double *data, int64_t dim0);
+int futhark_free_f64_1d(struct futhark_context *ctx, struct futhark_f64_1d *arr);
+int futhark_values_f64_1d(struct futhark_context *ctx, struct futhark_f64_1d *arr, double *data);
+const int64_t *futhark_shape_f64_1d(struct futhark_context *ctx, struct futhark_f64_1d *arr);
+]]
+
+--- Array type with 1 dimensions and f64 elements
+M.ArrayF64D1 = {}
+M.ArrayF64D1.__index = M.ArrayF64D1
+
+function M.ArrayF64D1.wrap(ctx, ptr)
+  ptr = manage(ctx, ptr, lib.futhark_free_f64_1d)
+  local shape = lib.futhark_shape_f64_1d(ctx.handle, ptr)
+  return setmetatable({ ctx = ctx, ptr = ptr, shape = { tonumber(shape[0]) } }, M.ArrayF64D1)
+end
+
+--- Create a new array with the shape `dims` from a flat table or a pointer to `double`
+--- values, `dims` defaults to the length of the table
+function M.ArrayF64D1.new(ctx, data, dims)
+  local buf = data
+  if type(data) == "table" then
+    dims = dims or { #data }
+  elseif dims == nil then
+    error("dims are required when data is not a table", 2)
+  end
+  local size = 1
+  for _, d in ipairs(dims) do
+    size = size * d
+  end
+  if type(data) == "table" then
+    if #dims ~= 1 or size ~= #data then
+      error(string.format("invalid shape: expected %d elements, got %d", size, #data), 2)
+    end
+    buf = ffi.new("double[?]", size)
+    for i = 1, size do
+      buf[i - 1] = data[i]
+    end
+  end
+  local ptr = lib.futhark_new_f64_1d(ctx:check_handle(), buf, dims[1])
+  ctx:maybe_sync()
+  return M.ArrayF64D1.wrap(ctx, ptr)
+end
+
+--- Number of elements in the array
+function M.ArrayF64D1:size()
+  local size = 1
+  for _, d in ipairs(self.shape) do
+    size = size * d
+  end
+  return size
+end
+
+--- Copy the values into a new `double` array
+function M.ArrayF64D1:values()
+  local out = ffi.new("double[?]", self:size())
+  self.ctx:check(lib.futhark_values_f64_1d(self.ctx:check_handle(), check_ptr(self), out))
+  self.ctx:maybe_sync()
+  return out
+end
+
+--- Copy the values into a new flat table
+function M.ArrayF64D1:to_table()
+  local out = self:values()
+  local t = {}
+  for i = 1, self:size() do
+    t[i] = out[i - 1]
+  end
+  return t
+end
+
+--- Free the array, it is also freed when it is garbage collected
+function M.ArrayF64D1:free()
+  release(self.ctx, self.ptr, lib.futhark_free_f64_1d)
+  self.ptr = nil
+end
+
+ffi.cdef[[
+struct futhark_i16_1d;
+struct futhark_i16_1d *futhark_new_i16_1d(struct futhark_context *ctx, const int16_t *data, int64_t dim0);
+int futhark_free_i16_1d(struct futhark_context *ctx, struct futhark_i16_1d *arr);
+int futhark_values_i16_1d(struct futhark_context *ctx, struct futhark_i16_1d *arr, int16_t *data);
+const int64_t *futhark_shape_i16_1d(struct futhark_context *ctx, struct futhark_i16_1d *arr);
+]]
+
+--- Array type with 1 dimensions and i16 elements
+M.ArrayI16D1 = {}
+M.ArrayI16D1.__index = M.ArrayI16D1
+
+function M.ArrayI16D1.wrap(ctx, ptr)
+  ptr = manage(ctx, ptr, lib.futhark_free_i16_1d)
+  local shape = lib.futhark_shape_i16_1d(ctx.handle, ptr)
+  return setmetatable({ ctx = ctx, ptr = ptr, shape = { tonumber(shape[0]) } }, M.ArrayI16D1)
+end
+
+--- Create a new array with the shape `dims` from a flat table or a pointer to `int16_t`
+--- values, `dims` defaults to the length of the table
+function M.ArrayI16D1.new(ctx, data, dims)
+  local buf = data
+  if type(data) == "table" then
+    dims = dims or { #data }
+  elseif dims == nil then
+    error("dims are required when data is not a table", 2)
+  end
+  local size = 1
+  for _, d in ipairs(dims) do
+    size = size * d
+  end
+  if type(data) == "table" then
+    if #dims ~= 1 or size ~= #data then
+      error(string.format("invalid shape: expected %d elements, got %d", size, #data), 2)
+    end
+    buf = ffi.new("int16_t[?]", size)
+    for i = 1, size do
+      buf[i - 1] = data[i]
+    end
+  end
+  local ptr = lib.futhark_new_i16_1d(ctx:check_handle(), buf, dims[1])
+  ctx:maybe_sync()
+  return M.ArrayI16D1.wrap(ctx, ptr)
+end
+
+--- Number of elements in the array
+function M.ArrayI16D1:size()
+  local size = 1
+  for _, d in ipairs(self.shape) do
+    size = size * d
+  end
+  return size
+end
+
+--- Copy the values into a new `int16_t` array
+function M.ArrayI16D1:values()
+  local out = ffi.new("int16_t[?]", self:size())
+  self.ctx:check(lib.futhark_values_i16_1d(self.ctx:check_handle(), check_ptr(self), out))
+  self.ctx:maybe_sync()
+  return out
+end
+
+--- Copy the values into a new flat table
+function M.ArrayI16D1:to_table()
+  local out = self:values()
+  local t = {}
+  for i = 1, self:size() do
+    t[i] = out[i - 1]
+  end
+  return t
+end
+
+--- Free the array, it is also freed when it is garbage collected
+function M.ArrayI16D1:free()
+  release(self.ctx, self.ptr, lib.futhark_free_i16_1d)
+  self.ptr = nil
+end
+
+ffi.cdef[[
+struct futhark_i32_1d;
+struct futhark_i32_1d *futhark_new_i32_1d(struct futhark_context *ctx, const int32_t *data, int64_t dim0);
+int futhark_free_i32_1d(struct futhark_context *ctx, struct futhark_i32_1d *arr);
+int futhark_values_i32_1d(struct futhark_context *ctx, struct futhark_i32_1d *arr, int32_t *data);
+const int64_t *futhark_shape_i32_1d(struct futhark_context *ctx, struct futhark_i32_1d *arr);
+]]
+
+--- Array type with 1 dimensions and i32 elements
+M.ArrayI32D1 = {}
+M.ArrayI32D1.__index = M.ArrayI32D1
+
+function M.ArrayI32D1.wrap(ctx, ptr)
+  ptr = manage(ctx, ptr, lib.futhark_free_i32_1d)
+  local shape = lib.futhark_shape_i32_1d(ctx.handle, ptr)
+  return setmetatable({ ctx = ctx, ptr = ptr, shape = { tonumber(shape[0]) } }, M.ArrayI32D1)
+end
+
+--- Create a new array with the shape `dims` from a flat table or a pointer to `int32_t`
+--- values, `dims` defaults to the length of the table
+function M.ArrayI32D1.new(ctx, data, dims)
+  local buf = data
+  if type(data) == "table" then
+    dims = dims or { #data }
+  elseif dims == nil then
+    error("dims are required when data is not a table", 2)
+  end
+  local size = 1
+  for _, d in ipairs(dims) do
+    size = size * d
+  end
+  if type(data) == "table" then
+    if #dims ~= 1 or size ~= #data then
+      error(string.format("invalid shape: expected %d elements, got %d", size, #data), 2)
+    end
+    buf = ffi.new("int32_t[?]", size)
+    for i = 1, size do
+      buf[i - 1] = data[i]
+    end
+  end
+  local ptr = lib.futhark_new_i32_1d(ctx:check_handle(), buf, dims[1])
+  ctx:maybe_sync()
+  return M.ArrayI32D1.wrap(ctx, ptr)
+end
+
+--- Number of elements in the array
+function M.ArrayI32D1:size()
+  local size = 1
+  for _, d in ipairs(self.shape) do
+    size = size * d
+  end
+  return size
+end
+
+--- Copy the values into a new `int32_t` array
+function M.ArrayI32D1:values()
+  local out = ffi.new("int32_t[?]", self:size())
+  self.ctx:check(lib.futhark_values_i32_1d(self.ctx:check_handle(), check_ptr(self), out))
+  self.ctx:maybe_sync()
+  return out
+end
+
+--- Copy the values into a new flat table
+function M.ArrayI32D1:to_table()
+  local out = self:values()
+  local t = {}
+  for i = 1, self:size() do
+    t[i] = out[i - 1]
+  end
+  return t
+end
+
+--- Free the array, it is also freed when it is garbage collected
+function M.ArrayI32D1:free()
+  release(self.ctx, self.ptr, lib.futhark_free_i32_1d)
+  self.ptr = nil
+end
+
+ffi.cdef[[
+struct futhark_i64_1d;
+struct futhark_i64_1d *futhark_new_i64_1d(struct futhark_context *ctx, const int64_t *data, int64_t dim0);
+int futhark_free_i64_1d(struct futhark_context *ctx, struct futhark_i64_1d *arr);
+int futhark_values_i64_1d(struct futhark_context *ctx, struct futhark_i64_1d *arr, int64_t *data);
+const int64_t *futhark_shape_i64_1d(struct futhark_context *ctx, struct futhark_i64_1d *arr);
+]]
+
+--- Array type with 1 dimensions and i64 elements
+M.ArrayI64D1 = {}
+M.ArrayI64D1.__index = M.ArrayI64D1
+
+function M.ArrayI64D1.wrap(ctx, ptr)
+  ptr = manage(ctx, ptr, lib.futhark_free_i64_1d)
+  local shape = lib.futhark_shape_i64_1d(ctx.handle, ptr)
+  return setmetatable({ ctx = ctx, ptr = ptr, shape = { tonumber(shape[0]) } }, M.ArrayI64D1)
+end
+
+--- Create a new array with the shape `dims` from a flat table or a pointer to `int64_t`
+--- values, `dims` defaults to the length of the table
+function M.ArrayI64D1.new(ctx, data, dims)
+  local buf = data
+  if type(data) == "table" then
+    dims = dims or { #data }
+  elseif dims == nil then
+    error("dims are required when data is not a table", 2)
+  end
+  local size = 1
+  for _, d in ipairs(dims) do
+    size = size * d
+  end
+  if type(data) == "table" then
+    if #dims ~= 1 or size ~= #data then
+      error(string.format("invalid shape: expected %d elements, got %d", size, #data), 2)
+    end
+    buf = ffi.new("int64_t[?]", size)
+    for i = 1, size do
+      buf[i - 1] = data[i]
+    end
+  end
+  local ptr = lib.futhark_new_i64_1d(ctx:check_handle(), buf, dims[1])
+  ctx:maybe_sync()
+  return M.ArrayI64D1.wrap(ctx, ptr)
+end
+
+--- Number of elements in the array
+function M.ArrayI64D1:size()
+  local size = 1
+  for _, d in ipairs(self.shape) do
+    size = size * d
+  end
+  return size
+end
+
+--- Copy the values into a new `int64_t` array
+function M.ArrayI64D1:values()
+  local out = ffi.new("int64_t[?]", self:size())
+  self.ctx:check(lib.futhark_values_i64_1d(self.ctx:check_handle(), check_ptr(self), out))
+  self.ctx:maybe_sync()
+  return out
+end
+
+--- Copy the values into a new flat table
+function M.ArrayI64D1:to_table()
+  local out = self:values()
+  local t = {}
+  for i = 1, self:size() do
+    t[i] = out[i - 1]
+  end
+  return t
+end
+
+--- Free the array, it is also freed when it is garbage collected
+function M.ArrayI64D1:free()
+  release(self.ctx, self.ptr, lib.futhark_free_i64_1d)
+  self.ptr = nil
+end
+
+ffi.cdef[[
+struct futhark_i8_1d;
+struct futhark_i8_1d *futhark_new_i8_1d(struct futhark_context *ctx, const int8_t *data, int64_t dim0);
+int futhark_free_i8_1d(struct futhark_context *ctx, struct futhark_i8_1d *arr);
+int futhark_values_i8_1d(struct futhark_context *ctx, struct futhark_i8_1d *arr, int8_t *data);
+const int64_t *futhark_shape_i8_1d(struct futhark_context *ctx, struct futhark_i8_1d *arr);
+]]
+
+--- Array type with 1 dimensions and i8 elements
+M.ArrayI8D1 = {}
+M.ArrayI8D1.__index = M.ArrayI8D1
+
+function M.ArrayI8D1.wrap(ctx, ptr)
+  ptr = manage(ctx, ptr, lib.futhark_free_i8_1d)
+  local shape = lib.futhark_shape_i8_1d(ctx.handle, ptr)
+  return setmetatable({ ctx = ctx, ptr = ptr, shape = { tonumber(shape[0]) } }, M.ArrayI8D1)
+end
+
+--- Create a new array with the shape `dims` from a flat table or a pointer to `int8_t`
+--- values, `dims` defaults to the length of the table
+function M.ArrayI8D1.new(ctx, data, dims)
+  local buf = data
+  if type(data) == "table" then
+    dims = dims or { #data }
+  elseif dims == nil then
+    error("dims are required when data is not a table", 2)
+  end
+  local size = 1
+  for _, d in ipairs(dims) do
+    size = size * d
+  end
+  if type(data) == "table" then
+    if #dims ~= 1 or size ~= #data then
+      error(string.format("invalid shape: expected %d elements, got %d", size, #data), 2)
+    end
+    buf = ffi.new("int8_t[?]", size)
+    for i = 1, size do
+      buf[i - 1] = data[i]
+    end
+  end
+  local ptr = lib.futhark_new_i8_1d(ctx:check_handle(), buf, dims[1])
+  ctx:maybe_sync()
+  return M.ArrayI8D1.wrap(ctx, ptr)
+end
+
+--- Number of elements in the array
+function M.ArrayI8D1:size()
+  local size = 1
+  for _, d in ipairs(self.shape) do
+    size = size * d
+  end
+  return size
+end
+
+--- Copy the values into a new `int8_t` array
+function M.ArrayI8D1:values()
+  local out = ffi.new("int8_t[?]", self:size())
+  self.ctx:check(lib.futhark_values_i8_1d(self.ctx:check_handle(), check_ptr(self), out))
+  self.ctx:maybe_sync()
+  return out
+end
+
+--- Copy the values into a new flat table
+function M.ArrayI8D1:to_table()
+  local out = self:values()
+  local t = {}
+  for i = 1, self:size() do
+    t[i] = out[i - 1]
+  end
+  return t
+end
+
+--- Free the array, it is also freed when it is garbage collected
+function M.ArrayI8D1:free()
+  release(self.ctx, self.ptr, lib.futhark_free_i8_1d)
+  self.ptr = nil
+end
+
+ffi.cdef[[
+struct futhark_u16_1d;
+struct futhark_u16_1d *futhark_new_u16_1d(struct futhark_context *ctx, const uint16_t *data, int64_t dim0);
+int futhark_free_u16_1d(struct futhark_context *ctx, struct futhark_u16_1d *arr);
+int futhark_values_u16_1d(struct futhark_context *ctx, struct futhark_u16_1d *arr, uint16_t *data);
+const int64_t *futhark_shape_u16_1d(struct futhark_context *ctx, struct futhark_u16_1d *arr);
+]]
+
+--- Array type with 1 dimensions and u16 elements
+M.ArrayU16D1 = {}
+M.ArrayU16D1.__index = M.ArrayU16D1
+
+function M.ArrayU16D1.wrap(ctx, ptr)
+  ptr = manage(ctx, ptr, lib.futhark_free_u16_1d)
+  local shape = lib.futhark_shape_u16_1d(ctx.handle, ptr)
+  return setmetatable({ ctx = ctx, ptr = ptr, shape = { tonumber(shape[0]) } }, M.ArrayU16D1)
+end
+
+--- Create a new array with the shape `dims` from a flat table or a pointer to `uint16_t`
+--- values, `dims` defaults to the length of the table
+function M.ArrayU16D1.new(ctx, data, dims)
+  local buf = data
+  if type(data) == "table" then
+    dims = dims or { #data }
+  elseif dims == nil then
+    error("dims are required when data is not a table", 2)
+  end
+  local size = 1
+  for _, d in ipairs(dims) do
+    size = size * d
+  end
+  if type(data) == "table" then
+    if #dims ~= 1 or size ~= #data then
+      error(string.format("invalid shape: expected %d elements, got %d", size, #data), 2)
+    end
+    buf = ffi.new("uint16_t[?]", size)
+    for i = 1, size do
+      buf[i - 1] = data[i]
+    end
+  end
+  local ptr = lib.futhark_new_u16_1d(ctx:check_handle(), buf, dims[1])
+  ctx:maybe_sync()
+  return M.ArrayU16D1.wrap(ctx, ptr)
+end
+
+--- Number of elements in the array
+function M.ArrayU16D1:size()
+  local size = 1
+  for _, d in ipairs(self.shape) do
+    size = size * d
+  end
+  return size
+end
+
+--- Copy the values into a new `uint16_t` array
+function M.ArrayU16D1:values()
+  local out = ffi.new("uint16_t[?]", self:size())
+  self.ctx:check(lib.futhark_values_u16_1d(self.ctx:check_handle(), check_ptr(self), out))
+  self.ctx:maybe_sync()
+  return out
+end
+
+--- Copy the values into a new flat table
+function M.ArrayU16D1:to_table()
+  local out = self:values()
+  local t = {}
+  for i = 1, self:size() do
+    t[i] = out[i - 1]
+  end
+  return t
+end
+
+--- Free the array, it is also freed when it is garbage collected
+function M.ArrayU16D1:free()
+  release(self.ctx, self.ptr, lib.futhark_free_u16_1d)
+  self.ptr = nil
+end
+
+ffi.cdef[[
+struct futhark_u32_1d;
+struct futhark_u32_1d *futhark_new_u32_1d(struct futhark_context *ctx, const uint32_t *data, int64_t dim0);
+int futhark_free_u32_1d(struct futhark_context *ctx, struct futhark_u32_1d *arr);
+int futhark_values_u32_1d(struct futhark_context *ctx, struct futhark_u32_1d *arr, uint32_t *data);
+const int64_t *futhark_shape_u32_1d(struct futhark_context *ctx, struct futhark_u32_1d *arr);
+]]
+
+--- Array type with 1 dimensions and u32 elements
+M.ArrayU32D1 = {}
+M.ArrayU32D1.__index = M.ArrayU32D1
+
+function M.ArrayU32D1.wrap(ctx, ptr)
+  ptr = manage(ctx, ptr, lib.futhark_free_u32_1d)
+  local shape = lib.futhark_shape_u32_1d(ctx.handle, ptr)
+  return setmetatable({ ctx = ctx, ptr = ptr, shape = { tonumber(shape[0]) } }, M.ArrayU32D1)
+end
+
+--- Create a new array with the shape `dims` from a flat table or a pointer to `uint32_t`
+--- values, `dims` defaults to the length of the table
+function M.ArrayU32D1.new(ctx, data, dims)
+  local buf = data
+  if type(data) == "table" then
+    dims = dims or { #data }
+  elseif dims == nil then
+    error("dims are required when data is not a table", 2)
+  end
+  local size = 1
+  for _, d in ipairs(dims) do
+    size = size * d
+  end
+  if type(data) == "table" then
+    if #dims ~= 1 or size ~= #data then
+      error(string.format("invalid shape: expected %d elements, got %d", size, #data), 2)
+    end
+    buf = ffi.new("uint32_t[?]", size)
+    for i = 1, size do
+      buf[i - 1] = data[i]
+    end
+  end
+  local ptr = lib.futhark_new_u32_1d(ctx:check_handle(), buf, dims[1])
+  ctx:maybe_sync()
+  return M.ArrayU32D1.wrap(ctx, ptr)
+end
+
+--- Number of elements in the array
+function M.ArrayU32D1:size()
+  local size = 1
+  for _, d in ipairs(self.shape) do
+    size = size * d
+  end
+  return size
+end
+
+--- Copy the values into a new `uint32_t` array
+function M.ArrayU32D1:values()
+  local out = ffi.new("uint32_t[?]", self:size())
+  self.ctx:check(lib.futhark_values_u32_1d(self.ctx:check_handle(), check_ptr(self), out))
+  self.ctx:maybe_sync()
+  return out
+end
+
+--- Copy the values into a new flat table
+function M.ArrayU32D1:to_table()
+  local out = self:values()
+  local t = {}
+  for i = 1, self:size() do
+    t[i] = out[i - 1]
+  end
+  return t
+end
+
+--- Free the array, it is also freed when it is garbage collected
+function M.ArrayU32D1:free()
+  release(self.ctx, self.ptr, lib.futhark_free_u32_1d)
+  self.ptr = nil
+end
+
+ffi.cdef[[
+struct futhark_u64_1d;
+struct futhark_u64_1d *futhark_new_u64_1d(struct futhark_context *ctx, const uint64_t *data, int64_t dim0);
+int futhark_free_u64_1d(struct futhark_context *ctx, struct futhark_u64_1d *arr);
+int futhark_values_u64_1d(struct futhark_context *ctx, struct futhark_u64_1d *arr, uint64_t *data);
+const int64_t *futhark_shape_u64_1d(struct futhark_context *ctx, struct futhark_u64_1d *arr);
+]]
+
+--- Array type with 1 dimensions and u64 elements
+M.ArrayU64D1 = {}
+M.ArrayU64D1.__index = M.ArrayU64D1
+
+function M.ArrayU64D1.wrap(ctx, ptr)
+  ptr = manage(ctx, ptr, lib.futhark_free_u64_1d)
+  local shape = lib.futhark_shape_u64_1d(ctx.handle, ptr)
+  return setmetatable({ ctx = ctx, ptr = ptr, shape = { tonumber(shape[0]) } }, M.ArrayU64D1)
+end
+
+--- Create a new array with the shape `dims` from a flat table or a pointer to `uint64_t`
+--- values, `dims` defaults to the length of the table
+function M.ArrayU64D1.new(ctx, data, dims)
+  local buf = data
+  if type(data) == "table" then
+    dims = dims or { #data }
+  elseif dims == nil then
+    error("dims are required when data is not a table", 2)
+  end
+  local size = 1
+  for _, d in ipairs(dims) do
+    size = size * d
+  end
+  if type(data) == "table" then
+    if #dims ~= 1 or size ~= #data then
+      error(string.format("invalid shape: expected %d elements, got %d", size, #data), 2)
+    end
+    buf = ffi.new("uint64_t[?]", size)
+    for i = 1, size do
+      buf[i - 1] = data[i]
+    end
+  end
+  local ptr = lib.futhark_new_u64_1d(ctx:check_handle(), buf, dims[1])
+  ctx:maybe_sync()
+  return M.ArrayU64D1.wrap(ctx, ptr)
+end
+
+--- Number of elements in the array
+function M.ArrayU64D1:size()
+  local size = 1
+  for _, d in ipairs(self.shape) do
+    size = size * d
+  end
+  return size
+end
+
+--- Copy the values into a new `uint64_t` array
+function M.ArrayU64D1:values()
+  local out = ffi.new("uint64_t[?]", self:size())
+  self.ctx:check(lib.futhark_values_u64_1d(self.ctx:check_handle(), check_ptr(self), out))
+  self.ctx:maybe_sync()
+  return out
+end
+
+--- Copy the values into a new flat table
+function M.ArrayU64D1:to_table()
+  local out = self:values()
+  local t = {}
+  for i = 1, self:size() do
+    t[i] = out[i - 1]
+  end
+  return t
+end
+
+--- Free the array, it is also freed when it is garbage collected
+function M.ArrayU64D1:free()
+  release(self.ctx, self.ptr, lib.futhark_free_u64_1d)
+  self.ptr = nil
+end
+
+ffi.cdef[[
+struct futhark_u8_1d;
+struct futhark_u8_1d *futhark_new_u8_1d(struct futhark_context *ctx, const uint8_t *data, int64_t dim0);
+int futhark_free_u8_1d(struct futhark_context *ctx, struct futhark_u8_1d *arr);
+int futhark_values_u8_1d(struct futhark_context *ctx, struct futhark_u8_1d *arr, uint8_t *data);
+const int64_t *futhark_shape_u8_1d(struct futhark_context *ctx, struct futhark_u8_1d *arr);
+]]
+
+--- Array type with 1 dimensions and u8 elements
+M.ArrayU8D1 = {}
+M.ArrayU8D1.__index = M.ArrayU8D1
+
+function M.ArrayU8D1.wrap(ctx, ptr)
+  ptr = manage(ctx, ptr, lib.futhark_free_u8_1d)
+  local shape = lib.futhark_shape_u8_1d(ctx.handle, ptr)
+  return setmetatable({ ctx = ctx, ptr = ptr, shape = { tonumber(shape[0]) } }, M.ArrayU8D1)
+end
+
+--- Create a new array with the shape `dims` from a flat table or a pointer to `uint8_t`
+--- values, `dims` defaults to the length of the table
+function M.ArrayU8D1.new(ctx, data, dims)
+  local buf = data
+  if type(data) == "table" then
+    dims = dims or { #data }
+  elseif dims == nil then
+    error("dims are required when data is not a table", 2)
+  end
+  local size = 1
+  for _, d in ipairs(dims) do
+    size = size * d
+  end
+  if type(data) == "table" then
+    if #dims ~= 1 or size ~= #data then
+      error(string.format("invalid shape: expected %d elements, got %d", size, #data), 2)
+    end
+    buf = ffi.new("uint8_t[?]", size)
+    for i = 1, size do
+      buf[i - 1] = data[i]
+    end
+  end
+  local ptr = lib.futhark_new_u8_1d(ctx:check_handle(), buf, dims[1])
+  ctx:maybe_sync()
+  return M.ArrayU8D1.wrap(ctx, ptr)
+end
+
+--- Number of elements in the array
+function M.ArrayU8D1:size()
+  local size = 1
+  for _, d in ipairs(self.shape) do
+    size = size * d
+  end
+  return size
+end
+
+--- Copy the values into a new `uint8_t` array
+function M.ArrayU8D1:values()
+  local out = ffi.new("uint8_t[?]", self:size())
+  self.ctx:check(lib.futhark_values_u8_1d(self.ctx:check_handle(), check_ptr(self), out))
+  self.ctx:maybe_sync()
+  return out
+end
+
+--- Copy the values into a new flat table
+function M.ArrayU8D1:to_table()
+  local out = self:values()
+  local t = {}
+  for i = 1, self:size() do
+    t[i] = out[i - 1]
+  end
+  return t
+end
+
+--- Free the array, it is also freed when it is garbage collected
+function M.ArrayU8D1:free()
+  release(self.ctx, self.ptr, lib.futhark_free_u8_1d)
+  self.ptr = nil
+end
+
+ffi.cdef[[
+int futhark_entry_flatten(struct futhark_context *ctx, struct futhark_f32_2d **out0, const struct futhark_f32_3d *input0);
+]]
+
+--- Entry point: flatten
+function M.Context:flatten(input0)
+  local out0 = ffi.new("struct futhark_f32_2d *[1]")
+  self:check(lib.futhark_entry_flatten(self:check_handle(), out0, check_ptr(input0)))
+  self:maybe_sync()
+  return M.ArrayF32D2.wrap(self, out0[0])
+end
+
+ffi.cdef[[
+int futhark_entry_lengths(struct futhark_context *ctx, int64_t *out0, const struct futhark_i8_1d *input0, const struct futhark_i16_1d *input1, const struct futhark_i32_1d *input2, const struct futhark_i64_1d *input3, const struct futhark_u8_1d *input4, const struct futhark_u16_1d *input5, const struct futhark_u32_1d *input6, const struct futhark_u64_1d *input7, const struct futhark_f16_1d *input8, const struct futhark_f32_1d *input9, const struct futhark_f64_1d *input10, const struct futhark_bool_1d *input11);
+]]
+
+--- Entry point: lengths
+function M.Context:lengths(input0, input1, input2, input3, input4, input5, input6, input7, input8, input9, input10, input11)
+  local out0 = ffi.new("int64_t[1]")
+  self:check(lib.futhark_entry_lengths(self:check_handle(), out0, check_ptr(input0), check_ptr(input1), check_ptr(input2), check_ptr(input3), check_ptr(input4), check_ptr(input5), check_ptr(input6), check_ptr(input7), check_ptr(input8), check_ptr(input9), check_ptr(input10), check_ptr(input11)))
+  self:maybe_sync()
+  return out0[0]
+end
+
+ffi.cdef[[
+int futhark_entry_sum_rows(struct futhark_context *ctx, struct futhark_f32_1d **out0, float *out1, const struct futhark_f32_2d *input0);
+]]
+
+--- Entry point: sum_rows
+function M.Context:sum_rows(input0)
+  local out0 = ffi.new("struct futhark_f32_1d *[1]")
+  local out1 = ffi.new("float[1]")
+  self:check(lib.futhark_entry_sum_rows(self:check_handle(), out0, out1, check_ptr(input0)))
+  self:maybe_sync()
+  return M.ArrayF32D1.wrap(self, out0[0]), out1[0]
+end
+
+return M
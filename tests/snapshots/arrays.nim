@@ -0,0 +1,1060 @@
+# Generated by futhark-bindgen
+
+import std/[os, strutils]
+
+# The library can be selected using `-d:futharkLib=path/to/libarrays.so`
+const futharkLib {.strdefine.} = ""
+const libName = (if futharkLib.len > 0: futharkLib else: DynlibFormat % "arrays")
+
+type
+  FutharkError* = object of CatchableError
+    ## Raised when a Futhark function returns a non-zero error code
+    code*: cint
+
+  NullPtrError* = object of CatchableError
+    ## Raised when a Futhark function returns a NULL pointer
+
+  InvalidShapeError* = object of CatchableError
+    ## Raised when the data of an array doesn't match its shape
+
+  futhark_context_config = object
+  futhark_context = object
+
+  Options* = object
+    ## Options used to create a `Context`
+    debug*: bool
+    profile*: bool
+    logging*: bool
+    cacheFile*: string
+    autoSync*: bool
+    numThreads*: int
+
+  ContextObj = object
+    config: ptr futhark_context_config
+    context: ptr futhark_context
+    autoSync: bool
+    cacheFile: string
+
+  Context* = ref ContextObj
+    ## Futhark context
+
+{.push importc, dynlib: libName, cdecl.}
+proc futhark_context_config_new(): ptr futhark_context_config
+proc futhark_context_config_free(cfg: ptr futhark_context_config)
+proc futhark_context_config_set_debugging(cfg: ptr futhark_context_config, flag: cint)
+proc futhark_context_config_set_profiling(cfg: ptr futhark_context_config, flag: cint)
+proc futhark_context_config_set_logging(cfg: ptr futhark_context_config, flag: cint)
+proc futhark_context_config_set_cache_file(cfg: ptr futhark_context_config, f: cstring)
+proc futhark_context_config_set_num_threads(cfg: ptr futhark_context_config, n: cint)
+proc futhark_context_new(cfg: ptr futhark_context_config): ptr futhark_context
+proc futhark_context_free(ctx: ptr futhark_context)
+proc futhark_context_sync(ctx: ptr futhark_context): cint
+proc futhark_context_clear_caches(ctx: ptr futhark_context): cint
+proc futhark_context_pause_profiling(ctx: ptr futhark_context)
+proc futhark_context_unpause_profiling(ctx: ptr futhark_context)
+proc futhark_context_get_error(ctx: ptr futhark_context): cstring
+proc futhark_context_report(ctx: ptr futhark_context): cstring
+{.pop.}
+
+proc cFree(p: pointer) {.importc: "free", header: "<stdlib.h>".}
+
+proc `=destroy`(c: ContextObj) =
+  if c.context != nil:
+    discard futhark_context_sync(c.context)
+    futhark_context_free(c.context)
+  if c.config != nil:
+    futhark_context_config_free(c.config)
+  `=destroy`(c.cacheFile)
+
+proc `=copy`(dst: var ContextObj, src: ContextObj) {.error.}
+
+proc initOptions*(): Options =
+  ## Create new `Options` with default settings
+  Options(autoSync: true)
+
+proc newContext*(options = initOptions()): Context =
+  ## Create a new context
+  let config = futhark_context_config_new()
+  if config == nil:
+    raise newException(NullPtrError, "NULL pointer encountered")
+  result = Context(config: config, autoSync: options.autoSync, cacheFile: options.cacheFile)
+  futhark_context_config_set_debugging(config, cint(options.debug))
+  futhark_context_config_set_profiling(config, cint(options.profile))
+  futhark_context_config_set_logging(config, cint(options.logging))
+  if result.cacheFile.len > 0:
+    futhark_context_config_set_cache_file(config, cstring(result.cacheFile))
+  if options.numThreads != 0:
+    futhark_context_config_set_num_threads(config, cint(options.numThreads))
+  result.context = futhark_context_new(config)
+  if result.context == nil:
+    raise newException(NullPtrError, "NULL pointer encountered")
+
+proc takeString(s: cstring): string =
+  if s == nil:
+    return ""
+  result = $s
+  cFree(cast[pointer](s))
+
+proc getError*(ctx: Context): string =
+  ## Get the last error message, or an empty string
+  takeString(futhark_context_get_error(ctx.context))
+
+proc report*(ctx: Context): string =
+  ## Get the profiling report
+  takeString(futhark_context_report(ctx.context))
+
+proc check(ctx: Context, rc: cint) =
+  if rc != 0:
+    raise (ref FutharkError)(code: rc, msg: "Futhark error code " & $rc & ": " & ctx.getError())
+
+proc sync*(ctx: Context) =
+  ## Sync the context, if `autoSync` is enabled this shouldn't be needed
+  ctx.check(futhark_context_sync(ctx.context))
+
+proc maybeSync(ctx: Context) =
+  if ctx.autoSync:
+    ctx.sync()
+
+proc clearCaches*(ctx: Context) =
+  ## Clear Futhark caches
+  ctx.check(futhark_context_clear_caches(ctx.context))
+
+proc pauseProfiling*(ctx: Context) =
+  ## Pause Futhark profiling
+  futhark_context_pause_profiling(ctx.context)
+
+proc unpauseProfiling*(ctx: Context) =
+  ## Resume profiling
+  futhark_context_unpause_profiling(ctx.context)
+
+proc checkPtr[T](p: ptr T): ptr T =
+  if p == nil:
+    raise newException(NullPtrError, "NULL pointer encountered")
+  p
+
+type
+  futhark_f32_3d = object
+  ArrayF32D3* = object
+    ## Array type with 3 dimensions and f32 elements
+    data: ptr futhark_f32_3d
+    ctx: Context
+    dims: array[3, int64]
+  futhark_f32_2d = object
+  ArrayF32D2* = object
+    ## Array type with 2 dimensions and f32 elements
+    data: ptr futhark_f32_2d
+    ctx: Context
+    dims: array[2, int64]
+  futhark_bool_1d = object
+  ArrayBoolD1* = object
+    ## Array type with 1 dimensions and bool elements
+    data: ptr futhark_bool_1d
+    ctx: Context
+    dims: array[1, int64]
+  futhark_f16_1d = object
+  ArrayF16D1* = object
+    ## Array type with 1 dimensions and f16 elements
+    data: ptr futhark_f16_1d
+    ctx: Context
+    dims: array[1, int64]
+  futhark_f32_1d = object
+  ArrayF32D1* = object
+    ## Array type with 1 dimensions and f32 elements
+    data: ptr futhark_f32_1d
+    ctx: Context
+    dims: array[1, int64]
+  futhark_f64_1d = object
+  ArrayF64D1* = object
+    ## Array type with 1 dimensions and f64 elements
+    data: ptr futhark_f64_1d
+    ctx: Context
+    dims: array[1, int64]
+  futhark_i16_1d = object
+  ArrayI16D1* = object
+    ## Array type with 1 dimensions and i16 elements
+    data: ptr futhark_i16_1d
+    ctx: Context
+    dims: array[1, int64]
+  futhark_i32_1d = object
+  ArrayI32D1* = object
+    ## Array type with 1 dimensions and i32 elements
+    data: ptr futhark_i32_1d
+    ctx: Context
+    dims: array[1, int64]
+  futhark_i64_1d = object
+  ArrayI64D1* = object
+    ## Array type with 1 dimensions and i64 elements
+    data: ptr futhark_i64_1d
+    ctx: Context
+    dims: array[1, int64]
+  futhark_i8_1d = object
+  ArrayI8D1* = object
+    ## Array type with 1 dimensions and i8 elements
+    data: ptr futhark_i8_1d
+    ctx: Context
+    dims: array[1, int64]
+  futhark_u16_1d = object
+  ArrayU16D1* = object
+    ## Array type with 1 dimensions and u16 elements
+    data: ptr futhark_u16_1d
+    ctx: Context
+    dims: array[1, int64]
+  futhark_u32_1d = object
+  ArrayU32D1* = object
+    ## Array type with 1 dimensions and u32 elements
+    data: ptr futhark_u32_1d
+    ctx: Context
+    dims: array[1, int64]
+  futhark_u64_1d = object
+  ArrayU64D1* = object
+    ## Array type with 1 dimensions and u64 elements
+    data: ptr futhark_u64_1d
+    ctx: Context
+    dims: array[1, int64]
+  futhark_u8_1d = object
+  ArrayU8D1* = object
+    ## Array type with 1 dimensions and u8 elements
+    data: ptr futhark_u8_1d
+    ctx: Context
+    dims: array[1, int64]
+
+proc futhark_free_f32_3d(ctx: ptr futhark_context, data: ptr futhark_f32_3d): cint {.importc, dynlib: libName, cdecl.}
+
+proc `=destroy`(x: ArrayF32D3) =
+  if x.data != nil:
+    discard futhark_free_f32_3d(x.ctx.context, x.data)
+  `=destroy`(x.ctx)
+
+proc `=copy`(dst: var ArrayF32D3, src: ArrayF32D3) {.error.}
+
+proc wrap(ctx: Context, data: ptr futhark_f32_3d): ArrayF32D3
+
+proc futhark_free_f32_2d(ctx: ptr futhark_context, data: ptr futhark_f32_2d): cint {.importc, dynlib: libName, cdecl.}
+
+proc `=destroy`(x: ArrayF32D2) =
+  if x.data != nil:
+    discard futhark_free_f32_2d(x.ctx.context, x.data)
+  `=destroy`(x.ctx)
+
+proc `=copy`(dst: var ArrayF32D2, src: ArrayF32D2) {.error.}
+
+proc wrap(ctx: Context, data: ptr futhark_f32_2d): ArrayF32D2
+
+proc futhark_free_bool_1d(ctx: ptr futhark_context, data: ptr futhark_bool_1d): cint {.importc, dynlib: libName, cdecl.}
+
+proc `=destroy`(x: ArrayBoolD1) =
+  if x.data != nil:
+    discard futhark_free_bool_1d(x.ctx.context, x.data)
+  `=destroy`(x.ctx)
+
+proc `=copy`(dst: var ArrayBoolD1, src: ArrayBoolD1) {.error.}
+
+proc wrap(ctx: Context, data: ptr futhark_bool_1d): ArrayBoolD1
+
+proc futhark_free_f16_1d(ctx: ptr futhark_context, data: ptr futhark_f16_1d): cint {.importc, dynlib: libName, cdecl.}
+
+proc `=destroy`(x: ArrayF16D1) =
+  if x.data != nil:
+    discard futhark_free_f16_1d(x.ctx.context, x.data)
+  `=destroy`(x.ctx)
+
+proc `=copy`(dst: var ArrayF16D1, src: ArrayF16D1) {.error.}
+
+proc wrap(ctx: Context, data: ptr futhark_f16_1d): ArrayF16D1
+
+proc futhark_free_f32_1d(ctx: ptr futhark_context, data: ptr futhark_f32_1d): cint {.importc, dynlib: libName, cdecl.}
+
+proc `=destroy`(x: ArrayF32D1) =
+  if x.data != nil:
+    discard futhark_free_f32_1d(x.ctx.context, x.data)
+  `=destroy`(x.ctx)
+
+proc `=copy`(dst: var ArrayF32D1, src: ArrayF32D1) {.error.}
+
+proc wrap(ctx: Context, data: ptr futhark_f32_1d): ArrayF32D1
+
+proc futhark_free_f64_1d(ctx: ptr futhark_context, data: ptr futhark_f64_1d): cint {.importc, dynlib: libName, cdecl.}
+
+proc `=destroy`(x: ArrayF64D1) =
+  if x.data != nil:
+    discard futhark_free_f64_1d(x.ctx.context, x.data)
+  `=destroy`(x.ctx)
+
+proc `=copy`(dst: var ArrayF64D1, src: ArrayF64D1) {.error.}
+
+proc wrap(ctx: Context, data: ptr futhark_f64_1d): ArrayF64D1
+
+proc futhark_free_i16_1d(ctx: ptr futhark_context, data: ptr futhark_i16_1d): cint {.importc, dynlib: libName, cdecl.}
+
+proc `=destroy`(x: ArrayI16D1) =
+  if x.data != nil:
+    discard futhark_free_i16_1d(x.ctx.context, x.data)
+  `=destroy`(x.ctx)
+
+proc `=copy`(dst: var ArrayI16D1, src: ArrayI16D1) {.error.}
+
+proc wrap(ctx: Context, data: ptr futhark_i16_1d): ArrayI16D1
+
+proc futhark_free_i32_1d(ctx: ptr futhark_context, data: ptr futhark_i32_1d): cint {.importc, dynlib: libName, cdecl.}
+
+proc `=destroy`(x: ArrayI32D1) =
+  if x.data != nil:
+    discard futhark_free_i32_1d(x.ctx.context, x.data)
+  `=destroy`(x.ctx)
+
+proc `=copy`(dst: var ArrayI32D1, src: ArrayI32D1) {.error.}
+
+proc wrap(ctx: Context, data: ptr futhark_i32_1d): ArrayI32D1
+
+proc futhark_free_i64_1d(ctx: ptr futhark_context, data: ptr futhark_i64_1d): cint {.importc, dynlib: libName, cdecl.}
+
+proc `=destroy`(x: ArrayI64D1) =
+  if x.data != nil:
+    discard futhark_free_i64_1d(x.ctx.context, x.data)
+  `=destroy`(x.ctx)
+
+proc `=copy`(dst: var ArrayI64D1, src: ArrayI64D1) {.error.}
+
+proc wrap(ctx: Context, data: ptr futhark_i64_1d): ArrayI64D1
+
+proc futhark_free_i8_1d(ctx: ptr futhark_context, data: ptr futhark_i8_1d): cint {.importc, dynlib: libName, cdecl.}
+
+proc `=destroy`(x: ArrayI8D1) =
+  if x.data != nil:
+    discard futhark_free_i8_1d(x.ctx.context, x.data)
+  `=destroy`(x.ctx)
+
+proc `=copy`(dst: var ArrayI8D1, src: ArrayI8D1) {.error.}
+
+proc wrap(ctx: Context, data: ptr futhark_i8_1d): ArrayI8D1
+
+proc futhark_free_u16_1d(ctx: ptr futhark_context, data: ptr futhark_u16_1d): cint {.importc, dynlib: libName, cdecl.}
+
+proc `=destroy`(x: ArrayU16D1) =
+  if x.data != nil:
+    discard futhark_free_u16_1d(x.ctx.context, x.data)
+  `=destroy`(x.ctx)
+
+proc `=copy`(dst: var ArrayU16D1, src: ArrayU16D1) {.error.}
+
+proc wrap(ctx: Context, data: ptr futhark_u16_1d): ArrayU16D1
+
+proc futhark_free_u32_1d(ctx: ptr futhark_context, data: ptr futhark_u32_1d): cint {.importc, dynlib: libName, cdecl.}
+
+proc `=destroy`(x: ArrayU32D1) =
+  if x.data != nil:
+    discard futhark_free_u32_1d(x.ctx.context, x.data)
+  `=destroy`(x.ctx)
+
+proc `=copy`(dst: var ArrayU32D1, src: ArrayU32D1) {.error.}
+
+proc wrap(ctx: Context, data: ptr futhark_u32_1d): ArrayU32D1
+
+proc futhark_free_u64_1d(ctx: ptr futhark_context, data: ptr futhark_u64_1d): cint {.importc, dynlib: libName, cdecl.}
+
+proc `=destroy`(x: ArrayU64D1) =
+  if x.data != nil:
+    discard futhark_free_u64_1d(x.ctx.context, x.data)
+  `=destroy`(x.ctx)
+
+proc `=copy`(dst: var ArrayU64D1, src: ArrayU64D1) {.error.}
+
+proc wrap(ctx: Context, data: ptr futhark_u64_1d): ArrayU64D1
+
+proc futhark_free_u8_1d(ctx: ptr futhark_context, data: ptr futhark_u8_1d): cint {.importc, dynlib: libName, cdecl.}
+
+proc `=destroy`(x: ArrayU8D1) =
+  if x.data != nil:
+    discard futhark_free_u8_1d(x.ctx.context, x.data)
+  `=destroy`(x.ctx)
+
+proc `=copy`(dst: var ArrayU8D1, src: ArrayU8D1) {.error.}
+
+proc wrap(ctx: Context, data: ptr futhark_u8_1d): ArrayU8D1
+
+{.push importc, dynlib: libName, cdecl.}
+proc futhark_new_f32_3d(ctx: ptr futhark_context, data: ptr float32, dim0: int64, dim1: int64, dim2: int64): ptr futhark_f32_3d
+proc futhark_values_f32_3d(ctx: ptr futhark_context, arr: ptr futhark_f32_3d, data: ptr float32): cint
+proc futhark_shape_f32_3d(ctx: ptr futhark_context, arr: ptr futhark_f32_3d): ptr UncheckedArray[int64]
+{.pop.}
+
+proc wrap(ctx: Context, data: ptr futhark_f32_3d): ArrayF32D3 =
+  let shape = futhark_shape_f32_3d(ctx.context, checkPtr(data))
+  result = ArrayF32D3(data: data, ctx: ctx)
+  for i in 0 ..< 3:
+    result.dims[i] = shape[i]
+
+proc newArrayF32D3*(ctx: Context, data: openArray[float32], dims: array[3, int64]): ArrayF32D3 =
+  ## Create a new array of `dims` dimensions and initialize it with the values from `data`
+  var size = 1'i64
+  for d in dims:
+    size *= d
+  if data.len.int64 != size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  let arr = checkPtr(futhark_new_f32_3d(ctx.context, p, dims[0], dims[1], dims[2]))
+  result = ArrayF32D3(data: arr, ctx: ctx, dims: dims)
+  ctx.maybeSync()
+
+proc shape*(x: ArrayF32D3): array[3, int64] =
+  ## Get the array shape
+  x.dims
+
+proc size*(x: ArrayF32D3): int64 =
+  ## Get the number of elements in the array
+  result = 1
+  for d in x.dims:
+    result *= d
+
+proc values*(x: ArrayF32D3, data: var openArray[float32]) =
+  ## Load values into `data`
+  if data.len.int64 != x.size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  x.ctx.check(futhark_values_f32_3d(x.ctx.context, x.data, p))
+  x.ctx.maybeSync()
+
+proc get*(x: ArrayF32D3): seq[float32] =
+  ## Load values into a `seq`
+  result = newSeq[float32](x.size)
+  x.values(result)
+
+{.push importc, dynlib: libName, cdecl.}
+proc futhark_new_f32_2d(ctx: ptr futhark_context, data: ptr float32, dim0: int64, dim1: int64): ptr futhark_f32_2d
+proc futhark_values_f32_2d(ctx: ptr futhark_context, arr: ptr futhark_f32_2d, data: ptr float32): cint
+proc futhark_shape_f32_2d(ctx: ptr futhark_context, arr: ptr futhark_f32_2d): ptr UncheckedArray[int64]
+{.pop.}
+
+proc wrap(ctx: Context, data: ptr futhark_f32_2d): ArrayF32D2 =
+  let shape = futhark_shape_f32_2d(ctx.context, checkPtr(data))
+  result = ArrayF32D2(data: data, ctx: ctx)
+  for i in 0 ..< 2:
+    result.dims[i] = shape[i]
+
+proc newArrayF32D2*(ctx: Context, data: openArray[float32], dims: array[2, int64]): ArrayF32D2 =
+  ## Create a new array of `dims` dimensions and initialize it with the values from `data`
+  var size = 1'i64
+  for d in dims:
+    size *= d
+  if data.len.int64 != size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  let arr = checkPtr(futhark_new_f32_2d(ctx.context, p, dims[0], dims[1]))
+  result = ArrayF32D2(data: arr, ctx: ctx, dims: dims)
+  ctx.maybeSync()
+
+proc shape*(x: ArrayF32D2): array[2, int64] =
+  ## Get the array shape
+  x.dims
+
+proc size*(x: ArrayF32D2): int64 =
+  ## Get the number of elements in the array
+  result = 1
+  for d in x.dims:
+    result *= d
+
+proc values*(x: ArrayF32D2, data: var openArray[float32]) =
+  ## Load values into `data`
+  if data.len.int64 != x.size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  x.ctx.check(futhark_values_f32_2d(x.ctx.context, x.data, p))
+  x.ctx.maybeSync()
+
+proc get*(x: ArrayF32D2): seq[float32] =
+  ## Load values into a `seq`
+  result = newSeq[float32](x.size)
+  x.values(result)
+
+{.push importc, dynlib: libName, cdecl.}
+proc futhark_new_bool_1d(ctx: ptr futhark_context, data: ptr bool, dim0: int64): ptr futhark_bool_1d
+proc futhark_values_bool_1d(ctx: ptr futhark_context, arr: ptr futhark_bool_1d, data: ptr bool): cint
+proc futhark_shape_bool_1d(ctx: ptr futhark_context, arr: ptr futhark_bool_1d): ptr UncheckedArray[int64]
+{.pop.}
+
+proc wrap(ctx: Context, data: ptr futhark_bool_1d): ArrayBoolD1 =
+  let shape = futhark_shape_bool_1d(ctx.context, checkPtr(data))
+  result = ArrayBoolD1(data: data, ctx: ctx)
+  for i in 0 ..< 1:
+    result.dims[i] = shape[i]
+
+proc newArrayBoolD1*(ctx: Context, data: openArray[bool], dims: array[1, int64]): ArrayBoolD1 =
+  ## Create a new array of `dims` dimensions and initialize it with the values from `data`
+  var size = 1'i64
+  for d in dims:
+    size *= d
+  if data.len.int64 != size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  let arr = checkPtr(futhark_new_bool_1d(ctx.context, p, dims[0]))
+  result = ArrayBoolD1(data: arr, ctx: ctx, dims: dims)
+  ctx.maybeSync()
+
+proc shape*(x: ArrayBoolD1): array[1, int64] =
+  ## Get the array shape
+  x.dims
+
+proc size*(x: ArrayBoolD1): int64 =
+  ## Get the number of elements in the array
+  result = 1
+  for d in x.dims:
+    result *= d
+
+proc values*(x: ArrayBoolD1, data: var openArray[bool]) =
+  ## Load values into `data`
+  if data.len.int64 != x.size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  x.ctx.check(futhark_values_bool_1d(x.ctx.context, x.data, p))
+  x.ctx.maybeSync()
+
+proc get*(x: ArrayBoolD1): seq[bool] =
+  ## Load values into a `seq`
+  result = newSeq[bool](x.size)
+  x.values(result)
+
+{.push importc, dynlib: libName, cdecl.}
+proc futhark_new_f16_1d(ctx: ptr futhark_context, data: ptr uint16, dim0: int64): ptr futhark_f16_1d
+proc futhark_values_f16_1d(ctx: ptr futhark_context, arr: ptr futhark_f16_1d, data: ptr uint16): cint
+proc futhark_shape_f16_1d(ctx: ptr futhark_context, arr: ptr futhark_f16_1d): ptr UncheckedArray[int64]
+{.pop.}
+
+proc wrap(ctx: Context, data: ptr futhark_f16_1d): ArrayF16D1 =
+  let shape = futhark_shape_f16_1d(ctx.context, checkPtr(data))
+  result = ArrayF16D1(data: data, ctx: ctx)
+  for i in 0 ..< 1:
+    result.dims[i] = shape[i]
+
+proc newArrayF16D1*(ctx: Context, data: openArray[uint16], dims: array[1, int64]): ArrayF16D1 =
+  ## Create a new array of `dims` dimensions and initialize it with the values from `data`
+  var size = 1'i64
+  for d in dims:
+    size *= d
+  if data.len.int64 != size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  let arr = checkPtr(futhark_new_f16_1d(ctx.context, p, dims[0]))
+  result = ArrayF16D1(data: arr, ctx: ctx, dims: dims)
+  ctx.maybeSync()
+
+proc shape*(x: ArrayF16D1): array[1, int64] =
+  ## Get the array shape
+  x.dims
+
+proc size*(x: ArrayF16D1): int64 =
+  ## Get the number of elements in the array
+  result = 1
+  for d in x.dims:
+    result *= d
+
+proc values*(x: ArrayF16D1, data: var openArray[uint16]) =
+  ## Load values into `data`
+  if data.len.int64 != x.size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  x.ctx.check(futhark_values_f16_1d(x.ctx.context, x.data, p))
+  x.ctx.maybeSync()
+
+proc get*(x: ArrayF16D1): seq[uint16] =
+  ## Load values into a `seq`
+  result = newSeq[uint16](x.size)
+  x.values(result)
+
+{.push importc, dynlib: libName, cdecl.}
+proc futhark_new_f32_1d(ctx: ptr futhark_context, data: ptr float32, dim0: int64): ptr futhark_f32_1d
+proc futhark_values_f32_1d(ctx: ptr futhark_context, arr: ptr futhark_f32_1d, data: ptr float32): cint
+proc futhark_shape_f32_1d(ctx: ptr futhark_context, arr: ptr futhark_f32_1d): ptr UncheckedArray[int64]
+{.pop.}
+
+proc wrap(ctx: Context, data: ptr futhark_f32_1d): ArrayF32D1 =
+  let shape = futhark_shape_f32_1d(ctx.context, checkPtr(data))
+  result = ArrayF32D1(data: data, ctx: ctx)
+  for i in 0 ..< 1:
+    result.dims[i] = shape[i]
+
+proc newArrayF32D1*(ctx: Context, data: openArray[float32], dims: array[1, int64]): ArrayF32D1 =
+  ## Create a new array of `dims` dimensions and initialize it with the values from `data`
+  var size = 1'i64
+  for d in dims:
+    size *= d
+  if data.len.int64 != size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  let arr = checkPtr(futhark_new_f32_1d(ctx.context, p, dims[0]))
+  result = ArrayF32D1(data: arr, ctx: ctx, dims: dims)
+  ctx.maybeSync()
+
+proc shape*(x: ArrayF32D1): array[1, int64] =
+  ## Get the array shape
+  x.dims
+
+proc size*(x: ArrayF32D1): int64 =
+  ## Get the number of elements in the array
+  result = 1
+  for d in x.dims:
+    result *= d
+
+proc values*(x: ArrayF32D1, data: var openArray[float32]) =
+  ## Load values into `data`
+  if data.len.int64 != x.size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  x.ctx.check(futhark_values_f32_1d(x.ctx.context, x.data, p))
+  x.ctx.maybeSync()
+
+proc get*(x: ArrayF32D1): seq[float32] =
+  ## Load values into a `seq`
+  result = newSeq[float32](x.size)
+  x.values(result)
+
+{.push importc, dynlib: libName, cdecl.}
+proc futhark_new_f64_1d(ctx: ptr futhark_context, data: ptr float64, dim0: int64): ptr futhark_f64_1d
+proc futhark_values_f64_1d(ctx: ptr futhark_context, arr: ptr futhark_f64_1d, data: ptr float64): cint
+proc futhark_shape_f64_1d(ctx: ptr futhark_context, arr: ptr futhark_f64_1d): ptr UncheckedArray[int64]
+{.pop.}
+
+proc wrap(ctx: Context, data: ptr futhark_f64_1d): ArrayF64D1 =
+  let shape = futhark_shape_f64_1d(ctx.context, checkPtr(data))
+  result = ArrayF64D1(data: data, ctx: ctx)
+  for i in 0 ..< 1:
+    result.dims[i] = shape[i]
+
+proc newArrayF64D1*(ctx: Context, data: openArray[float64], dims: array[1, int64]): ArrayF64D1 =
+  ## Create a new array of `dims` dimensions and initialize it with the values from `data`
+  var size = 1'i64
+  for d in dims:
+    size *= d
+  if data.len.int64 != size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  let arr = checkPtr(futhark_new_f64_1d(ctx.context, p, dims[0]))
+  result = ArrayF64D1(data: arr, ctx: ctx, dims: dims)
+  ctx.maybeSync()
+
+proc shape*(x: ArrayF64D1): array[1, int64] =
+  ## Get the array shape
+  x.dims
+
+proc size*(x: ArrayF64D1): int64 =
+  ## Get the number of elements in the array
+  result = 1
+  for d in x.dims:
+    result *= d
+
+proc values*(x: ArrayF64D1, data: var openArray[float64]) =
+  ## Load values into `data`
+  if data.len.int64 != x.size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  x.ctx.check(futhark_values_f64_1d(x.ctx.context, x.data, p))
+  x.ctx.maybeSync()
+
+proc get*(x: ArrayF64D1): seq[float64] =
+  ## Load values into a `seq`
+  result = newSeq[float64](x.size)
+  x.values(result)
+
+{.push importc, dynlib: libName, cdecl.}
+proc futhark_new_i16_1d(ctx: ptr futhark_context, data: ptr int16, dim0: int64): ptr futhark_i16_1d
+proc futhark_values_i16_1d(ctx: ptr futhark_context, arr: ptr futhark_i16_1d, data: ptr int16): cint
+proc futhark_shape_i16_1d(ctx: ptr futhark_context, arr: ptr futhark_i16_1d): ptr UncheckedArray[int64]
+{.pop.}
+
+proc wrap(ctx: Context, data: ptr futhark_i16_1d): ArrayI16D1 =
+  let shape = futhark_shape_i16_1d(ctx.context, checkPtr(data))
+  result = ArrayI16D1(data: data, ctx: ctx)
+  for i in 0 ..< 1:
+    result.dims[i] = shape[i]
+
+proc newArrayI16D1*(ctx: Context, data: openArray[int16], dims: array[1, int64]): ArrayI16D1 =
+  ## Create a new array of `dims` dimensions and initialize it with the values from `data`
+  var size = 1'i64
+  for d in dims:
+    size *= d
+  if data.len.int64 != size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  let arr = checkPtr(futhark_new_i16_1d(ctx.context, p, dims[0]))
+  result = ArrayI16D1(data: arr, ctx: ctx, dims: dims)
+  ctx.maybeSync()
+
+proc shape*(x: ArrayI16D1): array[1, int64] =
+  ## Get the array shape
+  x.dims
+
+proc size*(x: ArrayI16D1): int64 =
+  ## Get the number of elements in the array
+  result = 1
+  for d in x.dims:
+    result *= d
+
+proc values*(x: ArrayI16D1, data: var openArray[int16]) =
+  ## Load values into `data`
+  if data.len.int64 != x.size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  x.ctx.check(futhark_values_i16_1d(x.ctx.context, x.data, p))
+  x.ctx.maybeSync()
+
+proc get*(x: ArrayI16D1): seq[int16] =
+  ## Load values into a `seq`
+  result = newSeq[int16](x.size)
+  x.values(result)
+
+{.push importc, dynlib: libName, cdecl.}
+proc futhark_new_i32_1d(ctx: ptr futhark_context, data: ptr int32, dim0: int64): ptr futhark_i32_1d
+proc futhark_values_i32_1d(ctx: ptr futhark_context, arr: ptr futhark_i32_1d, data: ptr int32): cint
+proc futhark_shape_i32_1d(ctx: ptr futhark_context, arr: ptr futhark_i32_1d): ptr UncheckedArray[int64]
+{.pop.}
+
+proc wrap(ctx: Context, data: ptr futhark_i32_1d): ArrayI32D1 =
+  let shape = futhark_shape_i32_1d(ctx.context, checkPtr(data))
+  result = ArrayI32D1(data: data, ctx: ctx)
+  for i in 0 ..< 1:
+    result.dims[i] = shape[i]
+
+proc newArrayI32D1*(ctx: Context, data: openArray[int32], dims: array[1, int64]): ArrayI32D1 =
+  ## Create a new array of `dims` dimensions and initialize it with the values from `data`
+  var size = 1'i64
+  for d in dims:
+    size *= d
+  if data.len.int64 != size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  let arr = checkPtr(futhark_new_i32_1d(ctx.context, p, dims[0]))
+  result = ArrayI32D1(data: arr, ctx: ctx, dims: dims)
+  ctx.maybeSync()
+
+proc shape*(x: ArrayI32D1): array[1, int64] =
+  ## Get the array shape
+  x.dims
+
+proc size*(x: ArrayI32D1): int64 =
+  ## Get the number of elements in the array
+  result = 1
+  for d in x.dims:
+    result *= d
+
+proc values*(x: ArrayI32D1, data: var openArray[int32]) =
+  ## Load values into `data`
+  if data.len.int64 != x.size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  x.ctx.check(futhark_values_i32_1d(x.ctx.context, x.data, p))
+  x.ctx.maybeSync()
+
+proc get*(x: ArrayI32D1): seq[int32] =
+  ## Load values into a `seq`
+  result = newSeq[int32](x.size)
+  x.values(result)
+
+{.push importc, dynlib: libName, cdecl.}
+proc futhark_new_i64_1d(ctx: ptr futhark_context, data: ptr int64, dim0: int64): ptr futhark_i64_1d
+proc futhark_values_i64_1d(ctx: ptr futhark_context, arr: ptr futhark_i64_1d, data: ptr int64): cint
+proc futhark_shape_i64_1d(ctx: ptr futhark_context, arr: ptr futhark_i64_1d): ptr UncheckedArray[int64]
+{.pop.}
+
+proc wrap(ctx: Context, data: ptr futhark_i64_1d): ArrayI64D1 =
+  let shape = futhark_shape_i64_1d(ctx.context, checkPtr(data))
+  result = ArrayI64D1(data: data, ctx: ctx)
+  for i in 0 ..< 1:
+    result.dims[i] = shape[i]
+
+proc newArrayI64D1*(ctx: Context, data: openArray[int64], dims: array[1, int64]): ArrayI64D1 =
+  ## Create a new array of `dims` dimensions and initialize it with the values from `data`
+  var size = 1'i64
+  for d in dims:
+    size *= d
+  if data.len.int64 != size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  let arr = checkPtr(futhark_new_i64_1d(ctx.context, p, dims[0]))
+  result = ArrayI64D1(data: arr, ctx: ctx, dims: dims)
+  ctx.maybeSync()
+
+proc shape*(x: ArrayI64D1): array[1, int64] =
+  ## Get the array shape
+  x.dims
+
+proc size*(x: ArrayI64D1): int64 =
+  ## Get the number of elements in the array
+  result = 1
+  for d in x.dims:
+    result *= d
+
+proc values*(x: ArrayI64D1, data: var openArray[int64]) =
+  ## Load values into `data`
+  if data.len.int64 != x.size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  x.ctx.check(futhark_values_i64_1d(x.ctx.context, x.data, p))
+  x.ctx.maybeSync()
+
+proc get*(x: ArrayI64D1): seq[int64] =
+  ## Load values into a `seq`
+  result = newSeq[int64](x.size)
+  x.values(result)
+
+{.push importc, dynlib: libName, cdecl.}
+proc futhark_new_i8_1d(ctx: ptr futhark_context, data: ptr int8, dim0: int64): ptr futhark_i8_1d
+proc futhark_values_i8_1d(ctx: ptr futhark_context, arr: ptr futhark_i8_1d, data: ptr int8): cint
+proc futhark_shape_i8_1d(ctx: ptr futhark_context, arr: ptr futhark_i8_1d): ptr UncheckedArray[int64]
+{.pop.}
+
+proc wrap(ctx: Context, data: ptr futhark_i8_1d): ArrayI8D1 =
+  let shape = futhark_shape_i8_1d(ctx.context, checkPtr(data))
+  result = ArrayI8D1(data: data, ctx: ctx)
+  for i in 0 ..< 1:
+    result.dims[i] = shape[i]
+
+proc newArrayI8D1*(ctx: Context, data: openArray[int8], dims: array[1, int64]): ArrayI8D1 =
+  ## Create a new array of `dims` dimensions and initialize it with the values from `data`
+  var size = 1'i64
+  for d in dims:
+    size *= d
+  if data.len.int64 != size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  let arr = checkPtr(futhark_new_i8_1d(ctx.context, p, dims[0]))
+  result = ArrayI8D1(data: arr, ctx: ctx, dims: dims)
+  ctx.maybeSync()
+
+proc shape*(x: ArrayI8D1): array[1, int64] =
+  ## Get the array shape
+  x.dims
+
+proc size*(x: ArrayI8D1): int64 =
+  ## Get the number of elements in the array
+  result = 1
+  for d in x.dims:
+    result *= d
+
+proc values*(x: ArrayI8D1, data: var openArray[int8]) =
+  ## Load values into `data`
+  if data.len.int64 != x.size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  x.ctx.check(futhark_values_i8_1d(x.ctx.context, x.data, p))
+  x.ctx.maybeSync()
+
+proc get*(x: ArrayI8D1): seq[int8] =
+  ## Load values into a `seq`
+  result = newSeq[int8](x.size)
+  x.values(result)
+
+{.push importc, dynlib: libName, cdecl.}
+proc futhark_new_u16_1d(ctx: ptr futhark_context, data: ptr uint16, dim0: int64): ptr futhark_u16_1d
+proc futhark_values_u16_1d(ctx: ptr futhark_context, arr: ptr futhark_u16_1d, data: ptr uint16): cint
+proc futhark_shape_u16_1d(ctx: ptr futhark_context, arr: ptr futhark_u16_1d): ptr UncheckedArray[int64]
+{.pop.}
+
+proc wrap(ctx: Context, data: ptr futhark_u16_1d): ArrayU16D1 =
+  let shape = futhark_shape_u16_1d(ctx.context, checkPtr(data))
+  result = ArrayU16D1(data: data, ctx: ctx)
+  for i in 0 ..< 1:
+    result.dims[i] = shape[i]
+
+proc newArrayU16D1*(ctx: Context, data: openArray[uint16], dims: array[1, int64]): ArrayU16D1 =
+  ## Create a new array of `dims` dimensions and initialize it with the values from `data`
+  var size = 1'i64
+  for d in dims:
+    size *= d
+  if data.len.int64 != size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  let arr = checkPtr(futhark_new_u16_1d(ctx.context, p, dims[0]))
+  result = ArrayU16D1(data: arr, ctx: ctx, dims: dims)
+  ctx.maybeSync()
+
+proc shape*(x: ArrayU16D1): array[1, int64] =
+  ## Get the array shape
+  x.dims
+
+proc size*(x: ArrayU16D1): int64 =
+  ## Get the number of elements in the array
+  result = 1
+  for d in x.dims:
+    result *= d
+
+proc values*(x: ArrayU16D1, data: var openArray[uint16]) =
+  ## Load values into `data`
+  if data.len.int64 != x.size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  x.ctx.check(futhark_values_u16_1d(x.ctx.context, x.data, p))
+  x.ctx.maybeSync()
+
+proc get*(x: ArrayU16D1): seq[uint16] =
+  ## Load values into a `seq`
+  result = newSeq[uint16](x.size)
+  x.values(result)
+
+{.push importc, dynlib: libName, cdecl.}
+proc futhark_new_u32_1d(ctx: ptr futhark_context, data: ptr uint32, dim0: int64): ptr futhark_u32_1d
+proc futhark_values_u32_1d(ctx: ptr futhark_context, arr: ptr futhark_u32_1d, data: ptr uint32): cint
+proc futhark_shape_u32_1d(ctx: ptr futhark_context, arr: ptr futhark_u32_1d): ptr UncheckedArray[int64]
+{.pop.}
+
+proc wrap(ctx: Context, data: ptr futhark_u32_1d): ArrayU32D1 =
+  let shape = futhark_shape_u32_1d(ctx.context, checkPtr(data))
+  result = ArrayU32D1(data: data, ctx: ctx)
+  for i in 0 ..< 1:
+    result.dims[i] = shape[i]
+
+proc newArrayU32D1*(ctx: Context, data: openArray[uint32], dims: array[1, int64]): ArrayU32D1 =
+  ## Create a new array of `dims` dimensions and initialize it with the values from `data`
+  var size = 1'i64
+  for d in dims:
+    size *= d
+  if data.len.int64 != size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  let arr = checkPtr(futhark_new_u32_1d(ctx.context, p, dims[0]))
+  result = ArrayU32D1(data: arr, ctx: ctx, dims: dims)
+  ctx.maybeSync()
+
+proc shape*(x: ArrayU32D1): array[1, int64] =
+  ## Get the array shape
+  x.dims
+
+proc size*(x: ArrayU32D1): int64 =
+  ## Get the number of elements in the array
+  result = 1
+  for d in x.dims:
+    result *= d
+
+proc values*(x: ArrayU32D1, data: var openArray[uint32]) =
+  ## Load values into `data`
+  if data.len.int64 != x.size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  x.ctx.check(futhark_values_u32_1d(x.ctx.context, x.data, p))
+  x.ctx.maybeSync()
+
+proc get*(x: ArrayU32D1): seq[uint32] =
+  ## Load values into a `seq`
+  result = newSeq[uint32](x.size)
+  x.values(result)
+
+{.push importc, dynlib: libName, cdecl.}
+proc futhark_new_u64_1d(ctx: ptr futhark_context, data: ptr uint64, dim0: int64): ptr futhark_u64_1d
+proc futhark_values_u64_1d(ctx: ptr futhark_context, arr: ptr futhark_u64_1d, data: ptr uint64): cint
+proc futhark_shape_u64_1d(ctx: ptr futhark_context, arr: ptr futhark_u64_1d): ptr UncheckedArray[int64]
+{.pop.}
+
+proc wrap(ctx: Context, data: ptr futhark_u64_1d): ArrayU64D1 =
+  let shape = futhark_shape_u64_1d(ctx.context, checkPtr(data))
+  result = ArrayU64D1(data: data, ctx: ctx)
+  for i in 0 ..< 1:
+    result.dims[i] = shape[i]
+
+proc newArrayU64D1*(ctx: Context, data: openArray[uint64], dims: array[1, int64]): ArrayU64D1 =
+  ## Create a new array of `dims` dimensions and initialize it with the values from `data`
+  var size = 1'i64
+  for d in dims:
+    size *= d
+  if data.len.int64 != size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  let arr = checkPtr(futhark_new_u64_1d(ctx.context, p, dims[0]))
+  result = ArrayU64D1(data: arr, ctx: ctx, dims: dims)
+  ctx.maybeSync()
+
+proc shape*(x: ArrayU64D1): array[1, int64] =
+  ## Get the array shape
+  x.dims
+
+proc size*(x: ArrayU64D1): int64 =
+  ## Get the number of elements in the array
+  result = 1
+  for d in x.dims:
+    result *= d
+
+proc values*(x: ArrayU64D1, data: var openArray[uint64]) =
+  ## Load values into `data`
+  if data.len.int64 != x.size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  x.ctx.check(futhark_values_u64_1d(x.ctx.context, x.data, p))
+  x.ctx.maybeSync()
+
+proc get*(x: ArrayU64D1): seq[uint64] =
+  ## Load values into a `seq`
+  result = newSeq[uint64](x.size)
+  x.values(result)
+
+{.push importc, dynlib: libName, cdecl.}
+proc futhark_new_u8_1d(ctx: ptr futhark_context, data: ptr uint8, dim0: int64): ptr futhark_u8_1d
+proc futhark_values_u8_1d(ctx: ptr futhark_context, arr: ptr futhark_u8_1d, data: ptr uint8): cint
+proc futhark_shape_u8_1d(ctx: ptr futhark_context, arr: ptr futhark_u8_1d): ptr UncheckedArray[int64]
+{.pop.}
+
+proc wrap(ctx: Context, data: ptr futhark_u8_1d): ArrayU8D1 =
+  let shape = futhark_shape_u8_1d(ctx.context, checkPtr(data))
+  result = ArrayU8D1(data: data, ctx: ctx)
+  for i in 0 ..< 1:
+    result.dims[i] = shape[i]
+
+proc newArrayU8D1*(ctx: Context, data: openArray[uint8], dims: array[1, int64]): ArrayU8D1 =
+  ## Create a new array of `dims` dimensions and initialize it with the values from `data`
+  var size = 1'i64
+  for d in dims:
+    size *= d
+  if data.len.int64 != size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  let arr = checkPtr(futhark_new_u8_1d(ctx.context, p, dims[0]))
+  result = ArrayU8D1(data: arr, ctx: ctx, dims: dims)
+  ctx.maybeSync()
+
+proc shape*(x: ArrayU8D1): array[1, int64] =
+  ## Get the array shape
+  x.dims
+
+proc size*(x: ArrayU8D1): int64 =
+  ## Get the number of elements in the array
+  result = 1
+  for d in x.dims:
+    result *= d
+
+proc values*(x: ArrayU8D1, data: var openArray[uint8]) =
+  ## Load values into `data`
+  if data.len.int64 != x.size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  x.ctx.check(futhark_values_u8_1d(x.ctx.context, x.data, p))
+  x.ctx.maybeSync()
+
+proc get*(x: ArrayU8D1): seq[uint8] =
+  ## Load values into a `seq`
+  result = newSeq[uint8](x.size)
+  x.values(result)
+
+proc futhark_entry_flatten(ctx: ptr futhark_context, out0: ptr ptr futhark_f32_2d, input0: ptr futhark_f32_3d): cint {.importc, dynlib: libName, cdecl.}
+
+proc flatten*(ctx: Context, input0: ArrayF32D3): ArrayF32D2 =
+  ## Entry point: flatten
+  var out0: ptr futhark_f32_2d
+  ctx.check(futhark_entry_flatten(ctx.context, addr out0, input0.data))
+  result = wrap(ctx, out0)
+  ctx.maybeSync()
+
+proc futhark_entry_lengths(ctx: ptr futhark_context, out0: ptr int64, input0: ptr futhark_i8_1d, input1: ptr futhark_i16_1d, input2: ptr futhark_i32_1d, input3: ptr futhark_i64_1d, input4: ptr futhark_u8_1d, input5: ptr futhark_u16_1d, input6: ptr futhark_u32_1d, input7: ptr futhark_u64_1d, input8: ptr futhark_f16_1d, input9: ptr futhark_f32_1d, input10: ptr futhark_f64_1d, input11: ptr futhark_bool_1d): cint {.importc, dynlib: libName, cdecl.}
+
+proc lengths*(ctx: Context, input0: ArrayI8D1, input1: ArrayI16D1, input2: ArrayI32D1, input3: ArrayI64D1, input4: ArrayU8D1, input5: ArrayU16D1, input6: ArrayU32D1, input7: ArrayU64D1, input8: ArrayF16D1, input9: ArrayF32D1, input10: ArrayF64D1, input11: ArrayBoolD1): int64 =
+  ## Entry point: lengths
+  var out0: int64
+  ctx.check(futhark_entry_lengths(ctx.context, addr out0, input0.data, input1.data, input2.data, input3.data, input4.data, input5.data, input6.data, input7.data, input8.data, input9.data, input10.data, input11.data))
+  result = out0
+  ctx.maybeSync()
+
+proc futhark_entry_sum_rows(ctx: ptr futhark_context, out0: ptr ptr futhark_f32_1d, out1: ptr float32, input0: ptr futhark_f32_2d): cint {.importc, dynlib: libName, cdecl.}
+
+proc sumRows*(ctx: Context, input0: ArrayF32D2): (ArrayF32D1, float32) =
+  ## Entry point: sum_rows
+  var out0: ptr futhark_f32_1d
+  var out1: float32
+  ctx.check(futhark_entry_sum_rows(ctx.context, addr out0, addr out1, input0.data))
+  result = (wrap(ctx, out0), out1)
+  ctx.maybeSync()
+
@@ -0,0 +1,955 @@
+// Generated by futhark-bindgen
+
+const std = @import("std");
+
+const futhark_context_config = opaque {};
+const futhark_context = opaque {};
+
+extern fn futhark_context_config_new() ?*futhark_context_config;
+extern fn futhark_context_config_free(cfg: *futhark_context_config) void;
+extern fn futhark_context_config_set_debugging(cfg: *futhark_context_config, flag: c_int) void;
+extern fn futhark_context_config_set_profiling(cfg: *futhark_context_config, flag: c_int) void;
+extern fn futhark_context_config_set_logging(cfg: *futhark_context_config, flag: c_int) void;
+extern fn futhark_context_config_set_cache_file(cfg: *futhark_context_config, f: [*:0]const u8) void;
+extern fn futhark_context_config_set_num_threads(cfg: *futhark_context_config, n: c_int) void;
+extern fn futhark_context_new(cfg: *futhark_context_config) ?*futhark_context;
+extern fn futhark_context_free(ctx: *futhark_context) void;
+extern fn futhark_context_sync(ctx: *futhark_context) c_int;
+extern fn futhark_context_clear_caches(ctx: *futhark_context) c_int;
+extern fn futhark_context_pause_profiling(ctx: *futhark_context) void;
+extern fn futhark_context_unpause_profiling(ctx: *futhark_context) void;
+extern fn futhark_context_get_error(ctx: *futhark_context) ?[*:0]u8;
+extern fn futhark_context_report(ctx: *futhark_context) ?[*:0]u8;
+extern fn free(ptr: ?*anyopaque) void;
+
+/// Errors returned by the generated functions
+pub const Error = error{
+    /// A Futhark function returned a non-zero error code, see `Context.getError`
+    Futhark,
+    /// A NULL pointer was returned
+    NullPtr,
+    /// The data doesn't match the shape of the array
+    InvalidShape,
+};
+
+/// Context options
+pub const Options = struct {
+    debug: bool = false,
+    profile: bool = false,
+    logging: bool = false,
+    cache_file: ?[*:0]const u8 = null,
+    auto_sync: bool = true,
+    num_threads: c_int = 0,
+};
+
+/// Futhark context
+pub const Context = struct {
+    config: *futhark_context_config,
+    handle: *futhark_context,
+    auto_sync: bool,
+
+    /// Create a new context
+    pub fn init(options: Options) Error!Context {
+        const config = futhark_context_config_new() orelse return error.NullPtr;
+        futhark_context_config_set_debugging(config, @intFromBool(options.debug));
+        futhark_context_config_set_profiling(config, @intFromBool(options.profile));
+        futhark_context_config_set_logging(config, @intFromBool(options.logging));
+        if (options.cache_file) |f| futhark_context_config_set_cache_file(config, f);
+        futhark_context_config_set_num_threads(config, options.num_threads);
+        const handle = futhark_context_new(config) orelse {
+            futhark_context_config_free(config);
+            return error.NullPtr;
+        };
+        return .{ .config = config, .handle = handle, .auto_sync = options.auto_sync };
+    }
+
+    /// Free the context, all values created using the context must be freed first
+    pub fn deinit(self: *Context) void {
+        _ = futhark_context_sync(self.handle);
+        futhark_context_free(self.handle);
+        futhark_context_config_free(self.config);
+    }
+
+    /// Wait for all pending operations to finish
+    pub fn sync(self: *Context) Error!void {
+        if (futhark_context_sync(self.handle) != 0) return error.Futhark;
+    }
+
+    /// Sync if `auto_sync` is enabled
+    pub fn autoSync(self: *Context) Error!void {
+        if (self.auto_sync) try self.sync();
+    }
+
+    /// Free any cached memory
+    pub fn clearCaches(self: *Context) Error!void {
+        if (futhark_context_clear_caches(self.handle) != 0) return error.Futhark;
+    }
+
+    /// Pause profiling
+    pub fn pauseProfiling(self: *Context) void {
+        futhark_context_pause_profiling(self.handle);
+    }
+
+    /// Resume profiling
+    pub fn unpauseProfiling(self: *Context) void {
+        futhark_context_unpause_profiling(self.handle);
+    }
+
+    /// Get the last error message, the caller owns the returned memory
+    pub fn getError(self: *Context, allocator: std.mem.Allocator) std.mem.Allocator.Error!?[]u8 {
+        const s = futhark_context_get_error(self.handle) orelse return null;
+        defer free(s);
+        return try allocator.dupe(u8, std.mem.span(s));
+    }
+
+    /// Get the profiling report, the caller owns the returned memory
+    pub fn report(self: *Context, allocator: std.mem.Allocator) std.mem.Allocator.Error!?[]u8 {
+        const s = futhark_context_report(self.handle) orelse return null;
+        defer free(s);
+        return try allocator.dupe(u8, std.mem.span(s));
+    }
+};
+
+const futhark_f32_3d = opaque {};
+
+extern fn futhark_new_f32_3d(ctx: *futhark_context, data: [*]const f32, dim0: i64, dim1: i64, dim2: i64) ?*futhark_f32_3d;
+extern fn futhark_free_f32_3d(ctx: *futhark_context, arr: *futhark_f32_3d) c_int;
+extern fn futhark_values_f32_3d(ctx: *futhark_context, arr: *futhark_f32_3d, data: [*]f32) c_int;
+extern fn futhark_shape_f32_3d(ctx: *futhark_context, arr: *futhark_f32_3d) [*]const i64;
+
+/// Array type with 3 dimensions and f32 elements
+pub const ArrayF32D3 = struct {
+    ptr: *futhark_f32_3d,
+    shape: [3]i64,
+    ctx: *Context,
+
+    /// Create a new array with the shape `dims`, initialized with the values from `data`
+    pub fn init(ctx: *Context, data: []const f32, dims: [3]i64) Error!ArrayF32D3 {
+        var size: usize = 1;
+        for (dims) |d| size *= @intCast(d);
+        if (data.len != size) return error.InvalidShape;
+        const ptr = futhark_new_f32_3d(ctx.handle, data.ptr, dims[0], dims[1], dims[2]) orelse return error.NullPtr;
+        try ctx.autoSync();
+        return .{ .ptr = ptr, .shape = dims, .ctx = ctx };
+    }
+
+    fn fromPtr(ctx: *Context, ptr: *futhark_f32_3d) ArrayF32D3 {
+        const s = futhark_shape_f32_3d(ctx.handle, ptr);
+        var shape: [3]i64 = undefined;
+        for (&shape, 0..) |*d, i| d.* = s[i];
+        return .{ .ptr = ptr, .shape = shape, .ctx = ctx };
+    }
+
+    /// Free the array
+    pub fn deinit(self: *ArrayF32D3) void {
+        _ = futhark_free_f32_3d(self.ctx.handle, self.ptr);
+    }
+
+    /// Number of elements in the array
+    pub fn len(self: ArrayF32D3) usize {
+        var size: usize = 1;
+        for (self.shape) |d| size *= @intCast(d);
+        return size;
+    }
+
+    /// Copy the values into `out`, which must have the same number of elements as the array
+    pub fn values(self: ArrayF32D3, out: []f32) Error!void {
+        if (out.len != self.len()) return error.InvalidShape;
+        if (futhark_values_f32_3d(self.ctx.handle, self.ptr, out.ptr) != 0) return error.Futhark;
+        try self.ctx.autoSync();
+    }
+
+    /// Copy the values into a new slice, the caller owns the returned memory
+    pub fn toSlice(self: ArrayF32D3, allocator: std.mem.Allocator) (Error || std.mem.Allocator.Error)![]f32 {
+        const out = try allocator.alloc(f32, self.len());
+        errdefer allocator.free(out);
+        try self.values(out);
+        return out;
+    }
+};
+
+const futhark_f32_2d = opaque {};
+
+extern fn futhark_new_f32_2d(ctx: *futhark_context, data: [*]const f32, dim0: i64, dim1: i64) ?*futhark_f32_2d;
+extern fn futhark_free_f32_2d(ctx: *futhark_context, arr: *futhark_f32_2d) c_int;
+extern fn futhark_values_f32_2d(ctx: *futhark_context, arr: *futhark_f32_2d, data: [*]f32) c_int;
+extern fn futhark_shape_f32_2d(ctx: *futhark_context, arr: *futhark_f32_2d) [*]const i64;
+
+/// Array type with 2 dimensions and f32 elements
+pub const ArrayF32D2 = struct {
+    ptr: *futhark_f32_2d,
+    shape: [2]i64,
+    ctx: *Context,
+
+    /// Create a new array with the shape `dims`, initialized with the values from `data`
+    pub fn init(ctx: *Context, data: []const f32, dims: [2]i64) Error!ArrayF32D2 {
+        var size: usize = 1;
+        for (dims) |d| size *= @intCast(d);
+        if (data.len != size) return error.InvalidShape;
+        const ptr = futhark_new_f32_2d(ctx.handle, data.ptr, dims[0], dims[1]) orelse return error.NullPtr;
+        try ctx.autoSync();
+        return .{ .ptr = ptr, .shape = dims, .ctx = ctx };
+    }
+
+    fn fromPtr(ctx: *Context, ptr: *futhark_f32_2d) ArrayF32D2 {
+        const s = futhark_shape_f32_2d(ctx.handle, ptr);
+        var shape: [2]i64 = undefined;
+        for (&shape, 0..) |*d, i| d.* = s[i];
+        return .{ .ptr = ptr, .shape = shape, .ctx = ctx };
+    }
+
+    /// Free the array
+    pub fn deinit(self: *ArrayF32D2) void {
+        _ = futhark_free_f32_2d(self.ctx.handle, self.ptr);
+    }
+
+    /// Number of elements in the array
+    pub fn len(self: ArrayF32D2) usize {
+        var size: usize = 1;
+        for (self.shape) |d| size *= @intCast(d);
+        return size;
+    }
+
+    /// Copy the values into `out`, which must have the same number of elements as the array
+    pub fn values(self: ArrayF32D2, out: []f32) Error!void {
+        if (out.len != self.len()) return error.InvalidShape;
+        if (futhark_values_f32_2d(self.ctx.handle, self.ptr, out.ptr) != 0) return error.Futhark;
+        try self.ctx.autoSync();
+    }
+
+    /// Copy the values into a new slice, the caller owns the returned memory
+    pub fn toSlice(self: ArrayF32D2, allocator: std.mem.Allocator) (Error || std.mem.Allocator.Error)![]f32 {
+        const out = try allocator.alloc(f32, self.len());
+        errdefer allocator.free(out);
+        try self.values(out);
+        return out;
+    }
+};
+
+const futhark_bool_1d = opaque {};
+
+extern fn futhark_new_bool_1d(ctx: *futhark_context, data: [*]const bool, dim0: i64) ?*futhark_bool_1d;
+extern fn futhark_free_bool_1d(ctx: *futhark_context, arr: *futhark_bool_1d) c_int;
+extern fn futhark_values_bool_1d(ctx: *futhark_context, arr: *futhark_bool_1d, data: [*]bool) c_int;
+extern fn futhark_shape_bool_1d(ctx: *futhark_context, arr: *futhark_bool_1d) [*]const i64;
+
+/// Array type with 1 dimensions and bool elements
+pub const ArrayBoolD1 = struct {
+    ptr: *futhark_bool_1d,
+    shape: [1]i64,
+    ctx: *Context,
+
+    /// Create a new array with the shape `dims`, initialized with the values from `data`
+    pub fn init(ctx: *Context, data: []const bool, dims: [1]i64) Error!ArrayBoolD1 {
+        var size: usize = 1;
+        for (dims) |d| size *= @intCast(d);
+        if (data.len != size) return error.InvalidShape;
+        const ptr = futhark_new_bool_1d(ctx.handle, data.ptr, dims[0]) orelse return error.NullPtr;
+        try ctx.autoSync();
+        return .{ .ptr = ptr, .shape = dims, .ctx = ctx };
+    }
+
+    fn fromPtr(ctx: *Context, ptr: *futhark_bool_1d) ArrayBoolD1 {
+        const s = futhark_shape_bool_1d(ctx.handle, ptr);
+        var shape: [1]i64 = undefined;
+        for (&shape, 0..) |*d, i| d.* = s[i];
+        return .{ .ptr = ptr, .shape = shape, .ctx = ctx };
+    }
+
+    /// Free the array
+    pub fn deinit(self: *ArrayBoolD1) void {
+        _ = futhark_free_bool_1d(self.ctx.handle, self.ptr);
+    }
+
+    /// Number of elements in the array
+    pub fn len(self: ArrayBoolD1) usize {
+        var size: usize = 1;
+        for (self.shape) |d| size *= @intCast(d);
+        return size;
+    }
+
+    /// Copy the values into `out`, which must have the same number of elements as the array
+    pub fn values(self: ArrayBoolD1, out: []bool) Error!void {
+        if (out.len != self.len()) return error.InvalidShape;
+        if (futhark_values_bool_1d(self.ctx.handle, self.ptr, out.ptr) != 0) return error.Futhark;
+        try self.ctx.autoSync();
+    }
+
+    /// Copy the values into a new slice, the caller owns the returned memory
+    pub fn toSlice(self: ArrayBoolD1, allocator: std.mem.Allocator) (Error || std.mem.Allocator.Error)![]bool {
+        const out = try allocator.alloc(bool, self.len());
+        errdefer allocator.free(out);
+        try self.values(out);
+        return out;
+    }
+};
+
+const futhark_f16_1d = opaque {};
+
+extern fn futhark_new_f16_1d(ctx: *futhark_context, data: [*]const f16, dim0: i64) ?*futhark_f16_1d;
+extern fn futhark_free_f16_1d(ctx: *futhark_context, arr: *futhark_f16_1d) c_int;
+extern fn futhark_values_f16_1d(ctx: *futhark_context, arr: *futhark_f16_1d, data: [*]f16) c_int;
+extern fn futhark_shape_f16_1d(ctx: *futhark_context, arr: *futhark_f16_1d) [*]const i64;
+
+/// Array type with 1 dimensions and f16 elements
+pub const ArrayF16D1 = struct {
+    ptr: *futhark_f16_1d,
+    shape: [1]i64,
+    ctx: *Context,
+
+    /// Create a new array with the shape `dims`, initialized with the values from `data`
+    pub fn init(ctx: *Context, data: []const f16, dims: [1]i64) Error!ArrayF16D1 {
+        var size: usize = 1;
+        for (dims) |d| size *= @intCast(d);
+        if (data.len != size) return error.InvalidShape;
+        const ptr = futhark_new_f16_1d(ctx.handle, data.ptr, dims[0]) orelse return error.NullPtr;
+        try ctx.autoSync();
+        return .{ .ptr = ptr, .shape = dims, .ctx = ctx };
+    }
+
+    fn fromPtr(ctx: *Context, ptr: *futhark_f16_1d) ArrayF16D1 {
+        const s = futhark_shape_f16_1d(ctx.handle, ptr);
+        var shape: [1]i64 = undefined;
+        for (&shape, 0..) |*d, i| d.* = s[i];
+        return .{ .ptr = ptr, .shape = shape, .ctx = ctx };
+    }
+
+    /// Free the array
+    pub fn deinit(self: *ArrayF16D1) void {
+        _ = futhark_free_f16_1d(self.ctx.handle, self.ptr);
+    }
+
+    /// Number of elements in the array
+    pub fn len(self: ArrayF16D1) usize {
+        var size: usize = 1;
+        for (self.shape) |d| size *= @intCast(d);
+        return size;
+    }
+
+    /// Copy the values into `out`, which must have the same number of elements as the array
+    pub fn values(self: ArrayF16D1, out: []f16) Error!void {
+        if (out.len != self.len()) return error.InvalidShape;
+        if (futhark_values_f16_1d(self.ctx.handle, self.ptr, out.ptr) != 0) return error.Futhark;
+        try self.ctx.autoSync();
+    }
+
+    /// Copy the values into a new slice, the caller owns the returned memory
+    pub fn toSlice(self: ArrayF16D1, allocator: std.mem.Allocator) (Error || std.mem.Allocator.Error)![]f16 {
+        const out = try allocator.alloc(f16, self.len());
+        errdefer allocator.free(out);
+        try self.values(out);
+        return out;
+    }
+};
+
+const futhark_f32_1d = opaque {};
+
+extern fn futhark_new_f32_1d(ctx: *futhark_context, data: [*]const f32, dim0: i64) ?*futhark_f32_1d;
+extern fn futhark_free_f32_1d(ctx: *futhark_context, arr: *futhark_f32_1d) c_int;
+extern fn futhark_values_f32_1d(ctx: *futhark_context, arr: *futhark_f32_1d, data: [*]f32) c_int;
+extern fn futhark_shape_f32_1d(ctx: *futhark_context, arr: *futhark_f32_1d) [*]const i64;
+
+/// Array type with 1 dimensions and f32 elements
+pub const ArrayF32D1 = struct {
+    ptr: *futhark_f32_1d,
+    shape: [1]i64,
+    ctx: *Context,
+
+    /// Create a new array with the shape `dims`, initialized with the values from `data`
+    pub fn init(ctx: *Context, data: []const f32, dims: [1]i64) Error!ArrayF32D1 {
+        var size: usize = 1;
+        for (dims) |d| size *= @intCast(d);
+        if (data.len != size) return error.InvalidShape;
+        const ptr = futhark_new_f32_1d(ctx.handle, data.ptr, dims[0]) orelse return error.NullPtr;
+        try ctx.autoSync();
+        return .{ .ptr = ptr, .shape = dims, .ctx = ctx };
+    }
+
+    fn fromPtr(ctx: *Context, ptr: *futhark_f32_1d) ArrayF32D1 {
+        const s = futhark_shape_f32_1d(ctx.handle, ptr);
+        var shape: [1]i64 = undefined;
+        for (&shape, 0..) |*d, i| d.* = s[i];
+        return .{ .ptr = ptr, .shape = shape, .ctx = ctx };
+    }
+
+    /// Free the array
+    pub fn deinit(self: *ArrayF32D1) void {
+        _ = futhark_free_f32_1d(self.ctx.handle, self.ptr);
+    }
+
+    /// Number of elements in the array
+    pub fn len(self: ArrayF32D1) usize {
+        var size: usize = 1;
+        for (self.shape) |d| size *= @intCast(d);
+        return size;
+    }
+
+    /// Copy the values into `out`, which must have the same number of elements as the array
+    pub fn values(self: ArrayF32D1, out: []f32) Error!void {
+        if (out.len != self.len()) return error.InvalidShape;
+        if (futhark_values_f32_1d(self.ctx.handle, self.ptr, out.ptr) != 0) return error.Futhark;
+        try self.ctx.autoSync();
+    }
+
+    /// Copy the values into a new slice, the caller owns the returned memory
+    pub fn toSlice(self: ArrayF32D1, allocator: std.mem.Allocator) (Error || std.mem.Allocator.Error)![]f32 {
+        const out = try allocator.alloc(f32, self.len());
+        errdefer allocator.free(out);
+        try self.values(out);
+        return out;
+    }
+};
+
+const futhark_f64_1d = opaque {};
+
+extern fn futhark_new_f64_1d(ctx: *futhark_context, data: [*]const f64, dim0: i64) ?*futhark_f64_1d;
+extern fn futhark_free_f64_1d(ctx: *futhark_context, arr: *futhark_f64_1d) c_int;
+extern fn futhark_values_f64_1d(ctx: *futhark_context, arr: *futhark_f64_1d, data: [*]f64) c_int;
+extern fn futhark_shape_f64_1d(ctx: *futhark_context, arr: *futhark_f64_1d) [*]const i64;
+
+/// Array type with 1 dimensions and f64 elements
+pub const ArrayF64D1 = struct {
+    ptr: *futhark_f64_1d,
+    shape: [1]i64,
+    ctx: *Context,
+
+    /// Create a new array with the shape `dims`, initialized with the values from `data`
+    pub fn init(ctx: *Context, data: []const f64, dims: [1]i64) Error!ArrayF64D1 {
+        var size: usize = 1;
+        for (dims) |d| size *= @intCast(d);
+        if (data.len != size) return error.InvalidShape;
+        const ptr = futhark_new_f64_1d(ctx.handle, data.ptr, dims[0]) orelse return error.NullPtr;
+        try ctx.autoSync();
+        return .{ .ptr = ptr, .shape = dims, .ctx = ctx };
+    }
+
+    fn fromPtr(ctx: *Context, ptr: *futhark_f64_1d) ArrayF64D1 {
+        const s = futhark_shape_f64_1d(ctx.handle, ptr);
+        var shape: [1]i64 = undefined;
+        for (&shape, 0..) |*d, i| d.* = s[i];
+        return .{ .ptr = ptr, .shape = shape, .ctx = ctx };
+    }
+
+    /// Free the array
+    pub fn deinit(self: *ArrayF64D1) void {
+        _ = futhark_free_f64_1d(self.ctx.handle, self.ptr);
+    }
+
+    /// Number of elements in the array
+    pub fn len(self: ArrayF64D1) usize {
+        var size: usize = 1;
+        for (self.shape) |d| size *= @intCast(d);
+        return size;
+    }
+
+    /// Copy the values into `out`, which must have the same number of elements as the array
+    pub fn values(self: ArrayF64D1, out: []f64) Error!void {
+        if (out.len != self.len()) return error.InvalidShape;
+        if (futhark_values_f64_1d(self.ctx.handle, self.ptr, out.ptr) != 0) return error.Futhark;
+        try self.ctx.autoSync();
+    }
+
+    /// Copy the values into a new slice, the caller owns the returned memory
+    pub fn toSlice(self: ArrayF64D1, allocator: std.mem.Allocator) (Error || std.mem.Allocator.Error)![]f64 {
+        const out = try allocator.alloc(f64, self.len());
+        errdefer allocator.free(out);
+        try self.values(out);
+        return out;
+    }
+};
+
+const futhark_i16_1d = opaque {};
+
+extern fn futhark_new_i16_1d(ctx: *futhark_context, data: [*]const i16, dim0: i64) ?*futhark_i16_1d;
+extern fn futhark_free_i16_1d(ctx: *futhark_context, arr: *futhark_i16_1d) c_int;
+extern fn futhark_values_i16_1d(ctx: *futhark_context, arr: *futhark_i16_1d, data: [*]i16) c_int;
+extern fn futhark_shape_i16_1d(ctx: *futhark_context, arr: *futhark_i16_1d) [*]const i64;
+
+/// Array type with 1 dimensions and i16 elements
+pub const ArrayI16D1 = struct {
+    ptr: *futhark_i16_1d,
+    shape: [1]i64,
+    ctx: *Context,
+
+    /// Create a new array with the shape `dims`, initialized with the values from `data`
+    pub fn init(ctx: *Context, data: []const i16, dims: [1]i64) Error!ArrayI16D1 {
+        var size: usize = 1;
+        for (dims) |d| size *= @intCast(d);
+        if (data.len != size) return error.InvalidShape;
+        const ptr = futhark_new_i16_1d(ctx.handle, data.ptr, dims[0]) orelse return error.NullPtr;
+        try ctx.autoSync();
+        return .{ .ptr = ptr, .shape = dims, .ctx = ctx };
+    }
+
+    fn fromPtr(ctx: *Context, ptr: *futhark_i16_1d) ArrayI16D1 {
+        const s = futhark_shape_i16_1d(ctx.handle, ptr);
+        var shape: [1]i64 = undefined;
+        for (&shape, 0..) |*d, i| d.* = s[i];
+        return .{ .ptr = ptr, .shape = shape, .ctx = ctx };
+    }
+
+    /// Free the array
+    pub fn deinit(self: *ArrayI16D1) void {
+        _ = futhark_free_i16_1d(self.ctx.handle, self.ptr);
+    }
+
+    /// Number of elements in the array
+    pub fn len(self: ArrayI16D1) usize {
+        var size: usize = 1;
+        for (self.shape) |d| size *= @intCast(d);
+        return size;
+    }
+
+    /// Copy the values into `out`, which must have the same number of elements as the array
+    pub fn values(self: ArrayI16D1, out: []i16) Error!void {
+        if (out.len != self.len()) return error.InvalidShape;
+        if (futhark_values_i16_1d(self.ctx.handle, self.ptr, out.ptr) != 0) return error.Futhark;
+        try self.ctx.autoSync();
+    }
+
+    /// Copy the values into a new slice, the caller owns the returned memory
+    pub fn toSlice(self: ArrayI16D1, allocator: std.mem.Allocator) (Error || std.mem.Allocator.Error)![]i16 {
+        const out = try allocator.alloc(i16, self.len());
+        errdefer allocator.free(out);
+        try self.values(out);
+        return out;
+    }
+};
+
+const futhark_i32_1d = opaque {};
+
+extern fn futhark_new_i32_1d(ctx: *futhark_context, data: [*]const i32, dim0: i64) ?*futhark_i32_1d;
+extern fn futhark_free_i32_1d(ctx: *futhark_context, arr: *futhark_i32_1d) c_int;
+extern fn futhark_values_i32_1d(ctx: *futhark_context, arr: *futhark_i32_1d, data: [*]i32) c_int;
+extern fn futhark_shape_i32_1d(ctx: *futhark_context, arr: *futhark_i32_1d) [*]const i64;
+
+/// Array type with 1 dimensions and i32 elements
+pub const ArrayI32D1 = struct {
+    ptr: *futhark_i32_1d,
+    shape: [1]i64,
+    ctx: *Context,
+
+    /// Create a new array with the shape `dims`, initialized with the values from `data`
+    pub fn init(ctx: *Context, data: []const i32, dims: [1]i64) Error!ArrayI32D1 {
+        var size: usize = 1;
+        for (dims) |d| size *= @intCast(d);
+        if (data.len != size) return error.InvalidShape;
+        const ptr = futhark_new_i32_1d(ctx.handle, data.ptr, dims[0]) orelse return error.NullPtr;
+        try ctx.autoSync();
+        return .{ .ptr = ptr, .shape = dims, .ctx = ctx };
+    }
+
+    fn fromPtr(ctx: *Context, ptr: *futhark_i32_1d) ArrayI32D1 {
+        const s = futhark_shape_i32_1d(ctx.handle, ptr);
+        var shape: [1]i64 = undefined;
+        for (&shape, 0..) |*d, i| d.* = s[i];
+        return .{ .ptr = ptr, .shape = shape, .ctx = ctx };
+    }
+
+    /// Free the array
+    pub fn deinit(self: *ArrayI32D1) void {
+        _ = futhark_free_i32_1d(self.ctx.handle, self.ptr);
+    }
+
+    /// Number of elements in the array
+    pub fn len(self: ArrayI32D1) usize {
+        var size: usize = 1;
+        for (self.shape) |d| size *= @intCast(d);
+        return size;
+    }
+
+    /// Copy the values into `out`, which must have the same number of elements as the array
+    pub fn values(self: ArrayI32D1, out: []i32) Error!void {
+        if (out.len != self.len()) return error.InvalidShape;
+        if (futhark_values_i32_1d(self.ctx.handle, self.ptr, out.ptr) != 0) return error.Futhark;
+        try self.ctx.autoSync();
+    }
+
+    /// Copy the values into a new slice, the caller owns the returned memory
+    pub fn toSlice(self: ArrayI32D1, allocator: std.mem.Allocator) (Error || std.mem.Allocator.Error)![]i32 {
+        const out = try allocator.alloc(i32, self.len());
+        errdefer allocator.free(out);
+        try self.values(out);
+        return out;
+    }
+};
+
+const futhark_i64_1d = opaque {};
+
+extern fn futhark_new_i64_1d(ctx: *futhark_context, data: [*]const i64, dim0: i64) ?*futhark_i64_1d;
+extern fn futhark_free_i64_1d(ctx: *futhark_context, arr: *futhark_i64_1d) c_int;
+extern fn futhark_values_i64_1d(ctx: *futhark_context, arr: *futhark_i64_1d, data: [*]i64) c_int;
+extern fn futhark_shape_i64_1d(ctx: *futhark_context, arr: *futhark_i64_1d) [*]const i64;
+
+/// Array type with 1 dimensions and i64 elements
+pub const ArrayI64D1 = struct {
+    ptr: *futhark_i64_1d,
+    shape: [1]i64,
+    ctx: *Context,
+
+    /// Create a new array with the shape `dims`, initialized with the values from `data`
+    pub fn init(ctx: *Context, data: []const i64, dims: [1]i64) Error!ArrayI64D1 {
+        var size: usize = 1;
+        for (dims) |d| size *= @intCast(d);
+        if (data.len != size) return error.InvalidShape;
+        const ptr = futhark_new_i64_1d(ctx.handle, data.ptr, dims[0]) orelse return error.NullPtr;
+        try ctx.autoSync();
+        return .{ .ptr = ptr, .shape = dims, .ctx = ctx };
+    }
+
+    fn fromPtr(ctx: *Context, ptr: *futhark_i64_1d) ArrayI64D1 {
+        const s = futhark_shape_i64_1d(ctx.handle, ptr);
+        var shape: [1]i64 = undefined;
+        for (&shape, 0..) |*d, i| d.* = s[i];
+        return .{ .ptr = ptr, .shape = shape, .ctx = ctx };
+    }
+
+    /// Free the array
+    pub fn deinit(self: *ArrayI64D1) void {
+        _ = futhark_free_i64_1d(self.ctx.handle, self.ptr);
+    }
+
+    /// Number of elements in the array
+    pub fn len(self: ArrayI64D1) usize {
+        var size: usize = 1;
+        for (self.shape) |d| size *= @intCast(d);
+        return size;
+    }
+
+    /// Copy the values into `out`, which must have the same number of elements as the array
+    pub fn values(self: ArrayI64D1, out: []i64) Error!void {
+        if (out.len != self.len()) return error.InvalidShape;
+        if (futhark_values_i64_1d(self.ctx.handle, self.ptr, out.ptr) != 0) return error.Futhark;
+        try self.ctx.autoSync();
+    }
+
+    /// Copy the values into a new slice, the caller owns the returned memory
+    pub fn toSlice(self: ArrayI64D1, allocator: std.mem.Allocator) (Error || std.mem.Allocator.Error)![]i64 {
+        const out = try allocator.alloc(i64, self.len());
+        errdefer allocator.free(out);
+        try self.values(out);
+        return out;
+    }
+};
+
+const futhark_i8_1d = opaque {};
+
+extern fn futhark_new_i8_1d(ctx: *futhark_context, data: [*]const i8, dim0: i64) ?*futhark_i8_1d;
+extern fn futhark_free_i8_1d(ctx: *futhark_context, arr: *futhark_i8_1d) c_int;
+extern fn futhark_values_i8_1d(ctx: *futhark_context, arr: *futhark_i8_1d, data: [*]i8) c_int;
+extern fn futhark_shape_i8_1d(ctx: *futhark_context, arr: *futhark_i8_1d) [*]const i64;
+
+/// Array type with 1 dimensions and i8 elements
+pub const ArrayI8D1 = struct {
+    ptr: *futhark_i8_1d,
+    shape: [1]i64,
+    ctx: *Context,
+
+    /// Create a new array with the shape `dims`, initialized with the values from `data`
+    pub fn init(ctx: *Context, data: []const i8, dims: [1]i64) Error!ArrayI8D1 {
+        var size: usize = 1;
+        for (dims) |d| size *= @intCast(d);
+        if (data.len != size) return error.InvalidShape;
+        const ptr = futhark_new_i8_1d(ctx.handle, data.ptr, dims[0]) orelse return error.NullPtr;
+        try ctx.autoSync();
+        return .{ .ptr = ptr, .shape = dims, .ctx = ctx };
+    }
+
+    fn fromPtr(ctx: *Context, ptr: *futhark_i8_1d) ArrayI8D1 {
+        const s = futhark_shape_i8_1d(ctx.handle, ptr);
+        var shape: [1]i64 = undefined;
+        for (&shape, 0..) |*d, i| d.* = s[i];
+        return .{ .ptr = ptr, .shape = shape, .ctx = ctx };
+    }
+
+    /// Free the array
+    pub fn deinit(self: *ArrayI8D1) void {
+        _ = futhark_free_i8_1d(self.ctx.handle, self.ptr);
+    }
+
+    /// Number of elements in the array
+    pub fn len(self: ArrayI8D1) usize {
+        var size: usize = 1;
+        for (self.shape) |d| size *= @intCast(d);
+        return size;
+    }
+
+    /// Copy the values into `out`, which must have the same number of elements as the array
+    pub fn values(self: ArrayI8D1, out: []i8) Error!void {
+        if (out.len != self.len()) return error.InvalidShape;
+        if (futhark_values_i8_1d(self.ctx.handle, self.ptr, out.ptr) != 0) return error.Futhark;
+        try self.ctx.autoSync();
+    }
+
+    /// Copy the values into a new slice, the caller owns the returned memory
+    pub fn toSlice(self: ArrayI8D1, allocator: std.mem.Allocator) (Error || std.mem.Allocator.Error)![]i8 {
+        const out = try allocator.alloc(i8, self.len());
+        errdefer allocator.free(out);
+        try self.values(out);
+        return out;
+    }
+};
+
+const futhark_u16_1d = opaque {};
+
+extern fn futhark_new_u16_1d(ctx: *futhark_context, data: [*]const u16, dim0: i64) ?*futhark_u16_1d;
+extern fn futhark_free_u16_1d(ctx: *futhark_context, arr: *futhark_u16_1d) c_int;
+extern fn futhark_values_u16_1d(ctx: *futhark_context, arr: *futhark_u16_1d, data: [*]u16) c_int;
+extern fn futhark_shape_u16_1d(ctx: *futhark_context, arr: *futhark_u16_1d) [*]const i64;
+
+/// Array type with 1 dimensions and u16 elements
+pub const ArrayU16D1 = struct {
+    ptr: *futhark_u16_1d,
+    shape: [1]i64,
+    ctx: *Context,
+
+    /// Create a new array with the shape `dims`, initialized with the values from `data`
+    pub fn init(ctx: *Context, data: []const u16, dims: [1]i64) Error!ArrayU16D1 {
+        var size: usize = 1;
+        for (dims) |d| size *= @intCast(d);
+        if (data.len != size) return error.InvalidShape;
+        const ptr = futhark_new_u16_1d(ctx.handle, data.ptr, dims[0]) orelse return error.NullPtr;
+        try ctx.autoSync();
+        return .{ .ptr = ptr, .shape = dims, .ctx = ctx };
+    }
+
+    fn fromPtr(ctx: *Context, ptr: *futhark_u16_1d) ArrayU16D1 {
+        const s = futhark_shape_u16_1d(ctx.handle, ptr);
+        var shape: [1]i64 = undefined;
+        for (&shape, 0..) |*d, i| d.* = s[i];
+        return .{ .ptr = ptr, .shape = shape, .ctx = ctx };
+    }
+
+    /// Free the array
+    pub fn deinit(self: *ArrayU16D1) void {
+        _ = futhark_free_u16_1d(self.ctx.handle, self.ptr);
+    }
+
+    /// Number of elements in the array
+    pub fn len(self: ArrayU16D1) usize {
+        var size: usize = 1;
+        for (self.shape) |d| size *= @intCast(d);
+        return size;
+    }
+
+    /// Copy the values into `out`, which must have the same number of elements as the array
+    pub fn values(self: ArrayU16D1, out: []u16) Error!void {
+        if (out.len != self.len()) return error.InvalidShape;
+        if (futhark_values_u16_1d(self.ctx.handle, self.ptr, out.ptr) != 0) return error.Futhark;
+        try self.ctx.autoSync();
+    }
+
+    /// Copy the values into a new slice, the caller owns the returned memory
+    pub fn toSlice(self: ArrayU16D1, allocator: std.mem.Allocator) (Error || std.mem.Allocator.Error)![]u16 {
+        const out = try allocator.alloc(u16, self.len());
+        errdefer allocator.free(out);
+        try self.values(out);
+        return out;
+    }
+};
+
+const futhark_u32_1d = opaque {};
+
+extern fn futhark_new_u32_1d(ctx: *futhark_context, data: [*]const u32, dim0: i64) ?*futhark_u32_1d;
+extern fn futhark_free_u32_1d(ctx: *futhark_context, arr: *futhark_u32_1d) c_int;
+extern fn futhark_values_u32_1d(ctx: *futhark_context, arr: *futhark_u32_1d, data: [*]u32) c_int;
+extern fn futhark_shape_u32_1d(ctx: *futhark_context, arr: *futhark_u32_1d) [*]const i64;
+
+/// Array type with 1 dimensions and u32 elements
+pub const ArrayU32D1 = struct {
+    ptr: *futhark_u32_1d,
+    shape: [1]i64,
+    ctx: *Context,
+
+    /// Create a new array with the shape `dims`, initialized with the values from `data`
+    pub fn init(ctx: *Context, data: []const u32, dims: [1]i64) Error!ArrayU32D1 {
+        var size: usize = 1;
+        for (dims) |d| size *= @intCast(d);
+        if (data.len != size) return error.InvalidShape;
+        const ptr = futhark_new_u32_1d(ctx.handle, data.ptr, dims[0]) orelse return error.NullPtr;
+        try ctx.autoSync();
+        return .{ .ptr = ptr, .shape = dims, .ctx = ctx };
+    }
+
+    fn fromPtr(ctx: *Context, ptr: *futhark_u32_1d) ArrayU32D1 {
+        const s = futhark_shape_u32_1d(ctx.handle, ptr);
+        var shape: [1]i64 = undefined;
+        for (&shape, 0..) |*d, i| d.* = s[i];
+        return .{ .ptr = ptr, .shape = shape, .ctx = ctx };
+    }
+
+    /// Free the array
+    pub fn deinit(self: *ArrayU32D1) void {
+        _ = futhark_free_u32_1d(self.ctx.handle, self.ptr);
+    }
+
+    /// Number of elements in the array
+    pub fn len(self: ArrayU32D1) usize {
+        var size: usize = 1;
+        for (self.shape) |d| size *= @intCast(d);
+        return size;
+    }
+
+    /// Copy the values into `out`, which must have the same number of elements as the array
+    pub fn values(self: ArrayU32D1, out: []u32) Error!void {
+        if (out.len != self.len()) return error.InvalidShape;
+        if (futhark_values_u32_1d(self.ctx.handle, self.ptr, out.ptr) != 0) return error.Futhark;
+        try self.ctx.autoSync();
+    }
+
+    /// Copy the values into a new slice, the caller owns the returned memory
+    pub fn toSlice(self: ArrayU32D1, allocator: std.mem.Allocator) (Error || std.mem.Allocator.Error)![]u32 {
+        const out = try allocator.alloc(u32, self.len());
+        errdefer allocator.free(out);
+        try self.values(out);
+        return out;
+    }
+};
+
+const futhark_u64_1d = opaque {};
+
+extern fn futhark_new_u64_1d(ctx: *futhark_context, data: [*]const u64, dim0: i64) ?*futhark_u64_1d;
+extern fn futhark_free_u64_1d(ctx: *futhark_context, arr: *futhark_u64_1d) c_int;
+extern fn futhark_values_u64_1d(ctx: *futhark_context, arr: *futhark_u64_1d, data: [*]u64) c_int;
+extern fn futhark_shape_u64_1d(ctx: *futhark_context, arr: *futhark_u64_1d) [*]const i64;
+
+/// Array type with 1 dimensions and u64 elements
+pub const ArrayU64D1 = struct {
+    ptr: *futhark_u64_1d,
+    shape: [1]i64,
+    ctx: *Context,
+
+    /// Create a new array with the shape `dims`, initialized with the values from `data`
+    pub fn init(ctx: *Context, data: []const u64, dims: [1]i64) Error!ArrayU64D1 {
+        var size: usize = 1;
+        for (dims) |d| size *= @intCast(d);
+        if (data.len != size) return error.InvalidShape;
+        const ptr = futhark_new_u64_1d(ctx.handle, data.ptr, dims[0]) orelse return error.NullPtr;
+        try ctx.autoSync();
+        return .{ .ptr = ptr, .shape = dims, .ctx = ctx };
+    }
+
+    fn fromPtr(ctx: *Context, ptr: *futhark_u64_1d) ArrayU64D1 {
+        const s = futhark_shape_u64_1d(ctx.handle, ptr);
+        var shape: [1]i64 = undefined;
+        for (&shape, 0..) |*d, i| d.* = s[i];
+        return .{ .ptr = ptr, .shape = shape, .ctx = ctx };
+    }
+
+    /// Free the array
+    pub fn deinit(self: *ArrayU64D1) void {
+        _ = futhark_free_u64_1d(self.ctx.handle, self.ptr);
+    }
+
+    /// Number of elements in the array
+    pub fn len(self: ArrayU64D1) usize {
+        var size: usize = 1;
+        for (self.shape) |d| size *= @intCast(d);
+        return size;
+    }
+
+    /// Copy the values into `out`, which must have the same number of elements as the array
+    pub fn values(self: ArrayU64D1, out: []u64) Error!void {
+        if (out.len != self.len()) return error.InvalidShape;
+        if (futhark_values_u64_1d(self.ctx.handle, self.ptr, out.ptr) != 0) return error.Futhark;
+        try self.ctx.autoSync();
+    }
+
+    /// Copy the values into a new slice, the caller owns the returned memory
+    pub fn toSlice(self: ArrayU64D1, allocator: std.mem.Allocator) (Error || std.mem.Allocator.Error)![]u64 {
+        const out = try allocator.alloc(u64, self.len());
+        errdefer allocator.free(out);
+        try self.values(out);
+        return out;
+    }
+};
+
+const futhark_u8_1d = opaque {};
+
+extern fn futhark_new_u8_1d(ctx: *futhark_context, data: [*]const u8, dim0: i64) ?*futhark_u8_1d;
+extern fn futhark_free_u8_1d(ctx: *futhark_context, arr: *futhark_u8_1d) c_int;
+extern fn futhark_values_u8_1d(ctx: *futhark_context, arr: *futhark_u8_1d, data: [*]u8) c_int;
+extern fn futhark_shape_u8_1d(ctx: *futhark_context, arr: *futhark_u8_1d) [*]const i64;
+
+/// Array type with 1 dimensions and u8 elements
+pub const ArrayU8D1 = struct {
+    ptr: *futhark_u8_1d,
+    shape: [1]i64,
+    ctx: *Context,
+
+    /// Create a new array with the shape `dims`, initialized with the values from `data`
+    pub fn init(ctx: *Context, data: []const u8, dims: [1]i64) Error!ArrayU8D1 {
+        var size: usize = 1;
+        for (dims) |d| size *= @intCast(d);
+        if (data.len != size) return error.InvalidShape;
+        const ptr = futhark_new_u8_1d(ctx.handle, data.ptr, dims[0]) orelse return error.NullPtr;
+        try ctx.autoSync();
+        return .{ .ptr = ptr, .shape = dims, .ctx = ctx };
+    }
+
+    fn fromPtr(ctx: *Context, ptr: *futhark_u8_1d) ArrayU8D1 {
+        const s = futhark_shape_u8_1d(ctx.handle, ptr);
+        var shape: [1]i64 = undefined;
+        for (&shape, 0..) |*d, i| d.* = s[i];
+        return .{ .ptr = ptr, .shape = shape, .ctx = ctx };
+    }
+
+    /// Free the array
+    pub fn deinit(self: *ArrayU8D1) void {
+        _ = futhark_free_u8_1d(self.ctx.handle, self.ptr);
+    }
+
+    /// Number of elements in the array
+    pub fn len(self: ArrayU8D1) usize {
+        var size: usize = 1;
+        for (self.shape) |d| size *= @intCast(d);
+        return size;
+    }
+
+    /// Copy the values into `out`, which must have the same number of elements as the array
+    pub fn values(self: ArrayU8D1, out: []u8) Error!void {
+        if (out.len != self.len()) return error.InvalidShape;
+        if (futhark_values_u8_1d(self.ctx.handle, self.ptr, out.ptr) != 0) return error.Futhark;
+        try self.ctx.autoSync();
+    }
+
+    /// Copy the values into a new slice, the caller owns the returned memory
+    pub fn toSlice(self: ArrayU8D1, allocator: std.mem.Allocator) (Error || std.mem.Allocator.Error)![]u8 {
+        const out = try allocator.alloc(u8, self.len());
+        errdefer allocator.free(out);
+        try self.values(out);
+        return out;
+    }
+};
+
+extern fn futhark_entry_flatten(ctx: *futhark_context, out0: *?*futhark_f32_2d, input0: *const futhark_f32_3d) c_int;
+
+/// Entry point: flatten
+pub fn flatten(ctx: *Context, input0: *const ArrayF32D3) Error!ArrayF32D2 {
+    var out0: ?*futhark_f32_2d = null;
+    if (futhark_entry_flatten(ctx.handle, &out0, input0.ptr) != 0) return error.Futhark;
+    try ctx.autoSync();
+    return ArrayF32D2.fromPtr(ctx, out0 orelse return error.NullPtr);
+}
+
+extern fn futhark_entry_lengths(ctx: *futhark_context, out0: *i64, input0: *const futhark_i8_1d, input1: *const futhark_i16_1d, input2: *const futhark_i32_1d, input3: *const futhark_i64_1d, input4: *const futhark_u8_1d, input5: *const futhark_u16_1d, input6: *const futhark_u32_1d, input7: *const futhark_u64_1d, input8: *const futhark_f16_1d, input9: *const futhark_f32_1d, input10: *const futhark_f64_1d, input11: *const futhark_bool_1d) c_int;
+
+/// Entry point: lengths
+pub fn lengths(ctx: *Context, input0: *const ArrayI8D1, input1: *const ArrayI16D1, input2: *const ArrayI32D1, input3: *const ArrayI64D1, input4: *const ArrayU8D1, input5: *const ArrayU16D1, input6: *const ArrayU32D1, input7: *const ArrayU64D1, input8: *const ArrayF16D1, input9: *const ArrayF32D1, input10: *const ArrayF64D1, input11: *const ArrayBoolD1) Error!i64 {
+    var out0: i64 = undefined;
+    if (futhark_entry_lengths(ctx.handle, &out0, input0.ptr, input1.ptr, input2.ptr, input3.ptr, input4.ptr, input5.ptr, input6.ptr, input7.ptr, input8.ptr, input9.ptr, input10.ptr, input11.ptr) != 0) return error.Futhark;
+    try ctx.autoSync();
+    return out0;
+}
+
+extern fn futhark_entry_sum_rows(ctx: *futhark_context, out0: *?*futhark_f32_1d, out1: *f32, input0: *const futhark_f32_2d) c_int;
+
+/// Entry point: sum_rows
+pub fn sumRows(ctx: *Context, input0: *const ArrayF32D2) Error!struct { ArrayF32D1, f32 } {
+    var out0: ?*futhark_f32_1d = null;
+    var out1: f32 = undefined;
+    if (futhark_entry_sum_rows(ctx.handle, &out0, &out1, input0.ptr) != 0) return error.Futhark;
+    try ctx.autoSync();
+    return .{ ArrayF32D1.fromPtr(ctx, out0 orelse return error.NullPtr), out1 };
+}
+
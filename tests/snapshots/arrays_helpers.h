@@ -0,0 +1,631 @@
+// Generated by futhark-bindgen
+
+#ifndef ARRAYS_HELPERS_H
+#define ARRAYS_HELPERS_H
+
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "arrays.h"
+
+// Error codes for failures that aren't reported by Futhark
+#define ARRAYS_HELPERS_ERR_NULL_PTR (-1)
+#define ARRAYS_HELPERS_ERR_ALLOC (-2)
+
+// Error reported by the helper functions, `code` is zero on success
+typedef struct arrays_helpers_error {
+  int code;
+  // Error message, freed by `arrays_helpers_error_free`
+  char *message;
+} arrays_helpers_error;
+
+// Free the message stored in `err` and reset it
+static inline void arrays_helpers_error_free(arrays_helpers_error *err) {
+  if (err == NULL) {
+    return;
+  }
+  free(err->message);
+  err->message = NULL;
+  err->code = 0;
+}
+
+static inline char *arrays_helpers_copy_string(const char *s) {
+  size_t n = strlen(s) + 1;
+  char *copy = (char *)malloc(n);
+  if (copy != NULL) {
+    memcpy(copy, s, n);
+  }
+  return copy;
+}
+
+// Store `code` in `err` and return it, Futhark errors take the message from the context
+static inline int arrays_helpers_set_error(struct futhark_context *ctx, arrays_helpers_error *err, int code) {
+  if (err == NULL) {
+    return code;
+  }
+  free(err->message);
+  err->code = code;
+  switch (code) {
+  case ARRAYS_HELPERS_ERR_NULL_PTR:
+    err->message = arrays_helpers_copy_string("NULL pointer encountered");
+    break;
+  case ARRAYS_HELPERS_ERR_ALLOC:
+    err->message = arrays_helpers_copy_string("Unable to allocate host memory");
+    break;
+  default:
+    err->message = futhark_context_get_error(ctx);
+    break;
+  }
+  return code;
+}
+
+// Copy a `[][][]f32` array into a new host buffer, which must be freed using `free`
+static inline int arrays_helpers_copy_f32_3d(struct futhark_context *ctx, arrays_helpers_error *err,
+    struct futhark_f32_3d *arr, float **data, int64_t shape[3]) {
+  const int64_t *dims = futhark_shape_f32_3d(ctx, arr);
+  int64_t size = 1;
+  for (int i = 0; i < 3; i++) {
+    shape[i] = dims[i];
+    size *= dims[i];
+  }
+  // Allocate at least one element, `malloc(0)` may return NULL
+  *data = (float *)malloc((size > 0 ? (size_t)size : 1) * sizeof(float));
+  if (*data == NULL) {
+    return arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_ALLOC);
+  }
+  int rc = futhark_values_f32_3d(ctx, arr, *data);
+  if (rc == 0) {
+    rc = futhark_context_sync(ctx);
+  }
+  if (rc != 0) {
+    free(*data);
+    *data = NULL;
+    return arrays_helpers_set_error(ctx, err, rc);
+  }
+  return 0;
+}
+
+// Copy a `[][]f32` array into a new host buffer, which must be freed using `free`
+static inline int arrays_helpers_copy_f32_2d(struct futhark_context *ctx, arrays_helpers_error *err,
+    struct futhark_f32_2d *arr, float **data, int64_t shape[2]) {
+  const int64_t *dims = futhark_shape_f32_2d(ctx, arr);
+  int64_t size = 1;
+  for (int i = 0; i < 2; i++) {
+    shape[i] = dims[i];
+    size *= dims[i];
+  }
+  // Allocate at least one element, `malloc(0)` may return NULL
+  *data = (float *)malloc((size > 0 ? (size_t)size : 1) * sizeof(float));
+  if (*data == NULL) {
+    return arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_ALLOC);
+  }
+  int rc = futhark_values_f32_2d(ctx, arr, *data);
+  if (rc == 0) {
+    rc = futhark_context_sync(ctx);
+  }
+  if (rc != 0) {
+    free(*data);
+    *data = NULL;
+    return arrays_helpers_set_error(ctx, err, rc);
+  }
+  return 0;
+}
+
+// Copy a `[]bool` array into a new host buffer, which must be freed using `free`
+static inline int arrays_helpers_copy_bool_1d(struct futhark_context *ctx, arrays_helpers_error *err,
+    struct futhark_bool_1d *arr, bool **data, int64_t shape[1]) {
+  const int64_t *dims = futhark_shape_bool_1d(ctx, arr);
+  int64_t size = 1;
+  for (int i = 0; i < 1; i++) {
+    shape[i] = dims[i];
+    size *= dims[i];
+  }
+  // Allocate at least one element, `malloc(0)` may return NULL
+  *data = (bool *)malloc((size > 0 ? (size_t)size : 1) * sizeof(bool));
+  if (*data == NULL) {
+    return arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_ALLOC);
+  }
+  int rc = futhark_values_bool_1d(ctx, arr, *data);
+  if (rc == 0) {
+    rc = futhark_context_sync(ctx);
+  }
+  if (rc != 0) {
+    free(*data);
+    *data = NULL;
+    return arrays_helpers_set_error(ctx, err, rc);
+  }
+  return 0;
+}
+
+// Copy a `[]f16` array into a new host buffer, which must be freed using `free`
+static inline int arrays_helpers_copy_f16_1d(struct futhark_context *ctx, arrays_helpers_error *err,
+    struct futhark_f16_1d *arr, uint16_t **data, int64_t shape[1]) {
+  const int64_t *dims = futhark_shape_f16_1d(ctx, arr);
+  int64_t size = 1;
+  for (int i = 0; i < 1; i++) {
+    shape[i] = dims[i];
+    size *= dims[i];
+  }
+  // Allocate at least one element, `malloc(0)` may return NULL
+  *data = (uint16_t *)malloc((size > 0 ? (size_t)size : 1) * sizeof(uint16_t));
+  if (*data == NULL) {
+    return arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_ALLOC);
+  }
+  int rc = futhark_values_f16_1d(ctx, arr, *data);
+  if (rc == 0) {
+    rc = futhark_context_sync(ctx);
+  }
+  if (rc != 0) {
+    free(*data);
+    *data = NULL;
+    return arrays_helpers_set_error(ctx, err, rc);
+  }
+  return 0;
+}
+
+// Copy a `[]f32` array into a new host buffer, which must be freed using `free`
+static inline int arrays_helpers_copy_f32_1d(struct futhark_context *ctx, arrays_helpers_error *err,
+    struct futhark_f32_1d *arr, float **data, int64_t shape[1]) {
+  const int64_t *dims = futhark_shape_f32_1d(ctx, arr);
+  int64_t size = 1;
+  for (int i = 0; i < 1; i++) {
+    shape[i] = dims[i];
+    size *= dims[i];
+  }
+  // Allocate at least one element, `malloc(0)` may return NULL
+  *data = (float *)malloc((size > 0 ? (size_t)size : 1) * sizeof(float));
+  if (*data == NULL) {
+    return arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_ALLOC);
+  }
+  int rc = futhark_values_f32_1d(ctx, arr, *data);
+  if (rc == 0) {
+    rc = futhark_context_sync(ctx);
+  }
+  if (rc != 0) {
+    free(*data);
+    *data = NULL;
+    return arrays_helpers_set_error(ctx, err, rc);
+  }
+  return 0;
+}
+
+// Copy a `[]f64` array into a new host buffer, which must be freed using `free`
+static inline int arrays_helpers_copy_f64_1d(struct futhark_context *ctx, arrays_helpers_error *err,
+    struct futhark_f64_1d *arr, double **data, int64_t shape[1]) {
+  const int64_t *dims = futhark_shape_f64_1d(ctx, arr);
+  int64_t size = 1;
+  for (int i = 0; i < 1; i++) {
+    shape[i] = dims[i];
+    size *= dims[i];
+  }
+  // Allocate at least one element, `malloc(0)` may return NULL
+  *data = (double *)malloc((size > 0 ? (size_t)size : 1) * sizeof(double));
+  if (*data == NULL) {
+    return arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_ALLOC);
+  }
+  int rc = futhark_values_f64_1d(ctx, arr, *data);
+  if (rc == 0) {
+    rc = futhark_context_sync(ctx);
+  }
+  if (rc != 0) {
+    free(*data);
+    *data = NULL;
+    return arrays_helpers_set_error(ctx, err, rc);
+  }
+  return 0;
+}
+
+// Copy a `[]i16` array into a new host buffer, which must be freed using `free`
+static inline int arrays_helpers_copy_i16_1d(struct futhark_context *ctx, arrays_helpers_error *err,
+    struct futhark_i16_1d *arr, int16_t **data, int64_t shape[1]) {
+  const int64_t *dims = futhark_shape_i16_1d(ctx, arr);
+  int64_t size = 1;
+  for (int i = 0; i < 1; i++) {
+    shape[i] = dims[i];
+    size *= dims[i];
+  }
+  // Allocate at least one element, `malloc(0)` may return NULL
+  *data = (int16_t *)malloc((size > 0 ? (size_t)size : 1) * sizeof(int16_t));
+  if (*data == NULL) {
+    return arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_ALLOC);
+  }
+  int rc = futhark_values_i16_1d(ctx, arr, *data);
+  if (rc == 0) {
+    rc = futhark_context_sync(ctx);
+  }
+  if (rc != 0) {
+    free(*data);
+    *data = NULL;
+    return arrays_helpers_set_error(ctx, err, rc);
+  }
+  return 0;
+}
+
+// Copy a `[]i32` array into a new host buffer, which must be freed using `free`
+static inline int arrays_helpers_copy_i32_1d(struct futhark_context *ctx, arrays_helpers_error *err,
+    struct futhark_i32_1d *arr, int32_t **data, int64_t shape[1]) {
+  const int64_t *dims = futhark_shape_i32_1d(ctx, arr);
+  int64_t size = 1;
+  for (int i = 0; i < 1; i++) {
+    shape[i] = dims[i];
+    size *= dims[i];
+  }
+  // Allocate at least one element, `malloc(0)` may return NULL
+  *data = (int32_t *)malloc((size > 0 ? (size_t)size : 1) * sizeof(int32_t));
+  if (*data == NULL) {
+    return arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_ALLOC);
+  }
+  int rc = futhark_values_i32_1d(ctx, arr, *data);
+  if (rc == 0) {
+    rc = futhark_context_sync(ctx);
+  }
+  if (rc != 0) {
+    free(*data);
+    *data = NULL;
+    return arrays_helpers_set_error(ctx, err, rc);
+  }
+  return 0;
+}
+
+// Copy a `[]i64` array into a new host buffer, which must be freed using `free`
+static inline int arrays_helpers_copy_i64_1d(struct futhark_context *ctx, arrays_helpers_error *err,
+    struct futhark_i64_1d *arr, int64_t **data, int64_t shape[1]) {
+  const int64_t *dims = futhark_shape_i64_1d(ctx, arr);
+  int64_t size = 1;
+  for (int i = 0; i < 1; i++) {
+    shape[i] = dims[i];
+    size *= dims[i];
+  }
+  // Allocate at least one element, `malloc(0)` may return NULL
+  *data = (int64_t *)malloc((size > 0 ? (size_t)size : 1) * sizeof(int64_t));
+  if (*data == NULL) {
+    return arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_ALLOC);
+  }
+  int rc = futhark_values_i64_1d(ctx, arr, *data);
+  if (rc == 0) {
+    rc = futhark_context_sync(ctx);
+  }
+  if (rc != 0) {
+    free(*data);
+    *data = NULL;
+    return arrays_helpers_set_error(ctx, err, rc);
+  }
+  return 0;
+}
+
+// Copy a `[]i8` array into a new host buffer, which must be freed using `free`
+static inline int arrays_helpers_copy_i8_1d(struct futhark_context *ctx, arrays_helpers_error *err,
+    struct futhark_i8_1d *arr, int8_t **data, int64_t shape[1]) {
+  const int64_t *dims = futhark_shape_i8_1d(ctx, arr);
+  int64_t size = 1;
+  for (int i = 0; i < 1; i++) {
+    shape[i] = dims[i];
+    size *= dims[i];
+  }
+  // Allocate at least one element, `malloc(0)` may return NULL
+  *data = (int8_t *)malloc((size > 0 ? (size_t)size : 1) * sizeof(int8_t));
+  if (*data == NULL) {
+    return arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_ALLOC);
+  }
+  int rc = futhark_values_i8_1d(ctx, arr, *data);
+  if (rc == 0) {
+    rc = futhark_context_sync(ctx);
+  }
+  if (rc != 0) {
+    free(*data);
+    *data = NULL;
+    return arrays_helpers_set_error(ctx, err, rc);
+  }
+  return 0;
+}
+
+// Copy a `[]u16` array into a new host buffer, which must be freed using `free`
+static inline int arrays_helpers_copy_u16_1d(struct futhark_context *ctx, arrays_helpers_error *err,
+    struct futhark_u16_1d *arr, uint16_t **data, int64_t shape[1]) {
+  const int64_t *dims = futhark_shape_u16_1d(ctx, arr);
+  int64_t size = 1;
+  for (int i = 0; i < 1; i++) {
+    shape[i] = dims[i];
+    size *= dims[i];
+  }
+  // Allocate at least one element, `malloc(0)` may return NULL
+  *data = (uint16_t *)malloc((size > 0 ? (size_t)size : 1) * sizeof(uint16_t));
+  if (*data == NULL) {
+    return arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_ALLOC);
+  }
+  int rc = futhark_values_u16_1d(ctx, arr, *data);
+  if (rc == 0) {
+    rc = futhark_context_sync(ctx);
+  }
+  if (rc != 0) {
+    free(*data);
+    *data = NULL;
+    return arrays_helpers_set_error(ctx, err, rc);
+  }
+  return 0;
+}
+
+// Copy a `[]u32` array into a new host buffer, which must be freed using `free`
+static inline int arrays_helpers_copy_u32_1d(struct futhark_context *ctx, arrays_helpers_error *err,
+    struct futhark_u32_1d *arr, uint32_t **data, int64_t shape[1]) {
+  const int64_t *dims = futhark_shape_u32_1d(ctx, arr);
+  int64_t size = 1;
+  for (int i = 0; i < 1; i++) {
+    shape[i] = dims[i];
+    size *= dims[i];
+  }
+  // Allocate at least one element, `malloc(0)` may return NULL
+  *data = (uint32_t *)malloc((size > 0 ? (size_t)size : 1) * sizeof(uint32_t));
+  if (*data == NULL) {
+    return arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_ALLOC);
+  }
+  int rc = futhark_values_u32_1d(ctx, arr, *data);
+  if (rc == 0) {
+    rc = futhark_context_sync(ctx);
+  }
+  if (rc != 0) {
+    free(*data);
+    *data = NULL;
+    return arrays_helpers_set_error(ctx, err, rc);
+  }
+  return 0;
+}
+
+// Copy a `[]u64` array into a new host buffer, which must be freed using `free`
+static inline int arrays_helpers_copy_u64_1d(struct futhark_context *ctx, arrays_helpers_error *err,
+    struct futhark_u64_1d *arr, uint64_t **data, int64_t shape[1]) {
+  const int64_t *dims = futhark_shape_u64_1d(ctx, arr);
+  int64_t size = 1;
+  for (int i = 0; i < 1; i++) {
+    shape[i] = dims[i];
+    size *= dims[i];
+  }
+  // Allocate at least one element, `malloc(0)` may return NULL
+  *data = (uint64_t *)malloc((size > 0 ? (size_t)size : 1) * sizeof(uint64_t));
+  if (*data == NULL) {
+    return arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_ALLOC);
+  }
+  int rc = futhark_values_u64_1d(ctx, arr, *data);
+  if (rc == 0) {
+    rc = futhark_context_sync(ctx);
+  }
+  if (rc != 0) {
+    free(*data);
+    *data = NULL;
+    return arrays_helpers_set_error(ctx, err, rc);
+  }
+  return 0;
+}
+
+// Copy a `[]u8` array into a new host buffer, which must be freed using `free`
+static inline int arrays_helpers_copy_u8_1d(struct futhark_context *ctx, arrays_helpers_error *err,
+    struct futhark_u8_1d *arr, uint8_t **data, int64_t shape[1]) {
+  const int64_t *dims = futhark_shape_u8_1d(ctx, arr);
+  int64_t size = 1;
+  for (int i = 0; i < 1; i++) {
+    shape[i] = dims[i];
+    size *= dims[i];
+  }
+  // Allocate at least one element, `malloc(0)` may return NULL
+  *data = (uint8_t *)malloc((size > 0 ? (size_t)size : 1) * sizeof(uint8_t));
+  if (*data == NULL) {
+    return arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_ALLOC);
+  }
+  int rc = futhark_values_u8_1d(ctx, arr, *data);
+  if (rc == 0) {
+    rc = futhark_context_sync(ctx);
+  }
+  if (rc != 0) {
+    free(*data);
+    *data = NULL;
+    return arrays_helpers_set_error(ctx, err, rc);
+  }
+  return 0;
+}
+
+// Run the `flatten` entry point and wait for it to finish, array outputs are copied into new
+// host buffers which must be freed using `free`. On failure the outputs are set to NULL and
+// the error is stored in `err`
+static inline int arrays_helpers_flatten(struct futhark_context *ctx, arrays_helpers_error *err, float **out0, int64_t out0_shape[2], const float *in0, const int64_t in0_shape[3]) {
+  int rc = 0;
+  struct futhark_f32_2d *out0_arr = NULL;
+  *out0 = NULL;
+  struct futhark_f32_3d *in0_arr = NULL;
+  in0_arr = futhark_new_f32_3d(ctx, in0, in0_shape[0], in0_shape[1], in0_shape[2]);
+  if (in0_arr == NULL) {
+    rc = arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_NULL_PTR);
+    goto done;
+  }
+  rc = futhark_entry_flatten(ctx, &out0_arr, in0_arr);
+  if (rc == 0) {
+    rc = futhark_context_sync(ctx);
+  }
+  if (rc != 0) {
+    rc = arrays_helpers_set_error(ctx, err, rc);
+    goto done;
+  }
+  rc = arrays_helpers_copy_f32_2d(ctx, err, out0_arr, out0, out0_shape);
+  if (rc != 0) {
+    goto done;
+  }
+done:
+  if (out0_arr != NULL) {
+    futhark_free_f32_2d(ctx, out0_arr);
+  }
+  if (in0_arr != NULL) {
+    futhark_free_f32_3d(ctx, in0_arr);
+  }
+  if (rc != 0) {
+    free(*out0);
+    *out0 = NULL;
+  }
+  return rc;
+}
+
+// Run the `lengths` entry point and wait for it to finish, array outputs are copied into new
+// host buffers which must be freed using `free`. On failure the outputs are set to NULL and
+// the error is stored in `err`
+static inline int arrays_helpers_lengths(struct futhark_context *ctx, arrays_helpers_error *err, int64_t *out0, const int8_t *in0, const int64_t in0_shape[1], const int16_t *in1, const int64_t in1_shape[1], const int32_t *in2, const int64_t in2_shape[1], const int64_t *in3, const int64_t in3_shape[1], const uint8_t *in4, const int64_t in4_shape[1], const uint16_t *in5, const int64_t in5_shape[1], const uint32_t *in6, const int64_t in6_shape[1], const uint64_t *in7, const int64_t in7_shape[1], const uint16_t *in8, const int64_t in8_shape[1], const float *in9, const int64_t in9_shape[1], const double *in10, const int64_t in10_shape[1], const bool *in11, const int64_t in11_shape[1]) {
+  int rc = 0;
+  struct futhark_i8_1d *in0_arr = NULL;
+  struct futhark_i16_1d *in1_arr = NULL;
+  struct futhark_i32_1d *in2_arr = NULL;
+  struct futhark_i64_1d *in3_arr = NULL;
+  struct futhark_u8_1d *in4_arr = NULL;
+  struct futhark_u16_1d *in5_arr = NULL;
+  struct futhark_u32_1d *in6_arr = NULL;
+  struct futhark_u64_1d *in7_arr = NULL;
+  struct futhark_f16_1d *in8_arr = NULL;
+  struct futhark_f32_1d *in9_arr = NULL;
+  struct futhark_f64_1d *in10_arr = NULL;
+  struct futhark_bool_1d *in11_arr = NULL;
+  in0_arr = futhark_new_i8_1d(ctx, in0, in0_shape[0]);
+  if (in0_arr == NULL) {
+    rc = arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_NULL_PTR);
+    goto done;
+  }
+  in1_arr = futhark_new_i16_1d(ctx, in1, in1_shape[0]);
+  if (in1_arr == NULL) {
+    rc = arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_NULL_PTR);
+    goto done;
+  }
+  in2_arr = futhark_new_i32_1d(ctx, in2, in2_shape[0]);
+  if (in2_arr == NULL) {
+    rc = arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_NULL_PTR);
+    goto done;
+  }
+  in3_arr = futhark_new_i64_1d(ctx, in3, in3_shape[0]);
+  if (in3_arr == NULL) {
+    rc = arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_NULL_PTR);
+    goto done;
+  }
+  in4_arr = futhark_new_u8_1d(ctx, in4, in4_shape[0]);
+  if (in4_arr == NULL) {
+    rc = arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_NULL_PTR);
+    goto done;
+  }
+  in5_arr = futhark_new_u16_1d(ctx, in5, in5_shape[0]);
+  if (in5_arr == NULL) {
+    rc = arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_NULL_PTR);
+    goto done;
+  }
+  in6_arr = futhark_new_u32_1d(ctx, in6, in6_shape[0]);
+  if (in6_arr == NULL) {
+    rc = arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_NULL_PTR);
+    goto done;
+  }
+  in7_arr = futhark_new_u64_1d(ctx, in7, in7_shape[0]);
+  if (in7_arr == NULL) {
+    rc = arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_NULL_PTR);
+    goto done;
+  }
+  in8_arr = futhark_new_f16_1d(ctx, in8, in8_shape[0]);
+  if (in8_arr == NULL) {
+    rc = arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_NULL_PTR);
+    goto done;
+  }
+  in9_arr = futhark_new_f32_1d(ctx, in9, in9_shape[0]);
+  if (in9_arr == NULL) {
+    rc = arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_NULL_PTR);
+    goto done;
+  }
+  in10_arr = futhark_new_f64_1d(ctx, in10, in10_shape[0]);
+  if (in10_arr == NULL) {
+    rc = arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_NULL_PTR);
+    goto done;
+  }
+  in11_arr = futhark_new_bool_1d(ctx, in11, in11_shape[0]);
+  if (in11_arr == NULL) {
+    rc = arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_NULL_PTR);
+    goto done;
+  }
+  rc = futhark_entry_lengths(ctx, out0, in0_arr, in1_arr, in2_arr, in3_arr, in4_arr, in5_arr, in6_arr, in7_arr, in8_arr, in9_arr, in10_arr, in11_arr);
+  if (rc == 0) {
+    rc = futhark_context_sync(ctx);
+  }
+  if (rc != 0) {
+    rc = arrays_helpers_set_error(ctx, err, rc);
+    goto done;
+  }
+done:
+  if (in0_arr != NULL) {
+    futhark_free_i8_1d(ctx, in0_arr);
+  }
+  if (in1_arr != NULL) {
+    futhark_free_i16_1d(ctx, in1_arr);
+  }
+  if (in2_arr != NULL) {
+    futhark_free_i32_1d(ctx, in2_arr);
+  }
+  if (in3_arr != NULL) {
+    futhark_free_i64_1d(ctx, in3_arr);
+  }
+  if (in4_arr != NULL) {
+    futhark_free_u8_1d(ctx, in4_arr);
+  }
+  if (in5_arr != NULL) {
+    futhark_free_u16_1d(ctx, in5_arr);
+  }
+  if (in6_arr != NULL) {
+    futhark_free_u32_1d(ctx, in6_arr);
+  }
+  if (in7_arr != NULL) {
+    futhark_free_u64_1d(ctx, in7_arr);
+  }
+  if (in8_arr != NULL) {
+    futhark_free_f16_1d(ctx, in8_arr);
+  }
+  if (in9_arr != NULL) {
+    futhark_free_f32_1d(ctx, in9_arr);
+  }
+  if (in10_arr != NULL) {
+    futhark_free_f64_1d(ctx, in10_arr);
+  }
+  if (in11_arr != NULL) {
+    futhark_free_bool_1d(ctx, in11_arr);
+  }
+  return rc;
+}
+
+// Run the `sum_rows` entry point and wait for it to finish, array outputs are copied into new
+// host buffers which must be freed using `free`. On failure the outputs are set to NULL and
+// the error is stored in `err`
+static inline int arrays_helpers_sum_rows(struct futhark_context *ctx, arrays_helpers_error *err, float **out0, int64_t out0_shape[1], float *out1, const float *in0, const int64_t in0_shape[2]) {
+  int rc = 0;
+  struct futhark_f32_1d *out0_arr = NULL;
+  *out0 = NULL;
+  struct futhark_f32_2d *in0_arr = NULL;
+  in0_arr = futhark_new_f32_2d(ctx, in0, in0_shape[0], in0_shape[1]);
+  if (in0_arr == NULL) {
+    rc = arrays_helpers_set_error(ctx, err, ARRAYS_HELPERS_ERR_NULL_PTR);
+    goto done;
+  }
+  rc = futhark_entry_sum_rows(ctx, &out0_arr, out1, in0_arr);
+  if (rc == 0) {
+    rc = futhark_context_sync(ctx);
+  }
+  if (rc != 0) {
+    rc = arrays_helpers_set_error(ctx, err, rc);
+    goto done;
+  }
+  rc = arrays_helpers_copy_f32_1d(ctx, err, out0_arr, out0, out0_shape);
+  if (rc != 0) {
+    goto done;
+  }
+done:
+  if (out0_arr != NULL) {
+    futhark_free_f32_1d(ctx, out0_arr);
+  }
+  if (in0_arr != NULL) {
+    futhark_free_f32_2d(ctx, in0_arr);
+  }
+  if (rc != 0) {
+    free(*out0);
+    *out0 = NULL;
+  }
+  return rc;
+}
+
+#endif // ARRAYS_HELPERS_H
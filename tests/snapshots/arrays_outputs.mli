@@ -0,0 +1,628 @@
+(* Generated by futhark-bindgen *)
+
+open! Signed
+open! Unsigned
+
+type error = 
+  | InvalidShape of int * int 
+  | NullPtr 
+  | Code of int
+  | UseAfterFree of [`context | `array | `opaque]
+  | IndexOutOfBounds
+  | Unsupported of string
+  | InvalidTuning of string
+
+exception Error of error
+
+module Context: sig
+  type t
+  (** Futhark context *)
+
+  val v: ?debug:bool -> ?log:bool -> ?profile:bool -> ?cache_file:string -> ?auto_sync:bool -> ?tuning_params:(string * int) list -> ?tuning_file:string -> ?num_threads:int -> unit -> t
+  (** Create a new context, [tuning_params] are applied after the parameters loaded from [tuning_file] *)
+  
+  val sync: t -> unit
+  (** Sync the context, if auto_sync is enabled this is not needed *)
+  
+  val free: t -> unit
+  (** Free the context *)
+  
+  val clear_caches: t -> unit
+  (** Clear Futhark caches *)
+
+  val get_error: t -> string option
+  (** Get last error message or None *)
+
+  val report: t -> string option
+  val pause_profiling: t -> unit
+  val unpause_profiling: t -> unit
+end
+module Array_f32_3d: sig
+  type t
+  (** Futhark array *)
+
+  type kind = (float, Bigarray.float32_elt) Bigarray.kind
+  (** The Bigarray kind that matches the correct element type for this array *)
+
+  val kind: kind
+
+  val shape: t -> int array
+  (** Array shape *)
+
+  val v: Context.t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Genarray.t -> t
+  (** Initialize an array with the data from the provided bigarray *)
+
+  val values: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Genarray.t -> unit
+  (** Load the values into the provided bigarray *)
+
+  val values_array1: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Array1.t -> unit
+  (** Similar to [values] but takes an [Array1] instead of [Genarray] *)
+
+  val get: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Genarray.t
+  (** Get a new bigarray with the values loaded *)
+
+  val get_array1: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Array1.t
+  (** Similar to [get] but returns an [Array1] *)
+
+  val index: t -> int array -> float
+  (** Get a single element of the array without copying the whole array, raises
+      [Error (Unsupported "index")] if the manifest doesn't include an [index] function *)
+
+  val of_array: Context.t -> int array -> (float) array -> t
+  (** Create [t] from an array of values *)
+
+  val of_array1: Context.t -> int array ->  (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Array1.t-> t
+  (** Create [t] from an [Array1] instead of [Genarray] *)
+
+  val free: t -> unit
+  (** Free the array *)
+end
+
+module Array_f32_2d: sig
+  type t
+  (** Futhark array *)
+
+  type kind = (float, Bigarray.float32_elt) Bigarray.kind
+  (** The Bigarray kind that matches the correct element type for this array *)
+
+  val kind: kind
+
+  val shape: t -> int array
+  (** Array shape *)
+
+  val v: Context.t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Genarray.t -> t
+  (** Initialize an array with the data from the provided bigarray *)
+
+  val values: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Genarray.t -> unit
+  (** Load the values into the provided bigarray *)
+
+  val values_array1: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Array1.t -> unit
+  (** Similar to [values] but takes an [Array1] instead of [Genarray] *)
+
+  val get: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Genarray.t
+  (** Get a new bigarray with the values loaded *)
+
+  val get_array1: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Array1.t
+  (** Similar to [get] but returns an [Array1] *)
+
+  val index: t -> int array -> float
+  (** Get a single element of the array without copying the whole array, raises
+      [Error (Unsupported "index")] if the manifest doesn't include an [index] function *)
+
+  val of_array: Context.t -> int array -> (float) array -> t
+  (** Create [t] from an array of values *)
+
+  val of_array1: Context.t -> int array ->  (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Array1.t-> t
+  (** Create [t] from an [Array1] instead of [Genarray] *)
+
+  val free: t -> unit
+  (** Free the array *)
+end
+
+module Array_bool_1d: sig
+  type t
+  (** Futhark array *)
+
+  type kind = (int, Bigarray.int8_unsigned_elt) Bigarray.kind
+  (** The Bigarray kind that matches the correct element type for this array *)
+
+  val kind: kind
+
+  val shape: t -> int array
+  (** Array shape *)
+
+  val v: Context.t -> (int, Bigarray.int8_unsigned_elt, Bigarray.c_layout) Bigarray.Genarray.t -> t
+  (** Initialize an array with the data from the provided bigarray *)
+
+  val values: t -> (int, Bigarray.int8_unsigned_elt, Bigarray.c_layout) Bigarray.Genarray.t -> unit
+  (** Load the values into the provided bigarray *)
+
+  val values_array1: t -> (int, Bigarray.int8_unsigned_elt, Bigarray.c_layout) Bigarray.Array1.t -> unit
+  (** Similar to [values] but takes an [Array1] instead of [Genarray] *)
+
+  val get: t -> (int, Bigarray.int8_unsigned_elt, Bigarray.c_layout) Bigarray.Genarray.t
+  (** Get a new bigarray with the values loaded *)
+
+  val get_array1: t -> (int, Bigarray.int8_unsigned_elt, Bigarray.c_layout) Bigarray.Array1.t
+  (** Similar to [get] but returns an [Array1] *)
+
+  val index: t -> int array -> int
+  (** Get a single element of the array without copying the whole array, raises
+      [Error (Unsupported "index")] if the manifest doesn't include an [index] function *)
+
+  val of_array: Context.t -> int array -> (int) array -> t
+  (** Create [t] from an array of values *)
+
+  val of_array1: Context.t -> int array ->  (int, Bigarray.int8_unsigned_elt, Bigarray.c_layout) Bigarray.Array1.t-> t
+  (** Create [t] from an [Array1] instead of [Genarray] *)
+
+  val free: t -> unit
+  (** Free the array *)
+end
+
+module Array_f16_1d: sig
+  type t
+  (** Futhark array *)
+
+  type kind = (float, Bigarray.float32_elt) Bigarray.kind
+  (** The Bigarray kind that matches the correct element type for this array *)
+
+  val kind: kind
+
+  val shape: t -> int array
+  (** Array shape *)
+
+  val v: Context.t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Genarray.t -> t
+  (** Initialize an array with the data from the provided bigarray *)
+
+  val values: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Genarray.t -> unit
+  (** Load the values into the provided bigarray *)
+
+  val values_array1: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Array1.t -> unit
+  (** Similar to [values] but takes an [Array1] instead of [Genarray] *)
+
+  val get: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Genarray.t
+  (** Get a new bigarray with the values loaded *)
+
+  val get_array1: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Array1.t
+  (** Similar to [get] but returns an [Array1] *)
+
+  val index: t -> int array -> float
+  (** Get a single element of the array without copying the whole array, raises
+      [Error (Unsupported "index")] if the manifest doesn't include an [index] function *)
+
+  val of_array: Context.t -> int array -> (float) array -> t
+  (** Create [t] from an array of values *)
+
+  val of_array1: Context.t -> int array ->  (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Array1.t-> t
+  (** Create [t] from an [Array1] instead of [Genarray] *)
+
+  val free: t -> unit
+  (** Free the array *)
+end
+
+module Array_f32_1d: sig
+  type t
+  (** Futhark array *)
+
+  type kind = (float, Bigarray.float32_elt) Bigarray.kind
+  (** The Bigarray kind that matches the correct element type for this array *)
+
+  val kind: kind
+
+  val shape: t -> int array
+  (** Array shape *)
+
+  val v: Context.t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Genarray.t -> t
+  (** Initialize an array with the data from the provided bigarray *)
+
+  val values: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Genarray.t -> unit
+  (** Load the values into the provided bigarray *)
+
+  val values_array1: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Array1.t -> unit
+  (** Similar to [values] but takes an [Array1] instead of [Genarray] *)
+
+  val get: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Genarray.t
+  (** Get a new bigarray with the values loaded *)
+
+  val get_array1: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Array1.t
+  (** Similar to [get] but returns an [Array1] *)
+
+  val index: t -> int array -> float
+  (** Get a single element of the array without copying the whole array, raises
+      [Error (Unsupported "index")] if the manifest doesn't include an [index] function *)
+
+  val of_array: Context.t -> int array -> (float) array -> t
+  (** Create [t] from an array of values *)
+
+  val of_array1: Context.t -> int array ->  (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Array1.t-> t
+  (** Create [t] from an [Array1] instead of [Genarray] *)
+
+  val free: t -> unit
+  (** Free the array *)
+end
+
+module Array_f64_1d: sig
+  type t
+  (** Futhark array *)
+
+  type kind = (float, Bigarray.float64_elt) Bigarray.kind
+  (** The Bigarray kind that matches the correct element type for this array *)
+
+  val kind: kind
+
+  val shape: t -> int array
+  (** Array shape *)
+
+  val v: Context.t -> (float, Bigarray.float64_elt, Bigarray.c_layout) Bigarray.Genarray.t -> t
+  (** Initialize an array with the data from the provided bigarray *)
+
+  val values: t -> (float, Bigarray.float64_elt, Bigarray.c_layout) Bigarray.Genarray.t -> unit
+  (** Load the values into the provided bigarray *)
+
+  val values_array1: t -> (float, Bigarray.float64_elt, Bigarray.c_layout) Bigarray.Array1.t -> unit
+  (** Similar to [values] but takes an [Array1] instead of [Genarray] *)
+
+  val get: t -> (float, Bigarray.float64_elt, Bigarray.c_layout) Bigarray.Genarray.t
+  (** Get a new bigarray with the values loaded *)
+
+  val get_array1: t -> (float, Bigarray.float64_elt, Bigarray.c_layout) Bigarray.Array1.t
+  (** Similar to [get] but returns an [Array1] *)
+
+  val index: t -> int array -> float
+  (** Get a single element of the array without copying the whole array, raises
+      [Error (Unsupported "index")] if the manifest doesn't include an [index] function *)
+
+  val of_array: Context.t -> int array -> (float) array -> t
+  (** Create [t] from an array of values *)
+
+  val of_array1: Context.t -> int array ->  (float, Bigarray.float64_elt, Bigarray.c_layout) Bigarray.Array1.t-> t
+  (** Create [t] from an [Array1] instead of [Genarray] *)
+
+  val free: t -> unit
+  (** Free the array *)
+end
+
+module Array_i16_1d: sig
+  type t
+  (** Futhark array *)
+
+  type kind = (int, Bigarray.int16_signed_elt) Bigarray.kind
+  (** The Bigarray kind that matches the correct element type for this array *)
+
+  val kind: kind
+
+  val shape: t -> int array
+  (** Array shape *)
+
+  val v: Context.t -> (int, Bigarray.int16_signed_elt, Bigarray.c_layout) Bigarray.Genarray.t -> t
+  (** Initialize an array with the data from the provided bigarray *)
+
+  val values: t -> (int, Bigarray.int16_signed_elt, Bigarray.c_layout) Bigarray.Genarray.t -> unit
+  (** Load the values into the provided bigarray *)
+
+  val values_array1: t -> (int, Bigarray.int16_signed_elt, Bigarray.c_layout) Bigarray.Array1.t -> unit
+  (** Similar to [values] but takes an [Array1] instead of [Genarray] *)
+
+  val get: t -> (int, Bigarray.int16_signed_elt, Bigarray.c_layout) Bigarray.Genarray.t
+  (** Get a new bigarray with the values loaded *)
+
+  val get_array1: t -> (int, Bigarray.int16_signed_elt, Bigarray.c_layout) Bigarray.Array1.t
+  (** Similar to [get] but returns an [Array1] *)
+
+  val index: t -> int array -> int
+  (** Get a single element of the array without copying the whole array, raises
+      [Error (Unsupported "index")] if the manifest doesn't include an [index] function *)
+
+  val of_array: Context.t -> int array -> (int) array -> t
+  (** Create [t] from an array of values *)
+
+  val of_array1: Context.t -> int array ->  (int, Bigarray.int16_signed_elt, Bigarray.c_layout) Bigarray.Array1.t-> t
+  (** Create [t] from an [Array1] instead of [Genarray] *)
+
+  val free: t -> unit
+  (** Free the array *)
+end
+
+module Array_i32_1d: sig
+  type t
+  (** Futhark array *)
+
+  type kind = (int32, Bigarray.int32_elt) Bigarray.kind
+  (** The Bigarray kind that matches the correct element type for this array *)
+
+  val kind: kind
+
+  val shape: t -> int array
+  (** Array shape *)
+
+  val v: Context.t -> (int32, Bigarray.int32_elt, Bigarray.c_layout) Bigarray.Genarray.t -> t
+  (** Initialize an array with the data from the provided bigarray *)
+
+  val values: t -> (int32, Bigarray.int32_elt, Bigarray.c_layout) Bigarray.Genarray.t -> unit
+  (** Load the values into the provided bigarray *)
+
+  val values_array1: t -> (int32, Bigarray.int32_elt, Bigarray.c_layout) Bigarray.Array1.t -> unit
+  (** Similar to [values] but takes an [Array1] instead of [Genarray] *)
+
+  val get: t -> (int32, Bigarray.int32_elt, Bigarray.c_layout) Bigarray.Genarray.t
+  (** Get a new bigarray with the values loaded *)
+
+  val get_array1: t -> (int32, Bigarray.int32_elt, Bigarray.c_layout) Bigarray.Array1.t
+  (** Similar to [get] but returns an [Array1] *)
+
+  val index: t -> int array -> int32
+  (** Get a single element of the array without copying the whole array, raises
+      [Error (Unsupported "index")] if the manifest doesn't include an [index] function *)
+
+  val of_array: Context.t -> int array -> (int32) array -> t
+  (** Create [t] from an array of values *)
+
+  val of_array1: Context.t -> int array ->  (int32, Bigarray.int32_elt, Bigarray.c_layout) Bigarray.Array1.t-> t
+  (** Create [t] from an [Array1] instead of [Genarray] *)
+
+  val free: t -> unit
+  (** Free the array *)
+end
+
+module Array_i64_1d: sig
+  type t
+  (** Futhark array *)
+
+  type kind = (int64, Bigarray.int64_elt) Bigarray.kind
+  (** The Bigarray kind that matches the correct element type for this array *)
+
+  val kind: kind
+
+  val shape: t -> int array
+  (** Array shape *)
+
+  val v: Context.t -> (int64, Bigarray.int64_elt, Bigarray.c_layout) Bigarray.Genarray.t -> t
+  (** Initialize an array with the data from the provided bigarray *)
+
+  val values: t -> (int64, Bigarray.int64_elt, Bigarray.c_layout) Bigarray.Genarray.t -> unit
+  (** Load the values into the provided bigarray *)
+
+  val values_array1: t -> (int64, Bigarray.int64_elt, Bigarray.c_layout) Bigarray.Array1.t -> unit
+  (** Similar to [values] but takes an [Array1] instead of [Genarray] *)
+
+  val get: t -> (int64, Bigarray.int64_elt, Bigarray.c_layout) Bigarray.Genarray.t
+  (** Get a new bigarray with the values loaded *)
+
+  val get_array1: t -> (int64, Bigarray.int64_elt, Bigarray.c_layout) Bigarray.Array1.t
+  (** Similar to [get] but returns an [Array1] *)
+
+  val index: t -> int array -> int64
+  (** Get a single element of the array without copying the whole array, raises
+      [Error (Unsupported "index")] if the manifest doesn't include an [index] function *)
+
+  val of_array: Context.t -> int array -> (int64) array -> t
+  (** Create [t] from an array of values *)
+
+  val of_array1: Context.t -> int array ->  (int64, Bigarray.int64_elt, Bigarray.c_layout) Bigarray.Array1.t-> t
+  (** Create [t] from an [Array1] instead of [Genarray] *)
+
+  val free: t -> unit
+  (** Free the array *)
+end
+
+module Array_i8_1d: sig
+  type t
+  (** Futhark array *)
+
+  type kind = (int, Bigarray.int8_signed_elt) Bigarray.kind
+  (** The Bigarray kind that matches the correct element type for this array *)
+
+  val kind: kind
+
+  val shape: t -> int array
+  (** Array shape *)
+
+  val v: Context.t -> (int, Bigarray.int8_signed_elt, Bigarray.c_layout) Bigarray.Genarray.t -> t
+  (** Initialize an array with the data from the provided bigarray *)
+
+  val values: t -> (int, Bigarray.int8_signed_elt, Bigarray.c_layout) Bigarray.Genarray.t -> unit
+  (** Load the values into the provided bigarray *)
+
+  val values_array1: t -> (int, Bigarray.int8_signed_elt, Bigarray.c_layout) Bigarray.Array1.t -> unit
+  (** Similar to [values] but takes an [Array1] instead of [Genarray] *)
+
+  val get: t -> (int, Bigarray.int8_signed_elt, Bigarray.c_layout) Bigarray.Genarray.t
+  (** Get a new bigarray with the values loaded *)
+
+  val get_array1: t -> (int, Bigarray.int8_signed_elt, Bigarray.c_layout) Bigarray.Array1.t
+  (** Similar to [get] but returns an [Array1] *)
+
+  val index: t -> int array -> int
+  (** Get a single element of the array without copying the whole array, raises
+      [Error (Unsupported "index")] if the manifest doesn't include an [index] function *)
+
+  val of_array: Context.t -> int array -> (int) array -> t
+  (** Create [t] from an array of values *)
+
+  val of_array1: Context.t -> int array ->  (int, Bigarray.int8_signed_elt, Bigarray.c_layout) Bigarray.Array1.t-> t
+  (** Create [t] from an [Array1] instead of [Genarray] *)
+
+  val free: t -> unit
+  (** Free the array *)
+end
+
+module Array_u16_1d: sig
+  type t
+  (** Futhark array *)
+
+  type kind = (int, Bigarray.int16_unsigned_elt) Bigarray.kind
+  (** The Bigarray kind that matches the correct element type for this array *)
+
+  val kind: kind
+
+  val shape: t -> int array
+  (** Array shape *)
+
+  val v: Context.t -> (int, Bigarray.int16_unsigned_elt, Bigarray.c_layout) Bigarray.Genarray.t -> t
+  (** Initialize an array with the data from the provided bigarray *)
+
+  val values: t -> (int, Bigarray.int16_unsigned_elt, Bigarray.c_layout) Bigarray.Genarray.t -> unit
+  (** Load the values into the provided bigarray *)
+
+  val values_array1: t -> (int, Bigarray.int16_unsigned_elt, Bigarray.c_layout) Bigarray.Array1.t -> unit
+  (** Similar to [values] but takes an [Array1] instead of [Genarray] *)
+
+  val get: t -> (int, Bigarray.int16_unsigned_elt, Bigarray.c_layout) Bigarray.Genarray.t
+  (** Get a new bigarray with the values loaded *)
+
+  val get_array1: t -> (int, Bigarray.int16_unsigned_elt, Bigarray.c_layout) Bigarray.Array1.t
+  (** Similar to [get] but returns an [Array1] *)
+
+  val index: t -> int array -> int
+  (** Get a single element of the array without copying the whole array, raises
+      [Error (Unsupported "index")] if the manifest doesn't include an [index] function *)
+
+  val of_array: Context.t -> int array -> (int) array -> t
+  (** Create [t] from an array of values *)
+
+  val of_array1: Context.t -> int array ->  (int, Bigarray.int16_unsigned_elt, Bigarray.c_layout) Bigarray.Array1.t-> t
+  (** Create [t] from an [Array1] instead of [Genarray] *)
+
+  val free: t -> unit
+  (** Free the array *)
+end
+
+module Array_u32_1d: sig
+  type t
+  (** Futhark array *)
+
+  type kind = (int32, Bigarray.int32_elt) Bigarray.kind
+  (** The Bigarray kind that matches the correct element type for this array *)
+
+  val kind: kind
+
+  val shape: t -> int array
+  (** Array shape *)
+
+  val v: Context.t -> (int32, Bigarray.int32_elt, Bigarray.c_layout) Bigarray.Genarray.t -> t
+  (** Initialize an array with the data from the provided bigarray *)
+
+  val values: t -> (int32, Bigarray.int32_elt, Bigarray.c_layout) Bigarray.Genarray.t -> unit
+  (** Load the values into the provided bigarray *)
+
+  val values_array1: t -> (int32, Bigarray.int32_elt, Bigarray.c_layout) Bigarray.Array1.t -> unit
+  (** Similar to [values] but takes an [Array1] instead of [Genarray] *)
+
+  val get: t -> (int32, Bigarray.int32_elt, Bigarray.c_layout) Bigarray.Genarray.t
+  (** Get a new bigarray with the values loaded *)
+
+  val get_array1: t -> (int32, Bigarray.int32_elt, Bigarray.c_layout) Bigarray.Array1.t
+  (** Similar to [get] but returns an [Array1] *)
+
+  val index: t -> int array -> int32
+  (** Get a single element of the array without copying the whole array, raises
+      [Error (Unsupported "index")] if the manifest doesn't include an [index] function *)
+
+  val of_array: Context.t -> int array -> (int32) array -> t
+  (** Create [t] from an array of values *)
+
+  val of_array1: Context.t -> int array ->  (int32, Bigarray.int32_elt, Bigarray.c_layout) Bigarray.Array1.t-> t
+  (** Create [t] from an [Array1] instead of [Genarray] *)
+
+  val free: t -> unit
+  (** Free the array *)
+end
+
+module Array_u64_1d: sig
+  type t
+  (** Futhark array *)
+
+  type kind = (int64, Bigarray.int64_elt) Bigarray.kind
+  (** The Bigarray kind that matches the correct element type for this array *)
+
+  val kind: kind
+
+  val shape: t -> int array
+  (** Array shape *)
+
+  val v: Context.t -> (int64, Bigarray.int64_elt, Bigarray.c_layout) Bigarray.Genarray.t -> t
+  (** Initialize an array with the data from the provided bigarray *)
+
+  val values: t -> (int64, Bigarray.int64_elt, Bigarray.c_layout) Bigarray.Genarray.t -> unit
+  (** Load the values into the provided bigarray *)
+
+  val values_array1: t -> (int64, Bigarray.int64_elt, Bigarray.c_layout) Bigarray.Array1.t -> unit
+  (** Similar to [values] but takes an [Array1] instead of [Genarray] *)
+
+  val get: t -> (int64, Bigarray.int64_elt, Bigarray.c_layout) Bigarray.Genarray.t
+  (** Get a new bigarray with the values loaded *)
+
+  val get_array1: t -> (int64, Bigarray.int64_elt, Bigarray.c_layout) Bigarray.Array1.t
+  (** Similar to [get] but returns an [Array1] *)
+
+  val index: t -> int array -> int64
+  (** Get a single element of the array without copying the whole array, raises
+      [Error (Unsupported "index")] if the manifest doesn't include an [index] function *)
+
+  val of_array: Context.t -> int array -> (int64) array -> t
+  (** Create [t] from an array of values *)
+
+  val of_array1: Context.t -> int array ->  (int64, Bigarray.int64_elt, Bigarray.c_layout) Bigarray.Array1.t-> t
+  (** Create [t] from an [Array1] instead of [Genarray] *)
+
+  val free: t -> unit
+  (** Free the array *)
+end
+
+module Array_u8_1d: sig
+  type t
+  (** Futhark array *)
+
+  type kind = (int, Bigarray.int8_unsigned_elt) Bigarray.kind
+  (** The Bigarray kind that matches the correct element type for this array *)
+
+  val kind: kind
+
+  val shape: t -> int array
+  (** Array shape *)
+
+  val v: Context.t -> (int, Bigarray.int8_unsigned_elt, Bigarray.c_layout) Bigarray.Genarray.t -> t
+  (** Initialize an array with the data from the provided bigarray *)
+
+  val values: t -> (int, Bigarray.int8_unsigned_elt, Bigarray.c_layout) Bigarray.Genarray.t -> unit
+  (** Load the values into the provided bigarray *)
+
+  val values_array1: t -> (int, Bigarray.int8_unsigned_elt, Bigarray.c_layout) Bigarray.Array1.t -> unit
+  (** Similar to [values] but takes an [Array1] instead of [Genarray] *)
+
+  val get: t -> (int, Bigarray.int8_unsigned_elt, Bigarray.c_layout) Bigarray.Genarray.t
+  (** Get a new bigarray with the values loaded *)
+
+  val get_array1: t -> (int, Bigarray.int8_unsigned_elt, Bigarray.c_layout) Bigarray.Array1.t
+  (** Similar to [get] but returns an [Array1] *)
+
+  val index: t -> int array -> int
+  (** Get a single element of the array without copying the whole array, raises
+      [Error (Unsupported "index")] if the manifest doesn't include an [index] function *)
+
+  val of_array: Context.t -> int array -> (int) array -> t
+  (** Create [t] from an array of values *)
+
+  val of_array1: Context.t -> int array ->  (int, Bigarray.int8_unsigned_elt, Bigarray.c_layout) Bigarray.Array1.t-> t
+  (** Create [t] from an [Array1] instead of [Genarray] *)
+
+  val free: t -> unit
+  (** Free the array *)
+end
+
+(** Entry point: flatten *)
+val flatten: Context.t -> Array_f32_3d.t -> (Array_f32_2d.t)
+
+(** Entry point: lengths *)
+val lengths: Context.t -> Array_i8_1d.t -> Array_i16_1d.t -> Array_i32_1d.t -> Array_i64_1d.t -> Array_u8_1d.t -> Array_u16_1d.t -> Array_u32_1d.t -> Array_u64_1d.t -> Array_f16_1d.t -> Array_f32_1d.t -> Array_f64_1d.t -> Array_bool_1d.t -> (int64)
+
+type sum_rows_output = {
+  out0: Array_f32_1d.t;
+  out1: float;
+}
+(** Outputs of the [sum_rows] entry point *)
+
+(** Entry point: sum_rows *)
+val sum_rows: Context.t -> Array_f32_2d.t -> (sum_rows_output)
+
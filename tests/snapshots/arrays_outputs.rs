@@ -0,0 +1,2072 @@
+// Generated by futhark-bindgen
+
+#[derive(Debug)]
+pub enum Error {
+    Code(std::os::raw::c_int),
+    NullPtr,
+    InvalidShape,
+    IndexOutOfBounds,
+    Unsupported(&'static str),
+    MissingField(&'static str),
+    InvalidTuning(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::Code(code) => write!(fmt, "Futhark error code: {code}"),
+            Error::NullPtr => write!(fmt, "NULL pointer encountered"),
+            Error::InvalidShape => write!(fmt, "Invalid image shape"),
+            Error::IndexOutOfBounds => write!(fmt, "Index out of bounds"),
+            Error::Unsupported(op) => write!(fmt, "{op} is not supported by this version of Futhark"),
+            Error::MissingField(field) => write!(fmt, "Missing field: {field}"),
+            Error::InvalidTuning(msg) => write!(fmt, "Invalid tuning parameter: {msg}"),
+        }
+    } 
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Debug, Clone)]
+pub struct Options {
+    debug: bool,
+    profile: bool,
+    logging: bool,
+    cache_file: std::option::Option<std::ffi::CString>,
+    num_threads: u32,
+    tuning_params: Vec<(std::ffi::CString, usize)>,
+    tuning_file: std::option::Option<std::path::PathBuf>,
+    auto_sync: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options::new()
+    }
+}
+
+impl Options {
+    /// Create new `Options` with default settings
+    pub fn new() -> Self {
+        Options {
+            debug: false,
+            profile: false,
+            logging: false,
+            cache_file: None,
+            num_threads: 0,
+            tuning_params: Vec::new(),
+            tuning_file: None,
+            auto_sync: true,
+        }
+    }
+
+    /// Enable debug
+    pub fn debug(mut self) -> Self {
+        self.debug = true;
+        self
+    }
+
+    /// Enable profiling
+    pub fn profile(mut self) -> Self {
+        self.profile = true;
+        self
+    }
+
+    /// Enable logging
+    pub fn log(mut self) -> Self {
+        self.logging = true;
+        self
+    }
+
+    /// Set Futhark cache file
+    pub fn cache_file(mut self, s: impl AsRef<str>) -> Self {
+        self.cache_file = Some(std::ffi::CString::new(s.as_ref()).expect("Invalid cache file"));
+        self
+    }
+
+    pub fn auto_sync(mut self, sync: bool) -> Self {
+        self.auto_sync = sync;
+        self
+    }
+
+    /// Set a tuning parameter, these are applied after the parameters from the tuning file
+    pub fn tuning_param(mut self, name: impl AsRef<str>, value: usize) -> Self {
+        let name = std::ffi::CString::new(name.as_ref()).expect("Invalid tuning parameter");
+        self.tuning_params.push((name, value));
+        self
+    }
+
+    /// Load tuning parameters from a `.tuning` file when the context is created
+    pub fn tuning_file(mut self, path: impl AsRef<std::path::Path>) -> Self {
+        self.tuning_file = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    // Read a tuning file, every line has the form `name=value`
+    fn read_tuning_file(path: &std::path::Path) -> std::result::Result<Vec<(std::ffi::CString, usize)>, Error> {
+        let s = std::fs::read_to_string(path)
+            .map_err(|e| Error::InvalidTuning(format!("{}: {e}", path.display())))?;
+        s.lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let invalid = || Error::InvalidTuning(line.to_string());
+                let (name, value) = line.split_once('=').ok_or_else(invalid)?;
+                let value = value.trim().parse().map_err(|_| invalid())?;
+                let name = std::ffi::CString::new(name.trim()).map_err(|_| invalid())?;
+                Ok((name, value))
+            })
+            .collect()
+    }
+
+
+    pub fn threads(mut self, n: u32) -> Options { self.num_threads = n as u32; self }
+}
+
+/// Futhark context
+pub struct Context {
+    config: *mut futhark_context_config,
+    context: *mut futhark_context,
+    auto_sync: bool,
+    _cache_file: std::option::Option<std::ffi::CString>,
+}
+
+impl Context {
+    /// Create a new context with default options
+    pub fn new() -> std::result::Result<Self, Error> {
+        unsafe {
+            let config = futhark_context_config_new();
+            if config.is_null() { return Err(Error::NullPtr) }
+            let context = futhark_context_new(config);
+            if context.is_null() {
+                futhark_context_config_free(config);
+                return Err(Error::NullPtr);
+            }
+            Ok(Context { config, context, auto_sync: true, _cache_file: None })
+        }
+    }
+
+    /// Create a new context with custom options
+    pub fn new_with_options(options: Options) -> std::result::Result<Self, Error> {
+        let mut tuning_params = match &options.tuning_file {
+            Some(path) => Options::read_tuning_file(path)?,
+            None => Vec::new(),
+        };
+        tuning_params.extend(options.tuning_params.iter().cloned());
+
+        unsafe {
+            let config = futhark_context_config_new();
+            if config.is_null() { return Err(Error::NullPtr) }
+
+            for (name, value) in &tuning_params {
+                if futhark_context_config_set_tuning_param(config, name.as_ptr(), *value) != 0 {
+                    futhark_context_config_free(config);
+                    return Err(Error::InvalidTuning(name.to_string_lossy().into_owned()));
+                }
+            }
+
+            futhark_context_config_set_debugging(config, options.debug as std::os::raw::c_int);
+            futhark_context_config_set_profiling(config, options.profile as std::os::raw::c_int);
+            futhark_context_config_set_logging(config, options.logging as std::os::raw::c_int);
+
+            if let Some(c) = &options.cache_file {
+                futhark_context_config_set_cache_file(config, c.as_ptr());
+            }
+
+            futhark_context_config_set_num_threads(config, options.num_threads as std::os::raw::c_int);
+
+            let context = futhark_context_new(config);
+            if context.is_null() {
+                futhark_context_config_free(config);
+                return Err(Error::NullPtr);
+            }
+            Ok(Context { config, context, auto_sync: options.auto_sync, _cache_file: options.cache_file })
+        }
+    }
+
+    /// Sync the context, if `auto_sync` is enabled this shouldn't be needed
+    pub fn sync(&self) {
+        unsafe { futhark_context_sync(self.context); }
+    }
+
+    /// Sync if `auto_sync` is enabled, otherwise this is a noop
+    pub fn auto_sync(&self) {
+        if self.auto_sync {
+            self.sync();
+        }
+    }
+
+    /// Clear Futhark caches
+    pub fn clear_caches(&self) -> std::result::Result<(), Error> {
+        let rc = unsafe {
+            futhark_context_clear_caches(self.context)
+        };
+        if rc != 0 { return Err(Error::Code(rc)) }
+        Ok(())
+    }
+
+    /// Pause Futhark profiling
+    pub fn pause_profiling(&self) {
+        unsafe {
+            futhark_context_pause_profiling(self.context);
+        }
+    }
+
+    /// Resume profiling
+    pub fn unpause_profiling(&self) {
+        unsafe {
+            futhark_context_unpause_profiling(self.context);
+        }
+    }
+
+    /// Get the last error message or None
+    pub fn get_error(&self) -> std::option::Option<String> {
+        unsafe {
+            let s = futhark_context_get_error(self.context);
+            if s.is_null() { return None }
+            let r = std::ffi::CStr::from_ptr(s).to_string_lossy().to_string();
+            free(s as *mut _);
+            Some(r)
+        }
+    }
+
+    pub fn report(&self) -> std::option::Option<String> {
+        unsafe {
+            let s = futhark_context_report(self.context);
+            if s.is_null() { return None }
+            let r = std::ffi::CStr::from_ptr(s).to_string_lossy().to_string();
+            free(s as *mut _);
+            Some(r)
+        }
+    }
+}
+
+impl Drop for Context {
+    fn drop(&mut self) {
+        unsafe {
+            futhark_context_sync(self.context);
+            futhark_context_free(self.context);
+            futhark_context_config_free(self.config);
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_context_config {
+    _private: [u8; 0]
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_context {
+    _private: [u8; 0]
+}
+
+extern "C" {
+    fn futhark_context_config_new() -> *mut futhark_context_config;
+    fn futhark_context_config_free(
+        _: *mut futhark_context_config
+    );
+    fn futhark_context_config_set_debugging(
+        _: *mut futhark_context_config,
+        _: std::os::raw::c_int
+    );
+
+    fn futhark_context_config_set_profiling(
+        _: *mut futhark_context_config,
+        _: std::os::raw::c_int
+    );
+
+    fn futhark_context_config_set_logging(
+        _: *mut futhark_context_config,
+        _: std::os::raw::c_int
+    );
+
+    fn futhark_context_config_set_cache_file(
+        _: *mut futhark_context_config,
+        _: *const std::os::raw::c_char,
+    );
+
+    fn futhark_context_config_set_tuning_param(
+        _: *mut futhark_context_config,
+        _: *const std::os::raw::c_char,
+        _: usize,
+    ) -> std::os::raw::c_int;
+
+    fn futhark_context_new(
+        _: *mut futhark_context_config
+    ) -> *mut futhark_context;
+
+    fn futhark_context_free(
+        _: *mut futhark_context
+    );
+
+    fn futhark_context_sync(
+        _: *mut futhark_context,
+    ) -> std::os::raw::c_int;
+
+    fn futhark_context_clear_caches(
+        _: *mut futhark_context,
+    ) -> std::os::raw::c_int;
+
+    fn futhark_context_pause_profiling(
+        _: *mut futhark_context
+    );
+
+    fn futhark_context_unpause_profiling(
+        _: *mut futhark_context
+    );
+
+    fn futhark_context_get_error(
+        _: *mut futhark_context
+    ) -> *mut std::os::raw::c_char;
+
+    fn futhark_context_report(
+        _: *mut futhark_context
+    ) -> *mut std::os::raw::c_char;
+
+    fn free(_: *mut std::ffi::c_void);
+
+    fn futhark_context_config_set_num_threads(_: *mut futhark_context_config, _: std::os::raw::c_int);
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_f32_3d {
+    _private: [u8; 0]
+}
+
+/// Array type with 3 dimensions and f32 elements
+pub struct ArrayF32D3<'a> {
+    ptr: *mut futhark_f32_3d,
+    pub shape: [i64; 3],
+    ctx: &'a Context,
+}
+
+impl<'a> ArrayF32D3<'a> {
+    /// Create a new array of `dims` dimensions and initialize it with the values from `data`
+    pub fn new(ctx: &'a Context, dims: [i64; 3], data: impl AsRef<[f32]>) -> std::result::Result<Self, Error> {
+        let size: i64 = dims.iter().product();
+        let data = data.as_ref();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape)
+        }
+        let ptr = unsafe {
+            futhark_new_f32_3d(ctx.context, data.as_ptr(), dims[0], dims[1], dims[2])
+        };
+        if ptr.is_null() { return Err(Error::NullPtr); }
+        ctx.auto_sync();
+        Ok(Self {
+            ptr: ptr as *mut _,
+            shape: dims,
+            ctx,
+        })
+    }
+
+    /// Get the array shape
+    pub fn shape(&self) -> &[i64; 3] {
+        &self.shape
+    }
+
+    /// Load values back into a slice
+    pub fn values(&self, mut data: impl AsMut<[f32]>) -> std::result::Result<(), Error> {
+        let size: i64 = self.shape.iter().product();
+        let data = data.as_mut();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape);
+        }
+        let rc = unsafe {
+            futhark_values_f32_3d(self.ctx.context, self.ptr, data.as_mut_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::Code(rc));
+        }
+        self.ctx.auto_sync();
+        Ok(())
+    }
+
+    /// Load values into a `Vec`
+    pub fn get(&self) -> std::result::Result<Vec<f32>, Error> {
+        let size: i64 = self.shape.iter().product();
+        let mut vec = vec![f32::default(); size as usize];
+        self.values(&mut vec)?;
+        Ok(vec)
+    }
+
+
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, ptr: *mut futhark_f32_3d) -> Self {
+        let len_ptr = unsafe { futhark_shape_f32_3d(ctx.context, ptr) };
+        let mut shape = [0i64; 3];
+        unsafe {
+            for (i, s) in shape.iter_mut().enumerate() {
+                *s = *len_ptr.add(i);
+            }
+        }
+        Self { ctx, shape, ptr }
+    }
+}
+
+
+impl<'a> Drop for ArrayF32D3<'a> {
+    fn drop(&mut self){
+        unsafe {
+            futhark_free_f32_3d(self.ctx.context, self.ptr as *mut _);
+        }
+    }
+}
+
+#[allow(unused)]
+extern "C" {
+    fn futhark_shape_f32_3d(
+        _: *mut futhark_context,
+        _: *mut futhark_f32_3d
+    ) -> *const i64;
+
+    fn futhark_new_f32_3d(
+        _: *mut futhark_context,
+        _: *const f32,
+        dim0: i64, dim1: i64, dim2: i64
+    ) -> *mut futhark_f32_3d;
+
+    fn futhark_free_f32_3d(
+        _: *mut futhark_context,
+        _: *mut futhark_f32_3d
+    ) -> std::os::raw::c_int;
+
+    fn futhark_values_f32_3d(
+        _: *mut futhark_context,
+        _: *mut futhark_f32_3d,
+        _: *mut f32
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> ArrayF32D3<'a> {
+    /// Get a single element of the array, the manifest doesn't include an `index` function so this
+    /// always returns `Error::Unsupported`
+    pub fn index(&self, _index: [i64; 3]) -> std::result::Result<f32, Error> {
+        Err(Error::Unsupported("index"))
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_f32_2d {
+    _private: [u8; 0]
+}
+
+/// Array type with 2 dimensions and f32 elements
+pub struct ArrayF32D2<'a> {
+    ptr: *mut futhark_f32_2d,
+    pub shape: [i64; 2],
+    ctx: &'a Context,
+}
+
+impl<'a> ArrayF32D2<'a> {
+    /// Create a new array of `dims` dimensions and initialize it with the values from `data`
+    pub fn new(ctx: &'a Context, dims: [i64; 2], data: impl AsRef<[f32]>) -> std::result::Result<Self, Error> {
+        let size: i64 = dims.iter().product();
+        let data = data.as_ref();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape)
+        }
+        let ptr = unsafe {
+            futhark_new_f32_2d(ctx.context, data.as_ptr(), dims[0], dims[1])
+        };
+        if ptr.is_null() { return Err(Error::NullPtr); }
+        ctx.auto_sync();
+        Ok(Self {
+            ptr: ptr as *mut _,
+            shape: dims,
+            ctx,
+        })
+    }
+
+    /// Get the array shape
+    pub fn shape(&self) -> &[i64; 2] {
+        &self.shape
+    }
+
+    /// Load values back into a slice
+    pub fn values(&self, mut data: impl AsMut<[f32]>) -> std::result::Result<(), Error> {
+        let size: i64 = self.shape.iter().product();
+        let data = data.as_mut();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape);
+        }
+        let rc = unsafe {
+            futhark_values_f32_2d(self.ctx.context, self.ptr, data.as_mut_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::Code(rc));
+        }
+        self.ctx.auto_sync();
+        Ok(())
+    }
+
+    /// Load values into a `Vec`
+    pub fn get(&self) -> std::result::Result<Vec<f32>, Error> {
+        let size: i64 = self.shape.iter().product();
+        let mut vec = vec![f32::default(); size as usize];
+        self.values(&mut vec)?;
+        Ok(vec)
+    }
+
+
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, ptr: *mut futhark_f32_2d) -> Self {
+        let len_ptr = unsafe { futhark_shape_f32_2d(ctx.context, ptr) };
+        let mut shape = [0i64; 2];
+        unsafe {
+            for (i, s) in shape.iter_mut().enumerate() {
+                *s = *len_ptr.add(i);
+            }
+        }
+        Self { ctx, shape, ptr }
+    }
+}
+
+
+impl<'a> Drop for ArrayF32D2<'a> {
+    fn drop(&mut self){
+        unsafe {
+            futhark_free_f32_2d(self.ctx.context, self.ptr as *mut _);
+        }
+    }
+}
+
+#[allow(unused)]
+extern "C" {
+    fn futhark_shape_f32_2d(
+        _: *mut futhark_context,
+        _: *mut futhark_f32_2d
+    ) -> *const i64;
+
+    fn futhark_new_f32_2d(
+        _: *mut futhark_context,
+        _: *const f32,
+        dim0: i64, dim1: i64
+    ) -> *mut futhark_f32_2d;
+
+    fn futhark_free_f32_2d(
+        _: *mut futhark_context,
+        _: *mut futhark_f32_2d
+    ) -> std::os::raw::c_int;
+
+    fn futhark_values_f32_2d(
+        _: *mut futhark_context,
+        _: *mut futhark_f32_2d,
+        _: *mut f32
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> ArrayF32D2<'a> {
+    /// Get a single element of the array, the manifest doesn't include an `index` function so this
+    /// always returns `Error::Unsupported`
+    pub fn index(&self, _index: [i64; 2]) -> std::result::Result<f32, Error> {
+        Err(Error::Unsupported("index"))
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_bool_1d {
+    _private: [u8; 0]
+}
+
+/// Array type with 1 dimensions and bool elements
+pub struct ArrayBoolD1<'a> {
+    ptr: *mut futhark_bool_1d,
+    pub shape: [i64; 1],
+    ctx: &'a Context,
+}
+
+impl<'a> ArrayBoolD1<'a> {
+    /// Create a new array of `dims` dimensions and initialize it with the values from `data`
+    pub fn new(ctx: &'a Context, dims: [i64; 1], data: impl AsRef<[bool]>) -> std::result::Result<Self, Error> {
+        let size: i64 = dims.iter().product();
+        let data = data.as_ref();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape)
+        }
+        let ptr = unsafe {
+            futhark_new_bool_1d(ctx.context, data.as_ptr(), dims[0])
+        };
+        if ptr.is_null() { return Err(Error::NullPtr); }
+        ctx.auto_sync();
+        Ok(Self {
+            ptr: ptr as *mut _,
+            shape: dims,
+            ctx,
+        })
+    }
+
+    /// Get the array shape
+    pub fn shape(&self) -> &[i64; 1] {
+        &self.shape
+    }
+
+    /// Load values back into a slice
+    pub fn values(&self, mut data: impl AsMut<[bool]>) -> std::result::Result<(), Error> {
+        let size: i64 = self.shape.iter().product();
+        let data = data.as_mut();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape);
+        }
+        let rc = unsafe {
+            futhark_values_bool_1d(self.ctx.context, self.ptr, data.as_mut_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::Code(rc));
+        }
+        self.ctx.auto_sync();
+        Ok(())
+    }
+
+    /// Load values into a `Vec`
+    pub fn get(&self) -> std::result::Result<Vec<bool>, Error> {
+        let size: i64 = self.shape.iter().product();
+        let mut vec = vec![bool::default(); size as usize];
+        self.values(&mut vec)?;
+        Ok(vec)
+    }
+
+
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, ptr: *mut futhark_bool_1d) -> Self {
+        let len_ptr = unsafe { futhark_shape_bool_1d(ctx.context, ptr) };
+        let mut shape = [0i64; 1];
+        unsafe {
+            for (i, s) in shape.iter_mut().enumerate() {
+                *s = *len_ptr.add(i);
+            }
+        }
+        Self { ctx, shape, ptr }
+    }
+}
+
+
+impl<'a> Drop for ArrayBoolD1<'a> {
+    fn drop(&mut self){
+        unsafe {
+            futhark_free_bool_1d(self.ctx.context, self.ptr as *mut _);
+        }
+    }
+}
+
+#[allow(unused)]
+extern "C" {
+    fn futhark_shape_bool_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_bool_1d
+    ) -> *const i64;
+
+    fn futhark_new_bool_1d(
+        _: *mut futhark_context,
+        _: *const bool,
+        dim0: i64
+    ) -> *mut futhark_bool_1d;
+
+    fn futhark_free_bool_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_bool_1d
+    ) -> std::os::raw::c_int;
+
+    fn futhark_values_bool_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_bool_1d,
+        _: *mut bool
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> ArrayBoolD1<'a> {
+    /// Get a single element of the array, the manifest doesn't include an `index` function so this
+    /// always returns `Error::Unsupported`
+    pub fn index(&self, _index: [i64; 1]) -> std::result::Result<bool, Error> {
+        Err(Error::Unsupported("index"))
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_f16_1d {
+    _private: [u8; 0]
+}
+
+/// Array type with 1 dimensions and f16 elements
+pub struct ArrayF16D1<'a> {
+    ptr: *mut futhark_f16_1d,
+    pub shape: [i64; 1],
+    ctx: &'a Context,
+}
+
+impl<'a> ArrayF16D1<'a> {
+    /// Create a new array of `dims` dimensions and initialize it with the values from `data`
+    pub fn new(ctx: &'a Context, dims: [i64; 1], data: impl AsRef<[half::f16]>) -> std::result::Result<Self, Error> {
+        let size: i64 = dims.iter().product();
+        let data = data.as_ref();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape)
+        }
+        let ptr = unsafe {
+            futhark_new_f16_1d(ctx.context, data.as_ptr(), dims[0])
+        };
+        if ptr.is_null() { return Err(Error::NullPtr); }
+        ctx.auto_sync();
+        Ok(Self {
+            ptr: ptr as *mut _,
+            shape: dims,
+            ctx,
+        })
+    }
+
+    /// Get the array shape
+    pub fn shape(&self) -> &[i64; 1] {
+        &self.shape
+    }
+
+    /// Load values back into a slice
+    pub fn values(&self, mut data: impl AsMut<[half::f16]>) -> std::result::Result<(), Error> {
+        let size: i64 = self.shape.iter().product();
+        let data = data.as_mut();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape);
+        }
+        let rc = unsafe {
+            futhark_values_f16_1d(self.ctx.context, self.ptr, data.as_mut_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::Code(rc));
+        }
+        self.ctx.auto_sync();
+        Ok(())
+    }
+
+    /// Load values into a `Vec`
+    pub fn get(&self) -> std::result::Result<Vec<half::f16>, Error> {
+        let size: i64 = self.shape.iter().product();
+        let mut vec = vec![half::f16::default(); size as usize];
+        self.values(&mut vec)?;
+        Ok(vec)
+    }
+
+
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, ptr: *mut futhark_f16_1d) -> Self {
+        let len_ptr = unsafe { futhark_shape_f16_1d(ctx.context, ptr) };
+        let mut shape = [0i64; 1];
+        unsafe {
+            for (i, s) in shape.iter_mut().enumerate() {
+                *s = *len_ptr.add(i);
+            }
+        }
+        Self { ctx, shape, ptr }
+    }
+}
+
+
+impl<'a> Drop for ArrayF16D1<'a> {
+    fn drop(&mut self){
+        unsafe {
+            futhark_free_f16_1d(self.ctx.context, self.ptr as *mut _);
+        }
+    }
+}
+
+#[allow(unused)]
+extern "C" {
+    fn futhark_shape_f16_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_f16_1d
+    ) -> *const i64;
+
+    fn futhark_new_f16_1d(
+        _: *mut futhark_context,
+        _: *const half::f16,
+        dim0: i64
+    ) -> *mut futhark_f16_1d;
+
+    fn futhark_free_f16_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_f16_1d
+    ) -> std::os::raw::c_int;
+
+    fn futhark_values_f16_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_f16_1d,
+        _: *mut half::f16
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> ArrayF16D1<'a> {
+    /// Get a single element of the array, the manifest doesn't include an `index` function so this
+    /// always returns `Error::Unsupported`
+    pub fn index(&self, _index: [i64; 1]) -> std::result::Result<half::f16, Error> {
+        Err(Error::Unsupported("index"))
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_f32_1d {
+    _private: [u8; 0]
+}
+
+/// Array type with 1 dimensions and f32 elements
+pub struct ArrayF32D1<'a> {
+    ptr: *mut futhark_f32_1d,
+    pub shape: [i64; 1],
+    ctx: &'a Context,
+}
+
+impl<'a> ArrayF32D1<'a> {
+    /// Create a new array of `dims` dimensions and initialize it with the values from `data`
+    pub fn new(ctx: &'a Context, dims: [i64; 1], data: impl AsRef<[f32]>) -> std::result::Result<Self, Error> {
+        let size: i64 = dims.iter().product();
+        let data = data.as_ref();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape)
+        }
+        let ptr = unsafe {
+            futhark_new_f32_1d(ctx.context, data.as_ptr(), dims[0])
+        };
+        if ptr.is_null() { return Err(Error::NullPtr); }
+        ctx.auto_sync();
+        Ok(Self {
+            ptr: ptr as *mut _,
+            shape: dims,
+            ctx,
+        })
+    }
+
+    /// Get the array shape
+    pub fn shape(&self) -> &[i64; 1] {
+        &self.shape
+    }
+
+    /// Load values back into a slice
+    pub fn values(&self, mut data: impl AsMut<[f32]>) -> std::result::Result<(), Error> {
+        let size: i64 = self.shape.iter().product();
+        let data = data.as_mut();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape);
+        }
+        let rc = unsafe {
+            futhark_values_f32_1d(self.ctx.context, self.ptr, data.as_mut_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::Code(rc));
+        }
+        self.ctx.auto_sync();
+        Ok(())
+    }
+
+    /// Load values into a `Vec`
+    pub fn get(&self) -> std::result::Result<Vec<f32>, Error> {
+        let size: i64 = self.shape.iter().product();
+        let mut vec = vec![f32::default(); size as usize];
+        self.values(&mut vec)?;
+        Ok(vec)
+    }
+
+
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, ptr: *mut futhark_f32_1d) -> Self {
+        let len_ptr = unsafe { futhark_shape_f32_1d(ctx.context, ptr) };
+        let mut shape = [0i64; 1];
+        unsafe {
+            for (i, s) in shape.iter_mut().enumerate() {
+                *s = *len_ptr.add(i);
+            }
+        }
+        Self { ctx, shape, ptr }
+    }
+}
+
+
+impl<'a> Drop for ArrayF32D1<'a> {
+    fn drop(&mut self){
+        unsafe {
+            futhark_free_f32_1d(self.ctx.context, self.ptr as *mut _);
+        }
+    }
+}
+
+#[allow(unused)]
+extern "C" {
+    fn futhark_shape_f32_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_f32_1d
+    ) -> *const i64;
+
+    fn futhark_new_f32_1d(
+        _: *mut futhark_context,
+        _: *const f32,
+        dim0: i64
+    ) -> *mut futhark_f32_1d;
+
+    fn futhark_free_f32_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_f32_1d
+    ) -> std::os::raw::c_int;
+
+    fn futhark_values_f32_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_f32_1d,
+        _: *mut f32
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> ArrayF32D1<'a> {
+    /// Get a single element of the array, the manifest doesn't include an `index` function so this
+    /// always returns `Error::Unsupported`
+    pub fn index(&self, _index: [i64; 1]) -> std::result::Result<f32, Error> {
+        Err(Error::Unsupported("index"))
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_f64_1d {
+    _private: [u8; 0]
+}
+
+/// Array type with 1 dimensions and f64 elements
+pub struct ArrayF64D1<'a> {
+    ptr: *mut futhark_f64_1d,
+    pub shape: [i64; 1],
+    ctx: &'a Context,
+}
+
+impl<'a> ArrayF64D1<'a> {
+    /// Create a new array of `dims` dimensions and initialize it with the values from `data`
+    pub fn new(ctx: &'a Context, dims: [i64; 1], data: impl AsRef<[f64]>) -> std::result::Result<Self, Error> {
+        let size: i64 = dims.iter().product();
+        let data = data.as_ref();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape)
+        }
+        let ptr = unsafe {
+            futhark_new_f64_1d(ctx.context, data.as_ptr(), dims[0])
+        };
+        if ptr.is_null() { return Err(Error::NullPtr); }
+        ctx.auto_sync();
+        Ok(Self {
+            ptr: ptr as *mut _,
+            shape: dims,
+            ctx,
+        })
+    }
+
+    /// Get the array shape
+    pub fn shape(&self) -> &[i64; 1] {
+        &self.shape
+    }
+
+    /// Load values back into a slice
+    pub fn values(&self, mut data: impl AsMut<[f64]>) -> std::result::Result<(), Error> {
+        let size: i64 = self.shape.iter().product();
+        let data = data.as_mut();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape);
+        }
+        let rc = unsafe {
+            futhark_values_f64_1d(self.ctx.context, self.ptr, data.as_mut_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::Code(rc));
+        }
+        self.ctx.auto_sync();
+        Ok(())
+    }
+
+    /// Load values into a `Vec`
+    pub fn get(&self) -> std::result::Result<Vec<f64>, Error> {
+        let size: i64 = self.shape.iter().product();
+        let mut vec = vec![f64::default(); size as usize];
+        self.values(&mut vec)?;
+        Ok(vec)
+    }
+
+
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, ptr: *mut futhark_f64_1d) -> Self {
+        let len_ptr = unsafe { futhark_shape_f64_1d(ctx.context, ptr) };
+        let mut shape = [0i64; 1];
+        unsafe {
+            for (i, s) in shape.iter_mut().enumerate() {
+                *s = *len_ptr.add(i);
+            }
+        }
+        Self { ctx, shape, ptr }
+    }
+}
+
+
+impl<'a> Drop for ArrayF64D1<'a> {
+    fn drop(&mut self){
+        unsafe {
+            futhark_free_f64_1d(self.ctx.context, self.ptr as *mut _);
+        }
+    }
+}
+
+#[allow(unused)]
+extern "C" {
+    fn futhark_shape_f64_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_f64_1d
+    ) -> *const i64;
+
+    fn futhark_new_f64_1d(
+        _: *mut futhark_context,
+        _: *const f64,
+        dim0: i64
+    ) -> *mut futhark_f64_1d;
+
+    fn futhark_free_f64_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_f64_1d
+    ) -> std::os::raw::c_int;
+
+    fn futhark_values_f64_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_f64_1d,
+        _: *mut f64
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> ArrayF64D1<'a> {
+    /// Get a single element of the array, the manifest doesn't include an `index` function so this
+    /// always returns `Error::Unsupported`
+    pub fn index(&self, _index: [i64; 1]) -> std::result::Result<f64, Error> {
+        Err(Error::Unsupported("index"))
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_i16_1d {
+    _private: [u8; 0]
+}
+
+/// Array type with 1 dimensions and i16 elements
+pub struct ArrayI16D1<'a> {
+    ptr: *mut futhark_i16_1d,
+    pub shape: [i64; 1],
+    ctx: &'a Context,
+}
+
+impl<'a> ArrayI16D1<'a> {
+    /// Create a new array of `dims` dimensions and initialize it with the values from `data`
+    pub fn new(ctx: &'a Context, dims: [i64; 1], data: impl AsRef<[i16]>) -> std::result::Result<Self, Error> {
+        let size: i64 = dims.iter().product();
+        let data = data.as_ref();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape)
+        }
+        let ptr = unsafe {
+            futhark_new_i16_1d(ctx.context, data.as_ptr(), dims[0])
+        };
+        if ptr.is_null() { return Err(Error::NullPtr); }
+        ctx.auto_sync();
+        Ok(Self {
+            ptr: ptr as *mut _,
+            shape: dims,
+            ctx,
+        })
+    }
+
+    /// Get the array shape
+    pub fn shape(&self) -> &[i64; 1] {
+        &self.shape
+    }
+
+    /// Load values back into a slice
+    pub fn values(&self, mut data: impl AsMut<[i16]>) -> std::result::Result<(), Error> {
+        let size: i64 = self.shape.iter().product();
+        let data = data.as_mut();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape);
+        }
+        let rc = unsafe {
+            futhark_values_i16_1d(self.ctx.context, self.ptr, data.as_mut_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::Code(rc));
+        }
+        self.ctx.auto_sync();
+        Ok(())
+    }
+
+    /// Load values into a `Vec`
+    pub fn get(&self) -> std::result::Result<Vec<i16>, Error> {
+        let size: i64 = self.shape.iter().product();
+        let mut vec = vec![i16::default(); size as usize];
+        self.values(&mut vec)?;
+        Ok(vec)
+    }
+
+
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, ptr: *mut futhark_i16_1d) -> Self {
+        let len_ptr = unsafe { futhark_shape_i16_1d(ctx.context, ptr) };
+        let mut shape = [0i64; 1];
+        unsafe {
+            for (i, s) in shape.iter_mut().enumerate() {
+                *s = *len_ptr.add(i);
+            }
+        }
+        Self { ctx, shape, ptr }
+    }
+}
+
+
+impl<'a> Drop for ArrayI16D1<'a> {
+    fn drop(&mut self){
+        unsafe {
+            futhark_free_i16_1d(self.ctx.context, self.ptr as *mut _);
+        }
+    }
+}
+
+#[allow(unused)]
+extern "C" {
+    fn futhark_shape_i16_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_i16_1d
+    ) -> *const i64;
+
+    fn futhark_new_i16_1d(
+        _: *mut futhark_context,
+        _: *const i16,
+        dim0: i64
+    ) -> *mut futhark_i16_1d;
+
+    fn futhark_free_i16_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_i16_1d
+    ) -> std::os::raw::c_int;
+
+    fn futhark_values_i16_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_i16_1d,
+        _: *mut i16
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> ArrayI16D1<'a> {
+    /// Get a single element of the array, the manifest doesn't include an `index` function so this
+    /// always returns `Error::Unsupported`
+    pub fn index(&self, _index: [i64; 1]) -> std::result::Result<i16, Error> {
+        Err(Error::Unsupported("index"))
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_i32_1d {
+    _private: [u8; 0]
+}
+
+/// Array type with 1 dimensions and i32 elements
+pub struct ArrayI32D1<'a> {
+    ptr: *mut futhark_i32_1d,
+    pub shape: [i64; 1],
+    ctx: &'a Context,
+}
+
+impl<'a> ArrayI32D1<'a> {
+    /// Create a new array of `dims` dimensions and initialize it with the values from `data`
+    pub fn new(ctx: &'a Context, dims: [i64; 1], data: impl AsRef<[i32]>) -> std::result::Result<Self, Error> {
+        let size: i64 = dims.iter().product();
+        let data = data.as_ref();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape)
+        }
+        let ptr = unsafe {
+            futhark_new_i32_1d(ctx.context, data.as_ptr(), dims[0])
+        };
+        if ptr.is_null() { return Err(Error::NullPtr); }
+        ctx.auto_sync();
+        Ok(Self {
+            ptr: ptr as *mut _,
+            shape: dims,
+            ctx,
+        })
+    }
+
+    /// Get the array shape
+    pub fn shape(&self) -> &[i64; 1] {
+        &self.shape
+    }
+
+    /// Load values back into a slice
+    pub fn values(&self, mut data: impl AsMut<[i32]>) -> std::result::Result<(), Error> {
+        let size: i64 = self.shape.iter().product();
+        let data = data.as_mut();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape);
+        }
+        let rc = unsafe {
+            futhark_values_i32_1d(self.ctx.context, self.ptr, data.as_mut_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::Code(rc));
+        }
+        self.ctx.auto_sync();
+        Ok(())
+    }
+
+    /// Load values into a `Vec`
+    pub fn get(&self) -> std::result::Result<Vec<i32>, Error> {
+        let size: i64 = self.shape.iter().product();
+        let mut vec = vec![i32::default(); size as usize];
+        self.values(&mut vec)?;
+        Ok(vec)
+    }
+
+
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, ptr: *mut futhark_i32_1d) -> Self {
+        let len_ptr = unsafe { futhark_shape_i32_1d(ctx.context, ptr) };
+        let mut shape = [0i64; 1];
+        unsafe {
+            for (i, s) in shape.iter_mut().enumerate() {
+                *s = *len_ptr.add(i);
+            }
+        }
+        Self { ctx, shape, ptr }
+    }
+}
+
+
+impl<'a> Drop for ArrayI32D1<'a> {
+    fn drop(&mut self){
+        unsafe {
+            futhark_free_i32_1d(self.ctx.context, self.ptr as *mut _);
+        }
+    }
+}
+
+#[allow(unused)]
+extern "C" {
+    fn futhark_shape_i32_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_i32_1d
+    ) -> *const i64;
+
+    fn futhark_new_i32_1d(
+        _: *mut futhark_context,
+        _: *const i32,
+        dim0: i64
+    ) -> *mut futhark_i32_1d;
+
+    fn futhark_free_i32_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_i32_1d
+    ) -> std::os::raw::c_int;
+
+    fn futhark_values_i32_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_i32_1d,
+        _: *mut i32
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> ArrayI32D1<'a> {
+    /// Get a single element of the array, the manifest doesn't include an `index` function so this
+    /// always returns `Error::Unsupported`
+    pub fn index(&self, _index: [i64; 1]) -> std::result::Result<i32, Error> {
+        Err(Error::Unsupported("index"))
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_i64_1d {
+    _private: [u8; 0]
+}
+
+/// Array type with 1 dimensions and i64 elements
+pub struct ArrayI64D1<'a> {
+    ptr: *mut futhark_i64_1d,
+    pub shape: [i64; 1],
+    ctx: &'a Context,
+}
+
+impl<'a> ArrayI64D1<'a> {
+    /// Create a new array of `dims` dimensions and initialize it with the values from `data`
+    pub fn new(ctx: &'a Context, dims: [i64; 1], data: impl AsRef<[i64]>) -> std::result::Result<Self, Error> {
+        let size: i64 = dims.iter().product();
+        let data = data.as_ref();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape)
+        }
+        let ptr = unsafe {
+            futhark_new_i64_1d(ctx.context, data.as_ptr(), dims[0])
+        };
+        if ptr.is_null() { return Err(Error::NullPtr); }
+        ctx.auto_sync();
+        Ok(Self {
+            ptr: ptr as *mut _,
+            shape: dims,
+            ctx,
+        })
+    }
+
+    /// Get the array shape
+    pub fn shape(&self) -> &[i64; 1] {
+        &self.shape
+    }
+
+    /// Load values back into a slice
+    pub fn values(&self, mut data: impl AsMut<[i64]>) -> std::result::Result<(), Error> {
+        let size: i64 = self.shape.iter().product();
+        let data = data.as_mut();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape);
+        }
+        let rc = unsafe {
+            futhark_values_i64_1d(self.ctx.context, self.ptr, data.as_mut_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::Code(rc));
+        }
+        self.ctx.auto_sync();
+        Ok(())
+    }
+
+    /// Load values into a `Vec`
+    pub fn get(&self) -> std::result::Result<Vec<i64>, Error> {
+        let size: i64 = self.shape.iter().product();
+        let mut vec = vec![i64::default(); size as usize];
+        self.values(&mut vec)?;
+        Ok(vec)
+    }
+
+
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, ptr: *mut futhark_i64_1d) -> Self {
+        let len_ptr = unsafe { futhark_shape_i64_1d(ctx.context, ptr) };
+        let mut shape = [0i64; 1];
+        unsafe {
+            for (i, s) in shape.iter_mut().enumerate() {
+                *s = *len_ptr.add(i);
+            }
+        }
+        Self { ctx, shape, ptr }
+    }
+}
+
+
+impl<'a> Drop for ArrayI64D1<'a> {
+    fn drop(&mut self){
+        unsafe {
+            futhark_free_i64_1d(self.ctx.context, self.ptr as *mut _);
+        }
+    }
+}
+
+#[allow(unused)]
+extern "C" {
+    fn futhark_shape_i64_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_i64_1d
+    ) -> *const i64;
+
+    fn futhark_new_i64_1d(
+        _: *mut futhark_context,
+        _: *const i64,
+        dim0: i64
+    ) -> *mut futhark_i64_1d;
+
+    fn futhark_free_i64_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_i64_1d
+    ) -> std::os::raw::c_int;
+
+    fn futhark_values_i64_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_i64_1d,
+        _: *mut i64
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> ArrayI64D1<'a> {
+    /// Get a single element of the array, the manifest doesn't include an `index` function so this
+    /// always returns `Error::Unsupported`
+    pub fn index(&self, _index: [i64; 1]) -> std::result::Result<i64, Error> {
+        Err(Error::Unsupported("index"))
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_i8_1d {
+    _private: [u8; 0]
+}
+
+/// Array type with 1 dimensions and i8 elements
+pub struct ArrayI8D1<'a> {
+    ptr: *mut futhark_i8_1d,
+    pub shape: [i64; 1],
+    ctx: &'a Context,
+}
+
+impl<'a> ArrayI8D1<'a> {
+    /// Create a new array of `dims` dimensions and initialize it with the values from `data`
+    pub fn new(ctx: &'a Context, dims: [i64; 1], data: impl AsRef<[i8]>) -> std::result::Result<Self, Error> {
+        let size: i64 = dims.iter().product();
+        let data = data.as_ref();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape)
+        }
+        let ptr = unsafe {
+            futhark_new_i8_1d(ctx.context, data.as_ptr(), dims[0])
+        };
+        if ptr.is_null() { return Err(Error::NullPtr); }
+        ctx.auto_sync();
+        Ok(Self {
+            ptr: ptr as *mut _,
+            shape: dims,
+            ctx,
+        })
+    }
+
+    /// Get the array shape
+    pub fn shape(&self) -> &[i64; 1] {
+        &self.shape
+    }
+
+    /// Load values back into a slice
+    pub fn values(&self, mut data: impl AsMut<[i8]>) -> std::result::Result<(), Error> {
+        let size: i64 = self.shape.iter().product();
+        let data = data.as_mut();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape);
+        }
+        let rc = unsafe {
+            futhark_values_i8_1d(self.ctx.context, self.ptr, data.as_mut_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::Code(rc));
+        }
+        self.ctx.auto_sync();
+        Ok(())
+    }
+
+    /// Load values into a `Vec`
+    pub fn get(&self) -> std::result::Result<Vec<i8>, Error> {
+        let size: i64 = self.shape.iter().product();
+        let mut vec = vec![i8::default(); size as usize];
+        self.values(&mut vec)?;
+        Ok(vec)
+    }
+
+
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, ptr: *mut futhark_i8_1d) -> Self {
+        let len_ptr = unsafe { futhark_shape_i8_1d(ctx.context, ptr) };
+        let mut shape = [0i64; 1];
+        unsafe {
+            for (i, s) in shape.iter_mut().enumerate() {
+                *s = *len_ptr.add(i);
+            }
+        }
+        Self { ctx, shape, ptr }
+    }
+}
+
+
+impl<'a> Drop for ArrayI8D1<'a> {
+    fn drop(&mut self){
+        unsafe {
+            futhark_free_i8_1d(self.ctx.context, self.ptr as *mut _);
+        }
+    }
+}
+
+#[allow(unused)]
+extern "C" {
+    fn futhark_shape_i8_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_i8_1d
+    ) -> *const i64;
+
+    fn futhark_new_i8_1d(
+        _: *mut futhark_context,
+        _: *const i8,
+        dim0: i64
+    ) -> *mut futhark_i8_1d;
+
+    fn futhark_free_i8_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_i8_1d
+    ) -> std::os::raw::c_int;
+
+    fn futhark_values_i8_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_i8_1d,
+        _: *mut i8
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> ArrayI8D1<'a> {
+    /// Get a single element of the array, the manifest doesn't include an `index` function so this
+    /// always returns `Error::Unsupported`
+    pub fn index(&self, _index: [i64; 1]) -> std::result::Result<i8, Error> {
+        Err(Error::Unsupported("index"))
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_u16_1d {
+    _private: [u8; 0]
+}
+
+/// Array type with 1 dimensions and u16 elements
+pub struct ArrayU16D1<'a> {
+    ptr: *mut futhark_u16_1d,
+    pub shape: [i64; 1],
+    ctx: &'a Context,
+}
+
+impl<'a> ArrayU16D1<'a> {
+    /// Create a new array of `dims` dimensions and initialize it with the values from `data`
+    pub fn new(ctx: &'a Context, dims: [i64; 1], data: impl AsRef<[u16]>) -> std::result::Result<Self, Error> {
+        let size: i64 = dims.iter().product();
+        let data = data.as_ref();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape)
+        }
+        let ptr = unsafe {
+            futhark_new_u16_1d(ctx.context, data.as_ptr(), dims[0])
+        };
+        if ptr.is_null() { return Err(Error::NullPtr); }
+        ctx.auto_sync();
+        Ok(Self {
+            ptr: ptr as *mut _,
+            shape: dims,
+            ctx,
+        })
+    }
+
+    /// Get the array shape
+    pub fn shape(&self) -> &[i64; 1] {
+        &self.shape
+    }
+
+    /// Load values back into a slice
+    pub fn values(&self, mut data: impl AsMut<[u16]>) -> std::result::Result<(), Error> {
+        let size: i64 = self.shape.iter().product();
+        let data = data.as_mut();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape);
+        }
+        let rc = unsafe {
+            futhark_values_u16_1d(self.ctx.context, self.ptr, data.as_mut_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::Code(rc));
+        }
+        self.ctx.auto_sync();
+        Ok(())
+    }
+
+    /// Load values into a `Vec`
+    pub fn get(&self) -> std::result::Result<Vec<u16>, Error> {
+        let size: i64 = self.shape.iter().product();
+        let mut vec = vec![u16::default(); size as usize];
+        self.values(&mut vec)?;
+        Ok(vec)
+    }
+
+
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, ptr: *mut futhark_u16_1d) -> Self {
+        let len_ptr = unsafe { futhark_shape_u16_1d(ctx.context, ptr) };
+        let mut shape = [0i64; 1];
+        unsafe {
+            for (i, s) in shape.iter_mut().enumerate() {
+                *s = *len_ptr.add(i);
+            }
+        }
+        Self { ctx, shape, ptr }
+    }
+}
+
+
+impl<'a> Drop for ArrayU16D1<'a> {
+    fn drop(&mut self){
+        unsafe {
+            futhark_free_u16_1d(self.ctx.context, self.ptr as *mut _);
+        }
+    }
+}
+
+#[allow(unused)]
+extern "C" {
+    fn futhark_shape_u16_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_u16_1d
+    ) -> *const i64;
+
+    fn futhark_new_u16_1d(
+        _: *mut futhark_context,
+        _: *const u16,
+        dim0: i64
+    ) -> *mut futhark_u16_1d;
+
+    fn futhark_free_u16_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_u16_1d
+    ) -> std::os::raw::c_int;
+
+    fn futhark_values_u16_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_u16_1d,
+        _: *mut u16
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> ArrayU16D1<'a> {
+    /// Get a single element of the array, the manifest doesn't include an `index` function so this
+    /// always returns `Error::Unsupported`
+    pub fn index(&self, _index: [i64; 1]) -> std::result::Result<u16, Error> {
+        Err(Error::Unsupported("index"))
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_u32_1d {
+    _private: [u8; 0]
+}
+
+/// Array type with 1 dimensions and u32 elements
+pub struct ArrayU32D1<'a> {
+    ptr: *mut futhark_u32_1d,
+    pub shape: [i64; 1],
+    ctx: &'a Context,
+}
+
+impl<'a> ArrayU32D1<'a> {
+    /// Create a new array of `dims` dimensions and initialize it with the values from `data`
+    pub fn new(ctx: &'a Context, dims: [i64; 1], data: impl AsRef<[u32]>) -> std::result::Result<Self, Error> {
+        let size: i64 = dims.iter().product();
+        let data = data.as_ref();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape)
+        }
+        let ptr = unsafe {
+            futhark_new_u32_1d(ctx.context, data.as_ptr(), dims[0])
+        };
+        if ptr.is_null() { return Err(Error::NullPtr); }
+        ctx.auto_sync();
+        Ok(Self {
+            ptr: ptr as *mut _,
+            shape: dims,
+            ctx,
+        })
+    }
+
+    /// Get the array shape
+    pub fn shape(&self) -> &[i64; 1] {
+        &self.shape
+    }
+
+    /// Load values back into a slice
+    pub fn values(&self, mut data: impl AsMut<[u32]>) -> std::result::Result<(), Error> {
+        let size: i64 = self.shape.iter().product();
+        let data = data.as_mut();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape);
+        }
+        let rc = unsafe {
+            futhark_values_u32_1d(self.ctx.context, self.ptr, data.as_mut_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::Code(rc));
+        }
+        self.ctx.auto_sync();
+        Ok(())
+    }
+
+    /// Load values into a `Vec`
+    pub fn get(&self) -> std::result::Result<Vec<u32>, Error> {
+        let size: i64 = self.shape.iter().product();
+        let mut vec = vec![u32::default(); size as usize];
+        self.values(&mut vec)?;
+        Ok(vec)
+    }
+
+
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, ptr: *mut futhark_u32_1d) -> Self {
+        let len_ptr = unsafe { futhark_shape_u32_1d(ctx.context, ptr) };
+        let mut shape = [0i64; 1];
+        unsafe {
+            for (i, s) in shape.iter_mut().enumerate() {
+                *s = *len_ptr.add(i);
+            }
+        }
+        Self { ctx, shape, ptr }
+    }
+}
+
+
+impl<'a> Drop for ArrayU32D1<'a> {
+    fn drop(&mut self){
+        unsafe {
+            futhark_free_u32_1d(self.ctx.context, self.ptr as *mut _);
+        }
+    }
+}
+
+#[allow(unused)]
+extern "C" {
+    fn futhark_shape_u32_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_u32_1d
+    ) -> *const i64;
+
+    fn futhark_new_u32_1d(
+        _: *mut futhark_context,
+        _: *const u32,
+        dim0: i64
+    ) -> *mut futhark_u32_1d;
+
+    fn futhark_free_u32_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_u32_1d
+    ) -> std::os::raw::c_int;
+
+    fn futhark_values_u32_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_u32_1d,
+        _: *mut u32
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> ArrayU32D1<'a> {
+    /// Get a single element of the array, the manifest doesn't include an `index` function so this
+    /// always returns `Error::Unsupported`
+    pub fn index(&self, _index: [i64; 1]) -> std::result::Result<u32, Error> {
+        Err(Error::Unsupported("index"))
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_u64_1d {
+    _private: [u8; 0]
+}
+
+/// Array type with 1 dimensions and u64 elements
+pub struct ArrayU64D1<'a> {
+    ptr: *mut futhark_u64_1d,
+    pub shape: [i64; 1],
+    ctx: &'a Context,
+}
+
+impl<'a> ArrayU64D1<'a> {
+    /// Create a new array of `dims` dimensions and initialize it with the values from `data`
+    pub fn new(ctx: &'a Context, dims: [i64; 1], data: impl AsRef<[u64]>) -> std::result::Result<Self, Error> {
+        let size: i64 = dims.iter().product();
+        let data = data.as_ref();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape)
+        }
+        let ptr = unsafe {
+            futhark_new_u64_1d(ctx.context, data.as_ptr(), dims[0])
+        };
+        if ptr.is_null() { return Err(Error::NullPtr); }
+        ctx.auto_sync();
+        Ok(Self {
+            ptr: ptr as *mut _,
+            shape: dims,
+            ctx,
+        })
+    }
+
+    /// Get the array shape
+    pub fn shape(&self) -> &[i64; 1] {
+        &self.shape
+    }
+
+    /// Load values back into a slice
+    pub fn values(&self, mut data: impl AsMut<[u64]>) -> std::result::Result<(), Error> {
+        let size: i64 = self.shape.iter().product();
+        let data = data.as_mut();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape);
+        }
+        let rc = unsafe {
+            futhark_values_u64_1d(self.ctx.context, self.ptr, data.as_mut_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::Code(rc));
+        }
+        self.ctx.auto_sync();
+        Ok(())
+    }
+
+    /// Load values into a `Vec`
+    pub fn get(&self) -> std::result::Result<Vec<u64>, Error> {
+        let size: i64 = self.shape.iter().product();
+        let mut vec = vec![u64::default(); size as usize];
+        self.values(&mut vec)?;
+        Ok(vec)
+    }
+
+
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, ptr: *mut futhark_u64_1d) -> Self {
+        let len_ptr = unsafe { futhark_shape_u64_1d(ctx.context, ptr) };
+        let mut shape = [0i64; 1];
+        unsafe {
+            for (i, s) in shape.iter_mut().enumerate() {
+                *s = *len_ptr.add(i);
+            }
+        }
+        Self { ctx, shape, ptr }
+    }
+}
+
+
+impl<'a> Drop for ArrayU64D1<'a> {
+    fn drop(&mut self){
+        unsafe {
+            futhark_free_u64_1d(self.ctx.context, self.ptr as *mut _);
+        }
+    }
+}
+
+#[allow(unused)]
+extern "C" {
+    fn futhark_shape_u64_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_u64_1d
+    ) -> *const i64;
+
+    fn futhark_new_u64_1d(
+        _: *mut futhark_context,
+        _: *const u64,
+        dim0: i64
+    ) -> *mut futhark_u64_1d;
+
+    fn futhark_free_u64_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_u64_1d
+    ) -> std::os::raw::c_int;
+
+    fn futhark_values_u64_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_u64_1d,
+        _: *mut u64
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> ArrayU64D1<'a> {
+    /// Get a single element of the array, the manifest doesn't include an `index` function so this
+    /// always returns `Error::Unsupported`
+    pub fn index(&self, _index: [i64; 1]) -> std::result::Result<u64, Error> {
+        Err(Error::Unsupported("index"))
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_u8_1d {
+    _private: [u8; 0]
+}
+
+/// Array type with 1 dimensions and u8 elements
+pub struct ArrayU8D1<'a> {
+    ptr: *mut futhark_u8_1d,
+    pub shape: [i64; 1],
+    ctx: &'a Context,
+}
+
+impl<'a> ArrayU8D1<'a> {
+    /// Create a new array of `dims` dimensions and initialize it with the values from `data`
+    pub fn new(ctx: &'a Context, dims: [i64; 1], data: impl AsRef<[u8]>) -> std::result::Result<Self, Error> {
+        let size: i64 = dims.iter().product();
+        let data = data.as_ref();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape)
+        }
+        let ptr = unsafe {
+            futhark_new_u8_1d(ctx.context, data.as_ptr(), dims[0])
+        };
+        if ptr.is_null() { return Err(Error::NullPtr); }
+        ctx.auto_sync();
+        Ok(Self {
+            ptr: ptr as *mut _,
+            shape: dims,
+            ctx,
+        })
+    }
+
+    /// Get the array shape
+    pub fn shape(&self) -> &[i64; 1] {
+        &self.shape
+    }
+
+    /// Load values back into a slice
+    pub fn values(&self, mut data: impl AsMut<[u8]>) -> std::result::Result<(), Error> {
+        let size: i64 = self.shape.iter().product();
+        let data = data.as_mut();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape);
+        }
+        let rc = unsafe {
+            futhark_values_u8_1d(self.ctx.context, self.ptr, data.as_mut_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::Code(rc));
+        }
+        self.ctx.auto_sync();
+        Ok(())
+    }
+
+    /// Load values into a `Vec`
+    pub fn get(&self) -> std::result::Result<Vec<u8>, Error> {
+        let size: i64 = self.shape.iter().product();
+        let mut vec = vec![u8::default(); size as usize];
+        self.values(&mut vec)?;
+        Ok(vec)
+    }
+
+
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, ptr: *mut futhark_u8_1d) -> Self {
+        let len_ptr = unsafe { futhark_shape_u8_1d(ctx.context, ptr) };
+        let mut shape = [0i64; 1];
+        unsafe {
+            for (i, s) in shape.iter_mut().enumerate() {
+                *s = *len_ptr.add(i);
+            }
+        }
+        Self { ctx, shape, ptr }
+    }
+}
+
+
+impl<'a> Drop for ArrayU8D1<'a> {
+    fn drop(&mut self){
+        unsafe {
+            futhark_free_u8_1d(self.ctx.context, self.ptr as *mut _);
+        }
+    }
+}
+
+#[allow(unused)]
+extern "C" {
+    fn futhark_shape_u8_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_u8_1d
+    ) -> *const i64;
+
+    fn futhark_new_u8_1d(
+        _: *mut futhark_context,
+        _: *const u8,
+        dim0: i64
+    ) -> *mut futhark_u8_1d;
+
+    fn futhark_free_u8_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_u8_1d
+    ) -> std::os::raw::c_int;
+
+    fn futhark_values_u8_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_u8_1d,
+        _: *mut u8
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> ArrayU8D1<'a> {
+    /// Get a single element of the array, the manifest doesn't include an `index` function so this
+    /// always returns `Error::Unsupported`
+    pub fn index(&self, _index: [i64; 1]) -> std::result::Result<u8, Error> {
+        Err(Error::Unsupported("index"))
+    }
+}
+
+impl Context {
+    /// Entry point: flatten
+    pub fn flatten(&self, input0: &ArrayF32D3) -> Result<ArrayF32D2<'_>, Error> {
+        let mut out0 = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_entry_flatten(self.context, out0.as_mut_ptr(), input0.ptr as *mut _)
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+    
+        #[allow(unused_unsafe)]
+        unsafe {
+            Ok(ArrayF32D2::from_ptr(self, out0.assume_init()))
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_entry_flatten(
+        _: *mut futhark_context,
+        out0: *mut *mut futhark_f32_2d, input0: *const futhark_f32_3d
+    ) -> std::os::raw::c_int;
+}
+impl Context {
+    /// Entry point: lengths
+    pub fn lengths(&self, input0: &ArrayI8D1, input1: &ArrayI16D1, input2: &ArrayI32D1, input3: &ArrayI64D1, input4: &ArrayU8D1, input5: &ArrayU16D1, input6: &ArrayU32D1, input7: &ArrayU64D1, input8: &ArrayF16D1, input9: &ArrayF32D1, input10: &ArrayF64D1, input11: &ArrayBoolD1) -> Result<i64, Error> {
+        let mut out0 = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_entry_lengths(self.context, out0.as_mut_ptr(), input0.ptr as *mut _, input1.ptr as *mut _, input2.ptr as *mut _, input3.ptr as *mut _, input4.ptr as *mut _, input5.ptr as *mut _, input6.ptr as *mut _, input7.ptr as *mut _, input8.ptr as *mut _, input9.ptr as *mut _, input10.ptr as *mut _, input11.ptr as *mut _)
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+    
+        #[allow(unused_unsafe)]
+        unsafe {
+            Ok(out0.assume_init())
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_entry_lengths(
+        _: *mut futhark_context,
+        out0: *mut i64, input0: *const futhark_i8_1d, input1: *const futhark_i16_1d, input2: *const futhark_i32_1d, input3: *const futhark_i64_1d, input4: *const futhark_u8_1d, input5: *const futhark_u16_1d, input6: *const futhark_u32_1d, input7: *const futhark_u64_1d, input8: *const futhark_f16_1d, input9: *const futhark_f32_1d, input10: *const futhark_f64_1d, input11: *const futhark_bool_1d
+    ) -> std::os::raw::c_int;
+}
+/// Outputs of the `sum_rows` entry point
+pub struct SumRowsOutput<'a> {
+    pub out0: ArrayF32D1<'a>,
+    pub out1: f32,
+}
+
+impl Context {
+    /// Entry point: sum_rows
+    pub fn sum_rows(&self, input0: &ArrayF32D2) -> Result<SumRowsOutput<'_>, Error> {
+        let mut out0 = std::mem::MaybeUninit::zeroed();
+        let mut out1 = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_entry_sum_rows(self.context, out0.as_mut_ptr(), out1.as_mut_ptr(), input0.ptr as *mut _)
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+    
+        #[allow(unused_unsafe)]
+        unsafe {
+            Ok(SumRowsOutput { out0: ArrayF32D1::from_ptr(self, out0.assume_init()), out1: out1.assume_init() })
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_entry_sum_rows(
+        _: *mut futhark_context,
+        out0: *mut *mut futhark_f32_1d, out1: *mut f32, input0: *const futhark_f32_2d
+    ) -> std::os::raw::c_int;
+}
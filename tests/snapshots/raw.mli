@@ -0,0 +1,138 @@
+(* Generated by futhark-bindgen *)
+
+open! Signed
+open! Unsigned
+
+type error = 
+  | InvalidShape of int * int 
+  | NullPtr 
+  | Code of int
+  | UseAfterFree of [`context | `array | `opaque]
+  | IndexOutOfBounds
+  | Unsupported of string
+  | InvalidTuning of string
+
+exception Error of error
+
+module Context: sig
+  type t
+  (** Futhark context *)
+
+  val v: ?debug:bool -> ?log:bool -> ?profile:bool -> ?cache_file:string -> ?auto_sync:bool -> ?tuning_params:(string * int) list -> ?tuning_file:string -> ?device:string -> ?default_group_size:int -> ?default_tile_size:int -> ?unified_memory:bool -> unit -> t
+  (** Create a new context, [tuning_params] are applied after the parameters loaded from [tuning_file] *)
+  
+  val sync: t -> unit
+  (** Sync the context, if auto_sync is enabled this is not needed *)
+  
+  val free: t -> unit
+  (** Free the context *)
+  
+  val clear_caches: t -> unit
+  (** Clear Futhark caches *)
+
+  val get_error: t -> string option
+  (** Get last error message or None *)
+
+  val report: t -> string option
+  val pause_profiling: t -> unit
+  val unpause_profiling: t -> unit
+end
+module Array_f32_2d: sig
+  type t
+  (** Futhark array *)
+
+  type kind = (float, Bigarray.float32_elt) Bigarray.kind
+  (** The Bigarray kind that matches the correct element type for this array *)
+
+  val kind: kind
+
+  val shape: t -> int array
+  (** Array shape *)
+
+  val v: Context.t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Genarray.t -> t
+  (** Initialize an array with the data from the provided bigarray *)
+
+  val values: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Genarray.t -> unit
+  (** Load the values into the provided bigarray *)
+
+  val values_array1: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Array1.t -> unit
+  (** Similar to [values] but takes an [Array1] instead of [Genarray] *)
+
+  val get: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Genarray.t
+  (** Get a new bigarray with the values loaded *)
+
+  val get_array1: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Array1.t
+  (** Similar to [get] but returns an [Array1] *)
+
+  val index: t -> int array -> float
+  (** Get a single element of the array without copying the whole array, raises
+      [Error (Unsupported "index")] if the manifest doesn't include an [index] function *)
+
+  val of_array: Context.t -> int array -> (float) array -> t
+  (** Create [t] from an array of values *)
+
+  val of_array1: Context.t -> int array ->  (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Array1.t-> t
+  (** Create [t] from an [Array1] instead of [Genarray] *)
+
+  val of_raw: Context.t -> int array -> UInt64.t -> t
+  (** Create [t] using existing backend memory, the memory is not copied and must stay valid
+      while [t] is alive *)
+
+  val to_raw: t -> UInt64.t
+  (** Get the backend memory used by the array, it is only valid while the array is alive *)
+
+  val free: t -> unit
+  (** Free the array *)
+end
+
+module Array_f32_1d: sig
+  type t
+  (** Futhark array *)
+
+  type kind = (float, Bigarray.float32_elt) Bigarray.kind
+  (** The Bigarray kind that matches the correct element type for this array *)
+
+  val kind: kind
+
+  val shape: t -> int array
+  (** Array shape *)
+
+  val v: Context.t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Genarray.t -> t
+  (** Initialize an array with the data from the provided bigarray *)
+
+  val values: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Genarray.t -> unit
+  (** Load the values into the provided bigarray *)
+
+  val values_array1: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Array1.t -> unit
+  (** Similar to [values] but takes an [Array1] instead of [Genarray] *)
+
+  val get: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Genarray.t
+  (** Get a new bigarray with the values loaded *)
+
+  val get_array1: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Array1.t
+  (** Similar to [get] but returns an [Array1] *)
+
+  val index: t -> int array -> float
+  (** Get a single element of the array without copying the whole array, raises
+      [Error (Unsupported "index")] if the manifest doesn't include an [index] function *)
+
+  val of_array: Context.t -> int array -> (float) array -> t
+  (** Create [t] from an array of values *)
+
+  val of_array1: Context.t -> int array ->  (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Array1.t-> t
+  (** Create [t] from an [Array1] instead of [Genarray] *)
+
+  val of_raw: Context.t -> int array -> UInt64.t -> t
+  (** Create [t] using existing backend memory, the memory is not copied and must stay valid
+      while [t] is alive *)
+
+  val to_raw: t -> UInt64.t
+  (** Get the backend memory used by the array, it is only valid while the array is alive *)
+
+  val free: t -> unit
+  (** Free the array *)
+end
+
+(** Entry point: matvec *)
+val matvec: Context.t -> Array_f32_2d.t -> Array_f32_1d.t -> (Array_f32_1d.t)
+
@@ -0,0 +1,756 @@
+// Generated by futhark-bindgen
+
+#[derive(Debug)]
+pub enum Error {
+    Code(std::os::raw::c_int),
+    NullPtr,
+    InvalidShape,
+    IndexOutOfBounds,
+    Unsupported(&'static str),
+    MissingField(&'static str),
+    InvalidTuning(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::Code(code) => write!(fmt, "Futhark error code: {code}"),
+            Error::NullPtr => write!(fmt, "NULL pointer encountered"),
+            Error::InvalidShape => write!(fmt, "Invalid image shape"),
+            Error::IndexOutOfBounds => write!(fmt, "Index out of bounds"),
+            Error::Unsupported(op) => write!(fmt, "{op} is not supported by this version of Futhark"),
+            Error::MissingField(field) => write!(fmt, "Missing field: {field}"),
+            Error::InvalidTuning(msg) => write!(fmt, "Invalid tuning parameter: {msg}"),
+        }
+    } 
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Debug, Clone)]
+pub struct Options {
+    debug: bool,
+    profile: bool,
+    logging: bool,
+    cache_file: std::option::Option<std::ffi::CString>,
+    device: std::option::Option<std::ffi::CString>,
+    default_group_size: std::option::Option<u32>,
+    default_tile_size: std::option::Option<u32>,
+    unified_memory: std::option::Option<bool>,
+    tuning_params: Vec<(std::ffi::CString, usize)>,
+    tuning_file: std::option::Option<std::path::PathBuf>,
+    auto_sync: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options::new()
+    }
+}
+
+impl Options {
+    /// Create new `Options` with default settings
+    pub fn new() -> Self {
+        Options {
+            debug: false,
+            profile: false,
+            logging: false,
+            cache_file: None,
+            device: None,
+            default_group_size: None,
+            default_tile_size: None,
+            unified_memory: None,
+            tuning_params: Vec::new(),
+            tuning_file: None,
+            auto_sync: true,
+        }
+    }
+
+    /// Enable debug
+    pub fn debug(mut self) -> Self {
+        self.debug = true;
+        self
+    }
+
+    /// Enable profiling
+    pub fn profile(mut self) -> Self {
+        self.profile = true;
+        self
+    }
+
+    /// Enable logging
+    pub fn log(mut self) -> Self {
+        self.logging = true;
+        self
+    }
+
+    /// Set Futhark cache file
+    pub fn cache_file(mut self, s: impl AsRef<str>) -> Self {
+        self.cache_file = Some(std::ffi::CString::new(s.as_ref()).expect("Invalid cache file"));
+        self
+    }
+
+    pub fn auto_sync(mut self, sync: bool) -> Self {
+        self.auto_sync = sync;
+        self
+    }
+
+    /// Set a tuning parameter, these are applied after the parameters from the tuning file
+    pub fn tuning_param(mut self, name: impl AsRef<str>, value: usize) -> Self {
+        let name = std::ffi::CString::new(name.as_ref()).expect("Invalid tuning parameter");
+        self.tuning_params.push((name, value));
+        self
+    }
+
+    /// Load tuning parameters from a `.tuning` file when the context is created
+    pub fn tuning_file(mut self, path: impl AsRef<std::path::Path>) -> Self {
+        self.tuning_file = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    // Read a tuning file, every line has the form `name=value`
+    fn read_tuning_file(path: &std::path::Path) -> std::result::Result<Vec<(std::ffi::CString, usize)>, Error> {
+        let s = std::fs::read_to_string(path)
+            .map_err(|e| Error::InvalidTuning(format!("{}: {e}", path.display())))?;
+        s.lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let invalid = || Error::InvalidTuning(line.to_string());
+                let (name, value) = line.split_once('=').ok_or_else(invalid)?;
+                let value = value.trim().parse().map_err(|_| invalid())?;
+                let name = std::ffi::CString::new(name.trim()).map_err(|_| invalid())?;
+                Ok((name, value))
+            })
+            .collect()
+    }
+
+
+    pub fn device(mut self, s: impl AsRef<str>) -> Options { self.device = Some(std::ffi::CString::new(s.as_ref()).expect("Invalid device")); self }
+
+    /// Set the default group size
+    pub fn default_group_size(mut self, n: u32) -> Options { self.default_group_size = Some(n); self }
+
+    /// Set the default tile size
+    pub fn default_tile_size(mut self, n: u32) -> Options { self.default_tile_size = Some(n); self }
+
+    /// Enable or disable unified memory, by default it's used when supported by the device
+    pub fn unified_memory(mut self, enable: bool) -> Options { self.unified_memory = Some(enable); self }
+}
+
+/// Futhark context
+pub struct Context {
+    config: *mut futhark_context_config,
+    context: *mut futhark_context,
+    auto_sync: bool,
+    _cache_file: std::option::Option<std::ffi::CString>,
+}
+
+impl Context {
+    /// Create a new context with default options
+    pub fn new() -> std::result::Result<Self, Error> {
+        unsafe {
+            let config = futhark_context_config_new();
+            if config.is_null() { return Err(Error::NullPtr) }
+            let context = futhark_context_new(config);
+            if context.is_null() {
+                futhark_context_config_free(config);
+                return Err(Error::NullPtr);
+            }
+            Ok(Context { config, context, auto_sync: true, _cache_file: None })
+        }
+    }
+
+    /// Create a new context with custom options
+    pub fn new_with_options(options: Options) -> std::result::Result<Self, Error> {
+        let mut tuning_params = match &options.tuning_file {
+            Some(path) => Options::read_tuning_file(path)?,
+            None => Vec::new(),
+        };
+        tuning_params.extend(options.tuning_params.iter().cloned());
+
+        unsafe {
+            let config = futhark_context_config_new();
+            if config.is_null() { return Err(Error::NullPtr) }
+
+            for (name, value) in &tuning_params {
+                if futhark_context_config_set_tuning_param(config, name.as_ptr(), *value) != 0 {
+                    futhark_context_config_free(config);
+                    return Err(Error::InvalidTuning(name.to_string_lossy().into_owned()));
+                }
+            }
+
+            futhark_context_config_set_debugging(config, options.debug as std::os::raw::c_int);
+            futhark_context_config_set_profiling(config, options.profile as std::os::raw::c_int);
+            futhark_context_config_set_logging(config, options.logging as std::os::raw::c_int);
+
+            if let Some(c) = &options.cache_file {
+                futhark_context_config_set_cache_file(config, c.as_ptr());
+            }
+
+            if let Some(d) = &options.device { futhark_context_config_set_device(config, d.as_ptr()); }
+            if let Some(n) = options.default_group_size { futhark_context_config_set_default_group_size(config, n as std::os::raw::c_int); }
+            if let Some(n) = options.default_tile_size { futhark_context_config_set_default_tile_size(config, n as std::os::raw::c_int); }
+            if let Some(x) = options.unified_memory { futhark_context_config_set_unified_memory(config, x as std::os::raw::c_int); }
+
+            let context = futhark_context_new(config);
+            if context.is_null() {
+                futhark_context_config_free(config);
+                return Err(Error::NullPtr);
+            }
+            Ok(Context { config, context, auto_sync: options.auto_sync, _cache_file: options.cache_file })
+        }
+    }
+
+    /// Sync the context, if `auto_sync` is enabled this shouldn't be needed
+    pub fn sync(&self) {
+        unsafe { futhark_context_sync(self.context); }
+    }
+
+    /// Sync if `auto_sync` is enabled, otherwise this is a noop
+    pub fn auto_sync(&self) {
+        if self.auto_sync {
+            self.sync();
+        }
+    }
+
+    /// Clear Futhark caches
+    pub fn clear_caches(&self) -> std::result::Result<(), Error> {
+        let rc = unsafe {
+            futhark_context_clear_caches(self.context)
+        };
+        if rc != 0 { return Err(Error::Code(rc)) }
+        Ok(())
+    }
+
+    /// Pause Futhark profiling
+    pub fn pause_profiling(&self) {
+        unsafe {
+            futhark_context_pause_profiling(self.context);
+        }
+    }
+
+    /// Resume profiling
+    pub fn unpause_profiling(&self) {
+        unsafe {
+            futhark_context_unpause_profiling(self.context);
+        }
+    }
+
+    /// Get the last error message or None
+    pub fn get_error(&self) -> std::option::Option<String> {
+        unsafe {
+            let s = futhark_context_get_error(self.context);
+            if s.is_null() { return None }
+            let r = std::ffi::CStr::from_ptr(s).to_string_lossy().to_string();
+            free(s as *mut _);
+            Some(r)
+        }
+    }
+
+    pub fn report(&self) -> std::option::Option<String> {
+        unsafe {
+            let s = futhark_context_report(self.context);
+            if s.is_null() { return None }
+            let r = std::ffi::CStr::from_ptr(s).to_string_lossy().to_string();
+            free(s as *mut _);
+            Some(r)
+        }
+    }
+}
+
+impl Drop for Context {
+    fn drop(&mut self) {
+        unsafe {
+            futhark_context_sync(self.context);
+            futhark_context_free(self.context);
+            futhark_context_config_free(self.config);
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_context_config {
+    _private: [u8; 0]
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_context {
+    _private: [u8; 0]
+}
+
+extern "C" {
+    fn futhark_context_config_new() -> *mut futhark_context_config;
+    fn futhark_context_config_free(
+        _: *mut futhark_context_config
+    );
+    fn futhark_context_config_set_debugging(
+        _: *mut futhark_context_config,
+        _: std::os::raw::c_int
+    );
+
+    fn futhark_context_config_set_profiling(
+        _: *mut futhark_context_config,
+        _: std::os::raw::c_int
+    );
+
+    fn futhark_context_config_set_logging(
+        _: *mut futhark_context_config,
+        _: std::os::raw::c_int
+    );
+
+    fn futhark_context_config_set_cache_file(
+        _: *mut futhark_context_config,
+        _: *const std::os::raw::c_char,
+    );
+
+    fn futhark_context_config_set_tuning_param(
+        _: *mut futhark_context_config,
+        _: *const std::os::raw::c_char,
+        _: usize,
+    ) -> std::os::raw::c_int;
+
+    fn futhark_context_new(
+        _: *mut futhark_context_config
+    ) -> *mut futhark_context;
+
+    fn futhark_context_free(
+        _: *mut futhark_context
+    );
+
+    fn futhark_context_sync(
+        _: *mut futhark_context,
+    ) -> std::os::raw::c_int;
+
+    fn futhark_context_clear_caches(
+        _: *mut futhark_context,
+    ) -> std::os::raw::c_int;
+
+    fn futhark_context_pause_profiling(
+        _: *mut futhark_context
+    );
+
+    fn futhark_context_unpause_profiling(
+        _: *mut futhark_context
+    );
+
+    fn futhark_context_get_error(
+        _: *mut futhark_context
+    ) -> *mut std::os::raw::c_char;
+
+    fn futhark_context_report(
+        _: *mut futhark_context
+    ) -> *mut std::os::raw::c_char;
+
+    fn free(_: *mut std::ffi::c_void);
+
+    fn futhark_context_config_set_device(_: *mut futhark_context_config, _: *const std::os::raw::c_char);
+    fn futhark_context_config_set_default_group_size(_: *mut futhark_context_config, _: std::os::raw::c_int);
+    fn futhark_context_config_set_default_tile_size(_: *mut futhark_context_config, _: std::os::raw::c_int);
+    fn futhark_context_config_set_unified_memory(_: *mut futhark_context_config, _: std::os::raw::c_int);
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_f32_2d {
+    _private: [u8; 0]
+}
+
+/// Array type with 2 dimensions and f32 elements
+pub struct ArrayF32D2<'a> {
+    ptr: *mut futhark_f32_2d,
+    pub shape: [i64; 2],
+    ctx: &'a Context,
+}
+
+impl<'a> ArrayF32D2<'a> {
+    /// Create a new array of `dims` dimensions and initialize it with the values from `data`
+    pub fn new(ctx: &'a Context, dims: [i64; 2], data: impl AsRef<[f32]>) -> std::result::Result<Self, Error> {
+        let size: i64 = dims.iter().product();
+        let data = data.as_ref();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape)
+        }
+        let ptr = unsafe {
+            futhark_new_f32_2d(ctx.context, data.as_ptr(), dims[0], dims[1])
+        };
+        if ptr.is_null() { return Err(Error::NullPtr); }
+        ctx.auto_sync();
+        Ok(Self {
+            ptr: ptr as *mut _,
+            shape: dims,
+            ctx,
+        })
+    }
+
+    /// Get the array shape
+    pub fn shape(&self) -> &[i64; 2] {
+        &self.shape
+    }
+
+    /// Load values back into a slice
+    pub fn values(&self, mut data: impl AsMut<[f32]>) -> std::result::Result<(), Error> {
+        let size: i64 = self.shape.iter().product();
+        let data = data.as_mut();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape);
+        }
+        let rc = unsafe {
+            futhark_values_f32_2d(self.ctx.context, self.ptr, data.as_mut_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::Code(rc));
+        }
+        self.ctx.auto_sync();
+        Ok(())
+    }
+
+    /// Load values into a `Vec`
+    pub fn get(&self) -> std::result::Result<Vec<f32>, Error> {
+        let size: i64 = self.shape.iter().product();
+        let mut vec = vec![f32::default(); size as usize];
+        self.values(&mut vec)?;
+        Ok(vec)
+    }
+
+
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, ptr: *mut futhark_f32_2d) -> Self {
+        let len_ptr = unsafe { futhark_shape_f32_2d(ctx.context, ptr) };
+        let mut shape = [0i64; 2];
+        unsafe {
+            for (i, s) in shape.iter_mut().enumerate() {
+                *s = *len_ptr.add(i);
+            }
+        }
+        Self { ctx, shape, ptr }
+    }
+}
+
+
+impl<'a> Drop for ArrayF32D2<'a> {
+    fn drop(&mut self){
+        unsafe {
+            futhark_free_f32_2d(self.ctx.context, self.ptr as *mut _);
+        }
+    }
+}
+
+#[allow(unused)]
+extern "C" {
+    fn futhark_shape_f32_2d(
+        _: *mut futhark_context,
+        _: *mut futhark_f32_2d
+    ) -> *const i64;
+
+    fn futhark_new_f32_2d(
+        _: *mut futhark_context,
+        _: *const f32,
+        dim0: i64, dim1: i64
+    ) -> *mut futhark_f32_2d;
+
+    fn futhark_free_f32_2d(
+        _: *mut futhark_context,
+        _: *mut futhark_f32_2d
+    ) -> std::os::raw::c_int;
+
+    fn futhark_values_f32_2d(
+        _: *mut futhark_context,
+        _: *mut futhark_f32_2d,
+        _: *mut f32
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> ArrayF32D2<'a> {
+    /// Get a single element of the array, without copying the whole array to the host
+    pub fn index(&self, index: [i64; 2]) -> std::result::Result<f32, Error> {
+        if index.iter().zip(self.shape.iter()).any(|(i, n)| *i < 0 || i >= n) {
+            return Err(Error::IndexOutOfBounds);
+        }
+        let mut out = f32::default();
+        let rc = unsafe {
+            futhark_index_f32_2d(self.ctx.context, &mut out, self.ptr, index[0], index[1])
+        };
+        if rc != 0 {
+            return Err(Error::Code(rc));
+        }
+        // `out` lives on the stack, so always wait for the value to be written
+        let rc = unsafe { futhark_context_sync(self.ctx.context) };
+        if rc != 0 {
+            return Err(Error::Code(rc));
+        }
+        Ok(out)
+    }
+}
+
+#[allow(unused)]
+extern "C" {
+    fn futhark_index_f32_2d(
+        _: *mut futhark_context,
+        _: *mut f32,
+        _: *mut futhark_f32_2d,
+        i0: i64, i1: i64
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> ArrayF32D2<'a> {
+    /// Create a new array of `dims` dimensions using existing backend memory, without copying it
+    ///
+    /// # Safety
+    ///
+    /// `data` must have been allocated for the same device as `ctx`, hold at least as many elements
+    /// as described by `dims` and stay valid for as long as the array is alive
+    pub unsafe fn from_raw(ctx: &'a Context, dims: [i64; 2], data: u64) -> std::result::Result<Self, Error> {
+        let ptr = unsafe {
+            futhark_new_raw_f32_2d(ctx.context, data, dims[0], dims[1])
+        };
+        if ptr.is_null() { return Err(Error::NullPtr); }
+        ctx.auto_sync();
+        Ok(Self {
+            ptr,
+            shape: dims,
+            ctx,
+        })
+    }
+
+    /// Get the backend memory used by the array
+    ///
+    /// # Safety
+    ///
+    /// The memory is owned by the array, it must not be freed and is only valid while the array is
+    /// alive. The context should be synced before the memory is accessed by other libraries
+    pub unsafe fn as_raw(&self) -> u64 {
+        unsafe {
+            futhark_values_raw_f32_2d(self.ctx.context, self.ptr)
+        }
+    }
+}
+
+#[allow(unused)]
+extern "C" {
+    fn futhark_new_raw_f32_2d(
+        _: *mut futhark_context,
+        _: u64,
+        dim0: i64, dim1: i64
+    ) -> *mut futhark_f32_2d;
+
+    fn futhark_values_raw_f32_2d(
+        _: *mut futhark_context,
+        _: *mut futhark_f32_2d
+    ) -> u64;
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_f32_1d {
+    _private: [u8; 0]
+}
+
+/// Array type with 1 dimensions and f32 elements
+pub struct ArrayF32D1<'a> {
+    ptr: *mut futhark_f32_1d,
+    pub shape: [i64; 1],
+    ctx: &'a Context,
+}
+
+impl<'a> ArrayF32D1<'a> {
+    /// Create a new array of `dims` dimensions and initialize it with the values from `data`
+    pub fn new(ctx: &'a Context, dims: [i64; 1], data: impl AsRef<[f32]>) -> std::result::Result<Self, Error> {
+        let size: i64 = dims.iter().product();
+        let data = data.as_ref();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape)
+        }
+        let ptr = unsafe {
+            futhark_new_f32_1d(ctx.context, data.as_ptr(), dims[0])
+        };
+        if ptr.is_null() { return Err(Error::NullPtr); }
+        ctx.auto_sync();
+        Ok(Self {
+            ptr: ptr as *mut _,
+            shape: dims,
+            ctx,
+        })
+    }
+
+    /// Get the array shape
+    pub fn shape(&self) -> &[i64; 1] {
+        &self.shape
+    }
+
+    /// Load values back into a slice
+    pub fn values(&self, mut data: impl AsMut<[f32]>) -> std::result::Result<(), Error> {
+        let size: i64 = self.shape.iter().product();
+        let data = data.as_mut();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape);
+        }
+        let rc = unsafe {
+            futhark_values_f32_1d(self.ctx.context, self.ptr, data.as_mut_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::Code(rc));
+        }
+        self.ctx.auto_sync();
+        Ok(())
+    }
+
+    /// Load values into a `Vec`
+    pub fn get(&self) -> std::result::Result<Vec<f32>, Error> {
+        let size: i64 = self.shape.iter().product();
+        let mut vec = vec![f32::default(); size as usize];
+        self.values(&mut vec)?;
+        Ok(vec)
+    }
+
+
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, ptr: *mut futhark_f32_1d) -> Self {
+        let len_ptr = unsafe { futhark_shape_f32_1d(ctx.context, ptr) };
+        let mut shape = [0i64; 1];
+        unsafe {
+            for (i, s) in shape.iter_mut().enumerate() {
+                *s = *len_ptr.add(i);
+            }
+        }
+        Self { ctx, shape, ptr }
+    }
+}
+
+
+impl<'a> Drop for ArrayF32D1<'a> {
+    fn drop(&mut self){
+        unsafe {
+            futhark_free_f32_1d(self.ctx.context, self.ptr as *mut _);
+        }
+    }
+}
+
+#[allow(unused)]
+extern "C" {
+    fn futhark_shape_f32_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_f32_1d
+    ) -> *const i64;
+
+    fn futhark_new_f32_1d(
+        _: *mut futhark_context,
+        _: *const f32,
+        dim0: i64
+    ) -> *mut futhark_f32_1d;
+
+    fn futhark_free_f32_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_f32_1d
+    ) -> std::os::raw::c_int;
+
+    fn futhark_values_f32_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_f32_1d,
+        _: *mut f32
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> ArrayF32D1<'a> {
+    /// Get a single element of the array, without copying the whole array to the host
+    pub fn index(&self, index: [i64; 1]) -> std::result::Result<f32, Error> {
+        if index.iter().zip(self.shape.iter()).any(|(i, n)| *i < 0 || i >= n) {
+            return Err(Error::IndexOutOfBounds);
+        }
+        let mut out = f32::default();
+        let rc = unsafe {
+            futhark_index_f32_1d(self.ctx.context, &mut out, self.ptr, index[0])
+        };
+        if rc != 0 {
+            return Err(Error::Code(rc));
+        }
+        // `out` lives on the stack, so always wait for the value to be written
+        let rc = unsafe { futhark_context_sync(self.ctx.context) };
+        if rc != 0 {
+            return Err(Error::Code(rc));
+        }
+        Ok(out)
+    }
+}
+
+#[allow(unused)]
+extern "C" {
+    fn futhark_index_f32_1d(
+        _: *mut futhark_context,
+        _: *mut f32,
+        _: *mut futhark_f32_1d,
+        i0: i64
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> ArrayF32D1<'a> {
+    /// Create a new array of `dims` dimensions using existing backend memory, without copying it
+    ///
+    /// # Safety
+    ///
+    /// `data` must have been allocated for the same device as `ctx`, hold at least as many elements
+    /// as described by `dims` and stay valid for as long as the array is alive
+    pub unsafe fn from_raw(ctx: &'a Context, dims: [i64; 1], data: u64) -> std::result::Result<Self, Error> {
+        let ptr = unsafe {
+            futhark_new_raw_f32_1d(ctx.context, data, dims[0])
+        };
+        if ptr.is_null() { return Err(Error::NullPtr); }
+        ctx.auto_sync();
+        Ok(Self {
+            ptr,
+            shape: dims,
+            ctx,
+        })
+    }
+
+    /// Get the backend memory used by the array
+    ///
+    /// # Safety
+    ///
+    /// The memory is owned by the array, it must not be freed and is only valid while the array is
+    /// alive. The context should be synced before the memory is accessed by other libraries
+    pub unsafe fn as_raw(&self) -> u64 {
+        unsafe {
+            futhark_values_raw_f32_1d(self.ctx.context, self.ptr)
+        }
+    }
+}
+
+#[allow(unused)]
+extern "C" {
+    fn futhark_new_raw_f32_1d(
+        _: *mut futhark_context,
+        _: u64,
+        dim0: i64
+    ) -> *mut futhark_f32_1d;
+
+    fn futhark_values_raw_f32_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_f32_1d
+    ) -> u64;
+}
+
+impl Context {
+    /// Entry point: matvec
+    pub fn matvec(&self, input0: &ArrayF32D2, input1: &ArrayF32D1) -> Result<ArrayF32D1<'_>, Error> {
+        let mut out0 = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_entry_matvec(self.context, out0.as_mut_ptr(), input0.ptr as *mut _, input1.ptr as *mut _)
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+    
+        #[allow(unused_unsafe)]
+        unsafe {
+            Ok(ArrayF32D1::from_ptr(self, out0.assume_init()))
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_entry_matvec(
+        _: *mut futhark_context,
+        out0: *mut *mut futhark_f32_1d, input0: *const futhark_f32_2d, input1: *const futhark_f32_1d
+    ) -> std::os::raw::c_int;
+}
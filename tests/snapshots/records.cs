@@ -0,0 +1,874 @@
+// <auto-generated />
+// Generated by futhark-bindgen
+
+#nullable enable
+
+using System;
+using System.Runtime.InteropServices;
+
+namespace Records;
+
+internal static class Native
+{
+    // Resolved as `librecords.so` on Linux
+    public const string Lib = "records";
+}
+
+/// <summary>Thrown when a Futhark function returns a non-zero error code</summary>
+public class FutharkException : Exception
+{
+    public int Code { get; }
+
+    public FutharkException(int code, string message) : base($"Futhark error code {code}: {message}")
+    {
+        Code = code;
+    }
+}
+
+/// <summary>Thrown when a Futhark function returns a NULL pointer</summary>
+public class NullPtrException : Exception
+{
+    public NullPtrException() : base("NULL pointer encountered")
+    {
+    }
+}
+
+/// <summary>Options used to configure a <see cref="Context"/></summary>
+public sealed class Options
+{
+    public bool Debug { get; set; }
+    public bool Profile { get; set; }
+    public bool Logging { get; set; }
+    public string? CacheFile { get; set; }
+    public bool AutoSync { get; set; } = true;
+    public string? Device { get; set; }
+}
+
+internal sealed class ContextHandle : SafeHandle
+{
+    [DllImport(Native.Lib)]
+    private static extern int futhark_context_sync(IntPtr ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_free(IntPtr ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_config_free(IntPtr config);
+
+    private readonly IntPtr config;
+    private readonly IntPtr cacheFile;
+
+    public ContextHandle(IntPtr config, IntPtr ctx, IntPtr cacheFile) : base(IntPtr.Zero, true)
+    {
+        this.config = config;
+        this.cacheFile = cacheFile;
+        SetHandle(ctx);
+    }
+
+    public override bool IsInvalid => handle == IntPtr.Zero;
+
+    protected override bool ReleaseHandle()
+    {
+        futhark_context_sync(handle);
+        futhark_context_free(handle);
+        futhark_context_config_free(config);
+        Marshal.FreeCoTaskMem(cacheFile);
+        return true;
+    }
+}
+
+// Handle to a value owned by a context, the context isn't freed until all of its values are
+internal abstract class FutharkHandle : SafeHandle
+{
+    private readonly ContextHandle ctx;
+
+    protected FutharkHandle(ContextHandle ctx, IntPtr ptr) : base(IntPtr.Zero, true)
+    {
+        if (ptr == IntPtr.Zero)
+        {
+            throw new NullPtrException();
+        }
+        bool added = false;
+        ctx.DangerousAddRef(ref added);
+        this.ctx = ctx;
+        SetHandle(ptr);
+    }
+
+    public override bool IsInvalid => handle == IntPtr.Zero;
+
+    protected abstract void Free(IntPtr ctx, IntPtr ptr);
+
+    protected override bool ReleaseHandle()
+    {
+        Free(ctx.DangerousGetHandle(), handle);
+        ctx.DangerousRelease();
+        return true;
+    }
+}
+
+/// <summary>Futhark context</summary>
+/// <remarks>Values created using the context keep it alive until they are disposed</remarks>
+public sealed partial class Context : IDisposable
+{
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_context_config_new();
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_config_free(IntPtr config);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_config_set_debugging(IntPtr config, int flag);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_config_set_profiling(IntPtr config, int flag);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_config_set_logging(IntPtr config, int flag);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_config_set_cache_file(IntPtr config, IntPtr path);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_config_set_device(IntPtr config, [MarshalAs(UnmanagedType.LPUTF8Str)] string device);
+
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_context_new(IntPtr config);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_context_sync(ContextHandle ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_context_clear_caches(ContextHandle ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_pause_profiling(ContextHandle ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_unpause_profiling(ContextHandle ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_context_get_error(ContextHandle ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_context_report(ContextHandle ctx);
+
+    internal readonly ContextHandle handle;
+
+    /// <summary>Sync after every operation</summary>
+    public bool AutoSync { get; set; }
+
+    /// <summary>Create a new context, using the default options if <paramref name="options"/> is null</summary>
+    public Context(Options? options = null)
+    {
+        options ??= new Options();
+        IntPtr config = futhark_context_config_new();
+        if (config == IntPtr.Zero)
+        {
+            throw new NullPtrException();
+        }
+        futhark_context_config_set_debugging(config, options.Debug ? 1 : 0);
+        futhark_context_config_set_profiling(config, options.Profile ? 1 : 0);
+        futhark_context_config_set_logging(config, options.Logging ? 1 : 0);
+        IntPtr cacheFile = IntPtr.Zero;
+        if (options.CacheFile != null)
+        {
+            cacheFile = Marshal.StringToCoTaskMemUTF8(options.CacheFile);
+            futhark_context_config_set_cache_file(config, cacheFile);
+        }
+        if (options.Device != null)
+        {
+            futhark_context_config_set_device(config, options.Device);
+        }
+        IntPtr ctx = futhark_context_new(config);
+        if (ctx == IntPtr.Zero)
+        {
+            futhark_context_config_free(config);
+            Marshal.FreeCoTaskMem(cacheFile);
+            throw new NullPtrException();
+        }
+        handle = new ContextHandle(config, ctx, cacheFile);
+        AutoSync = options.AutoSync;
+    }
+
+    /// <summary>Free the context once all values created using it have been disposed</summary>
+    public void Dispose()
+    {
+        handle.Dispose();
+    }
+
+    internal void Check(int rc)
+    {
+        if (rc != 0)
+        {
+            throw new FutharkException(rc, GetError() ?? "");
+        }
+    }
+
+    /// <summary>Wait for all pending operations to finish</summary>
+    public void Sync()
+    {
+        Check(futhark_context_sync(handle));
+    }
+
+    internal void MaybeSync()
+    {
+        if (AutoSync)
+        {
+            Sync();
+        }
+    }
+
+    /// <summary>Free any cached memory</summary>
+    public void ClearCaches()
+    {
+        Check(futhark_context_clear_caches(handle));
+    }
+
+    /// <summary>Pause profiling</summary>
+    public void PauseProfiling()
+    {
+        futhark_context_pause_profiling(handle);
+    }
+
+    /// <summary>Resume profiling</summary>
+    public void UnpauseProfiling()
+    {
+        futhark_context_unpause_profiling(handle);
+    }
+
+    /// <summary>Get the last error message</summary>
+    public string? GetError()
+    {
+        return TakeString(futhark_context_get_error(handle));
+    }
+
+    /// <summary>Get the profiling report</summary>
+    public string? Report()
+    {
+        return TakeString(futhark_context_report(handle));
+    }
+
+    private static string? TakeString(IntPtr s)
+    {
+        if (s == IntPtr.Zero)
+        {
+            return null;
+        }
+        string? result = Marshal.PtrToStringUTF8(s);
+        // Futhark allocates strings using `malloc`, `NativeMemory.Free` calls the matching `free`
+        unsafe { NativeMemory.Free((void*)s); }
+        return result;
+    }
+}
+
+/// <summary>Futhark type</summary>
+public sealed class Type2b0ad0ae : IDisposable
+{
+    [DllImport(Native.Lib)]
+    private static extern int futhark_free_opaque_2b0ad0ae(IntPtr ctx, IntPtr obj);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_new_opaque_2b0ad0ae(ContextHandle ctx, out IntPtr obj, int field0, float field1);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_project_opaque_2b0ad0ae_0(ContextHandle ctx, out int output, FutharkHandle obj);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_project_opaque_2b0ad0ae_1(ContextHandle ctx, out float output, FutharkHandle obj);
+
+    private sealed class Handle : FutharkHandle
+    {
+        public Handle(ContextHandle ctx, IntPtr ptr) : base(ctx, ptr)
+        {
+        }
+
+        protected override void Free(IntPtr ctx, IntPtr ptr)
+        {
+            futhark_free_opaque_2b0ad0ae(ctx, ptr);
+        }
+    }
+
+    private readonly Context ctx;
+    internal readonly FutharkHandle handle;
+
+    internal Type2b0ad0ae(Context ctx, IntPtr ptr)
+    {
+        this.ctx = ctx;
+        handle = new Handle(ctx.handle, ptr);
+    }
+
+    /// <summary>Free the value, it is also freed when it is garbage collected</summary>
+    public void Dispose()
+    {
+        handle.Dispose();
+    }
+
+    /// <summary>Create a new Type2b0ad0ae from its fields</summary>
+    public Type2b0ad0ae(Context ctx, int field0, float field1)
+    {
+        this.ctx = ctx;
+        ctx.Check(futhark_new_opaque_2b0ad0ae(ctx.handle, out IntPtr ptr, field0, field1));
+        handle = new Handle(ctx.handle, ptr);
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Get the 0 field</summary>
+    public int GetField0()
+    {
+        ctx.Check(futhark_project_opaque_2b0ad0ae_0(ctx.handle, out int output, handle));
+        int result = output;
+        ctx.MaybeSync();
+        return result;
+    }
+
+    /// <summary>Get the 1 field</summary>
+    public float GetField1()
+    {
+        ctx.Check(futhark_project_opaque_2b0ad0ae_1(ctx.handle, out float output, handle));
+        float result = output;
+        ctx.MaybeSync();
+        return result;
+    }
+}
+
+/// <summary>Array type with 1 dimensions and f32 elements</summary>
+public sealed class ArrayF32D1 : IDisposable
+{
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_new_f32_1d(ContextHandle ctx, ref float data, long dim0);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_free_f32_1d(IntPtr ctx, IntPtr arr);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_values_f32_1d(ContextHandle ctx, FutharkHandle arr, ref float data);
+
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_shape_f32_1d(ContextHandle ctx, FutharkHandle arr);
+
+    private sealed class Handle : FutharkHandle
+    {
+        public Handle(ContextHandle ctx, IntPtr ptr) : base(ctx, ptr)
+        {
+        }
+
+        protected override void Free(IntPtr ctx, IntPtr ptr)
+        {
+            futhark_free_f32_1d(ctx, ptr);
+        }
+    }
+
+    private readonly Context ctx;
+    internal readonly FutharkHandle handle;
+    private readonly long[] shape = new long[1];
+
+    internal ArrayF32D1(Context ctx, IntPtr ptr)
+    {
+        this.ctx = ctx;
+        handle = new Handle(ctx.handle, ptr);
+        Marshal.Copy(futhark_shape_f32_1d(ctx.handle, handle), shape, 0, 1);
+    }
+
+    /// <summary>Create a new array with the shape <paramref name="dims"/>, initialized with the values from <paramref name="data"/></summary>
+    public ArrayF32D1(Context ctx, ReadOnlySpan<float> data, params long[] dims)
+    {
+        long size = 1;
+        foreach (long d in dims)
+        {
+            size *= d;
+        }
+        if (dims.Length != 1 || size != data.Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {size} elements, got {data.Length}", nameof(data));
+        }
+        this.ctx = ctx;
+        IntPtr ptr = futhark_new_f32_1d(ctx.handle, ref MemoryMarshal.GetReference(data), dims[0]);
+        handle = new Handle(ctx.handle, ptr);
+        dims.CopyTo(shape, 0);
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Shape of the array</summary>
+    public ReadOnlySpan<long> Shape => shape;
+
+    /// <summary>Number of elements in the array</summary>
+    public long Length
+    {
+        get
+        {
+            long size = 1;
+            foreach (long d in shape)
+            {
+                size *= d;
+            }
+            return size;
+        }
+    }
+
+    /// <summary>Copy the values into <paramref name="output"/>, which must have the same number of elements as the array</summary>
+    public void Values(Span<float> output)
+    {
+        if (output.Length != Length)
+        {
+            throw new ArgumentException($"Invalid shape: expected {Length} elements, got {output.Length}", nameof(output));
+        }
+        ctx.Check(futhark_values_f32_1d(ctx.handle, handle, ref MemoryMarshal.GetReference(output)));
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Copy the values into a new array</summary>
+    public float[] ToArray()
+    {
+        var output = new float[Length];
+        Values(output);
+        return output;
+    }
+
+    /// <summary>Free the array, it is also freed when it is garbage collected</summary>
+    public void Dispose()
+    {
+        handle.Dispose();
+    }
+}
+
+/// <summary>Futhark type</summary>
+public sealed class Arr_point_1d : IDisposable
+{
+    [DllImport(Native.Lib)]
+    private static extern int futhark_free_opaque_arr_point_1d(IntPtr ctx, IntPtr obj);
+
+    private sealed class Handle : FutharkHandle
+    {
+        public Handle(ContextHandle ctx, IntPtr ptr) : base(ctx, ptr)
+        {
+        }
+
+        protected override void Free(IntPtr ctx, IntPtr ptr)
+        {
+            futhark_free_opaque_arr_point_1d(ctx, ptr);
+        }
+    }
+
+    private readonly Context ctx;
+    internal readonly FutharkHandle handle;
+
+    internal Arr_point_1d(Context ctx, IntPtr ptr)
+    {
+        this.ctx = ctx;
+        handle = new Handle(ctx.handle, ptr);
+    }
+
+    /// <summary>Free the value, it is also freed when it is garbage collected</summary>
+    public void Dispose()
+    {
+        handle.Dispose();
+    }
+}
+
+/// <summary>Futhark type</summary>
+public sealed class Number : IDisposable
+{
+    [DllImport(Native.Lib)]
+    private static extern int futhark_free_opaque_number(IntPtr ctx, IntPtr obj);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_new_opaque_number(ContextHandle ctx, out IntPtr obj, float fieldx);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_project_opaque_number_x(ContextHandle ctx, out float output, FutharkHandle obj);
+
+    private sealed class Handle : FutharkHandle
+    {
+        public Handle(ContextHandle ctx, IntPtr ptr) : base(ctx, ptr)
+        {
+        }
+
+        protected override void Free(IntPtr ctx, IntPtr ptr)
+        {
+            futhark_free_opaque_number(ctx, ptr);
+        }
+    }
+
+    private readonly Context ctx;
+    internal readonly FutharkHandle handle;
+
+    internal Number(Context ctx, IntPtr ptr)
+    {
+        this.ctx = ctx;
+        handle = new Handle(ctx.handle, ptr);
+    }
+
+    /// <summary>Free the value, it is also freed when it is garbage collected</summary>
+    public void Dispose()
+    {
+        handle.Dispose();
+    }
+
+    /// <summary>Create a new Number from its fields</summary>
+    public Number(Context ctx, float fieldx)
+    {
+        this.ctx = ctx;
+        ctx.Check(futhark_new_opaque_number(ctx.handle, out IntPtr ptr, fieldx));
+        handle = new Handle(ctx.handle, ptr);
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Get the x field</summary>
+    public float GetX()
+    {
+        ctx.Check(futhark_project_opaque_number_x(ctx.handle, out float output, handle));
+        float result = output;
+        ctx.MaybeSync();
+        return result;
+    }
+}
+
+/// <summary>Futhark type</summary>
+public sealed class Option : IDisposable
+{
+    [DllImport(Native.Lib)]
+    private static extern int futhark_free_opaque_option(IntPtr ctx, IntPtr obj);
+
+    private sealed class Handle : FutharkHandle
+    {
+        public Handle(ContextHandle ctx, IntPtr ptr) : base(ctx, ptr)
+        {
+        }
+
+        protected override void Free(IntPtr ctx, IntPtr ptr)
+        {
+            futhark_free_opaque_option(ctx, ptr);
+        }
+    }
+
+    private readonly Context ctx;
+    internal readonly FutharkHandle handle;
+
+    internal Option(Context ctx, IntPtr ptr)
+    {
+        this.ctx = ctx;
+        handle = new Handle(ctx.handle, ptr);
+    }
+
+    /// <summary>Free the value, it is also freed when it is garbage collected</summary>
+    public void Dispose()
+    {
+        handle.Dispose();
+    }
+}
+
+/// <summary>Futhark type</summary>
+public sealed class Point : IDisposable
+{
+    [DllImport(Native.Lib)]
+    private static extern int futhark_free_opaque_point(IntPtr ctx, IntPtr obj);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_new_opaque_point(ContextHandle ctx, out IntPtr obj, float fieldx, float fieldy);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_project_opaque_point_x(ContextHandle ctx, out float output, FutharkHandle obj);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_project_opaque_point_y(ContextHandle ctx, out float output, FutharkHandle obj);
+
+    private sealed class Handle : FutharkHandle
+    {
+        public Handle(ContextHandle ctx, IntPtr ptr) : base(ctx, ptr)
+        {
+        }
+
+        protected override void Free(IntPtr ctx, IntPtr ptr)
+        {
+            futhark_free_opaque_point(ctx, ptr);
+        }
+    }
+
+    private readonly Context ctx;
+    internal readonly FutharkHandle handle;
+
+    internal Point(Context ctx, IntPtr ptr)
+    {
+        this.ctx = ctx;
+        handle = new Handle(ctx.handle, ptr);
+    }
+
+    /// <summary>Free the value, it is also freed when it is garbage collected</summary>
+    public void Dispose()
+    {
+        handle.Dispose();
+    }
+
+    /// <summary>Create a new Point from its fields</summary>
+    public Point(Context ctx, float fieldx, float fieldy)
+    {
+        this.ctx = ctx;
+        ctx.Check(futhark_new_opaque_point(ctx.handle, out IntPtr ptr, fieldx, fieldy));
+        handle = new Handle(ctx.handle, ptr);
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Get the x field</summary>
+    public float GetX()
+    {
+        ctx.Check(futhark_project_opaque_point_x(ctx.handle, out float output, handle));
+        float result = output;
+        ctx.MaybeSync();
+        return result;
+    }
+
+    /// <summary>Get the y field</summary>
+    public float GetY()
+    {
+        ctx.Check(futhark_project_opaque_point_y(ctx.handle, out float output, handle));
+        float result = output;
+        ctx.MaybeSync();
+        return result;
+    }
+}
+
+/// <summary>Futhark type</summary>
+public sealed class Segment : IDisposable
+{
+    [DllImport(Native.Lib)]
+    private static extern int futhark_free_opaque_segment(IntPtr ctx, IntPtr obj);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_new_opaque_segment(ContextHandle ctx, out IntPtr obj, FutharkHandle fieldstart, FutharkHandle fieldend);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_project_opaque_segment_start(ContextHandle ctx, out IntPtr output, FutharkHandle obj);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_project_opaque_segment_end(ContextHandle ctx, out IntPtr output, FutharkHandle obj);
+
+    private sealed class Handle : FutharkHandle
+    {
+        public Handle(ContextHandle ctx, IntPtr ptr) : base(ctx, ptr)
+        {
+        }
+
+        protected override void Free(IntPtr ctx, IntPtr ptr)
+        {
+            futhark_free_opaque_segment(ctx, ptr);
+        }
+    }
+
+    private readonly Context ctx;
+    internal readonly FutharkHandle handle;
+
+    internal Segment(Context ctx, IntPtr ptr)
+    {
+        this.ctx = ctx;
+        handle = new Handle(ctx.handle, ptr);
+    }
+
+    /// <summary>Free the value, it is also freed when it is garbage collected</summary>
+    public void Dispose()
+    {
+        handle.Dispose();
+    }
+
+    /// <summary>Create a new Segment from its fields</summary>
+    public Segment(Context ctx, Point fieldstart, Point fieldend)
+    {
+        this.ctx = ctx;
+        ctx.Check(futhark_new_opaque_segment(ctx.handle, out IntPtr ptr, fieldstart.handle, fieldend.handle));
+        handle = new Handle(ctx.handle, ptr);
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Get the start field</summary>
+    public Point GetStart()
+    {
+        ctx.Check(futhark_project_opaque_segment_start(ctx.handle, out IntPtr output, handle));
+        Point result = new Point(ctx, output);
+        ctx.MaybeSync();
+        return result;
+    }
+
+    /// <summary>Get the end field</summary>
+    public Point GetEnd()
+    {
+        ctx.Check(futhark_project_opaque_segment_end(ctx.handle, out IntPtr output, handle));
+        Point result = new Point(ctx, output);
+        ctx.MaybeSync();
+        return result;
+    }
+}
+
+/// <summary>Futhark type</summary>
+public sealed class Tup : IDisposable
+{
+    [DllImport(Native.Lib)]
+    private static extern int futhark_free_opaque_tup(IntPtr ctx, IntPtr obj);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_new_opaque_tup(ContextHandle ctx, out IntPtr obj, FutharkHandle field0, FutharkHandle field1);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_project_opaque_tup_0(ContextHandle ctx, out IntPtr output, FutharkHandle obj);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_project_opaque_tup_1(ContextHandle ctx, out IntPtr output, FutharkHandle obj);
+
+    private sealed class Handle : FutharkHandle
+    {
+        public Handle(ContextHandle ctx, IntPtr ptr) : base(ctx, ptr)
+        {
+        }
+
+        protected override void Free(IntPtr ctx, IntPtr ptr)
+        {
+            futhark_free_opaque_tup(ctx, ptr);
+        }
+    }
+
+    private readonly Context ctx;
+    internal readonly FutharkHandle handle;
+
+    internal Tup(Context ctx, IntPtr ptr)
+    {
+        this.ctx = ctx;
+        handle = new Handle(ctx.handle, ptr);
+    }
+
+    /// <summary>Free the value, it is also freed when it is garbage collected</summary>
+    public void Dispose()
+    {
+        handle.Dispose();
+    }
+
+    /// <summary>Create a new Tup from its fields</summary>
+    public Tup(Context ctx, Number field0, ArrayF32D1 field1)
+    {
+        this.ctx = ctx;
+        ctx.Check(futhark_new_opaque_tup(ctx.handle, out IntPtr ptr, field0.handle, field1.handle));
+        handle = new Handle(ctx.handle, ptr);
+        ctx.MaybeSync();
+    }
+
+    /// <summary>Get the 0 field</summary>
+    public Number GetField0()
+    {
+        ctx.Check(futhark_project_opaque_tup_0(ctx.handle, out IntPtr output, handle));
+        Number result = new Number(ctx, output);
+        ctx.MaybeSync();
+        return result;
+    }
+
+    /// <summary>Get the 1 field</summary>
+    public ArrayF32D1 GetField1()
+    {
+        ctx.Check(futhark_project_opaque_tup_1(ctx.handle, out IntPtr output, handle));
+        ArrayF32D1 result = new ArrayF32D1(ctx, output);
+        ctx.MaybeSync();
+        return result;
+    }
+}
+
+public sealed partial class Context
+{
+    [DllImport(Native.Lib)]
+    private static extern int futhark_entry_distance(ContextHandle ctx, out float out0, FutharkHandle input0, FutharkHandle input1);
+
+    /// <summary>Entry point: distance</summary>
+    public float Distance(Point input0, Point input1)
+    {
+        Check(futhark_entry_distance(handle, out float out0, input0.handle, input1.handle));
+        float result = out0;
+        MaybeSync();
+        return result;
+    }
+}
+
+public sealed partial class Context
+{
+    [DllImport(Native.Lib)]
+    private static extern int futhark_entry_length(ContextHandle ctx, out float out0, FutharkHandle input0);
+
+    /// <summary>Entry point: length</summary>
+    public float Length(Segment input0)
+    {
+        Check(futhark_entry_length(handle, out float out0, input0.handle));
+        float result = out0;
+        MaybeSync();
+        return result;
+    }
+}
+
+public sealed partial class Context
+{
+    [DllImport(Native.Lib)]
+    private static extern int futhark_entry_points(ContextHandle ctx, out IntPtr out0, FutharkHandle input0, FutharkHandle input1);
+
+    /// <summary>Entry point: points</summary>
+    public Arr_point_1d Points(ArrayF32D1 input0, ArrayF32D1 input1)
+    {
+        Check(futhark_entry_points(handle, out IntPtr out0, input0.handle, input1.handle));
+        Arr_point_1d result = new Arr_point_1d(this, out0);
+        MaybeSync();
+        return result;
+    }
+}
+
+public sealed partial class Context
+{
+    [DllImport(Native.Lib)]
+    private static extern int futhark_entry_return_option(ContextHandle ctx, out IntPtr out0, FutharkHandle input0);
+
+    /// <summary>Entry point: return_option</summary>
+    public Option ReturnOption(Option input0)
+    {
+        Check(futhark_entry_return_option(handle, out IntPtr out0, input0.handle));
+        Option result = new Option(this, out0);
+        MaybeSync();
+        return result;
+    }
+}
+
+public sealed partial class Context
+{
+    [DllImport(Native.Lib)]
+    private static extern int futhark_entry_swap(ContextHandle ctx, out float out0, out int out1, FutharkHandle input0);
+
+    /// <summary>Entry point: swap</summary>
+    public (float, int) Swap(Type2b0ad0ae input0)
+    {
+        Check(futhark_entry_swap(handle, out float out0, out int out1, input0.handle));
+        (float, int) result = (out0, out1);
+        MaybeSync();
+        return result;
+    }
+}
+
+public sealed partial class Context
+{
+    [DllImport(Native.Lib)]
+    private static extern int futhark_entry_tup_mul(ContextHandle ctx, out IntPtr out0, FutharkHandle input0);
+
+    /// <summary>Entry point: tup_mul</summary>
+    public ArrayF32D1 TupMul(Tup input0)
+    {
+        Check(futhark_entry_tup_mul(handle, out IntPtr out0, input0.handle));
+        ArrayF32D1 result = new ArrayF32D1(this, out0);
+        MaybeSync();
+        return result;
+    }
+}
+
+public sealed partial class Context
+{
+    [DllImport(Native.Lib)]
+    private static extern int futhark_entry_unzip_points(ContextHandle ctx, out IntPtr out0, out IntPtr out1, FutharkHandle input0);
+
+    /// <summary>Entry point: unzip_points</summary>
+    public (ArrayF32D1, ArrayF32D1) UnzipPoints(Arr_point_1d input0)
+    {
+        Check(futhark_entry_unzip_points(handle, out IntPtr out0, out IntPtr out1, input0.handle));
+        (ArrayF32D1, ArrayF32D1) result = (new ArrayF32D1(this, out0), new ArrayF32D1(this, out1));
+        MaybeSync();
+        return result;
+    }
+}
+
@@ -0,0 +1,194 @@
+// Generated by futhark-bindgen
+
+/** Instantiated Emscripten module created by `futhark wasm --library` */
+export interface FutharkModule {
+  HEAPU8: Uint8Array;
+  _malloc(size: number): number;
+  _free(ptr: number): void;
+  [name: string]: any;
+}
+
+/** Context options */
+export interface Options {
+  debug?: boolean;
+  profile?: boolean;
+  logging?: boolean;
+  autoSync?: boolean;
+
+}
+
+/** Thrown when a Futhark function returns a non-zero error code */
+export declare class FutharkError extends Error {
+  readonly code: number;
+}
+
+/** Thrown when a Futhark function returns a NULL pointer */
+export declare class NullPtrError extends Error {}
+
+/** Thrown when data doesn't match the shape of an array */
+export declare class InvalidShapeError extends Error {}
+
+/** Futhark context */
+export declare class Context {
+  constructor(module: FutharkModule, options?: Options);
+  readonly module: FutharkModule;
+  autoSync: boolean;
+
+  /** Free the context, values created using the context can no longer be used */
+  free(): void;
+
+  /** Wait for all pending operations to finish */
+  sync(): void;
+
+  /** Free any cached memory */
+  clearCaches(): void;
+
+  /** Pause profiling */
+  pauseProfiling(): void;
+
+  /** Resume profiling */
+  unpauseProfiling(): void;
+
+  /** Get the last error message */
+  getError(): string | null;
+
+  /** Get the profiling report */
+  report(): string | null;
+}
+
+/** Futhark type */
+export declare class Type2b0ad0ae {
+  /** Free the value, it is also freed when it is garbage collected */
+  free(): void;
+
+  /** Create a new Type2b0ad0ae from its fields */
+  static create(ctx: Context, field0: number, field1: number): Type2b0ad0ae;
+
+  /** Get the 0 field */
+  getField0(): number;
+
+  /** Get the 1 field */
+  getField1(): number;
+}
+
+/** Array type with 1 dimensions and f32 elements */
+export declare class ArrayF32D1 {
+  /** Create a new array with the shape `dims`, initialized with the values from `data` */
+  static from(ctx: Context, data: Float32Array | ArrayLike<number>, dims?: number[]): ArrayF32D1;
+
+  readonly shape: number[];
+
+  /** Number of elements in the array */
+  readonly length: number;
+
+  /** Copy the values into a new typed array */
+  values(): Float32Array;
+
+  /** Free the array, it is also freed when it is garbage collected */
+  free(): void;
+}
+
+/** Futhark type */
+export declare class Arr_point_1d {
+  /** Free the value, it is also freed when it is garbage collected */
+  free(): void;
+}
+
+/** Futhark type */
+export declare class Number {
+  /** Free the value, it is also freed when it is garbage collected */
+  free(): void;
+
+  /** Create a new Number from its fields */
+  static create(ctx: Context, fieldx: number): Number;
+
+  /** Get the x field */
+  getX(): number;
+}
+
+/** Futhark type */
+export declare class Option {
+  /** Free the value, it is also freed when it is garbage collected */
+  free(): void;
+}
+
+/** Futhark type */
+export declare class Point {
+  /** Free the value, it is also freed when it is garbage collected */
+  free(): void;
+
+  /** Create a new Point from its fields */
+  static create(ctx: Context, fieldx: number, fieldy: number): Point;
+
+  /** Get the x field */
+  getX(): number;
+
+  /** Get the y field */
+  getY(): number;
+}
+
+/** Futhark type */
+export declare class Segment {
+  /** Free the value, it is also freed when it is garbage collected */
+  free(): void;
+
+  /** Create a new Segment from its fields */
+  static create(ctx: Context, fieldstart: Point, fieldend: Point): Segment;
+
+  /** Get the start field */
+  getStart(): Point;
+
+  /** Get the end field */
+  getEnd(): Point;
+}
+
+/** Futhark type */
+export declare class Tup {
+  /** Free the value, it is also freed when it is garbage collected */
+  free(): void;
+
+  /** Create a new Tup from its fields */
+  static create(ctx: Context, field0: Number, field1: ArrayF32D1): Tup;
+
+  /** Get the 0 field */
+  getField0(): Number;
+
+  /** Get the 1 field */
+  getField1(): ArrayF32D1;
+}
+
+export interface Context {
+  /** Entry point: distance */
+  distance(input0: Point, input1: Point): number;
+}
+
+export interface Context {
+  /** Entry point: length */
+  length(input0: Segment): number;
+}
+
+export interface Context {
+  /** Entry point: points */
+  points(input0: ArrayF32D1, input1: ArrayF32D1): Arr_point_1d;
+}
+
+export interface Context {
+  /** Entry point: return_option */
+  returnOption(input0: Option): Option;
+}
+
+export interface Context {
+  /** Entry point: swap */
+  swap(input0: Type2b0ad0ae): [number, number];
+}
+
+export interface Context {
+  /** Entry point: tup_mul */
+  tupMul(input0: Tup): ArrayF32D1;
+}
+
+export interface Context {
+  /** Entry point: unzip_points */
+  unzipPoints(input0: Arr_point_1d): [ArrayF32D1, ArrayF32D1];
+}
+
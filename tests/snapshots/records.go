@@ -0,0 +1,899 @@
+// Code generated by futhark-bindgen. DO NOT EDIT.
+
+package records
+
+/*
+#cgo LDFLAGS: -lm -lOpenCL
+#include <stdlib.h>
+#include "records.h"
+*/
+import "C"
+
+import (
+	"errors"
+	"fmt"
+	"runtime"
+	"unsafe"
+)
+
+var (
+	// ErrNullPtr is returned when a Futhark function returns a NULL pointer
+	ErrNullPtr = errors.New("futhark: NULL pointer encountered")
+
+	// ErrInvalidShape is returned when data doesn't match the shape of an array
+	ErrInvalidShape = errors.New("futhark: invalid shape")
+
+	// ErrFreed is returned when a value is used after it has been freed
+	ErrFreed = errors.New("futhark: use after free")
+)
+
+// Error is returned when a Futhark function returns a non-zero error code
+type Error struct {
+	Code    int
+	Message string
+}
+
+func (e *Error) Error() string {
+	return fmt.Sprintf("futhark: error code %d: %s", e.Code, e.Message)
+}
+
+// Options used to configure a Context
+type Options struct {
+	Debug      bool
+	Profile    bool
+	Logging    bool
+	CacheFile  string
+	NoAutoSync bool
+	Device     string
+}
+
+// Context is a Futhark context, it is freed by Close or when it is garbage collected
+type Context struct {
+	config    *C.struct_futhark_context_config
+	handle    *C.struct_futhark_context
+	autoSync  bool
+	cacheFile *C.char
+}
+
+func boolToInt(b bool) C.int {
+	if b {
+		return 1
+	}
+	return 0
+}
+
+// NewContext creates a new context, options may be nil
+func NewContext(options *Options) (*Context, error) {
+	if options == nil {
+		options = &Options{}
+	}
+	config := C.futhark_context_config_new()
+	if config == nil {
+		return nil, ErrNullPtr
+	}
+	C.futhark_context_config_set_debugging(config, boolToInt(options.Debug))
+	C.futhark_context_config_set_profiling(config, boolToInt(options.Profile))
+	C.futhark_context_config_set_logging(config, boolToInt(options.Logging))
+	var cacheFile *C.char
+	if options.CacheFile != "" {
+		cacheFile = C.CString(options.CacheFile)
+		C.futhark_context_config_set_cache_file(config, cacheFile)
+	}
+	if options.Device != "" {
+		device := C.CString(options.Device)
+		C.futhark_context_config_set_device(config, device)
+		C.free(unsafe.Pointer(device))
+	}
+	handle := C.futhark_context_new(config)
+	if handle == nil {
+		C.futhark_context_config_free(config)
+		C.free(unsafe.Pointer(cacheFile))
+		return nil, ErrNullPtr
+	}
+	ctx := &Context{config: config, handle: handle, autoSync: !options.NoAutoSync, cacheFile: cacheFile}
+	runtime.SetFinalizer(ctx, (*Context).Close)
+	return ctx, nil
+}
+
+// Close frees the context, values created using the context can no longer be used
+func (ctx *Context) Close() {
+	if ctx.handle == nil {
+		return
+	}
+	C.futhark_context_sync(ctx.handle)
+	C.futhark_context_free(ctx.handle)
+	C.futhark_context_config_free(ctx.config)
+	C.free(unsafe.Pointer(ctx.cacheFile))
+	ctx.handle = nil
+	ctx.cacheFile = nil
+	runtime.SetFinalizer(ctx, nil)
+}
+
+func (ctx *Context) error(rc C.int) error {
+	msg, _ := ctx.GetError()
+	return &Error{Code: int(rc), Message: msg}
+}
+
+func (ctx *Context) check() error {
+	if ctx.handle == nil {
+		return ErrFreed
+	}
+	return nil
+}
+
+// Sync waits for all pending operations to finish
+func (ctx *Context) Sync() error {
+	if err := ctx.check(); err != nil {
+		return err
+	}
+	if rc := C.futhark_context_sync(ctx.handle); rc != 0 {
+		return ctx.error(rc)
+	}
+	return nil
+}
+
+func (ctx *Context) maybeSync() error {
+	if ctx.autoSync {
+		return ctx.Sync()
+	}
+	return nil
+}
+
+// ClearCaches frees any cached memory
+func (ctx *Context) ClearCaches() error {
+	if err := ctx.check(); err != nil {
+		return err
+	}
+	if rc := C.futhark_context_clear_caches(ctx.handle); rc != 0 {
+		return ctx.error(rc)
+	}
+	return nil
+}
+
+// PauseProfiling pauses profiling
+func (ctx *Context) PauseProfiling() error {
+	if err := ctx.check(); err != nil {
+		return err
+	}
+	C.futhark_context_pause_profiling(ctx.handle)
+	return nil
+}
+
+// UnpauseProfiling resumes profiling
+func (ctx *Context) UnpauseProfiling() error {
+	if err := ctx.check(); err != nil {
+		return err
+	}
+	C.futhark_context_unpause_profiling(ctx.handle)
+	return nil
+}
+
+func takeString(s *C.char) string {
+	if s == nil {
+		return ""
+	}
+	defer C.free(unsafe.Pointer(s))
+	return C.GoString(s)
+}
+
+// GetError returns the last error message
+func (ctx *Context) GetError() (string, error) {
+	if err := ctx.check(); err != nil {
+		return "", err
+	}
+	return takeString(C.futhark_context_get_error(ctx.handle)), nil
+}
+
+// Report returns the profiling report
+func (ctx *Context) Report() (string, error) {
+	if err := ctx.check(); err != nil {
+		return "", err
+	}
+	return takeString(C.futhark_context_report(ctx.handle)), nil
+}
+
+// Type2b0ad0ae is an opaque Futhark value
+type Type2b0ad0ae struct {
+	ptr *C.struct_futhark_opaque_2b0ad0ae
+	ctx *Context
+}
+
+func newType2b0ad0ae(ctx *Context, ptr *C.struct_futhark_opaque_2b0ad0ae) *Type2b0ad0ae {
+	t := &Type2b0ad0ae{ptr: ptr, ctx: ctx}
+	runtime.SetFinalizer(t, (*Type2b0ad0ae).Free)
+	return t
+}
+
+// Free frees the value, it is also freed when it is garbage collected
+func (t *Type2b0ad0ae) Free() {
+	if t.ptr != nil && t.ctx.handle != nil {
+		C.futhark_free_opaque_2b0ad0ae(t.ctx.handle, t.ptr)
+	}
+	t.ptr = nil
+	runtime.SetFinalizer(t, nil)
+}
+
+func (t *Type2b0ad0ae) check() error {
+	if t.ptr == nil {
+		return ErrFreed
+	}
+	return t.ctx.check()
+}
+
+// Field0 returns the 0 field
+func (t *Type2b0ad0ae) Field0() (int32, error) {
+	if err := t.check(); err != nil {
+		return 0, err
+	}
+	var out C.int32_t
+	rc := C.futhark_project_opaque_2b0ad0ae_0(t.ctx.handle, &out, t.ptr)
+	runtime.KeepAlive(t)
+	if rc != 0 {
+		return 0, t.ctx.error(rc)
+	}
+	if err := t.ctx.maybeSync(); err != nil {
+		return 0, err
+	}
+	return int32(out), nil
+}
+
+// Field1 returns the 1 field
+func (t *Type2b0ad0ae) Field1() (float32, error) {
+	if err := t.check(); err != nil {
+		return 0, err
+	}
+	var out C.float
+	rc := C.futhark_project_opaque_2b0ad0ae_1(t.ctx.handle, &out, t.ptr)
+	runtime.KeepAlive(t)
+	if rc != 0 {
+		return 0, t.ctx.error(rc)
+	}
+	if err := t.ctx.maybeSync(); err != nil {
+		return 0, err
+	}
+	return float32(out), nil
+}
+
+// NewType2b0ad0ae creates a new Type2b0ad0ae from its fields
+func NewType2b0ad0ae(ctx *Context, field0 int32, field1 float32) (*Type2b0ad0ae, error) {
+	if err := ctx.check(); err != nil {
+		return nil, err
+	}
+	var out *C.struct_futhark_opaque_2b0ad0ae
+	rc := C.futhark_new_opaque_2b0ad0ae(ctx.handle, &out, C.int32_t(field0), C.float(field1))
+	if rc != 0 {
+		return nil, ctx.error(rc)
+	}
+	if err := ctx.maybeSync(); err != nil {
+		C.futhark_free_opaque_2b0ad0ae(ctx.handle, out)
+		return nil, err
+	}
+	return newType2b0ad0ae(ctx, out), nil
+}
+
+// ArrayF32D1 is an array with 1 dimensions and f32 elements
+type ArrayF32D1 struct {
+	ptr   *C.struct_futhark_f32_1d
+	shape [1]int64
+	ctx   *Context
+}
+
+// NewArrayF32D1 creates a new array with the shape `dims`, initialized with the values from `data`
+func NewArrayF32D1(ctx *Context, data []float32, dims [1]int64) (*ArrayF32D1, error) {
+	if err := ctx.check(); err != nil {
+		return nil, err
+	}
+	size := int64(1)
+	for _, d := range dims {
+		size *= d
+	}
+	if int64(len(data)) != size {
+		return nil, ErrInvalidShape
+	}
+	var p *C.float
+	if len(data) > 0 {
+		p = (*C.float)(unsafe.Pointer(&data[0]))
+	}
+	ptr := C.futhark_new_f32_1d(ctx.handle, p, C.int64_t(dims[0]))
+	if ptr == nil {
+		return nil, ErrNullPtr
+	}
+	if err := ctx.maybeSync(); err != nil {
+		C.futhark_free_f32_1d(ctx.handle, ptr)
+		return nil, err
+	}
+	return newArrayF32D1(ctx, ptr), nil
+}
+
+func newArrayF32D1(ctx *Context, ptr *C.struct_futhark_f32_1d) *ArrayF32D1 {
+	a := &ArrayF32D1{ptr: ptr, ctx: ctx}
+	shape := unsafe.Slice(C.futhark_shape_f32_1d(ctx.handle, ptr), 1)
+	for i := range a.shape {
+		a.shape[i] = int64(shape[i])
+	}
+	runtime.SetFinalizer(a, (*ArrayF32D1).Free)
+	return a
+}
+
+// Free frees the array, it is also freed when it is garbage collected
+func (a *ArrayF32D1) Free() {
+	if a.ptr != nil && a.ctx.handle != nil {
+		C.futhark_free_f32_1d(a.ctx.handle, a.ptr)
+	}
+	a.ptr = nil
+	runtime.SetFinalizer(a, nil)
+}
+
+func (a *ArrayF32D1) check() error {
+	if a.ptr == nil {
+		return ErrFreed
+	}
+	return a.ctx.check()
+}
+
+// Shape returns the shape of the array
+func (a *ArrayF32D1) Shape() []int64 {
+	return a.shape[:]
+}
+
+// Len returns the number of elements in the array
+func (a *ArrayF32D1) Len() int {
+	size := int64(1)
+	for _, d := range a.shape {
+		size *= d
+	}
+	return int(size)
+}
+
+// Values copies the values into `out`, which must have the same number of elements as the array
+func (a *ArrayF32D1) Values(out []float32) error {
+	if err := a.check(); err != nil {
+		return err
+	}
+	if len(out) != a.Len() {
+		return ErrInvalidShape
+	}
+	var p *C.float
+	if len(out) > 0 {
+		p = (*C.float)(unsafe.Pointer(&out[0]))
+	}
+	rc := C.futhark_values_f32_1d(a.ctx.handle, a.ptr, p)
+	runtime.KeepAlive(a)
+	if rc != 0 {
+		return a.ctx.error(rc)
+	}
+	return a.ctx.maybeSync()
+}
+
+// Get copies the values into a new slice
+func (a *ArrayF32D1) Get() ([]float32, error) {
+	out := make([]float32, a.Len())
+	if err := a.Values(out); err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+// Arr_point_1d is an opaque Futhark value
+type Arr_point_1d struct {
+	ptr *C.struct_futhark_opaque_arr_point_1d
+	ctx *Context
+}
+
+func newArr_point_1d(ctx *Context, ptr *C.struct_futhark_opaque_arr_point_1d) *Arr_point_1d {
+	t := &Arr_point_1d{ptr: ptr, ctx: ctx}
+	runtime.SetFinalizer(t, (*Arr_point_1d).Free)
+	return t
+}
+
+// Free frees the value, it is also freed when it is garbage collected
+func (t *Arr_point_1d) Free() {
+	if t.ptr != nil && t.ctx.handle != nil {
+		C.futhark_free_opaque_arr_point_1d(t.ctx.handle, t.ptr)
+	}
+	t.ptr = nil
+	runtime.SetFinalizer(t, nil)
+}
+
+func (t *Arr_point_1d) check() error {
+	if t.ptr == nil {
+		return ErrFreed
+	}
+	return t.ctx.check()
+}
+
+// Number is an opaque Futhark value
+type Number struct {
+	ptr *C.struct_futhark_opaque_number
+	ctx *Context
+}
+
+func newNumber(ctx *Context, ptr *C.struct_futhark_opaque_number) *Number {
+	t := &Number{ptr: ptr, ctx: ctx}
+	runtime.SetFinalizer(t, (*Number).Free)
+	return t
+}
+
+// Free frees the value, it is also freed when it is garbage collected
+func (t *Number) Free() {
+	if t.ptr != nil && t.ctx.handle != nil {
+		C.futhark_free_opaque_number(t.ctx.handle, t.ptr)
+	}
+	t.ptr = nil
+	runtime.SetFinalizer(t, nil)
+}
+
+func (t *Number) check() error {
+	if t.ptr == nil {
+		return ErrFreed
+	}
+	return t.ctx.check()
+}
+
+// X returns the x field
+func (t *Number) X() (float32, error) {
+	if err := t.check(); err != nil {
+		return 0, err
+	}
+	var out C.float
+	rc := C.futhark_project_opaque_number_x(t.ctx.handle, &out, t.ptr)
+	runtime.KeepAlive(t)
+	if rc != 0 {
+		return 0, t.ctx.error(rc)
+	}
+	if err := t.ctx.maybeSync(); err != nil {
+		return 0, err
+	}
+	return float32(out), nil
+}
+
+// NewNumber creates a new Number from its fields
+func NewNumber(ctx *Context, fieldx float32) (*Number, error) {
+	if err := ctx.check(); err != nil {
+		return nil, err
+	}
+	var out *C.struct_futhark_opaque_number
+	rc := C.futhark_new_opaque_number(ctx.handle, &out, C.float(fieldx))
+	if rc != 0 {
+		return nil, ctx.error(rc)
+	}
+	if err := ctx.maybeSync(); err != nil {
+		C.futhark_free_opaque_number(ctx.handle, out)
+		return nil, err
+	}
+	return newNumber(ctx, out), nil
+}
+
+// Option is an opaque Futhark value
+type Option struct {
+	ptr *C.struct_futhark_opaque_option
+	ctx *Context
+}
+
+func newOption(ctx *Context, ptr *C.struct_futhark_opaque_option) *Option {
+	t := &Option{ptr: ptr, ctx: ctx}
+	runtime.SetFinalizer(t, (*Option).Free)
+	return t
+}
+
+// Free frees the value, it is also freed when it is garbage collected
+func (t *Option) Free() {
+	if t.ptr != nil && t.ctx.handle != nil {
+		C.futhark_free_opaque_option(t.ctx.handle, t.ptr)
+	}
+	t.ptr = nil
+	runtime.SetFinalizer(t, nil)
+}
+
+func (t *Option) check() error {
+	if t.ptr == nil {
+		return ErrFreed
+	}
+	return t.ctx.check()
+}
+
+// Point is an opaque Futhark value
+type Point struct {
+	ptr *C.struct_futhark_opaque_point
+	ctx *Context
+}
+
+func newPoint(ctx *Context, ptr *C.struct_futhark_opaque_point) *Point {
+	t := &Point{ptr: ptr, ctx: ctx}
+	runtime.SetFinalizer(t, (*Point).Free)
+	return t
+}
+
+// Free frees the value, it is also freed when it is garbage collected
+func (t *Point) Free() {
+	if t.ptr != nil && t.ctx.handle != nil {
+		C.futhark_free_opaque_point(t.ctx.handle, t.ptr)
+	}
+	t.ptr = nil
+	runtime.SetFinalizer(t, nil)
+}
+
+func (t *Point) check() error {
+	if t.ptr == nil {
+		return ErrFreed
+	}
+	return t.ctx.check()
+}
+
+// X returns the x field
+func (t *Point) X() (float32, error) {
+	if err := t.check(); err != nil {
+		return 0, err
+	}
+	var out C.float
+	rc := C.futhark_project_opaque_point_x(t.ctx.handle, &out, t.ptr)
+	runtime.KeepAlive(t)
+	if rc != 0 {
+		return 0, t.ctx.error(rc)
+	}
+	if err := t.ctx.maybeSync(); err != nil {
+		return 0, err
+	}
+	return float32(out), nil
+}
+
+// Y returns the y field
+func (t *Point) Y() (float32, error) {
+	if err := t.check(); err != nil {
+		return 0, err
+	}
+	var out C.float
+	rc := C.futhark_project_opaque_point_y(t.ctx.handle, &out, t.ptr)
+	runtime.KeepAlive(t)
+	if rc != 0 {
+		return 0, t.ctx.error(rc)
+	}
+	if err := t.ctx.maybeSync(); err != nil {
+		return 0, err
+	}
+	return float32(out), nil
+}
+
+// NewPoint creates a new Point from its fields
+func NewPoint(ctx *Context, fieldx float32, fieldy float32) (*Point, error) {
+	if err := ctx.check(); err != nil {
+		return nil, err
+	}
+	var out *C.struct_futhark_opaque_point
+	rc := C.futhark_new_opaque_point(ctx.handle, &out, C.float(fieldx), C.float(fieldy))
+	if rc != 0 {
+		return nil, ctx.error(rc)
+	}
+	if err := ctx.maybeSync(); err != nil {
+		C.futhark_free_opaque_point(ctx.handle, out)
+		return nil, err
+	}
+	return newPoint(ctx, out), nil
+}
+
+// Segment is an opaque Futhark value
+type Segment struct {
+	ptr *C.struct_futhark_opaque_segment
+	ctx *Context
+}
+
+func newSegment(ctx *Context, ptr *C.struct_futhark_opaque_segment) *Segment {
+	t := &Segment{ptr: ptr, ctx: ctx}
+	runtime.SetFinalizer(t, (*Segment).Free)
+	return t
+}
+
+// Free frees the value, it is also freed when it is garbage collected
+func (t *Segment) Free() {
+	if t.ptr != nil && t.ctx.handle != nil {
+		C.futhark_free_opaque_segment(t.ctx.handle, t.ptr)
+	}
+	t.ptr = nil
+	runtime.SetFinalizer(t, nil)
+}
+
+func (t *Segment) check() error {
+	if t.ptr == nil {
+		return ErrFreed
+	}
+	return t.ctx.check()
+}
+
+// Start returns the start field
+func (t *Segment) Start() (*Point, error) {
+	if err := t.check(); err != nil {
+		return nil, err
+	}
+	var out *C.struct_futhark_opaque_point
+	rc := C.futhark_project_opaque_segment_start(t.ctx.handle, &out, t.ptr)
+	runtime.KeepAlive(t)
+	if rc != 0 {
+		return nil, t.ctx.error(rc)
+	}
+	if err := t.ctx.maybeSync(); err != nil {
+		return nil, err
+	}
+	return newPoint(t.ctx, out), nil
+}
+
+// End returns the end field
+func (t *Segment) End() (*Point, error) {
+	if err := t.check(); err != nil {
+		return nil, err
+	}
+	var out *C.struct_futhark_opaque_point
+	rc := C.futhark_project_opaque_segment_end(t.ctx.handle, &out, t.ptr)
+	runtime.KeepAlive(t)
+	if rc != 0 {
+		return nil, t.ctx.error(rc)
+	}
+	if err := t.ctx.maybeSync(); err != nil {
+		return nil, err
+	}
+	return newPoint(t.ctx, out), nil
+}
+
+// NewSegment creates a new Segment from its fields
+func NewSegment(ctx *Context, fieldstart *Point, fieldend *Point) (*Segment, error) {
+	if err := ctx.check(); err != nil {
+		return nil, err
+	}
+	if err := fieldstart.check(); err != nil {
+		return nil, err
+	}
+	if err := fieldend.check(); err != nil {
+		return nil, err
+	}
+	var out *C.struct_futhark_opaque_segment
+	rc := C.futhark_new_opaque_segment(ctx.handle, &out, fieldstart.ptr, fieldend.ptr)
+	runtime.KeepAlive(fieldstart)
+	runtime.KeepAlive(fieldend)
+	if rc != 0 {
+		return nil, ctx.error(rc)
+	}
+	if err := ctx.maybeSync(); err != nil {
+		C.futhark_free_opaque_segment(ctx.handle, out)
+		return nil, err
+	}
+	return newSegment(ctx, out), nil
+}
+
+// Tup is an opaque Futhark value
+type Tup struct {
+	ptr *C.struct_futhark_opaque_tup
+	ctx *Context
+}
+
+func newTup(ctx *Context, ptr *C.struct_futhark_opaque_tup) *Tup {
+	t := &Tup{ptr: ptr, ctx: ctx}
+	runtime.SetFinalizer(t, (*Tup).Free)
+	return t
+}
+
+// Free frees the value, it is also freed when it is garbage collected
+func (t *Tup) Free() {
+	if t.ptr != nil && t.ctx.handle != nil {
+		C.futhark_free_opaque_tup(t.ctx.handle, t.ptr)
+	}
+	t.ptr = nil
+	runtime.SetFinalizer(t, nil)
+}
+
+func (t *Tup) check() error {
+	if t.ptr == nil {
+		return ErrFreed
+	}
+	return t.ctx.check()
+}
+
+// Field0 returns the 0 field
+func (t *Tup) Field0() (*Number, error) {
+	if err := t.check(); err != nil {
+		return nil, err
+	}
+	var out *C.struct_futhark_opaque_number
+	rc := C.futhark_project_opaque_tup_0(t.ctx.handle, &out, t.ptr)
+	runtime.KeepAlive(t)
+	if rc != 0 {
+		return nil, t.ctx.error(rc)
+	}
+	if err := t.ctx.maybeSync(); err != nil {
+		return nil, err
+	}
+	return newNumber(t.ctx, out), nil
+}
+
+// Field1 returns the 1 field
+func (t *Tup) Field1() (*ArrayF32D1, error) {
+	if err := t.check(); err != nil {
+		return nil, err
+	}
+	var out *C.struct_futhark_f32_1d
+	rc := C.futhark_project_opaque_tup_1(t.ctx.handle, &out, t.ptr)
+	runtime.KeepAlive(t)
+	if rc != 0 {
+		return nil, t.ctx.error(rc)
+	}
+	if err := t.ctx.maybeSync(); err != nil {
+		return nil, err
+	}
+	return newArrayF32D1(t.ctx, out), nil
+}
+
+// NewTup creates a new Tup from its fields
+func NewTup(ctx *Context, field0 *Number, field1 *ArrayF32D1) (*Tup, error) {
+	if err := ctx.check(); err != nil {
+		return nil, err
+	}
+	if err := field0.check(); err != nil {
+		return nil, err
+	}
+	if err := field1.check(); err != nil {
+		return nil, err
+	}
+	var out *C.struct_futhark_opaque_tup
+	rc := C.futhark_new_opaque_tup(ctx.handle, &out, field0.ptr, field1.ptr)
+	runtime.KeepAlive(field0)
+	runtime.KeepAlive(field1)
+	if rc != 0 {
+		return nil, ctx.error(rc)
+	}
+	if err := ctx.maybeSync(); err != nil {
+		C.futhark_free_opaque_tup(ctx.handle, out)
+		return nil, err
+	}
+	return newTup(ctx, out), nil
+}
+
+// Distance calls the distance entry point
+func (ctx *Context) Distance(input0 *Point, input1 *Point) (out0 float32, err error) {
+	if err = ctx.check(); err != nil {
+		return
+	}
+	if err = input0.check(); err != nil {
+		return
+	}
+	if err = input1.check(); err != nil {
+		return
+	}
+	var cout0 C.float
+	rc := C.futhark_entry_distance(ctx.handle, &cout0, input0.ptr, input1.ptr)
+	runtime.KeepAlive(input0)
+	runtime.KeepAlive(input1)
+	if rc != 0 {
+		err = ctx.error(rc)
+		return
+	}
+	out0 = float32(cout0)
+	err = ctx.maybeSync()
+	return
+}
+
+// Length calls the length entry point
+func (ctx *Context) Length(input0 *Segment) (out0 float32, err error) {
+	if err = ctx.check(); err != nil {
+		return
+	}
+	if err = input0.check(); err != nil {
+		return
+	}
+	var cout0 C.float
+	rc := C.futhark_entry_length(ctx.handle, &cout0, input0.ptr)
+	runtime.KeepAlive(input0)
+	if rc != 0 {
+		err = ctx.error(rc)
+		return
+	}
+	out0 = float32(cout0)
+	err = ctx.maybeSync()
+	return
+}
+
+// Points calls the points entry point
+func (ctx *Context) Points(input0 *ArrayF32D1, input1 *ArrayF32D1) (out0 *Arr_point_1d, err error) {
+	if err = ctx.check(); err != nil {
+		return
+	}
+	if err = input0.check(); err != nil {
+		return
+	}
+	if err = input1.check(); err != nil {
+		return
+	}
+	var cout0 *C.struct_futhark_opaque_arr_point_1d
+	rc := C.futhark_entry_points(ctx.handle, &cout0, input0.ptr, input1.ptr)
+	runtime.KeepAlive(input0)
+	runtime.KeepAlive(input1)
+	if rc != 0 {
+		err = ctx.error(rc)
+		return
+	}
+	out0 = newArr_point_1d(ctx, cout0)
+	err = ctx.maybeSync()
+	return
+}
+
+// ReturnOption calls the return_option entry point
+func (ctx *Context) ReturnOption(input0 *Option) (out0 *Option, err error) {
+	if err = ctx.check(); err != nil {
+		return
+	}
+	if err = input0.check(); err != nil {
+		return
+	}
+	var cout0 *C.struct_futhark_opaque_option
+	rc := C.futhark_entry_return_option(ctx.handle, &cout0, input0.ptr)
+	runtime.KeepAlive(input0)
+	if rc != 0 {
+		err = ctx.error(rc)
+		return
+	}
+	out0 = newOption(ctx, cout0)
+	err = ctx.maybeSync()
+	return
+}
+
+// Swap calls the swap entry point
+func (ctx *Context) Swap(input0 *Type2b0ad0ae) (out0 float32, out1 int32, err error) {
+	if err = ctx.check(); err != nil {
+		return
+	}
+	if err = input0.check(); err != nil {
+		return
+	}
+	var cout0 C.float
+	var cout1 C.int32_t
+	rc := C.futhark_entry_swap(ctx.handle, &cout0, &cout1, input0.ptr)
+	runtime.KeepAlive(input0)
+	if rc != 0 {
+		err = ctx.error(rc)
+		return
+	}
+	out0 = float32(cout0)
+	out1 = int32(cout1)
+	err = ctx.maybeSync()
+	return
+}
+
+// TupMul calls the tup_mul entry point
+func (ctx *Context) TupMul(input0 *Tup) (out0 *ArrayF32D1, err error) {
+	if err = ctx.check(); err != nil {
+		return
+	}
+	if err = input0.check(); err != nil {
+		return
+	}
+	var cout0 *C.struct_futhark_f32_1d
+	rc := C.futhark_entry_tup_mul(ctx.handle, &cout0, input0.ptr)
+	runtime.KeepAlive(input0)
+	if rc != 0 {
+		err = ctx.error(rc)
+		return
+	}
+	out0 = newArrayF32D1(ctx, cout0)
+	err = ctx.maybeSync()
+	return
+}
+
+// UnzipPoints calls the unzip_points entry point
+func (ctx *Context) UnzipPoints(input0 *Arr_point_1d) (out0 *ArrayF32D1, out1 *ArrayF32D1, err error) {
+	if err = ctx.check(); err != nil {
+		return
+	}
+	if err = input0.check(); err != nil {
+		return
+	}
+	var cout0 *C.struct_futhark_f32_1d
+	var cout1 *C.struct_futhark_f32_1d
+	rc := C.futhark_entry_unzip_points(ctx.handle, &cout0, &cout1, input0.ptr)
+	runtime.KeepAlive(input0)
+	if rc != 0 {
+		err = ctx.error(rc)
+		return
+	}
+	out0 = newArrayF32D1(ctx, cout0)
+	out1 = newArrayF32D1(ctx, cout1)
+	err = ctx.maybeSync()
+	return
+}
+
@@ -0,0 +1,643 @@
+-- Generated by futhark-bindgen
+
+{-# LANGUAGE ForeignFunctionInterface #-}
+{-# OPTIONS_GHC -Wno-unused-top-binds #-}
+
+module Records where
+
+import Control.Exception (Exception, throwIO)
+import Control.Monad (when)
+import Data.IORef
+import Data.Int
+import Data.Word
+import qualified Data.Vector.Storable as V
+import qualified Data.Vector.Storable.Mutable as MV
+import Foreign
+import Foreign.C.String
+import Foreign.C.Types
+import qualified Foreign.Concurrent as FC
+import qualified Prelude as P
+import Prelude (Bool (..), Double, Float, IO, Int, Maybe (..), Show, String, return, ($), (.), (/=), (==), (>>=), (<$>))
+
+data FutharkContextConfig
+
+data FutharkContext
+
+foreign import ccall unsafe "futhark_context_config_new" futhark_context_config_new :: IO (Ptr FutharkContextConfig)
+foreign import ccall unsafe "futhark_context_config_free" futhark_context_config_free :: Ptr FutharkContextConfig -> IO ()
+foreign import ccall unsafe "futhark_context_config_set_debugging" futhark_context_config_set_debugging :: Ptr FutharkContextConfig -> CInt -> IO ()
+foreign import ccall unsafe "futhark_context_config_set_profiling" futhark_context_config_set_profiling :: Ptr FutharkContextConfig -> CInt -> IO ()
+foreign import ccall unsafe "futhark_context_config_set_logging" futhark_context_config_set_logging :: Ptr FutharkContextConfig -> CInt -> IO ()
+foreign import ccall unsafe "futhark_context_config_set_cache_file" futhark_context_config_set_cache_file :: Ptr FutharkContextConfig -> CString -> IO ()
+foreign import ccall unsafe "futhark_context_config_set_device" futhark_context_config_set_device :: Ptr FutharkContextConfig -> CString -> IO ()
+foreign import ccall safe "futhark_context_new" futhark_context_new :: Ptr FutharkContextConfig -> IO (Ptr FutharkContext)
+foreign import ccall safe "futhark_context_free" futhark_context_free :: Ptr FutharkContext -> IO ()
+foreign import ccall safe "futhark_context_sync" futhark_context_sync :: Ptr FutharkContext -> IO CInt
+foreign import ccall safe "futhark_context_clear_caches" futhark_context_clear_caches :: Ptr FutharkContext -> IO CInt
+foreign import ccall unsafe "futhark_context_pause_profiling" futhark_context_pause_profiling :: Ptr FutharkContext -> IO ()
+foreign import ccall unsafe "futhark_context_unpause_profiling" futhark_context_unpause_profiling :: Ptr FutharkContext -> IO ()
+foreign import ccall unsafe "futhark_context_get_error" futhark_context_get_error :: Ptr FutharkContext -> IO CString
+foreign import ccall unsafe "futhark_context_report" futhark_context_report :: Ptr FutharkContext -> IO CString
+
+-- | Errors thrown by the generated functions
+data FutharkException
+  = -- | A Futhark function returned a non-zero error code
+    FutharkError Int String
+  | -- | A NULL pointer was returned
+    NullPtr
+  | -- | The data doesn't match the shape of an array, expected and actual number of elements
+    InvalidShape Int Int
+  | -- | A value was used after it has been freed
+    UseAfterFree
+  deriving (Show)
+
+instance Exception FutharkException
+
+-- | Context options
+data Options = Options
+  { optDebug :: Bool,
+    optProfile :: Bool,
+    optLogging :: Bool,
+    optCacheFile :: Maybe String,
+    optAutoSync :: Bool,
+    optDevice :: Maybe String
+  }
+
+-- | Default context options
+defaultOptions :: Options
+defaultOptions =
+  Options
+    { optDebug = False,
+      optProfile = False,
+      optLogging = False,
+      optCacheFile = Nothing,
+      optAutoSync = True,
+      optDevice = Nothing
+    }
+
+-- | Futhark context, freed by 'freeContext' or when it is garbage collected
+data Context = Context
+  { contextPtr :: ForeignPtr FutharkContext,
+    contextAlive :: IORef Bool,
+    contextAutoSync :: Bool
+  }
+
+-- | Create a new context
+newContext :: Options -> IO Context
+newContext opts = do
+  config <- futhark_context_config_new
+  when (config == nullPtr) $ throwIO NullPtr
+  futhark_context_config_set_debugging config (fromBool (optDebug opts))
+  futhark_context_config_set_profiling config (fromBool (optProfile opts))
+  futhark_context_config_set_logging config (fromBool (optLogging opts))
+  cacheFile <- P.maybe (return nullPtr) newCString (optCacheFile opts)
+  when (cacheFile /= nullPtr) $ futhark_context_config_set_cache_file config cacheFile
+  P.maybe (return ()) (\d -> withCString d (futhark_context_config_set_device config)) (optDevice opts)
+  ptr <- futhark_context_new config
+  when (ptr == nullPtr) $ do
+    futhark_context_config_free config
+    free cacheFile
+    throwIO NullPtr
+  alive <- newIORef True
+  fp <- FC.newForeignPtr ptr $ do
+    writeIORef alive False
+    _ <- futhark_context_sync ptr
+    futhark_context_free ptr
+    futhark_context_config_free config
+    free cacheFile
+  return (Context fp alive (optAutoSync opts))
+
+-- | Free the context, values created using the context can no longer be used
+freeContext :: Context -> IO ()
+freeContext = finalizeForeignPtr . contextPtr
+
+-- | Use the raw context pointer
+withContext :: Context -> (Ptr FutharkContext -> IO a) -> IO a
+withContext ctx f = do
+  alive <- readIORef (contextAlive ctx)
+  when (P.not alive) $ throwIO UseAfterFree
+  withForeignPtr (contextPtr ctx) f
+
+-- | Free a value using `f` unless the context has already been freed
+freeWith :: Context -> (Ptr FutharkContext -> IO CInt) -> IO ()
+freeWith ctx f = do
+  alive <- readIORef (contextAlive ctx)
+  when alive $ withForeignPtr (contextPtr ctx) $ \c -> do
+    _ <- f c
+    return ()
+
+takeString :: CString -> IO (Maybe String)
+takeString s
+  | s == nullPtr = return Nothing
+  | P.otherwise = do
+      x <- peekCString s
+      free s
+      return (Just x)
+
+-- | Get the last error message
+getError :: Context -> IO (Maybe String)
+getError ctx = withContext ctx $ \c -> futhark_context_get_error c >>= takeString
+
+-- | Get the profiling report
+report :: Context -> IO (Maybe String)
+report ctx = withContext ctx $ \c -> futhark_context_report c >>= takeString
+
+-- | Throw a 'FutharkError' if `rc` is non-zero
+check :: Context -> CInt -> IO ()
+check ctx rc = when (rc /= 0) $ do
+  msg <- getError ctx
+  throwIO (FutharkError (P.fromIntegral rc) (P.maybe "" P.id msg))
+
+-- | Wait for all pending operations to finish
+sync :: Context -> IO ()
+sync ctx = withContext ctx futhark_context_sync >>= check ctx
+
+-- | Sync if auto sync is enabled
+autoSync :: Context -> IO ()
+autoSync ctx = when (contextAutoSync ctx) (sync ctx)
+
+-- | Free any cached memory
+clearCaches :: Context -> IO ()
+clearCaches ctx = withContext ctx futhark_context_clear_caches >>= check ctx
+
+-- | Pause profiling
+pauseProfiling :: Context -> IO ()
+pauseProfiling ctx = withContext ctx futhark_context_pause_profiling
+
+-- | Resume profiling
+unpauseProfiling :: Context -> IO ()
+unpauseProfiling ctx = withContext ctx futhark_context_unpause_profiling
+
+data Futhark_opaque_2b0ad0ae
+
+foreign import ccall unsafe "futhark_free_opaque_2b0ad0ae" futhark_free_opaque_2b0ad0ae :: Ptr FutharkContext -> Ptr Futhark_opaque_2b0ad0ae -> IO CInt
+
+-- | Futhark type
+data Type2b0ad0ae = Type2b0ad0ae (ForeignPtr Futhark_opaque_2b0ad0ae) Context
+
+wrapType2b0ad0ae :: Context -> Ptr Futhark_opaque_2b0ad0ae -> IO Type2b0ad0ae
+wrapType2b0ad0ae ctx ptr = do
+  fp <- FC.newForeignPtr ptr (freeWith ctx (\c -> futhark_free_opaque_2b0ad0ae c ptr))
+  return (Type2b0ad0ae fp ctx)
+
+-- | Use the raw pointer
+withType2b0ad0ae :: Type2b0ad0ae -> (Ptr Futhark_opaque_2b0ad0ae -> IO a) -> IO a
+withType2b0ad0ae (Type2b0ad0ae fp ctx) f = withContext ctx $ \_ -> withForeignPtr fp f
+
+-- | Free the value, it is also freed when it is garbage collected
+freeType2b0ad0ae :: Type2b0ad0ae -> IO ()
+freeType2b0ad0ae (Type2b0ad0ae fp _) = finalizeForeignPtr fp
+
+foreign import ccall unsafe "futhark_project_opaque_2b0ad0ae_0" futhark_project_opaque_2b0ad0ae_0 :: Ptr FutharkContext -> Ptr Int32 -> Ptr Futhark_opaque_2b0ad0ae -> IO CInt
+
+-- | Get the 0 field
+type2b0ad0aeField0 :: Type2b0ad0ae -> IO Int32
+type2b0ad0aeField0 t@(Type2b0ad0ae _ ctx) =
+  withContext ctx $ \c ->
+  withType2b0ad0ae t $ \p ->
+  alloca $ \out -> do
+    rc <- futhark_project_opaque_2b0ad0ae_0 c out p
+    check ctx rc
+    autoSync ctx
+    peek out
+
+foreign import ccall unsafe "futhark_project_opaque_2b0ad0ae_1" futhark_project_opaque_2b0ad0ae_1 :: Ptr FutharkContext -> Ptr Float -> Ptr Futhark_opaque_2b0ad0ae -> IO CInt
+
+-- | Get the 1 field
+type2b0ad0aeField1 :: Type2b0ad0ae -> IO Float
+type2b0ad0aeField1 t@(Type2b0ad0ae _ ctx) =
+  withContext ctx $ \c ->
+  withType2b0ad0ae t $ \p ->
+  alloca $ \out -> do
+    rc <- futhark_project_opaque_2b0ad0ae_1 c out p
+    check ctx rc
+    autoSync ctx
+    peek out
+
+foreign import ccall unsafe "futhark_new_opaque_2b0ad0ae" futhark_new_opaque_2b0ad0ae :: Ptr FutharkContext -> Ptr (Ptr Futhark_opaque_2b0ad0ae) -> Int32 -> Float -> IO CInt
+
+-- | Create a new Type2b0ad0ae from its fields
+newType2b0ad0ae :: Context -> Int32 -> Float -> IO Type2b0ad0ae
+newType2b0ad0ae ctx field0 field1 =
+  withContext ctx $ \c ->
+  alloca $ \out -> do
+    rc <- futhark_new_opaque_2b0ad0ae c out field0 field1
+    check ctx rc
+    autoSync ctx
+    peek out >>= wrapType2b0ad0ae ctx
+
+data Futhark_f32_1d
+
+foreign import ccall unsafe "futhark_new_f32_1d" futhark_new_f32_1d :: Ptr FutharkContext -> Ptr Float -> Int64 -> IO (Ptr Futhark_f32_1d)
+foreign import ccall unsafe "futhark_free_f32_1d" futhark_free_f32_1d :: Ptr FutharkContext -> Ptr Futhark_f32_1d -> IO CInt
+foreign import ccall safe "futhark_values_f32_1d" futhark_values_f32_1d :: Ptr FutharkContext -> Ptr Futhark_f32_1d -> Ptr Float -> IO CInt
+foreign import ccall unsafe "futhark_shape_f32_1d" futhark_shape_f32_1d :: Ptr FutharkContext -> Ptr Futhark_f32_1d -> IO (Ptr Int64)
+
+-- | Array type with 1 dimensions and f32 elements
+data ArrayF32D1 = ArrayF32D1 (ForeignPtr Futhark_f32_1d) [Int64] Context
+
+wrapArrayF32D1 :: Context -> Ptr Futhark_f32_1d -> IO ArrayF32D1
+wrapArrayF32D1 ctx ptr = do
+  shape <- withContext ctx $ \c -> futhark_shape_f32_1d c ptr >>= peekArray 1
+  fp <- FC.newForeignPtr ptr (freeWith ctx (\c -> futhark_free_f32_1d c ptr))
+  return (ArrayF32D1 fp shape ctx)
+
+-- | Create a new array with the shape `dims`, initialized with the values from `v`
+newArrayF32D1 :: Context -> [Int64] -> V.Vector Float -> IO ArrayF32D1
+newArrayF32D1 ctx dims v = do
+  when (P.length dims /= 1) $ throwIO (InvalidShape 1 (P.length dims))
+  let size = P.fromIntegral (P.product dims)
+  when (size /= V.length v) $ throwIO (InvalidShape size (V.length v))
+  ptr <- withContext ctx $ \c -> V.unsafeWith v $ \p -> futhark_new_f32_1d c p (dims P.!! 0)
+  when (ptr == nullPtr) $ throwIO NullPtr
+  autoSync ctx
+  wrapArrayF32D1 ctx ptr
+
+-- | Use the raw array pointer
+withArrayF32D1 :: ArrayF32D1 -> (Ptr Futhark_f32_1d -> IO a) -> IO a
+withArrayF32D1 (ArrayF32D1 fp _ ctx) f = withContext ctx $ \_ -> withForeignPtr fp f
+
+-- | Free the array, it is also freed when it is garbage collected
+freeArrayF32D1 :: ArrayF32D1 -> IO ()
+freeArrayF32D1 (ArrayF32D1 fp _ _) = finalizeForeignPtr fp
+
+-- | Get the shape of the array
+arrayF32D1Shape :: ArrayF32D1 -> [Int64]
+arrayF32D1Shape (ArrayF32D1 _ shape _) = shape
+
+-- | Copy the values into a new vector
+arrayF32D1ToVector :: ArrayF32D1 -> IO (V.Vector Float)
+arrayF32D1ToVector arr@(ArrayF32D1 _ shape ctx) = do
+  mv <- MV.new (P.fromIntegral (P.product shape))
+  rc <- withContext ctx $ \c -> withArrayF32D1 arr $ \p -> MV.unsafeWith mv (futhark_values_f32_1d c p)
+  check ctx rc
+  autoSync ctx
+  V.unsafeFreeze mv
+
+data Futhark_opaque_arr_point_1d
+
+foreign import ccall unsafe "futhark_free_opaque_arr_point_1d" futhark_free_opaque_arr_point_1d :: Ptr FutharkContext -> Ptr Futhark_opaque_arr_point_1d -> IO CInt
+
+-- | Futhark type
+data Arr_point_1d = Arr_point_1d (ForeignPtr Futhark_opaque_arr_point_1d) Context
+
+wrapArr_point_1d :: Context -> Ptr Futhark_opaque_arr_point_1d -> IO Arr_point_1d
+wrapArr_point_1d ctx ptr = do
+  fp <- FC.newForeignPtr ptr (freeWith ctx (\c -> futhark_free_opaque_arr_point_1d c ptr))
+  return (Arr_point_1d fp ctx)
+
+-- | Use the raw pointer
+withArr_point_1d :: Arr_point_1d -> (Ptr Futhark_opaque_arr_point_1d -> IO a) -> IO a
+withArr_point_1d (Arr_point_1d fp ctx) f = withContext ctx $ \_ -> withForeignPtr fp f
+
+-- | Free the value, it is also freed when it is garbage collected
+freeArr_point_1d :: Arr_point_1d -> IO ()
+freeArr_point_1d (Arr_point_1d fp _) = finalizeForeignPtr fp
+
+data Futhark_opaque_number
+
+foreign import ccall unsafe "futhark_free_opaque_number" futhark_free_opaque_number :: Ptr FutharkContext -> Ptr Futhark_opaque_number -> IO CInt
+
+-- | Futhark type
+data Number = Number (ForeignPtr Futhark_opaque_number) Context
+
+wrapNumber :: Context -> Ptr Futhark_opaque_number -> IO Number
+wrapNumber ctx ptr = do
+  fp <- FC.newForeignPtr ptr (freeWith ctx (\c -> futhark_free_opaque_number c ptr))
+  return (Number fp ctx)
+
+-- | Use the raw pointer
+withNumber :: Number -> (Ptr Futhark_opaque_number -> IO a) -> IO a
+withNumber (Number fp ctx) f = withContext ctx $ \_ -> withForeignPtr fp f
+
+-- | Free the value, it is also freed when it is garbage collected
+freeNumber :: Number -> IO ()
+freeNumber (Number fp _) = finalizeForeignPtr fp
+
+foreign import ccall unsafe "futhark_project_opaque_number_x" futhark_project_opaque_number_x :: Ptr FutharkContext -> Ptr Float -> Ptr Futhark_opaque_number -> IO CInt
+
+-- | Get the x field
+numberX :: Number -> IO Float
+numberX t@(Number _ ctx) =
+  withContext ctx $ \c ->
+  withNumber t $ \p ->
+  alloca $ \out -> do
+    rc <- futhark_project_opaque_number_x c out p
+    check ctx rc
+    autoSync ctx
+    peek out
+
+foreign import ccall unsafe "futhark_new_opaque_number" futhark_new_opaque_number :: Ptr FutharkContext -> Ptr (Ptr Futhark_opaque_number) -> Float -> IO CInt
+
+-- | Create a new Number from its fields
+newNumber :: Context -> Float -> IO Number
+newNumber ctx field0 =
+  withContext ctx $ \c ->
+  alloca $ \out -> do
+    rc <- futhark_new_opaque_number c out field0
+    check ctx rc
+    autoSync ctx
+    peek out >>= wrapNumber ctx
+
+data Futhark_opaque_option
+
+foreign import ccall unsafe "futhark_free_opaque_option" futhark_free_opaque_option :: Ptr FutharkContext -> Ptr Futhark_opaque_option -> IO CInt
+
+-- | Futhark type
+data Option = Option (ForeignPtr Futhark_opaque_option) Context
+
+wrapOption :: Context -> Ptr Futhark_opaque_option -> IO Option
+wrapOption ctx ptr = do
+  fp <- FC.newForeignPtr ptr (freeWith ctx (\c -> futhark_free_opaque_option c ptr))
+  return (Option fp ctx)
+
+-- | Use the raw pointer
+withOption :: Option -> (Ptr Futhark_opaque_option -> IO a) -> IO a
+withOption (Option fp ctx) f = withContext ctx $ \_ -> withForeignPtr fp f
+
+-- | Free the value, it is also freed when it is garbage collected
+freeOption :: Option -> IO ()
+freeOption (Option fp _) = finalizeForeignPtr fp
+
+data Futhark_opaque_point
+
+foreign import ccall unsafe "futhark_free_opaque_point" futhark_free_opaque_point :: Ptr FutharkContext -> Ptr Futhark_opaque_point -> IO CInt
+
+-- | Futhark type
+data Point = Point (ForeignPtr Futhark_opaque_point) Context
+
+wrapPoint :: Context -> Ptr Futhark_opaque_point -> IO Point
+wrapPoint ctx ptr = do
+  fp <- FC.newForeignPtr ptr (freeWith ctx (\c -> futhark_free_opaque_point c ptr))
+  return (Point fp ctx)
+
+-- | Use the raw pointer
+withPoint :: Point -> (Ptr Futhark_opaque_point -> IO a) -> IO a
+withPoint (Point fp ctx) f = withContext ctx $ \_ -> withForeignPtr fp f
+
+-- | Free the value, it is also freed when it is garbage collected
+freePoint :: Point -> IO ()
+freePoint (Point fp _) = finalizeForeignPtr fp
+
+foreign import ccall unsafe "futhark_project_opaque_point_x" futhark_project_opaque_point_x :: Ptr FutharkContext -> Ptr Float -> Ptr Futhark_opaque_point -> IO CInt
+
+-- | Get the x field
+pointX :: Point -> IO Float
+pointX t@(Point _ ctx) =
+  withContext ctx $ \c ->
+  withPoint t $ \p ->
+  alloca $ \out -> do
+    rc <- futhark_project_opaque_point_x c out p
+    check ctx rc
+    autoSync ctx
+    peek out
+
+foreign import ccall unsafe "futhark_project_opaque_point_y" futhark_project_opaque_point_y :: Ptr FutharkContext -> Ptr Float -> Ptr Futhark_opaque_point -> IO CInt
+
+-- | Get the y field
+pointY :: Point -> IO Float
+pointY t@(Point _ ctx) =
+  withContext ctx $ \c ->
+  withPoint t $ \p ->
+  alloca $ \out -> do
+    rc <- futhark_project_opaque_point_y c out p
+    check ctx rc
+    autoSync ctx
+    peek out
+
+foreign import ccall unsafe "futhark_new_opaque_point" futhark_new_opaque_point :: Ptr FutharkContext -> Ptr (Ptr Futhark_opaque_point) -> Float -> Float -> IO CInt
+
+-- | Create a new Point from its fields
+newPoint :: Context -> Float -> Float -> IO Point
+newPoint ctx field0 field1 =
+  withContext ctx $ \c ->
+  alloca $ \out -> do
+    rc <- futhark_new_opaque_point c out field0 field1
+    check ctx rc
+    autoSync ctx
+    peek out >>= wrapPoint ctx
+
+data Futhark_opaque_segment
+
+foreign import ccall unsafe "futhark_free_opaque_segment" futhark_free_opaque_segment :: Ptr FutharkContext -> Ptr Futhark_opaque_segment -> IO CInt
+
+-- | Futhark type
+data Segment = Segment (ForeignPtr Futhark_opaque_segment) Context
+
+wrapSegment :: Context -> Ptr Futhark_opaque_segment -> IO Segment
+wrapSegment ctx ptr = do
+  fp <- FC.newForeignPtr ptr (freeWith ctx (\c -> futhark_free_opaque_segment c ptr))
+  return (Segment fp ctx)
+
+-- | Use the raw pointer
+withSegment :: Segment -> (Ptr Futhark_opaque_segment -> IO a) -> IO a
+withSegment (Segment fp ctx) f = withContext ctx $ \_ -> withForeignPtr fp f
+
+-- | Free the value, it is also freed when it is garbage collected
+freeSegment :: Segment -> IO ()
+freeSegment (Segment fp _) = finalizeForeignPtr fp
+
+foreign import ccall unsafe "futhark_project_opaque_segment_start" futhark_project_opaque_segment_start :: Ptr FutharkContext -> Ptr (Ptr Futhark_opaque_point) -> Ptr Futhark_opaque_segment -> IO CInt
+
+-- | Get the start field
+segmentStart :: Segment -> IO Point
+segmentStart t@(Segment _ ctx) =
+  withContext ctx $ \c ->
+  withSegment t $ \p ->
+  alloca $ \out -> do
+    rc <- futhark_project_opaque_segment_start c out p
+    check ctx rc
+    autoSync ctx
+    peek out >>= wrapPoint ctx
+
+foreign import ccall unsafe "futhark_project_opaque_segment_end" futhark_project_opaque_segment_end :: Ptr FutharkContext -> Ptr (Ptr Futhark_opaque_point) -> Ptr Futhark_opaque_segment -> IO CInt
+
+-- | Get the end field
+segmentEnd :: Segment -> IO Point
+segmentEnd t@(Segment _ ctx) =
+  withContext ctx $ \c ->
+  withSegment t $ \p ->
+  alloca $ \out -> do
+    rc <- futhark_project_opaque_segment_end c out p
+    check ctx rc
+    autoSync ctx
+    peek out >>= wrapPoint ctx
+
+foreign import ccall unsafe "futhark_new_opaque_segment" futhark_new_opaque_segment :: Ptr FutharkContext -> Ptr (Ptr Futhark_opaque_segment) -> (Ptr Futhark_opaque_point) -> (Ptr Futhark_opaque_point) -> IO CInt
+
+-- | Create a new Segment from its fields
+newSegment :: Context -> Point -> Point -> IO Segment
+newSegment ctx field0 field1 =
+  withContext ctx $ \c ->
+  withPoint field0 $ \p0 ->
+  withPoint field1 $ \p1 ->
+  alloca $ \out -> do
+    rc <- futhark_new_opaque_segment c out p0 p1
+    check ctx rc
+    autoSync ctx
+    peek out >>= wrapSegment ctx
+
+data Futhark_opaque_tup
+
+foreign import ccall unsafe "futhark_free_opaque_tup" futhark_free_opaque_tup :: Ptr FutharkContext -> Ptr Futhark_opaque_tup -> IO CInt
+
+-- | Futhark type
+data Tup = Tup (ForeignPtr Futhark_opaque_tup) Context
+
+wrapTup :: Context -> Ptr Futhark_opaque_tup -> IO Tup
+wrapTup ctx ptr = do
+  fp <- FC.newForeignPtr ptr (freeWith ctx (\c -> futhark_free_opaque_tup c ptr))
+  return (Tup fp ctx)
+
+-- | Use the raw pointer
+withTup :: Tup -> (Ptr Futhark_opaque_tup -> IO a) -> IO a
+withTup (Tup fp ctx) f = withContext ctx $ \_ -> withForeignPtr fp f
+
+-- | Free the value, it is also freed when it is garbage collected
+freeTup :: Tup -> IO ()
+freeTup (Tup fp _) = finalizeForeignPtr fp
+
+foreign import ccall unsafe "futhark_project_opaque_tup_0" futhark_project_opaque_tup_0 :: Ptr FutharkContext -> Ptr (Ptr Futhark_opaque_number) -> Ptr Futhark_opaque_tup -> IO CInt
+
+-- | Get the 0 field
+tupField0 :: Tup -> IO Number
+tupField0 t@(Tup _ ctx) =
+  withContext ctx $ \c ->
+  withTup t $ \p ->
+  alloca $ \out -> do
+    rc <- futhark_project_opaque_tup_0 c out p
+    check ctx rc
+    autoSync ctx
+    peek out >>= wrapNumber ctx
+
+foreign import ccall unsafe "futhark_project_opaque_tup_1" futhark_project_opaque_tup_1 :: Ptr FutharkContext -> Ptr (Ptr Futhark_f32_1d) -> Ptr Futhark_opaque_tup -> IO CInt
+
+-- | Get the 1 field
+tupField1 :: Tup -> IO ArrayF32D1
+tupField1 t@(Tup _ ctx) =
+  withContext ctx $ \c ->
+  withTup t $ \p ->
+  alloca $ \out -> do
+    rc <- futhark_project_opaque_tup_1 c out p
+    check ctx rc
+    autoSync ctx
+    peek out >>= wrapArrayF32D1 ctx
+
+foreign import ccall unsafe "futhark_new_opaque_tup" futhark_new_opaque_tup :: Ptr FutharkContext -> Ptr (Ptr Futhark_opaque_tup) -> (Ptr Futhark_opaque_number) -> (Ptr Futhark_f32_1d) -> IO CInt
+
+-- | Create a new Tup from its fields
+newTup :: Context -> Number -> ArrayF32D1 -> IO Tup
+newTup ctx field0 field1 =
+  withContext ctx $ \c ->
+  withNumber field0 $ \p0 ->
+  withArrayF32D1 field1 $ \p1 ->
+  alloca $ \out -> do
+    rc <- futhark_new_opaque_tup c out p0 p1
+    check ctx rc
+    autoSync ctx
+    peek out >>= wrapTup ctx
+
+foreign import ccall safe "futhark_entry_distance" futhark_entry_distance :: Ptr FutharkContext -> Ptr Float -> (Ptr Futhark_opaque_point) -> (Ptr Futhark_opaque_point) -> IO CInt
+
+-- | Entry point: distance
+distance :: Context -> Point -> Point -> IO Float
+distance ctx input0 input1 =
+  withContext ctx $ \c ->
+  alloca $ \out0 ->
+  withPoint input0 $ \p0 ->
+  withPoint input1 $ \p1 -> do
+    rc <- futhark_entry_distance c out0 p0 p1
+    check ctx rc
+    autoSync ctx
+    r0 <- peek out0
+    return r0
+
+foreign import ccall safe "futhark_entry_length" futhark_entry_length :: Ptr FutharkContext -> Ptr Float -> (Ptr Futhark_opaque_segment) -> IO CInt
+
+-- | Entry point: length
+length :: Context -> Segment -> IO Float
+length ctx input0 =
+  withContext ctx $ \c ->
+  alloca $ \out0 ->
+  withSegment input0 $ \p0 -> do
+    rc <- futhark_entry_length c out0 p0
+    check ctx rc
+    autoSync ctx
+    r0 <- peek out0
+    return r0
+
+foreign import ccall safe "futhark_entry_points" futhark_entry_points :: Ptr FutharkContext -> Ptr (Ptr Futhark_opaque_arr_point_1d) -> (Ptr Futhark_f32_1d) -> (Ptr Futhark_f32_1d) -> IO CInt
+
+-- | Entry point: points
+points :: Context -> ArrayF32D1 -> ArrayF32D1 -> IO Arr_point_1d
+points ctx input0 input1 =
+  withContext ctx $ \c ->
+  alloca $ \out0 ->
+  withArrayF32D1 input0 $ \p0 ->
+  withArrayF32D1 input1 $ \p1 -> do
+    rc <- futhark_entry_points c out0 p0 p1
+    check ctx rc
+    autoSync ctx
+    r0 <- peek out0 >>= wrapArr_point_1d ctx
+    return r0
+
+foreign import ccall safe "futhark_entry_return_option" futhark_entry_return_option :: Ptr FutharkContext -> Ptr (Ptr Futhark_opaque_option) -> (Ptr Futhark_opaque_option) -> IO CInt
+
+-- | Entry point: return_option
+returnOption :: Context -> Option -> IO Option
+returnOption ctx input0 =
+  withContext ctx $ \c ->
+  alloca $ \out0 ->
+  withOption input0 $ \p0 -> do
+    rc <- futhark_entry_return_option c out0 p0
+    check ctx rc
+    autoSync ctx
+    r0 <- peek out0 >>= wrapOption ctx
+    return r0
+
+foreign import ccall safe "futhark_entry_swap" futhark_entry_swap :: Ptr FutharkContext -> Ptr Float -> Ptr Int32 -> (Ptr Futhark_opaque_2b0ad0ae) -> IO CInt
+
+-- | Entry point: swap
+swap :: Context -> Type2b0ad0ae -> IO (Float, Int32)
+swap ctx input0 =
+  withContext ctx $ \c ->
+  alloca $ \out0 ->
+  alloca $ \out1 ->
+  withType2b0ad0ae input0 $ \p0 -> do
+    rc <- futhark_entry_swap c out0 out1 p0
+    check ctx rc
+    autoSync ctx
+    r0 <- peek out0
+    r1 <- peek out1
+    return (r0, r1)
+
+foreign import ccall safe "futhark_entry_tup_mul" futhark_entry_tup_mul :: Ptr FutharkContext -> Ptr (Ptr Futhark_f32_1d) -> (Ptr Futhark_opaque_tup) -> IO CInt
+
+-- | Entry point: tup_mul
+tupMul :: Context -> Tup -> IO ArrayF32D1
+tupMul ctx input0 =
+  withContext ctx $ \c ->
+  alloca $ \out0 ->
+  withTup input0 $ \p0 -> do
+    rc <- futhark_entry_tup_mul c out0 p0
+    check ctx rc
+    autoSync ctx
+    r0 <- peek out0 >>= wrapArrayF32D1 ctx
+    return r0
+
+foreign import ccall safe "futhark_entry_unzip_points" futhark_entry_unzip_points :: Ptr FutharkContext -> Ptr (Ptr Futhark_f32_1d) -> Ptr (Ptr Futhark_f32_1d) -> (Ptr Futhark_opaque_arr_point_1d) -> IO CInt
+
+-- | Entry point: unzip_points
+unzipPoints :: Context -> Arr_point_1d -> IO (ArrayF32D1, ArrayF32D1)
+unzipPoints ctx input0 =
+  withContext ctx $ \c ->
+  alloca $ \out0 ->
+  alloca $ \out1 ->
+  withArr_point_1d input0 $ \p0 -> do
+    rc <- futhark_entry_unzip_points c out0 out1 p0
+    check ctx rc
+    autoSync ctx
+    r0 <- peek out0 >>= wrapArrayF32D1 ctx
+    r1 <- peek out1 >>= wrapArrayF32D1 ctx
+    return (r0, r1)
+
@@ -0,0 +1,822 @@
+// Generated by futhark-bindgen
+
+import java.lang.foreign.Arena;
+import java.lang.foreign.FunctionDescriptor;
+import java.lang.foreign.Linker;
+import java.lang.foreign.MemorySegment;
+import java.lang.foreign.SymbolLookup;
+import java.lang.invoke.MethodHandle;
+import java.lang.ref.Cleaner;
+import java.lang.ref.Reference;
+import java.util.Objects;
+
+import static java.lang.foreign.ValueLayout.*;
+
+/** Bindings for the Futhark library {@code records}, using the Foreign Function and Memory API */
+public final class records {
+    private records() {
+    }
+
+    // Loaded from the path in the `futhark.lib.records` system property, or `librecords.so`
+    private static final SymbolLookup LIB = SymbolLookup.libraryLookup(
+        System.getProperty("futhark.lib.records", System.mapLibraryName("records")), Arena.global());
+    private static final Linker LINKER = Linker.nativeLinker();
+    private static final Cleaner CLEANER = Cleaner.create();
+    private static final MethodHandle FREE =
+        LINKER.downcallHandle(LINKER.defaultLookup().find("free").orElseThrow(), FunctionDescriptor.ofVoid(ADDRESS));
+
+    static MethodHandle function(String name, FunctionDescriptor desc) {
+        MemorySegment f = LIB.find(name).orElseThrow(() -> new UnsatisfiedLinkError("Missing symbol: " + name));
+        return LINKER.downcallHandle(f, desc);
+    }
+
+    static Object call(MethodHandle f, Object... args) {
+        try {
+            return f.invokeWithArguments(args);
+        } catch (RuntimeException | Error e) {
+            throw e;
+        } catch (Throwable e) {
+            throw new RuntimeException(e);
+        }
+    }
+
+    static String takeString(MemorySegment s) {
+        if (s.equals(MemorySegment.NULL)) {
+            return null;
+        }
+        String result = s.reinterpret(Long.MAX_VALUE).getString(0);
+        call(FREE, s);
+        return result;
+    }
+
+    static long product(long[] dims) {
+        long size = 1;
+        for (long d : dims) {
+            size *= d;
+        }
+        return size;
+    }
+
+    // `boolean` arrays can't be copied to and from memory segments directly
+    static MemorySegment toSegment(Arena arena, boolean[] data) {
+        MemorySegment out = arena.allocate(JAVA_BOOLEAN, data.length);
+        for (int i = 0; i < data.length; i++) {
+            out.setAtIndex(JAVA_BOOLEAN, i, data[i]);
+        }
+        return out;
+    }
+
+    static boolean[] toBooleans(MemorySegment data) {
+        boolean[] out = new boolean[(int) data.byteSize()];
+        for (int i = 0; i < out.length; i++) {
+            out[i] = data.getAtIndex(JAVA_BOOLEAN, i);
+        }
+        return out;
+    }
+
+    /** Thrown when a Futhark function returns a non-zero error code */
+    public static class FutharkException extends RuntimeException {
+        private final int code;
+
+        public FutharkException(int code, String message) {
+            super("Futhark error code " + code + ": " + message);
+            this.code = code;
+        }
+
+        public int code() {
+            return code;
+        }
+    }
+
+    /** Thrown when a Futhark function returns a NULL pointer */
+    public static class NullPtrException extends RuntimeException {
+        public NullPtrException() {
+            super("NULL pointer encountered");
+        }
+    }
+
+    /** Options used to configure a {@link Context} */
+    public static final class Options {
+        boolean debug;
+        boolean profile;
+        boolean logging;
+        String cacheFile;
+        boolean autoSync = true;
+        String device;
+
+        /** Enable debugging */
+        public Options debug(boolean debug) {
+            this.debug = debug;
+            return this;
+        }
+
+        /** Enable profiling */
+        public Options profile(boolean profile) {
+            this.profile = profile;
+            return this;
+        }
+
+        /** Enable logging */
+        public Options logging(boolean logging) {
+            this.logging = logging;
+            return this;
+        }
+
+        /** Set the Futhark cache file */
+        public Options cacheFile(String cacheFile) {
+            this.cacheFile = cacheFile;
+            return this;
+        }
+
+        /** Sync after every operation, enabled by default */
+        public Options autoSync(boolean autoSync) {
+            this.autoSync = autoSync;
+            return this;
+        }
+
+        /** Set the device */
+        public Options device(String device) {
+            this.device = device;
+            return this;
+        }
+    }
+
+    // Shared by a context and the values created using it, the context is freed once all of
+    // them have been closed
+    static final class ContextState {
+        final MemorySegment config;
+        final MemorySegment handle;
+        final Arena arena;
+        private int refs = 1;
+
+        ContextState(MemorySegment config, MemorySegment handle, Arena arena) {
+            this.config = config;
+            this.handle = handle;
+            this.arena = arena;
+        }
+
+        synchronized void retain() {
+            if (refs == 0) {
+                throw new IllegalStateException("Context has already been freed");
+            }
+            refs++;
+        }
+
+        synchronized void release() {
+            if (--refs == 0) {
+                call(Context.FUTHARK_CONTEXT_SYNC, handle);
+                call(Context.FUTHARK_CONTEXT_FREE, handle);
+                call(Context.FUTHARK_CONTEXT_CONFIG_FREE, config);
+                arena.close();
+            }
+        }
+    }
+
+    /** Base class of arrays and opaque values, freed by {@link #close} or when they are garbage collected */
+    public abstract static class FutharkValue implements AutoCloseable {
+        final Context ctx;
+        private final MemorySegment ptr;
+        private final Cleaner.Cleanable cleanable;
+        private volatile boolean closed;
+
+        FutharkValue(Context ctx, MemorySegment ptr, MethodHandle free) {
+            if (ptr.equals(MemorySegment.NULL)) {
+                throw new NullPtrException();
+            }
+            ContextState state = ctx.state;
+            state.retain();
+            this.ctx = ctx;
+            this.ptr = ptr;
+            this.cleanable = CLEANER.register(this, () -> {
+                call(free, state.handle, ptr);
+                state.release();
+            });
+        }
+
+        MemorySegment ptr() {
+            if (closed) {
+                throw new IllegalStateException("Value has already been closed");
+            }
+            return ptr;
+        }
+
+        /** Free the value */
+        @Override
+        public void close() {
+            closed = true;
+            cleanable.clean();
+        }
+    }
+
+    /** Opaque Futhark type */
+    public static final class Type2b0ad0ae extends FutharkValue {
+        private static final MethodHandle FUTHARK_FREE_OPAQUE_2B0AD0AE =
+            function("futhark_free_opaque_2b0ad0ae", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_NEW_OPAQUE_2B0AD0AE =
+            function("futhark_new_opaque_2b0ad0ae", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, JAVA_INT, JAVA_FLOAT));
+        private static final MethodHandle FUTHARK_PROJECT_OPAQUE_2B0AD0AE_0 =
+            function("futhark_project_opaque_2b0ad0ae_0", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_PROJECT_OPAQUE_2B0AD0AE_1 =
+            function("futhark_project_opaque_2b0ad0ae_1", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+
+        Type2b0ad0ae(Context ctx, MemorySegment ptr) {
+            super(ctx, ptr, FUTHARK_FREE_OPAQUE_2B0AD0AE);
+        }
+
+        /** Create a new Type2b0ad0ae from its fields */
+        public static Type2b0ad0ae create(Context ctx, int field0, float field1) {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(ADDRESS);
+                int rc = (int) call(FUTHARK_NEW_OPAQUE_2B0AD0AE, ctx.handle(), out, field0, field1);
+                ctx.check(rc);
+                Type2b0ad0ae result = new Type2b0ad0ae(ctx, out.get(ADDRESS, 0));
+                ctx.maybeSync();
+                return result;
+            }
+        }
+
+        /** Get the 0 field */
+        public int getField0() {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(JAVA_INT);
+                int rc = (int) call(FUTHARK_PROJECT_OPAQUE_2B0AD0AE_0, ctx.handle(), out, ptr());
+                Reference.reachabilityFence(this);
+                ctx.check(rc);
+                int result = out.get(JAVA_INT, 0);
+                ctx.maybeSync();
+                return result;
+            }
+        }
+
+        /** Get the 1 field */
+        public float getField1() {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(JAVA_FLOAT);
+                int rc = (int) call(FUTHARK_PROJECT_OPAQUE_2B0AD0AE_1, ctx.handle(), out, ptr());
+                Reference.reachabilityFence(this);
+                ctx.check(rc);
+                float result = out.get(JAVA_FLOAT, 0);
+                ctx.maybeSync();
+                return result;
+            }
+        }
+    }
+
+    /** Array type with 1 dimensions and f32 elements */
+    public static final class ArrayF32D1 extends FutharkValue {
+        private static final MethodHandle FUTHARK_NEW_F32_1D =
+            function("futhark_new_f32_1d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS, JAVA_LONG));
+        private static final MethodHandle FUTHARK_FREE_F32_1D =
+            function("futhark_free_f32_1d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_VALUES_F32_1D =
+            function("futhark_values_f32_1d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_SHAPE_F32_1D =
+            function("futhark_shape_f32_1d", FunctionDescriptor.of(ADDRESS, ADDRESS, ADDRESS));
+
+        private final long[] shape;
+
+        ArrayF32D1(Context ctx, MemorySegment ptr) {
+            super(ctx, ptr, FUTHARK_FREE_F32_1D);
+            MemorySegment dims = (MemorySegment) call(FUTHARK_SHAPE_F32_1D, ctx.handle(), ptr);
+            this.shape = dims.reinterpret(1 * JAVA_LONG.byteSize()).toArray(JAVA_LONG);
+        }
+
+        /** Create a new array with the shape {@code dims} from the values in {@code data} */
+        public static ArrayF32D1 from(Context ctx, float[] data, long... dims) {
+            if (product(dims) != data.length) {
+                throw new IllegalArgumentException("Invalid shape, expected " + product(dims) + " elements, got " + data.length);
+            }
+            try (Arena arena = Arena.ofConfined()) {
+                return from(ctx, arena.allocateFrom(JAVA_FLOAT, data), dims);
+            }
+        }
+
+        /** Create a new array with the shape {@code dims} by copying the values in {@code data} */
+        public static ArrayF32D1 from(Context ctx, MemorySegment data, long... dims) {
+            if (dims.length != 1) {
+                throw new IllegalArgumentException("Expected 1 dimensions, got " + dims.length);
+            }
+            if (data.byteSize() < product(dims) * JAVA_FLOAT.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the given shape");
+            }
+            MemorySegment ptr = (MemorySegment) call(FUTHARK_NEW_F32_1D, ctx.handle(), data, dims[0]);
+            ArrayF32D1 array = new ArrayF32D1(ctx, ptr);
+            ctx.maybeSync();
+            return array;
+        }
+
+        /** Get the shape of the array */
+        public long[] shape() {
+            return shape.clone();
+        }
+
+        /** Get the number of elements in the array */
+        public long size() {
+            return product(shape);
+        }
+
+        /** Copy the values of the array into {@code out} */
+        public void values(MemorySegment out) {
+            if (out.byteSize() < size() * JAVA_FLOAT.byteSize()) {
+                throw new IllegalArgumentException("Memory segment is too small for the array");
+            }
+            int rc = (int) call(FUTHARK_VALUES_F32_1D, ctx.handle(), ptr(), out);
+            Reference.reachabilityFence(this);
+            ctx.check(rc);
+            ctx.maybeSync();
+        }
+
+        /** Copy the values of the array into a new Java array */
+        public float[] toArray() {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(JAVA_FLOAT, size());
+                values(out);
+                return out.toArray(JAVA_FLOAT);
+            }
+        }
+    }
+
+    /** Opaque Futhark type */
+    public static final class Arr_point_1d extends FutharkValue {
+        private static final MethodHandle FUTHARK_FREE_OPAQUE_ARR_POINT_1D =
+            function("futhark_free_opaque_arr_point_1d", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS));
+
+        Arr_point_1d(Context ctx, MemorySegment ptr) {
+            super(ctx, ptr, FUTHARK_FREE_OPAQUE_ARR_POINT_1D);
+        }
+    }
+
+    /** Opaque Futhark type */
+    public static final class Number extends FutharkValue {
+        private static final MethodHandle FUTHARK_FREE_OPAQUE_NUMBER =
+            function("futhark_free_opaque_number", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_NEW_OPAQUE_NUMBER =
+            function("futhark_new_opaque_number", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, JAVA_FLOAT));
+        private static final MethodHandle FUTHARK_PROJECT_OPAQUE_NUMBER_X =
+            function("futhark_project_opaque_number_x", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+
+        Number(Context ctx, MemorySegment ptr) {
+            super(ctx, ptr, FUTHARK_FREE_OPAQUE_NUMBER);
+        }
+
+        /** Create a new Number from its fields */
+        public static Number create(Context ctx, float fieldx) {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(ADDRESS);
+                int rc = (int) call(FUTHARK_NEW_OPAQUE_NUMBER, ctx.handle(), out, fieldx);
+                ctx.check(rc);
+                Number result = new Number(ctx, out.get(ADDRESS, 0));
+                ctx.maybeSync();
+                return result;
+            }
+        }
+
+        /** Get the x field */
+        public float getX() {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(JAVA_FLOAT);
+                int rc = (int) call(FUTHARK_PROJECT_OPAQUE_NUMBER_X, ctx.handle(), out, ptr());
+                Reference.reachabilityFence(this);
+                ctx.check(rc);
+                float result = out.get(JAVA_FLOAT, 0);
+                ctx.maybeSync();
+                return result;
+            }
+        }
+    }
+
+    /** Opaque Futhark type */
+    public static final class Option extends FutharkValue {
+        private static final MethodHandle FUTHARK_FREE_OPAQUE_OPTION =
+            function("futhark_free_opaque_option", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS));
+
+        Option(Context ctx, MemorySegment ptr) {
+            super(ctx, ptr, FUTHARK_FREE_OPAQUE_OPTION);
+        }
+    }
+
+    /** Opaque Futhark type */
+    public static final class Point extends FutharkValue {
+        private static final MethodHandle FUTHARK_FREE_OPAQUE_POINT =
+            function("futhark_free_opaque_point", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_NEW_OPAQUE_POINT =
+            function("futhark_new_opaque_point", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, JAVA_FLOAT, JAVA_FLOAT));
+        private static final MethodHandle FUTHARK_PROJECT_OPAQUE_POINT_X =
+            function("futhark_project_opaque_point_x", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_PROJECT_OPAQUE_POINT_Y =
+            function("futhark_project_opaque_point_y", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+
+        Point(Context ctx, MemorySegment ptr) {
+            super(ctx, ptr, FUTHARK_FREE_OPAQUE_POINT);
+        }
+
+        /** Create a new Point from its fields */
+        public static Point create(Context ctx, float fieldx, float fieldy) {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(ADDRESS);
+                int rc = (int) call(FUTHARK_NEW_OPAQUE_POINT, ctx.handle(), out, fieldx, fieldy);
+                ctx.check(rc);
+                Point result = new Point(ctx, out.get(ADDRESS, 0));
+                ctx.maybeSync();
+                return result;
+            }
+        }
+
+        /** Get the x field */
+        public float getX() {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(JAVA_FLOAT);
+                int rc = (int) call(FUTHARK_PROJECT_OPAQUE_POINT_X, ctx.handle(), out, ptr());
+                Reference.reachabilityFence(this);
+                ctx.check(rc);
+                float result = out.get(JAVA_FLOAT, 0);
+                ctx.maybeSync();
+                return result;
+            }
+        }
+
+        /** Get the y field */
+        public float getY() {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(JAVA_FLOAT);
+                int rc = (int) call(FUTHARK_PROJECT_OPAQUE_POINT_Y, ctx.handle(), out, ptr());
+                Reference.reachabilityFence(this);
+                ctx.check(rc);
+                float result = out.get(JAVA_FLOAT, 0);
+                ctx.maybeSync();
+                return result;
+            }
+        }
+    }
+
+    /** Opaque Futhark type */
+    public static final class Segment extends FutharkValue {
+        private static final MethodHandle FUTHARK_FREE_OPAQUE_SEGMENT =
+            function("futhark_free_opaque_segment", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_NEW_OPAQUE_SEGMENT =
+            function("futhark_new_opaque_segment", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_PROJECT_OPAQUE_SEGMENT_START =
+            function("futhark_project_opaque_segment_start", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_PROJECT_OPAQUE_SEGMENT_END =
+            function("futhark_project_opaque_segment_end", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+
+        Segment(Context ctx, MemorySegment ptr) {
+            super(ctx, ptr, FUTHARK_FREE_OPAQUE_SEGMENT);
+        }
+
+        /** Create a new Segment from its fields */
+        public static Segment create(Context ctx, Point fieldstart, Point fieldend) {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(ADDRESS);
+                int rc = (int) call(FUTHARK_NEW_OPAQUE_SEGMENT, ctx.handle(), out, fieldstart.ptr(), fieldend.ptr());
+                Reference.reachabilityFence(fieldstart);
+                Reference.reachabilityFence(fieldend);
+                ctx.check(rc);
+                Segment result = new Segment(ctx, out.get(ADDRESS, 0));
+                ctx.maybeSync();
+                return result;
+            }
+        }
+
+        /** Get the start field */
+        public Point getStart() {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(ADDRESS);
+                int rc = (int) call(FUTHARK_PROJECT_OPAQUE_SEGMENT_START, ctx.handle(), out, ptr());
+                Reference.reachabilityFence(this);
+                ctx.check(rc);
+                Point result = new Point(ctx, out.get(ADDRESS, 0));
+                ctx.maybeSync();
+                return result;
+            }
+        }
+
+        /** Get the end field */
+        public Point getEnd() {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(ADDRESS);
+                int rc = (int) call(FUTHARK_PROJECT_OPAQUE_SEGMENT_END, ctx.handle(), out, ptr());
+                Reference.reachabilityFence(this);
+                ctx.check(rc);
+                Point result = new Point(ctx, out.get(ADDRESS, 0));
+                ctx.maybeSync();
+                return result;
+            }
+        }
+    }
+
+    /** Opaque Futhark type */
+    public static final class Tup extends FutharkValue {
+        private static final MethodHandle FUTHARK_FREE_OPAQUE_TUP =
+            function("futhark_free_opaque_tup", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_NEW_OPAQUE_TUP =
+            function("futhark_new_opaque_tup", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_PROJECT_OPAQUE_TUP_0 =
+            function("futhark_project_opaque_tup_0", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+        private static final MethodHandle FUTHARK_PROJECT_OPAQUE_TUP_1 =
+            function("futhark_project_opaque_tup_1", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+
+        Tup(Context ctx, MemorySegment ptr) {
+            super(ctx, ptr, FUTHARK_FREE_OPAQUE_TUP);
+        }
+
+        /** Create a new Tup from its fields */
+        public static Tup create(Context ctx, Number field0, ArrayF32D1 field1) {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(ADDRESS);
+                int rc = (int) call(FUTHARK_NEW_OPAQUE_TUP, ctx.handle(), out, field0.ptr(), field1.ptr());
+                Reference.reachabilityFence(field0);
+                Reference.reachabilityFence(field1);
+                ctx.check(rc);
+                Tup result = new Tup(ctx, out.get(ADDRESS, 0));
+                ctx.maybeSync();
+                return result;
+            }
+        }
+
+        /** Get the 0 field */
+        public Number getField0() {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(ADDRESS);
+                int rc = (int) call(FUTHARK_PROJECT_OPAQUE_TUP_0, ctx.handle(), out, ptr());
+                Reference.reachabilityFence(this);
+                ctx.check(rc);
+                Number result = new Number(ctx, out.get(ADDRESS, 0));
+                ctx.maybeSync();
+                return result;
+            }
+        }
+
+        /** Get the 1 field */
+        public ArrayF32D1 getField1() {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out = arena.allocate(ADDRESS);
+                int rc = (int) call(FUTHARK_PROJECT_OPAQUE_TUP_1, ctx.handle(), out, ptr());
+                Reference.reachabilityFence(this);
+                ctx.check(rc);
+                ArrayF32D1 result = new ArrayF32D1(ctx, out.get(ADDRESS, 0));
+                ctx.maybeSync();
+                return result;
+            }
+        }
+    }
+
+    /** Futhark context, values created using the context keep it alive until they are closed */
+    public static final class Context implements AutoCloseable {
+        static final MethodHandle FUTHARK_CONTEXT_CONFIG_NEW =
+            function("futhark_context_config_new", FunctionDescriptor.of(ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_CONFIG_FREE =
+            function("futhark_context_config_free", FunctionDescriptor.ofVoid(ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_CONFIG_SET_DEBUGGING =
+            function("futhark_context_config_set_debugging", FunctionDescriptor.ofVoid(ADDRESS, JAVA_INT));
+        static final MethodHandle FUTHARK_CONTEXT_CONFIG_SET_PROFILING =
+            function("futhark_context_config_set_profiling", FunctionDescriptor.ofVoid(ADDRESS, JAVA_INT));
+        static final MethodHandle FUTHARK_CONTEXT_CONFIG_SET_LOGGING =
+            function("futhark_context_config_set_logging", FunctionDescriptor.ofVoid(ADDRESS, JAVA_INT));
+        static final MethodHandle FUTHARK_CONTEXT_CONFIG_SET_CACHE_FILE =
+            function("futhark_context_config_set_cache_file", FunctionDescriptor.ofVoid(ADDRESS, ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_CONFIG_SET_DEVICE =
+            function("futhark_context_config_set_device", FunctionDescriptor.ofVoid(ADDRESS, ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_NEW =
+            function("futhark_context_new", FunctionDescriptor.of(ADDRESS, ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_FREE =
+            function("futhark_context_free", FunctionDescriptor.ofVoid(ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_SYNC =
+            function("futhark_context_sync", FunctionDescriptor.of(JAVA_INT, ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_CLEAR_CACHES =
+            function("futhark_context_clear_caches", FunctionDescriptor.of(JAVA_INT, ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_PAUSE_PROFILING =
+            function("futhark_context_pause_profiling", FunctionDescriptor.ofVoid(ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_UNPAUSE_PROFILING =
+            function("futhark_context_unpause_profiling", FunctionDescriptor.ofVoid(ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_GET_ERROR =
+            function("futhark_context_get_error", FunctionDescriptor.of(ADDRESS, ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_REPORT =
+            function("futhark_context_report", FunctionDescriptor.of(ADDRESS, ADDRESS));
+
+        final ContextState state;
+        private final Cleaner.Cleanable cleanable;
+        private volatile boolean closed;
+        private volatile boolean autoSync;
+
+        /** Create a new context using the default options */
+        public Context() {
+            this(new Options());
+        }
+
+        /** Create a new context */
+        public Context(Options options) {
+            MemorySegment config = (MemorySegment) call(FUTHARK_CONTEXT_CONFIG_NEW);
+            if (config.equals(MemorySegment.NULL)) {
+                throw new NullPtrException();
+            }
+            call(FUTHARK_CONTEXT_CONFIG_SET_DEBUGGING, config, options.debug ? 1 : 0);
+            call(FUTHARK_CONTEXT_CONFIG_SET_PROFILING, config, options.profile ? 1 : 0);
+            call(FUTHARK_CONTEXT_CONFIG_SET_LOGGING, config, options.logging ? 1 : 0);
+            // Strings passed to the config are kept alive until the context is freed
+            Arena arena = Arena.ofShared();
+            if (options.cacheFile != null) {
+                call(FUTHARK_CONTEXT_CONFIG_SET_CACHE_FILE, config, arena.allocateFrom(options.cacheFile));
+            }
+            if (options.device != null) {
+                call(FUTHARK_CONTEXT_CONFIG_SET_DEVICE, config, arena.allocateFrom(options.device));
+            }
+
+            MemorySegment handle = (MemorySegment) call(FUTHARK_CONTEXT_NEW, config);
+            if (handle.equals(MemorySegment.NULL)) {
+                call(FUTHARK_CONTEXT_CONFIG_FREE, config);
+                arena.close();
+                throw new NullPtrException();
+            }
+            ContextState state = new ContextState(config, handle, arena);
+            this.state = state;
+            this.cleanable = CLEANER.register(this, state::release);
+            this.autoSync = options.autoSync;
+        }
+
+        MemorySegment handle() {
+            if (closed) {
+                throw new IllegalStateException("Context has already been closed");
+            }
+            return state.handle;
+        }
+
+        /** Free the context once all values created using it have been closed */
+        @Override
+        public void close() {
+            closed = true;
+            cleanable.clean();
+        }
+
+        void check(int rc) {
+            if (rc != 0) {
+                throw new FutharkException(rc, Objects.requireNonNullElse(getError(), ""));
+            }
+        }
+
+        void maybeSync() {
+            if (autoSync) {
+                sync();
+            }
+        }
+
+        /** Sync after every operation */
+        public void setAutoSync(boolean autoSync) {
+            this.autoSync = autoSync;
+        }
+
+        /** Wait for all pending operations to finish */
+        public void sync() {
+            check((int) call(FUTHARK_CONTEXT_SYNC, handle()));
+        }
+
+        /** Free any cached memory */
+        public void clearCaches() {
+            check((int) call(FUTHARK_CONTEXT_CLEAR_CACHES, handle()));
+        }
+
+        /** Pause profiling */
+        public void pauseProfiling() {
+            call(FUTHARK_CONTEXT_PAUSE_PROFILING, handle());
+        }
+
+        /** Resume profiling */
+        public void unpauseProfiling() {
+            call(FUTHARK_CONTEXT_UNPAUSE_PROFILING, handle());
+        }
+
+        /** Get the last error message */
+        public String getError() {
+            return takeString((MemorySegment) call(FUTHARK_CONTEXT_GET_ERROR, handle()));
+        }
+
+        /** Get the profiling report */
+        public String report() {
+            return takeString((MemorySegment) call(FUTHARK_CONTEXT_REPORT, handle()));
+        }
+
+        private static final MethodHandle FUTHARK_ENTRY_DISTANCE =
+            function("futhark_entry_distance", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS, ADDRESS));
+
+        /** Entry point: distance */
+        public float distance(Point input0, Point input1) {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out0 = arena.allocate(JAVA_FLOAT);
+                int rc = (int) call(FUTHARK_ENTRY_DISTANCE, handle(), out0, input0.ptr(), input1.ptr());
+                Reference.reachabilityFence(input0);
+                Reference.reachabilityFence(input1);
+                check(rc);
+                float result = out0.get(JAVA_FLOAT, 0);
+                maybeSync();
+                return result;
+            }
+        }
+
+        private static final MethodHandle FUTHARK_ENTRY_LENGTH =
+            function("futhark_entry_length", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+
+        /** Entry point: length */
+        public float length(Segment input0) {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out0 = arena.allocate(JAVA_FLOAT);
+                int rc = (int) call(FUTHARK_ENTRY_LENGTH, handle(), out0, input0.ptr());
+                Reference.reachabilityFence(input0);
+                check(rc);
+                float result = out0.get(JAVA_FLOAT, 0);
+                maybeSync();
+                return result;
+            }
+        }
+
+        private static final MethodHandle FUTHARK_ENTRY_POINTS =
+            function("futhark_entry_points", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS, ADDRESS));
+
+        /** Entry point: points */
+        public Arr_point_1d points(ArrayF32D1 input0, ArrayF32D1 input1) {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out0 = arena.allocate(ADDRESS);
+                int rc = (int) call(FUTHARK_ENTRY_POINTS, handle(), out0, input0.ptr(), input1.ptr());
+                Reference.reachabilityFence(input0);
+                Reference.reachabilityFence(input1);
+                check(rc);
+                Arr_point_1d result = new Arr_point_1d(this, out0.get(ADDRESS, 0));
+                maybeSync();
+                return result;
+            }
+        }
+
+        private static final MethodHandle FUTHARK_ENTRY_RETURN_OPTION =
+            function("futhark_entry_return_option", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+
+        /** Entry point: return_option */
+        public Option returnOption(Option input0) {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out0 = arena.allocate(ADDRESS);
+                int rc = (int) call(FUTHARK_ENTRY_RETURN_OPTION, handle(), out0, input0.ptr());
+                Reference.reachabilityFence(input0);
+                check(rc);
+                Option result = new Option(this, out0.get(ADDRESS, 0));
+                maybeSync();
+                return result;
+            }
+        }
+
+        /** Outputs of the swap entry point */
+        public record SwapResult(float out0, int out1) {
+        }
+
+        private static final MethodHandle FUTHARK_ENTRY_SWAP =
+            function("futhark_entry_swap", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS, ADDRESS));
+
+        /** Entry point: swap */
+        public SwapResult swap(Type2b0ad0ae input0) {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out0 = arena.allocate(JAVA_FLOAT);
+                MemorySegment out1 = arena.allocate(JAVA_INT);
+                int rc = (int) call(FUTHARK_ENTRY_SWAP, handle(), out0, out1, input0.ptr());
+                Reference.reachabilityFence(input0);
+                check(rc);
+                SwapResult result = new SwapResult(out0.get(JAVA_FLOAT, 0), out1.get(JAVA_INT, 0));
+                maybeSync();
+                return result;
+            }
+        }
+
+        private static final MethodHandle FUTHARK_ENTRY_TUP_MUL =
+            function("futhark_entry_tup_mul", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS));
+
+        /** Entry point: tup_mul */
+        public ArrayF32D1 tupMul(Tup input0) {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out0 = arena.allocate(ADDRESS);
+                int rc = (int) call(FUTHARK_ENTRY_TUP_MUL, handle(), out0, input0.ptr());
+                Reference.reachabilityFence(input0);
+                check(rc);
+                ArrayF32D1 result = new ArrayF32D1(this, out0.get(ADDRESS, 0));
+                maybeSync();
+                return result;
+            }
+        }
+
+        /** Outputs of the unzip_points entry point */
+        public record UnzipPointsResult(ArrayF32D1 out0, ArrayF32D1 out1) {
+        }
+
+        private static final MethodHandle FUTHARK_ENTRY_UNZIP_POINTS =
+            function("futhark_entry_unzip_points", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS, ADDRESS));
+
+        /** Entry point: unzip_points */
+        public UnzipPointsResult unzipPoints(Arr_point_1d input0) {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out0 = arena.allocate(ADDRESS);
+                MemorySegment out1 = arena.allocate(ADDRESS);
+                int rc = (int) call(FUTHARK_ENTRY_UNZIP_POINTS, handle(), out0, out1, input0.ptr());
+                Reference.reachabilityFence(input0);
+                check(rc);
+                UnzipPointsResult result = new UnzipPointsResult(new ArrayF32D1(this, out0.get(ADDRESS, 0)), new ArrayF32D1(this, out1.get(ADDRESS, 0)));
+                maybeSync();
+                return result;
+            }
+        }
+    }
+}
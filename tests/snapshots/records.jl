@@ -0,0 +1,599 @@
+# Generated by futhark-bindgen
+
+module Records
+
+export Context, FutharkError, NullPtrError, InvalidShapeError, free!, sync!
+
+# Path to the shared library containing the compiled Futhark code
+const LIB = get(ENV, "FUTHARK_LIB_RECORDS", joinpath(@__DIR__, "librecords.so"))
+
+"""
+Returned when a Futhark function returns a non-zero error code
+"""
+struct FutharkError <: Exception
+    code::Cint
+    message::String
+end
+
+Base.showerror(io::IO, e::FutharkError) = print(io, "Futhark error code $(e.code): $(e.message)")
+
+"""
+Returned when a Futhark function returns a NULL pointer
+"""
+struct NullPtrError <: Exception end
+
+"""
+Returned when data doesn't match the shape of an array
+"""
+struct InvalidShapeError <: Exception
+    expected::Int
+    actual::Int
+end
+
+"""
+Futhark context, freed by `free!` or when it is garbage collected
+"""
+mutable struct Context
+    config::Ptr{Cvoid}
+    handle::Ptr{Cvoid}
+    auto_sync::Bool
+
+    function Context(; debug::Bool=false, profile::Bool=false, logging::Bool=false,
+                     cache_file::Union{String,Nothing}=nothing, auto_sync::Bool=true, device::Union{String,Nothing}=nothing)
+        config = ccall((:futhark_context_config_new, LIB), Ptr{Cvoid}, ())
+        config == C_NULL && throw(NullPtrError())
+        ccall((:futhark_context_config_set_debugging, LIB), Cvoid, (Ptr{Cvoid}, Cint), config, debug)
+        ccall((:futhark_context_config_set_profiling, LIB), Cvoid, (Ptr{Cvoid}, Cint), config, profile)
+        ccall((:futhark_context_config_set_logging, LIB), Cvoid, (Ptr{Cvoid}, Cint), config, logging)
+        if cache_file !== nothing
+            ccall((:futhark_context_config_set_cache_file, LIB), Cvoid, (Ptr{Cvoid}, Cstring), config, cache_file)
+        end
+        if device !== nothing
+            ccall((:futhark_context_config_set_device, LIB), Cvoid, (Ptr{Cvoid}, Cstring), config, device)
+        end
+        handle = ccall((:futhark_context_new, LIB), Ptr{Cvoid}, (Ptr{Cvoid},), config)
+        if handle == C_NULL
+            ccall((:futhark_context_config_free, LIB), Cvoid, (Ptr{Cvoid},), config)
+            throw(NullPtrError())
+        end
+        finalizer(free!, new(config, handle, auto_sync))
+    end
+end
+
+"""
+Free the context, values created using the context can no longer be used
+"""
+function free!(ctx::Context)
+    if ctx.handle != C_NULL
+        ccall((:futhark_context_sync, LIB), Cint, (Ptr{Cvoid},), ctx.handle)
+        ccall((:futhark_context_free, LIB), Cvoid, (Ptr{Cvoid},), ctx.handle)
+        ccall((:futhark_context_config_free, LIB), Cvoid, (Ptr{Cvoid},), ctx.config)
+        ctx.handle = C_NULL
+        ctx.config = C_NULL
+    end
+    nothing
+end
+
+function check_handle(ctx::Context)
+    ctx.handle == C_NULL && error("Context has already been freed")
+    ctx.handle
+end
+
+function take_string(ptr::Ptr{Cchar})
+    ptr == C_NULL && return nothing
+    s = unsafe_string(ptr)
+    Libc.free(ptr)
+    s
+end
+
+"""
+Get the last error message, or `nothing`
+"""
+get_error(ctx::Context) = take_string(ccall((:futhark_context_get_error, LIB), Ptr{Cchar}, (Ptr{Cvoid},), check_handle(ctx)))
+
+"""
+Get the profiling report
+"""
+report(ctx::Context) = take_string(ccall((:futhark_context_report, LIB), Ptr{Cchar}, (Ptr{Cvoid},), check_handle(ctx)))
+
+function check_rc(ctx::Context, rc::Cint)
+    if rc != 0
+        msg = get_error(ctx)
+        throw(FutharkError(rc, msg === nothing ? "" : msg))
+    end
+    nothing
+end
+
+"""
+Wait for all pending operations to finish
+"""
+sync!(ctx::Context) = check_rc(ctx, ccall((:futhark_context_sync, LIB), Cint, (Ptr{Cvoid},), check_handle(ctx)))
+
+auto_sync!(ctx::Context) = ctx.auto_sync && sync!(ctx)
+
+"""
+Free any cached memory
+"""
+clear_caches!(ctx::Context) = check_rc(ctx, ccall((:futhark_context_clear_caches, LIB), Cint, (Ptr{Cvoid},), check_handle(ctx)))
+
+pause_profiling!(ctx::Context) = ccall((:futhark_context_pause_profiling, LIB), Cvoid, (Ptr{Cvoid},), check_handle(ctx))
+
+unpause_profiling!(ctx::Context) = ccall((:futhark_context_unpause_profiling, LIB), Cvoid, (Ptr{Cvoid},), check_handle(ctx))
+
+# Futhark arrays are row-major and Julia arrays are column-major, so the order of the
+# dimensions is reversed when copying data in either direction
+row_major(a::AbstractArray{T,N}) where {T,N} = permutedims(a, ntuple(i -> N - i + 1, N))
+
+"""
+Futhark type
+"""
+mutable struct Type2b0ad0ae
+    ptr::Ptr{Cvoid}
+    ctx::Context
+
+    Type2b0ad0ae(ctx::Context, ptr::Ptr{Cvoid}) = finalizer(free!, new(ptr, ctx))
+end
+
+"""
+Free the value, it is also freed when it is garbage collected
+"""
+function free!(t::Type2b0ad0ae)
+    if t.ptr != C_NULL && t.ctx.handle != C_NULL
+        ccall((:futhark_free_opaque_2b0ad0ae, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}), t.ctx.handle, t.ptr)
+    end
+    t.ptr = C_NULL
+    nothing
+end
+
+function check_ptr(t::Type2b0ad0ae)
+    t.ptr == C_NULL && error("Value has already been freed")
+    t.ptr
+end
+
+"""
+Get the 0 field
+"""
+function get_0(t::Type2b0ad0ae)
+    out = Ref{Int32}()
+    rc = GC.@preserve t ccall((:futhark_project_opaque_2b0ad0ae_0, LIB), Cint, (Ptr{Cvoid}, Ptr{Int32}, Ptr{Cvoid}), check_handle(t.ctx), out, check_ptr(t))
+    check_rc(t.ctx, rc)
+    auto_sync!(t.ctx)
+    out[]
+end
+
+"""
+Get the 1 field
+"""
+function get_1(t::Type2b0ad0ae)
+    out = Ref{Float32}()
+    rc = GC.@preserve t ccall((:futhark_project_opaque_2b0ad0ae_1, LIB), Cint, (Ptr{Cvoid}, Ptr{Float32}, Ptr{Cvoid}), check_handle(t.ctx), out, check_ptr(t))
+    check_rc(t.ctx, rc)
+    auto_sync!(t.ctx)
+    out[]
+end
+
+"""
+Create a new Type2b0ad0ae from its fields
+"""
+function Type2b0ad0ae(ctx::Context, field0, field1)
+    out = Ref{Ptr{Cvoid}}(C_NULL)
+    rc = GC.@preserve ctx ccall((:futhark_new_opaque_2b0ad0ae, LIB), Cint, (Ptr{Cvoid}, Ptr{Ptr{Cvoid}}, Int32, Float32), check_handle(ctx), out, field0, field1)
+    check_rc(ctx, rc)
+    auto_sync!(ctx)
+    Type2b0ad0ae(ctx, out[])
+end
+
+"""
+Array type with 1 dimensions and f32 elements
+"""
+mutable struct ArrayF32D1
+    ptr::Ptr{Cvoid}
+    shape::NTuple{1,Int64}
+    ctx::Context
+
+    function ArrayF32D1(ctx::Context, ptr::Ptr{Cvoid})
+        shape = unsafe_load(ccall((:futhark_shape_f32_1d, LIB), Ptr{NTuple{1,Int64}}, (Ptr{Cvoid}, Ptr{Cvoid}), ctx.handle, ptr))
+        finalizer(free!, new(ptr, shape, ctx))
+    end
+end
+
+"""
+Create a new array from a Julia array, the array keeps the same shape
+"""
+function ArrayF32D1(ctx::Context, data::AbstractArray{Float32,1})
+    dims = size(data)
+    data = row_major(data)
+    ptr = ccall((:futhark_new_f32_1d, LIB), Ptr{Cvoid}, (Ptr{Cvoid}, Ptr{Float32}, Int64), check_handle(ctx), data, dims...)
+    ptr == C_NULL && throw(NullPtrError())
+    auto_sync!(ctx)
+    ArrayF32D1(ctx, ptr)
+end
+
+"""
+Free the array, it is also freed when it is garbage collected
+"""
+function free!(a::ArrayF32D1)
+    if a.ptr != C_NULL && a.ctx.handle != C_NULL
+        ccall((:futhark_free_f32_1d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}), a.ctx.handle, a.ptr)
+    end
+    a.ptr = C_NULL
+    nothing
+end
+
+function check_ptr(a::ArrayF32D1)
+    a.ptr == C_NULL && error("Array has already been freed")
+    a.ptr
+end
+
+Base.size(a::ArrayF32D1) = Int.(a.shape)
+Base.length(a::ArrayF32D1) = prod(a.shape)
+Base.eltype(::Type{ArrayF32D1}) = Float32
+
+"""
+Copy the values into a new Julia array with the same shape
+"""
+function Base.Array(a::ArrayF32D1)
+    out = Array{Float32,1}(undef, reverse(size(a))...)
+    rc = GC.@preserve a ccall((:futhark_values_f32_1d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}, Ptr{Float32}), check_handle(a.ctx), check_ptr(a), out)
+    check_rc(a.ctx, rc)
+    auto_sync!(a.ctx)
+    row_major(out)
+end
+
+"""
+Futhark type
+"""
+mutable struct Arr_point_1d
+    ptr::Ptr{Cvoid}
+    ctx::Context
+
+    Arr_point_1d(ctx::Context, ptr::Ptr{Cvoid}) = finalizer(free!, new(ptr, ctx))
+end
+
+"""
+Free the value, it is also freed when it is garbage collected
+"""
+function free!(t::Arr_point_1d)
+    if t.ptr != C_NULL && t.ctx.handle != C_NULL
+        ccall((:futhark_free_opaque_arr_point_1d, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}), t.ctx.handle, t.ptr)
+    end
+    t.ptr = C_NULL
+    nothing
+end
+
+function check_ptr(t::Arr_point_1d)
+    t.ptr == C_NULL && error("Value has already been freed")
+    t.ptr
+end
+
+"""
+Futhark type
+"""
+mutable struct Number
+    ptr::Ptr{Cvoid}
+    ctx::Context
+
+    Number(ctx::Context, ptr::Ptr{Cvoid}) = finalizer(free!, new(ptr, ctx))
+end
+
+"""
+Free the value, it is also freed when it is garbage collected
+"""
+function free!(t::Number)
+    if t.ptr != C_NULL && t.ctx.handle != C_NULL
+        ccall((:futhark_free_opaque_number, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}), t.ctx.handle, t.ptr)
+    end
+    t.ptr = C_NULL
+    nothing
+end
+
+function check_ptr(t::Number)
+    t.ptr == C_NULL && error("Value has already been freed")
+    t.ptr
+end
+
+"""
+Get the x field
+"""
+function get_x(t::Number)
+    out = Ref{Float32}()
+    rc = GC.@preserve t ccall((:futhark_project_opaque_number_x, LIB), Cint, (Ptr{Cvoid}, Ptr{Float32}, Ptr{Cvoid}), check_handle(t.ctx), out, check_ptr(t))
+    check_rc(t.ctx, rc)
+    auto_sync!(t.ctx)
+    out[]
+end
+
+"""
+Create a new Number from its fields
+"""
+function Number(ctx::Context, fieldx)
+    out = Ref{Ptr{Cvoid}}(C_NULL)
+    rc = GC.@preserve ctx ccall((:futhark_new_opaque_number, LIB), Cint, (Ptr{Cvoid}, Ptr{Ptr{Cvoid}}, Float32), check_handle(ctx), out, fieldx)
+    check_rc(ctx, rc)
+    auto_sync!(ctx)
+    Number(ctx, out[])
+end
+
+"""
+Futhark type
+"""
+mutable struct Option
+    ptr::Ptr{Cvoid}
+    ctx::Context
+
+    Option(ctx::Context, ptr::Ptr{Cvoid}) = finalizer(free!, new(ptr, ctx))
+end
+
+"""
+Free the value, it is also freed when it is garbage collected
+"""
+function free!(t::Option)
+    if t.ptr != C_NULL && t.ctx.handle != C_NULL
+        ccall((:futhark_free_opaque_option, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}), t.ctx.handle, t.ptr)
+    end
+    t.ptr = C_NULL
+    nothing
+end
+
+function check_ptr(t::Option)
+    t.ptr == C_NULL && error("Value has already been freed")
+    t.ptr
+end
+
+"""
+Futhark type
+"""
+mutable struct Point
+    ptr::Ptr{Cvoid}
+    ctx::Context
+
+    Point(ctx::Context, ptr::Ptr{Cvoid}) = finalizer(free!, new(ptr, ctx))
+end
+
+"""
+Free the value, it is also freed when it is garbage collected
+"""
+function free!(t::Point)
+    if t.ptr != C_NULL && t.ctx.handle != C_NULL
+        ccall((:futhark_free_opaque_point, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}), t.ctx.handle, t.ptr)
+    end
+    t.ptr = C_NULL
+    nothing
+end
+
+function check_ptr(t::Point)
+    t.ptr == C_NULL && error("Value has already been freed")
+    t.ptr
+end
+
+"""
+Get the x field
+"""
+function get_x(t::Point)
+    out = Ref{Float32}()
+    rc = GC.@preserve t ccall((:futhark_project_opaque_point_x, LIB), Cint, (Ptr{Cvoid}, Ptr{Float32}, Ptr{Cvoid}), check_handle(t.ctx), out, check_ptr(t))
+    check_rc(t.ctx, rc)
+    auto_sync!(t.ctx)
+    out[]
+end
+
+"""
+Get the y field
+"""
+function get_y(t::Point)
+    out = Ref{Float32}()
+    rc = GC.@preserve t ccall((:futhark_project_opaque_point_y, LIB), Cint, (Ptr{Cvoid}, Ptr{Float32}, Ptr{Cvoid}), check_handle(t.ctx), out, check_ptr(t))
+    check_rc(t.ctx, rc)
+    auto_sync!(t.ctx)
+    out[]
+end
+
+"""
+Create a new Point from its fields
+"""
+function Point(ctx::Context, fieldx, fieldy)
+    out = Ref{Ptr{Cvoid}}(C_NULL)
+    rc = GC.@preserve ctx ccall((:futhark_new_opaque_point, LIB), Cint, (Ptr{Cvoid}, Ptr{Ptr{Cvoid}}, Float32, Float32), check_handle(ctx), out, fieldx, fieldy)
+    check_rc(ctx, rc)
+    auto_sync!(ctx)
+    Point(ctx, out[])
+end
+
+"""
+Futhark type
+"""
+mutable struct Segment
+    ptr::Ptr{Cvoid}
+    ctx::Context
+
+    Segment(ctx::Context, ptr::Ptr{Cvoid}) = finalizer(free!, new(ptr, ctx))
+end
+
+"""
+Free the value, it is also freed when it is garbage collected
+"""
+function free!(t::Segment)
+    if t.ptr != C_NULL && t.ctx.handle != C_NULL
+        ccall((:futhark_free_opaque_segment, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}), t.ctx.handle, t.ptr)
+    end
+    t.ptr = C_NULL
+    nothing
+end
+
+function check_ptr(t::Segment)
+    t.ptr == C_NULL && error("Value has already been freed")
+    t.ptr
+end
+
+"""
+Get the start field
+"""
+function get_start(t::Segment)
+    out = Ref{Ptr{Cvoid}}(C_NULL)
+    rc = GC.@preserve t ccall((:futhark_project_opaque_segment_start, LIB), Cint, (Ptr{Cvoid}, Ptr{Ptr{Cvoid}}, Ptr{Cvoid}), check_handle(t.ctx), out, check_ptr(t))
+    check_rc(t.ctx, rc)
+    auto_sync!(t.ctx)
+    Point(t.ctx, out[])
+end
+
+"""
+Get the end field
+"""
+function get_end(t::Segment)
+    out = Ref{Ptr{Cvoid}}(C_NULL)
+    rc = GC.@preserve t ccall((:futhark_project_opaque_segment_end, LIB), Cint, (Ptr{Cvoid}, Ptr{Ptr{Cvoid}}, Ptr{Cvoid}), check_handle(t.ctx), out, check_ptr(t))
+    check_rc(t.ctx, rc)
+    auto_sync!(t.ctx)
+    Point(t.ctx, out[])
+end
+
+"""
+Create a new Segment from its fields
+"""
+function Segment(ctx::Context, fieldstart::Point, fieldend::Point)
+    out = Ref{Ptr{Cvoid}}(C_NULL)
+    rc = GC.@preserve ctx fieldstart fieldend ccall((:futhark_new_opaque_segment, LIB), Cint, (Ptr{Cvoid}, Ptr{Ptr{Cvoid}}, Ptr{Cvoid}, Ptr{Cvoid}), check_handle(ctx), out, check_ptr(fieldstart), check_ptr(fieldend))
+    check_rc(ctx, rc)
+    auto_sync!(ctx)
+    Segment(ctx, out[])
+end
+
+"""
+Futhark type
+"""
+mutable struct Tup
+    ptr::Ptr{Cvoid}
+    ctx::Context
+
+    Tup(ctx::Context, ptr::Ptr{Cvoid}) = finalizer(free!, new(ptr, ctx))
+end
+
+"""
+Free the value, it is also freed when it is garbage collected
+"""
+function free!(t::Tup)
+    if t.ptr != C_NULL && t.ctx.handle != C_NULL
+        ccall((:futhark_free_opaque_tup, LIB), Cint, (Ptr{Cvoid}, Ptr{Cvoid}), t.ctx.handle, t.ptr)
+    end
+    t.ptr = C_NULL
+    nothing
+end
+
+function check_ptr(t::Tup)
+    t.ptr == C_NULL && error("Value has already been freed")
+    t.ptr
+end
+
+"""
+Get the 0 field
+"""
+function get_0(t::Tup)
+    out = Ref{Ptr{Cvoid}}(C_NULL)
+    rc = GC.@preserve t ccall((:futhark_project_opaque_tup_0, LIB), Cint, (Ptr{Cvoid}, Ptr{Ptr{Cvoid}}, Ptr{Cvoid}), check_handle(t.ctx), out, check_ptr(t))
+    check_rc(t.ctx, rc)
+    auto_sync!(t.ctx)
+    Number(t.ctx, out[])
+end
+
+"""
+Get the 1 field
+"""
+function get_1(t::Tup)
+    out = Ref{Ptr{Cvoid}}(C_NULL)
+    rc = GC.@preserve t ccall((:futhark_project_opaque_tup_1, LIB), Cint, (Ptr{Cvoid}, Ptr{Ptr{Cvoid}}, Ptr{Cvoid}), check_handle(t.ctx), out, check_ptr(t))
+    check_rc(t.ctx, rc)
+    auto_sync!(t.ctx)
+    ArrayF32D1(t.ctx, out[])
+end
+
+"""
+Create a new Tup from its fields
+"""
+function Tup(ctx::Context, field0::Number, field1::ArrayF32D1)
+    out = Ref{Ptr{Cvoid}}(C_NULL)
+    rc = GC.@preserve ctx field0 field1 ccall((:futhark_new_opaque_tup, LIB), Cint, (Ptr{Cvoid}, Ptr{Ptr{Cvoid}}, Ptr{Cvoid}, Ptr{Cvoid}), check_handle(ctx), out, check_ptr(field0), check_ptr(field1))
+    check_rc(ctx, rc)
+    auto_sync!(ctx)
+    Tup(ctx, out[])
+end
+
+"""
+Entry point: distance
+"""
+function distance(ctx::Context, input0::Point, input1::Point)
+    out0 = Ref{Float32}()
+    rc = GC.@preserve ctx input0 input1 ccall((:futhark_entry_distance, LIB), Cint, (Ptr{Cvoid}, Ptr{Float32}, Ptr{Cvoid}, Ptr{Cvoid}), check_handle(ctx), out0, check_ptr(input0), check_ptr(input1))
+    check_rc(ctx, rc)
+    auto_sync!(ctx)
+    out0[]
+end
+
+"""
+Entry point: length
+"""
+function length(ctx::Context, input0::Segment)
+    out0 = Ref{Float32}()
+    rc = GC.@preserve ctx input0 ccall((:futhark_entry_length, LIB), Cint, (Ptr{Cvoid}, Ptr{Float32}, Ptr{Cvoid}), check_handle(ctx), out0, check_ptr(input0))
+    check_rc(ctx, rc)
+    auto_sync!(ctx)
+    out0[]
+end
+
+"""
+Entry point: points
+"""
+function points(ctx::Context, input0::ArrayF32D1, input1::ArrayF32D1)
+    out0 = Ref{Ptr{Cvoid}}(C_NULL)
+    rc = GC.@preserve ctx input0 input1 ccall((:futhark_entry_points, LIB), Cint, (Ptr{Cvoid}, Ptr{Ptr{Cvoid}}, Ptr{Cvoid}, Ptr{Cvoid}), check_handle(ctx), out0, check_ptr(input0), check_ptr(input1))
+    check_rc(ctx, rc)
+    auto_sync!(ctx)
+    Arr_point_1d(ctx, out0[])
+end
+
+"""
+Entry point: return_option
+"""
+function return_option(ctx::Context, input0::Option)
+    out0 = Ref{Ptr{Cvoid}}(C_NULL)
+    rc = GC.@preserve ctx input0 ccall((:futhark_entry_return_option, LIB), Cint, (Ptr{Cvoid}, Ptr{Ptr{Cvoid}}, Ptr{Cvoid}), check_handle(ctx), out0, check_ptr(input0))
+    check_rc(ctx, rc)
+    auto_sync!(ctx)
+    Option(ctx, out0[])
+end
+
+"""
+Entry point: swap
+"""
+function swap(ctx::Context, input0::Type2b0ad0ae)
+    out0 = Ref{Float32}()
+    out1 = Ref{Int32}()
+    rc = GC.@preserve ctx input0 ccall((:futhark_entry_swap, LIB), Cint, (Ptr{Cvoid}, Ptr{Float32}, Ptr{Int32}, Ptr{Cvoid}), check_handle(ctx), out0, out1, check_ptr(input0))
+    check_rc(ctx, rc)
+    auto_sync!(ctx)
+    (out0[], out1[])
+end
+
+"""
+Entry point: tup_mul
+"""
+function tup_mul(ctx::Context, input0::Tup)
+    out0 = Ref{Ptr{Cvoid}}(C_NULL)
+    rc = GC.@preserve ctx input0 ccall((:futhark_entry_tup_mul, LIB), Cint, (Ptr{Cvoid}, Ptr{Ptr{Cvoid}}, Ptr{Cvoid}), check_handle(ctx), out0, check_ptr(input0))
+    check_rc(ctx, rc)
+    auto_sync!(ctx)
+    ArrayF32D1(ctx, out0[])
+end
+
+"""
+Entry point: unzip_points
+"""
+function unzip_points(ctx::Context, input0::Arr_point_1d)
+    out0 = Ref{Ptr{Cvoid}}(C_NULL)
+    out1 = Ref{Ptr{Cvoid}}(C_NULL)
+    rc = GC.@preserve ctx input0 ccall((:futhark_entry_unzip_points, LIB), Cint, (Ptr{Cvoid}, Ptr{Ptr{Cvoid}}, Ptr{Ptr{Cvoid}}, Ptr{Cvoid}), check_handle(ctx), out0, out1, check_ptr(input0))
+    check_rc(ctx, rc)
+    auto_sync!(ctx)
+    (ArrayF32D1(ctx, out0[]), ArrayF32D1(ctx, out1[]))
+end
+
+end # module
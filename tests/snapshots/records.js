@@ -0,0 +1,568 @@
+// Generated by futhark-bindgen
+
+// `module` is an instantiated Emscripten module created by `futhark wasm --library` or
+// `futhark wasm-multicore --library`, 64-bit integers are passed as `BigInt` values
+
+/** Thrown when a Futhark function returns a non-zero error code */
+export class FutharkError extends Error {
+  constructor(code, message) {
+    super(`Futhark error code ${code}: ${message}`);
+    this.code = code;
+  }
+}
+
+/** Thrown when a Futhark function returns a NULL pointer */
+export class NullPtrError extends Error {
+  constructor() {
+    super("NULL pointer encountered");
+  }
+}
+
+/** Thrown when data doesn't match the shape of an array */
+export class InvalidShapeError extends Error {
+  constructor(expected, actual) {
+    super(`Invalid shape: expected ${expected} elements, got ${actual}`);
+  }
+}
+
+// Frees values that are garbage collected without calling `free`
+const registry =
+  typeof FinalizationRegistry !== "undefined"
+    ? new FinalizationRegistry((free) => free())
+    : null;
+
+function readString(module, ptr) {
+  if (ptr === 0) return null;
+  let end = ptr;
+  while (module.HEAPU8[end] !== 0) end++;
+  const s = new TextDecoder().decode(module.HEAPU8.slice(ptr, end));
+  module._free(ptr);
+  return s;
+}
+
+/** Futhark context */
+export class Context {
+  constructor(module, options = {}) {
+    const m = module;
+    const config = m._futhark_context_config_new();
+    if (config === 0) throw new NullPtrError();
+    m._futhark_context_config_set_debugging(config, options.debug ? 1 : 0);
+    m._futhark_context_config_set_profiling(config, options.profile ? 1 : 0);
+    m._futhark_context_config_set_logging(config, options.logging ? 1 : 0);
+
+    const handle = m._futhark_context_new(config);
+    if (handle === 0) {
+      m._futhark_context_config_free(config);
+      throw new NullPtrError();
+    }
+    this.module = m;
+    this.config = config;
+    this.handle = handle;
+    this.autoSync = options.autoSync ?? true;
+  }
+
+  /** Free the context, values created using the context can no longer be used */
+  free() {
+    if (this.handle === 0) return;
+    this.module._futhark_context_sync(this.handle);
+    this.module._futhark_context_free(this.handle);
+    this.module._futhark_context_config_free(this.config);
+    this.handle = 0;
+  }
+
+  checkHandle() {
+    if (this.handle === 0) throw new Error("Context has already been freed");
+    return this.handle;
+  }
+
+  check(rc) {
+    if (rc !== 0) throw new FutharkError(rc, this.getError() ?? "");
+  }
+
+  /** Wait for all pending operations to finish */
+  sync() {
+    this.check(this.module._futhark_context_sync(this.checkHandle()));
+  }
+
+  maybeSync() {
+    if (this.autoSync) this.sync();
+  }
+
+  /** Free any cached memory */
+  clearCaches() {
+    this.check(this.module._futhark_context_clear_caches(this.checkHandle()));
+  }
+
+  /** Pause profiling */
+  pauseProfiling() {
+    this.module._futhark_context_pause_profiling(this.checkHandle());
+  }
+
+  /** Resume profiling */
+  unpauseProfiling() {
+    this.module._futhark_context_unpause_profiling(this.checkHandle());
+  }
+
+  /** Get the last error message */
+  getError() {
+    return readString(this.module, this.module._futhark_context_get_error(this.checkHandle()));
+  }
+
+  /** Get the profiling report */
+  report() {
+    return readString(this.module, this.module._futhark_context_report(this.checkHandle()));
+  }
+
+  // Allocate `size` bytes of scratch memory, pass it to `f` and free it afterwards
+  withScratch(size, f) {
+    const ptr = this.module._malloc(Math.max(size, 8));
+    if (ptr === 0) throw new NullPtrError();
+    try {
+      return f(ptr);
+    } finally {
+      this.module._free(ptr);
+    }
+  }
+
+  view(type, ptr, length) {
+    return new type(this.module.HEAPU8.buffer, ptr, length);
+  }
+}
+
+/** Futhark type */
+export class Type2b0ad0ae {
+  constructor(ctx, ptr) {
+    this.ctx = ctx;
+    this.ptr = ptr;
+    registry?.register(this, () => {
+      if (ctx.handle !== 0) ctx.module._futhark_free_opaque_2b0ad0ae(ctx.handle, ptr);
+    }, this);
+  }
+
+  checkPtr() {
+    if (this.ptr === 0) throw new Error("Value has already been freed");
+    return this.ptr;
+  }
+
+  /** Free the value, it is also freed when it is garbage collected */
+  free() {
+    if (this.ptr !== 0 && this.ctx.handle !== 0) {
+      this.ctx.module._futhark_free_opaque_2b0ad0ae(this.ctx.handle, this.ptr);
+    }
+    this.ptr = 0;
+    registry?.unregister(this);
+  }
+
+  /** Create a new Type2b0ad0ae from its fields */
+  static create(ctx, field0, field1) {
+    return ctx.withScratch(4, (out) => {
+      ctx.check(ctx.module._futhark_new_opaque_2b0ad0ae(ctx.checkHandle(), out, field0, field1));
+      ctx.maybeSync();
+      return new Type2b0ad0ae(ctx, ctx.view(Uint32Array, out, 1)[0]);
+    });
+  }
+
+  /** Get the 0 field */
+  getField0() {
+    const ctx = this.ctx;
+    return ctx.withScratch(8, (out) => {
+      ctx.check(ctx.module._futhark_project_opaque_2b0ad0ae_0(ctx.checkHandle(), out, this.checkPtr()));
+      ctx.maybeSync();
+      return ctx.view(Int32Array, out, 1)[0];
+    });
+  }
+
+  /** Get the 1 field */
+  getField1() {
+    const ctx = this.ctx;
+    return ctx.withScratch(8, (out) => {
+      ctx.check(ctx.module._futhark_project_opaque_2b0ad0ae_1(ctx.checkHandle(), out, this.checkPtr()));
+      ctx.maybeSync();
+      return ctx.view(Float32Array, out, 1)[0];
+    });
+  }
+}
+
+/** Array type with 1 dimensions and f32 elements */
+export class ArrayF32D1 {
+  constructor(ctx, ptr) {
+    const shape = ctx.module._futhark_shape_f32_1d(ctx.handle, ptr);
+    this.ctx = ctx;
+    this.ptr = ptr;
+    this.shape = Array.from(ctx.view(BigInt64Array, shape, 1), Number);
+    registry?.register(this, () => {
+      if (ctx.handle !== 0) ctx.module._futhark_free_f32_1d(ctx.handle, ptr);
+    }, this);
+  }
+
+  /** Create a new array with the shape `dims`, initialized with the values from `data` */
+  static from(ctx, data, dims = [data.length]) {
+    const size = dims.reduce((a, b) => a * b, 1);
+    if (dims.length !== 1 || size !== data.length) {
+      throw new InvalidShapeError(size, data.length);
+    }
+    const ptr = ctx.withScratch(size * 4, (p) => {
+      ctx.view(Float32Array, p, size).set(data);
+      return ctx.module._futhark_new_f32_1d(ctx.checkHandle(), p, ...dims.map(BigInt));
+    });
+    if (ptr === 0) throw new NullPtrError();
+    ctx.maybeSync();
+    return new ArrayF32D1(ctx, ptr);
+  }
+
+  checkPtr() {
+    if (this.ptr === 0) throw new Error("Array has already been freed");
+    return this.ptr;
+  }
+
+  /** Number of elements in the array */
+  get length() {
+    return this.shape.reduce((a, b) => a * b, 1);
+  }
+
+  /** Copy the values into a new typed array */
+  values() {
+    const ctx = this.ctx;
+    return ctx.withScratch(this.length * 4, (p) => {
+      ctx.check(ctx.module._futhark_values_f32_1d(ctx.checkHandle(), this.checkPtr(), p));
+      ctx.maybeSync();
+      return ctx.view(Float32Array, p, this.length).slice();
+    });
+  }
+
+  /** Free the array, it is also freed when it is garbage collected */
+  free() {
+    if (this.ptr !== 0 && this.ctx.handle !== 0) {
+      this.ctx.module._futhark_free_f32_1d(this.ctx.handle, this.ptr);
+    }
+    this.ptr = 0;
+    registry?.unregister(this);
+  }
+}
+
+/** Futhark type */
+export class Arr_point_1d {
+  constructor(ctx, ptr) {
+    this.ctx = ctx;
+    this.ptr = ptr;
+    registry?.register(this, () => {
+      if (ctx.handle !== 0) ctx.module._futhark_free_opaque_arr_point_1d(ctx.handle, ptr);
+    }, this);
+  }
+
+  checkPtr() {
+    if (this.ptr === 0) throw new Error("Value has already been freed");
+    return this.ptr;
+  }
+
+  /** Free the value, it is also freed when it is garbage collected */
+  free() {
+    if (this.ptr !== 0 && this.ctx.handle !== 0) {
+      this.ctx.module._futhark_free_opaque_arr_point_1d(this.ctx.handle, this.ptr);
+    }
+    this.ptr = 0;
+    registry?.unregister(this);
+  }
+}
+
+/** Futhark type */
+export class Number {
+  constructor(ctx, ptr) {
+    this.ctx = ctx;
+    this.ptr = ptr;
+    registry?.register(this, () => {
+      if (ctx.handle !== 0) ctx.module._futhark_free_opaque_number(ctx.handle, ptr);
+    }, this);
+  }
+
+  checkPtr() {
+    if (this.ptr === 0) throw new Error("Value has already been freed");
+    return this.ptr;
+  }
+
+  /** Free the value, it is also freed when it is garbage collected */
+  free() {
+    if (this.ptr !== 0 && this.ctx.handle !== 0) {
+      this.ctx.module._futhark_free_opaque_number(this.ctx.handle, this.ptr);
+    }
+    this.ptr = 0;
+    registry?.unregister(this);
+  }
+
+  /** Create a new Number from its fields */
+  static create(ctx, fieldx) {
+    return ctx.withScratch(4, (out) => {
+      ctx.check(ctx.module._futhark_new_opaque_number(ctx.checkHandle(), out, fieldx));
+      ctx.maybeSync();
+      return new Number(ctx, ctx.view(Uint32Array, out, 1)[0]);
+    });
+  }
+
+  /** Get the x field */
+  getX() {
+    const ctx = this.ctx;
+    return ctx.withScratch(8, (out) => {
+      ctx.check(ctx.module._futhark_project_opaque_number_x(ctx.checkHandle(), out, this.checkPtr()));
+      ctx.maybeSync();
+      return ctx.view(Float32Array, out, 1)[0];
+    });
+  }
+}
+
+/** Futhark type */
+export class Option {
+  constructor(ctx, ptr) {
+    this.ctx = ctx;
+    this.ptr = ptr;
+    registry?.register(this, () => {
+      if (ctx.handle !== 0) ctx.module._futhark_free_opaque_option(ctx.handle, ptr);
+    }, this);
+  }
+
+  checkPtr() {
+    if (this.ptr === 0) throw new Error("Value has already been freed");
+    return this.ptr;
+  }
+
+  /** Free the value, it is also freed when it is garbage collected */
+  free() {
+    if (this.ptr !== 0 && this.ctx.handle !== 0) {
+      this.ctx.module._futhark_free_opaque_option(this.ctx.handle, this.ptr);
+    }
+    this.ptr = 0;
+    registry?.unregister(this);
+  }
+}
+
+/** Futhark type */
+export class Point {
+  constructor(ctx, ptr) {
+    this.ctx = ctx;
+    this.ptr = ptr;
+    registry?.register(this, () => {
+      if (ctx.handle !== 0) ctx.module._futhark_free_opaque_point(ctx.handle, ptr);
+    }, this);
+  }
+
+  checkPtr() {
+    if (this.ptr === 0) throw new Error("Value has already been freed");
+    return this.ptr;
+  }
+
+  /** Free the value, it is also freed when it is garbage collected */
+  free() {
+    if (this.ptr !== 0 && this.ctx.handle !== 0) {
+      this.ctx.module._futhark_free_opaque_point(this.ctx.handle, this.ptr);
+    }
+    this.ptr = 0;
+    registry?.unregister(this);
+  }
+
+  /** Create a new Point from its fields */
+  static create(ctx, fieldx, fieldy) {
+    return ctx.withScratch(4, (out) => {
+      ctx.check(ctx.module._futhark_new_opaque_point(ctx.checkHandle(), out, fieldx, fieldy));
+      ctx.maybeSync();
+      return new Point(ctx, ctx.view(Uint32Array, out, 1)[0]);
+    });
+  }
+
+  /** Get the x field */
+  getX() {
+    const ctx = this.ctx;
+    return ctx.withScratch(8, (out) => {
+      ctx.check(ctx.module._futhark_project_opaque_point_x(ctx.checkHandle(), out, this.checkPtr()));
+      ctx.maybeSync();
+      return ctx.view(Float32Array, out, 1)[0];
+    });
+  }
+
+  /** Get the y field */
+  getY() {
+    const ctx = this.ctx;
+    return ctx.withScratch(8, (out) => {
+      ctx.check(ctx.module._futhark_project_opaque_point_y(ctx.checkHandle(), out, this.checkPtr()));
+      ctx.maybeSync();
+      return ctx.view(Float32Array, out, 1)[0];
+    });
+  }
+}
+
+/** Futhark type */
+export class Segment {
+  constructor(ctx, ptr) {
+    this.ctx = ctx;
+    this.ptr = ptr;
+    registry?.register(this, () => {
+      if (ctx.handle !== 0) ctx.module._futhark_free_opaque_segment(ctx.handle, ptr);
+    }, this);
+  }
+
+  checkPtr() {
+    if (this.ptr === 0) throw new Error("Value has already been freed");
+    return this.ptr;
+  }
+
+  /** Free the value, it is also freed when it is garbage collected */
+  free() {
+    if (this.ptr !== 0 && this.ctx.handle !== 0) {
+      this.ctx.module._futhark_free_opaque_segment(this.ctx.handle, this.ptr);
+    }
+    this.ptr = 0;
+    registry?.unregister(this);
+  }
+
+  /** Create a new Segment from its fields */
+  static create(ctx, fieldstart, fieldend) {
+    return ctx.withScratch(4, (out) => {
+      ctx.check(ctx.module._futhark_new_opaque_segment(ctx.checkHandle(), out, fieldstart.checkPtr(), fieldend.checkPtr()));
+      ctx.maybeSync();
+      return new Segment(ctx, ctx.view(Uint32Array, out, 1)[0]);
+    });
+  }
+
+  /** Get the start field */
+  getStart() {
+    const ctx = this.ctx;
+    return ctx.withScratch(8, (out) => {
+      ctx.check(ctx.module._futhark_project_opaque_segment_start(ctx.checkHandle(), out, this.checkPtr()));
+      ctx.maybeSync();
+      return new Point(ctx, ctx.view(Uint32Array, out, 1)[0]);
+    });
+  }
+
+  /** Get the end field */
+  getEnd() {
+    const ctx = this.ctx;
+    return ctx.withScratch(8, (out) => {
+      ctx.check(ctx.module._futhark_project_opaque_segment_end(ctx.checkHandle(), out, this.checkPtr()));
+      ctx.maybeSync();
+      return new Point(ctx, ctx.view(Uint32Array, out, 1)[0]);
+    });
+  }
+}
+
+/** Futhark type */
+export class Tup {
+  constructor(ctx, ptr) {
+    this.ctx = ctx;
+    this.ptr = ptr;
+    registry?.register(this, () => {
+      if (ctx.handle !== 0) ctx.module._futhark_free_opaque_tup(ctx.handle, ptr);
+    }, this);
+  }
+
+  checkPtr() {
+    if (this.ptr === 0) throw new Error("Value has already been freed");
+    return this.ptr;
+  }
+
+  /** Free the value, it is also freed when it is garbage collected */
+  free() {
+    if (this.ptr !== 0 && this.ctx.handle !== 0) {
+      this.ctx.module._futhark_free_opaque_tup(this.ctx.handle, this.ptr);
+    }
+    this.ptr = 0;
+    registry?.unregister(this);
+  }
+
+  /** Create a new Tup from its fields */
+  static create(ctx, field0, field1) {
+    return ctx.withScratch(4, (out) => {
+      ctx.check(ctx.module._futhark_new_opaque_tup(ctx.checkHandle(), out, field0.checkPtr(), field1.checkPtr()));
+      ctx.maybeSync();
+      return new Tup(ctx, ctx.view(Uint32Array, out, 1)[0]);
+    });
+  }
+
+  /** Get the 0 field */
+  getField0() {
+    const ctx = this.ctx;
+    return ctx.withScratch(8, (out) => {
+      ctx.check(ctx.module._futhark_project_opaque_tup_0(ctx.checkHandle(), out, this.checkPtr()));
+      ctx.maybeSync();
+      return new Number(ctx, ctx.view(Uint32Array, out, 1)[0]);
+    });
+  }
+
+  /** Get the 1 field */
+  getField1() {
+    const ctx = this.ctx;
+    return ctx.withScratch(8, (out) => {
+      ctx.check(ctx.module._futhark_project_opaque_tup_1(ctx.checkHandle(), out, this.checkPtr()));
+      ctx.maybeSync();
+      return new ArrayF32D1(ctx, ctx.view(Uint32Array, out, 1)[0]);
+    });
+  }
+}
+
+/** Entry point: distance */
+Context.prototype.distance = function (input0, input1) {
+  const ctx = this;
+  return ctx.withScratch(8, (out) => {
+    ctx.check(ctx.module._futhark_entry_distance(ctx.checkHandle(), out, input0.checkPtr(), input1.checkPtr()));
+    ctx.maybeSync();
+    return ctx.view(Float32Array, out, 1)[0];
+  });
+};
+
+/** Entry point: length */
+Context.prototype.length = function (input0) {
+  const ctx = this;
+  return ctx.withScratch(8, (out) => {
+    ctx.check(ctx.module._futhark_entry_length(ctx.checkHandle(), out, input0.checkPtr()));
+    ctx.maybeSync();
+    return ctx.view(Float32Array, out, 1)[0];
+  });
+};
+
+/** Entry point: points */
+Context.prototype.points = function (input0, input1) {
+  const ctx = this;
+  return ctx.withScratch(8, (out) => {
+    ctx.check(ctx.module._futhark_entry_points(ctx.checkHandle(), out, input0.checkPtr(), input1.checkPtr()));
+    ctx.maybeSync();
+    return new Arr_point_1d(ctx, ctx.view(Uint32Array, out, 1)[0]);
+  });
+};
+
+/** Entry point: return_option */
+Context.prototype.returnOption = function (input0) {
+  const ctx = this;
+  return ctx.withScratch(8, (out) => {
+    ctx.check(ctx.module._futhark_entry_return_option(ctx.checkHandle(), out, input0.checkPtr()));
+    ctx.maybeSync();
+    return new Option(ctx, ctx.view(Uint32Array, out, 1)[0]);
+  });
+};
+
+/** Entry point: swap */
+Context.prototype.swap = function (input0) {
+  const ctx = this;
+  return ctx.withScratch(16, (out) => {
+    ctx.check(ctx.module._futhark_entry_swap(ctx.checkHandle(), out, out + 8, input0.checkPtr()));
+    ctx.maybeSync();
+    return [ctx.view(Float32Array, out, 1)[0], ctx.view(Int32Array, out + 8, 1)[0]];
+  });
+};
+
+/** Entry point: tup_mul */
+Context.prototype.tupMul = function (input0) {
+  const ctx = this;
+  return ctx.withScratch(8, (out) => {
+    ctx.check(ctx.module._futhark_entry_tup_mul(ctx.checkHandle(), out, input0.checkPtr()));
+    ctx.maybeSync();
+    return new ArrayF32D1(ctx, ctx.view(Uint32Array, out, 1)[0]);
+  });
+};
+
+/** Entry point: unzip_points */
+Context.prototype.unzipPoints = function (input0) {
+  const ctx = this;
+  return ctx.withScratch(16, (out) => {
+    ctx.check(ctx.module._futhark_entry_unzip_points(ctx.checkHandle(), out, out + 8, input0.checkPtr()));
+    ctx.maybeSync();
+    return [new ArrayF32D1(ctx, ctx.view(Uint32Array, out, 1)[0]), new ArrayF32D1(ctx, ctx.view(Uint32Array, out + 8, 1)[0])];
+  });
+};
+
@@ -0,0 +1,600 @@
+-- Generated by futhark-bindgen
+
+local ffi = require("ffi")
+
+ffi.cdef[[
+struct futhark_context_config;
+struct futhark_context;
+struct futhark_context_config *futhark_context_config_new(void);
+void futhark_context_config_free(struct futhark_context_config *cfg);
+void futhark_context_config_set_debugging(struct futhark_context_config *cfg, int flag);
+void futhark_context_config_set_profiling(struct futhark_context_config *cfg, int flag);
+void futhark_context_config_set_logging(struct futhark_context_config *cfg, int flag);
+void futhark_context_config_set_cache_file(struct futhark_context_config *cfg, const char *f);
+void futhark_context_config_set_device(struct futhark_context_config *cfg, const char *s);
+struct futhark_context *futhark_context_new(struct futhark_context_config *cfg);
+void futhark_context_free(struct futhark_context *ctx);
+int futhark_context_sync(struct futhark_context *ctx);
+int futhark_context_clear_caches(struct futhark_context *ctx);
+void futhark_context_pause_profiling(struct futhark_context *ctx);
+void futhark_context_unpause_profiling(struct futhark_context *ctx);
+char *futhark_context_get_error(struct futhark_context *ctx);
+char *futhark_context_report(struct futhark_context *ctx);
+void free(void *ptr);
+]]
+
+-- Shared library containing the compiled Futhark code
+local lib = ffi.load(os.getenv("FUTHARK_LIB_RECORDS") or "records")
+
+local M = {}
+
+local function take_string(s)
+  if s == nil then
+    return nil
+  end
+  local result = ffi.string(s)
+  ffi.C.free(s)
+  return result
+end
+
+--- Futhark context, freed by `free` or when it is garbage collected
+M.Context = {}
+M.Context.__index = M.Context
+
+--- Create a new context, `options` is an optional table with the fields `debug`, `profile`,
+--- `logging`, `cache_file`, `device` and `auto_sync`
+function M.Context.new(options)
+  options = options or {}
+  local config = lib.futhark_context_config_new()
+  if config == nil then
+    error("NULL pointer encountered", 2)
+  end
+  lib.futhark_context_config_set_debugging(config, options.debug and 1 or 0)
+  lib.futhark_context_config_set_profiling(config, options.profile and 1 or 0)
+  lib.futhark_context_config_set_logging(config, options.logging and 1 or 0)
+  if options.cache_file ~= nil then
+    lib.futhark_context_config_set_cache_file(config, options.cache_file)
+  end
+  if options.device ~= nil then
+    lib.futhark_context_config_set_device(config, options.device)
+  end
+  local handle = lib.futhark_context_new(config)
+  if handle == nil then
+    lib.futhark_context_config_free(config)
+    error("NULL pointer encountered", 2)
+  end
+
+  -- Shared with the finalizers of values created using the context
+  local state = { alive = true }
+  local self = setmetatable({
+    config = config,
+    state = state,
+    cache_file = options.cache_file,
+    auto_sync = options.auto_sync ~= false,
+  }, M.Context)
+  self.handle = ffi.gc(handle, function(h)
+    state.alive = false
+    lib.futhark_context_sync(h)
+    lib.futhark_context_free(h)
+    lib.futhark_context_config_free(config)
+  end)
+  return self
+end
+
+--- Free the context, values created using the context can no longer be used
+function M.Context:free()
+  if self.handle == nil then
+    return
+  end
+  local handle = ffi.gc(self.handle, nil)
+  self.state.alive = false
+  lib.futhark_context_sync(handle)
+  lib.futhark_context_free(handle)
+  lib.futhark_context_config_free(self.config)
+  self.handle = nil
+end
+
+function M.Context:check_handle()
+  if self.handle == nil then
+    error("context has already been freed", 3)
+  end
+  return self.handle
+end
+
+function M.Context:check(rc)
+  if rc ~= 0 then
+    error(string.format("Futhark error code %d: %s", rc, self:get_error() or ""), 3)
+  end
+end
+
+--- Wait for all pending operations to finish
+function M.Context:sync()
+  self:check(lib.futhark_context_sync(self:check_handle()))
+end
+
+function M.Context:maybe_sync()
+  if self.auto_sync then
+    self:sync()
+  end
+end
+
+--- Free any cached memory
+function M.Context:clear_caches()
+  self:check(lib.futhark_context_clear_caches(self:check_handle()))
+end
+
+--- Pause profiling
+function M.Context:pause_profiling()
+  lib.futhark_context_pause_profiling(self:check_handle())
+end
+
+--- Resume profiling
+function M.Context:unpause_profiling()
+  lib.futhark_context_unpause_profiling(self:check_handle())
+end
+
+--- Get the last error message
+function M.Context:get_error()
+  return take_string(lib.futhark_context_get_error(self:check_handle()))
+end
+
+--- Get the profiling report
+function M.Context:report()
+  return take_string(lib.futhark_context_report(self:check_handle()))
+end
+
+-- Attach a finalizer to `ptr` that frees it unless the context has been freed first, the
+-- finalizer keeps the context handle alive until the value is collected
+local function manage(ctx, ptr, free)
+  if ptr == nil then
+    error("NULL pointer encountered", 3)
+  end
+  local handle, state = ctx.handle, ctx.state
+  return ffi.gc(ptr, function(p)
+    if state.alive then
+      free(handle, p)
+    end
+  end)
+end
+
+-- Free a value created using `manage`
+local function release(ctx, ptr, free)
+  if ptr ~= nil then
+    ffi.gc(ptr, nil)
+    if ctx.state.alive then
+      free(ctx.handle, ptr)
+    end
+  end
+end
+
+local function check_ptr(value)
+  if value.ptr == nil then
+    error("value has already been freed", 3)
+  end
+  return value.ptr
+end
+
+ffi.cdef[[
+struct futhark_opaque_2b0ad0ae;
+int futhark_free_opaque_2b0ad0ae(struct futhark_context *ctx, struct futhark_opaque_2b0ad0ae *obj);
+int futhark_new_opaque_2b0ad0ae(struct futhark_context *ctx, struct futhark_opaque_2b0ad0ae **out, int32_t field0, float field1);
+int futhark_project_opaque_2b0ad0ae_0(struct futhark_context *ctx, int32_t *out, const struct futhark_opaque_2b0ad0ae *obj);
+int futhark_project_opaque_2b0ad0ae_1(struct futhark_context *ctx, float *out, const struct futhark_opaque_2b0ad0ae *obj);
+]]
+
+--- Futhark type
+M.Type2b0ad0ae = {}
+M.Type2b0ad0ae.__index = M.Type2b0ad0ae
+
+function M.Type2b0ad0ae.wrap(ctx, ptr)
+  return setmetatable({ ctx = ctx, ptr = manage(ctx, ptr, lib.futhark_free_opaque_2b0ad0ae) }, M.Type2b0ad0ae)
+end
+
+--- Free the value, it is also freed when it is garbage collected
+function M.Type2b0ad0ae:free()
+  release(self.ctx, self.ptr, lib.futhark_free_opaque_2b0ad0ae)
+  self.ptr = nil
+end
+
+--- Create a new Type2b0ad0ae from its fields
+function M.Type2b0ad0ae.new(ctx, field0, field1)
+  local out = ffi.new("struct futhark_opaque_2b0ad0ae *[1]")
+  ctx:check(lib.futhark_new_opaque_2b0ad0ae(ctx:check_handle(), out, field0, field1))
+  ctx:maybe_sync()
+  return M.Type2b0ad0ae.wrap(ctx, out[0])
+end
+
+--- Get the 0 field
+function M.Type2b0ad0ae:get_0()
+  local out = ffi.new("int32_t[1]")
+  self.ctx:check(lib.futhark_project_opaque_2b0ad0ae_0(self.ctx:check_handle(), out, check_ptr(self)))
+  self.ctx:maybe_sync()
+  return out[0]
+end
+
+--- Get the 1 field
+function M.Type2b0ad0ae:get_1()
+  local out = ffi.new("float[1]")
+  self.ctx:check(lib.futhark_project_opaque_2b0ad0ae_1(self.ctx:check_handle(), out, check_ptr(self)))
+  self.ctx:maybe_sync()
+  return out[0]
+end
+
+ffi.cdef[[
+struct futhark_f32_1d;
+struct futhark_f32_1d *futhark_new_f32_1d(struct futhark_context *ctx, const float *data, int64_t dim0);
+int futhark_free_f32_1d(struct futhark_context *ctx, struct futhark_f32_1d *arr);
+int futhark_values_f32_1d(struct futhark_context *ctx, struct futhark_f32_1d *arr, float *data);
+const int64_t *futhark_shape_f32_1d(struct futhark_context *ctx, struct futhark_f32_1d *arr);
+]]
+
+--- Array type with 1 dimensions and f32 elements
+M.ArrayF32D1 = {}
+M.ArrayF32D1.__index = M.ArrayF32D1
+
+function M.ArrayF32D1.wrap(ctx, ptr)
+  ptr = manage(ctx, ptr, lib.futhark_free_f32_1d)
+  local shape = lib.futhark_shape_f32_1d(ctx.handle, ptr)
+  return setmetatable({ ctx = ctx, ptr = ptr, shape = { tonumber(shape[0]) } }, M.ArrayF32D1)
+end
+
+--- Create a new array with the shape `dims` from a flat table or a pointer to `float`
+--- values, `dims` defaults to the length of the table
+function M.ArrayF32D1.new(ctx, data, dims)
+  local buf = data
+  if type(data) == "table" then
+    dims = dims or { #data }
+  elseif dims == nil then
+    error("dims are required when data is not a table", 2)
+  end
+  local size = 1
+  for _, d in ipairs(dims) do
+    size = size * d
+  end
+  if type(data) == "table" then
+    if #dims ~= 1 or size ~= #data then
+      error(string.format("invalid shape: expected %d elements, got %d", size, #data), 2)
+    end
+    buf = ffi.new("float[?]", size)
+    for i = 1, size do
+      buf[i - 1] = data[i]
+    end
+  end
+  local ptr = lib.futhark_new_f32_1d(ctx:check_handle(), buf, dims[1])
+  ctx:maybe_sync()
+  return M.ArrayF32D1.wrap(ctx, ptr)
+end
+
+--- Number of elements in the array
+function M.ArrayF32D1:size()
+  local size = 1
+  for _, d in ipairs(self.shape) do
+    size = size * d
+  end
+  return size
+end
+
+--- Copy the values into a new `float` array
+function M.ArrayF32D1:values()
+  local out = ffi.new("float[?]", self:size())
+  self.ctx:check(lib.futhark_values_f32_1d(self.ctx:check_handle(), check_ptr(self), out))
+  self.ctx:maybe_sync()
+  return out
+end
+
+--- Copy the values into a new flat table
+function M.ArrayF32D1:to_table()
+  local out = self:values()
+  local t = {}
+  for i = 1, self:size() do
+    t[i] = out[i - 1]
+  end
+  return t
+end
+
+--- Free the array, it is also freed when it is garbage collected
+function M.ArrayF32D1:free()
+  release(self.ctx, self.ptr, lib.futhark_free_f32_1d)
+  self.ptr = nil
+end
+
+ffi.cdef[[
+struct futhark_opaque_arr_point_1d;
+int futhark_free_opaque_arr_point_1d(struct futhark_context *ctx, struct futhark_opaque_arr_point_1d *obj);
+]]
+
+--- Futhark type
+M.Arr_point_1d = {}
+M.Arr_point_1d.__index = M.Arr_point_1d
+
+function M.Arr_point_1d.wrap(ctx, ptr)
+  return setmetatable({ ctx = ctx, ptr = manage(ctx, ptr, lib.futhark_free_opaque_arr_point_1d) }, M.Arr_point_1d)
+end
+
+--- Free the value, it is also freed when it is garbage collected
+function M.Arr_point_1d:free()
+  release(self.ctx, self.ptr, lib.futhark_free_opaque_arr_point_1d)
+  self.ptr = nil
+end
+
+ffi.cdef[[
+struct futhark_opaque_number;
+int futhark_free_opaque_number(struct futhark_context *ctx, struct futhark_opaque_number *obj);
+int futhark_new_opaque_number(struct futhark_context *ctx, struct futhark_opaque_number **out, float fieldx);
+int futhark_project_opaque_number_x(struct futhark_context *ctx, float *out, const struct futhark_opaque_number *obj);
+]]
+
+--- Futhark type
+M.Number = {}
+M.Number.__index = M.Number
+
+function M.Number.wrap(ctx, ptr)
+  return setmetatable({ ctx = ctx, ptr = manage(ctx, ptr, lib.futhark_free_opaque_number) }, M.Number)
+end
+
+--- Free the value, it is also freed when it is garbage collected
+function M.Number:free()
+  release(self.ctx, self.ptr, lib.futhark_free_opaque_number)
+  self.ptr = nil
+end
+
+--- Create a new Number from its fields
+function M.Number.new(ctx, fieldx)
+  local out = ffi.new("struct futhark_opaque_number *[1]")
+  ctx:check(lib.futhark_new_opaque_number(ctx:check_handle(), out, fieldx))
+  ctx:maybe_sync()
+  return M.Number.wrap(ctx, out[0])
+end
+
+--- Get the x field
+function M.Number:get_x()
+  local out = ffi.new("float[1]")
+  self.ctx:check(lib.futhark_project_opaque_number_x(self.ctx:check_handle(), out, check_ptr(self)))
+  self.ctx:maybe_sync()
+  return out[0]
+end
+
+ffi.cdef[[
+struct futhark_opaque_option;
+int futhark_free_opaque_option(struct futhark_context *ctx, struct futhark_opaque_option *obj);
+]]
+
+--- Futhark type
+M.Option = {}
+M.Option.__index = M.Option
+
+function M.Option.wrap(ctx, ptr)
+  return setmetatable({ ctx = ctx, ptr = manage(ctx, ptr, lib.futhark_free_opaque_option) }, M.Option)
+end
+
+--- Free the value, it is also freed when it is garbage collected
+function M.Option:free()
+  release(self.ctx, self.ptr, lib.futhark_free_opaque_option)
+  self.ptr = nil
+end
+
+ffi.cdef[[
+struct futhark_opaque_point;
+int futhark_free_opaque_point(struct futhark_context *ctx, struct futhark_opaque_point *obj);
+int futhark_new_opaque_point(struct futhark_context *ctx, struct futhark_opaque_point **out, float fieldx, float fieldy);
+int futhark_project_opaque_point_x(struct futhark_context *ctx, float *out, const struct futhark_opaque_point *obj);
+int futhark_project_opaque_point_y(struct futhark_context *ctx, float *out, const struct futhark_opaque_point *obj);
+]]
+
+--- Futhark type
+M.Point = {}
+M.Point.__index = M.Point
+
+function M.Point.wrap(ctx, ptr)
+  return setmetatable({ ctx = ctx, ptr = manage(ctx, ptr, lib.futhark_free_opaque_point) }, M.Point)
+end
+
+--- Free the value, it is also freed when it is garbage collected
+function M.Point:free()
+  release(self.ctx, self.ptr, lib.futhark_free_opaque_point)
+  self.ptr = nil
+end
+
+--- Create a new Point from its fields
+function M.Point.new(ctx, fieldx, fieldy)
+  local out = ffi.new("struct futhark_opaque_point *[1]")
+  ctx:check(lib.futhark_new_opaque_point(ctx:check_handle(), out, fieldx, fieldy))
+  ctx:maybe_sync()
+  return M.Point.wrap(ctx, out[0])
+end
+
+--- Get the x field
+function M.Point:get_x()
+  local out = ffi.new("float[1]")
+  self.ctx:check(lib.futhark_project_opaque_point_x(self.ctx:check_handle(), out, check_ptr(self)))
+  self.ctx:maybe_sync()
+  return out[0]
+end
+
+--- Get the y field
+function M.Point:get_y()
+  local out = ffi.new("float[1]")
+  self.ctx:check(lib.futhark_project_opaque_point_y(self.ctx:check_handle(), out, check_ptr(self)))
+  self.ctx:maybe_sync()
+  return out[0]
+end
+
+ffi.cdef[[
+struct futhark_opaque_segment;
+int futhark_free_opaque_segment(struct futhark_context *ctx, struct futhark_opaque_segment *obj);
+int futhark_new_opaque_segment(struct futhark_context *ctx, struct futhark_opaque_segment **out, const struct futhark_opaque_point *fieldstart, const struct futhark_opaque_point *fieldend);
+int futhark_project_opaque_segment_start(struct futhark_context *ctx, struct futhark_opaque_point **out, const struct futhark_opaque_segment *obj);
+int futhark_project_opaque_segment_end(struct futhark_context *ctx, struct futhark_opaque_point **out, const struct futhark_opaque_segment *obj);
+]]
+
+--- Futhark type
+M.Segment = {}
+M.Segment.__index = M.Segment
+
+function M.Segment.wrap(ctx, ptr)
+  return setmetatable({ ctx = ctx, ptr = manage(ctx, ptr, lib.futhark_free_opaque_segment) }, M.Segment)
+end
+
+--- Free the value, it is also freed when it is garbage collected
+function M.Segment:free()
+  release(self.ctx, self.ptr, lib.futhark_free_opaque_segment)
+  self.ptr = nil
+end
+
+--- Create a new Segment from its fields
+function M.Segment.new(ctx, fieldstart, fieldend)
+  local out = ffi.new("struct futhark_opaque_segment *[1]")
+  ctx:check(lib.futhark_new_opaque_segment(ctx:check_handle(), out, check_ptr(fieldstart), check_ptr(fieldend)))
+  ctx:maybe_sync()
+  return M.Segment.wrap(ctx, out[0])
+end
+
+--- Get the start field
+function M.Segment:get_start()
+  local out = ffi.new("struct futhark_opaque_point *[1]")
+  self.ctx:check(lib.futhark_project_opaque_segment_start(self.ctx:check_handle(), out, check_ptr(self)))
+  self.ctx:maybe_sync()
+  return M.Point.wrap(self.ctx, out[0])
+end
+
+--- Get the end field
+function M.Segment:get_end()
+  local out = ffi.new("struct futhark_opaque_point *[1]")
+  self.ctx:check(lib.futhark_project_opaque_segment_end(self.ctx:check_handle(), out, check_ptr(self)))
+  self.ctx:maybe_sync()
+  return M.Point.wrap(self.ctx, out[0])
+end
+
+ffi.cdef[[
+struct futhark_opaque_tup;
+int futhark_free_opaque_tup(struct futhark_context *ctx, struct futhark_opaque_tup *obj);
+int futhark_new_opaque_tup(struct futhark_context *ctx, struct futhark_opaque_tup **out, const struct futhark_opaque_number *field0, const struct futhark_f32_1d *field1);
+int futhark_project_opaque_tup_0(struct futhark_context *ctx, struct futhark_opaque_number **out, const struct futhark_opaque_tup *obj);
+int futhark_project_opaque_tup_1(struct futhark_context *ctx, struct futhark_f32_1d **out, const struct futhark_opaque_tup *obj);
+]]
+
+--- Futhark type
+M.Tup = {}
+M.Tup.__index = M.Tup
+
+function M.Tup.wrap(ctx, ptr)
+  return setmetatable({ ctx = ctx, ptr = manage(ctx, ptr, lib.futhark_free_opaque_tup) }, M.Tup)
+end
+
+--- Free the value, it is also freed when it is garbage collected
+function M.Tup:free()
+  release(self.ctx, self.ptr, lib.futhark_free_opaque_tup)
+  self.ptr = nil
+end
+
+--- Create a new Tup from its fields
+function M.Tup.new(ctx, field0, field1)
+  local out = ffi.new("struct futhark_opaque_tup *[1]")
+  ctx:check(lib.futhark_new_opaque_tup(ctx:check_handle(), out, check_ptr(field0), check_ptr(field1)))
+  ctx:maybe_sync()
+  return M.Tup.wrap(ctx, out[0])
+end
+
+--- Get the 0 field
+function M.Tup:get_0()
+  local out = ffi.new("struct futhark_opaque_number *[1]")
+  self.ctx:check(lib.futhark_project_opaque_tup_0(self.ctx:check_handle(), out, check_ptr(self)))
+  self.ctx:maybe_sync()
+  return M.Number.wrap(self.ctx, out[0])
+end
+
+--- Get the 1 field
+function M.Tup:get_1()
+  local out = ffi.new("struct futhark_f32_1d *[1]")
+  self.ctx:check(lib.futhark_project_opaque_tup_1(self.ctx:check_handle(), out, check_ptr(self)))
+  self.ctx:maybe_sync()
+  return M.ArrayF32D1.wrap(self.ctx, out[0])
+end
+
+ffi.cdef[[
+int futhark_entry_distance(struct futhark_context *ctx, float *out0, const struct futhark_opaque_point *input0, const struct futhark_opaque_point *input1);
+]]
+
+--- Entry point: distance
+function M.Context:distance(input0, input1)
+  local out0 = ffi.new("float[1]")
+  self:check(lib.futhark_entry_distance(self:check_handle(), out0, check_ptr(input0), check_ptr(input1)))
+  self:maybe_sync()
+  return out0[0]
+end
+
+ffi.cdef[[
+int futhark_entry_length(struct futhark_context *ctx, float *out0, const struct futhark_opaque_segment *input0);
+]]
+
+--- Entry point: length
+function M.Context:length(input0)
+  local out0 = ffi.new("float[1]")
+  self:check(lib.futhark_entry_length(self:check_handle(), out0, check_ptr(input0)))
+  self:maybe_sync()
+  return out0[0]
+end
+
+ffi.cdef[[
+int futhark_entry_points(struct futhark_context *ctx, struct futhark_opaque_arr_point_1d **out0, const struct futhark_f32_1d *input0, const struct futhark_f32_1d *input1);
+]]
+
+--- Entry point: points
+function M.Context:points(input0, input1)
+  local out0 = ffi.new("struct futhark_opaque_arr_point_1d *[1]")
+  self:check(lib.futhark_entry_points(self:check_handle(), out0, check_ptr(input0), check_ptr(input1)))
+  self:maybe_sync()
+  return M.Arr_point_1d.wrap(self, out0[0])
+end
+
+ffi.cdef[[
+int futhark_entry_return_option(struct futhark_context *ctx, struct futhark_opaque_option **out0, const struct futhark_opaque_option *input0);
+]]
+
+--- Entry point: return_option
+function M.Context:return_option(input0)
+  local out0 = ffi.new("struct futhark_opaque_option *[1]")
+  self:check(lib.futhark_entry_return_option(self:check_handle(), out0, check_ptr(input0)))
+  self:maybe_sync()
+  return M.Option.wrap(self, out0[0])
+end
+
+ffi.cdef[[
+int futhark_entry_swap(struct futhark_context *ctx, float *out0, int32_t *out1, const struct futhark_opaque_2b0ad0ae *input0);
+]]
+
+--- Entry point: swap
+function M.Context:swap(input0)
+  local out0 = ffi.new("float[1]")
+  local out1 = ffi.new("int32_t[1]")
+  self:check(lib.futhark_entry_swap(self:check_handle(), out0, out1, check_ptr(input0)))
+  self:maybe_sync()
+  return out0[0], out1[0]
+end
+
+ffi.cdef[[
+int futhark_entry_tup_mul(struct futhark_context *ctx, struct futhark_f32_1d **out0, const struct futhark_opaque_tup *input0);
+]]
+
+--- Entry point: tup_mul
+function M.Context:tup_mul(input0)
+  local out0 = ffi.new("struct futhark_f32_1d *[1]")
+  self:check(lib.futhark_entry_tup_mul(self:check_handle(), out0, check_ptr(input0)))
+  self:maybe_sync()
+  return M.ArrayF32D1.wrap(self, out0[0])
+end
+
+ffi.cdef[[
+int futhark_entry_unzip_points(struct futhark_context *ctx, struct futhark_f32_1d **out0, struct futhark_f32_1d **out1, const struct futhark_opaque_arr_point_1d *input0);
+]]
+
+--- Entry point: unzip_points
+function M.Context:unzip_points(input0)
+  local out0 = ffi.new("struct futhark_f32_1d *[1]")
+  local out1 = ffi.new("struct futhark_f32_1d *[1]")
+  self:check(lib.futhark_entry_unzip_points(self:check_handle(), out0, out1, check_ptr(input0)))
+  self:maybe_sync()
+  return M.ArrayF32D1.wrap(self, out0[0]), M.ArrayF32D1.wrap(self, out1[0])
+end
+
+return M
@@ -0,0 +1,624 @@
+(* Generated by futhark-bindgen *)
+
+open Ctypes
+open! Unsigned
+open! Signed
+
+module Bindings = struct
+  external _stub: unit -> unit = "futhark_context_new"
+
+  let fn = Foreign.foreign ~release_runtime_lock:true
+  let context = typedef (ptr void) "context"
+  let context_config = typedef (ptr void) "context_config"
+  let futhark_context_new = fn "futhark_context_new" (context_config @-> returning context)
+  let futhark_context_free = fn "futhark_context_free" (context @-> returning int)
+  let futhark_context_sync = fn "futhark_context_sync" (context @-> returning int)
+  let futhark_context_config_new = fn "futhark_context_config_new" (void @-> returning context_config)
+  let futhark_context_config_free = fn "futhark_context_config_free" (context_config @-> returning int)
+  let futhark_context_config_set_profiling = fn "futhark_context_config_set_profiling" (context_config @-> int @-> returning void)
+  let futhark_context_config_set_debugging = fn "futhark_context_config_set_debugging" (context_config @-> int @-> returning void)
+  let futhark_context_config_set_logging = fn "futhark_context_config_set_logging" (context_config @-> int @-> returning void)
+  let futhark_context_config_set_cache_file = fn "futhark_context_config_set_cache_file" (context_config @-> string @-> returning void)
+  let futhark_context_config_set_tuning_param = fn "futhark_context_config_set_tuning_param" (context_config @-> string @-> size_t @-> returning int)
+  let futhark_context_pause_profiling = fn "futhark_context_pause_profiling" (context @-> returning void)
+  let futhark_context_unpause_profiling = fn "futhark_context_unpause_profiling" (context @-> returning void)
+  let futhark_context_clear_caches = fn "futhark_context_clear_caches" (context @-> returning int)
+  let futhark_context_get_error = fn "futhark_context_get_error" (context @-> returning (ptr char))
+  let futhark_context_report = fn "futhark_context_report" (context @-> returning (ptr char))
+  let free = fn "free" (ptr void @-> returning void)
+  let strlen = fn "strlen" (ptr char @-> returning size_t)
+
+  let futhark_context_config_set_device = fn "futhark_context_config_set_device" (context_config @-> string @-> returning (void))
+  let futhark_context_config_set_platform = fn "futhark_context_config_set_platform" (context_config @-> string @-> returning (void))
+  let futhark_context_config_set_default_group_size = fn "futhark_context_config_set_default_group_size" (context_config @-> int @-> returning (void))
+  let futhark_context_config_set_default_tile_size = fn "futhark_context_config_set_default_tile_size" (context_config @-> int @-> returning (void))
+  let type_2b0ad0ae = typedef (ptr void) "futhark_opaque_2b0ad0ae"
+  let futhark_free_opaque_2b0ad0ae = fn "futhark_free_opaque_2b0ad0ae" (context @-> type_2b0ad0ae @-> returning (int))
+  let futhark_project_opaque_2b0ad0ae_0 = fn "futhark_project_opaque_2b0ad0ae_0" (context @-> ptr int32_t @-> type_2b0ad0ae @-> returning (int))
+  let futhark_project_opaque_2b0ad0ae_1 = fn "futhark_project_opaque_2b0ad0ae_1" (context @-> ptr float @-> type_2b0ad0ae @-> returning (int))
+  let futhark_new_opaque_2b0ad0ae = fn "futhark_new_opaque_2b0ad0ae" (context @-> ptr type_2b0ad0ae @-> int32_t @-> float @-> returning (int))
+  let array_f32_1d = typedef (ptr void) "array_f32_1d"
+  let futhark_new_f32_1d = fn "futhark_new_f32_1d" (context @-> ptr float @-> int64_t @-> returning (array_f32_1d))
+  let futhark_values_f32_1d = fn "futhark_values_f32_1d" (context @-> array_f32_1d @-> ptr float @-> returning (int))
+  let futhark_free_f32_1d = fn "futhark_free_f32_1d" (context @-> array_f32_1d @-> returning (int))
+  let futhark_shape_f32_1d = fn "futhark_shape_f32_1d" (context @-> array_f32_1d @-> returning (ptr int64_t))
+  let arr_point_1d = typedef (ptr void) "futhark_opaque_arr_point_1d"
+  let futhark_free_opaque_arr_point_1d = fn "futhark_free_opaque_arr_point_1d" (context @-> arr_point_1d @-> returning (int))
+  let number = typedef (ptr void) "futhark_opaque_number"
+  let futhark_free_opaque_number = fn "futhark_free_opaque_number" (context @-> number @-> returning (int))
+  let futhark_project_opaque_number_x = fn "futhark_project_opaque_number_x" (context @-> ptr float @-> number @-> returning (int))
+  let futhark_new_opaque_number = fn "futhark_new_opaque_number" (context @-> ptr number @-> float @-> returning (int))
+  let option = typedef (ptr void) "futhark_opaque_option"
+  let futhark_free_opaque_option = fn "futhark_free_opaque_option" (context @-> option @-> returning (int))
+  let point = typedef (ptr void) "futhark_opaque_point"
+  let futhark_free_opaque_point = fn "futhark_free_opaque_point" (context @-> point @-> returning (int))
+  let futhark_project_opaque_point_x = fn "futhark_project_opaque_point_x" (context @-> ptr float @-> point @-> returning (int))
+  let futhark_project_opaque_point_y = fn "futhark_project_opaque_point_y" (context @-> ptr float @-> point @-> returning (int))
+  let futhark_new_opaque_point = fn "futhark_new_opaque_point" (context @-> ptr point @-> float @-> float @-> returning (int))
+  let segment = typedef (ptr void) "futhark_opaque_segment"
+  let futhark_free_opaque_segment = fn "futhark_free_opaque_segment" (context @-> segment @-> returning (int))
+  let futhark_project_opaque_segment_start = fn "futhark_project_opaque_segment_start" (context @-> ptr point @-> segment @-> returning (int))
+  let futhark_project_opaque_segment_end = fn "futhark_project_opaque_segment_end" (context @-> ptr point @-> segment @-> returning (int))
+  let futhark_new_opaque_segment = fn "futhark_new_opaque_segment" (context @-> ptr segment @-> point @-> point @-> returning (int))
+  let tup = typedef (ptr void) "futhark_opaque_tup"
+  let futhark_free_opaque_tup = fn "futhark_free_opaque_tup" (context @-> tup @-> returning (int))
+  let futhark_project_opaque_tup_0 = fn "futhark_project_opaque_tup_0" (context @-> ptr number @-> tup @-> returning (int))
+  let futhark_project_opaque_tup_1 = fn "futhark_project_opaque_tup_1" (context @-> ptr array_f32_1d @-> tup @-> returning (int))
+  let futhark_new_opaque_tup = fn "futhark_new_opaque_tup" (context @-> ptr tup @-> number @-> array_f32_1d @-> returning (int))
+  let futhark_entry_distance = fn "futhark_entry_distance" (context @-> ptr float @-> point @-> point @-> returning (int))
+  let futhark_entry_length = fn "futhark_entry_length" (context @-> ptr float @-> segment @-> returning (int))
+  let futhark_entry_points = fn "futhark_entry_points" (context @-> ptr arr_point_1d @-> array_f32_1d @-> array_f32_1d @-> returning (int))
+  let futhark_entry_return_option = fn "futhark_entry_return_option" (context @-> ptr option @-> option @-> returning (int))
+  let futhark_entry_swap = fn "futhark_entry_swap" (context @-> ptr float @-> ptr int32_t @-> type_2b0ad0ae @-> returning (int))
+  let futhark_entry_tup_mul = fn "futhark_entry_tup_mul" (context @-> ptr array_f32_1d @-> tup @-> returning (int))
+  let futhark_entry_unzip_points = fn "futhark_entry_unzip_points" (context @-> ptr array_f32_1d @-> ptr array_f32_1d @-> arr_point_1d @-> returning (int))
+end
+
+type error =
+  | InvalidShape of int * int
+  | NullPtr
+  | Code of int
+  | UseAfterFree of [`context | `array | `opaque]
+  | IndexOutOfBounds
+  | Unsupported of string
+  | InvalidTuning of string
+
+exception Error of error
+
+let set_managed (p: 'a Ctypes_static.ptr) x =
+  match p with
+  | Ctypes_static.CPointer fat -> Ctypes_ptr.Fat.set_managed fat (Some (Obj.repr x))
+
+let check_use_after_free t b = if b then raise (Error (UseAfterFree t))
+
+let () = Printexc.register_printer (function
+  | Error (InvalidShape (a, b)) -> Some (Printf.sprintf "futhark error: invalid shape, expected %d but got %d" a b)
+  | Error NullPtr -> Some "futhark error: null pointer"
+  | Error (Code c) -> Some (Printf.sprintf "futhark error: code %d" c) 
+  | Error (UseAfterFree `context) -> Some "futhark: context used after beeing freed"
+  | Error (UseAfterFree `array) -> Some "futhark: array used after beeing freed"
+  | Error (UseAfterFree `opaque) -> Some "futhark: opaque value used after beeing freed"
+  | Error IndexOutOfBounds -> Some "futhark error: index out of bounds"
+  | Error (Unsupported op) -> Some (Printf.sprintf "futhark error: %s is not supported by this version of Futhark" op)
+  | Error (InvalidTuning msg) -> Some (Printf.sprintf "futhark error: invalid tuning parameter: %s" msg)
+  | _ -> None)
+
+
+
+open Bigarray
+
+module Context = struct
+  [@@@ocaml.warning "-69"]
+  type t = { handle: unit ptr; config: unit ptr; cache_file: string option; auto_sync: bool; mutable context_free: bool }
+  [@@@ocaml.warning "+69"]
+
+  let free t =
+    if not t.context_free then
+      let () = ignore (Bindings.futhark_context_sync t.handle) in
+      let () = ignore (Bindings.futhark_context_free t.handle) in
+      let () = ignore (Bindings.futhark_context_config_free t.config) in
+      t.context_free <- true
+
+  let read_tuning_file path =
+    let ic = open_in path in
+    let parse line =
+      match String.index_opt line '=' with
+      | None -> None
+      | Some i ->
+        let name = String.trim (String.sub line 0 i) in
+        let value = String.trim (String.sub line (i + 1) (String.length line - i - 1)) in
+        Option.map (fun v -> (name, v)) (int_of_string_opt value)
+    in
+    let rec loop acc =
+      match input_line ic with
+      | exception End_of_file -> close_in ic; List.rev acc
+      | line when String.trim line = "" -> loop acc
+      | line ->
+        match parse line with
+        | Some x -> loop (x :: acc)
+        | None -> close_in ic; raise (Error (InvalidTuning line))
+    in
+    loop []
+
+  let v ?(debug = false) ?(log = false) ?(profile = false) ?cache_file ?(auto_sync = true) ?(tuning_params = []) ?tuning_file ?device ?platform ?default_group_size ?default_tile_size () =
+    let tuning_params = (match tuning_file with Some f -> read_tuning_file f | None -> []) @ tuning_params in
+    let config = Bindings.futhark_context_config_new () in
+    if is_null config then raise (Error NullPtr);
+    Bindings.futhark_context_config_set_debugging config (if debug then 1 else 0);
+    Bindings.futhark_context_config_set_profiling config (if profile then 1 else 0);
+    Bindings.futhark_context_config_set_logging config (if log then 1 else 0);
+    Option.iter (Bindings.futhark_context_config_set_device config) device;
+    Option.iter (Bindings.futhark_context_config_set_platform config) platform;
+    Option.iter (Bindings.futhark_context_config_set_default_group_size config) default_group_size;
+    Option.iter (Bindings.futhark_context_config_set_default_tile_size config) default_tile_size;
+    Option.iter (Bindings.futhark_context_config_set_cache_file config) cache_file;
+    List.iter (fun (name, value) ->
+      if Bindings.futhark_context_config_set_tuning_param config name (Unsigned.Size_t.of_int value) <> 0 then
+        let () = ignore @@ Bindings.futhark_context_config_free config in
+        raise (Error (InvalidTuning name))) tuning_params;
+    let handle = Bindings.futhark_context_new config in
+    if is_null handle then 
+      let () = ignore @@ Bindings.futhark_context_config_free config in
+      raise (Error NullPtr)
+    else
+      let t = { handle; config; cache_file; auto_sync; context_free = false } in
+      set_managed handle t; 
+      let () = Gc.finalise free t in
+      t
+
+  let sync t =
+    check_use_after_free `context t.context_free;
+    let rc = Bindings.futhark_context_sync t.handle in
+    if rc <> 0 then raise (Error (Code rc))
+
+  let auto_sync t =
+    if t.auto_sync then sync t
+  
+  let clear_caches t =
+    check_use_after_free `context t.context_free;
+    let rc = Bindings.futhark_context_clear_caches t.handle in
+    if rc <> 0 then raise (Error (Code rc))
+
+  let string_opt_of_ptr ptr = 
+    if is_null ptr then None
+    else
+      let len = Bindings.strlen ptr |> Unsigned.Size_t.to_int in
+      let s = String.init len (fun i -> !@(ptr +@ i)) in
+      let () = Bindings.free (coerce (Ctypes.ptr Ctypes.char) (Ctypes.ptr void) ptr) in Some s
+
+  let get_error t = 
+    check_use_after_free `context t.context_free;
+    let ptr = Bindings.futhark_context_get_error t.handle in string_opt_of_ptr ptr
+
+  let report t = 
+    check_use_after_free `context t.context_free;
+    let ptr = Bindings.futhark_context_report t.handle in string_opt_of_ptr ptr
+
+  let pause_profiling t = 
+    check_use_after_free `context t.context_free;
+    Bindings.futhark_context_pause_profiling t.handle
+
+  let unpause_profiling t =
+    check_use_after_free `context t.context_free;
+    Bindings.futhark_context_unpause_profiling t.handle
+end
+
+[@@@ocaml.warning "-34"]
+[@@@ocaml.warning "-69"]
+type futhark_array = { mutable ptr: unit ptr ptr; shape: int array; ctx: Context.t }
+type opaque = { mutable opaque_ptr: unit ptr ptr; opaque_ctx: Context.t }
+[@@@ocaml.warning "+34"]
+[@@@ocaml.warning "+69"]
+
+[@@@ocaml.warning "-32"]
+let get_ptr t =
+  let x = !@(t.ptr) in
+  check_use_after_free `array (Ctypes.is_null x);
+  x
+
+let get_opaque_ptr t =
+  let x = !@(t.opaque_ptr) in
+  check_use_after_free `opaque (Ctypes.is_null x);
+  x
+[@@@ocaml.warning "+32"]
+
+
+module Type_2b0ad0ae = struct
+  type t = opaque
+  let t = Bindings.type_2b0ad0ae
+  let _ = t
+
+  let free' ctx ptr = 
+    let is_null = Ctypes.is_null ptr || Ctypes.is_null (!@ptr) in
+    if not ctx.Context.context_free && not is_null then
+      let () = ignore (Bindings.futhark_free_opaque_2b0ad0ae ctx.Context.handle (!@ptr)) in
+      ptr <-@ Ctypes.null
+
+  let of_ptr ctx ptr =
+    if is_null ptr then raise (Error NullPtr);
+    { opaque_ptr = allocate ~finalise:(free' ctx) (Ctypes.ptr Ctypes.void) ptr; opaque_ctx = ctx }
+
+  let free t = free' t.opaque_ctx t.opaque_ptr 
+
+  let _ = of_ptr
+
+  let v ctx field0 field1 =
+    check_use_after_free `context ctx.Context.context_free;
+    let ptr = allocate ~finalise:(free' ctx) (ptr void) null in
+    let rc = Bindings.futhark_new_opaque_2b0ad0ae ctx.Context.handle ptr field0 field1 in
+    if rc <> 0 then raise (Error (Code rc));
+    Context.auto_sync ctx;
+    { opaque_ptr = ptr; opaque_ctx = ctx }
+
+  let get_0 t =
+    check_use_after_free `context t.opaque_ctx.Context.context_free;
+    let out = allocate_n ~count:1 int32_t in
+    let rc = Bindings.futhark_project_opaque_2b0ad0ae_0 t.opaque_ctx.Context.handle out (get_opaque_ptr t) in
+    if rc <> 0 then raise (Error (Code rc));
+    Context.auto_sync t.opaque_ctx;
+    !@out
+
+
+  let get_1 t =
+    check_use_after_free `context t.opaque_ctx.Context.context_free;
+    let out = allocate_n ~count:1 float in
+    let rc = Bindings.futhark_project_opaque_2b0ad0ae_1 t.opaque_ctx.Context.handle out (get_opaque_ptr t) in
+    if rc <> 0 then raise (Error (Code rc));
+    Context.auto_sync t.opaque_ctx;
+    !@out
+
+
+end
+
+module Array_f32_1d = struct
+  type t = futhark_array
+
+  type kind = (float, Bigarray.float32_elt) Bigarray.kind
+  
+  let kind = Bigarray.float32
+
+  let free ctx ptr =
+    let is_null = Ctypes.is_null ptr || Ctypes.is_null (!@ptr) in
+    if not ctx.Context.context_free && not is_null then
+      let () = ignore (Bindings.futhark_free_f32_1d ctx.Context.handle (!@ptr)) in
+      ptr <-@ Ctypes.null
+
+  let cast x =
+    coerce (ptr void) (ptr float) (to_voidp x)
+  
+  let v ctx ba =
+    check_use_after_free `context ctx.Context.context_free;
+    let dims = Genarray.dims ba in
+    let ptr = Bindings.futhark_new_f32_1d ctx.Context.handle (cast @@ bigarray_start genarray ba) (Int64.of_int dims.(0)) in
+    if is_null ptr then raise (Error NullPtr);
+    Context.auto_sync ctx;
+    { ptr = Ctypes.allocate ~finalise:(free ctx) (Ctypes.ptr Ctypes.void) ptr; ctx; shape = dims }
+
+  let values t ba =
+    check_use_after_free `context t.ctx.Context.context_free;
+    let dims = Genarray.dims ba in
+    let a = Array.fold_left ( * ) 1 t.shape in
+    let b = Array.fold_left ( * ) 1 dims in
+    if (a <> b) then raise (Error (InvalidShape (a, b)));
+    let rc = Bindings.futhark_values_f32_1d t.ctx.Context.handle (get_ptr t) (cast @@ bigarray_start genarray ba) in
+    Context.auto_sync t.ctx;
+    if rc <> 0 then raise (Error (Code rc))
+
+  let values_array1 t ba =
+    let ba = genarray_of_array1 ba in
+    let ba = reshape ba t.shape in
+    values t ba
+
+  let get t =
+    let dims = t.shape in
+    let g = Genarray.create kind C_layout dims in
+    values t g;
+    g
+
+  let get_array1 t =
+    let len = Array.fold_left ( * ) 1 t.shape in
+    let g = Array1.create kind C_layout len in
+    values_array1 t g;
+    g
+
+  let index _ _ = raise (Error (Unsupported "index"))
+
+  let shape t = t.shape
+
+  let of_array1 ctx dims arr =
+    let len = Array.fold_left ( * ) 1 dims in
+    assert (len = Array1.dim arr);
+    let g = genarray_of_array1 arr in
+    let g = reshape g dims in
+    v ctx g
+
+  let of_array ctx dims arr =
+    let arr = Array1.of_array kind C_layout arr in
+    of_array1 ctx dims arr
+
+  let ptr_shape ctx ptr =
+    let s = Bindings.futhark_shape_f32_1d ctx ptr in
+    Array.init 1 (fun i -> Int64.to_int !@ (s +@ i))
+
+  let of_ptr ctx ptr =
+    check_use_after_free `context ctx.Context.context_free;
+    if is_null ptr then raise (Error NullPtr);
+    let shape = ptr_shape ctx.Context.handle ptr in
+    { ptr = Ctypes.allocate ~finalise:(free ctx) (Ctypes.ptr Ctypes.void) ptr; ctx; shape }
+
+  let free t = free t.ctx t.ptr
+    
+  let _ = of_ptr
+end
+
+
+module Arr_point_1d = struct
+  type t = opaque
+  let t = Bindings.arr_point_1d
+  let _ = t
+
+  let free' ctx ptr = 
+    let is_null = Ctypes.is_null ptr || Ctypes.is_null (!@ptr) in
+    if not ctx.Context.context_free && not is_null then
+      let () = ignore (Bindings.futhark_free_opaque_arr_point_1d ctx.Context.handle (!@ptr)) in
+      ptr <-@ Ctypes.null
+
+  let of_ptr ctx ptr =
+    if is_null ptr then raise (Error NullPtr);
+    { opaque_ptr = allocate ~finalise:(free' ctx) (Ctypes.ptr Ctypes.void) ptr; opaque_ctx = ctx }
+
+  let free t = free' t.opaque_ctx t.opaque_ptr 
+
+  let _ = of_ptr
+
+end
+module Number = struct
+  type t = opaque
+  let t = Bindings.number
+  let _ = t
+
+  let free' ctx ptr = 
+    let is_null = Ctypes.is_null ptr || Ctypes.is_null (!@ptr) in
+    if not ctx.Context.context_free && not is_null then
+      let () = ignore (Bindings.futhark_free_opaque_number ctx.Context.handle (!@ptr)) in
+      ptr <-@ Ctypes.null
+
+  let of_ptr ctx ptr =
+    if is_null ptr then raise (Error NullPtr);
+    { opaque_ptr = allocate ~finalise:(free' ctx) (Ctypes.ptr Ctypes.void) ptr; opaque_ctx = ctx }
+
+  let free t = free' t.opaque_ctx t.opaque_ptr 
+
+  let _ = of_ptr
+
+  let v ctx fieldx =
+    check_use_after_free `context ctx.Context.context_free;
+    let ptr = allocate ~finalise:(free' ctx) (ptr void) null in
+    let rc = Bindings.futhark_new_opaque_number ctx.Context.handle ptr fieldx in
+    if rc <> 0 then raise (Error (Code rc));
+    Context.auto_sync ctx;
+    { opaque_ptr = ptr; opaque_ctx = ctx }
+
+  let get_x t =
+    check_use_after_free `context t.opaque_ctx.Context.context_free;
+    let out = allocate_n ~count:1 float in
+    let rc = Bindings.futhark_project_opaque_number_x t.opaque_ctx.Context.handle out (get_opaque_ptr t) in
+    if rc <> 0 then raise (Error (Code rc));
+    Context.auto_sync t.opaque_ctx;
+    !@out
+
+
+end
+
+module Option = struct
+  type t = opaque
+  let t = Bindings.option
+  let _ = t
+
+  let free' ctx ptr = 
+    let is_null = Ctypes.is_null ptr || Ctypes.is_null (!@ptr) in
+    if not ctx.Context.context_free && not is_null then
+      let () = ignore (Bindings.futhark_free_opaque_option ctx.Context.handle (!@ptr)) in
+      ptr <-@ Ctypes.null
+
+  let of_ptr ctx ptr =
+    if is_null ptr then raise (Error NullPtr);
+    { opaque_ptr = allocate ~finalise:(free' ctx) (Ctypes.ptr Ctypes.void) ptr; opaque_ctx = ctx }
+
+  let free t = free' t.opaque_ctx t.opaque_ptr 
+
+  let _ = of_ptr
+
+end
+module Point = struct
+  type t = opaque
+  let t = Bindings.point
+  let _ = t
+
+  let free' ctx ptr = 
+    let is_null = Ctypes.is_null ptr || Ctypes.is_null (!@ptr) in
+    if not ctx.Context.context_free && not is_null then
+      let () = ignore (Bindings.futhark_free_opaque_point ctx.Context.handle (!@ptr)) in
+      ptr <-@ Ctypes.null
+
+  let of_ptr ctx ptr =
+    if is_null ptr then raise (Error NullPtr);
+    { opaque_ptr = allocate ~finalise:(free' ctx) (Ctypes.ptr Ctypes.void) ptr; opaque_ctx = ctx }
+
+  let free t = free' t.opaque_ctx t.opaque_ptr 
+
+  let _ = of_ptr
+
+  let v ctx fieldx fieldy =
+    check_use_after_free `context ctx.Context.context_free;
+    let ptr = allocate ~finalise:(free' ctx) (ptr void) null in
+    let rc = Bindings.futhark_new_opaque_point ctx.Context.handle ptr fieldx fieldy in
+    if rc <> 0 then raise (Error (Code rc));
+    Context.auto_sync ctx;
+    { opaque_ptr = ptr; opaque_ctx = ctx }
+
+  let get_x t =
+    check_use_after_free `context t.opaque_ctx.Context.context_free;
+    let out = allocate_n ~count:1 float in
+    let rc = Bindings.futhark_project_opaque_point_x t.opaque_ctx.Context.handle out (get_opaque_ptr t) in
+    if rc <> 0 then raise (Error (Code rc));
+    Context.auto_sync t.opaque_ctx;
+    !@out
+
+
+  let get_y t =
+    check_use_after_free `context t.opaque_ctx.Context.context_free;
+    let out = allocate_n ~count:1 float in
+    let rc = Bindings.futhark_project_opaque_point_y t.opaque_ctx.Context.handle out (get_opaque_ptr t) in
+    if rc <> 0 then raise (Error (Code rc));
+    Context.auto_sync t.opaque_ctx;
+    !@out
+
+
+end
+
+module Segment = struct
+  type t = opaque
+  let t = Bindings.segment
+  let _ = t
+
+  let free' ctx ptr = 
+    let is_null = Ctypes.is_null ptr || Ctypes.is_null (!@ptr) in
+    if not ctx.Context.context_free && not is_null then
+      let () = ignore (Bindings.futhark_free_opaque_segment ctx.Context.handle (!@ptr)) in
+      ptr <-@ Ctypes.null
+
+  let of_ptr ctx ptr =
+    if is_null ptr then raise (Error NullPtr);
+    { opaque_ptr = allocate ~finalise:(free' ctx) (Ctypes.ptr Ctypes.void) ptr; opaque_ctx = ctx }
+
+  let free t = free' t.opaque_ctx t.opaque_ptr 
+
+  let _ = of_ptr
+
+  let v ctx fieldstart fieldend =
+    check_use_after_free `context ctx.Context.context_free;
+    let ptr = allocate ~finalise:(free' ctx) (ptr void) null in
+    let rc = Bindings.futhark_new_opaque_segment ctx.Context.handle ptr (get_opaque_ptr fieldstart) (get_opaque_ptr fieldend) in
+    if rc <> 0 then raise (Error (Code rc));
+    Context.auto_sync ctx;
+    { opaque_ptr = ptr; opaque_ctx = ctx }
+
+  let get_start t =
+    check_use_after_free `context t.opaque_ctx.Context.context_free;
+    let out = allocate_n ~count:1 Point.t in
+    let rc = Bindings.futhark_project_opaque_segment_start t.opaque_ctx.Context.handle out (get_opaque_ptr t) in
+    if rc <> 0 then raise (Error (Code rc));
+    Context.auto_sync t.opaque_ctx;
+    Point.of_ptr t.opaque_ctx !@out
+
+
+  let get_end t =
+    check_use_after_free `context t.opaque_ctx.Context.context_free;
+    let out = allocate_n ~count:1 Point.t in
+    let rc = Bindings.futhark_project_opaque_segment_end t.opaque_ctx.Context.handle out (get_opaque_ptr t) in
+    if rc <> 0 then raise (Error (Code rc));
+    Context.auto_sync t.opaque_ctx;
+    Point.of_ptr t.opaque_ctx !@out
+
+
+end
+
+module Tup = struct
+  type t = opaque
+  let t = Bindings.tup
+  let _ = t
+
+  let free' ctx ptr = 
+    let is_null = Ctypes.is_null ptr || Ctypes.is_null (!@ptr) in
+    if not ctx.Context.context_free && not is_null then
+      let () = ignore (Bindings.futhark_free_opaque_tup ctx.Context.handle (!@ptr)) in
+      ptr <-@ Ctypes.null
+
+  let of_ptr ctx ptr =
+    if is_null ptr then raise (Error NullPtr);
+    { opaque_ptr = allocate ~finalise:(free' ctx) (Ctypes.ptr Ctypes.void) ptr; opaque_ctx = ctx }
+
+  let free t = free' t.opaque_ctx t.opaque_ptr 
+
+  let _ = of_ptr
+
+  let v ctx field0 field1 =
+    check_use_after_free `context ctx.Context.context_free;
+    let ptr = allocate ~finalise:(free' ctx) (ptr void) null in
+    let rc = Bindings.futhark_new_opaque_tup ctx.Context.handle ptr (get_opaque_ptr field0) (get_ptr field1) in
+    if rc <> 0 then raise (Error (Code rc));
+    Context.auto_sync ctx;
+    { opaque_ptr = ptr; opaque_ctx = ctx }
+
+  let get_0 t =
+    check_use_after_free `context t.opaque_ctx.Context.context_free;
+    let out = allocate_n ~count:1 Number.t in
+    let rc = Bindings.futhark_project_opaque_tup_0 t.opaque_ctx.Context.handle out (get_opaque_ptr t) in
+    if rc <> 0 then raise (Error (Code rc));
+    Context.auto_sync t.opaque_ctx;
+    Number.of_ptr t.opaque_ctx !@out
+
+
+  let get_1 t =
+    check_use_after_free `context t.opaque_ctx.Context.context_free;
+    let out = allocate_n ~count:1 Bindings.array_f32_1d in
+    let rc = Bindings.futhark_project_opaque_tup_1 t.opaque_ctx.Context.handle out (get_opaque_ptr t) in
+    if rc <> 0 then raise (Error (Code rc));
+    Context.auto_sync t.opaque_ctx;
+    Array_f32_1d.of_ptr t.opaque_ctx !@out
+
+
+end
+
+let distance ctx input0 input1 =
+  check_use_after_free `context ctx.Context.context_free;
+  let out_ptr = allocate_n float ~count:1 in
+  let rc = Bindings.futhark_entry_distance ctx.Context.handle out_ptr (get_opaque_ptr input0) (get_opaque_ptr input1) in
+  if rc <> 0 then raise (Error (Code rc));
+  (!@out_ptr)
+
+let length ctx input0 =
+  check_use_after_free `context ctx.Context.context_free;
+  let out_ptr = allocate_n float ~count:1 in
+  let rc = Bindings.futhark_entry_length ctx.Context.handle out_ptr (get_opaque_ptr input0) in
+  if rc <> 0 then raise (Error (Code rc));
+  (!@out_ptr)
+
+let points ctx input0 input1 =
+  check_use_after_free `context ctx.Context.context_free;
+  let out_ptr = allocate (ptr void) null in
+  let rc = Bindings.futhark_entry_points ctx.Context.handle out_ptr (get_ptr input0) (get_ptr input1) in
+  if rc <> 0 then raise (Error (Code rc));
+  ((Arr_point_1d.of_ptr ctx !@out_ptr))
+
+let return_option ctx input0 =
+  check_use_after_free `context ctx.Context.context_free;
+  let out_ptr = allocate (ptr void) null in
+  let rc = Bindings.futhark_entry_return_option ctx.Context.handle out_ptr (get_opaque_ptr input0) in
+  if rc <> 0 then raise (Error (Code rc));
+  ((Option.of_ptr ctx !@out_ptr))
+
+let swap ctx input0 =
+  check_use_after_free `context ctx.Context.context_free;
+  let out0_ptr = allocate_n float ~count:1 in
+  let out1_ptr = allocate_n int32_t ~count:1 in
+  let rc = Bindings.futhark_entry_swap ctx.Context.handle out0_ptr out1_ptr (get_opaque_ptr input0) in
+  if rc <> 0 then raise (Error (Code rc));
+  (!@out0_ptr, !@out1_ptr)
+
+let tup_mul ctx input0 =
+  check_use_after_free `context ctx.Context.context_free;
+  let out_ptr = allocate (ptr void) null in
+  let rc = Bindings.futhark_entry_tup_mul ctx.Context.handle out_ptr (get_opaque_ptr input0) in
+  if rc <> 0 then raise (Error (Code rc));
+  ((Array_f32_1d.of_ptr ctx !@out_ptr))
+
+let unzip_points ctx input0 =
+  check_use_after_free `context ctx.Context.context_free;
+  let out0_ptr = allocate (ptr void) null in
+  let out1_ptr = allocate (ptr void) null in
+  let rc = Bindings.futhark_entry_unzip_points ctx.Context.handle out0_ptr out1_ptr (get_opaque_ptr input0) in
+  if rc <> 0 then raise (Error (Code rc));
+  ((Array_f32_1d.of_ptr ctx !@out0_ptr), (Array_f32_1d.of_ptr ctx !@out1_ptr))
+
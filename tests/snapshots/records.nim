@@ -0,0 +1,527 @@
+# Generated by futhark-bindgen
+
+import std/[os, strutils]
+
+# The library can be selected using `-d:futharkLib=path/to/librecords.so`
+const futharkLib {.strdefine.} = ""
+const libName = (if futharkLib.len > 0: futharkLib else: DynlibFormat % "records")
+
+type
+  FutharkError* = object of CatchableError
+    ## Raised when a Futhark function returns a non-zero error code
+    code*: cint
+
+  NullPtrError* = object of CatchableError
+    ## Raised when a Futhark function returns a NULL pointer
+
+  InvalidShapeError* = object of CatchableError
+    ## Raised when the data of an array doesn't match its shape
+
+  futhark_context_config = object
+  futhark_context = object
+
+  Options* = object
+    ## Options used to create a `Context`
+    debug*: bool
+    profile*: bool
+    logging*: bool
+    cacheFile*: string
+    autoSync*: bool
+    device*: string
+
+  ContextObj = object
+    config: ptr futhark_context_config
+    context: ptr futhark_context
+    autoSync: bool
+    cacheFile: string
+    device: string
+
+  Context* = ref ContextObj
+    ## Futhark context
+
+{.push importc, dynlib: libName, cdecl.}
+proc futhark_context_config_new(): ptr futhark_context_config
+proc futhark_context_config_free(cfg: ptr futhark_context_config)
+proc futhark_context_config_set_debugging(cfg: ptr futhark_context_config, flag: cint)
+proc futhark_context_config_set_profiling(cfg: ptr futhark_context_config, flag: cint)
+proc futhark_context_config_set_logging(cfg: ptr futhark_context_config, flag: cint)
+proc futhark_context_config_set_cache_file(cfg: ptr futhark_context_config, f: cstring)
+proc futhark_context_config_set_device(cfg: ptr futhark_context_config, s: cstring)
+proc futhark_context_new(cfg: ptr futhark_context_config): ptr futhark_context
+proc futhark_context_free(ctx: ptr futhark_context)
+proc futhark_context_sync(ctx: ptr futhark_context): cint
+proc futhark_context_clear_caches(ctx: ptr futhark_context): cint
+proc futhark_context_pause_profiling(ctx: ptr futhark_context)
+proc futhark_context_unpause_profiling(ctx: ptr futhark_context)
+proc futhark_context_get_error(ctx: ptr futhark_context): cstring
+proc futhark_context_report(ctx: ptr futhark_context): cstring
+{.pop.}
+
+proc cFree(p: pointer) {.importc: "free", header: "<stdlib.h>".}
+
+proc `=destroy`(c: ContextObj) =
+  if c.context != nil:
+    discard futhark_context_sync(c.context)
+    futhark_context_free(c.context)
+  if c.config != nil:
+    futhark_context_config_free(c.config)
+  `=destroy`(c.cacheFile)
+  `=destroy`(c.device)
+
+proc `=copy`(dst: var ContextObj, src: ContextObj) {.error.}
+
+proc initOptions*(): Options =
+  ## Create new `Options` with default settings
+  Options(autoSync: true)
+
+proc newContext*(options = initOptions()): Context =
+  ## Create a new context
+  let config = futhark_context_config_new()
+  if config == nil:
+    raise newException(NullPtrError, "NULL pointer encountered")
+  result = Context(config: config, autoSync: options.autoSync, cacheFile: options.cacheFile)
+  futhark_context_config_set_debugging(config, cint(options.debug))
+  futhark_context_config_set_profiling(config, cint(options.profile))
+  futhark_context_config_set_logging(config, cint(options.logging))
+  if result.cacheFile.len > 0:
+    futhark_context_config_set_cache_file(config, cstring(result.cacheFile))
+  result.device = options.device
+  if result.device.len > 0:
+    futhark_context_config_set_device(config, cstring(result.device))
+  result.context = futhark_context_new(config)
+  if result.context == nil:
+    raise newException(NullPtrError, "NULL pointer encountered")
+
+proc takeString(s: cstring): string =
+  if s == nil:
+    return ""
+  result = $s
+  cFree(cast[pointer](s))
+
+proc getError*(ctx: Context): string =
+  ## Get the last error message, or an empty string
+  takeString(futhark_context_get_error(ctx.context))
+
+proc report*(ctx: Context): string =
+  ## Get the profiling report
+  takeString(futhark_context_report(ctx.context))
+
+proc check(ctx: Context, rc: cint) =
+  if rc != 0:
+    raise (ref FutharkError)(code: rc, msg: "Futhark error code " & $rc & ": " & ctx.getError())
+
+proc sync*(ctx: Context) =
+  ## Sync the context, if `autoSync` is enabled this shouldn't be needed
+  ctx.check(futhark_context_sync(ctx.context))
+
+proc maybeSync(ctx: Context) =
+  if ctx.autoSync:
+    ctx.sync()
+
+proc clearCaches*(ctx: Context) =
+  ## Clear Futhark caches
+  ctx.check(futhark_context_clear_caches(ctx.context))
+
+proc pauseProfiling*(ctx: Context) =
+  ## Pause Futhark profiling
+  futhark_context_pause_profiling(ctx.context)
+
+proc unpauseProfiling*(ctx: Context) =
+  ## Resume profiling
+  futhark_context_unpause_profiling(ctx.context)
+
+proc checkPtr[T](p: ptr T): ptr T =
+  if p == nil:
+    raise newException(NullPtrError, "NULL pointer encountered")
+  p
+
+type
+  futhark_opaque_2b0ad0ae = object
+  Type2b0ad0ae* = object
+    ## Futhark type
+    data: ptr futhark_opaque_2b0ad0ae
+    ctx: Context
+  futhark_f32_1d = object
+  ArrayF32D1* = object
+    ## Array type with 1 dimensions and f32 elements
+    data: ptr futhark_f32_1d
+    ctx: Context
+    dims: array[1, int64]
+  futhark_opaque_arr_point_1d = object
+  Arr_point_1d* = object
+    ## Futhark type
+    data: ptr futhark_opaque_arr_point_1d
+    ctx: Context
+  futhark_opaque_number = object
+  Number* = object
+    ## Futhark type
+    data: ptr futhark_opaque_number
+    ctx: Context
+  futhark_opaque_option = object
+  Option* = object
+    ## Futhark type
+    data: ptr futhark_opaque_option
+    ctx: Context
+  futhark_opaque_point = object
+  Point* = object
+    ## Futhark type
+    data: ptr futhark_opaque_point
+    ctx: Context
+  futhark_opaque_segment = object
+  Segment* = object
+    ## Futhark type
+    data: ptr futhark_opaque_segment
+    ctx: Context
+  futhark_opaque_tup = object
+  Tup* = object
+    ## Futhark type
+    data: ptr futhark_opaque_tup
+    ctx: Context
+
+proc futhark_free_opaque_2b0ad0ae(ctx: ptr futhark_context, data: ptr futhark_opaque_2b0ad0ae): cint {.importc, dynlib: libName, cdecl.}
+
+proc `=destroy`(x: Type2b0ad0ae) =
+  if x.data != nil:
+    discard futhark_free_opaque_2b0ad0ae(x.ctx.context, x.data)
+  `=destroy`(x.ctx)
+
+proc `=copy`(dst: var Type2b0ad0ae, src: Type2b0ad0ae) {.error.}
+
+proc wrap(ctx: Context, data: ptr futhark_opaque_2b0ad0ae): Type2b0ad0ae
+
+proc futhark_free_f32_1d(ctx: ptr futhark_context, data: ptr futhark_f32_1d): cint {.importc, dynlib: libName, cdecl.}
+
+proc `=destroy`(x: ArrayF32D1) =
+  if x.data != nil:
+    discard futhark_free_f32_1d(x.ctx.context, x.data)
+  `=destroy`(x.ctx)
+
+proc `=copy`(dst: var ArrayF32D1, src: ArrayF32D1) {.error.}
+
+proc wrap(ctx: Context, data: ptr futhark_f32_1d): ArrayF32D1
+
+proc futhark_free_opaque_arr_point_1d(ctx: ptr futhark_context, data: ptr futhark_opaque_arr_point_1d): cint {.importc, dynlib: libName, cdecl.}
+
+proc `=destroy`(x: Arr_point_1d) =
+  if x.data != nil:
+    discard futhark_free_opaque_arr_point_1d(x.ctx.context, x.data)
+  `=destroy`(x.ctx)
+
+proc `=copy`(dst: var Arr_point_1d, src: Arr_point_1d) {.error.}
+
+proc wrap(ctx: Context, data: ptr futhark_opaque_arr_point_1d): Arr_point_1d
+
+proc futhark_free_opaque_number(ctx: ptr futhark_context, data: ptr futhark_opaque_number): cint {.importc, dynlib: libName, cdecl.}
+
+proc `=destroy`(x: Number) =
+  if x.data != nil:
+    discard futhark_free_opaque_number(x.ctx.context, x.data)
+  `=destroy`(x.ctx)
+
+proc `=copy`(dst: var Number, src: Number) {.error.}
+
+proc wrap(ctx: Context, data: ptr futhark_opaque_number): Number
+
+proc futhark_free_opaque_option(ctx: ptr futhark_context, data: ptr futhark_opaque_option): cint {.importc, dynlib: libName, cdecl.}
+
+proc `=destroy`(x: Option) =
+  if x.data != nil:
+    discard futhark_free_opaque_option(x.ctx.context, x.data)
+  `=destroy`(x.ctx)
+
+proc `=copy`(dst: var Option, src: Option) {.error.}
+
+proc wrap(ctx: Context, data: ptr futhark_opaque_option): Option
+
+proc futhark_free_opaque_point(ctx: ptr futhark_context, data: ptr futhark_opaque_point): cint {.importc, dynlib: libName, cdecl.}
+
+proc `=destroy`(x: Point) =
+  if x.data != nil:
+    discard futhark_free_opaque_point(x.ctx.context, x.data)
+  `=destroy`(x.ctx)
+
+proc `=copy`(dst: var Point, src: Point) {.error.}
+
+proc wrap(ctx: Context, data: ptr futhark_opaque_point): Point
+
+proc futhark_free_opaque_segment(ctx: ptr futhark_context, data: ptr futhark_opaque_segment): cint {.importc, dynlib: libName, cdecl.}
+
+proc `=destroy`(x: Segment) =
+  if x.data != nil:
+    discard futhark_free_opaque_segment(x.ctx.context, x.data)
+  `=destroy`(x.ctx)
+
+proc `=copy`(dst: var Segment, src: Segment) {.error.}
+
+proc wrap(ctx: Context, data: ptr futhark_opaque_segment): Segment
+
+proc futhark_free_opaque_tup(ctx: ptr futhark_context, data: ptr futhark_opaque_tup): cint {.importc, dynlib: libName, cdecl.}
+
+proc `=destroy`(x: Tup) =
+  if x.data != nil:
+    discard futhark_free_opaque_tup(x.ctx.context, x.data)
+  `=destroy`(x.ctx)
+
+proc `=copy`(dst: var Tup, src: Tup) {.error.}
+
+proc wrap(ctx: Context, data: ptr futhark_opaque_tup): Tup
+
+proc wrap(ctx: Context, data: ptr futhark_opaque_2b0ad0ae): Type2b0ad0ae =
+  Type2b0ad0ae(data: checkPtr(data), ctx: ctx)
+
+proc futhark_project_opaque_2b0ad0ae_0(ctx: ptr futhark_context, res: ptr int32, obj: ptr futhark_opaque_2b0ad0ae): cint {.importc, dynlib: libName, cdecl.}
+
+proc getField0*(x: Type2b0ad0ae): int32 =
+  ## Get 0 field
+  var value: int32
+  x.ctx.check(futhark_project_opaque_2b0ad0ae_0(x.ctx.context, addr value, x.data))
+  result = value
+  x.ctx.maybeSync()
+
+proc futhark_project_opaque_2b0ad0ae_1(ctx: ptr futhark_context, res: ptr float32, obj: ptr futhark_opaque_2b0ad0ae): cint {.importc, dynlib: libName, cdecl.}
+
+proc getField1*(x: Type2b0ad0ae): float32 =
+  ## Get 1 field
+  var value: float32
+  x.ctx.check(futhark_project_opaque_2b0ad0ae_1(x.ctx.context, addr value, x.data))
+  result = value
+  x.ctx.maybeSync()
+
+proc futhark_new_opaque_2b0ad0ae(ctx: ptr futhark_context, res: ptr ptr futhark_opaque_2b0ad0ae, field0: int32, field1: float32): cint {.importc, dynlib: libName, cdecl.}
+
+proc newType2b0ad0ae*(ctx: Context, field0: int32, field1: float32): Type2b0ad0ae =
+  ## Create new Type2b0ad0ae
+  var data: ptr futhark_opaque_2b0ad0ae
+  ctx.check(futhark_new_opaque_2b0ad0ae(ctx.context, addr data, field0, field1))
+  result = wrap(ctx, data)
+  ctx.maybeSync()
+
+{.push importc, dynlib: libName, cdecl.}
+proc futhark_new_f32_1d(ctx: ptr futhark_context, data: ptr float32, dim0: int64): ptr futhark_f32_1d
+proc futhark_values_f32_1d(ctx: ptr futhark_context, arr: ptr futhark_f32_1d, data: ptr float32): cint
+proc futhark_shape_f32_1d(ctx: ptr futhark_context, arr: ptr futhark_f32_1d): ptr UncheckedArray[int64]
+{.pop.}
+
+proc wrap(ctx: Context, data: ptr futhark_f32_1d): ArrayF32D1 =
+  let shape = futhark_shape_f32_1d(ctx.context, checkPtr(data))
+  result = ArrayF32D1(data: data, ctx: ctx)
+  for i in 0 ..< 1:
+    result.dims[i] = shape[i]
+
+proc newArrayF32D1*(ctx: Context, data: openArray[float32], dims: array[1, int64]): ArrayF32D1 =
+  ## Create a new array of `dims` dimensions and initialize it with the values from `data`
+  var size = 1'i64
+  for d in dims:
+    size *= d
+  if data.len.int64 != size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  let arr = checkPtr(futhark_new_f32_1d(ctx.context, p, dims[0]))
+  result = ArrayF32D1(data: arr, ctx: ctx, dims: dims)
+  ctx.maybeSync()
+
+proc shape*(x: ArrayF32D1): array[1, int64] =
+  ## Get the array shape
+  x.dims
+
+proc size*(x: ArrayF32D1): int64 =
+  ## Get the number of elements in the array
+  result = 1
+  for d in x.dims:
+    result *= d
+
+proc values*(x: ArrayF32D1, data: var openArray[float32]) =
+  ## Load values into `data`
+  if data.len.int64 != x.size:
+    raise newException(InvalidShapeError, "Invalid array shape")
+  let p = (if data.len > 0: addr data[0] else: nil)
+  x.ctx.check(futhark_values_f32_1d(x.ctx.context, x.data, p))
+  x.ctx.maybeSync()
+
+proc get*(x: ArrayF32D1): seq[float32] =
+  ## Load values into a `seq`
+  result = newSeq[float32](x.size)
+  x.values(result)
+
+proc wrap(ctx: Context, data: ptr futhark_opaque_arr_point_1d): Arr_point_1d =
+  Arr_point_1d(data: checkPtr(data), ctx: ctx)
+
+proc wrap(ctx: Context, data: ptr futhark_opaque_number): Number =
+  Number(data: checkPtr(data), ctx: ctx)
+
+proc futhark_project_opaque_number_x(ctx: ptr futhark_context, res: ptr float32, obj: ptr futhark_opaque_number): cint {.importc, dynlib: libName, cdecl.}
+
+proc getX*(x: Number): float32 =
+  ## Get x field
+  var value: float32
+  x.ctx.check(futhark_project_opaque_number_x(x.ctx.context, addr value, x.data))
+  result = value
+  x.ctx.maybeSync()
+
+proc futhark_new_opaque_number(ctx: ptr futhark_context, res: ptr ptr futhark_opaque_number, fieldx: float32): cint {.importc, dynlib: libName, cdecl.}
+
+proc newNumber*(ctx: Context, fieldx: float32): Number =
+  ## Create new Number
+  var data: ptr futhark_opaque_number
+  ctx.check(futhark_new_opaque_number(ctx.context, addr data, fieldx))
+  result = wrap(ctx, data)
+  ctx.maybeSync()
+
+proc wrap(ctx: Context, data: ptr futhark_opaque_option): Option =
+  Option(data: checkPtr(data), ctx: ctx)
+
+proc wrap(ctx: Context, data: ptr futhark_opaque_point): Point =
+  Point(data: checkPtr(data), ctx: ctx)
+
+proc futhark_project_opaque_point_x(ctx: ptr futhark_context, res: ptr float32, obj: ptr futhark_opaque_point): cint {.importc, dynlib: libName, cdecl.}
+
+proc getX*(x: Point): float32 =
+  ## Get x field
+  var value: float32
+  x.ctx.check(futhark_project_opaque_point_x(x.ctx.context, addr value, x.data))
+  result = value
+  x.ctx.maybeSync()
+
+proc futhark_project_opaque_point_y(ctx: ptr futhark_context, res: ptr float32, obj: ptr futhark_opaque_point): cint {.importc, dynlib: libName, cdecl.}
+
+proc getY*(x: Point): float32 =
+  ## Get y field
+  var value: float32
+  x.ctx.check(futhark_project_opaque_point_y(x.ctx.context, addr value, x.data))
+  result = value
+  x.ctx.maybeSync()
+
+proc futhark_new_opaque_point(ctx: ptr futhark_context, res: ptr ptr futhark_opaque_point, fieldx: float32, fieldy: float32): cint {.importc, dynlib: libName, cdecl.}
+
+proc newPoint*(ctx: Context, fieldx: float32, fieldy: float32): Point =
+  ## Create new Point
+  var data: ptr futhark_opaque_point
+  ctx.check(futhark_new_opaque_point(ctx.context, addr data, fieldx, fieldy))
+  result = wrap(ctx, data)
+  ctx.maybeSync()
+
+proc wrap(ctx: Context, data: ptr futhark_opaque_segment): Segment =
+  Segment(data: checkPtr(data), ctx: ctx)
+
+proc futhark_project_opaque_segment_start(ctx: ptr futhark_context, res: ptr ptr futhark_opaque_point, obj: ptr futhark_opaque_segment): cint {.importc, dynlib: libName, cdecl.}
+
+proc getStart*(x: Segment): Point =
+  ## Get start field
+  var value: ptr futhark_opaque_point
+  x.ctx.check(futhark_project_opaque_segment_start(x.ctx.context, addr value, x.data))
+  result = wrap(x.ctx, value)
+  x.ctx.maybeSync()
+
+proc futhark_project_opaque_segment_end(ctx: ptr futhark_context, res: ptr ptr futhark_opaque_point, obj: ptr futhark_opaque_segment): cint {.importc, dynlib: libName, cdecl.}
+
+proc getEnd*(x: Segment): Point =
+  ## Get end field
+  var value: ptr futhark_opaque_point
+  x.ctx.check(futhark_project_opaque_segment_end(x.ctx.context, addr value, x.data))
+  result = wrap(x.ctx, value)
+  x.ctx.maybeSync()
+
+proc futhark_new_opaque_segment(ctx: ptr futhark_context, res: ptr ptr futhark_opaque_segment, fieldstart: ptr futhark_opaque_point, fieldend: ptr futhark_opaque_point): cint {.importc, dynlib: libName, cdecl.}
+
+proc newSegment*(ctx: Context, fieldstart: Point, fieldend: Point): Segment =
+  ## Create new Segment
+  var data: ptr futhark_opaque_segment
+  ctx.check(futhark_new_opaque_segment(ctx.context, addr data, fieldstart.data, fieldend.data))
+  result = wrap(ctx, data)
+  ctx.maybeSync()
+
+proc wrap(ctx: Context, data: ptr futhark_opaque_tup): Tup =
+  Tup(data: checkPtr(data), ctx: ctx)
+
+proc futhark_project_opaque_tup_0(ctx: ptr futhark_context, res: ptr ptr futhark_opaque_number, obj: ptr futhark_opaque_tup): cint {.importc, dynlib: libName, cdecl.}
+
+proc getField0*(x: Tup): Number =
+  ## Get 0 field
+  var value: ptr futhark_opaque_number
+  x.ctx.check(futhark_project_opaque_tup_0(x.ctx.context, addr value, x.data))
+  result = wrap(x.ctx, value)
+  x.ctx.maybeSync()
+
+proc futhark_project_opaque_tup_1(ctx: ptr futhark_context, res: ptr ptr futhark_f32_1d, obj: ptr futhark_opaque_tup): cint {.importc, dynlib: libName, cdecl.}
+
+proc getField1*(x: Tup): ArrayF32D1 =
+  ## Get 1 field
+  var value: ptr futhark_f32_1d
+  x.ctx.check(futhark_project_opaque_tup_1(x.ctx.context, addr value, x.data))
+  result = wrap(x.ctx, value)
+  x.ctx.maybeSync()
+
+proc futhark_new_opaque_tup(ctx: ptr futhark_context, res: ptr ptr futhark_opaque_tup, field0: ptr futhark_opaque_number, field1: ptr futhark_f32_1d): cint {.importc, dynlib: libName, cdecl.}
+
+proc newTup*(ctx: Context, field0: Number, field1: ArrayF32D1): Tup =
+  ## Create new Tup
+  var data: ptr futhark_opaque_tup
+  ctx.check(futhark_new_opaque_tup(ctx.context, addr data, field0.data, field1.data))
+  result = wrap(ctx, data)
+  ctx.maybeSync()
+
+proc futhark_entry_distance(ctx: ptr futhark_context, out0: ptr float32, input0: ptr futhark_opaque_point, input1: ptr futhark_opaque_point): cint {.importc, dynlib: libName, cdecl.}
+
+proc distance*(ctx: Context, input0: Point, input1: Point): float32 =
+  ## Entry point: distance
+  var out0: float32
+  ctx.check(futhark_entry_distance(ctx.context, addr out0, input0.data, input1.data))
+  result = out0
+  ctx.maybeSync()
+
+proc futhark_entry_length(ctx: ptr futhark_context, out0: ptr float32, input0: ptr futhark_opaque_segment): cint {.importc, dynlib: libName, cdecl.}
+
+proc length*(ctx: Context, input0: Segment): float32 =
+  ## Entry point: length
+  var out0: float32
+  ctx.check(futhark_entry_length(ctx.context, addr out0, input0.data))
+  result = out0
+  ctx.maybeSync()
+
+proc futhark_entry_points(ctx: ptr futhark_context, out0: ptr ptr futhark_opaque_arr_point_1d, input0: ptr futhark_f32_1d, input1: ptr futhark_f32_1d): cint {.importc, dynlib: libName, cdecl.}
+
+proc points*(ctx: Context, input0: ArrayF32D1, input1: ArrayF32D1): Arr_point_1d =
+  ## Entry point: points
+  var out0: ptr futhark_opaque_arr_point_1d
+  ctx.check(futhark_entry_points(ctx.context, addr out0, input0.data, input1.data))
+  result = wrap(ctx, out0)
+  ctx.maybeSync()
+
+proc futhark_entry_return_option(ctx: ptr futhark_context, out0: ptr ptr futhark_opaque_option, input0: ptr futhark_opaque_option): cint {.importc, dynlib: libName, cdecl.}
+
+proc returnOption*(ctx: Context, input0: Option): Option =
+  ## Entry point: return_option
+  var out0: ptr futhark_opaque_option
+  ctx.check(futhark_entry_return_option(ctx.context, addr out0, input0.data))
+  result = wrap(ctx, out0)
+  ctx.maybeSync()
+
+proc futhark_entry_swap(ctx: ptr futhark_context, out0: ptr float32, out1: ptr int32, input0: ptr futhark_opaque_2b0ad0ae): cint {.importc, dynlib: libName, cdecl.}
+
+proc swap*(ctx: Context, input0: Type2b0ad0ae): (float32, int32) =
+  ## Entry point: swap
+  var out0: float32
+  var out1: int32
+  ctx.check(futhark_entry_swap(ctx.context, addr out0, addr out1, input0.data))
+  result = (out0, out1)
+  ctx.maybeSync()
+
+proc futhark_entry_tup_mul(ctx: ptr futhark_context, out0: ptr ptr futhark_f32_1d, input0: ptr futhark_opaque_tup): cint {.importc, dynlib: libName, cdecl.}
+
+proc tupMul*(ctx: Context, input0: Tup): ArrayF32D1 =
+  ## Entry point: tup_mul
+  var out0: ptr futhark_f32_1d
+  ctx.check(futhark_entry_tup_mul(ctx.context, addr out0, input0.data))
+  result = wrap(ctx, out0)
+  ctx.maybeSync()
+
+proc futhark_entry_unzip_points(ctx: ptr futhark_context, out0: ptr ptr futhark_f32_1d, out1: ptr ptr futhark_f32_1d, input0: ptr futhark_opaque_arr_point_1d): cint {.importc, dynlib: libName, cdecl.}
+
+proc unzipPoints*(ctx: Context, input0: Arr_point_1d): (ArrayF32D1, ArrayF32D1) =
+  ## Entry point: unzip_points
+  var out0: ptr futhark_f32_1d
+  var out1: ptr futhark_f32_1d
+  ctx.check(futhark_entry_unzip_points(ctx.context, addr out0, addr out1, input0.data))
+  result = (wrap(ctx, out0), wrap(ctx, out1))
+  ctx.maybeSync()
+
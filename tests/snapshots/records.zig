@@ -0,0 +1,515 @@
+// Generated by futhark-bindgen
+
+const std = @import("std");
+
+const futhark_context_config = opaque {};
+const futhark_context = opaque {};
+
+extern fn futhark_context_config_new() ?*futhark_context_config;
+extern fn futhark_context_config_free(cfg: *futhark_context_config) void;
+extern fn futhark_context_config_set_debugging(cfg: *futhark_context_config, flag: c_int) void;
+extern fn futhark_context_config_set_profiling(cfg: *futhark_context_config, flag: c_int) void;
+extern fn futhark_context_config_set_logging(cfg: *futhark_context_config, flag: c_int) void;
+extern fn futhark_context_config_set_cache_file(cfg: *futhark_context_config, f: [*:0]const u8) void;
+extern fn futhark_context_config_set_device(cfg: *futhark_context_config, s: [*:0]const u8) void;
+extern fn futhark_context_new(cfg: *futhark_context_config) ?*futhark_context;
+extern fn futhark_context_free(ctx: *futhark_context) void;
+extern fn futhark_context_sync(ctx: *futhark_context) c_int;
+extern fn futhark_context_clear_caches(ctx: *futhark_context) c_int;
+extern fn futhark_context_pause_profiling(ctx: *futhark_context) void;
+extern fn futhark_context_unpause_profiling(ctx: *futhark_context) void;
+extern fn futhark_context_get_error(ctx: *futhark_context) ?[*:0]u8;
+extern fn futhark_context_report(ctx: *futhark_context) ?[*:0]u8;
+extern fn free(ptr: ?*anyopaque) void;
+
+/// Errors returned by the generated functions
+pub const Error = error{
+    /// A Futhark function returned a non-zero error code, see `Context.getError`
+    Futhark,
+    /// A NULL pointer was returned
+    NullPtr,
+    /// The data doesn't match the shape of the array
+    InvalidShape,
+};
+
+/// Context options
+pub const Options = struct {
+    debug: bool = false,
+    profile: bool = false,
+    logging: bool = false,
+    cache_file: ?[*:0]const u8 = null,
+    auto_sync: bool = true,
+    device: ?[*:0]const u8 = null,
+};
+
+/// Futhark context
+pub const Context = struct {
+    config: *futhark_context_config,
+    handle: *futhark_context,
+    auto_sync: bool,
+
+    /// Create a new context
+    pub fn init(options: Options) Error!Context {
+        const config = futhark_context_config_new() orelse return error.NullPtr;
+        futhark_context_config_set_debugging(config, @intFromBool(options.debug));
+        futhark_context_config_set_profiling(config, @intFromBool(options.profile));
+        futhark_context_config_set_logging(config, @intFromBool(options.logging));
+        if (options.cache_file) |f| futhark_context_config_set_cache_file(config, f);
+        if (options.device) |d| futhark_context_config_set_device(config, d);
+        const handle = futhark_context_new(config) orelse {
+            futhark_context_config_free(config);
+            return error.NullPtr;
+        };
+        return .{ .config = config, .handle = handle, .auto_sync = options.auto_sync };
+    }
+
+    /// Free the context, all values created using the context must be freed first
+    pub fn deinit(self: *Context) void {
+        _ = futhark_context_sync(self.handle);
+        futhark_context_free(self.handle);
+        futhark_context_config_free(self.config);
+    }
+
+    /// Wait for all pending operations to finish
+    pub fn sync(self: *Context) Error!void {
+        if (futhark_context_sync(self.handle) != 0) return error.Futhark;
+    }
+
+    /// Sync if `auto_sync` is enabled
+    pub fn autoSync(self: *Context) Error!void {
+        if (self.auto_sync) try self.sync();
+    }
+
+    /// Free any cached memory
+    pub fn clearCaches(self: *Context) Error!void {
+        if (futhark_context_clear_caches(self.handle) != 0) return error.Futhark;
+    }
+
+    /// Pause profiling
+    pub fn pauseProfiling(self: *Context) void {
+        futhark_context_pause_profiling(self.handle);
+    }
+
+    /// Resume profiling
+    pub fn unpauseProfiling(self: *Context) void {
+        futhark_context_unpause_profiling(self.handle);
+    }
+
+    /// Get the last error message, the caller owns the returned memory
+    pub fn getError(self: *Context, allocator: std.mem.Allocator) std.mem.Allocator.Error!?[]u8 {
+        const s = futhark_context_get_error(self.handle) orelse return null;
+        defer free(s);
+        return try allocator.dupe(u8, std.mem.span(s));
+    }
+
+    /// Get the profiling report, the caller owns the returned memory
+    pub fn report(self: *Context, allocator: std.mem.Allocator) std.mem.Allocator.Error!?[]u8 {
+        const s = futhark_context_report(self.handle) orelse return null;
+        defer free(s);
+        return try allocator.dupe(u8, std.mem.span(s));
+    }
+};
+
+const futhark_opaque_2b0ad0ae = opaque {};
+
+extern fn futhark_free_opaque_2b0ad0ae(ctx: *futhark_context, obj: *futhark_opaque_2b0ad0ae) c_int;
+
+/// Futhark type
+pub const Type2b0ad0ae = struct {
+    ptr: *futhark_opaque_2b0ad0ae,
+    ctx: *Context,
+
+    fn fromPtr(ctx: *Context, ptr: *futhark_opaque_2b0ad0ae) Type2b0ad0ae {
+        return .{ .ptr = ptr, .ctx = ctx };
+    }
+
+    /// Free the value
+    pub fn deinit(self: *Type2b0ad0ae) void {
+        _ = futhark_free_opaque_2b0ad0ae(self.ctx.handle, self.ptr);
+    }
+
+    extern fn futhark_new_opaque_2b0ad0ae(ctx: *futhark_context, out: *?*futhark_opaque_2b0ad0ae, field0: i32, field1: f32) c_int;
+
+    /// Create a new Type2b0ad0ae
+    pub fn init(ctx: *Context, field0: i32, field1: f32) Error!Type2b0ad0ae {
+        var out: ?*futhark_opaque_2b0ad0ae = null;
+        if (futhark_new_opaque_2b0ad0ae(ctx.handle, &out, field0, field1) != 0) return error.Futhark;
+        try ctx.autoSync();
+        return .{ .ptr = out orelse return error.NullPtr, .ctx = ctx };
+    }
+
+    extern fn futhark_project_opaque_2b0ad0ae_0(ctx: *futhark_context, out: *i32, obj: *const futhark_opaque_2b0ad0ae) c_int;
+
+    /// Get the 0 field
+    pub fn get0(self: Type2b0ad0ae) Error!i32 {
+        var out: i32 = undefined;
+        if (futhark_project_opaque_2b0ad0ae_0(self.ctx.handle, &out, self.ptr) != 0) return error.Futhark;
+        try self.ctx.autoSync();
+        return out;
+    }
+
+    extern fn futhark_project_opaque_2b0ad0ae_1(ctx: *futhark_context, out: *f32, obj: *const futhark_opaque_2b0ad0ae) c_int;
+
+    /// Get the 1 field
+    pub fn get1(self: Type2b0ad0ae) Error!f32 {
+        var out: f32 = undefined;
+        if (futhark_project_opaque_2b0ad0ae_1(self.ctx.handle, &out, self.ptr) != 0) return error.Futhark;
+        try self.ctx.autoSync();
+        return out;
+    }
+};
+
+const futhark_f32_1d = opaque {};
+
+extern fn futhark_new_f32_1d(ctx: *futhark_context, data: [*]const f32, dim0: i64) ?*futhark_f32_1d;
+extern fn futhark_free_f32_1d(ctx: *futhark_context, arr: *futhark_f32_1d) c_int;
+extern fn futhark_values_f32_1d(ctx: *futhark_context, arr: *futhark_f32_1d, data: [*]f32) c_int;
+extern fn futhark_shape_f32_1d(ctx: *futhark_context, arr: *futhark_f32_1d) [*]const i64;
+
+/// Array type with 1 dimensions and f32 elements
+pub const ArrayF32D1 = struct {
+    ptr: *futhark_f32_1d,
+    shape: [1]i64,
+    ctx: *Context,
+
+    /// Create a new array with the shape `dims`, initialized with the values from `data`
+    pub fn init(ctx: *Context, data: []const f32, dims: [1]i64) Error!ArrayF32D1 {
+        var size: usize = 1;
+        for (dims) |d| size *= @intCast(d);
+        if (data.len != size) return error.InvalidShape;
+        const ptr = futhark_new_f32_1d(ctx.handle, data.ptr, dims[0]) orelse return error.NullPtr;
+        try ctx.autoSync();
+        return .{ .ptr = ptr, .shape = dims, .ctx = ctx };
+    }
+
+    fn fromPtr(ctx: *Context, ptr: *futhark_f32_1d) ArrayF32D1 {
+        const s = futhark_shape_f32_1d(ctx.handle, ptr);
+        var shape: [1]i64 = undefined;
+        for (&shape, 0..) |*d, i| d.* = s[i];
+        return .{ .ptr = ptr, .shape = shape, .ctx = ctx };
+    }
+
+    /// Free the array
+    pub fn deinit(self: *ArrayF32D1) void {
+        _ = futhark_free_f32_1d(self.ctx.handle, self.ptr);
+    }
+
+    /// Number of elements in the array
+    pub fn len(self: ArrayF32D1) usize {
+        var size: usize = 1;
+        for (self.shape) |d| size *= @intCast(d);
+        return size;
+    }
+
+    /// Copy the values into `out`, which must have the same number of elements as the array
+    pub fn values(self: ArrayF32D1, out: []f32) Error!void {
+        if (out.len != self.len()) return error.InvalidShape;
+        if (futhark_values_f32_1d(self.ctx.handle, self.ptr, out.ptr) != 0) return error.Futhark;
+        try self.ctx.autoSync();
+    }
+
+    /// Copy the values into a new slice, the caller owns the returned memory
+    pub fn toSlice(self: ArrayF32D1, allocator: std.mem.Allocator) (Error || std.mem.Allocator.Error)![]f32 {
+        const out = try allocator.alloc(f32, self.len());
+        errdefer allocator.free(out);
+        try self.values(out);
+        return out;
+    }
+};
+
+const futhark_opaque_arr_point_1d = opaque {};
+
+extern fn futhark_free_opaque_arr_point_1d(ctx: *futhark_context, obj: *futhark_opaque_arr_point_1d) c_int;
+
+/// Futhark type
+pub const Arr_point_1d = struct {
+    ptr: *futhark_opaque_arr_point_1d,
+    ctx: *Context,
+
+    fn fromPtr(ctx: *Context, ptr: *futhark_opaque_arr_point_1d) Arr_point_1d {
+        return .{ .ptr = ptr, .ctx = ctx };
+    }
+
+    /// Free the value
+    pub fn deinit(self: *Arr_point_1d) void {
+        _ = futhark_free_opaque_arr_point_1d(self.ctx.handle, self.ptr);
+    }
+};
+
+const futhark_opaque_number = opaque {};
+
+extern fn futhark_free_opaque_number(ctx: *futhark_context, obj: *futhark_opaque_number) c_int;
+
+/// Futhark type
+pub const Number = struct {
+    ptr: *futhark_opaque_number,
+    ctx: *Context,
+
+    fn fromPtr(ctx: *Context, ptr: *futhark_opaque_number) Number {
+        return .{ .ptr = ptr, .ctx = ctx };
+    }
+
+    /// Free the value
+    pub fn deinit(self: *Number) void {
+        _ = futhark_free_opaque_number(self.ctx.handle, self.ptr);
+    }
+
+    extern fn futhark_new_opaque_number(ctx: *futhark_context, out: *?*futhark_opaque_number, fieldx: f32) c_int;
+
+    /// Create a new Number
+    pub fn init(ctx: *Context, fieldx: f32) Error!Number {
+        var out: ?*futhark_opaque_number = null;
+        if (futhark_new_opaque_number(ctx.handle, &out, fieldx) != 0) return error.Futhark;
+        try ctx.autoSync();
+        return .{ .ptr = out orelse return error.NullPtr, .ctx = ctx };
+    }
+
+    extern fn futhark_project_opaque_number_x(ctx: *futhark_context, out: *f32, obj: *const futhark_opaque_number) c_int;
+
+    /// Get the x field
+    pub fn getX(self: Number) Error!f32 {
+        var out: f32 = undefined;
+        if (futhark_project_opaque_number_x(self.ctx.handle, &out, self.ptr) != 0) return error.Futhark;
+        try self.ctx.autoSync();
+        return out;
+    }
+};
+
+const futhark_opaque_option = opaque {};
+
+extern fn futhark_free_opaque_option(ctx: *futhark_context, obj: *futhark_opaque_option) c_int;
+
+/// Futhark type
+pub const Option = struct {
+    ptr: *futhark_opaque_option,
+    ctx: *Context,
+
+    fn fromPtr(ctx: *Context, ptr: *futhark_opaque_option) Option {
+        return .{ .ptr = ptr, .ctx = ctx };
+    }
+
+    /// Free the value
+    pub fn deinit(self: *Option) void {
+        _ = futhark_free_opaque_option(self.ctx.handle, self.ptr);
+    }
+};
+
+const futhark_opaque_point = opaque {};
+
+extern fn futhark_free_opaque_point(ctx: *futhark_context, obj: *futhark_opaque_point) c_int;
+
+/// Futhark type
+pub const Point = struct {
+    ptr: *futhark_opaque_point,
+    ctx: *Context,
+
+    fn fromPtr(ctx: *Context, ptr: *futhark_opaque_point) Point {
+        return .{ .ptr = ptr, .ctx = ctx };
+    }
+
+    /// Free the value
+    pub fn deinit(self: *Point) void {
+        _ = futhark_free_opaque_point(self.ctx.handle, self.ptr);
+    }
+
+    extern fn futhark_new_opaque_point(ctx: *futhark_context, out: *?*futhark_opaque_point, fieldx: f32, fieldy: f32) c_int;
+
+    /// Create a new Point
+    pub fn init(ctx: *Context, fieldx: f32, fieldy: f32) Error!Point {
+        var out: ?*futhark_opaque_point = null;
+        if (futhark_new_opaque_point(ctx.handle, &out, fieldx, fieldy) != 0) return error.Futhark;
+        try ctx.autoSync();
+        return .{ .ptr = out orelse return error.NullPtr, .ctx = ctx };
+    }
+
+    extern fn futhark_project_opaque_point_x(ctx: *futhark_context, out: *f32, obj: *const futhark_opaque_point) c_int;
+
+    /// Get the x field
+    pub fn getX(self: Point) Error!f32 {
+        var out: f32 = undefined;
+        if (futhark_project_opaque_point_x(self.ctx.handle, &out, self.ptr) != 0) return error.Futhark;
+        try self.ctx.autoSync();
+        return out;
+    }
+
+    extern fn futhark_project_opaque_point_y(ctx: *futhark_context, out: *f32, obj: *const futhark_opaque_point) c_int;
+
+    /// Get the y field
+    pub fn getY(self: Point) Error!f32 {
+        var out: f32 = undefined;
+        if (futhark_project_opaque_point_y(self.ctx.handle, &out, self.ptr) != 0) return error.Futhark;
+        try self.ctx.autoSync();
+        return out;
+    }
+};
+
+const futhark_opaque_segment = opaque {};
+
+extern fn futhark_free_opaque_segment(ctx: *futhark_context, obj: *futhark_opaque_segment) c_int;
+
+/// Futhark type
+pub const Segment = struct {
+    ptr: *futhark_opaque_segment,
+    ctx: *Context,
+
+    fn fromPtr(ctx: *Context, ptr: *futhark_opaque_segment) Segment {
+        return .{ .ptr = ptr, .ctx = ctx };
+    }
+
+    /// Free the value
+    pub fn deinit(self: *Segment) void {
+        _ = futhark_free_opaque_segment(self.ctx.handle, self.ptr);
+    }
+
+    extern fn futhark_new_opaque_segment(ctx: *futhark_context, out: *?*futhark_opaque_segment, fieldstart: *const futhark_opaque_point, fieldend: *const futhark_opaque_point) c_int;
+
+    /// Create a new Segment
+    pub fn init(ctx: *Context, fieldstart: *const Point, fieldend: *const Point) Error!Segment {
+        var out: ?*futhark_opaque_segment = null;
+        if (futhark_new_opaque_segment(ctx.handle, &out, fieldstart.ptr, fieldend.ptr) != 0) return error.Futhark;
+        try ctx.autoSync();
+        return .{ .ptr = out orelse return error.NullPtr, .ctx = ctx };
+    }
+
+    extern fn futhark_project_opaque_segment_start(ctx: *futhark_context, out: *?*futhark_opaque_point, obj: *const futhark_opaque_segment) c_int;
+
+    /// Get the start field
+    pub fn getStart(self: Segment) Error!Point {
+        var out: ?*futhark_opaque_point = null;
+        if (futhark_project_opaque_segment_start(self.ctx.handle, &out, self.ptr) != 0) return error.Futhark;
+        try self.ctx.autoSync();
+        return Point.fromPtr(self.ctx, out orelse return error.NullPtr);
+    }
+
+    extern fn futhark_project_opaque_segment_end(ctx: *futhark_context, out: *?*futhark_opaque_point, obj: *const futhark_opaque_segment) c_int;
+
+    /// Get the end field
+    pub fn getEnd(self: Segment) Error!Point {
+        var out: ?*futhark_opaque_point = null;
+        if (futhark_project_opaque_segment_end(self.ctx.handle, &out, self.ptr) != 0) return error.Futhark;
+        try self.ctx.autoSync();
+        return Point.fromPtr(self.ctx, out orelse return error.NullPtr);
+    }
+};
+
+const futhark_opaque_tup = opaque {};
+
+extern fn futhark_free_opaque_tup(ctx: *futhark_context, obj: *futhark_opaque_tup) c_int;
+
+/// Futhark type
+pub const Tup = struct {
+    ptr: *futhark_opaque_tup,
+    ctx: *Context,
+
+    fn fromPtr(ctx: *Context, ptr: *futhark_opaque_tup) Tup {
+        return .{ .ptr = ptr, .ctx = ctx };
+    }
+
+    /// Free the value
+    pub fn deinit(self: *Tup) void {
+        _ = futhark_free_opaque_tup(self.ctx.handle, self.ptr);
+    }
+
+    extern fn futhark_new_opaque_tup(ctx: *futhark_context, out: *?*futhark_opaque_tup, field0: *const futhark_opaque_number, field1: *const futhark_f32_1d) c_int;
+
+    /// Create a new Tup
+    pub fn init(ctx: *Context, field0: *const Number, field1: *const ArrayF32D1) Error!Tup {
+        var out: ?*futhark_opaque_tup = null;
+        if (futhark_new_opaque_tup(ctx.handle, &out, field0.ptr, field1.ptr) != 0) return error.Futhark;
+        try ctx.autoSync();
+        return .{ .ptr = out orelse return error.NullPtr, .ctx = ctx };
+    }
+
+    extern fn futhark_project_opaque_tup_0(ctx: *futhark_context, out: *?*futhark_opaque_number, obj: *const futhark_opaque_tup) c_int;
+
+    /// Get the 0 field
+    pub fn get0(self: Tup) Error!Number {
+        var out: ?*futhark_opaque_number = null;
+        if (futhark_project_opaque_tup_0(self.ctx.handle, &out, self.ptr) != 0) return error.Futhark;
+        try self.ctx.autoSync();
+        return Number.fromPtr(self.ctx, out orelse return error.NullPtr);
+    }
+
+    extern fn futhark_project_opaque_tup_1(ctx: *futhark_context, out: *?*futhark_f32_1d, obj: *const futhark_opaque_tup) c_int;
+
+    /// Get the 1 field
+    pub fn get1(self: Tup) Error!ArrayF32D1 {
+        var out: ?*futhark_f32_1d = null;
+        if (futhark_project_opaque_tup_1(self.ctx.handle, &out, self.ptr) != 0) return error.Futhark;
+        try self.ctx.autoSync();
+        return ArrayF32D1.fromPtr(self.ctx, out orelse return error.NullPtr);
+    }
+};
+
+extern fn futhark_entry_distance(ctx: *futhark_context, out0: *f32, input0: *const futhark_opaque_point, input1: *const futhark_opaque_point) c_int;
+
+/// Entry point: distance
+pub fn distance(ctx: *Context, input0: *const Point, input1: *const Point) Error!f32 {
+    var out0: f32 = undefined;
+    if (futhark_entry_distance(ctx.handle, &out0, input0.ptr, input1.ptr) != 0) return error.Futhark;
+    try ctx.autoSync();
+    return out0;
+}
+
+extern fn futhark_entry_length(ctx: *futhark_context, out0: *f32, input0: *const futhark_opaque_segment) c_int;
+
+/// Entry point: length
+pub fn length(ctx: *Context, input0: *const Segment) Error!f32 {
+    var out0: f32 = undefined;
+    if (futhark_entry_length(ctx.handle, &out0, input0.ptr) != 0) return error.Futhark;
+    try ctx.autoSync();
+    return out0;
+}
+
+extern fn futhark_entry_points(ctx: *futhark_context, out0: *?*futhark_opaque_arr_point_1d, input0: *const futhark_f32_1d, input1: *const futhark_f32_1d) c_int;
+
+/// Entry point: points
+pub fn points(ctx: *Context, input0: *const ArrayF32D1, input1: *const ArrayF32D1) Error!Arr_point_1d {
+    var out0: ?*futhark_opaque_arr_point_1d = null;
+    if (futhark_entry_points(ctx.handle, &out0, input0.ptr, input1.ptr) != 0) return error.Futhark;
+    try ctx.autoSync();
+    return Arr_point_1d.fromPtr(ctx, out0 orelse return error.NullPtr);
+}
+
+extern fn futhark_entry_return_option(ctx: *futhark_context, out0: *?*futhark_opaque_option, input0: *const futhark_opaque_option) c_int;
+
+/// Entry point: return_option
+pub fn returnOption(ctx: *Context, input0: *const Option) Error!Option {
+    var out0: ?*futhark_opaque_option = null;
+    if (futhark_entry_return_option(ctx.handle, &out0, input0.ptr) != 0) return error.Futhark;
+    try ctx.autoSync();
+    return Option.fromPtr(ctx, out0 orelse return error.NullPtr);
+}
+
+extern fn futhark_entry_swap(ctx: *futhark_context, out0: *f32, out1: *i32, input0: *const futhark_opaque_2b0ad0ae) c_int;
+
+/// Entry point: swap
+pub fn swap(ctx: *Context, input0: *const Type2b0ad0ae) Error!struct { f32, i32 } {
+    var out0: f32 = undefined;
+    var out1: i32 = undefined;
+    if (futhark_entry_swap(ctx.handle, &out0, &out1, input0.ptr) != 0) return error.Futhark;
+    try ctx.autoSync();
+    return .{ out0, out1 };
+}
+
+extern fn futhark_entry_tup_mul(ctx: *futhark_context, out0: *?*futhark_f32_1d, input0: *const futhark_opaque_tup) c_int;
+
+/// Entry point: tup_mul
+pub fn tupMul(ctx: *Context, input0: *const Tup) Error!ArrayF32D1 {
+    var out0: ?*futhark_f32_1d = null;
+    if (futhark_entry_tup_mul(ctx.handle, &out0, input0.ptr) != 0) return error.Futhark;
+    try ctx.autoSync();
+    return ArrayF32D1.fromPtr(ctx, out0 orelse return error.NullPtr);
+}
+
+extern fn futhark_entry_unzip_points(ctx: *futhark_context, out0: *?*futhark_f32_1d, out1: *?*futhark_f32_1d, input0: *const futhark_opaque_arr_point_1d) c_int;
+
+/// Entry point: unzip_points
+pub fn unzipPoints(ctx: *Context, input0: *const Arr_point_1d) Error!struct { ArrayF32D1, ArrayF32D1 } {
+    var out0: ?*futhark_f32_1d = null;
+    var out1: ?*futhark_f32_1d = null;
+    if (futhark_entry_unzip_points(ctx.handle, &out0, &out1, input0.ptr) != 0) return error.Futhark;
+    try ctx.autoSync();
+    return .{ ArrayF32D1.fromPtr(ctx, out0 orelse return error.NullPtr), ArrayF32D1.fromPtr(ctx, out1 orelse return error.NullPtr) };
+}
+
@@ -0,0 +1,1645 @@
+// Generated by futhark-bindgen
+
+#[derive(Debug)]
+pub enum Error {
+    Code(std::os::raw::c_int),
+    NullPtr,
+    InvalidShape,
+    IndexOutOfBounds,
+    Unsupported(&'static str),
+    MissingField(&'static str),
+    InvalidTuning(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::Code(code) => write!(fmt, "Futhark error code: {code}"),
+            Error::NullPtr => write!(fmt, "NULL pointer encountered"),
+            Error::InvalidShape => write!(fmt, "Invalid image shape"),
+            Error::IndexOutOfBounds => write!(fmt, "Index out of bounds"),
+            Error::Unsupported(op) => write!(fmt, "{op} is not supported by this version of Futhark"),
+            Error::MissingField(field) => write!(fmt, "Missing field: {field}"),
+            Error::InvalidTuning(msg) => write!(fmt, "Invalid tuning parameter: {msg}"),
+        }
+    } 
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Debug, Clone)]
+pub struct Options {
+    debug: bool,
+    profile: bool,
+    logging: bool,
+    cache_file: std::option::Option<std::ffi::CString>,
+    device: std::option::Option<std::ffi::CString>,
+    platform: std::option::Option<std::ffi::CString>,
+    default_group_size: std::option::Option<u32>,
+    default_tile_size: std::option::Option<u32>,
+    tuning_params: Vec<(std::ffi::CString, usize)>,
+    tuning_file: std::option::Option<std::path::PathBuf>,
+    auto_sync: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options::new()
+    }
+}
+
+impl Options {
+    /// Create new `Options` with default settings
+    pub fn new() -> Self {
+        Options {
+            debug: false,
+            profile: false,
+            logging: false,
+            cache_file: None,
+            device: None,
+            platform: None,
+            default_group_size: None,
+            default_tile_size: None,
+            tuning_params: Vec::new(),
+            tuning_file: None,
+            auto_sync: true,
+        }
+    }
+
+    /// Enable debug
+    pub fn debug(mut self) -> Self {
+        self.debug = true;
+        self
+    }
+
+    /// Enable profiling
+    pub fn profile(mut self) -> Self {
+        self.profile = true;
+        self
+    }
+
+    /// Enable logging
+    pub fn log(mut self) -> Self {
+        self.logging = true;
+        self
+    }
+
+    /// Set Futhark cache file
+    pub fn cache_file(mut self, s: impl AsRef<str>) -> Self {
+        self.cache_file = Some(std::ffi::CString::new(s.as_ref()).expect("Invalid cache file"));
+        self
+    }
+
+    pub fn auto_sync(mut self, sync: bool) -> Self {
+        self.auto_sync = sync;
+        self
+    }
+
+    /// Set a tuning parameter, these are applied after the parameters from the tuning file
+    pub fn tuning_param(mut self, name: impl AsRef<str>, value: usize) -> Self {
+        let name = std::ffi::CString::new(name.as_ref()).expect("Invalid tuning parameter");
+        self.tuning_params.push((name, value));
+        self
+    }
+
+    /// Load tuning parameters from a `.tuning` file when the context is created
+    pub fn tuning_file(mut self, path: impl AsRef<std::path::Path>) -> Self {
+        self.tuning_file = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    // Read a tuning file, every line has the form `name=value`
+    fn read_tuning_file(path: &std::path::Path) -> std::result::Result<Vec<(std::ffi::CString, usize)>, Error> {
+        let s = std::fs::read_to_string(path)
+            .map_err(|e| Error::InvalidTuning(format!("{}: {e}", path.display())))?;
+        s.lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let invalid = || Error::InvalidTuning(line.to_string());
+                let (name, value) = line.split_once('=').ok_or_else(invalid)?;
+                let value = value.trim().parse().map_err(|_| invalid())?;
+                let name = std::ffi::CString::new(name.trim()).map_err(|_| invalid())?;
+                Ok((name, value))
+            })
+            .collect()
+    }
+
+
+    pub fn device(mut self, s: impl AsRef<str>) -> Options { self.device = Some(std::ffi::CString::new(s.as_ref()).expect("Invalid device")); self }
+
+    /// Select the OpenCL platform by name
+    pub fn platform(mut self, s: impl AsRef<str>) -> Options { self.platform = Some(std::ffi::CString::new(s.as_ref()).expect("Invalid platform")); self }
+
+    /// Set the default group size
+    pub fn default_group_size(mut self, n: u32) -> Options { self.default_group_size = Some(n); self }
+
+    /// Set the default tile size
+    pub fn default_tile_size(mut self, n: u32) -> Options { self.default_tile_size = Some(n); self }
+}
+
+/// Futhark context
+pub struct Context {
+    config: *mut futhark_context_config,
+    context: *mut futhark_context,
+    auto_sync: bool,
+    _cache_file: std::option::Option<std::ffi::CString>,
+    sync_worker: std::sync::OnceLock<SyncWorker>,
+}
+
+impl Context {
+    /// Create a new context with default options
+    pub fn new() -> std::result::Result<Self, Error> {
+        unsafe {
+            let config = futhark_context_config_new();
+            if config.is_null() { return Err(Error::NullPtr) }
+            let context = futhark_context_new(config);
+            if context.is_null() {
+                futhark_context_config_free(config);
+                return Err(Error::NullPtr);
+            }
+            Ok(Context { config, context, auto_sync: true, _cache_file: None, sync_worker: std::sync::OnceLock::new() })
+        }
+    }
+
+    /// Create a new context with custom options
+    pub fn new_with_options(options: Options) -> std::result::Result<Self, Error> {
+        let mut tuning_params = match &options.tuning_file {
+            Some(path) => Options::read_tuning_file(path)?,
+            None => Vec::new(),
+        };
+        tuning_params.extend(options.tuning_params.iter().cloned());
+
+        unsafe {
+            let config = futhark_context_config_new();
+            if config.is_null() { return Err(Error::NullPtr) }
+
+            for (name, value) in &tuning_params {
+                if futhark_context_config_set_tuning_param(config, name.as_ptr(), *value) != 0 {
+                    futhark_context_config_free(config);
+                    return Err(Error::InvalidTuning(name.to_string_lossy().into_owned()));
+                }
+            }
+
+            futhark_context_config_set_debugging(config, options.debug as std::os::raw::c_int);
+            futhark_context_config_set_profiling(config, options.profile as std::os::raw::c_int);
+            futhark_context_config_set_logging(config, options.logging as std::os::raw::c_int);
+
+            if let Some(c) = &options.cache_file {
+                futhark_context_config_set_cache_file(config, c.as_ptr());
+            }
+
+            if let Some(d) = &options.device { futhark_context_config_set_device(config, d.as_ptr()); }
+            if let Some(p) = &options.platform { futhark_context_config_set_platform(config, p.as_ptr()); }
+            if let Some(n) = options.default_group_size { futhark_context_config_set_default_group_size(config, n as std::os::raw::c_int); }
+            if let Some(n) = options.default_tile_size { futhark_context_config_set_default_tile_size(config, n as std::os::raw::c_int); }
+
+            let context = futhark_context_new(config);
+            if context.is_null() {
+                futhark_context_config_free(config);
+                return Err(Error::NullPtr);
+            }
+            Ok(Context { config, context, auto_sync: options.auto_sync, _cache_file: options.cache_file, sync_worker: std::sync::OnceLock::new() })
+        }
+    }
+
+    /// Sync the context, if `auto_sync` is enabled this shouldn't be needed
+    pub fn sync(&self) {
+        unsafe { futhark_context_sync(self.context); }
+    }
+
+    /// Sync if `auto_sync` is enabled, otherwise this is a noop
+    pub fn auto_sync(&self) {
+        if self.auto_sync {
+            self.sync();
+        }
+    }
+
+    /// Clear Futhark caches
+    pub fn clear_caches(&self) -> std::result::Result<(), Error> {
+        let rc = unsafe {
+            futhark_context_clear_caches(self.context)
+        };
+        if rc != 0 { return Err(Error::Code(rc)) }
+        Ok(())
+    }
+
+    /// Pause Futhark profiling
+    pub fn pause_profiling(&self) {
+        unsafe {
+            futhark_context_pause_profiling(self.context);
+        }
+    }
+
+    /// Resume profiling
+    pub fn unpause_profiling(&self) {
+        unsafe {
+            futhark_context_unpause_profiling(self.context);
+        }
+    }
+
+    /// Get the last error message or None
+    pub fn get_error(&self) -> std::option::Option<String> {
+        unsafe {
+            let s = futhark_context_get_error(self.context);
+            if s.is_null() { return None }
+            let r = std::ffi::CStr::from_ptr(s).to_string_lossy().to_string();
+            free(s as *mut _);
+            Some(r)
+        }
+    }
+
+    pub fn report(&self) -> std::option::Option<String> {
+        unsafe {
+            let s = futhark_context_report(self.context);
+            if s.is_null() { return None }
+            let r = std::ffi::CStr::from_ptr(s).to_string_lossy().to_string();
+            free(s as *mut _);
+            Some(r)
+        }
+    }
+}
+
+impl Drop for Context {
+    fn drop(&mut self) {
+        if let Some(worker) = self.sync_worker.take() {
+            worker.stop();
+        }
+        unsafe {
+            futhark_context_sync(self.context);
+            futhark_context_free(self.context);
+            futhark_context_config_free(self.config);
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_context_config {
+    _private: [u8; 0]
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_context {
+    _private: [u8; 0]
+}
+
+extern "C" {
+    fn futhark_context_config_new() -> *mut futhark_context_config;
+    fn futhark_context_config_free(
+        _: *mut futhark_context_config
+    );
+    fn futhark_context_config_set_debugging(
+        _: *mut futhark_context_config,
+        _: std::os::raw::c_int
+    );
+
+    fn futhark_context_config_set_profiling(
+        _: *mut futhark_context_config,
+        _: std::os::raw::c_int
+    );
+
+    fn futhark_context_config_set_logging(
+        _: *mut futhark_context_config,
+        _: std::os::raw::c_int
+    );
+
+    fn futhark_context_config_set_cache_file(
+        _: *mut futhark_context_config,
+        _: *const std::os::raw::c_char,
+    );
+
+    fn futhark_context_config_set_tuning_param(
+        _: *mut futhark_context_config,
+        _: *const std::os::raw::c_char,
+        _: usize,
+    ) -> std::os::raw::c_int;
+
+    fn futhark_context_new(
+        _: *mut futhark_context_config
+    ) -> *mut futhark_context;
+
+    fn futhark_context_free(
+        _: *mut futhark_context
+    );
+
+    fn futhark_context_sync(
+        _: *mut futhark_context,
+    ) -> std::os::raw::c_int;
+
+    fn futhark_context_clear_caches(
+        _: *mut futhark_context,
+    ) -> std::os::raw::c_int;
+
+    fn futhark_context_pause_profiling(
+        _: *mut futhark_context
+    );
+
+    fn futhark_context_unpause_profiling(
+        _: *mut futhark_context
+    );
+
+    fn futhark_context_get_error(
+        _: *mut futhark_context
+    ) -> *mut std::os::raw::c_char;
+
+    fn futhark_context_report(
+        _: *mut futhark_context
+    ) -> *mut std::os::raw::c_char;
+
+    fn free(_: *mut std::ffi::c_void);
+
+    fn futhark_context_config_set_device(_: *mut futhark_context_config, _: *const std::os::raw::c_char);
+    fn futhark_context_config_set_platform(_: *mut futhark_context_config, _: *const std::os::raw::c_char);
+    fn futhark_context_config_set_default_group_size(_: *mut futhark_context_config, _: std::os::raw::c_int);
+    fn futhark_context_config_set_default_tile_size(_: *mut futhark_context_config, _: std::os::raw::c_int);
+}
+
+// Result of a sync run by a `SyncWorker`, along with the task waiting for it
+#[derive(Default)]
+struct SyncState {
+    inner: std::sync::Mutex<(std::option::Option<std::os::raw::c_int>, std::option::Option<std::task::Waker>)>,
+    done: std::sync::Condvar,
+}
+
+struct ContextPtr(*mut futhark_context);
+
+// Futhark contexts use an internal lock, so syncing from another thread is safe
+unsafe impl Send for ContextPtr {}
+
+/// Thread owned by a `Context`, used to sync without blocking async executors
+struct SyncWorker {
+    sender: std::sync::Mutex<std::sync::mpsc::Sender<std::sync::Arc<SyncState>>>,
+    thread: std::thread::JoinHandle<()>,
+}
+
+impl SyncWorker {
+    fn new(context: *mut futhark_context) -> Self {
+        let (sender, receiver) = std::sync::mpsc::channel::<std::sync::Arc<SyncState>>();
+        let ptr = ContextPtr(context);
+        let thread = std::thread::spawn(move || {
+            let ptr = ptr;
+            for state in receiver {
+                let rc = unsafe { futhark_context_sync(ptr.0) };
+                let mut inner = state.inner.lock().unwrap();
+                inner.0 = Some(rc);
+                state.done.notify_all();
+                if let Some(waker) = inner.1.take() {
+                    waker.wake();
+                }
+            }
+        });
+        SyncWorker { sender: std::sync::Mutex::new(sender), thread }
+    }
+
+    /// Wait for any pending syncs and stop the thread
+    fn stop(self) {
+        drop(self.sender);
+        let _ = self.thread.join();
+    }
+}
+
+/// Future returned by `Context::sync_async`, resolved once the context has been synced. It
+/// doesn't borrow the context, so it can be sent to other threads.
+pub struct SyncFuture {
+    state: std::sync::Arc<SyncState>,
+}
+
+impl std::future::Future for SyncFuture {
+    type Output = std::result::Result<(), Error>;
+
+    fn poll(self: std::pin::Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> std::task::Poll<Self::Output> {
+        let mut inner = self.state.inner.lock().unwrap();
+        match inner.0 {
+            Some(0) => std::task::Poll::Ready(Ok(())),
+            Some(rc) => std::task::Poll::Ready(Err(Error::Code(rc))),
+            None => {
+                inner.1 = Some(cx.waker().clone());
+                std::task::Poll::Pending
+            }
+        }
+    }
+}
+
+impl SyncFuture {
+    /// Block the current thread until the context has been synced
+    fn wait(&self) {
+        let inner = self.state.inner.lock().unwrap();
+        let _unused = self.state.done.wait_while(inner, |inner| inner.0.is_none()).unwrap();
+    }
+}
+
+/// Future returned by `get_async` on arrays, resolved once the values have been copied to the
+/// host. Dropping it before then blocks until the context has been synced, since the values are
+/// still being written to the buffer it owns.
+pub struct ValuesFuture<T> {
+    rc: std::os::raw::c_int,
+    sync: std::option::Option<SyncFuture>,
+    data: Vec<T>,
+}
+
+impl<T: Unpin> std::future::Future for ValuesFuture<T> {
+    type Output = std::result::Result<Vec<T>, Error>;
+
+    fn poll(mut self: std::pin::Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> std::task::Poll<Self::Output> {
+        if let Some(sync) = &mut self.sync {
+            std::task::ready!(std::pin::Pin::new(sync).poll(cx))?;
+            self.sync = None;
+        }
+        if self.rc != 0 {
+            return std::task::Poll::Ready(Err(Error::Code(self.rc)));
+        }
+        std::task::Poll::Ready(Ok(std::mem::take(&mut self.data)))
+    }
+}
+
+impl<T> Drop for ValuesFuture<T> {
+    fn drop(&mut self) {
+        if let Some(sync) = self.sync.take() {
+            sync.wait();
+        }
+    }
+}
+
+// Futhark contexts use an internal lock, so they can be shared between threads
+unsafe impl Send for Context {}
+unsafe impl Sync for Context {}
+
+impl Context {
+    fn sync_worker(&self) -> &SyncWorker {
+        self.sync_worker.get_or_init(|| SyncWorker::new(self.context))
+    }
+
+    /// Start syncing the context on a separate thread, the returned future can be awaited from
+    /// any async runtime without blocking it
+    pub fn sync_async(&self) -> SyncFuture {
+        let state = std::sync::Arc::new(SyncState::default());
+        self.sync_worker()
+            .sender
+            .lock()
+            .unwrap()
+            .send(state.clone())
+            .expect("Sync worker has stopped");
+        SyncFuture { state }
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_opaque_2b0ad0ae {
+    _private: [u8; 0]
+}
+
+extern "C" {
+    fn futhark_free_opaque_2b0ad0ae(
+        _: *mut futhark_context,
+        _: *mut futhark_opaque_2b0ad0ae
+    ) -> std::os::raw::c_int;
+}
+
+/// Futhark type
+pub struct Type2b0ad0ae<'a> {
+    data: *mut futhark_opaque_2b0ad0ae,
+    ctx: &'a Context,
+}
+
+impl<'a> Type2b0ad0ae<'a> {
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, data: *mut futhark_opaque_2b0ad0ae) -> Self {
+        Self { ctx, data }
+    }
+}
+
+impl<'a> Drop for Type2b0ad0ae<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            futhark_free_opaque_2b0ad0ae(self.ctx.context, self.data);
+        }
+    }
+}
+
+// Opaque values are only accessed through the context, which uses an internal lock
+unsafe impl Send for Type2b0ad0ae<'_> {}
+unsafe impl Sync for Type2b0ad0ae<'_> {}
+
+impl<'a> Type2b0ad0ae<'a> {
+    /// Get 0 field
+    pub fn get_0(&self) -> Result<i32, Error> {
+        let mut out = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_project_opaque_2b0ad0ae_0(
+                self.ctx.context,
+                out.as_mut_ptr(),
+                self.data
+            )
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+        self.ctx.auto_sync();
+        let out = unsafe { out.assume_init() };
+        Ok(out)
+    }
+}
+
+extern "C" {
+    fn futhark_project_opaque_2b0ad0ae_0(
+        _: *mut futhark_context,
+        _: *mut i32,
+        _: *const futhark_opaque_2b0ad0ae
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> Type2b0ad0ae<'a> {
+    /// Get 1 field
+    pub fn get_1(&self) -> Result<f32, Error> {
+        let mut out = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_project_opaque_2b0ad0ae_1(
+                self.ctx.context,
+                out.as_mut_ptr(),
+                self.data
+            )
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+        self.ctx.auto_sync();
+        let out = unsafe { out.assume_init() };
+        Ok(out)
+    }
+}
+
+extern "C" {
+    fn futhark_project_opaque_2b0ad0ae_1(
+        _: *mut futhark_context,
+        _: *mut f32,
+        _: *const futhark_opaque_2b0ad0ae
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> Type2b0ad0ae<'a> {
+    /// Create new Type2b0ad0ae
+    pub fn new(ctx: &'a Context, field0: i32, field1: f32) -> std::result::Result<Self, Error> {
+        unsafe {
+            let mut out = std::ptr::null_mut();
+            let rc = futhark_new_opaque_2b0ad0ae(ctx.context, &mut out, field0, field1);
+            if rc != 0 { return Err(Error::Code(rc)); }
+            ctx.auto_sync();
+            Ok(Self { data: out, ctx })
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_new_opaque_2b0ad0ae(
+        _: *mut futhark_context,
+        _: *mut *mut futhark_opaque_2b0ad0ae,
+        field0: i32, field1: f32
+    ) -> std::os::raw::c_int;
+}
+/// Builder for [Type2b0ad0ae], all fields have to be set before calling `build`
+#[derive(Default)]
+pub struct Type2b0ad0aeBuilder {
+    field0: std::option::Option<i32>,
+    field1: std::option::Option<f32>,
+}
+
+impl Type2b0ad0aeBuilder {
+    /// Create a new builder without any fields set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the `0` field
+    pub fn field0(mut self, value: i32) -> Self {
+        self.field0 = Some(value);
+        self
+    }
+
+    /// Set the `1` field
+    pub fn field1(mut self, value: f32) -> Self {
+        self.field1 = Some(value);
+        self
+    }
+
+    /// Create a new [Type2b0ad0ae], returns `Error::MissingField` if any field hasn't been set
+    pub fn build<'a>(self, ctx: &'a Context) -> std::result::Result<Type2b0ad0ae<'a>, Error> {
+        let field0 = self.field0.ok_or(Error::MissingField("0"))?;
+        let field1 = self.field1.ok_or(Error::MissingField("1"))?;
+        Type2b0ad0ae::new(ctx, field0, field1)
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_f32_1d {
+    _private: [u8; 0]
+}
+
+/// Array type with 1 dimensions and f32 elements
+pub struct ArrayF32D1<'a> {
+    ptr: *mut futhark_f32_1d,
+    pub shape: [i64; 1],
+    ctx: &'a Context,
+}
+
+impl<'a> ArrayF32D1<'a> {
+    /// Create a new array of `dims` dimensions and initialize it with the values from `data`
+    pub fn new(ctx: &'a Context, dims: [i64; 1], data: impl AsRef<[f32]>) -> std::result::Result<Self, Error> {
+        let size: i64 = dims.iter().product();
+        let data = data.as_ref();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape)
+        }
+        let ptr = unsafe {
+            futhark_new_f32_1d(ctx.context, data.as_ptr(), dims[0])
+        };
+        if ptr.is_null() { return Err(Error::NullPtr); }
+        ctx.auto_sync();
+        Ok(Self {
+            ptr: ptr as *mut _,
+            shape: dims,
+            ctx,
+        })
+    }
+
+    /// Get the array shape
+    pub fn shape(&self) -> &[i64; 1] {
+        &self.shape
+    }
+
+    /// Load values back into a slice
+    pub fn values(&self, mut data: impl AsMut<[f32]>) -> std::result::Result<(), Error> {
+        let size: i64 = self.shape.iter().product();
+        let data = data.as_mut();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape);
+        }
+        let rc = unsafe {
+            futhark_values_f32_1d(self.ctx.context, self.ptr, data.as_mut_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::Code(rc));
+        }
+        self.ctx.auto_sync();
+        Ok(())
+    }
+
+    /// Load values into a `Vec`
+    pub fn get(&self) -> std::result::Result<Vec<f32>, Error> {
+        let size: i64 = self.shape.iter().product();
+        let mut vec = vec![f32::default(); size as usize];
+        self.values(&mut vec)?;
+        Ok(vec)
+    }
+
+
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, ptr: *mut futhark_f32_1d) -> Self {
+        let len_ptr = unsafe { futhark_shape_f32_1d(ctx.context, ptr) };
+        let mut shape = [0i64; 1];
+        unsafe {
+            for (i, s) in shape.iter_mut().enumerate() {
+                *s = *len_ptr.add(i);
+            }
+        }
+        Self { ctx, shape, ptr }
+    }
+}
+
+
+impl<'a> Drop for ArrayF32D1<'a> {
+    fn drop(&mut self){
+        unsafe {
+            futhark_free_f32_1d(self.ctx.context, self.ptr as *mut _);
+        }
+    }
+}
+
+#[allow(unused)]
+extern "C" {
+    fn futhark_shape_f32_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_f32_1d
+    ) -> *const i64;
+
+    fn futhark_new_f32_1d(
+        _: *mut futhark_context,
+        _: *const f32,
+        dim0: i64
+    ) -> *mut futhark_f32_1d;
+
+    fn futhark_free_f32_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_f32_1d
+    ) -> std::os::raw::c_int;
+
+    fn futhark_values_f32_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_f32_1d,
+        _: *mut f32
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> ArrayF32D1<'a> {
+    /// Get a single element of the array, the manifest doesn't include an `index` function so this
+    /// always returns `Error::Unsupported`
+    pub fn index(&self, _index: [i64; 1]) -> std::result::Result<f32, Error> {
+        Err(Error::Unsupported("index"))
+    }
+}
+
+impl<'a> ArrayF32D1<'a> {
+    /// Load values back into a slice, without blocking while the context is synced. The values
+    /// are copied into `data` once they are available on the host.
+    pub fn values_async<'b>(&self, data: &'b mut [f32]) -> impl std::future::Future<Output = std::result::Result<(), Error>> + Send + 'b {
+        let size: i64 = self.shape.iter().product();
+        let values = if data.len() as i64 != size { Err(Error::InvalidShape) } else { Ok(self.get_async()) };
+        async move {
+            data.copy_from_slice(&values?.await?);
+            Ok(())
+        }
+    }
+
+    /// Load values into a `Vec`, without blocking while the context is synced. If the future is
+    /// dropped early it blocks until the sync has finished, since the `Vec` is still being written.
+    pub fn get_async(&self) -> ValuesFuture<f32> {
+        let size: i64 = self.shape.iter().product();
+        let mut data = vec![f32::default(); size as usize];
+        let rc = unsafe {
+            futhark_values_f32_1d(self.ctx.context, self.ptr, data.as_mut_ptr())
+        };
+        ValuesFuture { rc, sync: Some(self.ctx.sync_async()), data }
+    }
+}
+
+// Arrays are only accessed through the context, which uses an internal lock
+unsafe impl Send for ArrayF32D1<'_> {}
+unsafe impl Sync for ArrayF32D1<'_> {}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_opaque_arr_point_1d {
+    _private: [u8; 0]
+}
+
+extern "C" {
+    fn futhark_free_opaque_arr_point_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_opaque_arr_point_1d
+    ) -> std::os::raw::c_int;
+}
+
+/// Futhark type
+pub struct Arr_point_1d<'a> {
+    data: *mut futhark_opaque_arr_point_1d,
+    ctx: &'a Context,
+}
+
+impl<'a> Arr_point_1d<'a> {
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, data: *mut futhark_opaque_arr_point_1d) -> Self {
+        Self { ctx, data }
+    }
+}
+
+impl<'a> Drop for Arr_point_1d<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            futhark_free_opaque_arr_point_1d(self.ctx.context, self.data);
+        }
+    }
+}
+
+// Opaque values are only accessed through the context, which uses an internal lock
+unsafe impl Send for Arr_point_1d<'_> {}
+unsafe impl Sync for Arr_point_1d<'_> {}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_opaque_number {
+    _private: [u8; 0]
+}
+
+extern "C" {
+    fn futhark_free_opaque_number(
+        _: *mut futhark_context,
+        _: *mut futhark_opaque_number
+    ) -> std::os::raw::c_int;
+}
+
+/// Futhark type
+pub struct Number<'a> {
+    data: *mut futhark_opaque_number,
+    ctx: &'a Context,
+}
+
+impl<'a> Number<'a> {
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, data: *mut futhark_opaque_number) -> Self {
+        Self { ctx, data }
+    }
+}
+
+impl<'a> Drop for Number<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            futhark_free_opaque_number(self.ctx.context, self.data);
+        }
+    }
+}
+
+// Opaque values are only accessed through the context, which uses an internal lock
+unsafe impl Send for Number<'_> {}
+unsafe impl Sync for Number<'_> {}
+
+impl<'a> Number<'a> {
+    /// Get x field
+    pub fn get_x(&self) -> Result<f32, Error> {
+        let mut out = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_project_opaque_number_x(
+                self.ctx.context,
+                out.as_mut_ptr(),
+                self.data
+            )
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+        self.ctx.auto_sync();
+        let out = unsafe { out.assume_init() };
+        Ok(out)
+    }
+}
+
+extern "C" {
+    fn futhark_project_opaque_number_x(
+        _: *mut futhark_context,
+        _: *mut f32,
+        _: *const futhark_opaque_number
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> Number<'a> {
+    /// Create new Number
+    pub fn new(ctx: &'a Context, fieldx: f32) -> std::result::Result<Self, Error> {
+        unsafe {
+            let mut out = std::ptr::null_mut();
+            let rc = futhark_new_opaque_number(ctx.context, &mut out, fieldx);
+            if rc != 0 { return Err(Error::Code(rc)); }
+            ctx.auto_sync();
+            Ok(Self { data: out, ctx })
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_new_opaque_number(
+        _: *mut futhark_context,
+        _: *mut *mut futhark_opaque_number,
+        fieldx: f32
+    ) -> std::os::raw::c_int;
+}
+/// Builder for [Number], all fields have to be set before calling `build`
+#[derive(Default)]
+pub struct NumberBuilder {
+    fieldx: std::option::Option<f32>,
+}
+
+impl NumberBuilder {
+    /// Create a new builder without any fields set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the `x` field
+    pub fn x(mut self, value: f32) -> Self {
+        self.fieldx = Some(value);
+        self
+    }
+
+    /// Create a new [Number], returns `Error::MissingField` if any field hasn't been set
+    pub fn build<'a>(self, ctx: &'a Context) -> std::result::Result<Number<'a>, Error> {
+        let fieldx = self.fieldx.ok_or(Error::MissingField("x"))?;
+        Number::new(ctx, fieldx)
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_opaque_option {
+    _private: [u8; 0]
+}
+
+extern "C" {
+    fn futhark_free_opaque_option(
+        _: *mut futhark_context,
+        _: *mut futhark_opaque_option
+    ) -> std::os::raw::c_int;
+}
+
+/// Futhark type
+pub struct Option<'a> {
+    data: *mut futhark_opaque_option,
+    ctx: &'a Context,
+}
+
+impl<'a> Option<'a> {
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, data: *mut futhark_opaque_option) -> Self {
+        Self { ctx, data }
+    }
+}
+
+impl<'a> Drop for Option<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            futhark_free_opaque_option(self.ctx.context, self.data);
+        }
+    }
+}
+
+// Opaque values are only accessed through the context, which uses an internal lock
+unsafe impl Send for Option<'_> {}
+unsafe impl Sync for Option<'_> {}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_opaque_point {
+    _private: [u8; 0]
+}
+
+extern "C" {
+    fn futhark_free_opaque_point(
+        _: *mut futhark_context,
+        _: *mut futhark_opaque_point
+    ) -> std::os::raw::c_int;
+}
+
+/// Futhark type
+pub struct Point<'a> {
+    data: *mut futhark_opaque_point,
+    ctx: &'a Context,
+}
+
+impl<'a> Point<'a> {
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, data: *mut futhark_opaque_point) -> Self {
+        Self { ctx, data }
+    }
+}
+
+impl<'a> Drop for Point<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            futhark_free_opaque_point(self.ctx.context, self.data);
+        }
+    }
+}
+
+// Opaque values are only accessed through the context, which uses an internal lock
+unsafe impl Send for Point<'_> {}
+unsafe impl Sync for Point<'_> {}
+
+impl<'a> Point<'a> {
+    /// Get x field
+    pub fn get_x(&self) -> Result<f32, Error> {
+        let mut out = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_project_opaque_point_x(
+                self.ctx.context,
+                out.as_mut_ptr(),
+                self.data
+            )
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+        self.ctx.auto_sync();
+        let out = unsafe { out.assume_init() };
+        Ok(out)
+    }
+}
+
+extern "C" {
+    fn futhark_project_opaque_point_x(
+        _: *mut futhark_context,
+        _: *mut f32,
+        _: *const futhark_opaque_point
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> Point<'a> {
+    /// Get y field
+    pub fn get_y(&self) -> Result<f32, Error> {
+        let mut out = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_project_opaque_point_y(
+                self.ctx.context,
+                out.as_mut_ptr(),
+                self.data
+            )
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+        self.ctx.auto_sync();
+        let out = unsafe { out.assume_init() };
+        Ok(out)
+    }
+}
+
+extern "C" {
+    fn futhark_project_opaque_point_y(
+        _: *mut futhark_context,
+        _: *mut f32,
+        _: *const futhark_opaque_point
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> Point<'a> {
+    /// Create new Point
+    pub fn new(ctx: &'a Context, fieldx: f32, fieldy: f32) -> std::result::Result<Self, Error> {
+        unsafe {
+            let mut out = std::ptr::null_mut();
+            let rc = futhark_new_opaque_point(ctx.context, &mut out, fieldx, fieldy);
+            if rc != 0 { return Err(Error::Code(rc)); }
+            ctx.auto_sync();
+            Ok(Self { data: out, ctx })
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_new_opaque_point(
+        _: *mut futhark_context,
+        _: *mut *mut futhark_opaque_point,
+        fieldx: f32, fieldy: f32
+    ) -> std::os::raw::c_int;
+}
+/// Builder for [Point], all fields have to be set before calling `build`
+#[derive(Default)]
+pub struct PointBuilder {
+    fieldx: std::option::Option<f32>,
+    fieldy: std::option::Option<f32>,
+}
+
+impl PointBuilder {
+    /// Create a new builder without any fields set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the `x` field
+    pub fn x(mut self, value: f32) -> Self {
+        self.fieldx = Some(value);
+        self
+    }
+
+    /// Set the `y` field
+    pub fn y(mut self, value: f32) -> Self {
+        self.fieldy = Some(value);
+        self
+    }
+
+    /// Create a new [Point], returns `Error::MissingField` if any field hasn't been set
+    pub fn build<'a>(self, ctx: &'a Context) -> std::result::Result<Point<'a>, Error> {
+        let fieldx = self.fieldx.ok_or(Error::MissingField("x"))?;
+        let fieldy = self.fieldy.ok_or(Error::MissingField("y"))?;
+        Point::new(ctx, fieldx, fieldy)
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_opaque_segment {
+    _private: [u8; 0]
+}
+
+extern "C" {
+    fn futhark_free_opaque_segment(
+        _: *mut futhark_context,
+        _: *mut futhark_opaque_segment
+    ) -> std::os::raw::c_int;
+}
+
+/// Futhark type
+pub struct Segment<'a> {
+    data: *mut futhark_opaque_segment,
+    ctx: &'a Context,
+}
+
+impl<'a> Segment<'a> {
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, data: *mut futhark_opaque_segment) -> Self {
+        Self { ctx, data }
+    }
+}
+
+impl<'a> Drop for Segment<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            futhark_free_opaque_segment(self.ctx.context, self.data);
+        }
+    }
+}
+
+// Opaque values are only accessed through the context, which uses an internal lock
+unsafe impl Send for Segment<'_> {}
+unsafe impl Sync for Segment<'_> {}
+
+impl<'a> Segment<'a> {
+    /// Get start field
+    pub fn get_start(&self) -> Result<Point<'_>, Error> {
+        let mut out = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_project_opaque_segment_start(
+                self.ctx.context,
+                out.as_mut_ptr(),
+                self.data
+            )
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+        self.ctx.auto_sync();
+        let out = unsafe { out.assume_init() };
+        Ok(Point::from_ptr(self.ctx, out))
+    }
+}
+
+extern "C" {
+    fn futhark_project_opaque_segment_start(
+        _: *mut futhark_context,
+        _: *mut *mut futhark_opaque_point,
+        _: *const futhark_opaque_segment
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> Segment<'a> {
+    /// Get end field
+    pub fn get_end(&self) -> Result<Point<'_>, Error> {
+        let mut out = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_project_opaque_segment_end(
+                self.ctx.context,
+                out.as_mut_ptr(),
+                self.data
+            )
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+        self.ctx.auto_sync();
+        let out = unsafe { out.assume_init() };
+        Ok(Point::from_ptr(self.ctx, out))
+    }
+}
+
+extern "C" {
+    fn futhark_project_opaque_segment_end(
+        _: *mut futhark_context,
+        _: *mut *mut futhark_opaque_point,
+        _: *const futhark_opaque_segment
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> Segment<'a> {
+    /// Create new Segment
+    pub fn new(ctx: &'a Context, fieldstart: &Point, fieldend: &Point) -> std::result::Result<Self, Error> {
+        unsafe {
+            let mut out = std::ptr::null_mut();
+            let rc = futhark_new_opaque_segment(ctx.context, &mut out, fieldstart.data, fieldend.data);
+            if rc != 0 { return Err(Error::Code(rc)); }
+            ctx.auto_sync();
+            Ok(Self { data: out, ctx })
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_new_opaque_segment(
+        _: *mut futhark_context,
+        _: *mut *mut futhark_opaque_segment,
+        fieldstart: *const futhark_opaque_point, fieldend: *const futhark_opaque_point
+    ) -> std::os::raw::c_int;
+}
+/// Builder for [Segment], all fields have to be set before calling `build`
+#[derive(Default)]
+pub struct SegmentBuilder<'b> {
+    fieldstart: std::option::Option<&'b Point<'b>>,
+    fieldend: std::option::Option<&'b Point<'b>>,
+}
+
+impl<'b> SegmentBuilder<'b> {
+    /// Create a new builder without any fields set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the `start` field
+    pub fn start(mut self, value: &'b Point<'b>) -> Self {
+        self.fieldstart = Some(value);
+        self
+    }
+
+    /// Set the `end` field
+    pub fn end(mut self, value: &'b Point<'b>) -> Self {
+        self.fieldend = Some(value);
+        self
+    }
+
+    /// Create a new [Segment], returns `Error::MissingField` if any field hasn't been set
+    pub fn build<'a>(self, ctx: &'a Context) -> std::result::Result<Segment<'a>, Error> {
+        let fieldstart = self.fieldstart.ok_or(Error::MissingField("start"))?;
+        let fieldend = self.fieldend.ok_or(Error::MissingField("end"))?;
+        Segment::new(ctx, fieldstart, fieldend)
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_opaque_tup {
+    _private: [u8; 0]
+}
+
+extern "C" {
+    fn futhark_free_opaque_tup(
+        _: *mut futhark_context,
+        _: *mut futhark_opaque_tup
+    ) -> std::os::raw::c_int;
+}
+
+/// Futhark type
+pub struct Tup<'a> {
+    data: *mut futhark_opaque_tup,
+    ctx: &'a Context,
+}
+
+impl<'a> Tup<'a> {
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, data: *mut futhark_opaque_tup) -> Self {
+        Self { ctx, data }
+    }
+}
+
+impl<'a> Drop for Tup<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            futhark_free_opaque_tup(self.ctx.context, self.data);
+        }
+    }
+}
+
+// Opaque values are only accessed through the context, which uses an internal lock
+unsafe impl Send for Tup<'_> {}
+unsafe impl Sync for Tup<'_> {}
+
+impl<'a> Tup<'a> {
+    /// Get 0 field
+    pub fn get_0(&self) -> Result<Number<'_>, Error> {
+        let mut out = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_project_opaque_tup_0(
+                self.ctx.context,
+                out.as_mut_ptr(),
+                self.data
+            )
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+        self.ctx.auto_sync();
+        let out = unsafe { out.assume_init() };
+        Ok(Number::from_ptr(self.ctx, out))
+    }
+}
+
+extern "C" {
+    fn futhark_project_opaque_tup_0(
+        _: *mut futhark_context,
+        _: *mut *mut futhark_opaque_number,
+        _: *const futhark_opaque_tup
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> Tup<'a> {
+    /// Get 1 field
+    pub fn get_1(&self) -> Result<ArrayF32D1<'_>, Error> {
+        let mut out = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_project_opaque_tup_1(
+                self.ctx.context,
+                out.as_mut_ptr(),
+                self.data
+            )
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+        self.ctx.auto_sync();
+        let out = unsafe { out.assume_init() };
+        Ok(ArrayF32D1::from_ptr(self.ctx, out))
+    }
+}
+
+extern "C" {
+    fn futhark_project_opaque_tup_1(
+        _: *mut futhark_context,
+        _: *mut *mut futhark_f32_1d,
+        _: *const futhark_opaque_tup
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> Tup<'a> {
+    /// Create new Tup
+    pub fn new(ctx: &'a Context, field0: &Number, field1: &ArrayF32D1) -> std::result::Result<Self, Error> {
+        unsafe {
+            let mut out = std::ptr::null_mut();
+            let rc = futhark_new_opaque_tup(ctx.context, &mut out, field0.data, field1.ptr);
+            if rc != 0 { return Err(Error::Code(rc)); }
+            ctx.auto_sync();
+            Ok(Self { data: out, ctx })
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_new_opaque_tup(
+        _: *mut futhark_context,
+        _: *mut *mut futhark_opaque_tup,
+        field0: *const futhark_opaque_number, field1: *const futhark_f32_1d
+    ) -> std::os::raw::c_int;
+}
+/// Builder for [Tup], all fields have to be set before calling `build`
+#[derive(Default)]
+pub struct TupBuilder<'b> {
+    field0: std::option::Option<&'b Number<'b>>,
+    field1: std::option::Option<&'b ArrayF32D1<'b>>,
+}
+
+impl<'b> TupBuilder<'b> {
+    /// Create a new builder without any fields set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the `0` field
+    pub fn field0(mut self, value: &'b Number<'b>) -> Self {
+        self.field0 = Some(value);
+        self
+    }
+
+    /// Set the `1` field
+    pub fn field1(mut self, value: &'b ArrayF32D1<'b>) -> Self {
+        self.field1 = Some(value);
+        self
+    }
+
+    /// Create a new [Tup], returns `Error::MissingField` if any field hasn't been set
+    pub fn build<'a>(self, ctx: &'a Context) -> std::result::Result<Tup<'a>, Error> {
+        let field0 = self.field0.ok_or(Error::MissingField("0"))?;
+        let field1 = self.field1.ok_or(Error::MissingField("1"))?;
+        Tup::new(ctx, field0, field1)
+    }
+}
+
+impl Context {
+    /// Entry point: distance
+    pub fn distance(&self, input0: &Point, input1: &Point) -> Result<f32, Error> {
+        let mut out0 = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_entry_distance(self.context, out0.as_mut_ptr(), input0.data as *mut _, input1.data as *mut _)
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+    
+        #[allow(unused_unsafe)]
+        unsafe {
+            Ok(out0.assume_init())
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_entry_distance(
+        _: *mut futhark_context,
+        out0: *mut f32, input0: *const futhark_opaque_point, input1: *const futhark_opaque_point
+    ) -> std::os::raw::c_int;
+}
+impl Context {
+    /// Entry point: distance, the result is returned once the context has been synced
+    /// without blocking the async runtime. The returned future is `Send`, the inputs are only
+    /// used before it is created.
+    pub fn distance_async(&self, input0: &Point<'_>, input1: &Point<'_>) -> impl std::future::Future<Output = std::result::Result<f32, Error>> + Send + '_ {
+        let out = self.distance(input0, input1).map(|out| (out, self.sync_async()));
+        async move {
+            let (out, sync) = out?;
+            sync.await?;
+            Ok(out)
+        }
+    }
+}
+
+impl Context {
+    /// Entry point: length
+    pub fn length(&self, input0: &Segment) -> Result<f32, Error> {
+        let mut out0 = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_entry_length(self.context, out0.as_mut_ptr(), input0.data as *mut _)
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+    
+        #[allow(unused_unsafe)]
+        unsafe {
+            Ok(out0.assume_init())
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_entry_length(
+        _: *mut futhark_context,
+        out0: *mut f32, input0: *const futhark_opaque_segment
+    ) -> std::os::raw::c_int;
+}
+impl Context {
+    /// Entry point: length, the result is returned once the context has been synced
+    /// without blocking the async runtime. The returned future is `Send`, the inputs are only
+    /// used before it is created.
+    pub fn length_async(&self, input0: &Segment<'_>) -> impl std::future::Future<Output = std::result::Result<f32, Error>> + Send + '_ {
+        let out = self.length(input0).map(|out| (out, self.sync_async()));
+        async move {
+            let (out, sync) = out?;
+            sync.await?;
+            Ok(out)
+        }
+    }
+}
+
+impl Context {
+    /// Entry point: points
+    pub fn points(&self, input0: &ArrayF32D1, input1: &ArrayF32D1) -> Result<Arr_point_1d<'_>, Error> {
+        let mut out0 = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_entry_points(self.context, out0.as_mut_ptr(), input0.ptr as *mut _, input1.ptr as *mut _)
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+    
+        #[allow(unused_unsafe)]
+        unsafe {
+            Ok(Arr_point_1d::from_ptr(self, out0.assume_init()))
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_entry_points(
+        _: *mut futhark_context,
+        out0: *mut *mut futhark_opaque_arr_point_1d, input0: *const futhark_f32_1d, input1: *const futhark_f32_1d
+    ) -> std::os::raw::c_int;
+}
+impl Context {
+    /// Entry point: points, the result is returned once the context has been synced
+    /// without blocking the async runtime. The returned future is `Send`, the inputs are only
+    /// used before it is created.
+    pub fn points_async(&self, input0: &ArrayF32D1<'_>, input1: &ArrayF32D1<'_>) -> impl std::future::Future<Output = std::result::Result<Arr_point_1d<'_>, Error>> + Send + '_ {
+        let out = self.points(input0, input1).map(|out| (out, self.sync_async()));
+        async move {
+            let (out, sync) = out?;
+            sync.await?;
+            Ok(out)
+        }
+    }
+}
+
+impl Context {
+    /// Entry point: return_option
+    pub fn return_option(&self, input0: &Option) -> Result<Option<'_>, Error> {
+        let mut out0 = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_entry_return_option(self.context, out0.as_mut_ptr(), input0.data as *mut _)
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+    
+        #[allow(unused_unsafe)]
+        unsafe {
+            Ok(Option::from_ptr(self, out0.assume_init()))
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_entry_return_option(
+        _: *mut futhark_context,
+        out0: *mut *mut futhark_opaque_option, input0: *const futhark_opaque_option
+    ) -> std::os::raw::c_int;
+}
+impl Context {
+    /// Entry point: return_option, the result is returned once the context has been synced
+    /// without blocking the async runtime. The returned future is `Send`, the inputs are only
+    /// used before it is created.
+    pub fn return_option_async(&self, input0: &Option<'_>) -> impl std::future::Future<Output = std::result::Result<Option<'_>, Error>> + Send + '_ {
+        let out = self.return_option(input0).map(|out| (out, self.sync_async()));
+        async move {
+            let (out, sync) = out?;
+            sync.await?;
+            Ok(out)
+        }
+    }
+}
+
+impl Context {
+    /// Entry point: swap
+    pub fn swap(&self, input0: &Type2b0ad0ae) -> Result<(f32, i32), Error> {
+        let mut out0 = std::mem::MaybeUninit::zeroed();
+        let mut out1 = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_entry_swap(self.context, out0.as_mut_ptr(), out1.as_mut_ptr(), input0.data as *mut _)
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+    
+        #[allow(unused_unsafe)]
+        unsafe {
+            Ok((out0.assume_init(), out1.assume_init()))
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_entry_swap(
+        _: *mut futhark_context,
+        out0: *mut f32, out1: *mut i32, input0: *const futhark_opaque_2b0ad0ae
+    ) -> std::os::raw::c_int;
+}
+impl Context {
+    /// Entry point: swap, the result is returned once the context has been synced
+    /// without blocking the async runtime. The returned future is `Send`, the inputs are only
+    /// used before it is created.
+    pub fn swap_async(&self, input0: &Type2b0ad0ae<'_>) -> impl std::future::Future<Output = std::result::Result<(f32, i32), Error>> + Send + '_ {
+        let out = self.swap(input0).map(|out| (out, self.sync_async()));
+        async move {
+            let (out, sync) = out?;
+            sync.await?;
+            Ok(out)
+        }
+    }
+}
+
+impl Context {
+    /// Entry point: tup_mul
+    pub fn tup_mul(&self, input0: &Tup) -> Result<ArrayF32D1<'_>, Error> {
+        let mut out0 = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_entry_tup_mul(self.context, out0.as_mut_ptr(), input0.data as *mut _)
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+    
+        #[allow(unused_unsafe)]
+        unsafe {
+            Ok(ArrayF32D1::from_ptr(self, out0.assume_init()))
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_entry_tup_mul(
+        _: *mut futhark_context,
+        out0: *mut *mut futhark_f32_1d, input0: *const futhark_opaque_tup
+    ) -> std::os::raw::c_int;
+}
+impl Context {
+    /// Entry point: tup_mul, the result is returned once the context has been synced
+    /// without blocking the async runtime. The returned future is `Send`, the inputs are only
+    /// used before it is created.
+    pub fn tup_mul_async(&self, input0: &Tup<'_>) -> impl std::future::Future<Output = std::result::Result<ArrayF32D1<'_>, Error>> + Send + '_ {
+        let out = self.tup_mul(input0).map(|out| (out, self.sync_async()));
+        async move {
+            let (out, sync) = out?;
+            sync.await?;
+            Ok(out)
+        }
+    }
+}
+
+impl Context {
+    /// Entry point: unzip_points
+    pub fn unzip_points(&self, input0: &Arr_point_1d) -> Result<(ArrayF32D1<'_>, ArrayF32D1<'_>), Error> {
+        let mut out0 = std::mem::MaybeUninit::zeroed();
+        let mut out1 = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_entry_unzip_points(self.context, out0.as_mut_ptr(), out1.as_mut_ptr(), input0.data as *mut _)
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+    
+        #[allow(unused_unsafe)]
+        unsafe {
+            Ok((ArrayF32D1::from_ptr(self, out0.assume_init()), ArrayF32D1::from_ptr(self, out1.assume_init())))
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_entry_unzip_points(
+        _: *mut futhark_context,
+        out0: *mut *mut futhark_f32_1d, out1: *mut *mut futhark_f32_1d, input0: *const futhark_opaque_arr_point_1d
+    ) -> std::os::raw::c_int;
+}
+impl Context {
+    /// Entry point: unzip_points, the result is returned once the context has been synced
+    /// without blocking the async runtime. The returned future is `Send`, the inputs are only
+    /// used before it is created.
+    pub fn unzip_points_async(&self, input0: &Arr_point_1d<'_>) -> impl std::future::Future<Output = std::result::Result<(ArrayF32D1<'_>, ArrayF32D1<'_>), Error>> + Send + '_ {
+        let out = self.unzip_points(input0).map(|out| (out, self.sync_async()));
+        async move {
+            let (out, sync) = out?;
+            sync.await?;
+            Ok(out)
+        }
+    }
+}
+
@@ -0,0 +1,276 @@
+// Generated by futhark-bindgen
+
+#ifndef RECORDS_HELPERS_H
+#define RECORDS_HELPERS_H
+
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "records.h"
+
+// Error codes for failures that aren't reported by Futhark
+#define RECORDS_HELPERS_ERR_NULL_PTR (-1)
+#define RECORDS_HELPERS_ERR_ALLOC (-2)
+
+// Error reported by the helper functions, `code` is zero on success
+typedef struct records_helpers_error {
+  int code;
+  // Error message, freed by `records_helpers_error_free`
+  char *message;
+} records_helpers_error;
+
+// Free the message stored in `err` and reset it
+static inline void records_helpers_error_free(records_helpers_error *err) {
+  if (err == NULL) {
+    return;
+  }
+  free(err->message);
+  err->message = NULL;
+  err->code = 0;
+}
+
+static inline char *records_helpers_copy_string(const char *s) {
+  size_t n = strlen(s) + 1;
+  char *copy = (char *)malloc(n);
+  if (copy != NULL) {
+    memcpy(copy, s, n);
+  }
+  return copy;
+}
+
+// Store `code` in `err` and return it, Futhark errors take the message from the context
+static inline int records_helpers_set_error(struct futhark_context *ctx, records_helpers_error *err, int code) {
+  if (err == NULL) {
+    return code;
+  }
+  free(err->message);
+  err->code = code;
+  switch (code) {
+  case RECORDS_HELPERS_ERR_NULL_PTR:
+    err->message = records_helpers_copy_string("NULL pointer encountered");
+    break;
+  case RECORDS_HELPERS_ERR_ALLOC:
+    err->message = records_helpers_copy_string("Unable to allocate host memory");
+    break;
+  default:
+    err->message = futhark_context_get_error(ctx);
+    break;
+  }
+  return code;
+}
+
+// Copy a `[]f32` array into a new host buffer, which must be freed using `free`
+static inline int records_helpers_copy_f32_1d(struct futhark_context *ctx, records_helpers_error *err,
+    struct futhark_f32_1d *arr, float **data, int64_t shape[1]) {
+  const int64_t *dims = futhark_shape_f32_1d(ctx, arr);
+  int64_t size = 1;
+  for (int i = 0; i < 1; i++) {
+    shape[i] = dims[i];
+    size *= dims[i];
+  }
+  // Allocate at least one element, `malloc(0)` may return NULL
+  *data = (float *)malloc((size > 0 ? (size_t)size : 1) * sizeof(float));
+  if (*data == NULL) {
+    return records_helpers_set_error(ctx, err, RECORDS_HELPERS_ERR_ALLOC);
+  }
+  int rc = futhark_values_f32_1d(ctx, arr, *data);
+  if (rc == 0) {
+    rc = futhark_context_sync(ctx);
+  }
+  if (rc != 0) {
+    free(*data);
+    *data = NULL;
+    return records_helpers_set_error(ctx, err, rc);
+  }
+  return 0;
+}
+
+// Run the `distance` entry point and wait for it to finish, array outputs are copied into new
+// host buffers which must be freed using `free`. On failure the outputs are set to NULL and
+// the error is stored in `err`
+static inline int records_helpers_distance(struct futhark_context *ctx, records_helpers_error *err, float *out0, const struct futhark_opaque_point *in0, const struct futhark_opaque_point *in1) {
+  int rc = 0;
+  rc = futhark_entry_distance(ctx, out0, in0, in1);
+  if (rc == 0) {
+    rc = futhark_context_sync(ctx);
+  }
+  if (rc != 0) {
+    rc = records_helpers_set_error(ctx, err, rc);
+    goto done;
+  }
+done:
+  return rc;
+}
+
+// Run the `length` entry point and wait for it to finish, array outputs are copied into new
+// host buffers which must be freed using `free`. On failure the outputs are set to NULL and
+// the error is stored in `err`
+static inline int records_helpers_length(struct futhark_context *ctx, records_helpers_error *err, float *out0, const struct futhark_opaque_segment *in0) {
+  int rc = 0;
+  rc = futhark_entry_length(ctx, out0, in0);
+  if (rc == 0) {
+    rc = futhark_context_sync(ctx);
+  }
+  if (rc != 0) {
+    rc = records_helpers_set_error(ctx, err, rc);
+    goto done;
+  }
+done:
+  return rc;
+}
+
+// Run the `points` entry point and wait for it to finish, array outputs are copied into new
+// host buffers which must be freed using `free`. On failure the outputs are set to NULL and
+// the error is stored in `err`
+static inline int records_helpers_points(struct futhark_context *ctx, records_helpers_error *err, struct futhark_opaque_arr_point_1d **out0, const float *in0, const int64_t in0_shape[1], const float *in1, const int64_t in1_shape[1]) {
+  int rc = 0;
+  *out0 = NULL;
+  struct futhark_f32_1d *in0_arr = NULL;
+  struct futhark_f32_1d *in1_arr = NULL;
+  in0_arr = futhark_new_f32_1d(ctx, in0, in0_shape[0]);
+  if (in0_arr == NULL) {
+    rc = records_helpers_set_error(ctx, err, RECORDS_HELPERS_ERR_NULL_PTR);
+    goto done;
+  }
+  in1_arr = futhark_new_f32_1d(ctx, in1, in1_shape[0]);
+  if (in1_arr == NULL) {
+    rc = records_helpers_set_error(ctx, err, RECORDS_HELPERS_ERR_NULL_PTR);
+    goto done;
+  }
+  rc = futhark_entry_points(ctx, out0, in0_arr, in1_arr);
+  if (rc == 0) {
+    rc = futhark_context_sync(ctx);
+  }
+  if (rc != 0) {
+    rc = records_helpers_set_error(ctx, err, rc);
+    goto done;
+  }
+done:
+  if (rc != 0 && *out0 != NULL) {
+    futhark_free_opaque_arr_point_1d(ctx, *out0);
+    *out0 = NULL;
+  }
+  if (in0_arr != NULL) {
+    futhark_free_f32_1d(ctx, in0_arr);
+  }
+  if (in1_arr != NULL) {
+    futhark_free_f32_1d(ctx, in1_arr);
+  }
+  return rc;
+}
+
+// Run the `return_option` entry point and wait for it to finish, array outputs are copied into new
+// host buffers which must be freed using `free`. On failure the outputs are set to NULL and
+// the error is stored in `err`
+static inline int records_helpers_return_option(struct futhark_context *ctx, records_helpers_error *err, struct futhark_opaque_option **out0, const struct futhark_opaque_option *in0) {
+  int rc = 0;
+  *out0 = NULL;
+  rc = futhark_entry_return_option(ctx, out0, in0);
+  if (rc == 0) {
+    rc = futhark_context_sync(ctx);
+  }
+  if (rc != 0) {
+    rc = records_helpers_set_error(ctx, err, rc);
+    goto done;
+  }
+done:
+  if (rc != 0 && *out0 != NULL) {
+    futhark_free_opaque_option(ctx, *out0);
+    *out0 = NULL;
+  }
+  return rc;
+}
+
+// Run the `swap` entry point and wait for it to finish, array outputs are copied into new
+// host buffers which must be freed using `free`. On failure the outputs are set to NULL and
+// the error is stored in `err`
+static inline int records_helpers_swap(struct futhark_context *ctx, records_helpers_error *err, float *out0, int32_t *out1, const struct futhark_opaque_2b0ad0ae *in0) {
+  int rc = 0;
+  rc = futhark_entry_swap(ctx, out0, out1, in0);
+  if (rc == 0) {
+    rc = futhark_context_sync(ctx);
+  }
+  if (rc != 0) {
+    rc = records_helpers_set_error(ctx, err, rc);
+    goto done;
+  }
+done:
+  return rc;
+}
+
+// Run the `tup_mul` entry point and wait for it to finish, array outputs are copied into new
+// host buffers which must be freed using `free`. On failure the outputs are set to NULL and
+// the error is stored in `err`
+static inline int records_helpers_tup_mul(struct futhark_context *ctx, records_helpers_error *err, float **out0, int64_t out0_shape[1], const struct futhark_opaque_tup *in0) {
+  int rc = 0;
+  struct futhark_f32_1d *out0_arr = NULL;
+  *out0 = NULL;
+  rc = futhark_entry_tup_mul(ctx, &out0_arr, in0);
+  if (rc == 0) {
+    rc = futhark_context_sync(ctx);
+  }
+  if (rc != 0) {
+    rc = records_helpers_set_error(ctx, err, rc);
+    goto done;
+  }
+  rc = records_helpers_copy_f32_1d(ctx, err, out0_arr, out0, out0_shape);
+  if (rc != 0) {
+    goto done;
+  }
+done:
+  if (out0_arr != NULL) {
+    futhark_free_f32_1d(ctx, out0_arr);
+  }
+  if (rc != 0) {
+    free(*out0);
+    *out0 = NULL;
+  }
+  return rc;
+}
+
+// Run the `unzip_points` entry point and wait for it to finish, array outputs are copied into new
+// host buffers which must be freed using `free`. On failure the outputs are set to NULL and
+// the error is stored in `err`
+static inline int records_helpers_unzip_points(struct futhark_context *ctx, records_helpers_error *err, float **out0, int64_t out0_shape[1], float **out1, int64_t out1_shape[1], const struct futhark_opaque_arr_point_1d *in0) {
+  int rc = 0;
+  struct futhark_f32_1d *out0_arr = NULL;
+  *out0 = NULL;
+  struct futhark_f32_1d *out1_arr = NULL;
+  *out1 = NULL;
+  rc = futhark_entry_unzip_points(ctx, &out0_arr, &out1_arr, in0);
+  if (rc == 0) {
+    rc = futhark_context_sync(ctx);
+  }
+  if (rc != 0) {
+    rc = records_helpers_set_error(ctx, err, rc);
+    goto done;
+  }
+  rc = records_helpers_copy_f32_1d(ctx, err, out0_arr, out0, out0_shape);
+  if (rc != 0) {
+    goto done;
+  }
+  rc = records_helpers_copy_f32_1d(ctx, err, out1_arr, out1, out1_shape);
+  if (rc != 0) {
+    goto done;
+  }
+done:
+  if (out0_arr != NULL) {
+    futhark_free_f32_1d(ctx, out0_arr);
+  }
+  if (out1_arr != NULL) {
+    futhark_free_f32_1d(ctx, out1_arr);
+  }
+  if (rc != 0) {
+    free(*out0);
+    *out0 = NULL;
+  }
+  if (rc != 0) {
+    free(*out1);
+    *out1 = NULL;
+  }
+  return rc;
+}
+
+#endif // RECORDS_HELPERS_H
@@ -0,0 +1,199 @@
+(* Generated by futhark-bindgen *)
+
+open! Signed
+open! Unsigned
+
+type error = 
+  | InvalidShape of int * int 
+  | NullPtr 
+  | Code of int
+  | UseAfterFree of [`context | `array | `opaque]
+  | IndexOutOfBounds
+  | Unsupported of string
+  | InvalidTuning of string
+
+exception Error of error
+
+module Context: sig
+  type t
+  (** Futhark context *)
+
+  val v: ?debug:bool -> ?log:bool -> ?profile:bool -> ?cache_file:string -> ?auto_sync:bool -> ?tuning_params:(string * int) list -> ?tuning_file:string -> ?device:string -> ?platform:string -> ?default_group_size:int -> ?default_tile_size:int -> unit -> t
+  (** Create a new context, [tuning_params] are applied after the parameters loaded from [tuning_file] *)
+  
+  val sync: t -> unit
+  (** Sync the context, if auto_sync is enabled this is not needed *)
+  
+  val free: t -> unit
+  (** Free the context *)
+  
+  val clear_caches: t -> unit
+  (** Clear Futhark caches *)
+
+  val get_error: t -> string option
+  (** Get last error message or None *)
+
+  val report: t -> string option
+  val pause_profiling: t -> unit
+  val unpause_profiling: t -> unit
+end
+module FutType2b0ad0ae : sig
+  type t
+  (** Futhark type *)
+  
+  val free: t -> unit
+  (** Free function *)
+
+  val v: Context.t -> int32 -> float -> t
+
+
+  val get_0: t -> int32
+  (** Get field: 0 *)
+
+  val get_1: t -> float
+  (** Get field: 1 *)
+end
+
+module FutArrayF32D1: sig
+  type t
+  (** Futhark array *)
+
+  type kind = (float, Bigarray.float32_elt) Bigarray.kind
+  (** The Bigarray kind that matches the correct element type for this array *)
+
+  val kind: kind
+
+  val shape: t -> int array
+  (** Array shape *)
+
+  val v: Context.t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Genarray.t -> t
+  (** Initialize an array with the data from the provided bigarray *)
+
+  val values: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Genarray.t -> unit
+  (** Load the values into the provided bigarray *)
+
+  val values_array1: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Array1.t -> unit
+  (** Similar to [values] but takes an [Array1] instead of [Genarray] *)
+
+  val get: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Genarray.t
+  (** Get a new bigarray with the values loaded *)
+
+  val get_array1: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Array1.t
+  (** Similar to [get] but returns an [Array1] *)
+
+  val index: t -> int array -> float
+  (** Get a single element of the array without copying the whole array, raises
+      [Error (Unsupported "index")] if the manifest doesn't include an [index] function *)
+
+  val of_array: Context.t -> int array -> (float) array -> t
+  (** Create [t] from an array of values *)
+
+  val of_array1: Context.t -> int array ->  (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Array1.t-> t
+  (** Create [t] from an [Array1] instead of [Genarray] *)
+
+  val free: t -> unit
+  (** Free the array *)
+end
+
+module FutArrPoint1d : sig
+  type t
+  (** Futhark type *)
+  
+  val free: t -> unit
+  (** Free function *)
+
+end
+module FutNumber : sig
+  type t
+  (** Futhark type *)
+  
+  val free: t -> unit
+  (** Free function *)
+
+  val v: Context.t -> float -> t
+
+
+  val get_x: t -> float
+  (** Get field: x *)
+end
+
+module FutOption : sig
+  type t
+  (** Futhark type *)
+  
+  val free: t -> unit
+  (** Free function *)
+
+end
+module FutPoint : sig
+  type t
+  (** Futhark type *)
+  
+  val free: t -> unit
+  (** Free function *)
+
+  val v: Context.t -> float -> float -> t
+
+
+  val get_x: t -> float
+  (** Get field: x *)
+
+  val get_y: t -> float
+  (** Get field: y *)
+end
+
+module FutSegment : sig
+  type t
+  (** Futhark type *)
+  
+  val free: t -> unit
+  (** Free function *)
+
+  val v: Context.t -> FutPoint.t -> FutPoint.t -> t
+
+
+  val get_start: t -> FutPoint.t
+  (** Get field: start *)
+
+  val get_end: t -> FutPoint.t
+  (** Get field: end *)
+end
+
+module PairWithScale : sig
+  type t
+  (** Futhark type *)
+  
+  val free: t -> unit
+  (** Free function *)
+
+  val v: Context.t -> FutNumber.t -> FutArrayF32D1.t -> t
+
+
+  val get_0: t -> FutNumber.t
+  (** Get field: 0 *)
+
+  val get_1: t -> FutArrayF32D1.t
+  (** Get field: 1 *)
+end
+
+(** Entry point: dist *)
+val dist: Context.t -> FutPoint.t -> FutPoint.t -> (float)
+
+(** Entry point: length *)
+val length: Context.t -> FutSegment.t -> (float)
+
+(** Entry point: points *)
+val points: Context.t -> FutArrayF32D1.t -> FutArrayF32D1.t -> (FutArrPoint1d.t)
+
+(** Entry point: return_option *)
+val return_option: Context.t -> FutOption.t -> (FutOption.t)
+
+(** Entry point: swap *)
+val swap: Context.t -> FutType2b0ad0ae.t -> (float * int32)
+
+(** Entry point: tup_mul *)
+val tup_mul: Context.t -> PairWithScale.t -> (FutArrayF32D1.t)
+
+(** Entry point: unzip_points *)
+val unzip_points: Context.t -> FutArrPoint1d.t -> (FutArrayF32D1.t * FutArrayF32D1.t)
+
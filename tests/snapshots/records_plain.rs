@@ -0,0 +1,1420 @@
+// Generated by futhark-bindgen
+
+#[derive(Debug)]
+pub enum Error {
+    Code(std::os::raw::c_int),
+    NullPtr,
+    InvalidShape,
+    IndexOutOfBounds,
+    Unsupported(&'static str),
+    MissingField(&'static str),
+    InvalidTuning(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::Code(code) => write!(fmt, "Futhark error code: {code}"),
+            Error::NullPtr => write!(fmt, "NULL pointer encountered"),
+            Error::InvalidShape => write!(fmt, "Invalid image shape"),
+            Error::IndexOutOfBounds => write!(fmt, "Index out of bounds"),
+            Error::Unsupported(op) => write!(fmt, "{op} is not supported by this version of Futhark"),
+            Error::MissingField(field) => write!(fmt, "Missing field: {field}"),
+            Error::InvalidTuning(msg) => write!(fmt, "Invalid tuning parameter: {msg}"),
+        }
+    } 
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Debug, Clone)]
+pub struct Options {
+    debug: bool,
+    profile: bool,
+    logging: bool,
+    cache_file: std::option::Option<std::ffi::CString>,
+    device: std::option::Option<std::ffi::CString>,
+    platform: std::option::Option<std::ffi::CString>,
+    default_group_size: std::option::Option<u32>,
+    default_tile_size: std::option::Option<u32>,
+    tuning_params: Vec<(std::ffi::CString, usize)>,
+    tuning_file: std::option::Option<std::path::PathBuf>,
+    auto_sync: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options::new()
+    }
+}
+
+impl Options {
+    /// Create new `Options` with default settings
+    pub fn new() -> Self {
+        Options {
+            debug: false,
+            profile: false,
+            logging: false,
+            cache_file: None,
+            device: None,
+            platform: None,
+            default_group_size: None,
+            default_tile_size: None,
+            tuning_params: Vec::new(),
+            tuning_file: None,
+            auto_sync: true,
+        }
+    }
+
+    /// Enable debug
+    pub fn debug(mut self) -> Self {
+        self.debug = true;
+        self
+    }
+
+    /// Enable profiling
+    pub fn profile(mut self) -> Self {
+        self.profile = true;
+        self
+    }
+
+    /// Enable logging
+    pub fn log(mut self) -> Self {
+        self.logging = true;
+        self
+    }
+
+    /// Set Futhark cache file
+    pub fn cache_file(mut self, s: impl AsRef<str>) -> Self {
+        self.cache_file = Some(std::ffi::CString::new(s.as_ref()).expect("Invalid cache file"));
+        self
+    }
+
+    pub fn auto_sync(mut self, sync: bool) -> Self {
+        self.auto_sync = sync;
+        self
+    }
+
+    /// Set a tuning parameter, these are applied after the parameters from the tuning file
+    pub fn tuning_param(mut self, name: impl AsRef<str>, value: usize) -> Self {
+        let name = std::ffi::CString::new(name.as_ref()).expect("Invalid tuning parameter");
+        self.tuning_params.push((name, value));
+        self
+    }
+
+    /// Load tuning parameters from a `.tuning` file when the context is created
+    pub fn tuning_file(mut self, path: impl AsRef<std::path::Path>) -> Self {
+        self.tuning_file = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    // Read a tuning file, every line has the form `name=value`
+    fn read_tuning_file(path: &std::path::Path) -> std::result::Result<Vec<(std::ffi::CString, usize)>, Error> {
+        let s = std::fs::read_to_string(path)
+            .map_err(|e| Error::InvalidTuning(format!("{}: {e}", path.display())))?;
+        s.lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let invalid = || Error::InvalidTuning(line.to_string());
+                let (name, value) = line.split_once('=').ok_or_else(invalid)?;
+                let value = value.trim().parse().map_err(|_| invalid())?;
+                let name = std::ffi::CString::new(name.trim()).map_err(|_| invalid())?;
+                Ok((name, value))
+            })
+            .collect()
+    }
+
+
+    pub fn device(mut self, s: impl AsRef<str>) -> Options { self.device = Some(std::ffi::CString::new(s.as_ref()).expect("Invalid device")); self }
+
+    /// Select the OpenCL platform by name
+    pub fn platform(mut self, s: impl AsRef<str>) -> Options { self.platform = Some(std::ffi::CString::new(s.as_ref()).expect("Invalid platform")); self }
+
+    /// Set the default group size
+    pub fn default_group_size(mut self, n: u32) -> Options { self.default_group_size = Some(n); self }
+
+    /// Set the default tile size
+    pub fn default_tile_size(mut self, n: u32) -> Options { self.default_tile_size = Some(n); self }
+}
+
+/// Futhark context
+pub struct Context {
+    config: *mut futhark_context_config,
+    context: *mut futhark_context,
+    auto_sync: bool,
+    _cache_file: std::option::Option<std::ffi::CString>,
+}
+
+impl Context {
+    /// Create a new context with default options
+    pub fn new() -> std::result::Result<Self, Error> {
+        unsafe {
+            let config = futhark_context_config_new();
+            if config.is_null() { return Err(Error::NullPtr) }
+            let context = futhark_context_new(config);
+            if context.is_null() {
+                futhark_context_config_free(config);
+                return Err(Error::NullPtr);
+            }
+            Ok(Context { config, context, auto_sync: true, _cache_file: None })
+        }
+    }
+
+    /// Create a new context with custom options
+    pub fn new_with_options(options: Options) -> std::result::Result<Self, Error> {
+        let mut tuning_params = match &options.tuning_file {
+            Some(path) => Options::read_tuning_file(path)?,
+            None => Vec::new(),
+        };
+        tuning_params.extend(options.tuning_params.iter().cloned());
+
+        unsafe {
+            let config = futhark_context_config_new();
+            if config.is_null() { return Err(Error::NullPtr) }
+
+            for (name, value) in &tuning_params {
+                if futhark_context_config_set_tuning_param(config, name.as_ptr(), *value) != 0 {
+                    futhark_context_config_free(config);
+                    return Err(Error::InvalidTuning(name.to_string_lossy().into_owned()));
+                }
+            }
+
+            futhark_context_config_set_debugging(config, options.debug as std::os::raw::c_int);
+            futhark_context_config_set_profiling(config, options.profile as std::os::raw::c_int);
+            futhark_context_config_set_logging(config, options.logging as std::os::raw::c_int);
+
+            if let Some(c) = &options.cache_file {
+                futhark_context_config_set_cache_file(config, c.as_ptr());
+            }
+
+            if let Some(d) = &options.device { futhark_context_config_set_device(config, d.as_ptr()); }
+            if let Some(p) = &options.platform { futhark_context_config_set_platform(config, p.as_ptr()); }
+            if let Some(n) = options.default_group_size { futhark_context_config_set_default_group_size(config, n as std::os::raw::c_int); }
+            if let Some(n) = options.default_tile_size { futhark_context_config_set_default_tile_size(config, n as std::os::raw::c_int); }
+
+            let context = futhark_context_new(config);
+            if context.is_null() {
+                futhark_context_config_free(config);
+                return Err(Error::NullPtr);
+            }
+            Ok(Context { config, context, auto_sync: options.auto_sync, _cache_file: options.cache_file })
+        }
+    }
+
+    /// Sync the context, if `auto_sync` is enabled this shouldn't be needed
+    pub fn sync(&self) {
+        unsafe { futhark_context_sync(self.context); }
+    }
+
+    /// Sync if `auto_sync` is enabled, otherwise this is a noop
+    pub fn auto_sync(&self) {
+        if self.auto_sync {
+            self.sync();
+        }
+    }
+
+    /// Clear Futhark caches
+    pub fn clear_caches(&self) -> std::result::Result<(), Error> {
+        let rc = unsafe {
+            futhark_context_clear_caches(self.context)
+        };
+        if rc != 0 { return Err(Error::Code(rc)) }
+        Ok(())
+    }
+
+    /// Pause Futhark profiling
+    pub fn pause_profiling(&self) {
+        unsafe {
+            futhark_context_pause_profiling(self.context);
+        }
+    }
+
+    /// Resume profiling
+    pub fn unpause_profiling(&self) {
+        unsafe {
+            futhark_context_unpause_profiling(self.context);
+        }
+    }
+
+    /// Get the last error message or None
+    pub fn get_error(&self) -> std::option::Option<String> {
+        unsafe {
+            let s = futhark_context_get_error(self.context);
+            if s.is_null() { return None }
+            let r = std::ffi::CStr::from_ptr(s).to_string_lossy().to_string();
+            free(s as *mut _);
+            Some(r)
+        }
+    }
+
+    pub fn report(&self) -> std::option::Option<String> {
+        unsafe {
+            let s = futhark_context_report(self.context);
+            if s.is_null() { return None }
+            let r = std::ffi::CStr::from_ptr(s).to_string_lossy().to_string();
+            free(s as *mut _);
+            Some(r)
+        }
+    }
+}
+
+impl Drop for Context {
+    fn drop(&mut self) {
+        unsafe {
+            futhark_context_sync(self.context);
+            futhark_context_free(self.context);
+            futhark_context_config_free(self.config);
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_context_config {
+    _private: [u8; 0]
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_context {
+    _private: [u8; 0]
+}
+
+extern "C" {
+    fn futhark_context_config_new() -> *mut futhark_context_config;
+    fn futhark_context_config_free(
+        _: *mut futhark_context_config
+    );
+    fn futhark_context_config_set_debugging(
+        _: *mut futhark_context_config,
+        _: std::os::raw::c_int
+    );
+
+    fn futhark_context_config_set_profiling(
+        _: *mut futhark_context_config,
+        _: std::os::raw::c_int
+    );
+
+    fn futhark_context_config_set_logging(
+        _: *mut futhark_context_config,
+        _: std::os::raw::c_int
+    );
+
+    fn futhark_context_config_set_cache_file(
+        _: *mut futhark_context_config,
+        _: *const std::os::raw::c_char,
+    );
+
+    fn futhark_context_config_set_tuning_param(
+        _: *mut futhark_context_config,
+        _: *const std::os::raw::c_char,
+        _: usize,
+    ) -> std::os::raw::c_int;
+
+    fn futhark_context_new(
+        _: *mut futhark_context_config
+    ) -> *mut futhark_context;
+
+    fn futhark_context_free(
+        _: *mut futhark_context
+    );
+
+    fn futhark_context_sync(
+        _: *mut futhark_context,
+    ) -> std::os::raw::c_int;
+
+    fn futhark_context_clear_caches(
+        _: *mut futhark_context,
+    ) -> std::os::raw::c_int;
+
+    fn futhark_context_pause_profiling(
+        _: *mut futhark_context
+    );
+
+    fn futhark_context_unpause_profiling(
+        _: *mut futhark_context
+    );
+
+    fn futhark_context_get_error(
+        _: *mut futhark_context
+    ) -> *mut std::os::raw::c_char;
+
+    fn futhark_context_report(
+        _: *mut futhark_context
+    ) -> *mut std::os::raw::c_char;
+
+    fn free(_: *mut std::ffi::c_void);
+
+    fn futhark_context_config_set_device(_: *mut futhark_context_config, _: *const std::os::raw::c_char);
+    fn futhark_context_config_set_platform(_: *mut futhark_context_config, _: *const std::os::raw::c_char);
+    fn futhark_context_config_set_default_group_size(_: *mut futhark_context_config, _: std::os::raw::c_int);
+    fn futhark_context_config_set_default_tile_size(_: *mut futhark_context_config, _: std::os::raw::c_int);
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_opaque_2b0ad0ae {
+    _private: [u8; 0]
+}
+
+extern "C" {
+    fn futhark_free_opaque_2b0ad0ae(
+        _: *mut futhark_context,
+        _: *mut futhark_opaque_2b0ad0ae
+    ) -> std::os::raw::c_int;
+}
+
+/// Futhark type
+pub struct Type2b0ad0aeOpaque<'a> {
+    data: *mut futhark_opaque_2b0ad0ae,
+    ctx: &'a Context,
+}
+
+impl<'a> Type2b0ad0aeOpaque<'a> {
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, data: *mut futhark_opaque_2b0ad0ae) -> Self {
+        Self { ctx, data }
+    }
+}
+
+impl<'a> Drop for Type2b0ad0aeOpaque<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            futhark_free_opaque_2b0ad0ae(self.ctx.context, self.data);
+        }
+    }
+}
+
+impl<'a> Type2b0ad0aeOpaque<'a> {
+    /// Get 0 field
+    pub fn get_0(&self) -> Result<i32, Error> {
+        let mut out = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_project_opaque_2b0ad0ae_0(
+                self.ctx.context,
+                out.as_mut_ptr(),
+                self.data
+            )
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+        self.ctx.auto_sync();
+        let out = unsafe { out.assume_init() };
+        Ok(out)
+    }
+}
+
+extern "C" {
+    fn futhark_project_opaque_2b0ad0ae_0(
+        _: *mut futhark_context,
+        _: *mut i32,
+        _: *const futhark_opaque_2b0ad0ae
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> Type2b0ad0aeOpaque<'a> {
+    /// Get 1 field
+    pub fn get_1(&self) -> Result<f32, Error> {
+        let mut out = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_project_opaque_2b0ad0ae_1(
+                self.ctx.context,
+                out.as_mut_ptr(),
+                self.data
+            )
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+        self.ctx.auto_sync();
+        let out = unsafe { out.assume_init() };
+        Ok(out)
+    }
+}
+
+extern "C" {
+    fn futhark_project_opaque_2b0ad0ae_1(
+        _: *mut futhark_context,
+        _: *mut f32,
+        _: *const futhark_opaque_2b0ad0ae
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> Type2b0ad0aeOpaque<'a> {
+    /// Create new Type2b0ad0aeOpaque
+    pub fn new(ctx: &'a Context, field0: i32, field1: f32) -> std::result::Result<Self, Error> {
+        unsafe {
+            let mut out = std::ptr::null_mut();
+            let rc = futhark_new_opaque_2b0ad0ae(ctx.context, &mut out, field0, field1);
+            if rc != 0 { return Err(Error::Code(rc)); }
+            ctx.auto_sync();
+            Ok(Self { data: out, ctx })
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_new_opaque_2b0ad0ae(
+        _: *mut futhark_context,
+        _: *mut *mut futhark_opaque_2b0ad0ae,
+        field0: i32, field1: f32
+    ) -> std::os::raw::c_int;
+}
+/// Builder for [Type2b0ad0aeOpaque], all fields have to be set before calling `build`
+#[derive(Default)]
+pub struct Type2b0ad0aeOpaqueBuilder {
+    field0: std::option::Option<i32>,
+    field1: std::option::Option<f32>,
+}
+
+impl Type2b0ad0aeOpaqueBuilder {
+    /// Create a new builder without any fields set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the `0` field
+    pub fn field0(mut self, value: i32) -> Self {
+        self.field0 = Some(value);
+        self
+    }
+
+    /// Set the `1` field
+    pub fn field1(mut self, value: f32) -> Self {
+        self.field1 = Some(value);
+        self
+    }
+
+    /// Create a new [Type2b0ad0aeOpaque], returns `Error::MissingField` if any field hasn't been set
+    pub fn build<'a>(self, ctx: &'a Context) -> std::result::Result<Type2b0ad0aeOpaque<'a>, Error> {
+        let field0 = self.field0.ok_or(Error::MissingField("0"))?;
+        let field1 = self.field1.ok_or(Error::MissingField("1"))?;
+        Type2b0ad0aeOpaque::new(ctx, field0, field1)
+    }
+}
+
+/// Host representation of [Type2b0ad0aeOpaque]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Type2b0ad0ae(pub i32, pub f32);
+
+impl Type2b0ad0ae {
+    /// Create a new Futhark value with the same fields
+    pub fn to_opaque<'a>(&self, ctx: &'a Context) -> std::result::Result<Type2b0ad0aeOpaque<'a>, Error> {
+        Type2b0ad0aeOpaque::new(ctx, self.0, self.1)
+    }
+}
+
+impl<'a> Type2b0ad0aeOpaque<'a> {
+    /// Load all fields into a [Type2b0ad0ae]
+    pub fn get(&self) -> std::result::Result<Type2b0ad0ae, Error> {
+        Ok(Type2b0ad0ae { 0: self.get_0()?, 1: self.get_1()? })
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_f32_1d {
+    _private: [u8; 0]
+}
+
+/// Array type with 1 dimensions and f32 elements
+pub struct ArrayF32D1<'a> {
+    ptr: *mut futhark_f32_1d,
+    pub shape: [i64; 1],
+    ctx: &'a Context,
+}
+
+impl<'a> ArrayF32D1<'a> {
+    /// Create a new array of `dims` dimensions and initialize it with the values from `data`
+    pub fn new(ctx: &'a Context, dims: [i64; 1], data: impl AsRef<[f32]>) -> std::result::Result<Self, Error> {
+        let size: i64 = dims.iter().product();
+        let data = data.as_ref();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape)
+        }
+        let ptr = unsafe {
+            futhark_new_f32_1d(ctx.context, data.as_ptr(), dims[0])
+        };
+        if ptr.is_null() { return Err(Error::NullPtr); }
+        ctx.auto_sync();
+        Ok(Self {
+            ptr: ptr as *mut _,
+            shape: dims,
+            ctx,
+        })
+    }
+
+    /// Get the array shape
+    pub fn shape(&self) -> &[i64; 1] {
+        &self.shape
+    }
+
+    /// Load values back into a slice
+    pub fn values(&self, mut data: impl AsMut<[f32]>) -> std::result::Result<(), Error> {
+        let size: i64 = self.shape.iter().product();
+        let data = data.as_mut();
+        if data.len() as i64 != size {
+            return Err(Error::InvalidShape);
+        }
+        let rc = unsafe {
+            futhark_values_f32_1d(self.ctx.context, self.ptr, data.as_mut_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::Code(rc));
+        }
+        self.ctx.auto_sync();
+        Ok(())
+    }
+
+    /// Load values into a `Vec`
+    pub fn get(&self) -> std::result::Result<Vec<f32>, Error> {
+        let size: i64 = self.shape.iter().product();
+        let mut vec = vec![f32::default(); size as usize];
+        self.values(&mut vec)?;
+        Ok(vec)
+    }
+
+
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, ptr: *mut futhark_f32_1d) -> Self {
+        let len_ptr = unsafe { futhark_shape_f32_1d(ctx.context, ptr) };
+        let mut shape = [0i64; 1];
+        unsafe {
+            for (i, s) in shape.iter_mut().enumerate() {
+                *s = *len_ptr.add(i);
+            }
+        }
+        Self { ctx, shape, ptr }
+    }
+}
+
+
+impl<'a> Drop for ArrayF32D1<'a> {
+    fn drop(&mut self){
+        unsafe {
+            futhark_free_f32_1d(self.ctx.context, self.ptr as *mut _);
+        }
+    }
+}
+
+#[allow(unused)]
+extern "C" {
+    fn futhark_shape_f32_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_f32_1d
+    ) -> *const i64;
+
+    fn futhark_new_f32_1d(
+        _: *mut futhark_context,
+        _: *const f32,
+        dim0: i64
+    ) -> *mut futhark_f32_1d;
+
+    fn futhark_free_f32_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_f32_1d
+    ) -> std::os::raw::c_int;
+
+    fn futhark_values_f32_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_f32_1d,
+        _: *mut f32
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> ArrayF32D1<'a> {
+    /// Get a single element of the array, the manifest doesn't include an `index` function so this
+    /// always returns `Error::Unsupported`
+    pub fn index(&self, _index: [i64; 1]) -> std::result::Result<f32, Error> {
+        Err(Error::Unsupported("index"))
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_opaque_arr_point_1d {
+    _private: [u8; 0]
+}
+
+extern "C" {
+    fn futhark_free_opaque_arr_point_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_opaque_arr_point_1d
+    ) -> std::os::raw::c_int;
+}
+
+/// Futhark type
+pub struct Arr_point_1d<'a> {
+    data: *mut futhark_opaque_arr_point_1d,
+    ctx: &'a Context,
+}
+
+impl<'a> Arr_point_1d<'a> {
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, data: *mut futhark_opaque_arr_point_1d) -> Self {
+        Self { ctx, data }
+    }
+}
+
+impl<'a> Drop for Arr_point_1d<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            futhark_free_opaque_arr_point_1d(self.ctx.context, self.data);
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_opaque_number {
+    _private: [u8; 0]
+}
+
+extern "C" {
+    fn futhark_free_opaque_number(
+        _: *mut futhark_context,
+        _: *mut futhark_opaque_number
+    ) -> std::os::raw::c_int;
+}
+
+/// Futhark type
+pub struct NumberOpaque<'a> {
+    data: *mut futhark_opaque_number,
+    ctx: &'a Context,
+}
+
+impl<'a> NumberOpaque<'a> {
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, data: *mut futhark_opaque_number) -> Self {
+        Self { ctx, data }
+    }
+}
+
+impl<'a> Drop for NumberOpaque<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            futhark_free_opaque_number(self.ctx.context, self.data);
+        }
+    }
+}
+
+impl<'a> NumberOpaque<'a> {
+    /// Get x field
+    pub fn get_x(&self) -> Result<f32, Error> {
+        let mut out = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_project_opaque_number_x(
+                self.ctx.context,
+                out.as_mut_ptr(),
+                self.data
+            )
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+        self.ctx.auto_sync();
+        let out = unsafe { out.assume_init() };
+        Ok(out)
+    }
+}
+
+extern "C" {
+    fn futhark_project_opaque_number_x(
+        _: *mut futhark_context,
+        _: *mut f32,
+        _: *const futhark_opaque_number
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> NumberOpaque<'a> {
+    /// Create new NumberOpaque
+    pub fn new(ctx: &'a Context, fieldx: f32) -> std::result::Result<Self, Error> {
+        unsafe {
+            let mut out = std::ptr::null_mut();
+            let rc = futhark_new_opaque_number(ctx.context, &mut out, fieldx);
+            if rc != 0 { return Err(Error::Code(rc)); }
+            ctx.auto_sync();
+            Ok(Self { data: out, ctx })
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_new_opaque_number(
+        _: *mut futhark_context,
+        _: *mut *mut futhark_opaque_number,
+        fieldx: f32
+    ) -> std::os::raw::c_int;
+}
+/// Builder for [NumberOpaque], all fields have to be set before calling `build`
+#[derive(Default)]
+pub struct NumberOpaqueBuilder {
+    fieldx: std::option::Option<f32>,
+}
+
+impl NumberOpaqueBuilder {
+    /// Create a new builder without any fields set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the `x` field
+    pub fn x(mut self, value: f32) -> Self {
+        self.fieldx = Some(value);
+        self
+    }
+
+    /// Create a new [NumberOpaque], returns `Error::MissingField` if any field hasn't been set
+    pub fn build<'a>(self, ctx: &'a Context) -> std::result::Result<NumberOpaque<'a>, Error> {
+        let fieldx = self.fieldx.ok_or(Error::MissingField("x"))?;
+        NumberOpaque::new(ctx, fieldx)
+    }
+}
+
+/// Host representation of [NumberOpaque]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Number {
+    pub x: f32,
+}
+
+impl Number {
+    /// Create a new Futhark value with the same fields
+    pub fn to_opaque<'a>(&self, ctx: &'a Context) -> std::result::Result<NumberOpaque<'a>, Error> {
+        NumberOpaque::new(ctx, self.x)
+    }
+}
+
+impl<'a> NumberOpaque<'a> {
+    /// Load all fields into a [Number]
+    pub fn get(&self) -> std::result::Result<Number, Error> {
+        Ok(Number { x: self.get_x()? })
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_opaque_option {
+    _private: [u8; 0]
+}
+
+extern "C" {
+    fn futhark_free_opaque_option(
+        _: *mut futhark_context,
+        _: *mut futhark_opaque_option
+    ) -> std::os::raw::c_int;
+}
+
+/// Futhark type
+pub struct Option<'a> {
+    data: *mut futhark_opaque_option,
+    ctx: &'a Context,
+}
+
+impl<'a> Option<'a> {
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, data: *mut futhark_opaque_option) -> Self {
+        Self { ctx, data }
+    }
+}
+
+impl<'a> Drop for Option<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            futhark_free_opaque_option(self.ctx.context, self.data);
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_opaque_point {
+    _private: [u8; 0]
+}
+
+extern "C" {
+    fn futhark_free_opaque_point(
+        _: *mut futhark_context,
+        _: *mut futhark_opaque_point
+    ) -> std::os::raw::c_int;
+}
+
+/// Futhark type
+pub struct PointOpaque<'a> {
+    data: *mut futhark_opaque_point,
+    ctx: &'a Context,
+}
+
+impl<'a> PointOpaque<'a> {
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, data: *mut futhark_opaque_point) -> Self {
+        Self { ctx, data }
+    }
+}
+
+impl<'a> Drop for PointOpaque<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            futhark_free_opaque_point(self.ctx.context, self.data);
+        }
+    }
+}
+
+impl<'a> PointOpaque<'a> {
+    /// Get x field
+    pub fn get_x(&self) -> Result<f32, Error> {
+        let mut out = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_project_opaque_point_x(
+                self.ctx.context,
+                out.as_mut_ptr(),
+                self.data
+            )
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+        self.ctx.auto_sync();
+        let out = unsafe { out.assume_init() };
+        Ok(out)
+    }
+}
+
+extern "C" {
+    fn futhark_project_opaque_point_x(
+        _: *mut futhark_context,
+        _: *mut f32,
+        _: *const futhark_opaque_point
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> PointOpaque<'a> {
+    /// Get y field
+    pub fn get_y(&self) -> Result<f32, Error> {
+        let mut out = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_project_opaque_point_y(
+                self.ctx.context,
+                out.as_mut_ptr(),
+                self.data
+            )
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+        self.ctx.auto_sync();
+        let out = unsafe { out.assume_init() };
+        Ok(out)
+    }
+}
+
+extern "C" {
+    fn futhark_project_opaque_point_y(
+        _: *mut futhark_context,
+        _: *mut f32,
+        _: *const futhark_opaque_point
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> PointOpaque<'a> {
+    /// Create new PointOpaque
+    pub fn new(ctx: &'a Context, fieldx: f32, fieldy: f32) -> std::result::Result<Self, Error> {
+        unsafe {
+            let mut out = std::ptr::null_mut();
+            let rc = futhark_new_opaque_point(ctx.context, &mut out, fieldx, fieldy);
+            if rc != 0 { return Err(Error::Code(rc)); }
+            ctx.auto_sync();
+            Ok(Self { data: out, ctx })
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_new_opaque_point(
+        _: *mut futhark_context,
+        _: *mut *mut futhark_opaque_point,
+        fieldx: f32, fieldy: f32
+    ) -> std::os::raw::c_int;
+}
+/// Builder for [PointOpaque], all fields have to be set before calling `build`
+#[derive(Default)]
+pub struct PointOpaqueBuilder {
+    fieldx: std::option::Option<f32>,
+    fieldy: std::option::Option<f32>,
+}
+
+impl PointOpaqueBuilder {
+    /// Create a new builder without any fields set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the `x` field
+    pub fn x(mut self, value: f32) -> Self {
+        self.fieldx = Some(value);
+        self
+    }
+
+    /// Set the `y` field
+    pub fn y(mut self, value: f32) -> Self {
+        self.fieldy = Some(value);
+        self
+    }
+
+    /// Create a new [PointOpaque], returns `Error::MissingField` if any field hasn't been set
+    pub fn build<'a>(self, ctx: &'a Context) -> std::result::Result<PointOpaque<'a>, Error> {
+        let fieldx = self.fieldx.ok_or(Error::MissingField("x"))?;
+        let fieldy = self.fieldy.ok_or(Error::MissingField("y"))?;
+        PointOpaque::new(ctx, fieldx, fieldy)
+    }
+}
+
+/// Host representation of [PointOpaque]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Point {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Point {
+    /// Create a new Futhark value with the same fields
+    pub fn to_opaque<'a>(&self, ctx: &'a Context) -> std::result::Result<PointOpaque<'a>, Error> {
+        PointOpaque::new(ctx, self.x, self.y)
+    }
+}
+
+impl<'a> PointOpaque<'a> {
+    /// Load all fields into a [Point]
+    pub fn get(&self) -> std::result::Result<Point, Error> {
+        Ok(Point { x: self.get_x()?, y: self.get_y()? })
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_opaque_segment {
+    _private: [u8; 0]
+}
+
+extern "C" {
+    fn futhark_free_opaque_segment(
+        _: *mut futhark_context,
+        _: *mut futhark_opaque_segment
+    ) -> std::os::raw::c_int;
+}
+
+/// Futhark type
+pub struct Segment<'a> {
+    data: *mut futhark_opaque_segment,
+    ctx: &'a Context,
+}
+
+impl<'a> Segment<'a> {
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, data: *mut futhark_opaque_segment) -> Self {
+        Self { ctx, data }
+    }
+}
+
+impl<'a> Drop for Segment<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            futhark_free_opaque_segment(self.ctx.context, self.data);
+        }
+    }
+}
+
+impl<'a> Segment<'a> {
+    /// Get start field
+    pub fn get_start(&self) -> Result<PointOpaque<'_>, Error> {
+        let mut out = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_project_opaque_segment_start(
+                self.ctx.context,
+                out.as_mut_ptr(),
+                self.data
+            )
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+        self.ctx.auto_sync();
+        let out = unsafe { out.assume_init() };
+        Ok(PointOpaque::from_ptr(self.ctx, out))
+    }
+}
+
+extern "C" {
+    fn futhark_project_opaque_segment_start(
+        _: *mut futhark_context,
+        _: *mut *mut futhark_opaque_point,
+        _: *const futhark_opaque_segment
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> Segment<'a> {
+    /// Get end field
+    pub fn get_end(&self) -> Result<PointOpaque<'_>, Error> {
+        let mut out = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_project_opaque_segment_end(
+                self.ctx.context,
+                out.as_mut_ptr(),
+                self.data
+            )
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+        self.ctx.auto_sync();
+        let out = unsafe { out.assume_init() };
+        Ok(PointOpaque::from_ptr(self.ctx, out))
+    }
+}
+
+extern "C" {
+    fn futhark_project_opaque_segment_end(
+        _: *mut futhark_context,
+        _: *mut *mut futhark_opaque_point,
+        _: *const futhark_opaque_segment
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> Segment<'a> {
+    /// Create new Segment
+    pub fn new(ctx: &'a Context, fieldstart: &PointOpaque, fieldend: &PointOpaque) -> std::result::Result<Self, Error> {
+        unsafe {
+            let mut out = std::ptr::null_mut();
+            let rc = futhark_new_opaque_segment(ctx.context, &mut out, fieldstart.data, fieldend.data);
+            if rc != 0 { return Err(Error::Code(rc)); }
+            ctx.auto_sync();
+            Ok(Self { data: out, ctx })
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_new_opaque_segment(
+        _: *mut futhark_context,
+        _: *mut *mut futhark_opaque_segment,
+        fieldstart: *const futhark_opaque_point, fieldend: *const futhark_opaque_point
+    ) -> std::os::raw::c_int;
+}
+/// Builder for [Segment], all fields have to be set before calling `build`
+#[derive(Default)]
+pub struct SegmentBuilder<'b> {
+    fieldstart: std::option::Option<&'b PointOpaque<'b>>,
+    fieldend: std::option::Option<&'b PointOpaque<'b>>,
+}
+
+impl<'b> SegmentBuilder<'b> {
+    /// Create a new builder without any fields set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the `start` field
+    pub fn start(mut self, value: &'b PointOpaque<'b>) -> Self {
+        self.fieldstart = Some(value);
+        self
+    }
+
+    /// Set the `end` field
+    pub fn end(mut self, value: &'b PointOpaque<'b>) -> Self {
+        self.fieldend = Some(value);
+        self
+    }
+
+    /// Create a new [Segment], returns `Error::MissingField` if any field hasn't been set
+    pub fn build<'a>(self, ctx: &'a Context) -> std::result::Result<Segment<'a>, Error> {
+        let fieldstart = self.fieldstart.ok_or(Error::MissingField("start"))?;
+        let fieldend = self.fieldend.ok_or(Error::MissingField("end"))?;
+        Segment::new(ctx, fieldstart, fieldend)
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct futhark_opaque_tup {
+    _private: [u8; 0]
+}
+
+extern "C" {
+    fn futhark_free_opaque_tup(
+        _: *mut futhark_context,
+        _: *mut futhark_opaque_tup
+    ) -> std::os::raw::c_int;
+}
+
+/// Futhark type
+pub struct Tup<'a> {
+    data: *mut futhark_opaque_tup,
+    ctx: &'a Context,
+}
+
+impl<'a> Tup<'a> {
+    #[allow(unused)]
+    fn from_ptr(ctx: &'a Context, data: *mut futhark_opaque_tup) -> Self {
+        Self { ctx, data }
+    }
+}
+
+impl<'a> Drop for Tup<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            futhark_free_opaque_tup(self.ctx.context, self.data);
+        }
+    }
+}
+
+impl<'a> Tup<'a> {
+    /// Get 0 field
+    pub fn get_0(&self) -> Result<NumberOpaque<'_>, Error> {
+        let mut out = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_project_opaque_tup_0(
+                self.ctx.context,
+                out.as_mut_ptr(),
+                self.data
+            )
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+        self.ctx.auto_sync();
+        let out = unsafe { out.assume_init() };
+        Ok(NumberOpaque::from_ptr(self.ctx, out))
+    }
+}
+
+extern "C" {
+    fn futhark_project_opaque_tup_0(
+        _: *mut futhark_context,
+        _: *mut *mut futhark_opaque_number,
+        _: *const futhark_opaque_tup
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> Tup<'a> {
+    /// Get 1 field
+    pub fn get_1(&self) -> Result<ArrayF32D1<'_>, Error> {
+        let mut out = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_project_opaque_tup_1(
+                self.ctx.context,
+                out.as_mut_ptr(),
+                self.data
+            )
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+        self.ctx.auto_sync();
+        let out = unsafe { out.assume_init() };
+        Ok(ArrayF32D1::from_ptr(self.ctx, out))
+    }
+}
+
+extern "C" {
+    fn futhark_project_opaque_tup_1(
+        _: *mut futhark_context,
+        _: *mut *mut futhark_f32_1d,
+        _: *const futhark_opaque_tup
+    ) -> std::os::raw::c_int;
+}
+
+impl<'a> Tup<'a> {
+    /// Create new Tup
+    pub fn new(ctx: &'a Context, field0: &NumberOpaque, field1: &ArrayF32D1) -> std::result::Result<Self, Error> {
+        unsafe {
+            let mut out = std::ptr::null_mut();
+            let rc = futhark_new_opaque_tup(ctx.context, &mut out, field0.data, field1.ptr);
+            if rc != 0 { return Err(Error::Code(rc)); }
+            ctx.auto_sync();
+            Ok(Self { data: out, ctx })
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_new_opaque_tup(
+        _: *mut futhark_context,
+        _: *mut *mut futhark_opaque_tup,
+        field0: *const futhark_opaque_number, field1: *const futhark_f32_1d
+    ) -> std::os::raw::c_int;
+}
+/// Builder for [Tup], all fields have to be set before calling `build`
+#[derive(Default)]
+pub struct TupBuilder<'b> {
+    field0: std::option::Option<&'b NumberOpaque<'b>>,
+    field1: std::option::Option<&'b ArrayF32D1<'b>>,
+}
+
+impl<'b> TupBuilder<'b> {
+    /// Create a new builder without any fields set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the `0` field
+    pub fn field0(mut self, value: &'b NumberOpaque<'b>) -> Self {
+        self.field0 = Some(value);
+        self
+    }
+
+    /// Set the `1` field
+    pub fn field1(mut self, value: &'b ArrayF32D1<'b>) -> Self {
+        self.field1 = Some(value);
+        self
+    }
+
+    /// Create a new [Tup], returns `Error::MissingField` if any field hasn't been set
+    pub fn build<'a>(self, ctx: &'a Context) -> std::result::Result<Tup<'a>, Error> {
+        let field0 = self.field0.ok_or(Error::MissingField("0"))?;
+        let field1 = self.field1.ok_or(Error::MissingField("1"))?;
+        Tup::new(ctx, field0, field1)
+    }
+}
+
+impl Context {
+    /// Entry point: distance
+    pub fn distance(&self, input0: Point, input1: Point) -> Result<f32, Error> {
+        let mut out0 = std::mem::MaybeUninit::zeroed();
+        let input0 = input0.to_opaque(self)?;
+        let input1 = input1.to_opaque(self)?;
+        let rc = unsafe {
+            futhark_entry_distance(self.context, out0.as_mut_ptr(), input0.data as *mut _, input1.data as *mut _)
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+    
+        #[allow(unused_unsafe)]
+        unsafe {
+            Ok(out0.assume_init())
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_entry_distance(
+        _: *mut futhark_context,
+        out0: *mut f32, input0: *const futhark_opaque_point, input1: *const futhark_opaque_point
+    ) -> std::os::raw::c_int;
+}
+impl Context {
+    /// Entry point: length
+    pub fn length(&self, input0: &Segment) -> Result<f32, Error> {
+        let mut out0 = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_entry_length(self.context, out0.as_mut_ptr(), input0.data as *mut _)
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+    
+        #[allow(unused_unsafe)]
+        unsafe {
+            Ok(out0.assume_init())
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_entry_length(
+        _: *mut futhark_context,
+        out0: *mut f32, input0: *const futhark_opaque_segment
+    ) -> std::os::raw::c_int;
+}
+impl Context {
+    /// Entry point: points
+    pub fn points(&self, input0: &ArrayF32D1, input1: &ArrayF32D1) -> Result<Arr_point_1d<'_>, Error> {
+        let mut out0 = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_entry_points(self.context, out0.as_mut_ptr(), input0.ptr as *mut _, input1.ptr as *mut _)
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+    
+        #[allow(unused_unsafe)]
+        unsafe {
+            Ok(Arr_point_1d::from_ptr(self, out0.assume_init()))
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_entry_points(
+        _: *mut futhark_context,
+        out0: *mut *mut futhark_opaque_arr_point_1d, input0: *const futhark_f32_1d, input1: *const futhark_f32_1d
+    ) -> std::os::raw::c_int;
+}
+impl Context {
+    /// Entry point: return_option
+    pub fn return_option(&self, input0: &Option) -> Result<Option<'_>, Error> {
+        let mut out0 = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_entry_return_option(self.context, out0.as_mut_ptr(), input0.data as *mut _)
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+    
+        #[allow(unused_unsafe)]
+        unsafe {
+            Ok(Option::from_ptr(self, out0.assume_init()))
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_entry_return_option(
+        _: *mut futhark_context,
+        out0: *mut *mut futhark_opaque_option, input0: *const futhark_opaque_option
+    ) -> std::os::raw::c_int;
+}
+impl Context {
+    /// Entry point: swap
+    pub fn swap(&self, input0: Type2b0ad0ae) -> Result<(f32, i32), Error> {
+        let mut out0 = std::mem::MaybeUninit::zeroed();
+        let mut out1 = std::mem::MaybeUninit::zeroed();
+        let input0 = input0.to_opaque(self)?;
+        let rc = unsafe {
+            futhark_entry_swap(self.context, out0.as_mut_ptr(), out1.as_mut_ptr(), input0.data as *mut _)
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+    
+        #[allow(unused_unsafe)]
+        unsafe {
+            Ok((out0.assume_init(), out1.assume_init()))
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_entry_swap(
+        _: *mut futhark_context,
+        out0: *mut f32, out1: *mut i32, input0: *const futhark_opaque_2b0ad0ae
+    ) -> std::os::raw::c_int;
+}
+impl Context {
+    /// Entry point: tup_mul
+    pub fn tup_mul(&self, input0: &Tup) -> Result<ArrayF32D1<'_>, Error> {
+        let mut out0 = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_entry_tup_mul(self.context, out0.as_mut_ptr(), input0.data as *mut _)
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+    
+        #[allow(unused_unsafe)]
+        unsafe {
+            Ok(ArrayF32D1::from_ptr(self, out0.assume_init()))
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_entry_tup_mul(
+        _: *mut futhark_context,
+        out0: *mut *mut futhark_f32_1d, input0: *const futhark_opaque_tup
+    ) -> std::os::raw::c_int;
+}
+impl Context {
+    /// Entry point: unzip_points
+    pub fn unzip_points(&self, input0: &Arr_point_1d) -> Result<(ArrayF32D1<'_>, ArrayF32D1<'_>), Error> {
+        let mut out0 = std::mem::MaybeUninit::zeroed();
+        let mut out1 = std::mem::MaybeUninit::zeroed();
+        let rc = unsafe {
+            futhark_entry_unzip_points(self.context, out0.as_mut_ptr(), out1.as_mut_ptr(), input0.data as *mut _)
+        };
+        if rc != 0 { return Err(Error::Code(rc)); }
+    
+        #[allow(unused_unsafe)]
+        unsafe {
+            Ok((ArrayF32D1::from_ptr(self, out0.assume_init()), ArrayF32D1::from_ptr(self, out1.assume_init())))
+        }
+    }
+}
+
+extern "C" {
+    fn futhark_entry_unzip_points(
+        _: *mut futhark_context,
+        out0: *mut *mut futhark_f32_1d, out1: *mut *mut futhark_f32_1d, input0: *const futhark_opaque_arr_point_1d
+    ) -> std::os::raw::c_int;
+}
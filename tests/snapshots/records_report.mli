@@ -0,0 +1,221 @@
+(* Generated by futhark-bindgen *)
+
+open! Signed
+open! Unsigned
+
+type error = 
+  | InvalidShape of int * int 
+  | NullPtr 
+  | Code of int
+  | UseAfterFree of [`context | `array | `opaque]
+  | IndexOutOfBounds
+  | Unsupported of string
+  | InvalidTuning of string
+  | InvalidReport of string
+
+exception Error of error
+
+module Report: sig
+  type kernel = { invocations: int; total: float }
+  (** Statistics for a kernel (or any other event, such as a copy), [total] is the time spent running it in seconds *)
+
+  type t = { memory: (string * int) list; kernels: (string * kernel) list }
+  (** Profiling report, [memory] contains the peak memory usage in bytes for each memory space and [kernels] is sorted by name *)
+
+  val parse: string -> t
+  (** Parse a JSON report, as created by recent versions of Futhark *)
+
+  val total_time: t -> float
+  (** Total time spent running kernels in seconds *)
+end
+
+module Context: sig
+  type t
+  (** Futhark context *)
+
+  val v: ?debug:bool -> ?log:bool -> ?profile:bool -> ?cache_file:string -> ?auto_sync:bool -> ?tuning_params:(string * int) list -> ?tuning_file:string -> ?device:string -> ?platform:string -> ?default_group_size:int -> ?default_tile_size:int -> unit -> t
+  (** Create a new context, [tuning_params] are applied after the parameters loaded from [tuning_file] *)
+  
+  val sync: t -> unit
+  (** Sync the context, if auto_sync is enabled this is not needed *)
+  
+  val free: t -> unit
+  (** Free the context *)
+  
+  val clear_caches: t -> unit
+  (** Clear Futhark caches *)
+
+  val get_error: t -> string option
+  (** Get last error message or None *)
+
+  val report: t -> string option
+  val parsed_report: t -> Report.t
+  (** Get the profiling report as a [Report.t], this requires a version of Futhark that creates JSON reports *)
+
+  val profile: t -> (unit -> 'a) -> 'a * Report.t
+  (** Run a function and return its result along with a report of the work done while it was running.
+      Profiling has to be enabled using [~profile:true], the counters are reset by reading the report before calling the function *)
+
+  val pause_profiling: t -> unit
+  val unpause_profiling: t -> unit
+end
+module Type_2b0ad0ae : sig
+  type t
+  (** Futhark type *)
+  
+  val free: t -> unit
+  (** Free function *)
+
+  val v: Context.t -> int32 -> float -> t
+
+
+  val get_0: t -> int32
+  (** Get field: 0 *)
+
+  val get_1: t -> float
+  (** Get field: 1 *)
+end
+
+module Array_f32_1d: sig
+  type t
+  (** Futhark array *)
+
+  type kind = (float, Bigarray.float32_elt) Bigarray.kind
+  (** The Bigarray kind that matches the correct element type for this array *)
+
+  val kind: kind
+
+  val shape: t -> int array
+  (** Array shape *)
+
+  val v: Context.t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Genarray.t -> t
+  (** Initialize an array with the data from the provided bigarray *)
+
+  val values: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Genarray.t -> unit
+  (** Load the values into the provided bigarray *)
+
+  val values_array1: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Array1.t -> unit
+  (** Similar to [values] but takes an [Array1] instead of [Genarray] *)
+
+  val get: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Genarray.t
+  (** Get a new bigarray with the values loaded *)
+
+  val get_array1: t -> (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Array1.t
+  (** Similar to [get] but returns an [Array1] *)
+
+  val index: t -> int array -> float
+  (** Get a single element of the array without copying the whole array, raises
+      [Error (Unsupported "index")] if the manifest doesn't include an [index] function *)
+
+  val of_array: Context.t -> int array -> (float) array -> t
+  (** Create [t] from an array of values *)
+
+  val of_array1: Context.t -> int array ->  (float, Bigarray.float32_elt, Bigarray.c_layout) Bigarray.Array1.t-> t
+  (** Create [t] from an [Array1] instead of [Genarray] *)
+
+  val free: t -> unit
+  (** Free the array *)
+end
+
+module Arr_point_1d : sig
+  type t
+  (** Futhark type *)
+  
+  val free: t -> unit
+  (** Free function *)
+
+end
+module Number : sig
+  type t
+  (** Futhark type *)
+  
+  val free: t -> unit
+  (** Free function *)
+
+  val v: Context.t -> float -> t
+
+
+  val get_x: t -> float
+  (** Get field: x *)
+end
+
+module Option : sig
+  type t
+  (** Futhark type *)
+  
+  val free: t -> unit
+  (** Free function *)
+
+end
+module Point : sig
+  type t
+  (** Futhark type *)
+  
+  val free: t -> unit
+  (** Free function *)
+
+  val v: Context.t -> float -> float -> t
+
+
+  val get_x: t -> float
+  (** Get field: x *)
+
+  val get_y: t -> float
+  (** Get field: y *)
+end
+
+module Segment : sig
+  type t
+  (** Futhark type *)
+  
+  val free: t -> unit
+  (** Free function *)
+
+  val v: Context.t -> Point.t -> Point.t -> t
+
+
+  val get_start: t -> Point.t
+  (** Get field: start *)
+
+  val get_end: t -> Point.t
+  (** Get field: end *)
+end
+
+module Tup : sig
+  type t
+  (** Futhark type *)
+  
+  val free: t -> unit
+  (** Free function *)
+
+  val v: Context.t -> Number.t -> Array_f32_1d.t -> t
+
+
+  val get_0: t -> Number.t
+  (** Get field: 0 *)
+
+  val get_1: t -> Array_f32_1d.t
+  (** Get field: 1 *)
+end
+
+(** Entry point: distance *)
+val distance: Context.t -> Point.t -> Point.t -> (float)
+
+(** Entry point: length *)
+val length: Context.t -> Segment.t -> (float)
+
+(** Entry point: points *)
+val points: Context.t -> Array_f32_1d.t -> Array_f32_1d.t -> (Arr_point_1d.t)
+
+(** Entry point: return_option *)
+val return_option: Context.t -> Option.t -> (Option.t)
+
+(** Entry point: swap *)
+val swap: Context.t -> Type_2b0ad0ae.t -> (float * int32)
+
+(** Entry point: tup_mul *)
+val tup_mul: Context.t -> Tup.t -> (Array_f32_1d.t)
+
+(** Entry point: unzip_points *)
+val unzip_points: Context.t -> Arr_point_1d.t -> (Array_f32_1d.t * Array_f32_1d.t)
+
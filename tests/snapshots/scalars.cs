@@ -0,0 +1,286 @@
+// <auto-generated />
+// Generated by futhark-bindgen
+
+#nullable enable
+
+using System;
+using System.Runtime.InteropServices;
+
+namespace Scalars;
+
+internal static class Native
+{
+    // Resolved as `libscalars.so` on Linux
+    public const string Lib = "scalars";
+}
+
+/// <summary>Thrown when a Futhark function returns a non-zero error code</summary>
+public class FutharkException : Exception
+{
+    public int Code { get; }
+
+    public FutharkException(int code, string message) : base($"Futhark error code {code}: {message}")
+    {
+        Code = code;
+    }
+}
+
+/// <summary>Thrown when a Futhark function returns a NULL pointer</summary>
+public class NullPtrException : Exception
+{
+    public NullPtrException() : base("NULL pointer encountered")
+    {
+    }
+}
+
+/// <summary>Options used to configure a <see cref="Context"/></summary>
+public sealed class Options
+{
+    public bool Debug { get; set; }
+    public bool Profile { get; set; }
+    public bool Logging { get; set; }
+    public string? CacheFile { get; set; }
+    public bool AutoSync { get; set; } = true;
+}
+
+internal sealed class ContextHandle : SafeHandle
+{
+    [DllImport(Native.Lib)]
+    private static extern int futhark_context_sync(IntPtr ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_free(IntPtr ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_config_free(IntPtr config);
+
+    private readonly IntPtr config;
+    private readonly IntPtr cacheFile;
+
+    public ContextHandle(IntPtr config, IntPtr ctx, IntPtr cacheFile) : base(IntPtr.Zero, true)
+    {
+        this.config = config;
+        this.cacheFile = cacheFile;
+        SetHandle(ctx);
+    }
+
+    public override bool IsInvalid => handle == IntPtr.Zero;
+
+    protected override bool ReleaseHandle()
+    {
+        futhark_context_sync(handle);
+        futhark_context_free(handle);
+        futhark_context_config_free(config);
+        Marshal.FreeCoTaskMem(cacheFile);
+        return true;
+    }
+}
+
+// Handle to a value owned by a context, the context isn't freed until all of its values are
+internal abstract class FutharkHandle : SafeHandle
+{
+    private readonly ContextHandle ctx;
+
+    protected FutharkHandle(ContextHandle ctx, IntPtr ptr) : base(IntPtr.Zero, true)
+    {
+        if (ptr == IntPtr.Zero)
+        {
+            throw new NullPtrException();
+        }
+        bool added = false;
+        ctx.DangerousAddRef(ref added);
+        this.ctx = ctx;
+        SetHandle(ptr);
+    }
+
+    public override bool IsInvalid => handle == IntPtr.Zero;
+
+    protected abstract void Free(IntPtr ctx, IntPtr ptr);
+
+    protected override bool ReleaseHandle()
+    {
+        Free(ctx.DangerousGetHandle(), handle);
+        ctx.DangerousRelease();
+        return true;
+    }
+}
+
+/// <summary>Futhark context</summary>
+/// <remarks>Values created using the context keep it alive until they are disposed</remarks>
+public sealed partial class Context : IDisposable
+{
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_context_config_new();
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_config_free(IntPtr config);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_config_set_debugging(IntPtr config, int flag);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_config_set_profiling(IntPtr config, int flag);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_config_set_logging(IntPtr config, int flag);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_config_set_cache_file(IntPtr config, IntPtr path);
+
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_context_new(IntPtr config);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_context_sync(ContextHandle ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern int futhark_context_clear_caches(ContextHandle ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_pause_profiling(ContextHandle ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern void futhark_context_unpause_profiling(ContextHandle ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_context_get_error(ContextHandle ctx);
+
+    [DllImport(Native.Lib)]
+    private static extern IntPtr futhark_context_report(ContextHandle ctx);
+
+    internal readonly ContextHandle handle;
+
+    /// <summary>Sync after every operation</summary>
+    public bool AutoSync { get; set; }
+
+    /// <summary>Create a new context, using the default options if <paramref name="options"/> is null</summary>
+    public Context(Options? options = null)
+    {
+        options ??= new Options();
+        IntPtr config = futhark_context_config_new();
+        if (config == IntPtr.Zero)
+        {
+            throw new NullPtrException();
+        }
+        futhark_context_config_set_debugging(config, options.Debug ? 1 : 0);
+        futhark_context_config_set_profiling(config, options.Profile ? 1 : 0);
+        futhark_context_config_set_logging(config, options.Logging ? 1 : 0);
+        IntPtr cacheFile = IntPtr.Zero;
+        if (options.CacheFile != null)
+        {
+            cacheFile = Marshal.StringToCoTaskMemUTF8(options.CacheFile);
+            futhark_context_config_set_cache_file(config, cacheFile);
+        }
+
+        IntPtr ctx = futhark_context_new(config);
+        if (ctx == IntPtr.Zero)
+        {
+            futhark_context_config_free(config);
+            Marshal.FreeCoTaskMem(cacheFile);
+            throw new NullPtrException();
+        }
+        handle = new ContextHandle(config, ctx, cacheFile);
+        AutoSync = options.AutoSync;
+    }
+
+    /// <summary>Free the context once all values created using it have been disposed</summary>
+    public void Dispose()
+    {
+        handle.Dispose();
+    }
+
+    internal void Check(int rc)
+    {
+        if (rc != 0)
+        {
+            throw new FutharkException(rc, GetError() ?? "");
+        }
+    }
+
+    /// <summary>Wait for all pending operations to finish</summary>
+    public void Sync()
+    {
+        Check(futhark_context_sync(handle));
+    }
+
+    internal void MaybeSync()
+    {
+        if (AutoSync)
+        {
+            Sync();
+        }
+    }
+
+    /// <summary>Free any cached memory</summary>
+    public void ClearCaches()
+    {
+        Check(futhark_context_clear_caches(handle));
+    }
+
+    /// <summary>Pause profiling</summary>
+    public void PauseProfiling()
+    {
+        futhark_context_pause_profiling(handle);
+    }
+
+    /// <summary>Resume profiling</summary>
+    public void UnpauseProfiling()
+    {
+        futhark_context_unpause_profiling(handle);
+    }
+
+    /// <summary>Get the last error message</summary>
+    public string? GetError()
+    {
+        return TakeString(futhark_context_get_error(handle));
+    }
+
+    /// <summary>Get the profiling report</summary>
+    public string? Report()
+    {
+        return TakeString(futhark_context_report(handle));
+    }
+
+    private static string? TakeString(IntPtr s)
+    {
+        if (s == IntPtr.Zero)
+        {
+            return null;
+        }
+        string? result = Marshal.PtrToStringUTF8(s);
+        // Futhark allocates strings using `malloc`, `NativeMemory.Free` calls the matching `free`
+        unsafe { NativeMemory.Free((void*)s); }
+        return result;
+    }
+}
+
+public sealed partial class Context
+{
+    [DllImport(Native.Lib)]
+    private static extern int futhark_entry_add(ContextHandle ctx, out int out0, int input0, int input1);
+
+    /// <summary>Entry point: add</summary>
+    public int Add(int input0, int input1)
+    {
+        Check(futhark_entry_add(handle, out int out0, input0, input1));
+        int result = out0;
+        MaybeSync();
+        return result;
+    }
+}
+
+public sealed partial class Context
+{
+    [DllImport(Native.Lib)]
+    private static extern int futhark_entry_scalars(ContextHandle ctx, out sbyte out0, out short out1, out int out2, out long out3, out byte out4, out ushort out5, out uint out6, out ulong out7, out ushort out8, out float out9, out double out10, [MarshalAs(UnmanagedType.U1)] out bool out11, sbyte input0, short input1, int input2, long input3, byte input4, ushort input5, uint input6, ulong input7, ushort input8, float input9, double input10, [MarshalAs(UnmanagedType.U1)] bool input11);
+
+    /// <summary>Entry point: scalars</summary>
+    public (sbyte, short, int, long, byte, ushort, uint, ulong, ushort, float, double, bool) Scalars(sbyte input0, short input1, int input2, long input3, byte input4, ushort input5, uint input6, ulong input7, ushort input8, float input9, double input10, bool input11)
+    {
+        Check(futhark_entry_scalars(handle, out sbyte out0, out short out1, out int out2, out long out3, out byte out4, out ushort out5, out uint out6, out ulong out7, out ushort out8, out float out9, out double out10, out bool out11, input0, input1, input2, input3, input4, input5, input6, input7, input8, input9, input10, input11));
+        (sbyte, short, int, long, byte, ushort, uint, ulong, ushort, float, double, bool) result = (out0, out1, out2, out3, out4, out5, out6, out7, out8, out9, out10, out11);
+        MaybeSync();
+        return result;
+    }
+}
+
@@ -0,0 +1,244 @@
+// Code generated by futhark-bindgen. DO NOT EDIT.
+
+package scalars
+
+/*
+#cgo LDFLAGS: -lm
+#include <stdlib.h>
+#include "scalars.h"
+*/
+import "C"
+
+import (
+	"errors"
+	"fmt"
+	"runtime"
+	"unsafe"
+)
+
+var (
+	// ErrNullPtr is returned when a Futhark function returns a NULL pointer
+	ErrNullPtr = errors.New("futhark: NULL pointer encountered")
+
+	// ErrInvalidShape is returned when data doesn't match the shape of an array
+	ErrInvalidShape = errors.New("futhark: invalid shape")
+
+	// ErrFreed is returned when a value is used after it has been freed
+	ErrFreed = errors.New("futhark: use after free")
+)
+
+// Error is returned when a Futhark function returns a non-zero error code
+type Error struct {
+	Code    int
+	Message string
+}
+
+func (e *Error) Error() string {
+	return fmt.Sprintf("futhark: error code %d: %s", e.Code, e.Message)
+}
+
+// Options used to configure a Context
+type Options struct {
+	Debug      bool
+	Profile    bool
+	Logging    bool
+	CacheFile  string
+	NoAutoSync bool
+
+}
+
+// Context is a Futhark context, it is freed by Close or when it is garbage collected
+type Context struct {
+	config    *C.struct_futhark_context_config
+	handle    *C.struct_futhark_context
+	autoSync  bool
+	cacheFile *C.char
+}
+
+func boolToInt(b bool) C.int {
+	if b {
+		return 1
+	}
+	return 0
+}
+
+// NewContext creates a new context, options may be nil
+func NewContext(options *Options) (*Context, error) {
+	if options == nil {
+		options = &Options{}
+	}
+	config := C.futhark_context_config_new()
+	if config == nil {
+		return nil, ErrNullPtr
+	}
+	C.futhark_context_config_set_debugging(config, boolToInt(options.Debug))
+	C.futhark_context_config_set_profiling(config, boolToInt(options.Profile))
+	C.futhark_context_config_set_logging(config, boolToInt(options.Logging))
+	var cacheFile *C.char
+	if options.CacheFile != "" {
+		cacheFile = C.CString(options.CacheFile)
+		C.futhark_context_config_set_cache_file(config, cacheFile)
+	}
+
+	handle := C.futhark_context_new(config)
+	if handle == nil {
+		C.futhark_context_config_free(config)
+		C.free(unsafe.Pointer(cacheFile))
+		return nil, ErrNullPtr
+	}
+	ctx := &Context{config: config, handle: handle, autoSync: !options.NoAutoSync, cacheFile: cacheFile}
+	runtime.SetFinalizer(ctx, (*Context).Close)
+	return ctx, nil
+}
+
+// Close frees the context, values created using the context can no longer be used
+func (ctx *Context) Close() {
+	if ctx.handle == nil {
+		return
+	}
+	C.futhark_context_sync(ctx.handle)
+	C.futhark_context_free(ctx.handle)
+	C.futhark_context_config_free(ctx.config)
+	C.free(unsafe.Pointer(ctx.cacheFile))
+	ctx.handle = nil
+	ctx.cacheFile = nil
+	runtime.SetFinalizer(ctx, nil)
+}
+
+func (ctx *Context) error(rc C.int) error {
+	msg, _ := ctx.GetError()
+	return &Error{Code: int(rc), Message: msg}
+}
+
+func (ctx *Context) check() error {
+	if ctx.handle == nil {
+		return ErrFreed
+	}
+	return nil
+}
+
+// Sync waits for all pending operations to finish
+func (ctx *Context) Sync() error {
+	if err := ctx.check(); err != nil {
+		return err
+	}
+	if rc := C.futhark_context_sync(ctx.handle); rc != 0 {
+		return ctx.error(rc)
+	}
+	return nil
+}
+
+func (ctx *Context) maybeSync() error {
+	if ctx.autoSync {
+		return ctx.Sync()
+	}
+	return nil
+}
+
+// ClearCaches frees any cached memory
+func (ctx *Context) ClearCaches() error {
+	if err := ctx.check(); err != nil {
+		return err
+	}
+	if rc := C.futhark_context_clear_caches(ctx.handle); rc != 0 {
+		return ctx.error(rc)
+	}
+	return nil
+}
+
+// PauseProfiling pauses profiling
+func (ctx *Context) PauseProfiling() error {
+	if err := ctx.check(); err != nil {
+		return err
+	}
+	C.futhark_context_pause_profiling(ctx.handle)
+	return nil
+}
+
+// UnpauseProfiling resumes profiling
+func (ctx *Context) UnpauseProfiling() error {
+	if err := ctx.check(); err != nil {
+		return err
+	}
+	C.futhark_context_unpause_profiling(ctx.handle)
+	return nil
+}
+
+func takeString(s *C.char) string {
+	if s == nil {
+		return ""
+	}
+	defer C.free(unsafe.Pointer(s))
+	return C.GoString(s)
+}
+
+// GetError returns the last error message
+func (ctx *Context) GetError() (string, error) {
+	if err := ctx.check(); err != nil {
+		return "", err
+	}
+	return takeString(C.futhark_context_get_error(ctx.handle)), nil
+}
+
+// Report returns the profiling report
+func (ctx *Context) Report() (string, error) {
+	if err := ctx.check(); err != nil {
+		return "", err
+	}
+	return takeString(C.futhark_context_report(ctx.handle)), nil
+}
+
+// Add calls the add entry point
+func (ctx *Context) Add(input0 int32, input1 int32) (out0 int32, err error) {
+	if err = ctx.check(); err != nil {
+		return
+	}
+	var cout0 C.int32_t
+	rc := C.futhark_entry_add(ctx.handle, &cout0, C.int32_t(input0), C.int32_t(input1))
+	if rc != 0 {
+		err = ctx.error(rc)
+		return
+	}
+	out0 = int32(cout0)
+	err = ctx.maybeSync()
+	return
+}
+
+// Scalars calls the scalars entry point
+func (ctx *Context) Scalars(input0 int8, input1 int16, input2 int32, input3 int64, input4 uint8, input5 uint16, input6 uint32, input7 uint64, input8 uint16, input9 float32, input10 float64, input11 bool) (out0 int8, out1 int16, out2 int32, out3 int64, out4 uint8, out5 uint16, out6 uint32, out7 uint64, out8 uint16, out9 float32, out10 float64, out11 bool, err error) {
+	if err = ctx.check(); err != nil {
+		return
+	}
+	var cout0 C.int8_t
+	var cout1 C.int16_t
+	var cout2 C.int32_t
+	var cout3 C.int64_t
+	var cout4 C.uint8_t
+	var cout5 C.uint16_t
+	var cout6 C.uint32_t
+	var cout7 C.uint64_t
+	var cout8 C.uint16_t
+	var cout9 C.float
+	var cout10 C.double
+	var cout11 C.bool
+	rc := C.futhark_entry_scalars(ctx.handle, &cout0, &cout1, &cout2, &cout3, &cout4, &cout5, &cout6, &cout7, &cout8, &cout9, &cout10, &cout11, C.int8_t(input0), C.int16_t(input1), C.int32_t(input2), C.int64_t(input3), C.uint8_t(input4), C.uint16_t(input5), C.uint32_t(input6), C.uint64_t(input7), C.uint16_t(input8), C.float(input9), C.double(input10), C.bool(input11))
+	if rc != 0 {
+		err = ctx.error(rc)
+		return
+	}
+	out0 = int8(cout0)
+	out1 = int16(cout1)
+	out2 = int32(cout2)
+	out3 = int64(cout3)
+	out4 = uint8(cout4)
+	out5 = uint16(cout5)
+	out6 = uint32(cout6)
+	out7 = uint64(cout7)
+	out8 = uint16(cout8)
+	out9 = float32(cout9)
+	out10 = float64(cout10)
+	out11 = bool(cout11)
+	err = ctx.maybeSync()
+	return
+}
+
@@ -0,0 +1,216 @@
+-- Generated by futhark-bindgen
+
+{-# LANGUAGE ForeignFunctionInterface #-}
+{-# OPTIONS_GHC -Wno-unused-top-binds #-}
+
+module Scalars where
+
+import Control.Exception (Exception, throwIO)
+import Control.Monad (when)
+import Data.IORef
+import Data.Int
+import Data.Word
+import qualified Data.Vector.Storable as V
+import qualified Data.Vector.Storable.Mutable as MV
+import Foreign
+import Foreign.C.String
+import Foreign.C.Types
+import qualified Foreign.Concurrent as FC
+import qualified Prelude as P
+import Prelude (Bool (..), Double, Float, IO, Int, Maybe (..), Show, String, return, ($), (.), (/=), (==), (>>=), (<$>))
+
+data FutharkContextConfig
+
+data FutharkContext
+
+foreign import ccall unsafe "futhark_context_config_new" futhark_context_config_new :: IO (Ptr FutharkContextConfig)
+foreign import ccall unsafe "futhark_context_config_free" futhark_context_config_free :: Ptr FutharkContextConfig -> IO ()
+foreign import ccall unsafe "futhark_context_config_set_debugging" futhark_context_config_set_debugging :: Ptr FutharkContextConfig -> CInt -> IO ()
+foreign import ccall unsafe "futhark_context_config_set_profiling" futhark_context_config_set_profiling :: Ptr FutharkContextConfig -> CInt -> IO ()
+foreign import ccall unsafe "futhark_context_config_set_logging" futhark_context_config_set_logging :: Ptr FutharkContextConfig -> CInt -> IO ()
+foreign import ccall unsafe "futhark_context_config_set_cache_file" futhark_context_config_set_cache_file :: Ptr FutharkContextConfig -> CString -> IO ()
+
+foreign import ccall safe "futhark_context_new" futhark_context_new :: Ptr FutharkContextConfig -> IO (Ptr FutharkContext)
+foreign import ccall safe "futhark_context_free" futhark_context_free :: Ptr FutharkContext -> IO ()
+foreign import ccall safe "futhark_context_sync" futhark_context_sync :: Ptr FutharkContext -> IO CInt
+foreign import ccall safe "futhark_context_clear_caches" futhark_context_clear_caches :: Ptr FutharkContext -> IO CInt
+foreign import ccall unsafe "futhark_context_pause_profiling" futhark_context_pause_profiling :: Ptr FutharkContext -> IO ()
+foreign import ccall unsafe "futhark_context_unpause_profiling" futhark_context_unpause_profiling :: Ptr FutharkContext -> IO ()
+foreign import ccall unsafe "futhark_context_get_error" futhark_context_get_error :: Ptr FutharkContext -> IO CString
+foreign import ccall unsafe "futhark_context_report" futhark_context_report :: Ptr FutharkContext -> IO CString
+
+-- | Errors thrown by the generated functions
+data FutharkException
+  = -- | A Futhark function returned a non-zero error code
+    FutharkError Int String
+  | -- | A NULL pointer was returned
+    NullPtr
+  | -- | The data doesn't match the shape of an array, expected and actual number of elements
+    InvalidShape Int Int
+  | -- | A value was used after it has been freed
+    UseAfterFree
+  deriving (Show)
+
+instance Exception FutharkException
+
+-- | Context options
+data Options = Options
+  { optDebug :: Bool,
+    optProfile :: Bool,
+    optLogging :: Bool,
+    optCacheFile :: Maybe String,
+    optAutoSync :: Bool
+  }
+
+-- | Default context options
+defaultOptions :: Options
+defaultOptions =
+  Options
+    { optDebug = False,
+      optProfile = False,
+      optLogging = False,
+      optCacheFile = Nothing,
+      optAutoSync = True
+    }
+
+-- | Futhark context, freed by 'freeContext' or when it is garbage collected
+data Context = Context
+  { contextPtr :: ForeignPtr FutharkContext,
+    contextAlive :: IORef Bool,
+    contextAutoSync :: Bool
+  }
+
+-- | Create a new context
+newContext :: Options -> IO Context
+newContext opts = do
+  config <- futhark_context_config_new
+  when (config == nullPtr) $ throwIO NullPtr
+  futhark_context_config_set_debugging config (fromBool (optDebug opts))
+  futhark_context_config_set_profiling config (fromBool (optProfile opts))
+  futhark_context_config_set_logging config (fromBool (optLogging opts))
+  cacheFile <- P.maybe (return nullPtr) newCString (optCacheFile opts)
+  when (cacheFile /= nullPtr) $ futhark_context_config_set_cache_file config cacheFile
+
+  ptr <- futhark_context_new config
+  when (ptr == nullPtr) $ do
+    futhark_context_config_free config
+    free cacheFile
+    throwIO NullPtr
+  alive <- newIORef True
+  fp <- FC.newForeignPtr ptr $ do
+    writeIORef alive False
+    _ <- futhark_context_sync ptr
+    futhark_context_free ptr
+    futhark_context_config_free config
+    free cacheFile
+  return (Context fp alive (optAutoSync opts))
+
+-- | Free the context, values created using the context can no longer be used
+freeContext :: Context -> IO ()
+freeContext = finalizeForeignPtr . contextPtr
+
+-- | Use the raw context pointer
+withContext :: Context -> (Ptr FutharkContext -> IO a) -> IO a
+withContext ctx f = do
+  alive <- readIORef (contextAlive ctx)
+  when (P.not alive) $ throwIO UseAfterFree
+  withForeignPtr (contextPtr ctx) f
+
+-- | Free a value using `f` unless the context has already been freed
+freeWith :: Context -> (Ptr FutharkContext -> IO CInt) -> IO ()
+freeWith ctx f = do
+  alive <- readIORef (contextAlive ctx)
+  when alive $ withForeignPtr (contextPtr ctx) $ \c -> do
+    _ <- f c
+    return ()
+
+takeString :: CString -> IO (Maybe String)
+takeString s
+  | s == nullPtr = return Nothing
+  | P.otherwise = do
+      x <- peekCString s
+      free s
+      return (Just x)
+
+-- | Get the last error message
+getError :: Context -> IO (Maybe String)
+getError ctx = withContext ctx $ \c -> futhark_context_get_error c >>= takeString
+
+-- | Get the profiling report
+report :: Context -> IO (Maybe String)
+report ctx = withContext ctx $ \c -> futhark_context_report c >>= takeString
+
+-- | Throw a 'FutharkError' if `rc` is non-zero
+check :: Context -> CInt -> IO ()
+check ctx rc = when (rc /= 0) $ do
+  msg <- getError ctx
+  throwIO (FutharkError (P.fromIntegral rc) (P.maybe "" P.id msg))
+
+-- | Wait for all pending operations to finish
+sync :: Context -> IO ()
+sync ctx = withContext ctx futhark_context_sync >>= check ctx
+
+-- | Sync if auto sync is enabled
+autoSync :: Context -> IO ()
+autoSync ctx = when (contextAutoSync ctx) (sync ctx)
+
+-- | Free any cached memory
+clearCaches :: Context -> IO ()
+clearCaches ctx = withContext ctx futhark_context_clear_caches >>= check ctx
+
+-- | Pause profiling
+pauseProfiling :: Context -> IO ()
+pauseProfiling ctx = withContext ctx futhark_context_pause_profiling
+
+-- | Resume profiling
+unpauseProfiling :: Context -> IO ()
+unpauseProfiling ctx = withContext ctx futhark_context_unpause_profiling
+
+foreign import ccall safe "futhark_entry_add" futhark_entry_add :: Ptr FutharkContext -> Ptr Int32 -> Int32 -> Int32 -> IO CInt
+
+-- | Entry point: add
+add :: Context -> Int32 -> Int32 -> IO Int32
+add ctx input0 input1 =
+  withContext ctx $ \c ->
+  alloca $ \out0 -> do
+    rc <- futhark_entry_add c out0 input0 input1
+    check ctx rc
+    autoSync ctx
+    r0 <- peek out0
+    return r0
+
+foreign import ccall safe "futhark_entry_scalars" futhark_entry_scalars :: Ptr FutharkContext -> Ptr Int8 -> Ptr Int16 -> Ptr Int32 -> Ptr Int64 -> Ptr Word8 -> Ptr Word16 -> Ptr Word32 -> Ptr Word64 -> Ptr Word16 -> Ptr Float -> Ptr Double -> Ptr CBool -> Int8 -> Int16 -> Int32 -> Int64 -> Word8 -> Word16 -> Word32 -> Word64 -> Word16 -> Float -> Double -> CBool -> IO CInt
+
+-- | Entry point: scalars
+scalars :: Context -> Int8 -> Int16 -> Int32 -> Int64 -> Word8 -> Word16 -> Word32 -> Word64 -> Word16 -> Float -> Double -> Bool -> IO (Int8, Int16, Int32, Int64, Word8, Word16, Word32, Word64, Word16, Float, Double, Bool)
+scalars ctx input0 input1 input2 input3 input4 input5 input6 input7 input8 input9 input10 input11 =
+  withContext ctx $ \c ->
+  alloca $ \out0 ->
+  alloca $ \out1 ->
+  alloca $ \out2 ->
+  alloca $ \out3 ->
+  alloca $ \out4 ->
+  alloca $ \out5 ->
+  alloca $ \out6 ->
+  alloca $ \out7 ->
+  alloca $ \out8 ->
+  alloca $ \out9 ->
+  alloca $ \out10 ->
+  alloca $ \out11 -> do
+    rc <- futhark_entry_scalars c out0 out1 out2 out3 out4 out5 out6 out7 out8 out9 out10 out11 input0 input1 input2 input3 input4 input5 input6 input7 input8 input9 input10 (fromBool input11)
+    check ctx rc
+    autoSync ctx
+    r0 <- peek out0
+    r1 <- peek out1
+    r2 <- peek out2
+    r3 <- peek out3
+    r4 <- peek out4
+    r5 <- peek out5
+    r6 <- peek out6
+    r7 <- peek out7
+    r8 <- peek out8
+    r9 <- peek out9
+    r10 <- peek out10
+    r11 <- toBool <$> peek out11
+    return (r0, r1, r2, r3, r4, r5, r6, r7, r8, r9, r10, r11)
+
@@ -0,0 +1,378 @@
+// Generated by futhark-bindgen
+
+import java.lang.foreign.Arena;
+import java.lang.foreign.FunctionDescriptor;
+import java.lang.foreign.Linker;
+import java.lang.foreign.MemorySegment;
+import java.lang.foreign.SymbolLookup;
+import java.lang.invoke.MethodHandle;
+import java.lang.ref.Cleaner;
+import java.lang.ref.Reference;
+import java.util.Objects;
+
+import static java.lang.foreign.ValueLayout.*;
+
+/** Bindings for the Futhark library {@code scalars}, using the Foreign Function and Memory API */
+public final class scalars {
+    private scalars() {
+    }
+
+    // Loaded from the path in the `futhark.lib.scalars` system property, or `libscalars.so`
+    private static final SymbolLookup LIB = SymbolLookup.libraryLookup(
+        System.getProperty("futhark.lib.scalars", System.mapLibraryName("scalars")), Arena.global());
+    private static final Linker LINKER = Linker.nativeLinker();
+    private static final Cleaner CLEANER = Cleaner.create();
+    private static final MethodHandle FREE =
+        LINKER.downcallHandle(LINKER.defaultLookup().find("free").orElseThrow(), FunctionDescriptor.ofVoid(ADDRESS));
+
+    static MethodHandle function(String name, FunctionDescriptor desc) {
+        MemorySegment f = LIB.find(name).orElseThrow(() -> new UnsatisfiedLinkError("Missing symbol: " + name));
+        return LINKER.downcallHandle(f, desc);
+    }
+
+    static Object call(MethodHandle f, Object... args) {
+        try {
+            return f.invokeWithArguments(args);
+        } catch (RuntimeException | Error e) {
+            throw e;
+        } catch (Throwable e) {
+            throw new RuntimeException(e);
+        }
+    }
+
+    static String takeString(MemorySegment s) {
+        if (s.equals(MemorySegment.NULL)) {
+            return null;
+        }
+        String result = s.reinterpret(Long.MAX_VALUE).getString(0);
+        call(FREE, s);
+        return result;
+    }
+
+    static long product(long[] dims) {
+        long size = 1;
+        for (long d : dims) {
+            size *= d;
+        }
+        return size;
+    }
+
+    // `boolean` arrays can't be copied to and from memory segments directly
+    static MemorySegment toSegment(Arena arena, boolean[] data) {
+        MemorySegment out = arena.allocate(JAVA_BOOLEAN, data.length);
+        for (int i = 0; i < data.length; i++) {
+            out.setAtIndex(JAVA_BOOLEAN, i, data[i]);
+        }
+        return out;
+    }
+
+    static boolean[] toBooleans(MemorySegment data) {
+        boolean[] out = new boolean[(int) data.byteSize()];
+        for (int i = 0; i < out.length; i++) {
+            out[i] = data.getAtIndex(JAVA_BOOLEAN, i);
+        }
+        return out;
+    }
+
+    /** Thrown when a Futhark function returns a non-zero error code */
+    public static class FutharkException extends RuntimeException {
+        private final int code;
+
+        public FutharkException(int code, String message) {
+            super("Futhark error code " + code + ": " + message);
+            this.code = code;
+        }
+
+        public int code() {
+            return code;
+        }
+    }
+
+    /** Thrown when a Futhark function returns a NULL pointer */
+    public static class NullPtrException extends RuntimeException {
+        public NullPtrException() {
+            super("NULL pointer encountered");
+        }
+    }
+
+    /** Options used to configure a {@link Context} */
+    public static final class Options {
+        boolean debug;
+        boolean profile;
+        boolean logging;
+        String cacheFile;
+        boolean autoSync = true;
+
+        /** Enable debugging */
+        public Options debug(boolean debug) {
+            this.debug = debug;
+            return this;
+        }
+
+        /** Enable profiling */
+        public Options profile(boolean profile) {
+            this.profile = profile;
+            return this;
+        }
+
+        /** Enable logging */
+        public Options logging(boolean logging) {
+            this.logging = logging;
+            return this;
+        }
+
+        /** Set the Futhark cache file */
+        public Options cacheFile(String cacheFile) {
+            this.cacheFile = cacheFile;
+            return this;
+        }
+
+        /** Sync after every operation, enabled by default */
+        public Options autoSync(boolean autoSync) {
+            this.autoSync = autoSync;
+            return this;
+        }
+    }
+
+    // Shared by a context and the values created using it, the context is freed once all of
+    // them have been closed
+    static final class ContextState {
+        final MemorySegment config;
+        final MemorySegment handle;
+        final Arena arena;
+        private int refs = 1;
+
+        ContextState(MemorySegment config, MemorySegment handle, Arena arena) {
+            this.config = config;
+            this.handle = handle;
+            this.arena = arena;
+        }
+
+        synchronized void retain() {
+            if (refs == 0) {
+                throw new IllegalStateException("Context has already been freed");
+            }
+            refs++;
+        }
+
+        synchronized void release() {
+            if (--refs == 0) {
+                call(Context.FUTHARK_CONTEXT_SYNC, handle);
+                call(Context.FUTHARK_CONTEXT_FREE, handle);
+                call(Context.FUTHARK_CONTEXT_CONFIG_FREE, config);
+                arena.close();
+            }
+        }
+    }
+
+    /** Base class of arrays and opaque values, freed by {@link #close} or when they are garbage collected */
+    public abstract static class FutharkValue implements AutoCloseable {
+        final Context ctx;
+        private final MemorySegment ptr;
+        private final Cleaner.Cleanable cleanable;
+        private volatile boolean closed;
+
+        FutharkValue(Context ctx, MemorySegment ptr, MethodHandle free) {
+            if (ptr.equals(MemorySegment.NULL)) {
+                throw new NullPtrException();
+            }
+            ContextState state = ctx.state;
+            state.retain();
+            this.ctx = ctx;
+            this.ptr = ptr;
+            this.cleanable = CLEANER.register(this, () -> {
+                call(free, state.handle, ptr);
+                state.release();
+            });
+        }
+
+        MemorySegment ptr() {
+            if (closed) {
+                throw new IllegalStateException("Value has already been closed");
+            }
+            return ptr;
+        }
+
+        /** Free the value */
+        @Override
+        public void close() {
+            closed = true;
+            cleanable.clean();
+        }
+    }
+
+    /** Futhark context, values created using the context keep it alive until they are closed */
+    public static final class Context implements AutoCloseable {
+        static final MethodHandle FUTHARK_CONTEXT_CONFIG_NEW =
+            function("futhark_context_config_new", FunctionDescriptor.of(ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_CONFIG_FREE =
+            function("futhark_context_config_free", FunctionDescriptor.ofVoid(ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_CONFIG_SET_DEBUGGING =
+            function("futhark_context_config_set_debugging", FunctionDescriptor.ofVoid(ADDRESS, JAVA_INT));
+        static final MethodHandle FUTHARK_CONTEXT_CONFIG_SET_PROFILING =
+            function("futhark_context_config_set_profiling", FunctionDescriptor.ofVoid(ADDRESS, JAVA_INT));
+        static final MethodHandle FUTHARK_CONTEXT_CONFIG_SET_LOGGING =
+            function("futhark_context_config_set_logging", FunctionDescriptor.ofVoid(ADDRESS, JAVA_INT));
+        static final MethodHandle FUTHARK_CONTEXT_CONFIG_SET_CACHE_FILE =
+            function("futhark_context_config_set_cache_file", FunctionDescriptor.ofVoid(ADDRESS, ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_NEW =
+            function("futhark_context_new", FunctionDescriptor.of(ADDRESS, ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_FREE =
+            function("futhark_context_free", FunctionDescriptor.ofVoid(ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_SYNC =
+            function("futhark_context_sync", FunctionDescriptor.of(JAVA_INT, ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_CLEAR_CACHES =
+            function("futhark_context_clear_caches", FunctionDescriptor.of(JAVA_INT, ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_PAUSE_PROFILING =
+            function("futhark_context_pause_profiling", FunctionDescriptor.ofVoid(ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_UNPAUSE_PROFILING =
+            function("futhark_context_unpause_profiling", FunctionDescriptor.ofVoid(ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_GET_ERROR =
+            function("futhark_context_get_error", FunctionDescriptor.of(ADDRESS, ADDRESS));
+        static final MethodHandle FUTHARK_CONTEXT_REPORT =
+            function("futhark_context_report", FunctionDescriptor.of(ADDRESS, ADDRESS));
+
+        final ContextState state;
+        private final Cleaner.Cleanable cleanable;
+        private volatile boolean closed;
+        private volatile boolean autoSync;
+
+        /** Create a new context using the default options */
+        public Context() {
+            this(new Options());
+        }
+
+        /** Create a new context */
+        public Context(Options options) {
+            MemorySegment config = (MemorySegment) call(FUTHARK_CONTEXT_CONFIG_NEW);
+            if (config.equals(MemorySegment.NULL)) {
+                throw new NullPtrException();
+            }
+            call(FUTHARK_CONTEXT_CONFIG_SET_DEBUGGING, config, options.debug ? 1 : 0);
+            call(FUTHARK_CONTEXT_CONFIG_SET_PROFILING, config, options.profile ? 1 : 0);
+            call(FUTHARK_CONTEXT_CONFIG_SET_LOGGING, config, options.logging ? 1 : 0);
+            // Strings passed to the config are kept alive until the context is freed
+            Arena arena = Arena.ofShared();
+            if (options.cacheFile != null) {
+                call(FUTHARK_CONTEXT_CONFIG_SET_CACHE_FILE, config, arena.allocateFrom(options.cacheFile));
+            }
+
+            MemorySegment handle = (MemorySegment) call(FUTHARK_CONTEXT_NEW, config);
+            if (handle.equals(MemorySegment.NULL)) {
+                call(FUTHARK_CONTEXT_CONFIG_FREE, config);
+                arena.close();
+                throw new NullPtrException();
+            }
+            ContextState state = new ContextState(config, handle, arena);
+            this.state = state;
+            this.cleanable = CLEANER.register(this, state::release);
+            this.autoSync = options.autoSync;
+        }
+
+        MemorySegment handle() {
+            if (closed) {
+                throw new IllegalStateException("Context has already been closed");
+            }
+            return state.handle;
+        }
+
+        /** Free the context once all values created using it have been closed */
+        @Override
+        public void close() {
+            closed = true;
+            cleanable.clean();
+        }
+
+        void check(int rc) {
+            if (rc != 0) {
+                throw new FutharkException(rc, Objects.requireNonNullElse(getError(), ""));
+            }
+        }
+
+        void maybeSync() {
+            if (autoSync) {
+                sync();
+            }
+        }
+
+        /** Sync after every operation */
+        public void setAutoSync(boolean autoSync) {
+            this.autoSync = autoSync;
+        }
+
+        /** Wait for all pending operations to finish */
+        public void sync() {
+            check((int) call(FUTHARK_CONTEXT_SYNC, handle()));
+        }
+
+        /** Free any cached memory */
+        public void clearCaches() {
+            check((int) call(FUTHARK_CONTEXT_CLEAR_CACHES, handle()));
+        }
+
+        /** Pause profiling */
+        public void pauseProfiling() {
+            call(FUTHARK_CONTEXT_PAUSE_PROFILING, handle());
+        }
+
+        /** Resume profiling */
+        public void unpauseProfiling() {
+            call(FUTHARK_CONTEXT_UNPAUSE_PROFILING, handle());
+        }
+
+        /** Get the last error message */
+        public String getError() {
+            return takeString((MemorySegment) call(FUTHARK_CONTEXT_GET_ERROR, handle()));
+        }
+
+        /** Get the profiling report */
+        public String report() {
+            return takeString((MemorySegment) call(FUTHARK_CONTEXT_REPORT, handle()));
+        }
+
+        private static final MethodHandle FUTHARK_ENTRY_ADD =
+            function("futhark_entry_add", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, JAVA_INT, JAVA_INT));
+
+        /** Entry point: add */
+        public int add(int input0, int input1) {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out0 = arena.allocate(JAVA_INT);
+                int rc = (int) call(FUTHARK_ENTRY_ADD, handle(), out0, input0, input1);
+                check(rc);
+                int result = out0.get(JAVA_INT, 0);
+                maybeSync();
+                return result;
+            }
+        }
+
+        /** Outputs of the scalars entry point */
+        public record ScalarsResult(byte out0, short out1, int out2, long out3, byte out4, short out5, int out6, long out7, short out8, float out9, double out10, boolean out11) {
+        }
+
+        private static final MethodHandle FUTHARK_ENTRY_SCALARS =
+            function("futhark_entry_scalars", FunctionDescriptor.of(JAVA_INT, ADDRESS, ADDRESS, ADDRESS, ADDRESS, ADDRESS, ADDRESS, ADDRESS, ADDRESS, ADDRESS, ADDRESS, ADDRESS, ADDRESS, ADDRESS, JAVA_BYTE, JAVA_SHORT, JAVA_INT, JAVA_LONG, JAVA_BYTE, JAVA_SHORT, JAVA_INT, JAVA_LONG, JAVA_SHORT, JAVA_FLOAT, JAVA_DOUBLE, JAVA_BOOLEAN));
+
+        /** Entry point: scalars */
+        public ScalarsResult scalars(byte input0, short input1, int input2, long input3, byte input4, short input5, int input6, long input7, short input8, float input9, double input10, boolean input11) {
+            try (Arena arena = Arena.ofConfined()) {
+                MemorySegment out0 = arena.allocate(JAVA_BYTE);
+                MemorySegment out1 = arena.allocate(JAVA_SHORT);
+                MemorySegment out2 = arena.allocate(JAVA_INT);
+                MemorySegment out3 = arena.allocate(JAVA_LONG);
+                MemorySegment out4 = arena.allocate(JAVA_BYTE);
+                MemorySegment out5 = arena.allocate(JAVA_SHORT);
+                MemorySegment out6 = arena.allocate(JAVA_INT);
+                MemorySegment out7 = arena.allocate(JAVA_LONG);
+                MemorySegment out8 = arena.allocate(JAVA_SHORT);
+                MemorySegment out9 = arena.allocate(JAVA_FLOAT);
+                MemorySegment out10 = arena.allocate(JAVA_DOUBLE);
+                MemorySegment out11 = arena.allocate(JAVA_BOOLEAN);
+                int rc = (int) call(FUTHARK_ENTRY_SCALARS, handle(), out0, out1, out2, out3, out4, out5, out6, out7, out8, out9, out10, out11, input0, input1, input2, input3, input4, input5, input6, input7, input8, input9, input10, input11);
+                check(rc);
+                ScalarsResult result = new ScalarsResult(out0.get(JAVA_BYTE, 0), out1.get(JAVA_SHORT, 0), out2.get(JAVA_INT, 0), out3.get(JAVA_LONG, 0), out4.get(JAVA_BYTE, 0), out5.get(JAVA_SHORT, 0), out6.get(JAVA_INT, 0), out7.get(JAVA_LONG, 0), out8.get(JAVA_SHORT, 0), out9.get(JAVA_FLOAT, 0), out10.get(JAVA_DOUBLE, 0), out11.get(JAVA_BOOLEAN, 0));
+                maybeSync();
+                return result;
+            }
+        }
+    }
+}
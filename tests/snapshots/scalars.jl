@@ -0,0 +1,158 @@
+# Generated by futhark-bindgen
+
+module Scalars
+
+export Context, FutharkError, NullPtrError, InvalidShapeError, free!, sync!
+
+# Path to the shared library containing the compiled Futhark code
+const LIB = get(ENV, "FUTHARK_LIB_SCALARS", joinpath(@__DIR__, "libscalars.so"))
+
+"""
+Returned when a Futhark function returns a non-zero error code
+"""
+struct FutharkError <: Exception
+    code::Cint
+    message::String
+end
+
+Base.showerror(io::IO, e::FutharkError) = print(io, "Futhark error code $(e.code): $(e.message)")
+
+"""
+Returned when a Futhark function returns a NULL pointer
+"""
+struct NullPtrError <: Exception end
+
+"""
+Returned when data doesn't match the shape of an array
+"""
+struct InvalidShapeError <: Exception
+    expected::Int
+    actual::Int
+end
+
+"""
+Futhark context, freed by `free!` or when it is garbage collected
+"""
+mutable struct Context
+    config::Ptr{Cvoid}
+    handle::Ptr{Cvoid}
+    auto_sync::Bool
+
+    function Context(; debug::Bool=false, profile::Bool=false, logging::Bool=false,
+                     cache_file::Union{String,Nothing}=nothing, auto_sync::Bool=true)
+        config = ccall((:futhark_context_config_new, LIB), Ptr{Cvoid}, ())
+        config == C_NULL && throw(NullPtrError())
+        ccall((:futhark_context_config_set_debugging, LIB), Cvoid, (Ptr{Cvoid}, Cint), config, debug)
+        ccall((:futhark_context_config_set_profiling, LIB), Cvoid, (Ptr{Cvoid}, Cint), config, profile)
+        ccall((:futhark_context_config_set_logging, LIB), Cvoid, (Ptr{Cvoid}, Cint), config, logging)
+        if cache_file !== nothing
+            ccall((:futhark_context_config_set_cache_file, LIB), Cvoid, (Ptr{Cvoid}, Cstring), config, cache_file)
+        end
+
+        handle = ccall((:futhark_context_new, LIB), Ptr{Cvoid}, (Ptr{Cvoid},), config)
+        if handle == C_NULL
+            ccall((:futhark_context_config_free, LIB), Cvoid, (Ptr{Cvoid},), config)
+            throw(NullPtrError())
+        end
+        finalizer(free!, new(config, handle, auto_sync))
+    end
+end
+
+"""
+Free the context, values created using the context can no longer be used
+"""
+function free!(ctx::Context)
+    if ctx.handle != C_NULL
+        ccall((:futhark_context_sync, LIB), Cint, (Ptr{Cvoid},), ctx.handle)
+        ccall((:futhark_context_free, LIB), Cvoid, (Ptr{Cvoid},), ctx.handle)
+        ccall((:futhark_context_config_free, LIB), Cvoid, (Ptr{Cvoid},), ctx.config)
+        ctx.handle = C_NULL
+        ctx.config = C_NULL
+    end
+    nothing
+end
+
+function check_handle(ctx::Context)
+    ctx.handle == C_NULL && error("Context has already been freed")
+    ctx.handle
+end
+
+function take_string(ptr::Ptr{Cchar})
+    ptr == C_NULL && return nothing
+    s = unsafe_string(ptr)
+    Libc.free(ptr)
+    s
+end
+
+"""
+Get the last error message, or `nothing`
+"""
+get_error(ctx::Context) = take_string(ccall((:futhark_context_get_error, LIB), Ptr{Cchar}, (Ptr{Cvoid},), check_handle(ctx)))
+
+"""
+Get the profiling report
+"""
+report(ctx::Context) = take_string(ccall((:futhark_context_report, LIB), Ptr{Cchar}, (Ptr{Cvoid},), check_handle(ctx)))
+
+function check_rc(ctx::Context, rc::Cint)
+    if rc != 0
+        msg = get_error(ctx)
+        throw(FutharkError(rc, msg === nothing ? "" : msg))
+    end
+    nothing
+end
+
+"""
+Wait for all pending operations to finish
+"""
+sync!(ctx::Context) = check_rc(ctx, ccall((:futhark_context_sync, LIB), Cint, (Ptr{Cvoid},), check_handle(ctx)))
+
+auto_sync!(ctx::Context) = ctx.auto_sync && sync!(ctx)
+
+"""
+Free any cached memory
+"""
+clear_caches!(ctx::Context) = check_rc(ctx, ccall((:futhark_context_clear_caches, LIB), Cint, (Ptr{Cvoid},), check_handle(ctx)))
+
+pause_profiling!(ctx::Context) = ccall((:futhark_context_pause_profiling, LIB), Cvoid, (Ptr{Cvoid},), check_handle(ctx))
+
+unpause_profiling!(ctx::Context) = ccall((:futhark_context_unpause_profiling, LIB), Cvoid, (Ptr{Cvoid},), check_handle(ctx))
+
+# Futhark arrays are row-major and Julia arrays are column-major, so the order of the
+# dimensions is reversed when copying data in either direction
+row_major(a::AbstractArray{T,N}) where {T,N} = permutedims(a, ntuple(i -> N - i + 1, N))
+
+"""
+Entry point: add
+"""
+function add(ctx::Context, input0, input1)
+    out0 = Ref{Int32}()
+    rc = GC.@preserve ctx ccall((:futhark_entry_add, LIB), Cint, (Ptr{Cvoid}, Ptr{Int32}, Int32, Int32), check_handle(ctx), out0, input0, input1)
+    check_rc(ctx, rc)
+    auto_sync!(ctx)
+    out0[]
+end
+
+"""
+Entry point: scalars
+"""
+function scalars(ctx::Context, input0, input1, input2, input3, input4, input5, input6, input7, input8, input9, input10, input11)
+    out0 = Ref{Int8}()
+    out1 = Ref{Int16}()
+    out2 = Ref{Int32}()
+    out3 = Ref{Int64}()
+    out4 = Ref{UInt8}()
+    out5 = Ref{UInt16}()
+    out6 = Ref{UInt32}()
+    out7 = Ref{UInt64}()
+    out8 = Ref{Float16}()
+    out9 = Ref{Float32}()
+    out10 = Ref{Float64}()
+    out11 = Ref{Bool}()
+    rc = GC.@preserve ctx ccall((:futhark_entry_scalars, LIB), Cint, (Ptr{Cvoid}, Ptr{Int8}, Ptr{Int16}, Ptr{Int32}, Ptr{Int64}, Ptr{UInt8}, Ptr{UInt16}, Ptr{UInt32}, Ptr{UInt64}, Ptr{Float16}, Ptr{Float32}, Ptr{Float64}, Ptr{Bool}, Int8, Int16, Int32, Int64, UInt8, UInt16, UInt32, UInt64, Float16, Float32, Float64, Bool), check_handle(ctx), out0, out1, out2, out3, out4, out5, out6, out7, out8, out9, out10, out11, input0, input1, input2, input3, input4, input5, input6, input7, input8, input9, input10, input11)
+    check_rc(ctx, rc)
+    auto_sync!(ctx)
+    (out0[], out1[], out2[], out3[], out4[], out5[], out6[], out7[], out8[], out9[], out10[], out11[])
+end
+
+end # module
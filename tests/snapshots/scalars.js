@@ -0,0 +1,151 @@
+// Generated by futhark-bindgen
+
+// `module` is an instantiated Emscripten module created by `futhark wasm --library` or
+// `futhark wasm-multicore --library`, 64-bit integers are passed as `BigInt` values
+
+/** Thrown when a Futhark function returns a non-zero error code */
+export class FutharkError extends Error {
+  constructor(code, message) {
+    super(`Futhark error code ${code}: ${message}`);
+    this.code = code;
+  }
+}
+
+/** Thrown when a Futhark function returns a NULL pointer */
+export class NullPtrError extends Error {
+  constructor() {
+    super("NULL pointer encountered");
+  }
+}
+
+/** Thrown when data doesn't match the shape of an array */
+export class InvalidShapeError extends Error {
+  constructor(expected, actual) {
+    super(`Invalid shape: expected ${expected} elements, got ${actual}`);
+  }
+}
+
+// Frees values that are garbage collected without calling `free`
+const registry =
+  typeof FinalizationRegistry !== "undefined"
+    ? new FinalizationRegistry((free) => free())
+    : null;
+
+function readString(module, ptr) {
+  if (ptr === 0) return null;
+  let end = ptr;
+  while (module.HEAPU8[end] !== 0) end++;
+  const s = new TextDecoder().decode(module.HEAPU8.slice(ptr, end));
+  module._free(ptr);
+  return s;
+}
+
+/** Futhark context */
+export class Context {
+  constructor(module, options = {}) {
+    const m = module;
+    const config = m._futhark_context_config_new();
+    if (config === 0) throw new NullPtrError();
+    m._futhark_context_config_set_debugging(config, options.debug ? 1 : 0);
+    m._futhark_context_config_set_profiling(config, options.profile ? 1 : 0);
+    m._futhark_context_config_set_logging(config, options.logging ? 1 : 0);
+
+    const handle = m._futhark_context_new(config);
+    if (handle === 0) {
+      m._futhark_context_config_free(config);
+      throw new NullPtrError();
+    }
+    this.module = m;
+    this.config = config;
+    this.handle = handle;
+    this.autoSync = options.autoSync ?? true;
+  }
+
+  /** Free the context, values created using the context can no longer be used */
+  free() {
+    if (this.handle === 0) return;
+    this.module._futhark_context_sync(this.handle);
+    this.module._futhark_context_free(this.handle);
+    this.module._futhark_context_config_free(this.config);
+    this.handle = 0;
+  }
+
+  checkHandle() {
+    if (this.handle === 0) throw new Error("Context has already been freed");
+    return this.handle;
+  }
+
+  check(rc) {
+    if (rc !== 0) throw new FutharkError(rc, this.getError() ?? "");
+  }
+
+  /** Wait for all pending operations to finish */
+  sync() {
+    this.check(this.module._futhark_context_sync(this.checkHandle()));
+  }
+
+  maybeSync() {
+    if (this.autoSync) this.sync();
+  }
+
+  /** Free any cached memory */
+  clearCaches() {
+    this.check(this.module._futhark_context_clear_caches(this.checkHandle()));
+  }
+
+  /** Pause profiling */
+  pauseProfiling() {
+    this.module._futhark_context_pause_profiling(this.checkHandle());
+  }
+
+  /** Resume profiling */
+  unpauseProfiling() {
+    this.module._futhark_context_unpause_profiling(this.checkHandle());
+  }
+
+  /** Get the last error message */
+  getError() {
+    return readString(this.module, this.module._futhark_context_get_error(this.checkHandle()));
+  }
+
+  /** Get the profiling report */
+  report() {
+    return readString(this.module, this.module._futhark_context_report(this.checkHandle()));
+  }
+
+  // Allocate `size` bytes of scratch memory, pass it to `f` and free it afterwards
+  withScratch(size, f) {
+    const ptr = this.module._malloc(Math.max(size, 8));
+    if (ptr === 0) throw new NullPtrError();
+    try {
+      return f(ptr);
+    } finally {
+      this.module._free(ptr);
+    }
+  }
+
+  view(type, ptr, length) {
+    return new type(this.module.HEAPU8.buffer, ptr, length);
+  }
+}
+
+/** Entry point: add */
+Context.prototype.add = function (input0, input1) {
+  const ctx = this;
+  return ctx.withScratch(8, (out) => {
+    ctx.check(ctx.module._futhark_entry_add(ctx.checkHandle(), out, input0, input1));
+    ctx.maybeSync();
+    return ctx.view(Int32Array, out, 1)[0];
+  });
+};
+
+/** Entry point: scalars */
+Context.prototype.scalars = function (input0, input1, input2, input3, input4, input5, input6, input7, input8, input9, input10, input11) {
+  const ctx = this;
+  return ctx.withScratch(96, (out) => {
+    ctx.check(ctx.module._futhark_entry_scalars(ctx.checkHandle(), out, out + 8, out + 16, out + 24, out + 32, out + 40, out + 48, out + 56, out + 64, out + 72, out + 80, out + 88, input0, input1, input2, input3, input4, input5, input6, input7, input8, input9, input10, input11 ? 1 : 0));
+    ctx.maybeSync();
+    return [ctx.view(Int8Array, out, 1)[0], ctx.view(Int16Array, out + 8, 1)[0], ctx.view(Int32Array, out + 16, 1)[0], ctx.view(BigInt64Array, out + 24, 1)[0], ctx.view(Uint8Array, out + 32, 1)[0], ctx.view(Uint16Array, out + 40, 1)[0], ctx.view(Uint32Array, out + 48, 1)[0], ctx.view(BigUint64Array, out + 56, 1)[0], ctx.view(Uint16Array, out + 64, 1)[0], ctx.view(Float32Array, out + 72, 1)[0], ctx.view(Float64Array, out + 80, 1)[0], ctx.view(Uint8Array, out + 88, 1)[0] !== 0];
+  });
+};
+
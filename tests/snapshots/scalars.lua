@@ -0,0 +1,209 @@
+-- Generated by futhark-bindgen
+
+local ffi = require("ffi")
+
+ffi.cdef[[
+struct futhark_context_config;
+struct futhark_context;
+struct futhark_context_config *futhark_context_config_new(void);
+void futhark_context_config_free(struct futhark_context_config *cfg);
+void futhark_context_config_set_debugging(struct futhark_context_config *cfg, int flag);
+void futhark_context_config_set_profiling(struct futhark_context_config *cfg, int flag);
+void futhark_context_config_set_logging(struct futhark_context_config *cfg, int flag);
+void futhark_context_config_set_cache_file(struct futhark_context_config *cfg, const char *f);
+struct futhark_context *futhark_context_new(struct futhark_context_config *cfg);
+void futhark_context_free(struct futhark_context *ctx);
+int futhark_context_sync(struct futhark_context *ctx);
+int futhark_context_clear_caches(struct futhark_context *ctx);
+void futhark_context_pause_profiling(struct futhark_context *ctx);
+void futhark_context_unpause_profiling(struct futhark_context *ctx);
+char *futhark_context_get_error(struct futhark_context *ctx);
+char *futhark_context_report(struct futhark_context *ctx);
+void free(void *ptr);
+]]
+
+-- Shared library containing the compiled Futhark code
+local lib = ffi.load(os.getenv("FUTHARK_LIB_SCALARS") or "scalars")
+
+local M = {}
+
+local function take_string(s)
+  if s == nil then
+    return nil
+  end
+  local result = ffi.string(s)
+  ffi.C.free(s)
+  return result
+end
+
+--- Futhark context, freed by `free` or when it is garbage collected
+M.Context = {}
+M.Context.__index = M.Context
+
+--- Create a new context, `options` is an optional table with the fields `debug`, `profile`,
+--- `logging`, `cache_file` and `auto_sync`
+function M.Context.new(options)
+  options = options or {}
+  local config = lib.futhark_context_config_new()
+  if config == nil then
+    error("NULL pointer encountered", 2)
+  end
+  lib.futhark_context_config_set_debugging(config, options.debug and 1 or 0)
+  lib.futhark_context_config_set_profiling(config, options.profile and 1 or 0)
+  lib.futhark_context_config_set_logging(config, options.logging and 1 or 0)
+  if options.cache_file ~= nil then
+    lib.futhark_context_config_set_cache_file(config, options.cache_file)
+  end
+
+  local handle = lib.futhark_context_new(config)
+  if handle == nil then
+    lib.futhark_context_config_free(config)
+    error("NULL pointer encountered", 2)
+  end
+
+  -- Shared with the finalizers of values created using the context
+  local state = { alive = true }
+  local self = setmetatable({
+    config = config,
+    state = state,
+    cache_file = options.cache_file,
+    auto_sync = options.auto_sync ~= false,
+  }, M.Context)
+  self.handle = ffi.gc(handle, function(h)
+    state.alive = false
+    lib.futhark_context_sync(h)
+    lib.futhark_context_free(h)
+    lib.futhark_context_config_free(config)
+  end)
+  return self
+end
+
+--- Free the context, values created using the context can no longer be used
+function M.Context:free()
+  if self.handle == nil then
+    return
+  end
+  local handle = ffi.gc(self.handle, nil)
+  self.state.alive = false
+  lib.futhark_context_sync(handle)
+  lib.futhark_context_free(handle)
+  lib.futhark_context_config_free(self.config)
+  self.handle = nil
+end
+
+function M.Context:check_handle()
+  if self.handle == nil then
+    error("context has already been freed", 3)
+  end
+  return self.handle
+end
+
+function M.Context:check(rc)
+  if rc ~= 0 then
+    error(string.format("Futhark error code %d: %s", rc, self:get_error() or ""), 3)
+  end
+end
+
+--- Wait for all pending operations to finish
+function M.Context:sync()
+  self:check(lib.futhark_context_sync(self:check_handle()))
+end
+
+function M.Context:maybe_sync()
+  if self.auto_sync then
+    self:sync()
+  end
+end
+
+--- Free any cached memory
+function M.Context:clear_caches()
+  self:check(lib.futhark_context_clear_caches(self:check_handle()))
+end
+
+--- Pause profiling
+function M.Context:pause_profiling()
+  lib.futhark_context_pause_profiling(self:check_handle())
+end
+
+--- Resume profiling
+function M.Context:unpause_profiling()
+  lib.futhark_context_unpause_profiling(self:check_handle())
+end
+
+--- Get the last error message
+function M.Context:get_error()
+  return take_string(lib.futhark_context_get_error(self:check_handle()))
+end
+
+--- Get the profiling report
+function M.Context:report()
+  return take_string(lib.futhark_context_report(self:check_handle()))
+end
+
+-- Attach a finalizer to `ptr` that frees it unless the context has been freed first, the
+-- finalizer keeps the context handle alive until the value is collected
+local function manage(ctx, ptr, free)
+  if ptr == nil then
+    error("NULL pointer encountered", 3)
+  end
+  local handle, state = ctx.handle, ctx.state
+  return ffi.gc(ptr, function(p)
+    if state.alive then
+      free(handle, p)
+    end
+  end)
+end
+
+-- Free a value created using `manage`
+local function release(ctx, ptr, free)
+  if ptr ~= nil then
+    ffi.gc(ptr, nil)
+    if ctx.state.alive then
+      free(ctx.handle, ptr)
+    end
+  end
+end
+
+local function check_ptr(value)
+  if value.ptr == nil then
+    error("value has already been freed", 3)
+  end
+  return value.ptr
+end
+
+ffi.cdef[[
+int futhark_entry_add(struct futhark_context *ctx, int32_t *out0, int32_t input0, int32_t input1);
+]]
+
+--- Entry point: add
+function M.Context:add(input0, input1)
+  local out0 = ffi.new("int32_t[1]")
+  self:check(lib.futhark_entry_add(self:check_handle(), out0, input0, input1))
+  self:maybe_sync()
+  return out0[0]
+end
+
+ffi.cdef[[
+int futhark_entry_scalars(struct futhark_context *ctx, int8_t *out0, int16_t *out1, int32_t *out2, int64_t *out3, uint8_t *out4, uint16_t *out5, uint32_t *out6, uint64_t *out7, uint16_t *out8, float *out9, double *out10, bool *out11, int8_t input0, int16_t input1, int32_t input2, int64_t input3, uint8_t input4, uint16_t input5, uint32_t input6, uint64_t input7, uint16_t input8, float input9, double input10, bool input11);
+]]
+
+--- Entry point: scalars
+function M.Context:scalars(input0, input1, input2, input3, input4, input5, input6, input7, input8, input9, input10, input11)
+  local out0 = ffi.new("int8_t[1]")
+  local out1 = ffi.new("int16_t[1]")
+  local out2 = ffi.new("int32_t[1]")
+  local out3 = ffi.new("int64_t[1]")
+  local out4 = ffi.new("uint8_t[1]")
+  local out5 = ffi.new("uint16_t[1]")
+  local out6 = ffi.new("uint32_t[1]")
+  local out7 = ffi.new("uint64_t[1]")
+  local out8 = ffi.new("uint16_t[1]")
+  local out9 = ffi.new("float[1]")
+  local out10 = ffi.new("double[1]")
+  local out11 = ffi.new("bool[1]")
+  self:check(lib.futhark_entry_scalars(self:check_handle(), out0, out1, out2, out3, out4, out5, out6, out7, out8, out9, out10, out11, input0, input1, input2, input3, input4, input5, input6, input7, input8, input9, input10, input11))
+  self:maybe_sync()
+  return out0[0], out1[0], out2[0], out3[0], out4[0], out5[0], out6[0], out7[0], out8[0], out9[0], out10[0], out11[0]
+end
+
+return M
@@ -0,0 +1,161 @@
+# Generated by futhark-bindgen
+
+import std/[os, strutils]
+
+# The library can be selected using `-d:futharkLib=path/to/libscalars.so`
+const futharkLib {.strdefine.} = ""
+const libName = (if futharkLib.len > 0: futharkLib else: DynlibFormat % "scalars")
+
+type
+  FutharkError* = object of CatchableError
+    ## Raised when a Futhark function returns a non-zero error code
+    code*: cint
+
+  NullPtrError* = object of CatchableError
+    ## Raised when a Futhark function returns a NULL pointer
+
+  InvalidShapeError* = object of CatchableError
+    ## Raised when the data of an array doesn't match its shape
+
+  futhark_context_config = object
+  futhark_context = object
+
+  Options* = object
+    ## Options used to create a `Context`
+    debug*: bool
+    profile*: bool
+    logging*: bool
+    cacheFile*: string
+    autoSync*: bool
+
+  ContextObj = object
+    config: ptr futhark_context_config
+    context: ptr futhark_context
+    autoSync: bool
+    cacheFile: string
+
+  Context* = ref ContextObj
+    ## Futhark context
+
+{.push importc, dynlib: libName, cdecl.}
+proc futhark_context_config_new(): ptr futhark_context_config
+proc futhark_context_config_free(cfg: ptr futhark_context_config)
+proc futhark_context_config_set_debugging(cfg: ptr futhark_context_config, flag: cint)
+proc futhark_context_config_set_profiling(cfg: ptr futhark_context_config, flag: cint)
+proc futhark_context_config_set_logging(cfg: ptr futhark_context_config, flag: cint)
+proc futhark_context_config_set_cache_file(cfg: ptr futhark_context_config, f: cstring)
+proc futhark_context_new(cfg: ptr futhark_context_config): ptr futhark_context
+proc futhark_context_free(ctx: ptr futhark_context)
+proc futhark_context_sync(ctx: ptr futhark_context): cint
+proc futhark_context_clear_caches(ctx: ptr futhark_context): cint
+proc futhark_context_pause_profiling(ctx: ptr futhark_context)
+proc futhark_context_unpause_profiling(ctx: ptr futhark_context)
+proc futhark_context_get_error(ctx: ptr futhark_context): cstring
+proc futhark_context_report(ctx: ptr futhark_context): cstring
+{.pop.}
+
+proc cFree(p: pointer) {.importc: "free", header: "<stdlib.h>".}
+
+proc `=destroy`(c: ContextObj) =
+  if c.context != nil:
+    discard futhark_context_sync(c.context)
+    futhark_context_free(c.context)
+  if c.config != nil:
+    futhark_context_config_free(c.config)
+  `=destroy`(c.cacheFile)
+
+proc `=copy`(dst: var ContextObj, src: ContextObj) {.error.}
+
+proc initOptions*(): Options =
+  ## Create new `Options` with default settings
+  Options(autoSync: true)
+
+proc newContext*(options = initOptions()): Context =
+  ## Create a new context
+  let config = futhark_context_config_new()
+  if config == nil:
+    raise newException(NullPtrError, "NULL pointer encountered")
+  result = Context(config: config, autoSync: options.autoSync, cacheFile: options.cacheFile)
+  futhark_context_config_set_debugging(config, cint(options.debug))
+  futhark_context_config_set_profiling(config, cint(options.profile))
+  futhark_context_config_set_logging(config, cint(options.logging))
+  if result.cacheFile.len > 0:
+    futhark_context_config_set_cache_file(config, cstring(result.cacheFile))
+  result.context = futhark_context_new(config)
+  if result.context == nil:
+    raise newException(NullPtrError, "NULL pointer encountered")
+
+proc takeString(s: cstring): string =
+  if s == nil:
+    return ""
+  result = $s
+  cFree(cast[pointer](s))
+
+proc getError*(ctx: Context): string =
+  ## Get the last error message, or an empty string
+  takeString(futhark_context_get_error(ctx.context))
+
+proc report*(ctx: Context): string =
+  ## Get the profiling report
+  takeString(futhark_context_report(ctx.context))
+
+proc check(ctx: Context, rc: cint) =
+  if rc != 0:
+    raise (ref FutharkError)(code: rc, msg: "Futhark error code " & $rc & ": " & ctx.getError())
+
+proc sync*(ctx: Context) =
+  ## Sync the context, if `autoSync` is enabled this shouldn't be needed
+  ctx.check(futhark_context_sync(ctx.context))
+
+proc maybeSync(ctx: Context) =
+  if ctx.autoSync:
+    ctx.sync()
+
+proc clearCaches*(ctx: Context) =
+  ## Clear Futhark caches
+  ctx.check(futhark_context_clear_caches(ctx.context))
+
+proc pauseProfiling*(ctx: Context) =
+  ## Pause Futhark profiling
+  futhark_context_pause_profiling(ctx.context)
+
+proc unpauseProfiling*(ctx: Context) =
+  ## Resume profiling
+  futhark_context_unpause_profiling(ctx.context)
+
+proc checkPtr[T](p: ptr T): ptr T =
+  if p == nil:
+    raise newException(NullPtrError, "NULL pointer encountered")
+  p
+
+type
+
+proc futhark_entry_add(ctx: ptr futhark_context, out0: ptr int32, input0: int32, input1: int32): cint {.importc, dynlib: libName, cdecl.}
+
+proc add*(ctx: Context, input0: int32, input1: int32): int32 =
+  ## Entry point: add
+  var out0: int32
+  ctx.check(futhark_entry_add(ctx.context, addr out0, input0, input1))
+  result = out0
+  ctx.maybeSync()
+
+proc futhark_entry_scalars(ctx: ptr futhark_context, out0: ptr int8, out1: ptr int16, out2: ptr int32, out3: ptr int64, out4: ptr uint8, out5: ptr uint16, out6: ptr uint32, out7: ptr uint64, out8: ptr uint16, out9: ptr float32, out10: ptr float64, out11: ptr bool, input0: int8, input1: int16, input2: int32, input3: int64, input4: uint8, input5: uint16, input6: uint32, input7: uint64, input8: uint16, input9: float32, input10: float64, input11: bool): cint {.importc, dynlib: libName, cdecl.}
+
+proc scalars*(ctx: Context, input0: int8, input1: int16, input2: int32, input3: int64, input4: uint8, input5: uint16, input6: uint32, input7: uint64, input8: uint16, input9: float32, input10: float64, input11: bool): (int8, int16, int32, int64, uint8, uint16, uint32, uint64, uint16, float32, float64, bool) =
+  ## Entry point: scalars
+  var out0: int8
+  var out1: int16
+  var out2: int32
+  var out3: int64
+  var out4: uint8
+  var out5: uint16
+  var out6: uint32
+  var out7: uint64
+  var out8: uint16
+  var out9: float32
+  var out10: float64
+  var out11: bool
+  ctx.check(futhark_entry_scalars(ctx.context, addr out0, addr out1, addr out2, addr out3, addr out4, addr out5, addr out6, addr out7, addr out8, addr out9, addr out10, addr out11, input0, input1, input2, input3, input4, input5, input6, input7, input8, input9, input10, input11))
+  result = (out0, out1, out2, out3, out4, out5, out6, out7, out8, out9, out10, out11)
+  ctx.maybeSync()
+
@@ -0,0 +1,143 @@
+// Generated by futhark-bindgen
+
+const std = @import("std");
+
+const futhark_context_config = opaque {};
+const futhark_context = opaque {};
+
+extern fn futhark_context_config_new() ?*futhark_context_config;
+extern fn futhark_context_config_free(cfg: *futhark_context_config) void;
+extern fn futhark_context_config_set_debugging(cfg: *futhark_context_config, flag: c_int) void;
+extern fn futhark_context_config_set_profiling(cfg: *futhark_context_config, flag: c_int) void;
+extern fn futhark_context_config_set_logging(cfg: *futhark_context_config, flag: c_int) void;
+extern fn futhark_context_config_set_cache_file(cfg: *futhark_context_config, f: [*:0]const u8) void;
+
+extern fn futhark_context_new(cfg: *futhark_context_config) ?*futhark_context;
+extern fn futhark_context_free(ctx: *futhark_context) void;
+extern fn futhark_context_sync(ctx: *futhark_context) c_int;
+extern fn futhark_context_clear_caches(ctx: *futhark_context) c_int;
+extern fn futhark_context_pause_profiling(ctx: *futhark_context) void;
+extern fn futhark_context_unpause_profiling(ctx: *futhark_context) void;
+extern fn futhark_context_get_error(ctx: *futhark_context) ?[*:0]u8;
+extern fn futhark_context_report(ctx: *futhark_context) ?[*:0]u8;
+extern fn free(ptr: ?*anyopaque) void;
+
+/// Errors returned by the generated functions
+pub const Error = error{
+    /// A Futhark function returned a non-zero error code, see `Context.getError`
+    Futhark,
+    /// A NULL pointer was returned
+    NullPtr,
+    /// The data doesn't match the shape of the array
+    InvalidShape,
+};
+
+/// Context options
+pub const Options = struct {
+    debug: bool = false,
+    profile: bool = false,
+    logging: bool = false,
+    cache_file: ?[*:0]const u8 = null,
+    auto_sync: bool = true,
+
+};
+
+/// Futhark context
+pub const Context = struct {
+    config: *futhark_context_config,
+    handle: *futhark_context,
+    auto_sync: bool,
+
+    /// Create a new context
+    pub fn init(options: Options) Error!Context {
+        const config = futhark_context_config_new() orelse return error.NullPtr;
+        futhark_context_config_set_debugging(config, @intFromBool(options.debug));
+        futhark_context_config_set_profiling(config, @intFromBool(options.profile));
+        futhark_context_config_set_logging(config, @intFromBool(options.logging));
+        if (options.cache_file) |f| futhark_context_config_set_cache_file(config, f);
+
+        const handle = futhark_context_new(config) orelse {
+            futhark_context_config_free(config);
+            return error.NullPtr;
+        };
+        return .{ .config = config, .handle = handle, .auto_sync = options.auto_sync };
+    }
+
+    /// Free the context, all values created using the context must be freed first
+    pub fn deinit(self: *Context) void {
+        _ = futhark_context_sync(self.handle);
+        futhark_context_free(self.handle);
+        futhark_context_config_free(self.config);
+    }
+
+    /// Wait for all pending operations to finish
+    pub fn sync(self: *Context) Error!void {
+        if (futhark_context_sync(self.handle) != 0) return error.Futhark;
+    }
+
+    /// Sync if `auto_sync` is enabled
+    pub fn autoSync(self: *Context) Error!void {
+        if (self.auto_sync) try self.sync();
+    }
+
+    /// Free any cached memory
+    pub fn clearCaches(self: *Context) Error!void {
+        if (futhark_context_clear_caches(self.handle) != 0) return error.Futhark;
+    }
+
+    /// Pause profiling
+    pub fn pauseProfiling(self: *Context) void {
+        futhark_context_pause_profiling(self.handle);
+    }
+
+    /// Resume profiling
+    pub fn unpauseProfiling(self: *Context) void {
+        futhark_context_unpause_profiling(self.handle);
+    }
+
+    /// Get the last error message, the caller owns the returned memory
+    pub fn getError(self: *Context, allocator: std.mem.Allocator) std.mem.Allocator.Error!?[]u8 {
+        const s = futhark_context_get_error(self.handle) orelse return null;
+        defer free(s);
+        return try allocator.dupe(u8, std.mem.span(s));
+    }
+
+    /// Get the profiling report, the caller owns the returned memory
+    pub fn report(self: *Context, allocator: std.mem.Allocator) std.mem.Allocator.Error!?[]u8 {
+        const s = futhark_context_report(self.handle) orelse return null;
+        defer free(s);
+        return try allocator.dupe(u8, std.mem.span(s));
+    }
+};
+
+extern fn futhark_entry_add(ctx: *futhark_context, out0: *i32, input0: i32, input1: i32) c_int;
+
+/// Entry point: add
+pub fn add(ctx: *Context, input0: i32, input1: i32) Error!i32 {
+    var out0: i32 = undefined;
+    if (futhark_entry_add(ctx.handle, &out0, input0, input1) != 0) return error.Futhark;
+    try ctx.autoSync();
+    return out0;
+}
+
+extern fn futhark_entry_scalars(ctx: *futhark_context, out0: *i8, out1: *i16, out2: *i32, out3: *i64, out4: *u8, out5: *u16, out6: *u32, out7: *u64, out8: *f16, out9: *f32, out10: *f64, out11: *bool, input0: i8, input1: i16, input2: i32, input3: i64, input4: u8, input5: u16, input6: u32, input7: u64, input8: f16, input9: f32, input10: f64, input11: bool) c_int;
+
+/// Entry point: scalars
+pub fn scalars(ctx: *Context, input0: i8, input1: i16, input2: i32, input3: i64, input4: u8, input5: u16, input6: u32, input7: u64, input8: f16, input9: f32, input10: f64, input11: bool) Error!struct { i8, i16, i32, i64, u8, u16, u32, u64, f16, f32, f64, bool } {
+    var out0: i8 = undefined;
+    var out1: i16 = undefined;
+    var out2: i32 = undefined;
+    var out3: i64 = undefined;
+    var out4: u8 = undefined;
+    var out5: u16 = undefined;
+    var out6: u32 = undefined;
+    var out7: u64 = undefined;
+    var out8: f16 = undefined;
+    var out9: f32 = undefined;
+    var out10: f64 = undefined;
+    var out11: bool = undefined;
+    if (futhark_entry_scalars(ctx.handle, &out0, &out1, &out2, &out3, &out4, &out5, &out6, &out7, &out8, &out9, &out10, &out11, input0, input1, input2, input3, input4, input5, input6, input7, input8, input9, input10, input11) != 0) return error.Futhark;
+    try ctx.autoSync();
+    return .{ out0, out1, out2, out3, out4, out5, out6, out7, out8, out9, out10, out11 };
+}
+
@@ -0,0 +1,98 @@
+// Generated by futhark-bindgen
+
+#ifndef SCALARS_HELPERS_H
+#define SCALARS_HELPERS_H
+
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "scalars.h"
+
+// Error codes for failures that aren't reported by Futhark
+#define SCALARS_HELPERS_ERR_NULL_PTR (-1)
+#define SCALARS_HELPERS_ERR_ALLOC (-2)
+
+// Error reported by the helper functions, `code` is zero on success
+typedef struct scalars_helpers_error {
+  int code;
+  // Error message, freed by `scalars_helpers_error_free`
+  char *message;
+} scalars_helpers_error;
+
+// Free the message stored in `err` and reset it
+static inline void scalars_helpers_error_free(scalars_helpers_error *err) {
+  if (err == NULL) {
+    return;
+  }
+  free(err->message);
+  err->message = NULL;
+  err->code = 0;
+}
+
+static inline char *scalars_helpers_copy_string(const char *s) {
+  size_t n = strlen(s) + 1;
+  char *copy = (char *)malloc(n);
+  if (copy != NULL) {
+    memcpy(copy, s, n);
+  }
+  return copy;
+}
+
+// Store `code` in `err` and return it, Futhark errors take the message from the context
+static inline int scalars_helpers_set_error(struct futhark_context *ctx, scalars_helpers_error *err, int code) {
+  if (err == NULL) {
+    return code;
+  }
+  free(err->message);
+  err->code = code;
+  switch (code) {
+  case SCALARS_HELPERS_ERR_NULL_PTR:
+    err->message = scalars_helpers_copy_string("NULL pointer encountered");
+    break;
+  case SCALARS_HELPERS_ERR_ALLOC:
+    err->message = scalars_helpers_copy_string("Unable to allocate host memory");
+    break;
+  default:
+    err->message = futhark_context_get_error(ctx);
+    break;
+  }
+  return code;
+}
+
+// Run the `add` entry point and wait for it to finish, array outputs are copied into new
+// host buffers which must be freed using `free`. On failure the outputs are set to NULL and
+// the error is stored in `err`
+static inline int scalars_helpers_add(struct futhark_context *ctx, scalars_helpers_error *err, int32_t *out0, int32_t in0, int32_t in1) {
+  int rc = 0;
+  rc = futhark_entry_add(ctx, out0, in0, in1);
+  if (rc == 0) {
+    rc = futhark_context_sync(ctx);
+  }
+  if (rc != 0) {
+    rc = scalars_helpers_set_error(ctx, err, rc);
+    goto done;
+  }
+done:
+  return rc;
+}
+
+// Run the `scalars` entry point and wait for it to finish, array outputs are copied into new
+// host buffers which must be freed using `free`. On failure the outputs are set to NULL and
+// the error is stored in `err`
+static inline int scalars_helpers_scalars(struct futhark_context *ctx, scalars_helpers_error *err, int8_t *out0, int16_t *out1, int32_t *out2, int64_t *out3, uint8_t *out4, uint16_t *out5, uint32_t *out6, uint64_t *out7, uint16_t *out8, float *out9, double *out10, bool *out11, int8_t in0, int16_t in1, int32_t in2, int64_t in3, uint8_t in4, uint16_t in5, uint32_t in6, uint64_t in7, uint16_t in8, float in9, double in10, bool in11) {
+  int rc = 0;
+  rc = futhark_entry_scalars(ctx, out0, out1, out2, out3, out4, out5, out6, out7, out8, out9, out10, out11, in0, in1, in2, in3, in4, in5, in6, in7, in8, in9, in10, in11);
+  if (rc == 0) {
+    rc = futhark_context_sync(ctx);
+  }
+  if (rc != 0) {
+    rc = scalars_helpers_set_error(ctx, err, rc);
+    goto done;
+  }
+done:
+  return rc;
+}
+
+#endif // SCALARS_HELPERS_H
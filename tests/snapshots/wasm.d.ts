@@ -0,0 +1,117 @@
+// Generated by futhark-bindgen
+
+/** Instantiated Emscripten module created by `futhark wasm --library` */
+export interface FutharkModule {
+  HEAPU8: Uint8Array;
+  _malloc(size: number): number;
+  _free(ptr: number): void;
+  [name: string]: any;
+}
+
+/** Context options */
+export interface Options {
+  debug?: boolean;
+  profile?: boolean;
+  logging?: boolean;
+  autoSync?: boolean;
+  numThreads?: number;
+}
+
+/** Thrown when a Futhark function returns a non-zero error code */
+export declare class FutharkError extends Error {
+  readonly code: number;
+}
+
+/** Thrown when a Futhark function returns a NULL pointer */
+export declare class NullPtrError extends Error {}
+
+/** Thrown when data doesn't match the shape of an array */
+export declare class InvalidShapeError extends Error {}
+
+/** Futhark context */
+export declare class Context {
+  constructor(module: FutharkModule, options?: Options);
+  readonly module: FutharkModule;
+  autoSync: boolean;
+
+  /** Free the context, values created using the context can no longer be used */
+  free(): void;
+
+  /** Wait for all pending operations to finish */
+  sync(): void;
+
+  /** Free any cached memory */
+  clearCaches(): void;
+
+  /** Pause profiling */
+  pauseProfiling(): void;
+
+  /** Resume profiling */
+  unpauseProfiling(): void;
+
+  /** Get the last error message */
+  getError(): string | null;
+
+  /** Get the profiling report */
+  report(): string | null;
+}
+
+/** Array type with 2 dimensions and bool elements */
+export declare class ArrayBoolD2 {
+  /** Create a new array with the shape `dims`, initialized with the values from `data` */
+  static from(ctx: Context, data: Uint8Array | ArrayLike<boolean>, dims?: number[]): ArrayBoolD2;
+
+  readonly shape: number[];
+
+  /** Number of elements in the array */
+  readonly length: number;
+
+  /** Copy the values into a new typed array */
+  values(): Uint8Array;
+
+  /** Free the array, it is also freed when it is garbage collected */
+  free(): void;
+}
+
+/** Array type with 1 dimensions and i64 elements */
+export declare class ArrayI64D1 {
+  /** Create a new array with the shape `dims`, initialized with the values from `data` */
+  static from(ctx: Context, data: BigInt64Array | ArrayLike<bigint>, dims?: number[]): ArrayI64D1;
+
+  readonly shape: number[];
+
+  /** Number of elements in the array */
+  readonly length: number;
+
+  /** Copy the values into a new typed array */
+  values(): BigInt64Array;
+
+  /** Free the array, it is also freed when it is garbage collected */
+  free(): void;
+}
+
+/** Futhark type */
+export declare class Flag {
+  /** Free the value, it is also freed when it is garbage collected */
+  free(): void;
+
+  /** Create a new Flag from its fields */
+  static create(ctx: Context, fieldid: bigint, fieldset: boolean): Flag;
+
+  /** Get the id field */
+  getId(): bigint;
+
+  /** Get the set field */
+  getSet(): boolean;
+}
+
+export interface Context {
+  /** Entry point: count */
+  count(input0: ArrayBoolD2): bigint;
+}
+
+export interface Context {
+  /** Entry point: histogram */
+  histogram(input0: ArrayI64D1, input1: bigint, input2: boolean): [ArrayI64D1, Flag];
+}
+
@@ -0,0 +1,321 @@
+// Generated by futhark-bindgen
+
+// `module` is an instantiated Emscripten module created by `futhark wasm --library` or
+// `futhark wasm-multicore --library`, 64-bit integers are passed as `BigInt` values
+
+/** Thrown when a Futhark function returns a non-zero error code */
+export class FutharkError extends Error {
+  constructor(code, message) {
+    super(`Futhark error code ${code}: ${message}`);
+    this.code = code;
+  }
+}
+
+/** Thrown when a Futhark function returns a NULL pointer */
+export class NullPtrError extends Error {
+  constructor() {
+    super("NULL pointer encountered");
+  }
+}
+
+/** Thrown when data doesn't match the shape of an array */
+export class InvalidShapeError extends Error {
+  constructor(expected, actual) {
+    super(`Invalid shape: expected ${expected} elements, got ${actual}`);
+  }
+}
+
+// Frees values that are garbage collected without calling `free`
+const registry =
+  typeof FinalizationRegistry !== "undefined"
+    ? new FinalizationRegistry((free) => free())
+    : null;
+
+function readString(module, ptr) {
+  if (ptr === 0) return null;
+  let end = ptr;
+  while (module.HEAPU8[end] !== 0) end++;
+  const s = new TextDecoder().decode(module.HEAPU8.slice(ptr, end));
+  module._free(ptr);
+  return s;
+}
+
+/** Futhark context */
+export class Context {
+  constructor(module, options = {}) {
+    const m = module;
+    const config = m._futhark_context_config_new();
+    if (config === 0) throw new NullPtrError();
+    m._futhark_context_config_set_debugging(config, options.debug ? 1 : 0);
+    m._futhark_context_config_set_profiling(config, options.profile ? 1 : 0);
+    m._futhark_context_config_set_logging(config, options.logging ? 1 : 0);
+    if (options.numThreads !== undefined) {
+      m._futhark_context_config_set_num_threads(config, options.numThreads);
+    }
+    const handle = m._futhark_context_new(config);
+    if (handle === 0) {
+      m._futhark_context_config_free(config);
+      throw new NullPtrError();
+    }
+    this.module = m;
+    this.config = config;
+    this.handle = handle;
+    this.autoSync = options.autoSync ?? true;
+  }
+
+  /** Free the context, values created using the context can no longer be used */
+  free() {
+    if (this.handle === 0) return;
+    this.module._futhark_context_sync(this.handle);
+    this.module._futhark_context_free(this.handle);
+    this.module._futhark_context_config_free(this.config);
+    this.handle = 0;
+  }
+
+  checkHandle() {
+    if (this.handle === 0) throw new Error("Context has already been freed");
+    return this.handle;
+  }
+
+  check(rc) {
+    if (rc !== 0) throw new FutharkError(rc, this.getError() ?? "");
+  }
+
+  /** Wait for all pending operations to finish */
+  sync() {
+    this.check(this.module._futhark_context_sync(this.checkHandle()));
+  }
+
+  maybeSync() {
+    if (this.autoSync) this.sync();
+  }
+
+  /** Free any cached memory */
+  clearCaches() {
+    this.check(this.module._futhark_context_clear_caches(this.checkHandle()));
+  }
+
+  /** Pause profiling */
+  pauseProfiling() {
+    this.module._futhark_context_pause_profiling(this.checkHandle());
+  }
+
+  /** Resume profiling */
+  unpauseProfiling() {
+    this.module._futhark_context_unpause_profiling(this.checkHandle());
+  }
+
+  /** Get the last error message */
+  getError() {
+    return readString(this.module, this.module._futhark_context_get_error(this.checkHandle()));
+  }
+
+  /** Get the profiling report */
+  report() {
+    return readString(this.module, this.module._futhark_context_report(this.checkHandle()));
+  }
+
+  // Allocate `size` bytes of scratch memory, pass it to `f` and free it afterwards
+  withScratch(size, f) {
+    const ptr = this.module._malloc(Math.max(size, 8));
+    if (ptr === 0) throw new NullPtrError();
+    try {
+      return f(ptr);
+    } finally {
+      this.module._free(ptr);
+    }
+  }
+
+  view(type, ptr, length) {
+    return new type(this.module.HEAPU8.buffer, ptr, length);
+  }
+}
+
+/** Array type with 2 dimensions and bool elements */
+export class ArrayBoolD2 {
+  constructor(ctx, ptr) {
+    const shape = ctx.module._futhark_shape_bool_2d(ctx.handle, ptr);
+    this.ctx = ctx;
+    this.ptr = ptr;
+    this.shape = Array.from(ctx.view(BigInt64Array, shape, 2), Number);
+    registry?.register(this, () => {
+      if (ctx.handle !== 0) ctx.module._futhark_free_bool_2d(ctx.handle, ptr);
+    }, this);
+  }
+
+  /** Create a new array with the shape `dims`, initialized with the values from `data` */
+  static from(ctx, data, dims = [data.length]) {
+    const size = dims.reduce((a, b) => a * b, 1);
+    if (dims.length !== 2 || size !== data.length) {
+      throw new InvalidShapeError(size, data.length);
+    }
+    const ptr = ctx.withScratch(size * 1, (p) => {
+      ctx.view(Uint8Array, p, size).set(data);
+      return ctx.module._futhark_new_bool_2d(ctx.checkHandle(), p, ...dims.map(BigInt));
+    });
+    if (ptr === 0) throw new NullPtrError();
+    ctx.maybeSync();
+    return new ArrayBoolD2(ctx, ptr);
+  }
+
+  checkPtr() {
+    if (this.ptr === 0) throw new Error("Array has already been freed");
+    return this.ptr;
+  }
+
+  /** Number of elements in the array */
+  get length() {
+    return this.shape.reduce((a, b) => a * b, 1);
+  }
+
+  /** Copy the values into a new typed array */
+  values() {
+    const ctx = this.ctx;
+    return ctx.withScratch(this.length * 1, (p) => {
+      ctx.check(ctx.module._futhark_values_bool_2d(ctx.checkHandle(), this.checkPtr(), p));
+      ctx.maybeSync();
+      return ctx.view(Uint8Array, p, this.length).slice();
+    });
+  }
+
+  /** Free the array, it is also freed when it is garbage collected */
+  free() {
+    if (this.ptr !== 0 && this.ctx.handle !== 0) {
+      this.ctx.module._futhark_free_bool_2d(this.ctx.handle, this.ptr);
+    }
+    this.ptr = 0;
+    registry?.unregister(this);
+  }
+}
+
+/** Array type with 1 dimensions and i64 elements */
+export class ArrayI64D1 {
+  constructor(ctx, ptr) {
+    const shape = ctx.module._futhark_shape_i64_1d(ctx.handle, ptr);
+    this.ctx = ctx;
+    this.ptr = ptr;
+    this.shape = Array.from(ctx.view(BigInt64Array, shape, 1), Number);
+    registry?.register(this, () => {
+      if (ctx.handle !== 0) ctx.module._futhark_free_i64_1d(ctx.handle, ptr);
+    }, this);
+  }
+
+  /** Create a new array with the shape `dims`, initialized with the values from `data` */
+  static from(ctx, data, dims = [data.length]) {
+    const size = dims.reduce((a, b) => a * b, 1);
+    if (dims.length !== 1 || size !== data.length) {
+      throw new InvalidShapeError(size, data.length);
+    }
+    const ptr = ctx.withScratch(size * 8, (p) => {
+      ctx.view(BigInt64Array, p, size).set(data instanceof BigInt64Array ? data : Array.from(data, BigInt));
+      return ctx.module._futhark_new_i64_1d(ctx.checkHandle(), p, ...dims.map(BigInt));
+    });
+    if (ptr === 0) throw new NullPtrError();
+    ctx.maybeSync();
+    return new ArrayI64D1(ctx, ptr);
+  }
+
+  checkPtr() {
+    if (this.ptr === 0) throw new Error("Array has already been freed");
+    return this.ptr;
+  }
+
+  /** Number of elements in the array */
+  get length() {
+    return this.shape.reduce((a, b) => a * b, 1);
+  }
+
+  /** Copy the values into a new typed array */
+  values() {
+    const ctx = this.ctx;
+    return ctx.withScratch(this.length * 8, (p) => {
+      ctx.check(ctx.module._futhark_values_i64_1d(ctx.checkHandle(), this.checkPtr(), p));
+      ctx.maybeSync();
+      return ctx.view(BigInt64Array, p, this.length).slice();
+    });
+  }
+
+  /** Free the array, it is also freed when it is garbage collected */
+  free() {
+    if (this.ptr !== 0 && this.ctx.handle !== 0) {
+      this.ctx.module._futhark_free_i64_1d(this.ctx.handle, this.ptr);
+    }
+    this.ptr = 0;
+    registry?.unregister(this);
+  }
+}
+
+/** Futhark type */
+export class Flag {
+  constructor(ctx, ptr) {
+    this.ctx = ctx;
+    this.ptr = ptr;
+    registry?.register(this, () => {
+      if (ctx.handle !== 0) ctx.module._futhark_free_opaque_flag(ctx.handle, ptr);
+    }, this);
+  }
+
+  checkPtr() {
+    if (this.ptr === 0) throw new Error("Value has already been freed");
+    return this.ptr;
+  }
+
+  /** Free the value, it is also freed when it is garbage collected */
+  free() {
+    if (this.ptr !== 0 && this.ctx.handle !== 0) {
+      this.ctx.module._futhark_free_opaque_flag(this.ctx.handle, this.ptr);
+    }
+    this.ptr = 0;
+    registry?.unregister(this);
+  }
+
+  /** Create a new Flag from its fields */
+  static create(ctx, fieldid, fieldset) {
+    return ctx.withScratch(4, (out) => {
+      ctx.check(ctx.module._futhark_new_opaque_flag(ctx.checkHandle(), out, fieldid, fieldset ? 1 : 0));
+      ctx.maybeSync();
+      return new Flag(ctx, ctx.view(Uint32Array, out, 1)[0]);
+    });
+  }
+
+  /** Get the id field */
+  getId() {
+    const ctx = this.ctx;
+    return ctx.withScratch(8, (out) => {
+      ctx.check(ctx.module._futhark_project_opaque_flag_id(ctx.checkHandle(), out, this.checkPtr()));
+      ctx.maybeSync();
+      return ctx.view(BigUint64Array, out, 1)[0];
+    });
+  }
+
+  /** Get the set field */
+  getSet() {
+    const ctx = this.ctx;
+    return ctx.withScratch(8, (out) => {
+      ctx.check(ctx.module._futhark_project_opaque_flag_set(ctx.checkHandle(), out, this.checkPtr()));
+      ctx.maybeSync();
+      return ctx.view(Uint8Array, out, 1)[0] !== 0;
+    });
+  }
+}
+
+/** Entry point: count */
+Context.prototype.count = function (input0) {
+  const ctx = this;
+  return ctx.withScratch(8, (out) => {
+    ctx.check(ctx.module._futhark_entry_count(ctx.checkHandle(), out, input0.checkPtr()));
+    ctx.maybeSync();
+    return ctx.view(BigInt64Array, out, 1)[0];
+  });
+};
+
+/** Entry point: histogram */
+Context.prototype.histogram = function (input0, input1, input2) {
+  const ctx = this;
+  return ctx.withScratch(16, (out) => {
+    ctx.check(ctx.module._futhark_entry_histogram(ctx.checkHandle(), out, out + 8, input0.checkPtr(), input1, input2 ? 1 : 0));
+    ctx.maybeSync();
+    return [new ArrayI64D1(ctx, ctx.view(Uint32Array, out, 1)[0]), new Flag(ctx, ctx.view(Uint32Array, out + 8, 1)[0])];
+  });
+};
+
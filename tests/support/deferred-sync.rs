@@ -0,0 +1,74 @@
+// Appended to the bindings generated for `tests/fixtures/raw.json` by `run_deferred_sync`, along
+// with `mock-context.rs`
+//
+// Provides the Futhark functions used to create a 4 element `ArrayF32D1` and read it back, where
+// `index` and `values` only record the destination and every element is set to 42 once the
+// context is synced, like a GPU backend would. Syncing takes a while, so callers that don't wait
+// for it are caught before the values are written.
+
+mod deferred_sync {
+    use super::mock_context::handle;
+    use super::*;
+    use std::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+
+    static PENDING: AtomicPtr<f32> = AtomicPtr::new(std::ptr::null_mut());
+    static PENDING_LEN: AtomicUsize = AtomicUsize::new(0);
+
+    /// Set once values have been written by a sync
+    pub static SYNCED: AtomicBool = AtomicBool::new(false);
+
+    fn defer(out: *mut f32, len: usize) {
+        PENDING_LEN.store(len, Ordering::SeqCst);
+        PENDING.store(out, Ordering::SeqCst);
+    }
+
+    #[no_mangle]
+    extern "C" fn futhark_context_sync(_: *mut futhark_context) -> std::os::raw::c_int {
+        let out = PENDING.swap(std::ptr::null_mut(), Ordering::SeqCst);
+        if !out.is_null() {
+            std::thread::sleep(std::time::Duration::from_millis(50));
+            let len = PENDING_LEN.load(Ordering::SeqCst);
+            unsafe { std::slice::from_raw_parts_mut(out, len).fill(42.0) };
+            SYNCED.store(true, Ordering::SeqCst);
+        }
+        0
+    }
+
+    #[no_mangle]
+    extern "C" fn futhark_new_raw_f32_1d(
+        _: *mut futhark_context,
+        _: u64,
+        _: i64,
+    ) -> *mut futhark_f32_1d {
+        handle()
+    }
+
+    #[no_mangle]
+    extern "C" fn futhark_free_f32_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_f32_1d,
+    ) -> std::os::raw::c_int {
+        0
+    }
+
+    #[no_mangle]
+    extern "C" fn futhark_index_f32_1d(
+        _: *mut futhark_context,
+        out: *mut f32,
+        _: *mut futhark_f32_1d,
+        _: i64,
+    ) -> std::os::raw::c_int {
+        defer(out, 1);
+        0
+    }
+
+    #[no_mangle]
+    extern "C" fn futhark_values_f32_1d(
+        _: *mut futhark_context,
+        _: *mut futhark_f32_1d,
+        out: *mut f32,
+    ) -> std::os::raw::c_int {
+        defer(out, 4);
+        0
+    }
+}
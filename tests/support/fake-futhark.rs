@@ -0,0 +1,125 @@
+//! Stand-in for the `futhark` executable, used by the test suite
+//!
+//! Accepts the same arguments as `futhark <backend> [args...] -o <output> --lib <src>`, but
+//! instead of compiling Futhark code `src` is expected to contain a manifest. The manifest is
+//! copied to `<output>.json` with the selected backend, along with a header and a C file that
+//! defines an empty function for every symbol the generated bindings can refer to, so they can
+//! be linked. If `src` isn't a manifest compilation fails with a non-zero exit code.
+//!
+//! This is built by the tests using `rustc` directly, so it can't have any dependencies.
+
+const BACKENDS: &[&str] = &[
+    "c",
+    "cuda",
+    "opencl",
+    "multicore",
+    "ispc",
+    "hip",
+    "wasm",
+    "wasm-multicore",
+];
+
+// Context functions used by the generated bindings, every other symbol is named in the manifest
+const CONTEXT_FUNCTIONS: &[&str] = &[
+    "futhark_context_config_new",
+    "futhark_context_config_free",
+    "futhark_context_config_set_debugging",
+    "futhark_context_config_set_profiling",
+    "futhark_context_config_set_logging",
+    "futhark_context_config_set_cache_file",
+    "futhark_context_config_set_tuning_param",
+    "futhark_context_config_set_num_threads",
+    "futhark_context_config_set_device",
+    "futhark_context_config_set_platform",
+    "futhark_context_config_set_default_group_size",
+    "futhark_context_config_set_default_tile_size",
+    "futhark_context_config_set_unified_memory",
+    "futhark_context_new",
+    "futhark_context_free",
+    "futhark_context_sync",
+    "futhark_context_clear_caches",
+    "futhark_context_pause_profiling",
+    "futhark_context_unpause_profiling",
+    "futhark_context_get_error",
+    "futhark_context_report",
+];
+
+fn fail(msg: impl std::fmt::Display) -> ! {
+    eprintln!("Error: {msg}");
+    std::process::exit(1)
+}
+
+/// Replace the value of the `backend` field in the manifest
+fn set_backend(manifest: &str, backend: &str) -> Option<String> {
+    let key = manifest.find("\"backend\"")? + "\"backend\"".len();
+    let colon = key + manifest[key..].find(':')?;
+    let start = colon + 1 + manifest[colon + 1..].find('"')? + 1;
+    let end = start + manifest[start..].find('"')?;
+    Some(format!("{}{backend}{}", &manifest[..start], &manifest[end..]))
+}
+
+/// All function names in the manifest, these are the only string values starting with `futhark_`
+fn manifest_symbols(manifest: &str) -> Vec<&str> {
+    manifest
+        .split('"')
+        .filter(|s| s.starts_with("futhark_"))
+        .filter(|s| s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'))
+        .collect()
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+
+    let backend = match args.next() {
+        Some(b) if BACKENDS.contains(&b.as_str()) => b,
+        Some(b) => fail(format!("invalid backend: {b}")),
+        None => fail("no backend specified"),
+    };
+
+    let mut output = None;
+    let mut lib = false;
+    let mut extra_args = Vec::new();
+    let mut src = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-o" => output = args.next(),
+            "--lib" => lib = true,
+            _ if arg.starts_with('-') => extra_args.push(arg),
+            _ => src = Some(arg),
+        }
+    }
+
+    if !lib {
+        fail("only --lib is supported");
+    }
+
+    let src = src.unwrap_or_else(|| fail("no input file"));
+    let output = std::path::PathBuf::from(output.unwrap_or_else(|| fail("no output file")));
+
+    let data = std::fs::read_to_string(&src).unwrap_or_else(|e| fail(format!("{src}: {e}")));
+    let manifest =
+        set_backend(&data, &backend).unwrap_or_else(|| fail(format!("{src}: not a manifest")));
+
+    let mut symbols = CONTEXT_FUNCTIONS.to_vec();
+    symbols.extend(manifest_symbols(&manifest));
+    symbols.sort_unstable();
+    symbols.dedup();
+
+    let header = output.with_extension("h");
+    let name = header.file_name().unwrap().to_string_lossy();
+    let h = format!(
+        "// Generated by fake-futhark: {}\n#pragma once\n\nint futhark_fake_stub(void);\n",
+        extra_args.join(" ")
+    );
+    let mut c = format!("#include \"{name}\"\n\nint futhark_fake_stub(void) {{ return 0; }}\n");
+    for symbol in symbols {
+        c.push_str(&format!("void {symbol}(void) {{}}\n"));
+    }
+
+    let write = |path: std::path::PathBuf, s: &str| {
+        std::fs::write(&path, s).unwrap_or_else(|e| fail(format!("{}: {e}", path.display())))
+    };
+    write(output.with_extension("json"), &manifest);
+    write(header, &h);
+    write(output.with_extension("c"), &c);
+}
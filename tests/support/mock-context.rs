@@ -0,0 +1,95 @@
+// Appended to generated Rust bindings by `run_rust` in `tests/generate.rs`
+//
+// Provides the context functions used by `Context::new` and `Context::new_with_options`, except
+// for `futhark_context_sync` which is defined by each test along with the functions it calls.
+
+// Defined in a module, the generated code declares the same functions in `extern` blocks
+mod mock_context {
+    use super::*;
+
+    // Any non-null pointer will do, the generated code never dereferences these
+    pub fn handle<T>() -> *mut T {
+        std::ptr::NonNull::dangling().as_ptr()
+    }
+
+    #[no_mangle]
+    extern "C" fn futhark_context_config_new() -> *mut futhark_context_config {
+        handle()
+    }
+
+    #[no_mangle]
+    extern "C" fn futhark_context_config_free(_: *mut futhark_context_config) {}
+
+    #[no_mangle]
+    extern "C" fn futhark_context_config_set_debugging(
+        _: *mut futhark_context_config,
+        _: std::os::raw::c_int,
+    ) {
+    }
+
+    #[no_mangle]
+    extern "C" fn futhark_context_config_set_profiling(
+        _: *mut futhark_context_config,
+        _: std::os::raw::c_int,
+    ) {
+    }
+
+    #[no_mangle]
+    extern "C" fn futhark_context_config_set_logging(
+        _: *mut futhark_context_config,
+        _: std::os::raw::c_int,
+    ) {
+    }
+
+    #[no_mangle]
+    extern "C" fn futhark_context_config_set_cache_file(
+        _: *mut futhark_context_config,
+        _: *const std::os::raw::c_char,
+    ) {
+    }
+
+    #[no_mangle]
+    extern "C" fn futhark_context_config_set_tuning_param(
+        _: *mut futhark_context_config,
+        _: *const std::os::raw::c_char,
+        _: usize,
+    ) -> std::os::raw::c_int {
+        0
+    }
+
+    #[no_mangle]
+    extern "C" fn futhark_context_config_set_device(
+        _: *mut futhark_context_config,
+        _: *const std::os::raw::c_char,
+    ) {
+    }
+
+    #[no_mangle]
+    extern "C" fn futhark_context_config_set_default_group_size(
+        _: *mut futhark_context_config,
+        _: std::os::raw::c_int,
+    ) {
+    }
+
+    #[no_mangle]
+    extern "C" fn futhark_context_config_set_default_tile_size(
+        _: *mut futhark_context_config,
+        _: std::os::raw::c_int,
+    ) {
+    }
+
+    #[no_mangle]
+    extern "C" fn futhark_context_config_set_unified_memory(
+        _: *mut futhark_context_config,
+        _: std::os::raw::c_int,
+    ) {
+    }
+
+    #[no_mangle]
+    extern "C" fn futhark_context_new(_: *mut futhark_context_config) -> *mut futhark_context {
+        handle()
+    }
+
+    #[no_mangle]
+    extern "C" fn futhark_context_free(_: *mut futhark_context) {}
+}
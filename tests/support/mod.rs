@@ -0,0 +1,30 @@
+//! Helpers shared by the integration tests
+
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+/// Path to the `fake-futhark` executable, compiled with `rustc` the first time it's needed
+///
+/// This isn't a `[[bin]]` target so it doesn't get installed along with `futhark-bindgen`
+pub fn fake_futhark() -> String {
+    static EXE: OnceLock<String> = OnceLock::new();
+    EXE.get_or_init(|| {
+        let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        // Each test binary gets its own copy, since they may be run in parallel
+        let out = PathBuf::from(env!("CARGO_TARGET_TMPDIR"))
+            .join("fake-futhark")
+            .join(env!("CARGO_CRATE_NAME"));
+        std::fs::create_dir_all(&out).unwrap();
+        let exe = out.join("fake-futhark");
+        let rustc = std::env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+        let status = std::process::Command::new(rustc)
+            .args(["--edition", "2021", "--crate-name", "fake_futhark", "-o"])
+            .arg(&exe)
+            .arg(root.join("tests/support/fake-futhark.rs"))
+            .status()
+            .expect("unable to run rustc");
+        assert!(status.success(), "unable to compile fake-futhark");
+        exe.to_string_lossy().into_owned()
+    })
+    .clone()
+}